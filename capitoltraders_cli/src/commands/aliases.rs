@@ -0,0 +1,63 @@
+//! The `aliases` subcommand: export/import user-managed ticker aliases.
+//!
+//! Aliases imported here are stored in the `ticker_aliases` table and
+//! override the bundled `seed_data/ticker_aliases.yml` entries during
+//! `enrich-prices`. The CSV has `from,to` columns; an empty `to` marks a
+//! ticker as known-unenrichable.
+
+use std::fs::File;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+use capitoltraders_lib::{parse_ticker_aliases_csv, write_ticker_aliases_csv, Db, OnConflict};
+use clap::Args;
+
+/// Arguments for the `aliases` subcommand.
+#[derive(Args)]
+pub struct AliasesArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Write every stored ticker alias to this CSV file
+    #[arg(long, value_name = "FILE", conflicts_with = "import")]
+    pub export: Option<PathBuf>,
+
+    /// Merge ticker aliases from a CSV file with from,to columns
+    #[arg(long, value_name = "FILE")]
+    pub import: Option<PathBuf>,
+
+    /// On --import, what to do when an alias already exists with a different
+    /// target: keep-existing, overwrite, or error (default: keep-existing)
+    #[arg(long, default_value = "keep-existing", requires = "import")]
+    pub on_conflict: String,
+}
+
+pub fn run(args: &AliasesArgs) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    db.init()?;
+
+    if let Some(path) = &args.export {
+        let aliases = db.get_ticker_aliases()?;
+        write_ticker_aliases_csv(File::create(path)?, &aliases)?;
+        eprintln!("Exported {} ticker aliases to {}", aliases.len(), path.display());
+        return Ok(());
+    }
+
+    let Some(path) = &args.import else {
+        bail!("Specify --export FILE or --import FILE");
+    };
+    let on_conflict: OnConflict = args.on_conflict.parse().map_err(|e: String| anyhow!(e))?;
+    let aliases = parse_ticker_aliases_csv(File::open(path)?)
+        .map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+    let report = db.import_ticker_aliases(&aliases, on_conflict)?;
+    eprintln!(
+        "Imported {} ticker aliases from {}: {} new, {} overwritten, {} kept",
+        aliases.len(),
+        path.display(),
+        report.inserted,
+        report.overwritten,
+        report.kept
+    );
+    Ok(())
+}
@@ -2,34 +2,51 @@
 
 use anyhow::{bail, Result};
 use capitoltraders_lib::{
+    analysis::{tag_session_status, OverlapOptions, TradingIndexParams},
+    anomaly::MAX_WINDOW_DAYS,
     analytics::{
-        aggregate_politician_metrics, calculate_closed_trades, compute_trade_metrics,
-        AnalyticsTrade, PoliticianMetrics,
+        aggregate_politician_metrics, apply_recess_volume_share, apply_tax_estimate,
+        apply_trading_style, calculate_closed_trades, compute_trade_metrics, group_by_session,
+        sort_by_lower_bound, ClosedTrade, IntervalConfig, PoliticianMetrics,
+        TaxRates, TradingStyle,
     },
-    validation, AnalyticsTradeRow, Db,
+    pricing, validation, AnalyticsTradeRow, AppConfig, CoverageRow, CoverageScope, Db, DbTradeFilter,
+    SessionCalendar, SessionStatus, Universe, UniverseProvenance,
 };
-use chrono::{Datelike, Local, NaiveDate};
-use clap::Args;
+use chrono::{Local, NaiveDate};
+use clap::{Args, Subcommand};
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::hints;
 use crate::output::{
-    print_json, print_leaderboard_csv, print_leaderboard_markdown, print_leaderboard_table,
-    print_leaderboard_xml, OutputFormat,
+    print_json, print_label_counts_csv, print_label_counts_markdown, print_label_counts_table,
+    print_label_counts_xml, print_label_report_csv, print_label_report_markdown,
+    print_label_report_table, print_label_report_xml, print_leaderboard_csv, print_leaderboard_markdown, print_leaderboard_table,
+    print_leaderboard_xml, print_overlap_csv, print_overlap_markdown, print_overlap_table,
+    print_overlap_xml, print_session_groups_csv, print_session_groups_markdown,
+    print_session_groups_table, print_session_groups_xml, print_tax_estimate_csv, print_tax_estimate_markdown,
+    print_tax_estimate_table, print_tax_estimate_xml, print_trade_detail_csv,
+    print_trading_index_csv, print_trading_index_markdown, print_trading_index_table,
+    print_trading_index_xml,
+    print_trade_detail_markdown, print_trade_detail_table, print_trade_detail_xml, OutputFormat,
 };
+use crate::universe_args::UniverseArgs;
 
 /// Arguments for the `analytics` subcommand.
 ///
 /// Displays politician performance rankings from the local SQLite database.
 /// Requires a synced and price-enriched database.
 #[derive(Args)]
+#[command(subcommand_negates_reqs = true)]
 pub struct AnalyticsArgs {
     /// SQLite database path (required)
-    #[arg(long)]
-    pub db: PathBuf,
+    #[arg(long, required = true)]
+    pub db: Option<PathBuf>,
 
-    /// Time period filter: ytd, 1y, 2y, all (default: all)
+    /// Time period filter by sell date: all, ytd, a relative window (30d, 6m, 1y, 2y), or a YYYY-MM-DD start (default: all)
     #[arg(long, default_value = "all")]
     pub period: String,
 
@@ -41,6 +58,23 @@ pub struct AnalyticsArgs {
     #[arg(long, default_value = "return")]
     pub sort_by: String,
 
+    /// Rank by the point estimate or by the 95% confidence interval lower
+    /// bound of the --sort-by metric: point, lower-bound (default: point)
+    #[arg(long, default_value = "point")]
+    pub rank_by: String,
+
+    /// Minimum closed trades before confidence intervals are computed (default: 5)
+    #[arg(long, default_value = "5")]
+    pub ci_min_trades: usize,
+
+    /// Bootstrap resamples for the return interval; 0 uses the normal approximation (default: 1000)
+    #[arg(long, default_value = "1000")]
+    pub bootstrap_iterations: usize,
+
+    /// Seed for the bootstrap so intervals are reproducible (default: 42)
+    #[arg(long, default_value = "42")]
+    pub bootstrap_seed: u64,
+
     /// Filter by party: democrat (d), republican (r)
     #[arg(long)]
     pub party: Option<String>,
@@ -49,13 +83,167 @@ pub struct AnalyticsArgs {
     #[arg(long)]
     pub state: Option<String>,
 
+    /// Filter by politician ID: P000197 format -- comma-separated
+    #[arg(long)]
+    pub politician_id: Option<String>,
+
+    /// Filter by trading style: buy-and-hold, active-trader, options-heavy,
+    /// inactive, mixed, unclassified. Thresholds come from [trading_style]
+    /// in the config file
+    #[arg(long, conflicts_with_all = ["trade_detail", "by_session"])]
+    pub style: Option<String>,
+
     /// Number of results to show (default: 25)
     #[arg(long, default_value = "25")]
     pub top: usize,
+
+    /// Minimum % of priceable stock trades with a trade-date price before warning (default: 50)
+    #[arg(long, default_value = "50")]
+    pub min_price_coverage: f64,
+
+    /// Fail instead of warning when price coverage is below --min-price-coverage
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Also warn about each listed politician whose own trades are priced below --min-price-coverage
+    #[arg(long)]
+    pub coverage_warnings: bool,
+
+    /// Show realized gains split into short/long-term with an approximate tax estimate
+    #[arg(long)]
+    pub tax_estimate: bool,
+
+    /// Tax rate applied to net short-term gains with --tax-estimate (default: 0.37)
+    #[arg(long, default_value = "0.37")]
+    pub short_rate: f64,
+
+    /// Tax rate applied to net long-term gains with --tax-estimate (default: 0.20)
+    #[arg(long, default_value = "0.20")]
+    pub long_rate: f64,
+
+    /// Print the FIFO lot-matched buy/sell legs for one ticker, with filing
+    /// URLs (requires a single --politician-id)
+    #[arg(long, value_name = "TICKER", requires = "politician_id", conflicts_with = "tax_estimate")]
+    pub trade_detail: Option<String>,
+
+    /// Recompute the leaderboard and store it in the database, so later runs
+    /// with the same --period and interval options read it instead
+    #[arg(long, conflicts_with = "trade_detail")]
+    pub refresh: bool,
+
+    /// Group trading volume and closed-trade returns by chamber and whether
+    /// the chamber was in session or in recess on the trade date
+    #[arg(long, conflicts_with_all = ["trade_detail", "tax_estimate", "refresh"])]
+    pub by_session: bool,
+
+    #[command(flatten)]
+    pub universe: UniverseArgs,
+
+    #[command(subcommand)]
+    pub action: Option<AnalyticsAction>,
+}
+
+#[derive(Subcommand)]
+pub enum AnalyticsAction {
+    /// Volume, buy/sell split and realized P&L for trades carrying a label
+    Labels(LabelsArgs),
+    /// Daily net buying pressure normalized by trailing activity, for charting
+    Index(IndexArgs),
+    /// Politician pairs who trade the same issuers, by Jaccard and
+    /// dollar-weighted cosine similarity
+    Overlap(OverlapArgs),
+}
+
+/// Arguments for `analytics labels`.
+#[derive(Args)]
+pub struct LabelsArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Label to report on, case-insensitive (e.g. faang, crypto, memestock, spac)
+    #[arg(long, required_unless_present = "list", conflicts_with = "list")]
+    pub label: Option<String>,
+
+    /// List every label on the filtered trades with its trade count instead
+    #[arg(long)]
+    pub list: bool,
+
+    /// Filter by party: democrat (d), republican (r)
+    #[arg(long)]
+    pub party: Option<String>,
+
+    /// Filter by state (e.g., CA, TX)
+    #[arg(long)]
+    pub state: Option<String>,
+
+    /// Filter by politician ID: P000197 format
+    #[arg(long)]
+    pub politician_id: Option<String>,
+
+    /// Only trades published on or after this date: YYYY-MM-DD or a relative window (30d, 6m, 1y)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Number of politicians to show (default: 10)
+    #[arg(long, default_value = "10")]
+    pub top: usize,
+}
+
+/// Arguments for `analytics index`.
+#[derive(Args)]
+pub struct IndexArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Chamber: house (h) or senate (s); both combined when omitted
+    #[arg(long)]
+    pub chamber: Option<String>,
+
+    /// First day of the series: YYYY-MM-DD or a relative window (30d, 6m, 1y)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Trailing days of gross value that normalize each day's net, 1-3650 (default: 90)
+    #[arg(
+        long,
+        default_value = "90",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=MAX_WINDOW_DAYS as u64)
+    )]
+    pub window: usize,
+
+    /// EMA span for the smoothed column; 0 disables smoothing (default: 20)
+    #[arg(long, default_value = "20")]
+    pub ema_span: usize,
+}
+
+/// Arguments for `analytics overlap`.
+#[derive(Args)]
+pub struct OverlapArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Minimum issuers a pair must both have traded (default: 5)
+    #[arg(long, default_value = "5")]
+    pub min_shared: usize,
+
+    /// Skip politicians with fewer than N trades before pairing (default: 10)
+    #[arg(long, value_name = "N", default_value = "10")]
+    pub min_trades: usize,
+
+    /// Only trades executed on or after this date: YYYY-MM-DD or a relative window (30d, 6m, 1y)
+    #[arg(long)]
+    pub since: Option<String>,
+
+    /// Number of pairs to show (default: 100)
+    #[arg(long, default_value = "100")]
+    pub top: usize,
 }
 
 /// Enriched leaderboard row for output (includes politician name, party, state).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct LeaderboardRow {
     pub rank: usize,
     pub politician_name: String,
@@ -64,22 +252,112 @@ pub struct LeaderboardRow {
     pub total_trades: usize,
     pub win_rate: f64,
     pub avg_return: f64,
+    /// 95% interval for avg_return; None below --ci-min-trades.
+    pub return_ci_low: Option<f64>,
+    pub return_ci_high: Option<f64>,
+    /// 95% Wilson interval for win_rate; None below --ci-min-trades.
+    pub win_rate_ci_low: Option<f64>,
+    pub win_rate_ci_high: Option<f64>,
     pub avg_alpha: Option<f64>,
     pub avg_holding_days: Option<f64>,
     pub percentile: f64,
+    /// Fraction of estimated volume traded while the member's chamber was in
+    /// recess; None when the session calendar covers none of their trades.
+    pub recess_volume_share: Option<f64>,
+    pub median_holding_days: Option<i64>,
+    pub trading_style: TradingStyle,
 }
 
-pub fn run(args: &AnalyticsArgs, format: &OutputFormat) -> Result<()> {
-    let db = Db::open(&args.db)?;
+/// Leaderboard JSON output: the ranked politicians and the trades they were
+/// ranked on.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct LeaderboardReport {
+    pub universe: UniverseProvenance,
+    pub politicians: Vec<LeaderboardRow>,
+}
 
-    // Validate period filter
-    let period_normalized = args.period.trim().to_lowercase();
-    if !matches!(period_normalized.as_str(), "ytd" | "1y" | "2y" | "all") {
-        bail!(
-            "Invalid --period value: '{}'. Valid options: ytd, 1y, 2y, all",
-            args.period
-        );
+/// One FIFO lot leg for `--trade-detail`: the part of a buy filing closed
+/// by a sell filing.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TradeDetailRow {
+    pub ticker: String,
+    pub sell_tx_id: i64,
+    pub sell_date: String,
+    pub sell_price: f64,
+    pub sell_filing_url: String,
+    pub buy_tx_id: i64,
+    pub buy_date: String,
+    pub buy_price: f64,
+    pub buy_filing_url: String,
+    /// Estimated shares of the buy lot matched to this sell.
+    pub shares: f64,
+    pub return_pct: f64,
+    pub realized_gain: f64,
+    pub holding_days: Option<i64>,
+}
+
+/// Per-politician realized gain split by holding period, for `--tax-estimate`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TaxEstimateRow {
+    pub rank: usize,
+    pub politician_name: String,
+    pub party: String,
+    pub state: String,
+    pub total_trades: usize,
+    pub realized_short_term_gain: f64,
+    pub realized_long_term_gain: f64,
+    /// Closed trades without a usable holding period, left out of the estimate.
+    pub unknown_term_trades: usize,
+    pub estimated_tax: f64,
+}
+
+/// `--tax-estimate` output with the assumptions behind the figures.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct TaxEstimateReport {
+    pub approximate: bool,
+    pub short_rate: f64,
+    pub long_rate: f64,
+    pub basis: &'static str,
+    /// The trades the estimate was computed from.
+    pub universe: UniverseProvenance,
+    pub politicians: Vec<TaxEstimateRow>,
+}
+
+/// Caveat printed with every tax estimate.
+pub const TAX_ESTIMATE_BASIS: &str = "Approximate: flat rates on estimated share counts; losses offset gains only within the same term; trades with unknown dates excluded";
+
+pub fn run(args: &AnalyticsArgs, config: &AppConfig, format: &OutputFormat) -> Result<()> {
+    match args.action {
+        Some(AnalyticsAction::Labels(ref labels_args)) => return run_labels(labels_args, format),
+        Some(AnalyticsAction::Index(ref index_args)) => return run_index(index_args, format),
+        Some(AnalyticsAction::Overlap(ref overlap_args)) => {
+            return run_overlap(overlap_args, format)
+        }
+        None => {}
     }
+    let Some(ref db_path) = args.db else {
+        bail!("--db is required");
+    };
+
+    // Only --refresh writes (the leaderboard cache); everything else reads.
+    let db = if args.refresh {
+        let db = Db::open(db_path)?;
+        db.init()?;
+        db
+    } else {
+        Db::open_read_only(db_path)?
+    };
+
+    // Resolve period filter: "all" or a relative/ISO start date
+    let period_cutoff = if args.period.trim().eq_ignore_ascii_case("all") {
+        None
+    } else {
+        let today = Local::now().naive_local().date();
+        Some(
+            validation::parse_relative_date(&args.period, today)
+                .map_err(|e| anyhow::anyhow!("Invalid --period value: {}", e))?,
+        )
+    };
 
     // Validate sort_by filter
     let sort_by_normalized = args.sort_by.trim().to_lowercase();
@@ -90,6 +368,18 @@ pub fn run(args: &AnalyticsArgs, format: &OutputFormat) -> Result<()> {
         );
     }
 
+    // Validate rank_by; alpha has no interval to rank on
+    let rank_by_normalized = args.rank_by.trim().to_lowercase();
+    if !matches!(rank_by_normalized.as_str(), "point" | "lower-bound") {
+        bail!(
+            "Invalid --rank-by value: '{}'. Valid options: point, lower-bound",
+            args.rank_by
+        );
+    }
+    if rank_by_normalized == "lower-bound" && sort_by_normalized == "alpha" {
+        bail!("--rank-by lower-bound supports --sort-by return or win-rate, not alpha");
+    }
+
     // Validate party filter if provided
     let party_filter = match args.party {
         Some(ref val) => Some(validation::validate_party(val.trim())?.to_string()),
@@ -102,84 +392,127 @@ pub fn run(args: &AnalyticsArgs, format: &OutputFormat) -> Result<()> {
         None => None,
     };
 
-    // Query all enriched trades
-    let trade_rows = db.query_trades_for_analytics()?;
+    let style_filter = match args.style {
+        Some(ref val) => Some(TradingStyle::parse(val).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid --style value: '{}'. Valid options: {}",
+                val,
+                TradingStyle::ALL
+                    .iter()
+                    .map(|s| s.as_str().replace('_', "-"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?),
+        None => None,
+    };
 
-    if trade_rows.is_empty() {
-        eprintln!("No enriched stock trades found.");
-        eprintln!(
-            "Hint: Run 'capitoltraders sync --db {}' then 'capitoltraders enrich-prices --db {}' first.",
-            args.db.display(),
-            args.db.display()
+    let politician_id_filter = match args.politician_id {
+        Some(ref val) => Some(
+            val.split(',')
+                .map(validation::validate_politician_id)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => None,
+    };
+
+    if !(0.0..=100.0).contains(&args.min_price_coverage) {
+        bail!(
+            "Invalid --min-price-coverage value: {}. Must be between 0 and 100",
+            args.min_price_coverage
         );
-        return Ok(());
     }
 
-    // Convert DB rows to AnalyticsTrade
-    let analytics_trades: Vec<AnalyticsTrade> = trade_rows
-        .iter()
-        .map(row_to_analytics_trade)
-        .collect();
+    for (flag, rate) in [("--short-rate", args.short_rate), ("--long-rate", args.long_rate)] {
+        if !(0.0..=1.0).contains(&rate) {
+            bail!("Invalid {} value: {}. Must be between 0 and 1", flag, rate);
+        }
+    }
 
-    // Run FIFO matching
-    let closed_trades = calculate_closed_trades(analytics_trades, false);
+    // Precheck price enrichment so a sparse DB is not mistaken for an empty leaderboard
+    check_price_coverage(&db, db_path, args)?;
 
-    if closed_trades.is_empty() {
-        eprintln!("No closed trades found (no matched buy-sell pairs).");
-        eprintln!("Hint: Politicians need at least one sell transaction to generate closed trades.");
-        return Ok(());
-    }
+    let universe = args.universe.load(&db)?;
 
-    // Apply time period filter to closed trades (before computing metrics)
-    let filtered_closed_trades = filter_closed_trades_by_period(&closed_trades, &period_normalized)?;
+    let intervals = IntervalConfig {
+        min_trades: args.ci_min_trades,
+        bootstrap_iterations: args.bootstrap_iterations,
+        seed: args.bootstrap_seed,
+    };
 
-    if filtered_closed_trades.is_empty() {
-        eprintln!("No closed trades found in the selected period '{}'.", args.period);
+    if let Some(ref ticker) = args.trade_detail {
+        let ids = politician_id_filter.as_deref().unwrap_or_default();
+        let [politician_id] = ids else {
+            bail!("--trade-detail takes exactly one --politician-id");
+        };
+        let Some(closed_trades) = load_closed_trades(&universe, db_path, args, period_cutoff)?
+        else {
+            return Ok(());
+        };
+        print_trade_detail(&db, &closed_trades, politician_id, ticker, format)?;
+        eprintln!("Trade universe: {}", universe.provenance);
         return Ok(());
     }
 
-    // Compute per-trade metrics
-    let trade_metrics: Vec<_> = filtered_closed_trades
-        .iter()
-        .map(compute_trade_metrics)
-        .collect();
+    let calendar = SessionCalendar::load(config.session_calendar_file.as_deref())?;
+    let filters = PoliticianFilters {
+        party: party_filter,
+        state: state_filter,
+        ids: politician_id_filter,
+    };
+    if args.by_session {
+        return print_session_groups(&db, &universe, &calendar, &filters, period_cutoff, format);
+    }
 
-    // Aggregate by politician
-    let mut politician_metrics = aggregate_politician_metrics(&trade_metrics);
+    let cache_key = analytics_cache_key(period_cutoff, &intervals, &universe);
+    let cached = if args.refresh {
+        None
+    } else {
+        read_analytics_cache(&db, &cache_key)?
+    };
+    let (mut politician_metrics, closed_trade_count) = match cached {
+        Some(hit) => hit,
+        None => {
+            // Fingerprint before reading, so trades synced mid-run leave the
+            // cache detectably stale rather than silently current.
+            let source = if args.refresh {
+                Some(db.analytics_source_state()?)
+            } else {
+                None
+            };
+            let Some(closed_trades) = load_closed_trades(&universe, db_path, args, period_cutoff)?
+            else {
+                if let Some(ref source) = source {
+                    db.replace_analytics_cache(&cache_key, source, 0, &[])?;
+                }
+                return Ok(());
+            };
+            let trade_metrics: Vec<_> = closed_trades.iter().map(compute_trade_metrics).collect();
+            let metrics = aggregate_politician_metrics(&trade_metrics, &intervals);
+            if let Some(ref source) = source {
+                db.replace_analytics_cache(&cache_key, source, closed_trades.len(), &metrics)?;
+                eprintln!(
+                    "Refreshed analytics cache: {} politicians from {} source trades",
+                    metrics.len(),
+                    source.rows
+                );
+            }
+            (metrics, closed_trades.len())
+        }
+    };
+
+    if closed_trade_count == 0 {
+        eprintln!("No closed trades found (cached leaderboard is empty).");
+        return Ok(());
+    }
 
     // Load politician metadata for filtering and enrichment
     let politician_metadata = load_politician_metadata(&db)?;
 
     // Apply politician-level filters
     politician_metrics.retain(|pm| {
-        // Min trades filter
-        if pm.total_trades < args.min_trades {
-            return false;
-        }
-
-        // Party filter
-        if let Some(ref party) = party_filter {
-            if let Some(meta) = politician_metadata.get(&pm.politician_id) {
-                if &meta.party != party {
-                    return false;
-                }
-            } else {
-                return false; // No metadata = exclude
-            }
-        }
-
-        // State filter
-        if let Some(ref state) = state_filter {
-            if let Some(meta) = politician_metadata.get(&pm.politician_id) {
-                if &meta.state != state {
-                    return false;
-                }
-            } else {
-                return false; // No metadata = exclude
-            }
-        }
-
-        true
+        pm.total_trades >= args.min_trades
+            && filters.matches(&pm.politician_id, &politician_metadata)
     });
 
     if politician_metrics.is_empty() {
@@ -187,18 +520,120 @@ pub fn run(args: &AnalyticsArgs, format: &OutputFormat) -> Result<()> {
         return Ok(());
     }
 
+    // Style labels come before the percentile pool so --style narrows it too
+    let session_rows = load_session_rows(&universe, &calendar, period_cutoff);
+    let style_closed = filter_closed_trades_by_period(
+        &calculate_closed_trades(universe.analytics_trades(), false),
+        period_cutoff,
+    );
+    let cutoff_str = period_cutoff.map(|d| d.format("%Y-%m-%d").to_string());
+    apply_trading_style(
+        &mut politician_metrics,
+        &session_rows,
+        &style_closed,
+        &db.option_trade_counts(cutoff_str.as_deref())?,
+        &config.trading_style_config(),
+    );
+    if let Some(style) = style_filter {
+        politician_metrics.retain(|pm| pm.trading_style == style);
+        if politician_metrics.is_empty() {
+            eprintln!("No politicians with trading style '{}'.", style);
+            return Ok(());
+        }
+    }
+
     // Re-compute percentile ranks after filtering
     recompute_percentile_ranks(&mut politician_metrics);
 
     // Sort by selected metric
-    sort_by_metric(&mut politician_metrics, &sort_by_normalized);
+    if rank_by_normalized == "lower-bound" {
+        match sort_by_normalized.as_str() {
+            "return" => sort_by_lower_bound(
+                &mut politician_metrics,
+                |pm| pm.return_ci_low,
+                |pm| pm.avg_return,
+            ),
+            _ => sort_by_lower_bound(
+                &mut politician_metrics,
+                |pm| pm.win_rate_ci_low,
+                |pm| pm.win_rate,
+            ),
+        }
+    } else {
+        sort_by_metric(&mut politician_metrics, &sort_by_normalized);
+    }
 
     // Truncate to top N
     let total_politicians = politician_metrics.len();
     politician_metrics.truncate(args.top);
 
+    if args.coverage_warnings {
+        warn_low_politician_coverage(&db, &politician_metrics, args.min_price_coverage)?;
+    }
+
+    apply_recess_volume_share(&mut politician_metrics, &session_rows);
+
+    if args.tax_estimate {
+        apply_tax_estimate(
+            &mut politician_metrics,
+            TaxRates {
+                short_term: args.short_rate,
+                long_term: args.long_rate,
+            },
+        );
+        let report = TaxEstimateReport {
+            approximate: true,
+            short_rate: args.short_rate,
+            long_rate: args.long_rate,
+            basis: TAX_ESTIMATE_BASIS,
+            universe: universe.provenance.clone(),
+            politicians: politician_metrics
+                .iter()
+                .enumerate()
+                .map(|(idx, pm)| {
+                    let meta = politician_metadata.get(&pm.politician_id).unwrap();
+                    TaxEstimateRow {
+                        rank: idx + 1,
+                        politician_name: meta.name.clone(),
+                        party: meta.party.clone(),
+                        state: meta.state.clone(),
+                        total_trades: pm.total_trades,
+                        realized_short_term_gain: pm.realized_short_term_gain,
+                        realized_long_term_gain: pm.realized_long_term_gain,
+                        unknown_term_trades: pm.unknown_term_trades,
+                        estimated_tax: pm.estimated_tax.unwrap_or(0.0),
+                    }
+                })
+                .collect(),
+        };
+
+        match format {
+            OutputFormat::Table => print_tax_estimate_table(&report.politicians),
+            OutputFormat::Json => print_json(&report),
+            OutputFormat::Csv => print_tax_estimate_csv(&report.politicians, &report.universe)?,
+            OutputFormat::Markdown => print_tax_estimate_markdown(&report.politicians),
+            OutputFormat::Xml => print_tax_estimate_xml(&report),
+        }
+
+        eprintln!(
+            "Tax estimate at {:.0}% short-term / {:.0}% long-term. {}.",
+            args.short_rate * 100.0,
+            args.long_rate * 100.0,
+            TAX_ESTIMATE_BASIS
+        );
+        eprintln!(
+            "Showing {}/{} politicians ({} closed trades analyzed, period: {})",
+            report.politicians.len(),
+            total_politicians,
+            closed_trade_count,
+            args.period
+        );
+        eprintln!("Trade universe: {}", universe.provenance);
+        return Ok(());
+    }
+
     // Build leaderboard rows
-    let leaderboard_rows: Vec<LeaderboardRow> = politician_metrics
+    let politicians: Vec<LeaderboardRow> = politician_metrics
         .iter()
         .enumerate()
         .map(|(idx, pm)| {
@@ -211,85 +646,573 @@ pub fn run(args: &AnalyticsArgs, format: &OutputFormat) -> Result<()> {
                 total_trades: pm.total_trades,
                 win_rate: pm.win_rate,
                 avg_return: pm.avg_return,
+                return_ci_low: pm.return_ci_low,
+                return_ci_high: pm.return_ci_high,
+                win_rate_ci_low: pm.win_rate_ci_low,
+                win_rate_ci_high: pm.win_rate_ci_high,
                 avg_alpha: pm
                     .avg_alpha_spy
                     .or(pm.avg_alpha_sector),
                 avg_holding_days: pm.avg_holding_days.map(|d| d as f64),
                 percentile: pm.percentile_rank,
+                recess_volume_share: pm.recess_volume_share,
+                median_holding_days: pm.median_holding_days,
+                trading_style: pm.trading_style,
             }
         })
         .collect();
 
+    let report = LeaderboardReport {
+        universe: universe.provenance.clone(),
+        politicians,
+    };
+
     // Output leaderboard
     match format {
-        OutputFormat::Table => print_leaderboard_table(&leaderboard_rows),
-        OutputFormat::Json => print_json(&leaderboard_rows),
-        OutputFormat::Csv => print_leaderboard_csv(&leaderboard_rows)?,
-        OutputFormat::Markdown => print_leaderboard_markdown(&leaderboard_rows),
-        OutputFormat::Xml => print_leaderboard_xml(&leaderboard_rows),
+        OutputFormat::Table => print_leaderboard_table(&report.politicians),
+        OutputFormat::Json => print_json(&report),
+        OutputFormat::Csv => print_leaderboard_csv(&report.politicians, &report.universe)?,
+        OutputFormat::Markdown => print_leaderboard_markdown(&report.politicians),
+        OutputFormat::Xml => print_leaderboard_xml(&report.politicians),
     }
 
     // Print summary to stderr
     eprintln!(
         "Showing {}/{} politicians ({} closed trades analyzed, period: {})",
-        leaderboard_rows.len(),
+        report.politicians.len(),
         total_politicians,
-        filtered_closed_trades.len(),
+        closed_trade_count,
         args.period
     );
+    eprintln!("Trade universe: {}", universe.provenance);
 
     Ok(())
 }
 
-/// Convert AnalyticsTradeRow to AnalyticsTrade.
-fn row_to_analytics_trade(row: &AnalyticsTradeRow) -> AnalyticsTrade {
-    // has_sector_benchmark: true if gics_sector.is_some() AND benchmark_price.is_some()
-    let has_sector_benchmark = row.gics_sector.is_some() && row.benchmark_price.is_some();
-
-    AnalyticsTrade {
-        tx_id: row.tx_id,
-        politician_id: row.politician_id.clone(),
-        ticker: row.issuer_ticker.clone(),
-        tx_type: row.tx_type.clone(),
-        tx_date: row.tx_date.clone(),
-        estimated_shares: row.estimated_shares,
-        trade_date_price: row.trade_date_price,
-        benchmark_price: row.benchmark_price,
-        has_sector_benchmark,
-        gics_sector: row.gics_sector.clone(),
+/// Replay priced trades through FIFO and keep the legs closed within the
+/// period. Returns None, after explaining why on stderr, when nothing is left.
+fn load_closed_trades(
+    universe: &Universe,
+    db_path: &Path,
+    args: &AnalyticsArgs,
+    period_cutoff: Option<NaiveDate>,
+) -> Result<Option<Vec<ClosedTrade>>> {
+    let analytics_trades = universe.analytics_trades();
+
+    if analytics_trades.is_empty() {
+        eprintln!("No enriched stock trades found.");
+        eprintln!(
+            "Hint: Run 'capitoltraders sync --db {}' then 'capitoltraders enrich-prices --db {}' first.",
+            db_path.display(),
+            db_path.display()
+        );
+        return Ok(None);
+    }
+
+    // Run FIFO matching
+    let closed_trades = calculate_closed_trades(analytics_trades, false);
+
+    if closed_trades.is_empty() {
+        eprintln!("No closed trades found (no matched buy-sell pairs).");
+        eprintln!("Hint: Politicians need at least one sell transaction to generate closed trades.");
+        return Ok(None);
     }
+
+    // Apply time period filter to closed trades (before computing metrics)
+    let filtered_closed_trades = filter_closed_trades_by_period(&closed_trades, period_cutoff);
+
+    if filtered_closed_trades.is_empty() {
+        eprintln!("No closed trades found in the selected period '{}'.", args.period);
+        return Ok(None);
+    }
+
+    Ok(Some(filtered_closed_trades))
 }
 
-/// Filter closed trades by time period based on sell_date.
-fn filter_closed_trades_by_period(
-    trades: &[capitoltraders_lib::analytics::ClosedTrade],
-    period: &str,
-) -> Result<Vec<capitoltraders_lib::analytics::ClosedTrade>> {
-    let today = Local::now().naive_local().date();
-
-    let cutoff_date = match period {
-        "ytd" => {
-            // Year-to-date: Jan 1 of current year
-            NaiveDate::from_ymd_opt(today.year(), 1, 1)
-                .ok_or_else(|| anyhow::anyhow!("Invalid date calculation for YTD"))?
+/// Identifies the options a cached leaderboard was computed with. Relative
+/// periods resolve to a date, so a `--period 1y` cache expires daily.
+fn analytics_cache_key(
+    period_cutoff: Option<NaiveDate>,
+    intervals: &IntervalConfig,
+    universe: &Universe,
+) -> String {
+    format!(
+        "period={};ci_min_trades={};bootstrap={};seed={};universe={}",
+        period_cutoff.map_or_else(|| "all".to_string(), |d| d.to_string()),
+        intervals.min_trades,
+        intervals.bootstrap_iterations,
+        intervals.seed,
+        universe.provenance.spec.key()
+    )
+}
+
+/// Cached per-politician metrics and closed-trade count, if the cache was
+/// built with `cache_key` and the source trades have not changed since.
+fn read_analytics_cache(db: &Db, cache_key: &str) -> Result<Option<(Vec<PoliticianMetrics>, usize)>> {
+    let Some(cache) = db.load_analytics_cache()? else {
+        return Ok(None);
+    };
+    if cache.cache_key != cache_key {
+        eprintln!(
+            "Note: analytics cache holds other options ({}); computing live. Run with --refresh to cache these.",
+            cache.cache_key
+        );
+        return Ok(None);
+    }
+    let current = db.analytics_source_state()?;
+    if current != cache.source {
+        eprintln!(
+            "Warning: analytics cache from {} is stale ({} source trades then, {} now); computing live. Run with --refresh to update it.",
+            cache.refreshed_at, cache.source.rows, current.rows
+        );
+        return Ok(None);
+    }
+    eprintln!("Using analytics cache from {}", cache.refreshed_at);
+    Ok(Some((cache.metrics, cache.closed_trades)))
+}
+
+/// Print the lot legs closed for one politician and ticker, oldest sell first,
+/// with each leg's buy and sell filing URL. A bare `ticker` ("NVDA") matches
+/// the stored suffixed form ("NVDA:US").
+fn print_trade_detail(
+    db: &Db,
+    closed_trades: &[ClosedTrade],
+    politician_id: &str,
+    ticker: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let filing_urls: HashMap<i64, String> = db
+        .query_trades(&DbTradeFilter {
+            politician_id: Some(politician_id.to_string()),
+            ..DbTradeFilter::default()
+        })?
+        .into_iter()
+        .map(|row| (row.tx_id, row.filing_url))
+        .collect();
+    let url = |tx_id: i64| filing_urls.get(&tx_id).cloned().unwrap_or_default();
+
+    // Legs come out of FIFO matching in sell order already, lots oldest first.
+    let rows: Vec<TradeDetailRow> = closed_trades
+        .iter()
+        .filter(|ct| ct.politician_id == politician_id && pricing::ticker_matches(ticker, &ct.ticker))
+        .map(|ct| {
+            let metrics = compute_trade_metrics(ct);
+            TradeDetailRow {
+                ticker: ct.ticker.clone(),
+                sell_tx_id: ct.sell_tx_id,
+                sell_date: ct.sell_date.clone(),
+                sell_price: ct.sell_price,
+                sell_filing_url: url(ct.sell_tx_id),
+                buy_tx_id: ct.buy_tx_id,
+                buy_date: ct.buy_date.clone(),
+                buy_price: ct.buy_price,
+                buy_filing_url: url(ct.buy_tx_id),
+                shares: ct.shares,
+                return_pct: metrics.absolute_return,
+                realized_gain: metrics.realized_gain,
+                holding_days: metrics.holding_days,
+            }
+        })
+        .collect();
+
+    if rows.is_empty() {
+        eprintln!(
+            "No closed {} trades found for {}.",
+            ticker.to_uppercase(),
+            politician_id
+        );
+        if db.find_issuer_ticker(&ticker.to_uppercase())?.is_none() {
+            if let Some(hint) = hints::issuer_hint(db, ticker) {
+                eprintln!("{}", hint);
+            }
         }
-        "1y" => {
-            // Last 365 days
-            today - chrono::Duration::days(365)
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Table => print_trade_detail_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_trade_detail_csv(&rows)?,
+        OutputFormat::Markdown => print_trade_detail_markdown(&rows),
+        OutputFormat::Xml => print_trade_detail_xml(&rows),
+    }
+
+    let mut sells: Vec<i64> = rows.iter().map(|r| r.sell_tx_id).collect();
+    sells.dedup();
+    eprintln!(
+        "{} lot legs across {} sells ({:.2} shares, {:.2} realized)",
+        rows.len(),
+        sells.len(),
+        rows.iter().map(|r| r.shares).sum::<f64>(),
+        rows.iter().map(|r| r.realized_gain).sum::<f64>()
+    );
+    Ok(())
+}
+
+/// `analytics index`: the daily trading index for one chamber or both.
+fn run_index(args: &IndexArgs, format: &OutputFormat) -> Result<()> {
+    let chamber = match args.chamber {
+        Some(ref val) => Some(validation::validate_chamber(val)?),
+        None => None,
+    };
+    let since = match args.since {
+        Some(ref val) => {
+            let today = Local::now().naive_local().date();
+            Some(
+                validation::parse_relative_date(val, today)
+                    .map_err(|e| anyhow::anyhow!("Invalid --since value: {}", e))?,
+            )
         }
-        "2y" => {
-            // Last 730 days
-            today - chrono::Duration::days(730)
+        None => None,
+    };
+    let params = TradingIndexParams {
+        window_days: args.window,
+        ema_span: (args.ema_span > 0).then_some(args.ema_span),
+    };
+
+    let db = Db::open_read_only(&args.db)?;
+    let points = db.query_trading_index(chamber, since, &params)?;
+    if points.is_empty() {
+        eprintln!("No buys or sells match the filters.");
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Table => print_trading_index_table(&points),
+        OutputFormat::Json => print_json(&points),
+        OutputFormat::Csv => print_trading_index_csv(&points)?,
+        OutputFormat::Markdown => print_trading_index_markdown(&points),
+        OutputFormat::Xml => print_trading_index_xml(&points),
+    }
+    let scope = chamber.map_or_else(|| "house and senate".to_string(), |c| c.to_string());
+    eprintln!(
+        "\n{} days ({} to {}), {}; {}-day window",
+        points.len(),
+        points[0].date,
+        points[points.len() - 1].date,
+        scope,
+        args.window
+    );
+    Ok(())
+}
+
+/// `analytics overlap`: the most alike politician pairs by traded issuers.
+fn run_overlap(args: &OverlapArgs, format: &OutputFormat) -> Result<()> {
+    if args.min_shared == 0 {
+        bail!("--min-shared must be at least 1");
+    }
+    let since = match args.since {
+        Some(ref val) => {
+            let today = Local::now().naive_local().date();
+            Some(
+                validation::parse_relative_date(val, today)
+                    .map_err(|e| anyhow::anyhow!("Invalid --since value: {}", e))?,
+            )
         }
-        "all" => {
-            // No filter
-            return Ok(trades.to_vec());
+        None => None,
+    };
+    let opts = OverlapOptions {
+        min_shared: args.min_shared,
+        min_trades: args.min_trades,
+    };
+
+    let db = Db::open_read_only(&args.db)?;
+    let mut pairs = db.query_politician_overlap(since, &opts)?;
+    if pairs.is_empty() {
+        eprintln!(
+            "No politician pairs share {} or more issuers (politicians under {} trades skipped).",
+            args.min_shared, args.min_trades
+        );
+        return Ok(());
+    }
+    let total = pairs.len();
+    pairs.truncate(args.top);
+
+    match format {
+        OutputFormat::Table => print_overlap_table(&pairs),
+        OutputFormat::Json => print_json(&pairs),
+        OutputFormat::Csv => print_overlap_csv(&pairs)?,
+        OutputFormat::Markdown => print_overlap_markdown(&pairs),
+        OutputFormat::Xml => print_overlap_xml(&pairs),
+    }
+    eprintln!(
+        "\n{} of {} pairs sharing {}+ issuers",
+        pairs.len(),
+        total,
+        args.min_shared
+    );
+    Ok(())
+}
+
+/// `analytics labels`: one label's volume and realized P&L, or every label
+/// with its trade count under `--list`.
+fn run_labels(args: &LabelsArgs, format: &OutputFormat) -> Result<()> {
+    let db = Db::open_read_only(&args.db)?;
+
+    let since = match args.since {
+        Some(ref val) => {
+            let today = Local::now().naive_local().date();
+            Some(
+                validation::parse_relative_date(val, today)
+                    .map_err(|e| anyhow::anyhow!("Invalid --since value: {}", e))?
+                    .format("%Y-%m-%d")
+                    .to_string(),
+            )
+        }
+        None => None,
+    };
+    let filter = DbTradeFilter {
+        party: match args.party {
+            Some(ref val) => Some(validation::validate_party(val.trim())?.to_string()),
+            None => None,
+        },
+        state: match args.state {
+            Some(ref val) => Some(validation::validate_state(val.trim())?.to_string()),
+            None => None,
+        },
+        politician_id: match args.politician_id {
+            Some(ref val) => Some(validation::validate_politician_id(val)?),
+            None => None,
+        },
+        since,
+        ..Default::default()
+    };
+
+    if args.list {
+        let counts = db.label_counts(&filter)?;
+        if counts.is_empty() {
+            eprintln!("No labeled trades found.");
+            return Ok(());
+        }
+        match format {
+            OutputFormat::Table => print_label_counts_table(&counts),
+            OutputFormat::Json => print_json(&counts),
+            OutputFormat::Csv => print_label_counts_csv(&counts)?,
+            OutputFormat::Markdown => print_label_counts_markdown(&counts),
+            OutputFormat::Xml => print_label_counts_xml(&counts),
         }
-        _ => unreachable!("period validated earlier"),
+        eprintln!("\n{} labels", counts.len());
+        return Ok(());
+    }
+
+    let Some(ref label) = args.label else {
+        bail!("--label or --list is required");
+    };
+    // Any stored label is accepted, not just the API's four, so derived
+    // labels can be reported too; --list shows what exists.
+    let label = label.trim();
+    if label.is_empty() {
+        bail!("--label cannot be empty");
+    }
+
+    let mut report = db.query_label_performance(label, &filter)?;
+    if report.trades == 0 {
+        eprintln!("No trades labeled '{}' match the filters.", label);
+        return Ok(());
+    }
+    report.by_politician.truncate(args.top);
+
+    match format {
+        OutputFormat::Table => print_label_report_table(&report),
+        OutputFormat::Json => print_json(&report),
+        OutputFormat::Csv => print_label_report_csv(&report)?,
+        OutputFormat::Markdown => print_label_report_markdown(&report),
+        OutputFormat::Xml => print_label_report_xml(&report),
+    }
+
+    eprintln!(
+        "\n'{}': {} trades by {} politicians, ${} volume ({} buys ${}, {} sells ${}); realized P&L ${:.0} over {} closed legs",
+        report.label,
+        report.trades,
+        report.politicians,
+        report.volume,
+        report.buys,
+        report.buy_volume,
+        report.sells,
+        report.sell_volume,
+        report.realized_pnl,
+        report.closed_legs
+    );
+    eprintln!("Note: {}.", report.pnl_basis);
+    eprintln!(
+        "Showing {}/{} politicians by labeled volume\n",
+        report.by_politician.len(),
+        report.politicians
+    );
+    Ok(())
+}
+
+/// Warn (or fail with --strict) when too few trades carry a trade-date price.
+fn check_price_coverage(db: &Db, db_path: &Path, args: &AnalyticsArgs) -> Result<()> {
+    let coverage = db.price_coverage()?;
+    if coverage.eligible_trades == 0 {
+        return Ok(());
+    }
+
+    let pct = coverage.trade_date_price_pct();
+    if pct >= args.min_price_coverage {
+        return Ok(());
+    }
+
+    let message = format!(
+        "Only {:.1}% of stock trades ({}/{}) have trade-date prices (threshold: {:.1}%); results will be incomplete",
+        pct,
+        coverage.with_trade_date_price,
+        coverage.eligible_trades,
+        args.min_price_coverage
+    );
+    let hint = format!(
+        "Hint: Run 'capitoltraders enrich-prices --db {}' to fill in missing prices.",
+        db_path.display()
+    );
+
+    if args.strict {
+        bail!("{}\n{}", message, hint);
+    }
+    eprintln!("Warning: {}", message);
+    eprintln!("{}", hint);
+    Ok(())
+}
+
+/// Warn about listed politicians whose own trade history is too sparsely
+/// priced for their metrics to be representative.
+fn warn_low_politician_coverage(
+    db: &Db,
+    politicians: &[PoliticianMetrics],
+    threshold: f64,
+) -> Result<()> {
+    let coverage: HashMap<String, CoverageRow> = db
+        .coverage_report(CoverageScope::Politician)?
+        .into_iter()
+        .map(|row| (row.id.clone(), row))
+        .collect();
+    for pm in politicians {
+        let Some(row) = coverage.get(&pm.politician_id) else {
+            continue;
+        };
+        if row.price_enriched_pct < threshold {
+            eprintln!(
+                "Warning: only {:.1}% of {}'s {} trades have trade-date prices (threshold: {:.1}%)",
+                row.price_enriched_pct, row.name, row.total_trades, threshold
+            );
+        }
+    }
+    Ok(())
+}
+
+/// `--party`, `--state` and `--politician-id`, validated.
+struct PoliticianFilters {
+    party: Option<String>,
+    state: Option<String>,
+    ids: Option<Vec<String>>,
+}
+
+impl PoliticianFilters {
+    /// Politicians without metadata only pass when no party or state filter is set.
+    fn matches(&self, politician_id: &str, metadata: &HashMap<String, PoliticianMetadata>) -> bool {
+        let meta = metadata.get(politician_id);
+        if let Some(ref party) = self.party {
+            if meta.is_none_or(|m| &m.party != party) {
+                return false;
+            }
+        }
+        if let Some(ref state) = self.state {
+            if meta.is_none_or(|m| &m.state != state) {
+                return false;
+            }
+        }
+        self.ids
+            .as_ref()
+            .is_none_or(|ids| ids.iter().any(|id| id == politician_id))
+    }
+}
+
+/// Priced trades tagged with their session status, from `cutoff` on.
+fn load_session_rows(
+    universe: &Universe,
+    calendar: &SessionCalendar,
+    cutoff: Option<NaiveDate>,
+) -> Vec<AnalyticsTradeRow> {
+    let mut rows = universe.analytics_rows();
+    if let Some(cutoff) = cutoff {
+        let cutoff = cutoff.format("%Y-%m-%d").to_string();
+        rows.retain(|row| row.tx_date >= cutoff);
+    }
+    tag_session_status(&mut rows, calendar);
+    rows
+}
+
+/// `--by-session`: volume and closed-trade returns per chamber and session
+/// status. Closed legs are matched over the full history, then kept when
+/// both the sell and the buy fall in the period.
+fn print_session_groups(
+    db: &Db,
+    universe: &Universe,
+    calendar: &SessionCalendar,
+    filters: &PoliticianFilters,
+    period_cutoff: Option<NaiveDate>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let metadata = load_politician_metadata(db)?;
+    let mut rows = load_session_rows(universe, calendar, period_cutoff);
+    rows.retain(|row| filters.matches(&row.politician_id, &metadata));
+    if rows.is_empty() {
+        eprintln!("No priced stock trades match the given filters.");
+        return Ok(());
+    }
+
+    let closed = filter_closed_trades_by_period(
+        &calculate_closed_trades(universe.analytics_trades(), false),
+        period_cutoff,
+    );
+    let groups = group_by_session(&rows, &closed);
+
+    match format {
+        OutputFormat::Table => print_session_groups_table(&groups),
+        OutputFormat::Json => print_json(&groups),
+        OutputFormat::Csv => print_session_groups_csv(&groups)?,
+        OutputFormat::Markdown => print_session_groups_markdown(&groups),
+        OutputFormat::Xml => print_session_groups_xml(&groups),
+    }
+
+    let unknown = rows
+        .iter()
+        .filter(|row| row.session_status == SessionStatus::Unknown)
+        .count();
+    if unknown > 0 {
+        let coverage: Vec<String> = ["house", "senate"]
+            .iter()
+            .filter_map(|chamber| {
+                calendar
+                    .coverage(chamber)
+                    .map(|(start, end)| format!("{} {} to {}", chamber, start, end))
+            })
+            .collect();
+        eprintln!(
+            "{} of {} trades fall outside the session calendar ({}; covers {}) and are grouped as unknown.",
+            unknown,
+            rows.len(),
+            calendar.version(),
+            coverage.join(", ")
+        );
+        eprintln!("Hint: Add session and recess ranges with session_calendar_file in the config file.");
+    }
+    Ok(())
+}
+
+/// Filter closed trades by time period based on sell_date.
+///
+/// `cutoff` is the resolved `--period` start; `None` (period `all`) keeps every trade.
+fn filter_closed_trades_by_period(
+    trades: &[capitoltraders_lib::analytics::ClosedTrade],
+    cutoff: Option<NaiveDate>,
+) -> Vec<capitoltraders_lib::analytics::ClosedTrade> {
+    let Some(cutoff_date) = cutoff else {
+        return trades.to_vec();
     };
 
     // Filter by sell_date >= cutoff_date
-    let filtered: Vec<_> = trades
+    trades
         .iter()
         .filter(|ct| {
             // Parse sell_date as NaiveDate
@@ -301,9 +1224,7 @@ fn filter_closed_trades_by_period(
             }
         })
         .cloned()
-        .collect();
-
-    Ok(filtered)
+        .collect()
 }
 
 /// Load politician metadata (id, name, party, state) into a HashMap.
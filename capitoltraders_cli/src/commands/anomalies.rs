@@ -1,24 +1,35 @@
 //! The `anomalies` subcommand: detect unusual trading patterns.
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use capitoltraders_lib::{
+    analysis::{trades_near_events, EventProximityRow},
     anomaly::{
-        calculate_composite_anomaly_score, calculate_sector_concentration, detect_pre_move_trades,
-        detect_unusual_volume, PortfolioPositionForHHI, TradeVolumeRecord, TradeWithFuturePrice,
+        calculate_composite_anomaly_score, calculate_sector_concentration, detect_crowded_trades,
+        detect_novel_issuer_trades, detect_pre_move_trades, detect_prescient_sells, detect_unusual_volume_universe,
+        future_price_candidates, AnomalyConfig, MAX_WINDOW_DAYS, NoveltyConfidence, PortfolioPositionForHHI, RealizedCheck,
+        SellTrade, SellsWithSeries, TradeDirection, VolumeParams,
     },
-    Db,
+    load_events, validation, Db, DbTradeFilter, UniverseProvenance,
 };
-use chrono::Local;
+use chrono::{Days, Local, NaiveDate};
 use clap::Args;
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::hints;
 use crate::output::{
     print_anomaly_csv, print_anomaly_markdown, print_anomaly_table, print_anomaly_xml,
-    print_json, print_pre_move_csv, print_pre_move_markdown, print_pre_move_table,
-    print_pre_move_xml, OutputFormat,
+    print_crowded_trade_csv, print_crowded_trade_markdown, print_crowded_trade_table,
+    print_crowded_trade_xml,
+    print_event_proximity_csv, print_event_proximity_markdown, print_event_proximity_table,
+    print_event_proximity_xml, print_json, print_novel_trade_csv, print_novel_trade_markdown,
+    print_novel_trade_table, print_novel_trade_xml, print_pre_move_csv, print_pre_move_markdown, print_pre_move_table,
+    print_pre_move_xml, print_sell_signal_csv, print_sell_signal_markdown, print_sell_signal_table,
+    print_sell_signal_xml, OutputFormat,
 };
+use crate::universe_args::UniverseArgs;
 
 /// Arguments for the `anomalies` subcommand.
 ///
@@ -29,10 +40,18 @@ pub struct AnomaliesArgs {
     #[arg(long)]
     pub db: PathBuf,
 
+    /// Threshold config file (TOML, or JSON by .json extension); omitted fields keep defaults
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
     /// Filter by politician name (partial match)
     #[arg(long)]
     pub politician: Option<String>,
 
+    /// Filter by politician ID (P000197 format); takes precedence over --politician
+    #[arg(long)]
+    pub politician_id: Option<String>,
+
     /// Minimum composite anomaly score (0.0-1.0, default: 0.0)
     #[arg(long, default_value = "0.0")]
     pub min_score: f64,
@@ -45,6 +64,14 @@ pub struct AnomaliesArgs {
     #[arg(long)]
     pub show_pre_move: bool,
 
+    /// Count only pre-move buys the politician later sold at a gain (FIFO) within the horizon
+    #[arg(long)]
+    pub realized_only: bool,
+
+    /// Days after a buy within which a sale counts as realizing the move (default: 365)
+    #[arg(long)]
+    pub realized_horizon_days: Option<i64>,
+
     /// Number of results to show (default: 25)
     #[arg(long, default_value = "25")]
     pub top: usize,
@@ -52,10 +79,105 @@ pub struct AnomaliesArgs {
     /// Sort by metric: score, volume, hhi, pre-move (default: score)
     #[arg(long, default_value = "score")]
     pub sort_by: String,
+
+    /// Events calendar CSV (date,title,tags,ticker,sector); lists trades near each event instead of scores
+    #[arg(long)]
+    pub events: Option<PathBuf>,
+
+    /// Days before/after each event with --events, or window length with --crowded, 0-3650 (default: 14)
+    #[arg(long, value_parser = clap::value_parser!(u32).range(0..=MAX_WINDOW_DAYS))]
+    pub window: Option<u32>,
+
+    /// List issuers many politicians traded in the same window instead of scores
+    #[arg(long)]
+    pub crowded: bool,
+
+    /// Minimum distinct politicians in one window, with --crowded (default: 5)
+    #[arg(long)]
+    pub min_politicians: Option<usize>,
+
+    /// Trade direction for --crowded: buy (crowded entries) or sell (crowded exits)
+    #[arg(long, default_value = "buy")]
+    pub direction: String,
+
+    /// List sell-discipline metrics per sale, flagging prescient sells, instead of scores
+    #[arg(long)]
+    pub sells: bool,
+
+    /// Days before a sale searched for the trailing peak, 1-3650, with --sells (default: 90)
+    #[arg(long, value_parser = clap::value_parser!(i64).range(1..=MAX_WINDOW_DAYS))]
+    pub peak_lookback_days: Option<i64>,
+
+    /// Days after a sale searched for the drawdown, 1-3650, with --sells (default: 30)
+    #[arg(long, value_parser = clap::value_parser!(i64).range(1..=MAX_WINDOW_DAYS))]
+    pub drawdown_days: Option<i64>,
+
+    /// Max % below the trailing peak for a sale to count as near the top (default: 5)
+    #[arg(long)]
+    pub near_peak_pct: Option<f64>,
+
+    /// Min % decline after the sale to flag it as prescient (default: 10)
+    #[arg(long)]
+    pub decline_pct: Option<f64>,
+
+    /// List trades in issuers no member had traded before, instead of scores
+    #[arg(long)]
+    pub novel: bool,
+
+    /// Earliest trade date for --novel (YYYY-MM-DD or relative: 90d, 6m, 1y)
+    #[arg(long, default_value = "90d")]
+    pub since: String,
+
+    /// With --novel, also list issuers traded again after a long gap
+    #[arg(long)]
+    pub include_returning: bool,
+
+    /// Years without a trade before an issuer counts as returning (default: 3)
+    #[arg(long)]
+    pub returning_gap_years: Option<u32>,
+
+    #[command(flatten)]
+    pub universe: UniverseArgs,
+}
+
+/// Thresholds for this run: the `--config` file (or the defaults) with any
+/// explicit flags applied on top.
+fn resolve_config(args: &AnomaliesArgs) -> Result<AnomalyConfig> {
+    let mut config = match args.config {
+        Some(ref path) => AnomalyConfig::load(path)
+            .map_err(|e| anyhow!("{}: {}", path.display(), e))?,
+        None => AnomalyConfig::default(),
+    };
+    if let Some(days) = args.realized_horizon_days {
+        config.pre_move.realized_horizon_days = days;
+    }
+    if let Some(window) = args.window {
+        config.events.window_days = window;
+        config.crowded.window_days = window as i64;
+    }
+    if let Some(n) = args.min_politicians {
+        config.crowded.min_politicians = n;
+    }
+    if let Some(days) = args.peak_lookback_days {
+        config.sells.peak_lookback_days = days;
+    }
+    if let Some(days) = args.drawdown_days {
+        config.sells.drawdown_days = days;
+    }
+    if let Some(pct) = args.near_peak_pct {
+        config.sells.near_peak_pct = pct;
+    }
+    if let Some(pct) = args.decline_pct {
+        config.sells.decline_pct = pct;
+    }
+    if let Some(years) = args.returning_gap_years {
+        config.novel.returning_gap_years = years;
+    }
+    Ok(config)
 }
 
 /// Anomaly row for output (composite scores per politician).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct AnomalyRow {
     pub rank: usize,
     pub politician_name: String,
@@ -66,8 +188,16 @@ pub struct AnomalyRow {
     pub confidence: f64,
 }
 
+/// Composite-score JSON output: the scored politicians and the trades their
+/// pre-move signals were drawn from.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AnomalyReport {
+    pub universe: UniverseProvenance,
+    pub politicians: Vec<AnomalyRow>,
+}
+
 /// Pre-move signal row for detailed output.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct PreMoveRow {
     pub politician_name: String,
     pub ticker: String,
@@ -76,6 +206,62 @@ pub struct PreMoveRow {
     pub trade_price: f64,
     pub price_30d_later: f64,
     pub price_change_pct: f64,
+    pub realized_fraction: Option<f64>,
+    pub realized_return_pct: Option<f64>,
+}
+
+/// Crowded trade row for `--crowded` output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CrowdedTradeRow {
+    pub issuer_name: String,
+    pub ticker: String,
+    pub direction: String,
+    pub window_start: String,
+    pub window_end: String,
+    pub politician_count: usize,
+    pub politicians: Vec<String>,
+    pub trade_count: usize,
+    pub total_value: i64,
+    pub democrats: usize,
+    pub republicans: usize,
+    pub other_party: usize,
+}
+
+/// Novel issuer trade row for `--novel` output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct NovelTradeRow {
+    pub politician_name: String,
+    pub issuer_name: String,
+    pub ticker: String,
+    pub sector: Option<String>,
+    pub mcap: Option<i64>,
+    pub tx_type: String,
+    pub tx_date: String,
+    pub value: i64,
+    /// `first_trade` or `returning`.
+    pub kind: String,
+    pub previous_trade_date: Option<String>,
+    /// `high`, `unverified` (no scraped issuer stats) or `low` (scraped
+    /// stats show earlier trades missing locally).
+    pub confidence: String,
+    pub local_trade_count: i64,
+    pub scraped_trade_count: Option<i64>,
+    pub scraped_date_last_traded: Option<String>,
+}
+
+/// Sell-discipline row for `--sells` output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SellSignalRow {
+    pub politician_name: String,
+    pub ticker: String,
+    pub tx_date: String,
+    pub sell_price: f64,
+    pub trailing_peak: f64,
+    pub peak_date: String,
+    pub below_peak_pct: f64,
+    pub forward_low: f64,
+    pub drawdown_after_pct: f64,
+    pub prescient: bool,
 }
 
 pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
@@ -105,13 +291,22 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
         );
     }
 
+    let config = resolve_config(args)?;
     let db = Db::open(&args.db)?;
 
-    // Optional politician filter
-    let politician_filter = if let Some(ref name) = args.politician {
+    // Optional politician filter: an exact ID wins over a name lookup
+    let politician_filter = if let Some(ref id) = args.politician_id {
+        if args.politician.is_some() {
+            eprintln!("Warning: --politician-id given; ignoring --politician");
+        }
+        Some(validation::validate_politician_id(id)?)
+    } else if let Some(ref name) = args.politician {
         let matches = db.find_politician_by_name(name)?;
         if matches.is_empty() {
             eprintln!("No politician found matching name: '{}'", name);
+            if let Some(hint) = hints::politician_hint(&db, name) {
+                eprintln!("{}", hint);
+            }
             return Ok(());
         }
         if matches.len() > 1 {
@@ -126,35 +321,69 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
         None
     };
 
-    // Query all three data sources
-    let pre_move_candidates = db.query_pre_move_candidates()?;
-    let volume_records = db.query_trade_volume_by_politician()?;
+    if let Some(ref path) = args.events {
+        return run_events(
+            &db,
+            path,
+            config.events.window_days,
+            politician_filter.as_deref(),
+            format,
+        );
+    }
+    if args.sells {
+        return run_sells(&db, args, &config, politician_filter.as_deref(), format);
+    }
+    if args.crowded {
+        return run_crowded(&db, args, &config, politician_filter.as_deref(), format);
+    }
+    if args.novel {
+        return run_novel(&db, args, &config, politician_filter.as_deref(), format);
+    }
+
+    // Query all three data sources. Pre-move and volume read the same universe.
+    let today = Local::now().naive_local().date();
+    let universe = args.universe.load(&db)?;
+    let names_by_id: HashMap<&str, &str> = universe
+        .trades
+        .iter()
+        .map(|t| (t.politician_id.as_str(), t.politician_name.as_str()))
+        .collect();
+    let trades_with_future = future_price_candidates(&universe.trades, today);
+    let volume_scan = detect_unusual_volume_universe(
+        &universe.trades,
+        &VolumeParams::from_config(today, &config.volume),
+    );
     let hhi_positions = db.query_portfolio_positions_for_hhi()?;
 
     // Check for empty data
-    if pre_move_candidates.is_empty() && volume_records.is_empty() && hhi_positions.is_empty() {
+    if trades_with_future.is_empty() && volume_scan.is_empty() && hhi_positions.is_empty() {
         eprintln!("No data available for anomaly detection.");
         eprintln!("Hint: Run 'enrich-prices' to enable pre-move detection.");
         return Ok(());
     }
 
-    // Convert DB rows to anomaly input types and run detection
+    // Run detection
 
     // 1. Pre-move detection
-    let trades_with_future: Vec<TradeWithFuturePrice> = pre_move_candidates
-        .iter()
-        .map(|row| TradeWithFuturePrice {
-            tx_id: row.tx_id,
-            politician_id: row.politician_id.clone(),
-            ticker: row.ticker.clone(),
-            tx_date: row.tx_date.clone(),
-            tx_type: row.tx_type.clone(),
-            trade_price: row.trade_price,
-            price_30d_later: row.price_30d_later,
-        })
-        .collect();
+    // Realized-gain tagging needs FIFO lots, so only build them when shown or filtered on.
+    let realized_check = if args.realized_only || args.show_pre_move {
+        if config.pre_move.realized_horizon_days <= 0 {
+            bail!("--realized-horizon-days must be positive");
+        }
+        Some(RealizedCheck::from_trades(
+            universe.analytics_trades(),
+            config.pre_move.realized_horizon_days,
+            args.realized_only,
+        ))
+    } else {
+        None
+    };
 
-    let pre_move_signals = detect_pre_move_trades(&trades_with_future, 10.0);
+    let pre_move_signals = detect_pre_move_trades(
+        &trades_with_future,
+        config.pre_move.threshold_pct,
+        realized_check.as_ref(),
+    );
 
     // Build pre_move count per politician
     let mut pre_move_counts: HashMap<String, usize> = HashMap::new();
@@ -163,25 +392,10 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
     }
 
     // 2. Volume detection
-    let mut volume_signals: HashMap<String, f64> = HashMap::new();
-    let today = Local::now().naive_local().date();
-
-    // Group volume records by politician_id
-    let mut volume_by_politician: HashMap<String, Vec<TradeVolumeRecord>> = HashMap::new();
-    for row in volume_records {
-        volume_by_politician
-            .entry(row.politician_id.clone())
-            .or_default()
-            .push(TradeVolumeRecord {
-                politician_id: row.politician_id.clone(),
-                tx_date: row.tx_date.clone(),
-            });
-    }
-
-    for (politician_id, records) in volume_by_politician {
-        let signal = detect_unusual_volume(&records, &politician_id, today, 90, 365);
-        volume_signals.insert(politician_id, signal.volume_ratio);
-    }
+    let volume_signals: HashMap<String, f64> = volume_scan
+        .iter()
+        .map(|v| (v.politician_id.clone(), v.signal.volume_ratio))
+        .collect();
 
     // 3. HHI sector concentration
     let mut hhi_scores: HashMap<String, f64> = HashMap::new();
@@ -201,17 +415,19 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
     }
 
     for (politician_id, positions) in positions_by_politician {
-        let concentration = calculate_sector_concentration(&positions);
+        let concentration = calculate_sector_concentration(&positions, &config.concentration);
         hhi_scores.insert(politician_id, concentration.hhi_score);
     }
 
     // Collect unique politician IDs and names
     let mut politician_names: HashMap<String, String> = HashMap::new();
 
-    for row in &pre_move_candidates {
-        politician_names.insert(row.politician_id.clone(), row.politician_name.clone());
+    for trade in &trades_with_future {
+        if let Some(name) = names_by_id.get(trade.politician_id.as_str()) {
+            politician_names.insert(trade.politician_id.clone(), name.to_string());
+        }
     }
-    for row in db.query_trade_volume_by_politician()? {
+    for row in &volume_scan {
         politician_names.insert(row.politician_id.clone(), row.politician_name.clone());
     }
     for row in db.query_portfolio_positions_for_hhi()? {
@@ -233,8 +449,12 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
         let volume_ratio = volume_signals.get(politician_id).copied().unwrap_or(0.0);
         let hhi_score = hhi_scores.get(politician_id).copied().unwrap_or(0.0);
 
-        let composite =
-            calculate_composite_anomaly_score(pre_move_count, volume_ratio, hhi_score);
+        let composite = calculate_composite_anomaly_score(
+            pre_move_count,
+            volume_ratio,
+            hhi_score,
+            &config.composite,
+        );
 
         // Apply filters
         if composite.composite < args.min_score {
@@ -272,7 +492,7 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
                 .partial_cmp(&a.hhi_score)
                 .unwrap_or(std::cmp::Ordering::Equal)
         }),
-        "pre-move" => anomaly_rows.sort_by(|a, b| b.pre_move_count.cmp(&a.pre_move_count)),
+        "pre-move" => anomaly_rows.sort_by_key(|r| std::cmp::Reverse(r.pre_move_count)),
         _ => {} // already validated
     }
 
@@ -289,8 +509,11 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
     // Output anomaly scores
     match format {
         OutputFormat::Table => print_anomaly_table(&anomaly_rows),
-        OutputFormat::Json => print_json(&anomaly_rows),
-        OutputFormat::Csv => print_anomaly_csv(&anomaly_rows)?,
+        OutputFormat::Json => print_json(&AnomalyReport {
+            universe: universe.provenance.clone(),
+            politicians: anomaly_rows.clone(),
+        }),
+        OutputFormat::Csv => print_anomaly_csv(&anomaly_rows, &universe.provenance)?,
         OutputFormat::Markdown => print_anomaly_markdown(&anomaly_rows),
         OutputFormat::Xml => print_anomaly_xml(&anomaly_rows),
     }
@@ -303,6 +526,7 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
         args.min_score,
         args.min_confidence
     );
+    eprintln!("Trade universe: {}", universe.provenance);
 
     // If --show-pre-move, output detailed pre-move signals
     if args.show_pre_move {
@@ -314,25 +538,21 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
             .map(|r| r.politician_name.clone())
             .collect();
 
-        // Build politician_id -> name mapping
-        let id_to_name: HashMap<String, String> = pre_move_candidates
-            .iter()
-            .map(|row| (row.politician_id.clone(), row.politician_name.clone()))
-            .collect();
-
         let pre_move_rows: Vec<PreMoveRow> = pre_move_signals
             .iter()
             .filter_map(|signal| {
-                let name = id_to_name.get(&signal.politician_id)?;
-                if shown_politician_names.contains(name) {
+                let name = names_by_id.get(signal.politician_id.as_str())?;
+                if shown_politician_names.contains(*name) {
                     Some(PreMoveRow {
-                        politician_name: name.clone(),
+                        politician_name: name.to_string(),
                         ticker: signal.ticker.clone(),
                         tx_date: signal.tx_date.clone(),
                         tx_type: signal.tx_type.clone(),
                         trade_price: signal.trade_price,
                         price_30d_later: signal.price_30d_later,
                         price_change_pct: signal.price_change_pct,
+                        realized_fraction: signal.realized_fraction,
+                        realized_return_pct: signal.realized_return_pct,
                     })
                 } else {
                     None
@@ -351,3 +571,283 @@ pub fn run(args: &AnomaliesArgs, format: &OutputFormat) -> Result<()> {
 
     Ok(())
 }
+
+/// `--crowded` mode: issuers that at least `--min-politicians` members traded
+/// in the same direction within `--window` days. With `--politician`, only
+/// crowds that member took part in are listed.
+fn run_crowded(
+    db: &Db,
+    args: &AnomaliesArgs,
+    config: &AnomalyConfig,
+    politician_id: Option<&str>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let direction: TradeDirection = args.direction.parse().map_err(|e: String| anyhow!(e))?;
+    let window = config.crowded.window_days;
+    let min_politicians = config.crowded.min_politicians;
+    if window == 0 {
+        bail!("--window must be positive");
+    }
+    if min_politicians < 2 {
+        bail!("--min-politicians must be at least 2");
+    }
+
+    let trades = db.query_crowding_trades(direction)?;
+    let mut signals = detect_crowded_trades(&trades, window, min_politicians, direction);
+    if let Some(id) = politician_id {
+        // A crowd spans every trade of its issuer between its first and last
+        // date, so membership can be checked against the input trades.
+        signals.retain(|s| {
+            trades.iter().any(|t| {
+                t.politician_id == id
+                    && t.issuer_id == s.issuer_id
+                    && t.tx_date.as_str() >= s.window_start.as_str()
+                    && t.tx_date.as_str() <= s.window_end.as_str()
+            })
+        });
+    }
+    let total = signals.len();
+
+    let rows: Vec<CrowdedTradeRow> = signals
+        .into_iter()
+        .take(args.top)
+        .map(|s| CrowdedTradeRow {
+            politician_count: s.politician_count(),
+            issuer_name: s.issuer_name,
+            ticker: s.ticker,
+            direction: s.direction.as_str().to_string(),
+            window_start: s.window_start,
+            window_end: s.window_end,
+            politicians: s.politicians,
+            trade_count: s.trade_count,
+            total_value: s.total_value,
+            democrats: s.democrats,
+            republicans: s.republicans,
+            other_party: s.other_party,
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Table => print_crowded_trade_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_crowded_trade_csv(&rows)?,
+        OutputFormat::Markdown => print_crowded_trade_markdown(&rows),
+        OutputFormat::Xml => print_crowded_trade_xml(&rows),
+    }
+
+    eprintln!(
+        "\n{} crowded {} windows ({}+ politicians within {} days)",
+        total,
+        direction.as_str(),
+        min_politicians,
+        window
+    );
+    Ok(())
+}
+
+/// `--novel` mode: trades since `--since` in issuers with no earlier local
+/// trade, checked against the scraped issuer stats. With `--politician`, only
+/// that member's trades are listed.
+fn run_novel(
+    db: &Db,
+    args: &AnomaliesArgs,
+    config: &AnomalyConfig,
+    politician_id: Option<&str>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let since = validation::validate_since_date(&args.since)?;
+    let gap_years = config.novel.returning_gap_years;
+    let returning = args.include_returning.then_some(gap_years);
+
+    let mut signals = detect_novel_issuer_trades(db, since, returning)?;
+    if let Some(id) = politician_id {
+        signals.retain(|s| s.trade.politician_id == id);
+    }
+    let total = signals.len();
+    let low = signals
+        .iter()
+        .filter(|s| s.confidence == NoveltyConfidence::Low)
+        .count();
+
+    let rows: Vec<NovelTradeRow> = signals
+        .into_iter()
+        .take(args.top)
+        .map(|s| NovelTradeRow {
+            kind: s.kind.as_str().to_string(),
+            confidence: s.confidence.as_str().to_string(),
+            politician_name: s.trade.politician_name,
+            issuer_name: s.trade.issuer_name,
+            ticker: s.trade.ticker,
+            sector: s.trade.sector,
+            mcap: s.trade.mcap,
+            tx_type: s.trade.tx_type,
+            tx_date: s.trade.tx_date,
+            value: s.trade.value,
+            previous_trade_date: s.trade.previous_trade_date,
+            local_trade_count: s.trade.local_trade_count,
+            scraped_trade_count: s.trade.scraped_trade_count,
+            scraped_date_last_traded: s.trade.scraped_date_last_traded,
+        })
+        .collect();
+
+    match format {
+        OutputFormat::Table => print_novel_trade_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_novel_trade_csv(&rows)?,
+        OutputFormat::Markdown => print_novel_trade_markdown(&rows),
+        OutputFormat::Xml => print_novel_trade_xml(&rows),
+    }
+
+    eprintln!(
+        "\n{} novel issuer trades since {} ({} low confidence)",
+        total,
+        since.format("%Y-%m-%d"),
+        low
+    );
+    if args.include_returning {
+        eprintln!("Returning issuers: no trade for more than {} years", gap_years);
+    }
+    Ok(())
+}
+
+/// `--sells` mode: trailing-peak and forward-drawdown metrics for each
+/// price-enriched sale, prescient sells first.
+fn run_sells(
+    db: &Db,
+    args: &AnomaliesArgs,
+    config: &AnomalyConfig,
+    politician_id: Option<&str>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let params = config.sells;
+    if params.peak_lookback_days <= 0 || params.drawdown_days <= 0 {
+        bail!("--peak-lookback-days and --drawdown-days must be positive");
+    }
+    if params.near_peak_pct < 0.0 || params.decline_pct < 0.0 {
+        bail!("--near-peak-pct and --decline-pct must not be negative");
+    }
+
+    let mut candidates = db.query_sell_candidates()?;
+    if let Some(id) = politician_id {
+        candidates.retain(|c| c.politician_id == id);
+    }
+    if candidates.is_empty() {
+        eprintln!("No price-enriched sell trades found.");
+        eprintln!("Hint: Run 'enrich-prices' to populate trade-date prices.");
+        return Ok(());
+    }
+
+    let mut input = SellsWithSeries::default();
+    let mut names: HashMap<String, String> = HashMap::new();
+    for c in candidates {
+        if let std::collections::hash_map::Entry::Vacant(slot) = input.series.entry(c.issuer_id) {
+            slot.insert(db.issuer_eod_series(c.issuer_id)?);
+        }
+        names.insert(c.politician_id.clone(), c.politician_name);
+        input.sells.push(SellTrade {
+            tx_id: c.tx_id,
+            politician_id: c.politician_id,
+            issuer_id: c.issuer_id,
+            ticker: c.ticker,
+            tx_date: c.tx_date,
+            sell_price: c.sell_price,
+        });
+    }
+
+    let scan = detect_prescient_sells(&input, &params);
+    let mut rows: Vec<SellSignalRow> = scan
+        .signals
+        .iter()
+        .map(|s| SellSignalRow {
+            politician_name: names.get(&s.politician_id).cloned().unwrap_or_default(),
+            ticker: s.ticker.clone(),
+            tx_date: s.tx_date.clone(),
+            sell_price: s.sell_price,
+            trailing_peak: s.trailing_peak,
+            peak_date: s.peak_date.clone(),
+            below_peak_pct: s.below_peak_pct,
+            forward_low: s.forward_low,
+            drawdown_after_pct: s.drawdown_after_pct,
+            prescient: s.is_prescient,
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        b.prescient.cmp(&a.prescient).then(
+            b.drawdown_after_pct
+                .partial_cmp(&a.drawdown_after_pct)
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+    rows.truncate(args.top);
+
+    match format {
+        OutputFormat::Table => print_sell_signal_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_sell_signal_csv(&rows)?,
+        OutputFormat::Markdown => print_sell_signal_markdown(&rows),
+        OutputFormat::Xml => print_sell_signal_xml(&rows),
+    }
+
+    eprintln!(
+        "\n{} of {} evaluated sells were prescient (within {:.1}% of the {}-day peak, then down >{:.1}% within {} days)",
+        scan.prescient_count(),
+        scan.signals.len(),
+        params.near_peak_pct,
+        params.peak_lookback_days,
+        params.decline_pct,
+        params.drawdown_days
+    );
+    if scan.skipped_insufficient_history > 0 {
+        eprintln!(
+            "Skipped {} sells with insufficient price history",
+            scan.skipped_insufficient_history
+        );
+    }
+    Ok(())
+}
+
+/// `--events` mode: list trades within `window` days of each calendar event.
+fn run_events(
+    db: &Db,
+    path: &std::path::Path,
+    window: u32,
+    politician_id: Option<&str>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let events = load_events(path)?;
+    let Some(first) = events.first() else {
+        eprintln!("No events found in {}", path.display());
+        return Ok(());
+    };
+
+    // The since filter applies to pub_date, which never precedes tx_date, so
+    // this only drops trades that cannot fall inside any event's window.
+    let since = first
+        .date
+        .checked_sub_days(Days::new(u64::from(window)))
+        .unwrap_or(NaiveDate::MIN);
+    let filter = DbTradeFilter {
+        since: Some(since.format("%Y-%m-%d").to_string()),
+        politician_id: politician_id.map(str::to_string),
+        ..Default::default()
+    };
+    let trades = db.query_trades(&filter)?;
+
+    let rows: Vec<EventProximityRow> = trades_near_events(&trades, &events, window);
+
+    match format {
+        OutputFormat::Table => print_event_proximity_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_event_proximity_csv(&rows)?,
+        OutputFormat::Markdown => print_event_proximity_markdown(&rows),
+        OutputFormat::Xml => print_event_proximity_xml(&rows),
+    }
+
+    eprintln!(
+        "\n{} trade/event matches across {} events (window: +/-{} days)",
+        rows.len(),
+        events.len(),
+        window
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! The `config` subcommand: inspect the effective layered configuration.
+//!
+//! Values are resolved from defaults, the config file, environment variables
+//! and global flags (see `capitoltraders_lib::config`). API keys are always
+//! redacted.
+
+use anyhow::Result;
+use capitoltraders_lib::{AppConfig, ConfigEntry};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+use crate::output::{print_json, OutputFormat};
+
+/// Arguments for the `config` subcommand.
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub action: ConfigAction,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Print every effective setting and where it came from
+    Show,
+}
+
+/// JSON shape of `config show`.
+#[derive(Serialize)]
+struct ConfigReport<'a> {
+    file: Option<String>,
+    settings: &'a [ConfigEntry],
+}
+
+pub fn run(args: &ConfigArgs, config: &AppConfig, format: &OutputFormat) -> Result<()> {
+    match args.action {
+        ConfigAction::Show => show(config, format),
+    }
+    Ok(())
+}
+
+fn show(config: &AppConfig, format: &OutputFormat) {
+    let entries = config.entries();
+    if matches!(format, OutputFormat::Json) {
+        print_json(&ConfigReport {
+            file: config.file().map(|p| p.display().to_string()),
+            settings: &entries,
+        });
+        return;
+    }
+    print!("{}", render(config, &entries));
+}
+
+/// TOML-style listing with each value's source as a trailing comment.
+fn render(config: &AppConfig, entries: &[ConfigEntry]) -> String {
+    let mut out = match config.file() {
+        Some(path) => format!("# config file: {}\n", path.display()),
+        None => "# config file: none\n".to_string(),
+    };
+    let width = entries
+        .iter()
+        .map(|e| e.key.len() + e.value.len())
+        .max()
+        .unwrap_or(0);
+    for entry in entries {
+        let line = format!("{} = \"{}\"", entry.key, entry.value);
+        out.push_str(&format!(
+            "{:<pad$}  # {}\n",
+            line,
+            entry.source,
+            pad = width + 5
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_shows_sources_and_hides_keys() {
+        let mut config = AppConfig::defaults();
+        config.layer(AppConfig::from_env(|name| {
+            (name == "TIINGO_API_KEY").then(|| "tiingo-secret-value-9876".to_string())
+        }));
+        let text = render(&config, &config.entries());
+        assert!(text.starts_with("# config file: none\n"));
+        assert!(text.contains("tiingo.api_key = \"********9876\""));
+        assert!(text.contains("# env TIINGO_API_KEY"));
+        assert!(text.contains("output = \"table\""));
+        assert!(!text.contains("tiingo-secret-value"));
+    }
+}
@@ -3,18 +3,28 @@
 use anyhow::{bail, Result};
 use capitoltraders_lib::{
     analytics::calculate_closed_trades,
-    conflict::calculate_committee_trading_score,
-    committee_jurisdiction::load_committee_jurisdictions,
-    Db,
+    conflict::{
+        calculate_committee_trading_score_as_of, calculate_committee_trading_score_by_owner,
+        donation_trade_affinity, hearing_proximity_analysis, AffinityOptions,
+    },
+    committee_jurisdiction::{load_committee_jurisdictions_with, CommitteeJurisdiction},
+    load_hearings, AppConfig, CongressCommitteeResolver, Db, JurisdictionChange,
 };
-use clap::Args;
+use clap::{Args, Subcommand};
+use schemars::JsonSchema;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::hints;
 use crate::output::{
+    print_affinity_csv, print_affinity_markdown, print_affinity_table, print_affinity_xml,
     print_conflict_csv, print_conflict_markdown, print_conflict_table, print_conflict_xml,
     print_donation_correlation_csv, print_donation_correlation_markdown,
-    print_donation_correlation_table, print_donation_correlation_xml, print_json, OutputFormat,
+    print_donation_correlation_table, print_donation_correlation_xml, print_hearing_proximity_csv,
+    print_hearing_proximity_markdown, print_hearing_proximity_table, print_hearing_proximity_xml,
+    print_json, print_jurisdictions_csv, print_jurisdictions_markdown, print_jurisdictions_table,
+    print_jurisdictions_xml, print_spouse_conflict_csv, print_spouse_conflict_markdown,
+    print_spouse_conflict_table, print_spouse_conflict_xml, OutputFormat,
 };
 
 /// Arguments for the `conflicts` subcommand.
@@ -22,10 +32,11 @@ use crate::output::{
 /// Displays committee trading scores and donation-trade correlations from the local SQLite database.
 /// Requires a synced, FEC-synced, and employer-mapped database.
 #[derive(Args)]
+#[command(subcommand_negates_reqs = true)]
 pub struct ConflictsArgs {
     /// SQLite database path (required)
-    #[arg(long)]
-    pub db: PathBuf,
+    #[arg(long, required = true)]
+    pub db: Option<PathBuf>,
 
     /// Filter by politician name (partial match)
     #[arg(long)]
@@ -50,10 +61,97 @@ pub struct ConflictsArgs {
     /// Number of results to show (default: 25)
     #[arg(long, default_value = "25")]
     pub top: usize,
+
+    /// Committee jurisdiction overrides (TOML, or CSV with a .csv extension) merged over the bundled mapping
+    #[arg(long, global = true)]
+    pub jurisdiction_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub action: Option<ConflictsAction>,
+}
+
+#[derive(Subcommand)]
+pub enum ConflictsAction {
+    /// Trades by committee members in in-jurisdiction issuers ahead of their committee's hearings
+    Hearings(HearingsArgs),
+    /// Committee-jurisdiction trading split into own-account vs spouse/joint accounts
+    Spouse(SpouseArgs),
+    /// Rank members by overall donation-trade affinity (donor employers whose stock they trade)
+    Rank(RankArgs),
+    /// List the effective committee-to-sector mapping and where each sector came from
+    ShowJurisdictions(ShowJurisdictionsArgs),
+}
+
+/// Arguments for `conflicts hearings`.
+#[derive(Args)]
+pub struct HearingsArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Hearing schedule CSV (date,committee,title)
+    #[arg(long)]
+    pub file: PathBuf,
+
+    /// Days before each hearing to include, 0-3650 (default: 30)
+    #[arg(long, default_value = "30", value_parser = clap::value_parser!(u32).range(0..=3650))]
+    pub window: u32,
+
+    /// Filter by politician name (partial match)
+    #[arg(long)]
+    pub politician: Option<String>,
+}
+
+/// Arguments for `conflicts spouse`.
+#[derive(Args)]
+pub struct SpouseArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Filter by politician name (partial match)
+    #[arg(long)]
+    pub politician: Option<String>,
+
+    /// Only show politicians whose in-jurisdiction trading is all in spouse/joint accounts
+    #[arg(long)]
+    pub exclusive_only: bool,
+
+    /// Number of results to show (default: 25)
+    #[arg(long, default_value = "25")]
+    pub top: usize,
+}
+
+/// Arguments for `conflicts show-jurisdictions`.
+#[derive(Args)]
+pub struct ShowJurisdictionsArgs {
+    /// Only show this committee code (e.g. hsba)
+    #[arg(long)]
+    pub committee: Option<String>,
+}
+
+/// Arguments for `conflicts rank`.
+#[derive(Args)]
+pub struct RankArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Minimum employer mapping confidence for donations (0.0-1.0, default: 0.90)
+    #[arg(long, default_value = "0.90")]
+    pub min_confidence: f64,
+
+    /// Days between a donation and a same-ticker trade at which proximity weight reaches zero (default: 180)
+    #[arg(long, default_value = "180")]
+    pub proximity_days: i64,
+
+    /// Number of results to show (default: 50)
+    #[arg(long, default_value = "50")]
+    pub top: usize,
 }
 
 /// Conflict row for output (committee trading scores).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct ConflictRow {
     pub rank: usize,
     pub politician_name: String,
@@ -64,7 +162,7 @@ pub struct ConflictRow {
 }
 
 /// Donation correlation row for output.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, JsonSchema)]
 pub struct DonationCorrelationRow {
     pub politician_name: String,
     pub ticker: String,
@@ -73,7 +171,61 @@ pub struct DonationCorrelationRow {
     pub donor_employers: String,
 }
 
-pub fn run(args: &ConflictsArgs, format: &OutputFormat) -> Result<()> {
+/// Own-account vs spouse-account committee trading row for output.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct SpouseConflictRow {
+    pub rank: usize,
+    pub politician_name: String,
+    pub committees: String,
+    pub self_scored_trades: i64,
+    pub self_in_jurisdiction_trades: i64,
+    pub self_in_jurisdiction_volume: i64,
+    pub spouse_scored_trades: i64,
+    pub spouse_in_jurisdiction_trades: i64,
+    pub spouse_in_jurisdiction_volume: i64,
+    pub spouse_committee_pct: f64,
+    /// Spouse:self in-jurisdiction volume; `None` when own-account volume is zero.
+    pub spouse_to_self_ratio: Option<f64>,
+    pub spouse_exclusive: bool,
+}
+
+/// Donation-trade affinity row for output (`conflicts rank`).
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct AffinityRow {
+    pub rank: usize,
+    pub politician_name: String,
+    pub score: f64,
+    pub matched_donations: usize,
+    pub matched_tickers: usize,
+    pub matched_donation_total: f64,
+    /// Matched share of all donation dollars, in percent.
+    pub donation_share_pct: f64,
+    pub matched_trade_volume: i64,
+    /// Matched share of all trade volume, in percent.
+    pub trade_share_pct: f64,
+    pub avg_confidence: f64,
+    pub proximity: f64,
+}
+
+pub fn run(args: &ConflictsArgs, config: &AppConfig, format: &OutputFormat) -> Result<()> {
+    let jurisdiction_file = args.jurisdiction_file.as_deref();
+    match args.action {
+        Some(ConflictsAction::Hearings(ref hearing_args)) => {
+            return run_hearings(hearing_args, jurisdiction_file, config, format)
+        }
+        Some(ConflictsAction::Spouse(ref spouse_args)) => {
+            return run_spouse(spouse_args, jurisdiction_file, config, format)
+        }
+        Some(ConflictsAction::Rank(ref rank_args)) => return run_rank(rank_args, format),
+        Some(ConflictsAction::ShowJurisdictions(ref show_args)) => {
+            return run_show_jurisdictions(show_args, jurisdiction_file, config, format)
+        }
+        None => {}
+    }
+    let Some(ref db_path) = args.db else {
+        bail!("--db is required");
+    };
+
     // Validate min_committee_pct range
     if args.min_committee_pct < 0.0 || args.min_committee_pct > 100.0 {
         bail!(
@@ -90,10 +242,13 @@ pub fn run(args: &ConflictsArgs, format: &OutputFormat) -> Result<()> {
         );
     }
 
-    let db = Db::open(&args.db)?;
+    let db = Db::open(db_path)?;
 
-    // Load committee jurisdictions
-    let committee_jurisdictions = load_committee_jurisdictions()?;
+    // Load committee jurisdictions; trades are scored against the committee
+    // structure in effect when they were made
+    let committee_resolver = CongressCommitteeResolver::load(config.committees_file.as_deref())?;
+    let committee_jurisdictions =
+        load_jurisdictions(args.jurisdiction_file.as_deref(), &committee_resolver)?;
 
     // Query all enriched trades
     let trade_rows = db.query_trades_for_analytics()?;
@@ -127,7 +282,13 @@ pub fn run(args: &ConflictsArgs, format: &OutputFormat) -> Result<()> {
     let politician_filter_id = if let Some(ref name) = args.politician {
         let matches = db.find_politician_by_name(name)?;
         if matches.is_empty() {
-            bail!("No politician found matching name: '{}'", name);
+            bail!(
+                "{}",
+                hints::with_hint(
+                    format!("No politician found matching name: '{}'", name),
+                    hints::politician_hint(&db, name)
+                )
+            );
         }
         if matches.len() > 1 {
             eprintln!(
@@ -166,10 +327,11 @@ pub fn run(args: &ConflictsArgs, format: &OutputFormat) -> Result<()> {
             .collect();
 
         // Calculate score
-        let score = calculate_committee_trading_score(
+        let score = calculate_committee_trading_score_as_of(
             &politician_trades,
             &committees,
             &committee_jurisdictions,
+            &committee_resolver,
             politician_id.clone(),
             politician_name.clone(),
         );
@@ -263,3 +425,312 @@ pub fn run(args: &ConflictsArgs, format: &OutputFormat) -> Result<()> {
 
     Ok(())
 }
+
+/// `conflicts hearings`: trades ahead of the trader's own committee hearings.
+fn run_hearings(
+    args: &HearingsArgs,
+    jurisdiction_file: Option<&Path>,
+    config: &AppConfig,
+    format: &OutputFormat,
+) -> Result<()> {
+    let hearings = load_hearings(&args.file)?;
+    if hearings.is_empty() {
+        eprintln!("No hearings found in {}", args.file.display());
+        return Ok(());
+    }
+
+    let db = Db::open(&args.db)?;
+    let committee_resolver = CongressCommitteeResolver::load(config.committees_file.as_deref())?;
+    let committee_jurisdictions = load_jurisdictions(jurisdiction_file, &committee_resolver)?;
+
+    let politician_filter_id = match args.politician {
+        Some(ref name) => {
+            let matches = db.find_politician_by_name(name)?;
+            if matches.is_empty() {
+                bail!(
+                    "{}",
+                    hints::with_hint(
+                        format!("No politician found matching name: '{}'", name),
+                        hints::politician_hint(&db, name)
+                    )
+                );
+            }
+            if matches.len() > 1 {
+                eprintln!(
+                    "Warning: Multiple politicians match '{}'. Using first match: {}",
+                    name, matches[0].1
+                );
+            }
+            Some(matches[0].0.clone())
+        }
+        None => None,
+    };
+
+    let mut report = hearing_proximity_analysis(
+        &db,
+        &hearings,
+        &committee_jurisdictions,
+        &committee_resolver,
+        args.window,
+    )?;
+    if let Some(ref id) = politician_filter_id {
+        report.rows.retain(|r| &r.politician_id == id);
+    }
+
+    if !report.used_committee_history {
+        eprintln!(
+            "Warning: committee membership history not available; using current committee assignments, which may not match assignments at trade time."
+        );
+    }
+    for committee in &report.unmapped_committees {
+        eprintln!(
+            "Warning: no jurisdiction mapping for committee '{}'; its hearings were skipped",
+            committee
+        );
+    }
+
+    match format {
+        OutputFormat::Table => print_hearing_proximity_table(&report.rows),
+        OutputFormat::Json => print_json(&report.rows),
+        OutputFormat::Csv => print_hearing_proximity_csv(&report.rows)?,
+        OutputFormat::Markdown => print_hearing_proximity_markdown(&report.rows),
+        OutputFormat::Xml => print_hearing_proximity_xml(&report.rows),
+    }
+
+    eprintln!(
+        "\n{} trades within {} days before {} hearings",
+        report.rows.len(),
+        args.window,
+        hearings.len()
+    );
+    Ok(())
+}
+
+/// `conflicts spouse`: committee trading score split by owner account.
+fn run_spouse(
+    args: &SpouseArgs,
+    jurisdiction_file: Option<&Path>,
+    config: &AppConfig,
+    format: &OutputFormat,
+) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    let committee_resolver = CongressCommitteeResolver::load(config.committees_file.as_deref())?;
+    let committee_jurisdictions = load_jurisdictions(jurisdiction_file, &committee_resolver)?;
+
+    let politician_ids: Vec<String> = match args.politician {
+        Some(ref name) => {
+            let matches = db.find_politician_by_name(name)?;
+            if matches.is_empty() {
+                bail!(
+                    "{}",
+                    hints::with_hint(
+                        format!("No politician found matching name: '{}'", name),
+                        hints::politician_hint(&db, name)
+                    )
+                );
+            }
+            if matches.len() > 1 {
+                eprintln!(
+                    "Warning: Multiple politicians match '{}'. Using first match: {}",
+                    name, matches[0].1
+                );
+            }
+            vec![matches[0].0.clone()]
+        }
+        None => db
+            .get_all_politicians_with_committees()?
+            .into_iter()
+            .map(|(id, _, _)| id)
+            .collect(),
+    };
+
+    let mut scores = Vec::new();
+    for politician_id in &politician_ids {
+        let Some(score) =
+            calculate_committee_trading_score_by_owner(&db, politician_id, &committee_jurisdictions)?
+        else {
+            continue;
+        };
+        // Without a politician filter, only list members with spouse/joint
+        // trading in their committees' sectors.
+        if args.politician.is_none() && score.spouse.in_jurisdiction_trades == 0 {
+            continue;
+        }
+        if args.exclusive_only && !score.spouse_exclusive {
+            continue;
+        }
+        scores.push(score);
+    }
+
+    scores.sort_by(|a, b| {
+        b.spouse
+            .in_jurisdiction_volume
+            .cmp(&a.spouse.in_jurisdiction_volume)
+            .then_with(|| a.politician_name.cmp(&b.politician_name))
+    });
+    scores.truncate(args.top);
+
+    let rows: Vec<SpouseConflictRow> = scores
+        .iter()
+        .enumerate()
+        .map(|(idx, score)| SpouseConflictRow {
+            rank: idx + 1,
+            politician_name: score.politician_name.clone(),
+            committees: score.committee_names.join(", "),
+            self_scored_trades: score.self_owned.scored_trades,
+            self_in_jurisdiction_trades: score.self_owned.in_jurisdiction_trades,
+            self_in_jurisdiction_volume: score.self_owned.in_jurisdiction_volume,
+            spouse_scored_trades: score.spouse.scored_trades,
+            spouse_in_jurisdiction_trades: score.spouse.in_jurisdiction_trades,
+            spouse_in_jurisdiction_volume: score.spouse.in_jurisdiction_volume,
+            spouse_committee_pct: score.spouse.committee_trading_pct(),
+            spouse_to_self_ratio: score.spouse_to_self_ratio,
+            spouse_exclusive: score.spouse_exclusive,
+        })
+        .collect();
+
+    eprintln!(
+        "\nNote: Spouse includes spouse and joint accounts. Based on current committee assignments. Trades with unknown sector excluded from scoring.\n"
+    );
+
+    match format {
+        OutputFormat::Table => print_spouse_conflict_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_spouse_conflict_csv(&rows)?,
+        OutputFormat::Markdown => print_spouse_conflict_markdown(&rows),
+        OutputFormat::Xml => print_spouse_conflict_xml(&rows),
+    }
+
+    eprintln!(
+        "\n{} politicians shown, {} with in-jurisdiction trading only in spouse/joint accounts\n",
+        rows.len(),
+        rows.iter().filter(|r| r.spouse_exclusive).count()
+    );
+    Ok(())
+}
+
+/// The bundled committee jurisdictions with `jurisdiction_file` merged in,
+/// summarizing the merge on stderr.
+fn load_jurisdictions(
+    jurisdiction_file: Option<&Path>,
+    resolver: &CongressCommitteeResolver,
+) -> Result<Vec<CommitteeJurisdiction>> {
+    let merged = load_committee_jurisdictions_with(jurisdiction_file, resolver)?;
+    if let Some(ref report) = merged.report {
+        report_jurisdiction_merge(report);
+    }
+    Ok(merged.jurisdictions)
+}
+
+fn report_jurisdiction_merge(report: &capitoltraders_lib::JurisdictionMergeReport) {
+    let (added, removed, new_committees, ignored) = report.counts();
+    eprintln!(
+        "Jurisdiction overrides from {}: {} sectors added, {} removed, {} new committees",
+        report.source, added, removed, new_committees
+    );
+    if ignored == 0 {
+        return;
+    }
+    for change in &report.changes {
+        if matches!(
+            change,
+            JurisdictionChange::AlreadyPresent { .. } | JurisdictionChange::NotPresent { .. }
+        ) {
+            eprintln!("  {}", change);
+        }
+    }
+}
+
+/// `conflicts show-jurisdictions`: the effective mapping with per-sector provenance.
+fn run_show_jurisdictions(
+    args: &ShowJurisdictionsArgs,
+    jurisdiction_file: Option<&Path>,
+    config: &AppConfig,
+    format: &OutputFormat,
+) -> Result<()> {
+    let resolver = CongressCommitteeResolver::load(config.committees_file.as_deref())?;
+    let merged = load_committee_jurisdictions_with(jurisdiction_file, &resolver)?;
+    if let Some(ref report) = merged.report {
+        report_jurisdiction_merge(report);
+    }
+
+    let mut rows = merged.rows();
+    if let Some(ref code) = args.committee {
+        let code = code.trim().to_ascii_lowercase();
+        rows.retain(|r| r.committee == code);
+        if rows.is_empty() {
+            bail!("No jurisdiction mapping for committee '{}'", code);
+        }
+    }
+
+    match format {
+        OutputFormat::Table => print_jurisdictions_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_jurisdictions_csv(&rows)?,
+        OutputFormat::Markdown => print_jurisdictions_markdown(&rows),
+        OutputFormat::Xml => print_jurisdictions_xml(&rows),
+    }
+    Ok(())
+}
+
+/// `conflicts rank`: one affinity score per member, highest first.
+fn run_rank(args: &RankArgs, format: &OutputFormat) -> Result<()> {
+    if args.min_confidence < 0.0 || args.min_confidence > 1.0 {
+        bail!(
+            "Invalid --min-confidence value: '{}'. Must be between 0.0 and 1.0",
+            args.min_confidence
+        );
+    }
+    if args.proximity_days < 1 {
+        bail!("--proximity-days must be at least 1");
+    }
+
+    let db = Db::open(&args.db)?;
+    let opts = AffinityOptions {
+        min_confidence: args.min_confidence,
+        proximity_days: args.proximity_days,
+    };
+    let report = donation_trade_affinity(&db, &opts)?;
+    let scored = report.scores.len();
+
+    let rows: Vec<AffinityRow> = report
+        .scores
+        .iter()
+        .take(args.top)
+        .enumerate()
+        .map(|(idx, s)| AffinityRow {
+            rank: idx + 1,
+            politician_name: s.politician_name.clone(),
+            score: s.score,
+            matched_donations: s.matched_donation_count,
+            matched_tickers: s.matched_tickers,
+            matched_donation_total: s.matched_donation_total,
+            donation_share_pct: s.donation_share * 100.0,
+            matched_trade_volume: s.matched_trade_volume,
+            trade_share_pct: s.trade_share * 100.0,
+            avg_confidence: s.avg_confidence,
+            proximity: s.proximity,
+        })
+        .collect();
+
+    eprintln!(
+        "\nNote: Score = 100 x sqrt(donation share x trade share) x confidence x (0.5 + 0.5 x proximity). Employer matches are inferred and do not establish a conflict.\n"
+    );
+
+    match format {
+        OutputFormat::Table => print_affinity_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_affinity_csv(&rows)?,
+        OutputFormat::Markdown => print_affinity_markdown(&rows),
+        OutputFormat::Xml => print_affinity_xml(&rows),
+    }
+
+    eprintln!(
+        "\nShowing {}/{} scored members (confidence >= {:.0}%); {} trading members have no synced donations and were not scored\n",
+        rows.len(),
+        scored,
+        args.min_confidence * 100.0,
+        report.unscored_no_donations
+    );
+    Ok(())
+}
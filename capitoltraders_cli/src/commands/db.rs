@@ -0,0 +1,799 @@
+//! The `db` subcommand: inspect what the local database actually holds.
+//!
+//! `db coverage` reports, per politician or issuer, how much of the trade
+//! history carries detail and price enrichment, so analytics for a given
+//! member can be weighed against how complete their data is.
+//!
+//! `db repair` runs data-quality fixes in place. Each operation is a flag
+//! (`--fix-chamber`, ...) so several can run in one invocation, and
+//! `--dry-run` lists the rows that would change.
+//!
+//! `db prune-price-cache` drops closes `enrich-prices` downloaded before a
+//! cutoff, keeping the on-disk price cache bounded.
+//!
+//! `db check` looks for trades and positions whose parent rows are missing;
+//! `--repair` inserts unenriched placeholder parents so enrichment can fill
+//! them in, without touching the child rows.
+//!
+//! `db export` streams the raw rows of a core table (see [`Db::iter_table`])
+//! for ETL, one JSON object per line or as CSV. The summary on stderr names
+//! the last rowid written so the next run can pass it to `--after-rowid`.
+//! A trade's or issuer's rowid is its upstream ID, so resuming by rowid
+//! skips rows synced later with lower IDs and rows updated in place.
+//! For scheduled trade exports, `--state-file` records the highest tx_id
+//! written and `--append` adds only newer trades to the `--out` file
+//! (without repeating the CSV header); `--include-revisions` also re-emits
+//! trades edited upstream since the last run, tagged with a `revision`.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use capitoltraders_lib::{
+    validation, CoreTable, CoverageScope, Db, DbTradeFilter, FromRow, IntegrityReport,
+    RawDonation, RawFecMapping, RawIssuer, RawPolitician, RawPosition, RawTrade, RepairStats,
+};
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+
+use crate::output::{
+    print_coverage_csv, print_coverage_markdown, print_coverage_table, print_coverage_xml,
+    print_json, OutputFormat,
+};
+
+/// Arguments for the `db` subcommand.
+#[derive(Args)]
+pub struct DbArgs {
+    #[command(subcommand)]
+    pub action: DbAction,
+}
+
+#[derive(Subcommand)]
+pub enum DbAction {
+    /// Per-politician or per-issuer enrichment coverage
+    Coverage(CoverageArgs),
+    /// Repair inconsistent stored data
+    Repair(RepairArgs),
+    /// Delete cached daily closes downloaded before a cutoff
+    PrunePriceCache(PrunePriceCacheArgs),
+    /// Stream a core table's raw rows as JSON lines or CSV (for ETL)
+    Export(ExportArgs),
+    /// Find trades and positions whose politician, issuer or asset row is missing
+    Check(CheckArgs),
+}
+
+#[derive(Args)]
+pub struct CoverageArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Group by: politician or issuer (default: politician)
+    #[arg(long, default_value = "politician")]
+    pub by: String,
+
+    /// Only list entities with at least this many trades (default: 1)
+    #[arg(long, default_value = "1")]
+    pub min_trades: i64,
+
+    /// Maximum rows to show (default: all)
+    #[arg(long)]
+    pub top: Option<usize>,
+}
+
+#[derive(Args)]
+#[command(group(clap::ArgGroup::new("operation").required(true).multiple(true)))]
+pub struct RepairArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Rewrite blank or inconsistent trade chambers from the politician's chamber
+    #[arg(long, group = "operation")]
+    pub fix_chamber: bool,
+
+    /// Recompute donation election cycles from their receipt dates
+    #[arg(long, group = "operation")]
+    pub fix_cycles: bool,
+
+    /// List affected rows without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct PrunePriceCacheArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Remove closes downloaded before this: YYYY-MM-DD or relative (90d, 6m, 2y)
+    #[arg(long)]
+    pub older_than: String,
+}
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Insert unenriched placeholder rows for missing parents
+    #[arg(long)]
+    pub repair: bool,
+
+    /// With --repair, list the placeholders without inserting them
+    #[arg(long, requires = "repair")]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Table: trades, politicians, issuers, donations, positions or fec_mappings
+    #[arg(long)]
+    pub table: String,
+
+    /// Only rows after this rowid (the last one a previous export wrote).
+    /// Rowids follow upstream IDs, not insertion order: rows synced later
+    /// with a lower ID, and rows updated in place, are not exported again
+    #[arg(long, default_value = "0")]
+    pub after_rowid: i64,
+
+    /// Write to this file instead of stdout
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+
+    /// Append only trades past the --state-file watermark to --out, without
+    /// repeating the CSV header
+    #[arg(long, requires_all = ["out", "state_file"], conflicts_with = "after_rowid")]
+    pub append: bool,
+
+    /// JSON file recording the highest tx_id and pub_date a trades export
+    /// wrote; read by --append and rewritten after every run
+    #[arg(long)]
+    pub state_file: Option<PathBuf>,
+
+    /// With --append, re-emit trades revised since the last run, adding a
+    /// `revision` column (the latest trade_revisions ID; empty for new rows)
+    #[arg(long, requires = "append")]
+    pub include_revisions: bool,
+}
+
+pub fn run(args: &DbArgs, format: &OutputFormat) -> Result<()> {
+    match &args.action {
+        DbAction::Coverage(args) => coverage(args, format),
+        DbAction::Repair(args) => repair(args, format),
+        DbAction::PrunePriceCache(args) => prune_price_cache(args, format),
+        DbAction::Export(args) => export(args, format),
+        DbAction::Check(args) => check(args, format),
+    }
+}
+
+fn check(args: &CheckArgs, format: &OutputFormat) -> Result<()> {
+    let db = if args.repair {
+        let db = Db::open(&args.db)?;
+        db.init()?;
+        db
+    } else {
+        Db::open_read_only(&args.db)?
+    };
+    let report = db.check_referential_integrity()?;
+    let repair = match (args.repair, report.is_clean()) {
+        (true, false) if args.dry_run => Some(db.preview_orphaned_parent_repair()?),
+        (true, false) => Some(db.repair_orphaned_parents()?),
+        _ => None,
+    };
+    let remaining = match repair {
+        Some(ref stats) if stats.applied => db.check_referential_integrity()?,
+        _ => report.clone(),
+    };
+
+    if matches!(format, OutputFormat::Json) {
+        print_json(&serde_json::json!({
+            "report": report,
+            "repair": repair,
+            "remaining": remaining,
+        }));
+    } else {
+        print_integrity_report(&report);
+        if let Some(ref stats) = repair {
+            let verb = if stats.applied { "inserted" } else { "would insert" };
+            eprintln!("{}: {} {} placeholder rows", stats.operation, verb, stats.total());
+            for (reason, count) in &stats.by_reason {
+                eprintln!("  {:<12} {}", reason, count);
+            }
+            if !stats.applied {
+                for id in &stats.affected_ids {
+                    println!("{}", id);
+                }
+                eprintln!("Dry run: no changes written.");
+            }
+        }
+    }
+
+    if !remaining.is_clean() && !args.dry_run {
+        let hint = if remaining.orphans.iter().any(|o| o.repairable) {
+            "; run `db check --repair` to insert placeholder parents"
+        } else {
+            ""
+        };
+        bail!(
+            "{} orphaned rows remain{}",
+            remaining.orphaned_rows(),
+            hint
+        );
+    }
+    Ok(())
+}
+
+/// Summarize an integrity report on stderr.
+pub(crate) fn print_integrity_report(report: &IntegrityReport) {
+    if report.is_clean() {
+        eprintln!("Referential integrity: ok");
+        return;
+    }
+    eprintln!(
+        "Referential integrity: {} orphaned rows",
+        report.orphaned_rows()
+    );
+    for orphan in &report.orphans {
+        let shown: Vec<&str> = orphan.missing_keys.iter().take(5).map(String::as_str).collect();
+        let more = orphan.missing_keys.len().saturating_sub(shown.len());
+        eprintln!(
+            "  {} -> {}: {} rows, missing {}{}{}",
+            orphan.reference,
+            orphan.parent,
+            orphan.rows,
+            shown.join(", "),
+            if more > 0 { format!(" (+{} more)", more) } else { String::new() },
+            if orphan.repairable { "" } else { " (not repairable; resync)" }
+        );
+    }
+    for violation in &report.foreign_key_violations {
+        eprintln!(
+            "  foreign_key_check: {} -> {}: {} rows",
+            violation.table, violation.parent, violation.rows
+        );
+    }
+}
+
+fn export(args: &ExportArgs, format: &OutputFormat) -> Result<()> {
+    let table: CoreTable = args.table.parse().map_err(|e: String| anyhow!(e))?;
+    if !matches!(format, OutputFormat::Json | OutputFormat::Csv) {
+        bail!("db export supports --output json (one object per line) and csv");
+    }
+
+    let db = Db::open_read_only(&args.db)?;
+    if let Some(state_path) = &args.state_file {
+        if table != CoreTable::Trades {
+            bail!("--state-file only supports --table trades");
+        }
+        return export_trades_incremental(&db, args, state_path, format);
+    }
+
+    let mut out: Box<dyn Write> = match &args.out {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).with_context(|| format!("creating {}", path.display()))?,
+        )),
+        None => Box::new(BufWriter::new(std::io::stdout().lock())),
+    };
+    let after = args.after_rowid;
+    let (written, last_rowid) = match table {
+        CoreTable::Trades => export_rows::<RawTrade>(&db, table, after, format, &mut out)?,
+        CoreTable::Politicians => export_rows::<RawPolitician>(&db, table, after, format, &mut out)?,
+        CoreTable::Issuers => export_rows::<RawIssuer>(&db, table, after, format, &mut out)?,
+        CoreTable::Donations => export_rows::<RawDonation>(&db, table, after, format, &mut out)?,
+        CoreTable::Positions => export_rows::<RawPosition>(&db, table, after, format, &mut out)?,
+        CoreTable::FecMappings => export_rows::<RawFecMapping>(&db, table, after, format, &mut out)?,
+    };
+    out.flush()?;
+
+    match last_rowid {
+        Some(rowid) => eprintln!(
+            "Exported {} {} rows; resume with --after-rowid {}",
+            written,
+            table.as_str(),
+            rowid
+        ),
+        None => eprintln!("No {} rows after rowid {}", table.as_str(), args.after_rowid),
+    }
+    Ok(())
+}
+
+/// Write every row after `after` to `out`. Returns the row count and the
+/// last rowid written.
+fn export_rows<T: FromRow + Serialize>(
+    db: &Db,
+    table: CoreTable,
+    after: i64,
+    format: &OutputFormat,
+    out: &mut dyn Write,
+) -> Result<(usize, Option<i64>)> {
+    let rows = db.iter_table_from::<T>(table, after);
+    let mut written = 0;
+    let mut last_rowid = None;
+    if matches!(format, OutputFormat::Csv) {
+        let mut wtr = csv::Writer::from_writer(out);
+        for row in rows {
+            let row = row?;
+            wtr.serialize(&row)?;
+            written += 1;
+            last_rowid = Some(row.rowid());
+        }
+        wtr.flush()?;
+    } else {
+        for row in rows {
+            let row = row?;
+            serde_json::to_writer(&mut *out, &row)?;
+            out.write_all(b"\n")?;
+            written += 1;
+            last_rowid = Some(row.rowid());
+        }
+    }
+    Ok((written, last_rowid))
+}
+
+/// Trades fetched per [`Db::query_trades_after`] call.
+const EXPORT_PAGE: i64 = 1000;
+
+/// Where a `db export --state-file` run left off.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct ExportState {
+    /// `csv` or `json`; appending in the other format would corrupt the file.
+    format: String,
+    /// Highest tx_id written; `--append` resumes after it.
+    last_tx_id: i64,
+    /// Latest pub_date among the trades written; `--append` also picks up
+    /// trades at or below `last_tx_id` published after it.
+    last_pub_date: Option<String>,
+    /// Highest trade_revisions ID at the last run.
+    last_revision_id: i64,
+    /// Whether rows carry the `revision` column.
+    include_revisions: bool,
+}
+
+impl ExportState {
+    /// The saved state, or `None` when the file does not exist yet.
+    fn load(path: &Path) -> Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map(Some)
+                .with_context(|| format!("parsing export state {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("reading export state {}", path.display())),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("writing export state {}", path.display()))
+    }
+
+    fn advance_pub_date(&mut self, pub_date: &str) {
+        if self.last_pub_date.as_deref().is_none_or(|d| pub_date > d) {
+            self.last_pub_date = Some(pub_date.to_string());
+        }
+    }
+}
+
+/// The `revision` column `--include-revisions` adds to CSV rows. The csv
+/// crate cannot serialize `#[serde(flatten)]`, so rows go out as a
+/// `(trade, column)` tuple instead of a [`RevisedTrade`].
+#[derive(Serialize)]
+struct RevisionColumn {
+    revision: Option<i64>,
+}
+
+/// A trade with its `revision` field, for JSON lines.
+#[derive(Serialize)]
+struct RevisedTrade<'a> {
+    #[serde(flatten)]
+    trade: &'a RawTrade,
+    revision: Option<i64>,
+}
+
+/// Serializes trades as CSV or JSON lines, with or without the `revision`
+/// column.
+struct TradeSink<W: Write> {
+    csv: Option<csv::Writer<W>>,
+    json: Option<W>,
+    include_revisions: bool,
+}
+
+impl<W: Write> TradeSink<W> {
+    fn new(out: W, format: &OutputFormat, header: bool, include_revisions: bool) -> Self {
+        if matches!(format, OutputFormat::Csv) {
+            let csv = csv::WriterBuilder::new().has_headers(header).from_writer(out);
+            Self { csv: Some(csv), json: None, include_revisions }
+        } else {
+            Self { csv: None, json: Some(out), include_revisions }
+        }
+    }
+
+    fn write(&mut self, trade: &RawTrade, revision: Option<i64>) -> Result<()> {
+        if let Some(wtr) = &mut self.csv {
+            if self.include_revisions {
+                wtr.serialize((trade, RevisionColumn { revision }))?;
+            } else {
+                wtr.serialize(trade)?;
+            }
+        } else if let Some(out) = &mut self.json {
+            if self.include_revisions {
+                serde_json::to_writer(&mut *out, &RevisedTrade { trade, revision })?;
+            } else {
+                serde_json::to_writer(&mut *out, trade)?;
+            }
+            out.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        if let Some(mut wtr) = self.csv {
+            wtr.flush()?;
+        }
+        if let Some(mut out) = self.json {
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// `db export --table trades --state-file`: write trades past the saved
+/// watermark (all of them without `--append`), then advance it.
+fn export_trades_incremental(
+    db: &Db,
+    args: &ExportArgs,
+    state_path: &Path,
+    format: &OutputFormat,
+) -> Result<()> {
+    let format_name = if matches!(format, OutputFormat::Csv) { "csv" } else { "json" };
+    let previous = if args.append { ExportState::load(state_path)? } else { None };
+    if let Some(prev) = &previous {
+        if prev.format != format_name {
+            bail!(
+                "{} was written by a --output {} export; cannot append {}",
+                state_path.display(),
+                prev.format,
+                format_name
+            );
+        }
+        if prev.include_revisions != args.include_revisions {
+            bail!(
+                "{} was written {} --include-revisions; pass the same flag to append",
+                state_path.display(),
+                if prev.include_revisions { "with" } else { "without" }
+            );
+        }
+    }
+    let resuming = previous.is_some();
+    let mut state = previous.unwrap_or_else(|| ExportState {
+        format: format_name.to_string(),
+        include_revisions: args.include_revisions,
+        ..Default::default()
+    });
+    // Read before the trades so an edit landing mid-export is re-emitted
+    // next run rather than missed.
+    let revision_mark = db.max_trade_revision_id()?;
+
+    let (out, header): (Box<dyn Write>, bool) = match &args.out {
+        Some(path) => {
+            let file = if args.append {
+                OpenOptions::new().create(true).append(true).open(path)
+            } else {
+                File::create(path)
+            }
+            .with_context(|| format!("opening {}", path.display()))?;
+            let empty = file.metadata()?.len() == 0;
+            (Box::new(BufWriter::new(file)), empty)
+        }
+        None => (Box::new(BufWriter::new(std::io::stdout().lock())), true),
+    };
+    let mut sink = TradeSink::new(out, format, header, args.include_revisions);
+
+    let mut revised = 0;
+    if args.include_revisions && resuming {
+        let rows = db.query_trades_revised_after(
+            state.last_revision_id,
+            state.last_tx_id,
+            &DbTradeFilter::default(),
+        )?;
+        for (revision_id, trade) in &rows {
+            sink.write(trade, Some(*revision_id))?;
+        }
+        revised = rows.len();
+    }
+
+    // Trades that arrived below the tx_id watermark. Every row written so
+    // far was published on or before last_pub_date, so none repeat; a late
+    // trade published before it is still missed.
+    let late = match state.last_pub_date.as_deref() {
+        Some(pub_date) if resuming => db.query_trades_published_after(
+            pub_date,
+            state.last_tx_id,
+            &DbTradeFilter::default(),
+        )?,
+        _ => Vec::new(),
+    };
+    for trade in &late {
+        sink.write(trade, None)?;
+        state.advance_pub_date(&trade.pub_date);
+    }
+
+    let page_filter = DbTradeFilter { limit: Some(EXPORT_PAGE), ..Default::default() };
+    let mut written = late.len();
+    loop {
+        let page = db.query_trades_after(state.last_tx_id, &page_filter)?;
+        let Some(last) = page.last() else { break };
+        state.last_tx_id = last.tx_id;
+        for trade in &page {
+            sink.write(trade, None)?;
+            state.advance_pub_date(&trade.pub_date);
+        }
+        written += page.len();
+    }
+    sink.finish()?;
+    state.last_revision_id = revision_mark;
+    state.save(state_path)?;
+
+    if args.include_revisions {
+        eprintln!(
+            "Exported {} new ({} late) and {} revised trades",
+            written,
+            late.len(),
+            revised
+        );
+    } else {
+        eprintln!("Exported {} trades ({} late)", written, late.len());
+    }
+    eprintln!(
+        "Watermark: tx_id {}, pub_date {} (saved to {})",
+        state.last_tx_id,
+        state.last_pub_date.as_deref().unwrap_or("-"),
+        state_path.display()
+    );
+    Ok(())
+}
+
+fn prune_price_cache(args: &PrunePriceCacheArgs, format: &OutputFormat) -> Result<()> {
+    let cutoff = validation::validate_since_date(&args.older_than)?;
+    let db = Db::open(&args.db)?;
+    db.init()?;
+    let removed = db.prune_price_cache(cutoff)?;
+
+    if matches!(format, OutputFormat::Json) {
+        print_json(&serde_json::json!({
+            "fetched_before": cutoff.format("%Y-%m-%d").to_string(),
+            "removed": removed,
+        }));
+    } else {
+        eprintln!(
+            "Removed {} cached closes downloaded before {}",
+            removed,
+            cutoff.format("%Y-%m-%d")
+        );
+    }
+    Ok(())
+}
+
+fn repair(args: &RepairArgs, format: &OutputFormat) -> Result<()> {
+    let db = if args.dry_run {
+        Db::open_read_only(&args.db)?
+    } else {
+        let db = Db::open(&args.db)?;
+        db.init()?;
+        db
+    };
+
+    let mut results: Vec<RepairStats> = Vec::new();
+    if args.fix_chamber {
+        results.push(if args.dry_run {
+            db.preview_trade_chamber_repair()?
+        } else {
+            db.repair_trade_chambers()?
+        });
+    }
+    if args.fix_cycles {
+        results.push(if args.dry_run {
+            db.preview_donation_cycle_repair()?
+        } else {
+            db.repair_donation_cycles()?
+        });
+    }
+
+    if matches!(format, OutputFormat::Json) {
+        print_json(&results);
+        return Ok(());
+    }
+
+    let verb = if args.dry_run { "would repair" } else { "repaired" };
+    for stats in &results {
+        eprintln!("{}: {} {} rows", stats.operation, verb, stats.total());
+        for (reason, count) in &stats.by_reason {
+            eprintln!("  {:<12} {}", reason, count);
+        }
+        if args.dry_run {
+            for id in &stats.affected_ids {
+                println!("{}", id);
+            }
+        }
+    }
+    if args.dry_run {
+        eprintln!("Dry run: no changes written.");
+    }
+    Ok(())
+}
+
+fn coverage(args: &CoverageArgs, format: &OutputFormat) -> Result<()> {
+    let scope: CoverageScope = args.by.parse().map_err(|e: String| anyhow!(e))?;
+    if args.min_trades < 1 {
+        bail!("--min-trades must be at least 1");
+    }
+
+    let db = Db::open(&args.db)?;
+    let mut rows = db.coverage_report(scope)?;
+    rows.retain(|r| r.total_trades >= args.min_trades);
+    if let Some(top) = args.top {
+        rows.truncate(top);
+    }
+
+    match format {
+        OutputFormat::Table => print_coverage_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_coverage_csv(&rows)?,
+        OutputFormat::Markdown => print_coverage_markdown(&rows),
+        OutputFormat::Xml => print_coverage_xml(&rows),
+    }
+
+    let noun = match scope {
+        CoverageScope::Politician => "politicians",
+        CoverageScope::Issuer => "issuers",
+    };
+    eprintln!("{} {} with {}+ trades", rows.len(), noun, args.min_trades);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capitoltraders_lib::fixtures::scraped_trade;
+
+    fn temp_path(name: &str, ext: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "capitoltraders-export-{}-{}-{}.{}",
+            name,
+            std::process::id(),
+            nanos,
+            ext
+        ))
+    }
+
+    fn seeded_db(tx_ids: &[i64]) -> PathBuf {
+        let path = temp_path("db", "db");
+        let mut db = Db::open(&path).unwrap();
+        db.init().unwrap();
+        let trades: Vec<_> = tx_ids.iter().map(|&id| scraped_trade(id, "P000001", 1)).collect();
+        db.upsert_scraped_trades(&trades).unwrap();
+        path
+    }
+
+    fn export_args(db: &Path, out: &Path, state: Option<&Path>, append: bool) -> ExportArgs {
+        ExportArgs {
+            db: db.to_path_buf(),
+            table: "trades".to_string(),
+            after_rowid: 0,
+            out: Some(out.to_path_buf()),
+            append,
+            state_file: state.map(Path::to_path_buf),
+            include_revisions: false,
+        }
+    }
+
+    #[test]
+    fn appended_exports_concatenate_to_a_full_export() {
+        for (format, ext) in [(OutputFormat::Csv, "csv"), (OutputFormat::Json, "ndjson")] {
+            let db_path = seeded_db(&[101, 102]);
+            let part = temp_path("part", ext);
+            let full = temp_path("full", ext);
+            let state = temp_path("state", "json");
+
+            export(&export_args(&db_path, &part, Some(&state), true), &format).unwrap();
+            let mut db = Db::open(&db_path).unwrap();
+            db.upsert_scraped_trades(&[scraped_trade(103, "P000002", 2)]).unwrap();
+            export(&export_args(&db_path, &part, Some(&state), true), &format).unwrap();
+            // A run with nothing new appends nothing.
+            export(&export_args(&db_path, &part, Some(&state), true), &format).unwrap();
+            export(&export_args(&db_path, &full, None, false), &format).unwrap();
+
+            let appended = std::fs::read_to_string(&part).unwrap();
+            assert_eq!(appended, std::fs::read_to_string(&full).unwrap(), "{}", ext);
+            assert_eq!(appended.lines().count(), if ext == "csv" { 4 } else { 3 });
+            let saved = ExportState::load(&state).unwrap().unwrap();
+            assert_eq!(saved.last_tx_id, 103);
+            assert_eq!(saved.last_pub_date.as_deref(), Some("2025-06-15T00:00:00Z"));
+            assert_eq!(saved.format, if ext == "csv" { "csv" } else { "json" });
+
+            // A trade arriving below the tx_id watermark but published after
+            // it is appended by the next run.
+            let mut late = scraped_trade(100, "P000002", 2);
+            late.pub_date = "2025-07-01T00:00:00Z".to_string();
+            db.upsert_scraped_trades(&[late]).unwrap();
+            export(&export_args(&db_path, &part, Some(&state), true), &format).unwrap();
+            export(&export_args(&db_path, &part, Some(&state), true), &format).unwrap();
+            let appended = std::fs::read_to_string(&part).unwrap();
+            let last = appended.lines().last().unwrap();
+            assert!(last.contains("2025-07-01T00:00:00Z"), "{}", last);
+            assert_eq!(appended.lines().count(), if ext == "csv" { 5 } else { 4 });
+            let saved = ExportState::load(&state).unwrap().unwrap();
+            assert_eq!(saved.last_tx_id, 103);
+            assert_eq!(saved.last_pub_date.as_deref(), Some("2025-07-01T00:00:00Z"));
+
+            // The state pins the format.
+            let other = if ext == "csv" { OutputFormat::Json } else { OutputFormat::Csv };
+            assert!(export(&export_args(&db_path, &part, Some(&state), true), &other).is_err());
+
+            for path in [&db_path, &part, &full, &state] {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    #[test]
+    fn include_revisions_re_emits_edited_trades_with_revision_column() {
+        let db_path = seeded_db(&[101, 102]);
+        let part = temp_path("part", "csv");
+        let state = temp_path("state", "json");
+        let args = ExportArgs {
+            include_revisions: true,
+            ..export_args(&db_path, &part, Some(&state), true)
+        };
+
+        export(&args, &OutputFormat::Csv).unwrap();
+        let mut db = Db::open(&db_path).unwrap();
+        let mut edited = scraped_trade(101, "P000001", 1);
+        edited.value = 75000;
+        db.upsert_scraped_trades(&[edited, scraped_trade(103, "P000001", 1)]).unwrap();
+        let revision_id = db.get_trade_revisions(101).unwrap()[0].revision_id;
+        export(&args, &OutputFormat::Csv).unwrap();
+
+        let mut rdr = csv::Reader::from_path(&part).unwrap();
+        let headers = rdr.headers().unwrap().clone();
+        assert_eq!(headers.iter().next_back(), Some("revision"));
+        let col = |name: &str| headers.iter().position(|h| h == name).unwrap();
+        let (tx_id, value, rev) = (col("tx_id"), col("value"), col("revision"));
+        let rows: Vec<(String, String, String)> = rdr
+            .records()
+            .map(|r| {
+                let r = r.unwrap();
+                (r[tx_id].to_string(), r[value].to_string(), r[rev].to_string())
+            })
+            .collect();
+        let revision = revision_id.to_string();
+        assert_eq!(
+            rows,
+            vec![
+                ("101".into(), "50000".into(), String::new()),
+                ("102".into(), "50000".into(), String::new()),
+                ("101".into(), "75000".into(), revision),
+                ("103".into(), "50000".into(), String::new()),
+            ]
+        );
+
+        // Switching the flag off would change the columns mid-file.
+        let plain = export_args(&db_path, &part, Some(&state), true);
+        assert!(export(&plain, &OutputFormat::Csv).is_err());
+
+        for path in [&db_path, &part, &state] {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
@@ -1,15 +1,21 @@
 //! The `donations` subcommand: queries synced FEC donation data.
 
 use anyhow::{bail, Result};
-use capitoltraders_lib::{validation, Db, DonationFilter};
+use capitoltraders_lib::{
+    validation, CommitteeClass, Db, DonationFilter, TradeStore, ZipDistrictMap,
+};
 use clap::Args;
 use std::path::PathBuf;
 
+use crate::hints;
 use crate::output::{
     print_contributor_agg_csv, print_contributor_agg_markdown, print_contributor_agg_table,
     print_contributor_agg_xml, print_donations_csv, print_donations_markdown,
     print_donations_table, print_donations_xml, print_employer_agg_csv,
-    print_employer_agg_markdown, print_employer_agg_table, print_employer_agg_xml, print_json,
+    print_employer_agg_markdown, print_employer_agg_table, print_employer_agg_xml,
+    print_geography_csv, print_geography_markdown, print_geography_table, print_geography_xml,
+    print_json, print_occupation_agg_csv, print_occupation_agg_markdown,
+    print_occupation_agg_table, print_occupation_agg_xml,
     print_state_agg_csv, print_state_agg_markdown, print_state_agg_table, print_state_agg_xml,
     OutputFormat,
 };
@@ -32,6 +38,10 @@ pub struct DonationsArgs {
     #[arg(long)]
     pub cycle: Option<i32>,
 
+    /// Donations received on/after this date (YYYY-MM-DD, or relative: 30d, 6m, 1y, ytd)
+    #[arg(long)]
+    pub since: Option<String>,
+
     /// Minimum contribution amount in dollars
     #[arg(long)]
     pub min_amount: Option<f64>,
@@ -44,23 +54,49 @@ pub struct DonationsArgs {
     #[arg(long)]
     pub state: Option<String>,
 
+    /// Filter by receiving committee class: campaign, leadership_pac, joint_fundraising, party, pac, other
+    #[arg(long)]
+    pub source_class: Option<String>,
+
+    /// Count refunds and committee transfers as ordinary receipts (by default refunds are netted and transfers excluded)
+    #[arg(long)]
+    pub include_all_receipts: bool,
+
     /// Show top N results
     #[arg(long)]
     pub top: Option<i64>,
 
-    /// Group results by: contributor, employer, state
+    /// Group results by: contributor, employer, state, occupation
     #[arg(long)]
     pub group_by: Option<String>,
+
+    /// Aggregate by contributor occupation, collapsing common variants (same as --group-by occupation)
+    #[arg(long, conflicts_with = "group_by")]
+    pub by_occupation: bool,
+
+    /// Break down donations into in-district / in-state / out-of-state buckets by ZIP (requires --politician)
+    #[arg(long)]
+    pub geography: bool,
+
+    /// ZIP-to-district mapping CSV for --geography (defaults to the bundled mapping)
+    #[arg(long)]
+    pub zip_districts: Option<PathBuf>,
 }
 
 pub fn run(args: &DonationsArgs, format: &OutputFormat) -> Result<()> {
-    let db = Db::open(&args.db)?;
+    let db = Db::open_read_only(&args.db)?;
 
     // Resolve politician name to ID if provided
     let politician_id = if let Some(ref name) = args.politician {
         let matches = db.find_politician_by_name(name)?;
         match matches.len() {
-            0 => bail!("No politician found matching '{}'", name),
+            0 => bail!(
+                "{}",
+                hints::with_hint(
+                    format!("No politician found matching '{}'", name),
+                    hints::politician_hint(&db, name)
+                )
+            ),
             1 => Some(matches[0].0.clone()),
             _ => {
                 let names: Vec<String> = matches.iter().map(|m| m.1.clone()).collect();
@@ -95,6 +131,19 @@ pub fn run(args: &DonationsArgs, format: &OutputFormat) -> Result<()> {
         }
     }
 
+    // Validate source class if provided
+    let source_class = match args.source_class {
+        Some(ref val) => Some(val.parse::<CommitteeClass>().map_err(anyhow::Error::msg)?),
+        None => None,
+    };
+
+    let since = args
+        .since
+        .as_deref()
+        .map(validation::validate_since_date)
+        .transpose()?
+        .map(|d| d.format("%Y-%m-%d").to_string());
+
     // Validate top if provided
     if let Some(top) = args.top {
         if top <= 0 {
@@ -105,13 +154,58 @@ pub fn run(args: &DonationsArgs, format: &OutputFormat) -> Result<()> {
     // Validate group_by if provided
     if let Some(ref group_by) = args.group_by {
         let normalized = group_by.trim().to_lowercase();
-        if !matches!(normalized.as_str(), "contributor" | "employer" | "state") {
+        if !matches!(normalized.as_str(), "contributor" | "employer" | "state" | "occupation") {
             bail!(
-                "Invalid --group-by value: '{}'. Valid options: contributor, employer, state",
+                "Invalid --group-by value: '{}'. Valid options: contributor, employer, state, occupation",
                 group_by
             );
         }
     }
+    let group_by = if args.by_occupation {
+        Some("occupation")
+    } else {
+        args.group_by.as_deref()
+    };
+
+    if args.geography {
+        if group_by.is_some() {
+            bail!("--geography cannot be combined with --group-by");
+        }
+        let Some(ref politician_id) = politician_id else {
+            bail!("--geography requires --politician");
+        };
+        let zips = match args.zip_districts {
+            Some(ref path) => ZipDistrictMap::from_file(path)?,
+            None => ZipDistrictMap::bundled()?,
+        };
+        let Some(geography) = db.query_donations_geography(politician_id, args.cycle, &zips)?
+        else {
+            bail!("Politician {} not found in database", politician_id);
+        };
+        if geography.buckets.iter().all(|b| b.donation_count == 0) {
+            eprintln!("No donations found for {}.", geography.politician_name);
+            eprintln!("Hint: Run 'capitoltraders sync-fec' and 'capitoltraders sync-donations' first.");
+            return Ok(());
+        }
+        eprintln!(
+            "{} ({}{}), ZIP-to-district mapping version {}",
+            geography.politician_name,
+            geography.state,
+            geography
+                .district
+                .map(|d| format!("-{}", d))
+                .unwrap_or_default(),
+            geography.mapping_version
+        );
+        match format {
+            OutputFormat::Table => print_geography_table(&geography),
+            OutputFormat::Json => print_json(&geography),
+            OutputFormat::Csv => print_geography_csv(&geography)?,
+            OutputFormat::Markdown => print_geography_markdown(&geography),
+            OutputFormat::Xml => print_geography_xml(&geography),
+        }
+        return Ok(());
+    }
 
     // Build filter
     let filter = DonationFilter {
@@ -120,27 +214,15 @@ pub fn run(args: &DonationsArgs, format: &OutputFormat) -> Result<()> {
         min_amount: args.min_amount,
         employer: args.employer.clone(),
         contributor_state: state,
+        source_class,
+        since,
+        include_all_receipts: args.include_all_receipts,
         limit: args.top,
     };
 
     // Dispatch based on group_by
-    match args.group_by.as_deref() {
-        None => {
-            // Individual listing
-            let donations = db.query_donations(&filter)?;
-            if donations.is_empty() {
-                eprintln!("No donations found matching the given filters.");
-                eprintln!("Hint: Run 'capitoltraders sync-fec' and 'capitoltraders sync-donations' first.");
-                return Ok(());
-            }
-            match format {
-                OutputFormat::Table => print_donations_table(&donations),
-                OutputFormat::Json => print_json(&donations),
-                OutputFormat::Csv => print_donations_csv(&donations)?,
-                OutputFormat::Markdown => print_donations_markdown(&donations),
-                OutputFormat::Xml => print_donations_xml(&donations),
-            }
-        }
+    match group_by {
+        None => print_donation_list(&db, &filter, format)?,
         Some("contributor") => {
             // Contributor aggregation
             let rows = db.query_donations_by_contributor(&filter)?;
@@ -189,8 +271,46 @@ pub fn run(args: &DonationsArgs, format: &OutputFormat) -> Result<()> {
                 OutputFormat::Xml => print_state_agg_xml(&rows),
             }
         }
+        Some("occupation") => {
+            // Occupation aggregation (normalized variants)
+            let rows = db.query_donations_by_occupation(&filter)?;
+            if rows.is_empty() {
+                eprintln!("No donations found matching the given filters.");
+                eprintln!("Hint: Run 'capitoltraders sync-fec' and 'capitoltraders sync-donations' first.");
+                return Ok(());
+            }
+            match format {
+                OutputFormat::Table => print_occupation_agg_table(&rows),
+                OutputFormat::Json => print_json(&rows),
+                OutputFormat::Csv => print_occupation_agg_csv(&rows)?,
+                OutputFormat::Markdown => print_occupation_agg_markdown(&rows),
+                OutputFormat::Xml => print_occupation_agg_xml(&rows),
+            }
+        }
         _ => unreachable!("group_by validated above"),
     }
 
     Ok(())
 }
+
+/// Print the individual donations matching `filter`, largest first.
+fn print_donation_list(
+    store: &impl TradeStore,
+    filter: &DonationFilter,
+    format: &OutputFormat,
+) -> Result<()> {
+    let donations = store.query_donations(filter)?;
+    if donations.is_empty() {
+        eprintln!("No donations found matching the given filters.");
+        eprintln!("Hint: Run 'capitoltraders sync-fec' and 'capitoltraders sync-donations' first.");
+        return Ok(());
+    }
+    match format {
+        OutputFormat::Table => print_donations_table(&donations),
+        OutputFormat::Json => print_json(&donations),
+        OutputFormat::Csv => print_donations_csv(&donations)?,
+        OutputFormat::Markdown => print_donations_markdown(&donations),
+        OutputFormat::Xml => print_donations_xml(&donations),
+    }
+    Ok(())
+}
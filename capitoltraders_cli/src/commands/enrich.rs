@@ -0,0 +1,300 @@
+//! The `enrich` subcommand: run one detail enrichment phase on its own.
+//!
+//! `enrich trades`, `enrich issuers` and `enrich politicians` each run the
+//! phase `sync --enrich` would, so they can be scheduled separately (issuers
+//! nightly, trade details weekly). Each prints its own summary and exits
+//! non-zero when any fetch failed or the circuit breaker tripped.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use capitoltraders_lib::{
+    enrich_issuers, enrich_politicians, enrich_trades, shutdown, AppConfig, CancellationToken, Db,
+    EnrichOptions, EnrichPhase, EnrichSummary, IssuerEnrichmentPriority, IssuerQueue,
+    ProgressSink, Rate, ScrapeClient,
+};
+use clap::{Args, Subcommand};
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Arguments for the `enrich` subcommand.
+#[derive(Args)]
+pub struct EnrichArgs {
+    #[command(subcommand)]
+    pub phase: EnrichCommand,
+}
+
+#[derive(Subcommand)]
+pub enum EnrichCommand {
+    /// Fetch trade detail pages (filing URLs, committees, labels, asset types)
+    Trades(EnrichTradesArgs),
+    /// Fetch issuer detail pages (sector, performance, end-of-day prices)
+    Issuers(EnrichIssuersArgs),
+    /// Rebuild committee memberships from the committee listings
+    Politicians(EnrichPoliticiansArgs),
+}
+
+/// Database, pacing and limits shared by every phase and flattened into
+/// `sync` as well; both build their [`EnrichOptions`] through
+/// [`enrich_options`].
+#[derive(Args)]
+pub struct EnrichCommonArgs {
+    /// SQLite database path
+    #[arg(long, default_value = "capitoltraders.db")]
+    pub db: PathBuf,
+
+    /// Show how many items would be enriched without fetching
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Pace CapitolTrades requests to one per N milliseconds per concurrent
+    /// fetch (ignored when the config sets `[capitoltrades] rate`)
+    #[arg(long, default_value = "500")]
+    pub details_delay_ms: u64,
+
+    /// Number of concurrent detail page fetches (1-10)
+    #[arg(long, default_value = "3")]
+    pub concurrency: usize,
+
+    /// Stop enrichment after N consecutive HTTP failures
+    #[arg(long, default_value = "5")]
+    pub max_failures: usize,
+}
+
+#[derive(Args)]
+pub struct EnrichTradesArgs {
+    #[command(flatten)]
+    pub common: EnrichCommonArgs,
+
+    /// Maximum trades to enrich per run (default: all)
+    #[arg(long)]
+    pub batch_size: Option<i64>,
+}
+
+#[derive(Args)]
+pub struct EnrichIssuersArgs {
+    #[command(flatten)]
+    pub common: EnrichCommonArgs,
+
+    /// Maximum issuers to enrich per run (default: all)
+    #[arg(long)]
+    pub batch_size: Option<i64>,
+
+    #[command(flatten)]
+    pub queue: IssuerQueueArgs,
+}
+
+/// Issuer ordering for `enrich issuers` and `sync --enrich`, parsed by
+/// [`issuer_queue`].
+#[derive(Args)]
+pub struct IssuerQueueArgs {
+    /// Issuer enrichment order: id, trades (most-traded first), or volume
+    /// (highest dollar volume first)
+    #[arg(long, default_value = "id")]
+    pub priority: String,
+
+    /// Skip enriching issuers with fewer than N trades; they stay unenriched
+    #[arg(long, value_name = "N")]
+    pub min_trades: Option<i64>,
+}
+
+#[derive(Args)]
+pub struct EnrichPoliticiansArgs {
+    #[command(flatten)]
+    pub common: EnrichCommonArgs,
+}
+
+pub async fn run(args: &EnrichArgs, config: &AppConfig) -> Result<()> {
+    let cancel = shutdown::ctrl_c_token();
+    let (common, batch_size) = match args.phase {
+        EnrichCommand::Trades(ref a) => (&a.common, a.batch_size),
+        EnrichCommand::Issuers(ref a) => (&a.common, a.batch_size),
+        EnrichCommand::Politicians(ref a) => (&a.common, None),
+    };
+    let opts = enrich_options(
+        common.concurrency,
+        common.max_failures,
+        batch_size,
+        common.dry_run,
+        &cancel,
+    )?;
+
+    let db = Db::open(&common.db)?;
+    db.init()?;
+    let scraper = paced_scraper(
+        ScrapeClient::from_config(config)?,
+        config,
+        common.details_delay_ms,
+        common.concurrency,
+    );
+    let progress = BarProgress::default();
+
+    let summary = match args.phase {
+        EnrichCommand::Trades(_) => enrich_trades(&db, &scraper, &opts, &progress).await?,
+        EnrichCommand::Issuers(ref a) => {
+            let queue = issuer_queue(&a.queue)?;
+            let summary = enrich_issuers(&db, &scraper, &opts, queue, &progress).await?;
+            if !common.dry_run {
+                db.recompute_issuer_stats()?;
+            }
+            summary
+        }
+        EnrichCommand::Politicians(_) => {
+            enrich_politicians(&db, &scraper, &opts, &progress).await?
+        }
+    };
+
+    if !summary.is_clean() {
+        bail!(
+            "{} enrichment: {} of {} {}s failed",
+            summary.phase,
+            summary.failed,
+            summary.total,
+            summary.phase.unit()
+        );
+    }
+    Ok(())
+}
+
+/// Validate the shared pacing flags.
+pub(crate) fn enrich_options(
+    concurrency: usize,
+    max_failures: usize,
+    batch_size: Option<i64>,
+    dry_run: bool,
+    cancel: &CancellationToken,
+) -> Result<EnrichOptions<'_>> {
+    if !(1..=10).contains(&concurrency) {
+        return Err(anyhow!("--concurrency must be between 1 and 10"));
+    }
+    if max_failures < 1 {
+        return Err(anyhow!("--max-failures must be at least 1"));
+    }
+    Ok(EnrichOptions {
+        concurrency,
+        max_failures,
+        batch_size,
+        dry_run,
+        cancel,
+    })
+}
+
+/// Pace `scraper` at `concurrency` requests per `--details-delay-ms`, the
+/// throughput the old per-fetch pause allowed. A `[capitoltrades] rate` in
+/// the config wins, and a zero delay leaves the scraper unpaced.
+pub(crate) fn paced_scraper(
+    scraper: ScrapeClient,
+    config: &AppConfig,
+    delay_ms: u64,
+    concurrency: usize,
+) -> ScrapeClient {
+    if config.capitoltrades.rate.is_some() || delay_ms == 0 {
+        return scraper;
+    }
+    let burst = u32::try_from(concurrency.max(1)).unwrap_or(u32::MAX);
+    scraper.with_rate(Rate::new(burst, Duration::from_millis(delay_ms)))
+}
+
+/// Parse `--priority` and `--min-trades`.
+pub(crate) fn issuer_queue(args: &IssuerQueueArgs) -> Result<IssuerQueue> {
+    let priority: IssuerEnrichmentPriority =
+        args.priority.parse().map_err(|e: String| anyhow!(e))?;
+    if args.min_trades.is_some_and(|n| n < 1) {
+        return Err(anyhow!("--min-trades must be at least 1"));
+    }
+    Ok(IssuerQueue {
+        priority,
+        min_trades: args.min_trades,
+    })
+}
+
+/// Progress bar per phase on stderr, plus the phase summary line.
+#[derive(Default)]
+pub(crate) struct BarProgress {
+    /// Bar of the phase running now; `start` replaces it for the next one.
+    bar: Mutex<Option<ProgressBar>>,
+}
+
+impl BarProgress {
+    fn current(&self) -> Option<ProgressBar> {
+        self.bar.lock().ok().and_then(|bar| bar.clone())
+    }
+}
+
+impl ProgressSink for BarProgress {
+    fn start(&self, phase: EnrichPhase, total: usize) {
+        let bar = ProgressBar::new(total as u64);
+        bar.set_style(
+            ProgressStyle::with_template(
+                "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
+            )
+            .unwrap(),
+        );
+        bar.set_message(format!("enriching {}...", phase));
+        if let Ok(mut slot) = self.bar.lock() {
+            *slot = Some(bar);
+        }
+    }
+
+    fn advance(&self, enriched: usize, failed: usize) {
+        if let Some(bar) = self.current() {
+            bar.set_message(format!("{} ok, {} err", enriched, failed));
+            bar.inc(1);
+        }
+    }
+
+    fn warn(&self, message: &str) {
+        match self.current() {
+            Some(bar) => bar.println(message),
+            None => eprintln!("{}", message),
+        }
+    }
+
+    fn finish(&self, message: &str) {
+        if let Ok(mut slot) = self.bar.lock() {
+            if let Some(bar) = slot.take() {
+                bar.finish_with_message(message.to_string());
+            }
+        }
+    }
+
+    fn phase_done(&self, summary: &EnrichSummary) {
+        print_summary(summary);
+    }
+}
+
+/// One stderr line per phase, the same for `sync --enrich` and `enrich`.
+fn print_summary(s: &EnrichSummary) {
+    let unit = s.phase.unit();
+    let skipped = if s.skipped > 0 {
+        format!(", {} skipped below --min-trades", s.skipped)
+    } else {
+        String::new()
+    };
+    if s.dry_run {
+        eprintln!(
+            "{} {}s would be enriched ({} selected{})",
+            s.pending, unit, s.total, skipped
+        );
+    } else if s.total == 0 {
+        eprintln!("No {} need enrichment", s.phase);
+    } else {
+        eprintln!(
+            "{} enrichment: {}/{} {}s processed ({} failed{})",
+            match s.phase {
+                EnrichPhase::Trades => "Trade",
+                EnrichPhase::Issuers => "Issuer",
+                EnrichPhase::Politicians => "Politician",
+            },
+            s.enriched,
+            s.total,
+            unit,
+            s.failed,
+            skipped
+        );
+        if s.phase == EnrichPhase::Politicians && s.enriched < s.total {
+            eprintln!("Committee memberships left unchanged");
+        }
+    }
+}
@@ -1,25 +1,46 @@
 //! Price enrichment pipeline for fetching historical and current prices.
 //!
-//! Implements three-phase enrichment:
-//! - Phase 1: Historical prices deduplicated by (ticker, date), Yahoo-first with Tiingo fallback
-//! - Phase 2: Current prices deduplicated by ticker (Yahoo only)
-//! - Phase 3: Benchmark prices (sector ETF or SPY) deduplicated by (ETF ticker, date)
+//! Implements two-phase enrichment:
+//! - Phase 1: Every price a trade needs (trade date, disclosure date and
+//!   today) is collected up front and deduplicated per ticker, then served
+//!   by one historical range request per ticker, Yahoo-first with Tiingo
+//!   fallback. Each trade's prices are written in a single update.
+//! - Phase 2: Benchmark prices (sector ETF or SPY) deduplicated by (ETF ticker, date)
 //!
-//! Uses Semaphore + JoinSet + mpsc pattern for concurrent fetching with rate limiting.
+//! A foreign issuer's bare ticker that returns no data is retried with the
+//! exchange suffixes for its country; the first that answers is saved as a
+//! ticker alias for later runs.
+//!
+//! Prices for foreign listings are converted to USD with Yahoo FX quotes
+//! fetched before Phase 1. When no rate is available the local-currency price
+//! is stored as-is and the ticker is listed in the summary.
+//!
+//! Historical prices (both phases) go through a [`PriceFetchChain`] and are
+//! read from and written to the database's `price_cache` table, so repeated
+//! runs only download dates not stored yet. Closes from the last few days are
+//! always downloaded again; `--no-price-cache` bypasses the cache entirely.
+//!
+//! Uses Semaphore + JoinSet + mpsc pattern for concurrent fetching. Request
+//! pacing comes from the Yahoo and Tiingo clients' rate limiters. On Ctrl-C
+//! queued fetches are dropped, in-flight ones are written, and the trades
+//! never fetched stay unenriched for the next run. A trade is marked enriched
+//! only once all of its anchors were attempted; trades whose lookup failed
+//! transiently (rate limits, network errors) stay unenriched too.
 
 use anyhow::{anyhow, bail, Result};
-use capitoltraders_lib::{pricing, ticker_alias, tiingo::TiingoClient, yahoo::YahooClient, Db};
+use capitoltraders_lib::{
+    plan_anchors, price_fetch::DEFAULT_REFRESH_DAYS, pricing, shutdown, ticker_alias,
+    tiingo::TiingoClient, yahoo::YahooClient, AnchorOutcome, AnchorPass, AnchorRequest, AppConfig,
+    CachedFetch, Db, ExchangeSuffixes, PriceAnchor, PriceCache, PriceFetchChain,
+};
 use chrono::NaiveDate;
 use clap::Args;
 use indicatif::{ProgressBar, ProgressStyle};
-use rand::Rng;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::sync::{mpsc, Semaphore};
 use tokio::task::JoinSet;
-use tokio::time::sleep;
 
 /// Price enrichment CLI arguments.
 #[derive(Args)]
@@ -43,28 +64,33 @@ pub struct EnrichPricesArgs {
     /// Reset trades that were attempted but got no price, then re-enrich them
     #[arg(long)]
     pub retry_failed: bool,
-}
 
-/// Message sent from fetch tasks to receiver for historical price enrichment.
-struct HistoricalPriceResult {
-    ticker: String,
-    date: NaiveDate,
-    trade_indices: Vec<usize>,
-    result: Result<Option<f64>, capitoltraders_lib::yahoo::YahooError>,
-    /// Which API provided the price ("yahoo", "tiingo", or "" if no data).
-    source: String,
+    /// Clear current prices fetched more than DAYS days ago, then refresh them
+    #[arg(long, value_name = "DAYS")]
+    pub refresh_older_than: Option<u32>,
+
+    /// Download every historical price instead of reading (and filling) the on-disk price cache
+    #[arg(long)]
+    pub no_price_cache: bool,
 }
 
-/// Message sent from fetch tasks to receiver for current price enrichment.
-struct CurrentPriceResult {
-    trade_indices: Vec<usize>,
-    result: Result<Option<f64>, capitoltraders_lib::yahoo::YahooError>,
+/// Message sent from fetch tasks to receiver for one ticker's anchor prices.
+struct TickerPriceResult {
+    ticker: String,
+    /// Indices into the trade (and anchor request) list.
+    requests: Vec<usize>,
+    /// Cached and downloaded closes for the anchor dates.
+    prices: CachedFetch,
+    /// Exchange-suffixed ticker that answered after `ticker` had no data.
+    resolved: Option<String>,
 }
 
 /// Message sent from fetch tasks to receiver for benchmark price enrichment.
 struct BenchmarkPriceResult {
+    ticker: String,
+    date: NaiveDate,
     trade_indices: Vec<i64>,  // tx_ids of trades to update
-    result: Result<Option<f64>, capitoltraders_lib::yahoo::YahooError>,
+    prices: CachedFetch,
 }
 
 /// Circuit breaker to stop processing after consecutive failures.
@@ -119,8 +145,17 @@ fn get_benchmark_ticker(gics_sector: Option<&str>) -> &'static str {
 fn print_diagnostics(db: &Db) -> Result<()> {
     let diag = db.get_enrichment_diagnostics()?;
 
+    let status = db.sync_status()?;
+    let stamp = |value: &Option<String>| value.clone().unwrap_or_else(|| "never".to_string());
+
     eprintln!("=== Enrichment Diagnostics ===");
     eprintln!();
+    eprintln!("Data freshness:");
+    eprintln!("  Last trade sync:        {}", stamp(&status.last_trade_sync));
+    eprintln!("  Last FEC sync:          {}", stamp(&status.last_fec_sync));
+    eprintln!("  Last donation sync:     {}", stamp(&status.last_donation_sync));
+    eprintln!("  Last price enrichment:  {}", stamp(&status.last_price_enrichment));
+    eprintln!();
     eprintln!("Overall breakdown:");
     eprintln!("  Total trades:           {:>6}", diag.total);
     eprintln!("  Has price:              {:>6} ({:.1}%)", diag.has_price, pct(diag.has_price, diag.total));
@@ -151,6 +186,13 @@ fn print_diagnostics(db: &Db) -> Result<()> {
         }
     }
 
+    if diag.suffix_resolved_tickers > 0 {
+        eprintln!();
+        eprintln!("Exchange suffix resolution:");
+        eprintln!("  Tickers resolved:       {:>6}", diag.suffix_resolved_tickers);
+        eprintln!("  Priced trades:          {:>6}", diag.suffix_resolved_trades);
+    }
+
     if !diag.price_source_breakdown.is_empty() {
         eprintln!();
         eprintln!("Price source breakdown:");
@@ -159,18 +201,105 @@ fn print_diagnostics(db: &Db) -> Result<()> {
         }
     }
 
+    if diag.fx_missing > 0 {
+        eprintln!();
+        eprintln!(
+            "Trades priced in listing currency (no FX rate): {}",
+            diag.fx_missing
+        );
+    }
+
+    if !diag.exchange_resolution.is_empty() {
+        eprintln!();
+        eprintln!("Price resolution by exchange (from c2iq):");
+        for (exchange, attempted, priced) in &diag.exchange_resolution {
+            eprintln!(
+                "  {:<10} {:>6}/{:<6} ({:.1}%)",
+                exchange,
+                priced,
+                attempted,
+                pct(*priced, *attempted)
+            );
+        }
+    }
+
+    let without_sector = db.count_trades_without_sector()?;
+    eprintln!();
+    eprintln!("Sector coverage:");
+    eprintln!(
+        "  Trades without GICS sector: {:>6} ({:.1}%)",
+        without_sector,
+        pct(without_sector, diag.total)
+    );
+    let missing = db.issuers_without_sector()?;
+    if !missing.is_empty() {
+        eprintln!("  Issuers without sector:     {:>6} (most traded first)", missing.len());
+        for (_, ticker, name) in missing.iter().take(10) {
+            eprintln!("    {:<15} {}", ticker.as_deref().unwrap_or("-"), name);
+        }
+    }
+
+    let issues = db.disclosure_integrity()?;
+    eprintln!();
+    eprintln!("Data integrity:");
+    if issues.is_empty() {
+        eprintln!("  No inconsistent trades found");
+    } else {
+        eprintln!("  Issues found:               {:>6}", issues.len());
+        for issue in issues.iter().take(10) {
+            eprintln!("    tx {:<10} {:<26} {}", issue.tx_id, issue.kind, issue.description);
+        }
+    }
+    let mismatches = db.issuer_c2iq_mismatches()?;
+    if !mismatches.is_empty() {
+        eprintln!("  c2iq/ticker mismatches:     {:>6} (most traded first)", mismatches.len());
+        for m in mismatches.iter().take(10) {
+            eprintln!("    {:<15} c2iq {:<15} {}", m.issuer_ticker, m.c2iq, m.issuer_name);
+        }
+    }
+
     eprintln!();
     eprintln!("=== End Diagnostics ===");
 
     Ok(())
 }
 
+/// Fetch FX quotes for every foreign listing currency on each anchor date.
+/// Missing quotes are simply left out.
+async fn fetch_fx_rates<'a>(
+    yahoo: &YahooClient,
+    pairs: impl Iterator<Item = (&'a str, NaiveDate)>,
+) -> pricing::FxRates {
+    let mut wanted: BTreeSet<(String, NaiveDate)> = BTreeSet::new();
+    for (ticker, date) in pairs {
+        let (currency, _) = pricing::major_currency(pricing::listing_currency(ticker));
+        if currency != pricing::BASE_CURRENCY {
+            wanted.insert((currency, date));
+        }
+    }
+
+    let mut fx = pricing::FxRates::new();
+    if wanted.is_empty() {
+        return fx;
+    }
+    eprintln!("Fetching {} FX rates for foreign listings", wanted.len());
+    for (currency, date) in wanted {
+        let pair = pricing::fx_pair_ticker(&currency);
+        match yahoo.get_price_on_date_with_fallback(&pair, date).await {
+            Ok(Some(rate)) => fx.insert(&currency, date, rate),
+            Ok(None) => {}
+            Err(e) => eprintln!("  Warning: FX rate {} on {} failed: {}", pair, date, e),
+        }
+    }
+    fx
+}
+
 fn pct(part: i64, total: i64) -> f64 {
     if total == 0 { 0.0 } else { (part as f64 / total as f64) * 100.0 }
 }
 
 /// Run the price enrichment pipeline.
-pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
+pub async fn run(args: &EnrichPricesArgs, config: &AppConfig) -> Result<()> {
     let db = Db::open(&args.db)?;
     db.init().map_err(|e| anyhow!("Failed to initialize database: {}", e))?;
 
@@ -190,36 +319,65 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
         eprintln!("Reset {} trades that were attempted but got no price", reset_count);
     }
 
+    // --refresh-older-than: drop stale current prices; trade-date prices are kept
+    if let Some(days) = args.refresh_older_than {
+        let purged = db.purge_stale_prices(days)?;
+        eprintln!("Cleared current prices older than {} days on {} trades", days, purged);
+    }
+
     // Load ticker aliases
-    let aliases = ticker_alias::load_ticker_aliases()
+    let mut aliases = ticker_alias::load_ticker_aliases()
         .map_err(|e| anyhow!("Failed to load ticker aliases: {}", e))?;
+    // Aliases imported with `aliases --import` take precedence over the bundled set.
+    for alias in db.get_ticker_aliases()? {
+        aliases.insert(alias.from, alias.to);
+    }
     let alias_count = aliases.len();
     if alias_count > 0 {
         eprintln!("Loaded {} ticker aliases", alias_count);
     }
+    let suffixes = ExchangeSuffixes::load(config.exchange_suffixes_file.as_deref())
+        .map_err(|e| anyhow!("Failed to load exchange suffixes: {}", e))?;
 
     // Step 1: Setup
     let yahoo = Arc::new(
-        YahooClient::new().map_err(|e| anyhow!("Failed to create Yahoo client: {}", e))?,
+        YahooClient::from_config(config)
+            .map_err(|e| anyhow!("Failed to create Yahoo client: {}", e))?,
     );
 
-    // Optional Tiingo fallback client (requires TIINGO_API_KEY in .env)
-    let tiingo: Option<Arc<TiingoClient>> = match std::env::var("TIINGO_API_KEY") {
-        Ok(key) if !key.is_empty() => {
-            match TiingoClient::new(key) {
-                Ok(client) => {
-                    eprintln!("Tiingo fallback enabled (TIINGO_API_KEY found)");
-                    Some(Arc::new(client))
-                }
-                Err(e) => {
-                    eprintln!("Warning: Failed to create Tiingo client: {}", e);
-                    None
-                }
-            }
+    // Optional Tiingo fallback client (requires a Tiingo API key in .env or the config file)
+    let tiingo: Option<Arc<TiingoClient>> = match TiingoClient::from_config(config) {
+        Ok(Some(client)) => {
+            eprintln!("Tiingo fallback enabled (Tiingo API key found)");
+            Some(Arc::new(client))
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Warning: Failed to create Tiingo client: {}", e);
+            None
         }
-        _ => None,
     };
 
+    let mut chain = PriceFetchChain::new(Arc::clone(&yahoo));
+    if let Some(ref client) = tiingo {
+        chain = chain.with_tiingo(Arc::clone(client));
+    }
+    let chain = Arc::new(chain);
+    let cache = if args.no_price_cache {
+        PriceCache::disabled(&db)
+    } else {
+        PriceCache::new(
+            &db,
+            chrono::Utc::now().date_naive() - chrono::Days::new(DEFAULT_REFRESH_DAYS),
+        )
+    };
+
+    // Bonds and treasuries are valued at face value; no price lookup needed.
+    let face_valued = db.record_face_values(args.batch_size)?;
+    if face_valued > 0 {
+        eprintln!("Valued {} bond/treasury trades at face value", face_valued);
+    }
+
     let trades = db.get_unenriched_price_trades(args.batch_size)?;
 
     if trades.is_empty() {
@@ -232,18 +390,31 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
         "Starting price enrichment for {} trades",
         total_trades
     );
+    let cancel = shutdown::ctrl_c_token();
 
-    // Step 2: Deduplicate by (normalized_ticker, date)
-    let mut ticker_date_map: HashMap<(String, NaiveDate), Vec<usize>> = HashMap::new();
-    let mut normalized_tickers: HashMap<String, String> = HashMap::new();
+    // Step 2: Collect each trade's anchor dates, grouped by ticker
+    let today = chrono::Utc::now().date_naive();
+    let mut requests: Vec<AnchorRequest> = Vec::with_capacity(trades.len());
     let mut skipped_parse_errors = 0usize;
     let mut skipped_no_ticker = 0usize;
     let mut alias_skipped_unenrichable = 0usize;
     let mut alias_resolved = 0usize;
-
-    for (idx, trade) in trades.iter().enumerate() {
-        let yahoo_ticker = match pricing::resolve_yahoo_ticker(&trade.issuer_ticker, &aliases) {
-            Some(t) => t,
+    let mut refresh_only = 0usize;
+    // Bare ticker -> every ticker to try for it, for foreign issuers.
+    let mut suffix_candidates: HashMap<String, Vec<String>> = HashMap::new();
+    // Tickers whose c2iq exchange Tiingo does not carry.
+    let mut yahoo_only: HashSet<String> = HashSet::new();
+
+    for trade in &trades {
+        let candidates = pricing::resolve_yahoo_candidates(
+            &trade.issuer_ticker,
+            &aliases,
+            trade.issuer_country.as_deref(),
+            trade.issuer_c2iq.as_deref(),
+            &suffixes,
+        );
+        let yahoo_ticker = match candidates.first() {
+            Some(t) => t.clone(),
             None => {
                 // Check if this was an alias-based skip (known unenrichable) vs empty ticker
                 if aliases.get(trade.issuer_ticker.as_str()) == Some(&None)
@@ -253,6 +424,10 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
                 } else {
                     skipped_no_ticker += 1;
                 }
+                requests.push(AnchorRequest {
+                    ticker: String::new(),
+                    anchors: Vec::new(),
+                });
                 continue;
             }
         };
@@ -263,25 +438,47 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
         {
             alias_resolved += 1;
         }
+        let off_tiingo = trade
+            .issuer_c2iq
+            .as_deref()
+            .and_then(pricing::parse_c2iq)
+            .and_then(|(_, exchange)| pricing::exchange_route(&exchange))
+            .is_some_and(|route| !route.tiingo);
+        if off_tiingo {
+            yahoo_only.insert(yahoo_ticker.clone());
+        }
+        if candidates.len() > 1 {
+            suffix_candidates.insert(yahoo_ticker.clone(), candidates);
+        }
 
-        normalized_tickers
-            .entry(trade.issuer_ticker.clone())
-            .or_insert_with(|| yahoo_ticker.clone());
-        match NaiveDate::parse_from_str(&trade.tx_date, "%Y-%m-%d") {
-            Ok(date) => {
-                ticker_date_map
-                    .entry((yahoo_ticker, date))
-                    .or_default()
-                    .push(idx);
+        // Historical prices are immutable; refreshed trades only ask for the
+        // anchors they are missing.
+        let mut anchors = Vec::with_capacity(3);
+        if trade.trade_date_priced {
+            refresh_only += 1;
+        } else {
+            match NaiveDate::parse_from_str(&trade.tx_date, "%Y-%m-%d") {
+                Ok(date) => anchors.push((PriceAnchor::TradeDate, date)),
+                Err(_) => {
+                    eprintln!(
+                        "Warning: tx_id {} has invalid tx_date '{}', skipping",
+                        trade.tx_id, trade.tx_date
+                    );
+                    skipped_parse_errors += 1;
+                }
             }
-            Err(_) => {
-                eprintln!(
-                    "Warning: tx_id {} has invalid tx_date '{}', skipping",
-                    trade.tx_id, trade.tx_date
-                );
-                skipped_parse_errors += 1;
+        }
+        if !trade.pub_date_priced {
+            let pub_date = trade.pub_date.split('T').next().unwrap_or(&trade.pub_date);
+            if let Ok(date) = NaiveDate::parse_from_str(pub_date, "%Y-%m-%d") {
+                anchors.push((PriceAnchor::PubDate, date));
             }
         }
+        anchors.push((PriceAnchor::Current, today));
+        requests.push(AnchorRequest {
+            ticker: yahoo_ticker,
+            anchors,
+        });
     }
 
     if skipped_no_ticker > 0 {
@@ -302,147 +499,148 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
             alias_resolved
         );
     }
+    if refresh_only > 0 {
+        eprintln!(
+            "Refreshing current price only for {} trades that keep their trade-date price",
+            refresh_only
+        );
+    }
+
+    let plan = plan_anchors(&requests);
+    let unique_tickers = plan.len();
+    let unique_pairs: usize = plan.values().map(|work| work.dates.len()).sum();
+
+    // Suffixed candidates may be the listing that answers, so their
+    // currencies are fetched too.
+    let fx_rates = fetch_fx_rates(
+        &yahoo,
+        plan.iter().flat_map(|(ticker, work)| {
+            let tickers: Vec<&str> = match suffix_candidates.get(ticker) {
+                Some(candidates) => candidates.iter().map(String::as_str).collect(),
+                None => vec![ticker.as_str()],
+            };
+            tickers
+                .into_iter()
+                .flat_map(move |t| work.dates.iter().map(move |date| (t, *date)))
+        }),
+    )
+    .await;
 
-    let unique_pairs = ticker_date_map.len();
     eprintln!(
-        "Phase 1: Fetching historical prices for {} unique (ticker, date) pairs",
-        unique_pairs
+        "Phase 1: Fetching prices for {} unique tickers ({} unique ticker-date pairs, one request per ticker)",
+        unique_tickers, unique_pairs
     );
 
-    // Step 3: Historical price enrichment (Phase 1)
+    // Step 3: Trade-date, disclosure-date and current prices (Phase 1)
     const CONCURRENCY: usize = 5;
     const CIRCUIT_BREAKER_THRESHOLD: usize = 10;
 
-    let pb = ProgressBar::new(unique_pairs as u64);
+    let pb = ProgressBar::new(unique_tickers as u64);
     pb.set_style(
         ProgressStyle::with_template(
             "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
         )
         .unwrap(),
     );
-    pb.set_message("fetching historical prices...");
+    pb.set_message("fetching prices...");
 
     let semaphore = Arc::new(Semaphore::new(CONCURRENCY));
-    let (tx, mut rx) = mpsc::channel::<HistoricalPriceResult>(CONCURRENCY * 2);
+    let (tx, mut rx) = mpsc::channel::<TickerPriceResult>(CONCURRENCY * 2);
     let mut join_set = JoinSet::new();
 
-    for ((ticker, date), indices) in ticker_date_map {
-        let sem = Arc::clone(&semaphore);
-        let sender = tx.clone();
-        let yahoo_clone = Arc::clone(&yahoo);
-        let tiingo_clone = tiingo.clone();
+    let mut cache_hits = 0usize;
+    for (ticker, work) in plan {
+        let range = cache.split(&ticker, &work.dates)?;
+        cache_hits += range.cached.len();
+        // A bare ticker with cached closes has prices; only try suffixes
+        // when it has none at all.
+        let candidates = match suffix_candidates.remove(&ticker) {
+            Some(candidates) if range.cached.is_empty() => candidates,
+            _ => vec![ticker.clone()],
+        };
 
+        let sender = tx.clone();
+        let sem = Arc::clone(&semaphore);
+        let chain_clone = if yahoo_only.contains(&ticker) {
+            Arc::new(chain.yahoo_only())
+        } else {
+            Arc::clone(&chain)
+        };
+        let cancel = cancel.clone();
         join_set.spawn(async move {
-            let _permit = sem.acquire().await.expect("semaphore closed");
-            // Rate limiting with jittered delay
-            let delay_ms = rand::thread_rng().gen_range(200..500);
-            sleep(Duration::from_millis(delay_ms)).await;
-
-            let yahoo_result = yahoo_clone.get_price_on_date_with_fallback(&ticker, date).await;
-
-            // If Yahoo returned no data and Tiingo is available, try fallback
-            let (result, source) = match &yahoo_result {
-                Ok(Some(_)) => (yahoo_result, "yahoo".to_string()),
-                Ok(None) if tiingo_clone.is_some() => {
-                    let tiingo_client = tiingo_clone.as_ref().unwrap();
-                    match tiingo_client.get_price_on_date(&ticker, date).await {
-                        Ok(Some(price)) => (Ok(Some(price)), "tiingo".to_string()),
-                        Ok(None) => (Ok(None), String::new()),
-                        Err(_) => {
-                            // Tiingo failed too, pass through Yahoo's Ok(None)
-                            (Ok(None), String::new())
-                        }
-                    }
-                }
-                _ => {
-                    // Yahoo returned Err or Ok(None) without Tiingo
-                    (yahoo_result, String::new())
+            let _permit = if range.missing.is_empty() {
+                None
+            } else {
+                let permit = sem.acquire().await.expect("semaphore closed");
+                if cancel.is_cancelled() {
+                    return;
                 }
+                Some(permit)
             };
-
+            let prices = range.fetch(&chain_clone, &candidates).await;
             let _ = sender
-                .send(HistoricalPriceResult {
+                .send(TickerPriceResult {
                     ticker,
-                    date,
-                    trade_indices: indices,
-                    result,
-                    source,
+                    requests: work.requests,
+                    resolved: (prices.candidate > 0).then(|| candidates[prices.candidate].clone()),
+                    prices,
                 })
                 .await;
         });
     }
     drop(tx);
+    if cache_hits > 0 {
+        pb.println(format!("  {} ticker-date pairs served from the price cache", cache_hits));
+    }
 
-    let mut enriched = 0usize;
-    let mut failed = 0usize;
-    let mut skipped = 0usize;
-    let mut tiingo_resolved = 0usize;
+    let mut pass = AnchorPass::new(&db, &trades, &requests, &fx_rates);
+    let mut suffix_resolved = 0usize;
     let mut breaker = CircuitBreaker::new(CIRCUIT_BREAKER_THRESHOLD);
 
     // Track tickers that return no data for end-of-run summary
     let mut no_data_tickers: HashSet<String> = HashSet::new();
 
     while let Some(fetch) = rx.recv().await {
-        let source_opt = if fetch.source.is_empty() { None } else { Some(fetch.source.as_str()) };
-
-        match fetch.result {
-            Ok(Some(price)) => {
-                if fetch.source == "tiingo" {
-                    tiingo_resolved += fetch.trade_indices.len();
-                    pb.println(format!("  Tiingo fallback: {} on {} = {:.2}", fetch.ticker, fetch.date, price));
-                }
-                // Process all trades with this (ticker, date) pair
-                for idx in &fetch.trade_indices {
-                    let trade = &trades[*idx];
-                    // Estimate shares: try range-based first, fall back to value-based
-                    let estimate = pricing::parse_trade_range(trade.size_range_low, trade.size_range_high)
-                        .and_then(|range| pricing::estimate_shares(&range, price))
-                        .or_else(|| pricing::estimate_shares_from_value(trade.value, price));
-
-                    if let Some(estimate) = estimate {
-                        db.update_trade_prices(
-                            trade.tx_id,
-                            Some(price),
-                            Some(estimate.estimated_shares),
-                            Some(estimate.estimated_value),
-                            source_opt,
-                        )?;
-                        enriched += 1;
-                        breaker.record_success();
-                    } else {
-                        db.update_trade_prices(trade.tx_id, Some(price), None, None, source_opt)?;
-                        skipped += 1;
+        // The listing the closes belong to: the bare ticker, or the
+        // exchange-suffixed one that answered instead.
+        let price_ticker = fetch.resolved.as_deref().unwrap_or(&fetch.ticker);
+        match fetch.prices.result {
+            Err(ref err) => {
+                pb.println(format!("  Warning: {} failed: {}", fetch.ticker, err));
+            }
+            Ok(ref range) => {
+                if let Some(ref resolved) = fetch.resolved {
+                    suffix_resolved += 1;
+                    pb.println(format!("  Exchange suffix: {} -> {}", fetch.ticker, resolved));
+                    let raw: BTreeSet<&str> = fetch
+                        .requests
+                        .iter()
+                        .map(|idx| trades[*idx].issuer_ticker.trim())
+                        .collect();
+                    for raw_ticker in raw {
+                        db.record_suffix_alias(raw_ticker, resolved)?;
                     }
                 }
+                cache.store(price_ticker, &fetch.prices)?;
+                if range.source == Some("tiingo") {
+                    pb.println(format!("  Tiingo fallback: {}", fetch.ticker));
+                }
             }
-            Ok(None) => {
+        }
+
+        match pass.apply(&fetch.requests, &fetch.prices, price_ticker)? {
+            AnchorOutcome::Priced => breaker.record_success(),
+            AnchorOutcome::NoData => {
                 // Log first occurrence of each failing ticker
                 if no_data_tickers.insert(fetch.ticker.clone()) {
-                    pb.println(format!("  No data: {} on {}", fetch.ticker, fetch.date));
-                }
-                for idx in &fetch.trade_indices {
-                    let trade = &trades[*idx];
-                    db.update_trade_prices(trade.tx_id, None, None, None, None)?;
-                    skipped += 1;
+                    pb.println(format!("  No data: {}", fetch.ticker));
                 }
             }
-            Err(ref err) => {
-                pb.println(format!(
-                    "  Warning: {} on {} failed: {}",
-                    fetch.ticker, fetch.date, err
-                ));
-                for idx in &fetch.trade_indices {
-                    let trade = &trades[*idx];
-                    // Mark as enriched with None to avoid re-processing
-                    db.update_trade_prices(trade.tx_id, None, None, None, None)?;
-                    failed += 1;
-                }
-                breaker.record_failure();
-            }
+            AnchorOutcome::Failed | AnchorOutcome::Deferred => breaker.record_failure(),
         }
-        pb.set_message(format!("{} ok, {} err, {} skip", enriched, failed, skipped));
+        let tally = &pass.tally;
+        pb.set_message(format!("{} ok, {} err, {} skip", tally.enriched, tally.failed, tally.skipped));
         pb.inc(1);
-
         if breaker.is_tripped() {
             pb.println(format!(
                 "Circuit breaker tripped after {} consecutive failures, stopping Phase 1",
@@ -452,10 +650,11 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
             break;
         }
     }
+    let tally = pass.tally;
 
     pb.finish_with_message(format!(
         "Phase 1 done: {} enriched, {} failed, {} skipped",
-        enriched, failed, skipped
+        tally.enriched, tally.failed, tally.skipped
     ));
 
     // Print failing ticker summary
@@ -477,87 +676,16 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
         eprintln!();
     }
 
-    // Step 4: Current price enrichment (Phase 2)
-    let mut ticker_map: HashMap<String, Vec<usize>> = HashMap::new();
-    for (idx, trade) in trades.iter().enumerate() {
-        if let Some(yahoo_ticker) = normalized_tickers.get(&trade.issuer_ticker) {
-            ticker_map
-                .entry(yahoo_ticker.clone())
-                .or_default()
-                .push(idx);
-        }
-    }
-
-    let unique_tickers = ticker_map.len();
-    eprintln!(
-        "Phase 2: Fetching current prices for {} unique tickers",
-        unique_tickers
-    );
-
-    let pb2 = ProgressBar::new(unique_tickers as u64);
-    pb2.set_style(
-        ProgressStyle::with_template(
-            "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
-        )
-        .unwrap(),
-    );
-    pb2.set_message("fetching current prices...");
-
-    let semaphore2 = Arc::new(Semaphore::new(CONCURRENCY));
-    let (tx2, mut rx2) = mpsc::channel::<CurrentPriceResult>(CONCURRENCY * 2);
-    let mut join_set2 = JoinSet::new();
-
-    for (ticker, indices) in ticker_map {
-        let sem = Arc::clone(&semaphore2);
-        let sender = tx2.clone();
-        let yahoo_clone = Arc::clone(&yahoo);
-
-        join_set2.spawn(async move {
-            let _permit = sem.acquire().await.expect("semaphore closed");
-            let delay_ms = rand::thread_rng().gen_range(200..500);
-            sleep(Duration::from_millis(delay_ms)).await;
-
-            let result = yahoo_clone.get_current_price(&ticker).await;
-            let _ = sender
-                .send(CurrentPriceResult {
-                    trade_indices: indices,
-                    result,
-                })
-                .await;
-        });
-    }
-    drop(tx2);
-
-    let mut current_enriched = 0usize;
-    let mut current_skipped = 0usize;
-
-    while let Some(fetch) = rx2.recv().await {
-        match fetch.result {
-            Ok(Some(price)) => {
-                for idx in &fetch.trade_indices {
-                    let trade = &trades[*idx];
-                    db.update_current_price(trade.tx_id, Some(price))?;
-                    current_enriched += 1;
-                }
-            }
-            Ok(None) | Err(_) => {
-                // Current price is best-effort, skip on failure
-                current_skipped += fetch.trade_indices.len();
-            }
-        }
-        pb2.set_message(format!("{} ok, {} skip", current_enriched, current_skipped));
-        pb2.inc(1);
-    }
-
-    pb2.finish_with_message(format!(
-        "Phase 2 done: {} enriched, {} skipped",
-        current_enriched, current_skipped
-    ));
-
-    // Step 4.5: Phase 3 -- Benchmark price enrichment
-    let benchmark_trades = db.get_benchmark_unenriched_trades(args.batch_size)?;
+    // Step 4: Phase 2 -- Benchmark price enrichment
+    let benchmark_trades = if cancel.is_cancelled() {
+        Vec::new()
+    } else {
+        db.get_benchmark_unenriched_trades(args.batch_size)?
+    };
 
-    let (benchmark_enriched, benchmark_skipped, breaker3_tripped) = if benchmark_trades.is_empty() {
+    let (benchmark_enriched, benchmark_skipped, breaker2_tripped) = if cancel.is_cancelled() {
+        (0, 0, false)
+    } else if benchmark_trades.is_empty() {
         eprintln!("No trades need benchmark enrichment");
         (0, 0, false)
     } else {
@@ -577,114 +705,143 @@ pub async fn run(args: &EnrichPricesArgs) -> Result<()> {
 
         let unique_pairs = benchmark_date_map.len();
         eprintln!(
-            "Phase 3: Fetching benchmark prices for {} unique (ETF, date) pairs across {} trades",
+            "Phase 2: Fetching benchmark prices for {} unique (ETF, date) pairs across {} trades",
             unique_pairs,
             benchmark_trades.len()
         );
 
-        let pb3 = ProgressBar::new(unique_pairs as u64);
-        pb3.set_style(
+        let pb2 = ProgressBar::new(unique_pairs as u64);
+        pb2.set_style(
             ProgressStyle::with_template(
                 "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
             )
             .unwrap(),
         );
-        pb3.set_message("fetching benchmark prices...");
+        pb2.set_message("fetching benchmark prices...");
 
-        let semaphore3 = Arc::new(Semaphore::new(CONCURRENCY));
-        let (tx3, mut rx3) = mpsc::channel::<BenchmarkPriceResult>(CONCURRENCY * 2);
-        let mut join_set3 = JoinSet::new();
+        let semaphore2 = Arc::new(Semaphore::new(CONCURRENCY));
+        let (tx2, mut rx2) = mpsc::channel::<BenchmarkPriceResult>(CONCURRENCY * 2);
+        let mut join_set2 = JoinSet::new();
 
         for ((ticker, date), tx_ids) in benchmark_date_map {
-            let sem = Arc::clone(&semaphore3);
-            let sender = tx3.clone();
-            let yahoo_clone = Arc::clone(&yahoo);
-
-            join_set3.spawn(async move {
-                let _permit = sem.acquire().await.expect("semaphore closed");
-                let delay_ms = rand::thread_rng().gen_range(200..500);
-                sleep(Duration::from_millis(delay_ms)).await;
+            let sender = tx2.clone();
+            let range = cache.split(&ticker, &BTreeSet::from([date]))?;
+            let sem = Arc::clone(&semaphore2);
+            let chain_clone = Arc::clone(&chain);
+            let cancel = cancel.clone();
+            join_set2.spawn(async move {
+                let _permit = if range.missing.is_empty() {
+                    None
+                } else {
+                    let permit = sem.acquire().await.expect("semaphore closed");
+                    if cancel.is_cancelled() {
+                        return;
+                    }
+                    Some(permit)
+                };
 
-                let result = yahoo_clone.get_price_on_date_with_fallback(&ticker, date).await;
+                let prices = range.fetch(&chain_clone, std::slice::from_ref(&ticker)).await;
                 let _ = sender
                     .send(BenchmarkPriceResult {
+                        ticker,
+                        date,
                         trade_indices: tx_ids,
-                        result,
+                        prices,
                     })
                     .await;
             });
         }
-        drop(tx3);
+        drop(tx2);
 
         let mut benchmark_enriched = 0usize;
         let mut benchmark_skipped = 0usize;
-        let mut breaker3 = CircuitBreaker::new(CIRCUIT_BREAKER_THRESHOLD);
+        let mut breaker2 = CircuitBreaker::new(CIRCUIT_BREAKER_THRESHOLD);
 
-        while let Some(fetch) = rx3.recv().await {
-            match fetch.result {
-                Ok(Some(price)) => {
+        while let Some(fetch) = rx2.recv().await {
+            cache.store(&fetch.ticker, &fetch.prices)?;
+            match fetch.prices.price_on(fetch.date) {
+                Some((price, _)) => {
                     for tx_id in &fetch.trade_indices {
                         db.update_benchmark_price(*tx_id, Some(price))?;
                         benchmark_enriched += 1;
                     }
-                    breaker3.record_success();
+                    breaker2.record_success();
                 }
-                Ok(None) | Err(_) => {
+                None => {
                     // Mark as processed to avoid re-fetch
                     for tx_id in &fetch.trade_indices {
                         db.update_benchmark_price(*tx_id, None)?;
                         benchmark_skipped += 1;
                     }
-                    breaker3.record_failure();
+                    breaker2.record_failure();
                 }
             }
-            pb3.set_message(format!("{} ok, {} skip", benchmark_enriched, benchmark_skipped));
-            pb3.inc(1);
+            pb2.set_message(format!("{} ok, {} skip", benchmark_enriched, benchmark_skipped));
+            pb2.inc(1);
 
-            if breaker3.is_tripped() {
-                pb3.println(format!(
-                    "Circuit breaker tripped after {} consecutive failures, stopping Phase 3",
+            if breaker2.is_tripped() {
+                pb2.println(format!(
+                    "Circuit breaker tripped after {} consecutive failures, stopping Phase 2",
                     CIRCUIT_BREAKER_THRESHOLD
                 ));
-                join_set3.abort_all();
+                join_set2.abort_all();
                 break;
             }
         }
 
-        pb3.finish_with_message(format!(
-            "Phase 3 done: {} enriched, {} skipped",
+        pb2.finish_with_message(format!(
+            "Phase 2 done: {} enriched, {} skipped",
             benchmark_enriched, benchmark_skipped
         ));
 
-        (benchmark_enriched, benchmark_skipped, breaker3.is_tripped())
+        (benchmark_enriched, benchmark_skipped, breaker2.is_tripped())
     };
 
     // Step 5: Summary
     eprintln!();
     eprintln!(
-        "Price enrichment complete: {} enriched, {} failed, {} skipped (historical)",
-        enriched, failed, skipped + skipped_parse_errors
+        "Price enrichment {}: {} enriched, {} failed, {} skipped (historical)",
+        if cancel.is_cancelled() { "interrupted" } else { "complete" },
+        tally.enriched,
+        tally.failed,
+        tally.skipped + skipped_parse_errors
     );
-    if tiingo_resolved > 0 {
+    if cancel.is_cancelled() {
+        eprintln!("  Trades not reached stay unenriched; re-run to continue");
+    }
+    if tally.tiingo_resolved > 0 {
         eprintln!(
             "  Tiingo fallback: {} trades resolved via Tiingo",
-            tiingo_resolved
+            tally.tiingo_resolved
+        );
+    }
+    if suffix_resolved > 0 {
+        eprintln!(
+            "  Exchange suffixes: {} foreign tickers resolved and saved as ticker aliases",
+            suffix_resolved
         );
     }
     eprintln!(
-        "  Phase 2: {} current prices enriched, {} skipped",
-        current_enriched, current_skipped
+        "  Disclosure-date prices: {} enriched; current prices: {} enriched, {} skipped",
+        tally.pub_enriched, tally.current_enriched, tally.current_skipped
     );
     eprintln!(
-        "  Phase 3: {} benchmark prices enriched, {} skipped",
+        "  Phase 2: {} benchmark prices enriched, {} skipped",
         benchmark_enriched, benchmark_skipped
     );
     eprintln!(
         "  ({} total trades, {} unique ticker-date pairs, {} unique tickers)",
         total_trades, unique_pairs, unique_tickers
     );
+    if !tally.fx_unconverted.is_empty() {
+        eprintln!(
+            "  Warning: no FX rate for {} foreign tickers; their prices were stored in local currency: {}",
+            tally.fx_unconverted.len(),
+            tally.fx_unconverted.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
 
-    if breaker.is_tripped() || breaker3_tripped {
+    if breaker.is_tripped() || breaker2_tripped {
         eprintln!(
             "Warning: Circuit breaker tripped after {} consecutive failures -- some trades were not processed",
             CIRCUIT_BREAKER_THRESHOLD
@@ -5,8 +5,11 @@ use std::path::PathBuf;
 use anyhow::{bail, Result};
 use capitoltraders_lib::types::IssuerDetail;
 use capitoltraders_lib::validation;
-use capitoltraders_lib::{Db, DbIssuerFilter, ScrapeClient, ScrapedIssuerDetail, ScrapedIssuerList};
-use clap::Args;
+use capitoltraders_lib::{
+    Db, DbIssuerFilter, IssuerScreen, ScrapeClient, ScrapedIssuerDetail, ScrapedIssuerList,
+    TradeStore,
+};
+use clap::{Args, Subcommand};
 
 use crate::output::{
     print_db_issuers_csv, print_db_issuers_markdown, print_db_issuers_table,
@@ -70,6 +73,96 @@ pub struct IssuersArgs {
     /// Maximum results to return (DB mode only)
     #[arg(long)]
     pub limit: Option<i64>,
+
+    #[command(subcommand)]
+    pub action: Option<IssuersAction>,
+}
+
+#[derive(Subcommand)]
+pub enum IssuersAction {
+    /// Screen issuers in the local DB by stored performance and trading activity
+    Screen(ScreenArgs),
+    /// Merge a duplicate issuer_id into the canonical one for the same company
+    Merge(MergeArgs),
+}
+
+/// Arguments for `issuers merge`.
+#[derive(Args)]
+pub struct MergeArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Duplicate issuer_id to fold away
+    #[arg(long)]
+    pub from: i64,
+
+    /// Canonical issuer_id that keeps the trades
+    #[arg(long)]
+    pub to: i64,
+
+    /// Print the affected row counts without changing the database
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Merge even when the two issuers have different tickers
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for `issuers screen`.
+///
+/// Performance bounds are inclusive and compare against the stored percent
+/// changes (e.g. 13.2 means +13.2%).
+#[derive(Args)]
+pub struct ScreenArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Minimum trailing 30-day % change
+    #[arg(long)]
+    pub trailing30_min: Option<f64>,
+
+    /// Maximum trailing 30-day % change
+    #[arg(long)]
+    pub trailing30_max: Option<f64>,
+
+    /// Minimum trailing 90-day % change
+    #[arg(long)]
+    pub trailing90_min: Option<f64>,
+
+    /// Maximum trailing 90-day % change
+    #[arg(long)]
+    pub trailing90_max: Option<f64>,
+
+    /// Minimum trailing 365-day % change
+    #[arg(long)]
+    pub trailing365_min: Option<f64>,
+
+    /// Maximum trailing 365-day % change
+    #[arg(long)]
+    pub trailing365_max: Option<f64>,
+
+    /// Minimum market cap in dollars
+    #[arg(long)]
+    pub mcap_min: Option<i64>,
+
+    /// Maximum market cap in dollars
+    #[arg(long)]
+    pub mcap_max: Option<i64>,
+
+    /// Minimum number of distinct politicians trading the issuer
+    #[arg(long)]
+    pub min_politicians: Option<i64>,
+
+    /// Only issuers last traded within this many days
+    #[arg(long)]
+    pub traded_within: Option<i64>,
+
+    /// Maximum results to return
+    #[arg(long)]
+    pub limit: Option<i64>,
 }
 
 /// Executes the issuers subcommand: validates inputs, scrapes results,
@@ -217,7 +310,7 @@ fn scraped_issuer_detail_to_detail(detail: &ScrapedIssuerDetail) -> Result<Issue
 /// Executes the issuers subcommand against the local SQLite database.
 ///
 /// Builds a [`DbIssuerFilter`] from the subset of CLI flags supported on the
-/// DB path, then calls [`Db::query_issuers`] and dispatches to output formatting.
+/// DB path, then calls [`TradeStore::query_issuers`] and dispatches to output formatting.
 pub fn run_db(
     args: &IssuersArgs,
     db_path: &std::path::Path,
@@ -239,8 +332,16 @@ pub fn run_db(
         }
     }
 
-    let db = Db::open(db_path)?;
+    let db = Db::open_read_only(db_path)?;
+    print_from_store(args, &db, format)
+}
 
+/// Builds the filter, queries `store`, and prints the issuer rows.
+fn print_from_store(
+    args: &IssuersArgs,
+    store: &impl TradeStore,
+    format: &OutputFormat,
+) -> Result<()> {
     let mut filter = DbIssuerFilter::default();
 
     if let Some(ref search) = args.search {
@@ -279,7 +380,7 @@ pub fn run_db(
         filter.limit = Some(limit);
     }
 
-    let rows = db.query_issuers(&filter)?;
+    let rows = store.query_issuers(&filter)?;
     eprintln!("{} issuers", rows.len());
 
     match format {
@@ -293,6 +394,106 @@ pub fn run_db(
     Ok(())
 }
 
+/// Merge one issuer into another (or preview it with `--dry-run`).
+pub fn run_merge(args: &MergeArgs) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    db.init()?;
+
+    let report = if args.dry_run {
+        db.preview_issuer_merge(args.from, args.to, args.force)?
+    } else {
+        db.merge_issuers(args.from, args.to, args.force)?
+    };
+
+    let verb = if args.dry_run { "Would merge" } else { "Merged" };
+    eprintln!("{} issuer {} into {}:", verb, args.from, args.to);
+    eprintln!("  trades re-pointed:     {}", report.trades_repointed);
+    eprintln!(
+        "  issuer stats:          {}",
+        if report.stats_merged { "summed into target" } else { "none on source" }
+    );
+    eprintln!(
+        "  performance:           {}",
+        if report.performance_moved { "moved (target had none)" } else { "target kept" }
+    );
+    eprintln!(
+        "  EOD prices:            {} moved, {} dropped (target already had the date)",
+        report.eod_prices_moved, report.eod_prices_dropped
+    );
+    if report.aliases_repointed > 0 {
+        eprintln!("  earlier merges:        {} re-pointed", report.aliases_repointed);
+    }
+    if args.dry_run {
+        eprintln!("Dry run: no changes written.");
+    }
+    Ok(())
+}
+
+/// Executes `issuers screen` against the local SQLite database.
+pub fn run_screen(args: &ScreenArgs, format: &OutputFormat) -> Result<()> {
+    let bounds = [
+        ("--trailing30", args.trailing30_min, args.trailing30_max),
+        ("--trailing90", args.trailing90_min, args.trailing90_max),
+        ("--trailing365", args.trailing365_min, args.trailing365_max),
+    ];
+    for (flag, min, max) in bounds {
+        if let (Some(lo), Some(hi)) = (min, max) {
+            if lo > hi {
+                bail!("{}-min ({}) must not exceed {}-max ({})", flag, lo, flag, hi);
+            }
+        }
+    }
+    if let (Some(lo), Some(hi)) = (args.mcap_min, args.mcap_max) {
+        if lo > hi {
+            bail!("--mcap-min ({}) must not exceed --mcap-max ({})", lo, hi);
+        }
+    }
+    if let Some(n) = args.min_politicians {
+        if n < 0 {
+            bail!("--min-politicians must be non-negative");
+        }
+    }
+    if let Some(days) = args.traded_within {
+        if days <= 0 {
+            bail!("--traded-within must be a positive number of days");
+        }
+    }
+
+    let db = Db::open_read_only(&args.db)?;
+    let criteria = IssuerScreen {
+        trailing30_change_min: args.trailing30_min,
+        trailing30_change_max: args.trailing30_max,
+        trailing90_change_min: args.trailing90_min,
+        trailing90_change_max: args.trailing90_max,
+        trailing365_change_min: args.trailing365_min,
+        trailing365_change_max: args.trailing365_max,
+        mcap_min: args.mcap_min,
+        mcap_max: args.mcap_max,
+        min_politicians: args.min_politicians,
+        traded_within_days: args.traded_within,
+        limit: args.limit,
+    };
+
+    let result = db.screen_issuers(&criteria)?;
+    eprintln!("{} issuers match", result.rows.len());
+    if result.excluded_no_performance > 0 {
+        eprintln!(
+            "Excluded {} issuers with no performance data (run 'capitoltraders sync --enrich' to populate)",
+            result.excluded_no_performance
+        );
+    }
+
+    match format {
+        OutputFormat::Table => print_db_issuers_table(&result.rows),
+        OutputFormat::Json => print_json(&result.rows),
+        OutputFormat::Csv => print_db_issuers_csv(&result.rows)?,
+        OutputFormat::Markdown => print_db_issuers_markdown(&result.rows),
+        OutputFormat::Xml => print_db_issuers_xml(&result.rows),
+    }
+
+    Ok(())
+}
+
 fn normalize_performance(value: Option<serde_json::Value>) -> serde_json::Value {
     let Some(value) = value else {
         return serde_json::Value::Null;
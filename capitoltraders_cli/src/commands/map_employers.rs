@@ -1,14 +1,23 @@
 //! The `map-employers` subcommand: export/import/load-seed for employer-to-issuer mappings.
+//!
+//! The `export`/`import` actions drive the review workflow for unmatched
+//! employers. The `--export`/`--import` flags instead back up and restore the
+//! whole mapping table (with its raw-name lookups) so curated mappings can
+//! move between databases.
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use capitoltraders_lib::{
-    employer_mapping::{is_blacklisted, load_seed_data, match_employer, normalize_employer},
-    Db,
+    employer_mapping::{
+        is_blacklisted, load_seed_data, match_employer, normalize_employer,
+        parse_employer_mappings_csv, write_employer_mappings_csv,
+    },
+    Db, OnConflict,
 };
 use clap::{Args, Subcommand};
 use csv::{Reader, Writer};
 use serde::Serialize;
-use std::path::PathBuf;
+use std::fs::File;
+use std::path::{Path, PathBuf};
 
 use crate::output::sanitize_csv_field;
 
@@ -19,8 +28,21 @@ pub struct MapEmployersArgs {
     #[arg(long)]
     pub db: PathBuf,
 
+    /// Write every stored employer mapping to this CSV file
+    #[arg(long, value_name = "FILE", conflicts_with = "import")]
+    pub export: Option<PathBuf>,
+
+    /// Merge employer mappings from a CSV file written by --export
+    #[arg(long, value_name = "FILE")]
+    pub import: Option<PathBuf>,
+
+    /// On --import, what to do when a mapping already exists with different
+    /// values: keep-existing, overwrite, or error (default: keep-existing)
+    #[arg(long, default_value = "keep-existing", requires = "import")]
+    pub on_conflict: String,
+
     #[command(subcommand)]
-    pub action: MapEmployersAction,
+    pub action: Option<MapEmployersAction>,
 }
 
 #[derive(Subcommand)]
@@ -79,13 +101,52 @@ pub fn run(args: &MapEmployersArgs) -> Result<()> {
     let db = Db::open(&args.db)?;
     db.init()?;
 
+    if args.action.is_some() && (args.export.is_some() || args.import.is_some()) {
+        bail!("--export/--import cannot be combined with a map-employers action");
+    }
+    if let Some(path) = &args.export {
+        return export_mappings(&db, path);
+    }
+    if let Some(path) = &args.import {
+        return import_mappings(&db, path, &args.on_conflict);
+    }
+
     match &args.action {
-        MapEmployersAction::Export(export_args) => run_export(&db, export_args),
-        MapEmployersAction::Import(import_args) => run_import(&db, import_args),
-        MapEmployersAction::LoadSeed(seed_args) => run_load_seed(&db, seed_args),
+        Some(MapEmployersAction::Export(export_args)) => run_export(&db, export_args),
+        Some(MapEmployersAction::Import(import_args)) => run_import(&db, import_args),
+        Some(MapEmployersAction::LoadSeed(seed_args)) => run_load_seed(&db, seed_args),
+        None => bail!("Specify an action (export, import, load-seed) or --export/--import FILE"),
     }
 }
 
+fn export_mappings(db: &Db, path: &Path) -> Result<()> {
+    let records = db.export_employer_mappings()?;
+    let file = File::create(path)?;
+    write_employer_mappings_csv(file, &records)?;
+    eprintln!(
+        "Exported {} employer mappings to {}",
+        records.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn import_mappings(db: &Db, path: &Path, on_conflict: &str) -> Result<()> {
+    let on_conflict: OnConflict = on_conflict.parse().map_err(|e: String| anyhow!(e))?;
+    let records = parse_employer_mappings_csv(File::open(path)?)
+        .map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+    let report = db.import_employer_mappings(&records, on_conflict)?;
+    eprintln!(
+        "Imported {} employer mappings from {}: {} new, {} overwritten, {} kept",
+        records.len(),
+        path.display(),
+        report.inserted,
+        report.overwritten,
+        report.kept
+    );
+    Ok(())
+}
+
 fn run_export(db: &Db, args: &ExportArgs) -> Result<()> {
     // Validate threshold
     if !(0.0..=1.0).contains(&args.threshold) {
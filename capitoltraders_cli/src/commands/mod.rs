@@ -1,15 +1,23 @@
 //! CLI subcommand implementations.
 
+pub mod aliases;
 pub mod analytics;
 pub mod anomalies;
+pub mod config;
 pub mod conflicts;
+pub mod db;
 pub mod donations;
+pub mod enrich;
 pub mod enrich_prices;
 pub mod issuers;
 pub mod map_employers;
 pub mod politicians;
 pub mod portfolio;
+pub mod report;
+pub mod schema;
+pub mod snapshot;
 pub mod sync;
 pub mod sync_donations;
 pub mod sync_fec;
 pub mod trades;
+pub mod watch;
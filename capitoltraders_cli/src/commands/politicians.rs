@@ -5,13 +5,16 @@ use std::path::PathBuf;
 use anyhow::{bail, Result};
 use capitoltraders_lib::analytics::{
     aggregate_politician_metrics, calculate_closed_trades, compute_trade_metrics, AnalyticsTrade,
-    PoliticianMetrics,
+    IntervalConfig, PoliticianMetrics,
 };
 use capitoltraders_lib::types::PoliticianDetail;
 use capitoltraders_lib::validation;
-use capitoltraders_lib::{Db, DbPoliticianFilter, DbPoliticianRow, ScrapeClient, ScrapedPoliticianCard};
+use capitoltraders_lib::{
+    Db, DbPoliticianFilter, DbPoliticianRow, ScrapeClient, ScrapedPoliticianCard, TradeStore,
+};
 use chrono::NaiveDate;
 use clap::Args;
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -60,7 +63,7 @@ pub struct PoliticiansArgs {
     pub page_size: i64,
 
     /// Sort field: volume, name, issuers, trades, last-traded
-    #[arg(long, default_value = "volume")]
+    #[arg(long, visible_alias = "sort", default_value = "volume")]
     pub sort_by: String,
 
     /// Sort ascending instead of descending
@@ -70,13 +73,17 @@ pub struct PoliticiansArgs {
     /// Read politicians from local SQLite database (requires prior sync)
     #[arg(long)]
     pub db: Option<PathBuf>,
+
+    /// Also list members with no trades (populated by `sync --politicians-only`)
+    #[arg(long, requires = "db")]
+    pub include_inactive: bool,
 }
 
 /// Enriched politician row with optional analytics summary fields.
 ///
 /// Extends [`DbPoliticianRow`] with closed_trades, avg_return, win_rate, and percentile.
 /// All analytics fields are Option types for backward compatibility.
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Clone, JsonSchema)]
 pub struct EnrichedDbPoliticianRow {
     // Base DbPoliticianRow fields
     pub politician_id: String,
@@ -215,44 +222,33 @@ pub async fn run(
     Ok(())
 }
 
-/// Capitalize a validated party string to match DB storage format.
-///
-/// The validation module returns lowercase ("democrat", "republican", "other")
-/// but the database stores the capitalized form from the API ("Democrat", etc.).
-fn capitalize_party(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(c) => c.to_uppercase().to_string() + chars.as_str(),
-    }
-}
-
 /// Executes the politicians subcommand against the local SQLite database.
 ///
 /// Builds a [`DbPoliticianFilter`] from the subset of CLI flags supported on the
-/// DB path, then calls [`Db::query_politicians`] and dispatches to output formatting.
+/// DB path, then calls [`TradeStore::query_politicians`] and dispatches to output formatting.
 pub async fn run_db(
     args: &PoliticiansArgs,
     db_path: &std::path::Path,
     format: &OutputFormat,
 ) -> Result<()> {
     // Bail on filters not supported by the DB query path
-    let unsupported: &[(&str, bool)] = &[
-        ("--committee", args.committee.is_some()),
-        ("--issuer-id", args.issuer_id.is_some()),
-    ];
-    for (flag, present) in unsupported {
-        if *present {
-            bail!(
-                "{} is not supported with --db. Supported filters: \
-                 --party, --state, --name, --chamber",
-                flag
-            );
-        }
+    if args.issuer_id.is_some() {
+        bail!(
+            "--issuer-id is not supported with --db. Supported filters: \
+             --party, --state, --name, --committee"
+        );
     }
 
-    let db = Db::open(db_path)?;
+    let db = Db::open_read_only(db_path)?;
+    print_from_store(args, &db, format)
+}
 
+/// Builds the filter, queries `store`, and prints the analytics-enriched rows.
+fn print_from_store(
+    args: &PoliticiansArgs,
+    store: &impl TradeStore,
+    format: &OutputFormat,
+) -> Result<()> {
     // Build filter from supported args
     let mut filter = DbPoliticianFilter::default();
 
@@ -260,7 +256,7 @@ pub async fn run_db(
         let mut parts = Vec::new();
         for item in val.split(',') {
             let validated = validation::validate_party(item.trim())?;
-            parts.push(capitalize_party(&validated.to_string()));
+            parts.push(validated.to_string());
         }
         filter.party = Some(parts.join(","));
     }
@@ -280,13 +276,24 @@ pub async fn run_db(
         filter.name = Some(validated.to_string());
     }
 
+    if let Some(ref val) = args.committee {
+        let mut parts = Vec::new();
+        for item in val.split(',') {
+            parts.push(validation::validate_committee(item)?);
+        }
+        filter.committee = Some(parts.join(","));
+    }
+
+    filter.sort_by = args.sort_by.parse().map_err(|e: String| anyhow::anyhow!(e))?;
+    filter.ascending = args.asc;
+    filter.include_inactive = args.include_inactive;
     filter.limit = Some(args.page_size);
 
-    let rows = db.query_politicians(&filter)?;
+    let rows = store.query_politicians(&filter)?;
     eprintln!("{} politicians from database", rows.len());
 
     // Best-effort analytics enrichment: compute politician performance metrics
-    let metrics_map: HashMap<String, PoliticianMetrics> = match load_politician_analytics(&db) {
+    let metrics_map: HashMap<String, PoliticianMetrics> = match load_politician_analytics(store) {
         Ok(map) => map,
         Err(e) => {
             eprintln!(
@@ -318,9 +325,11 @@ pub async fn run_db(
 ///
 /// Returns a HashMap keyed by politician_id with aggregated performance metrics.
 /// Best-effort: returns error if price enrichment data is unavailable.
-fn load_politician_analytics(db: &Db) -> Result<HashMap<String, PoliticianMetrics>> {
+fn load_politician_analytics(
+    store: &impl TradeStore,
+) -> Result<HashMap<String, PoliticianMetrics>> {
     // Query price-enriched trades for analytics
-    let analytics_rows = db.query_trades_for_analytics()?;
+    let analytics_rows = store.query_trades_for_analytics()?;
     if analytics_rows.is_empty() {
         bail!("no price-enriched trades available");
     }
@@ -349,7 +358,7 @@ fn load_politician_analytics(db: &Db) -> Result<HashMap<String, PoliticianMetric
     let all_metrics: Vec<_> = closed_trades.iter().map(compute_trade_metrics).collect();
 
     // Aggregate by politician
-    let politician_metrics = aggregate_politician_metrics(&all_metrics);
+    let politician_metrics = aggregate_politician_metrics(&all_metrics, &IntervalConfig::default());
 
     // Build HashMap keyed by politician_id
     let metrics_map: HashMap<String, PoliticianMetrics> = politician_metrics
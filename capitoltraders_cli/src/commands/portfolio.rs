@@ -1,17 +1,25 @@
-//! The `portfolio` subcommand: displays per-politician stock positions with P&L.
+//! The `portfolio` subcommand: displays per-politician stock positions with P&L,
+//! or bond and treasury exposure with `--fixed-income`.
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use capitoltraders_lib::committee_jurisdiction::load_committee_jurisdictions;
-use capitoltraders_lib::portfolio::calculate_positions;
-use capitoltraders_lib::{validation, Db, PortfolioFilter, PortfolioPosition};
+use capitoltraders_lib::portfolio::{calculate_positions, is_default_asset_types};
+use capitoltraders_lib::{
+    pricing, validation, Db, PortfolioFilter, PortfolioPosition, PriceSource, TradeStore,
+    DEFAULT_PORTFOLIO_ASSET_TYPES, GICS_SECTORS,
+};
 use clap::Args;
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+use crate::hints;
 use crate::output::{
     print_enriched_portfolio_csv, print_enriched_portfolio_markdown,
-    print_enriched_portfolio_table, print_enriched_portfolio_xml, print_json, OutputFormat,
+    print_enriched_portfolio_table, print_enriched_portfolio_xml, print_fixed_income_csv,
+    print_fixed_income_markdown, print_fixed_income_table, print_fixed_income_xml, print_json,
+    OutputFormat,
 };
 
 /// Arguments for the `portfolio` subcommand.
@@ -40,6 +48,14 @@ pub struct PortfolioArgs {
     #[arg(long)]
     pub ticker: Option<String>,
 
+    /// Filter by GICS sector (e.g., energy, health-care, "Information Technology")
+    #[arg(long)]
+    pub sector: Option<String>,
+
+    /// Minimum position value in dollars (current value, or cost when unpriced)
+    #[arg(long)]
+    pub min_value: Option<f64>,
+
     /// Include closed positions (shares near zero)
     #[arg(long)]
     pub include_closed: bool,
@@ -51,13 +67,32 @@ pub struct PortfolioArgs {
     /// Show FIFO oversold position warnings (hidden by default)
     #[arg(long)]
     pub verbose: bool,
+
+    /// Asset types the FIFO positions are built from: stock,etf,... --
+    /// comma-separated (default: stock,unknown). Bonds are not accepted; see
+    /// --fixed-income
+    #[arg(long)]
+    pub asset_type: Option<String>,
+
+    /// Summarize bond and treasury exposure by politician at face value
+    /// instead of showing stock positions
+    #[arg(
+        long,
+        conflicts_with_all = ["ticker", "sector", "min_value", "include_closed", "asset_type", "as_of"]
+    )]
+    pub fixed_income: bool,
+
+    /// Reconstruct positions as of this date (YYYY-MM-DD) from trades made on
+    /// or before it, valued at the last end-of-day close on or before it
+    #[arg(long)]
+    pub as_of: Option<String>,
 }
 
 /// Enriched portfolio position with optional conflict detection fields.
 ///
 /// Extends [`PortfolioPosition`] with gics_sector and in_committee_sector flag.
 /// All conflict fields are Option types for backward compatibility.
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Clone, JsonSchema)]
 pub struct EnrichedPortfolioPosition {
     // Base PortfolioPosition fields
     pub politician_id: String,
@@ -68,6 +103,11 @@ pub struct EnrichedPortfolioPosition {
     pub current_value: Option<f64>,
     pub unrealized_pnl: Option<f64>,
     pub unrealized_pnl_pct: Option<f64>,
+    /// Date of `current_price`.
+    pub price_date: Option<String>,
+    /// `current` (latest enriched price) or `eod_close` (with `--as-of`);
+    /// null when unpriced.
+    pub price_source: Option<PriceSource>,
     // Conflict enrichment fields
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gics_sector: Option<String>,
@@ -86,21 +126,41 @@ impl From<PortfolioPosition> for EnrichedPortfolioPosition {
             current_value: pos.current_value,
             unrealized_pnl: pos.unrealized_pnl,
             unrealized_pnl_pct: pos.unrealized_pnl_pct,
-            gics_sector: None,
+            price_date: pos.price_date,
+            price_source: pos.price_source,
+            gics_sector: pos.gics_sector,
             in_committee_sector: None,
         }
     }
 }
 
 pub fn run(args: &PortfolioArgs, format: &OutputFormat) -> Result<()> {
+    // Read-write, unlike the other query commands: positions are recomputed
+    // and stored on every run.
     let db = Db::open(&args.db)?;
 
-    // Compute FIFO positions from trades and persist to positions table
-    let trades = db.query_trades_for_portfolio()?;
-    if !trades.is_empty() {
+    let as_of = match args.as_of {
+        Some(ref val) => Some(
+            validation::validate_date(val.trim())
+                .map_err(|e| anyhow::anyhow!("Invalid --as-of value: {}", e))?,
+        ),
+        None => None,
+    };
+    let asset_types = match args.asset_type {
+        Some(ref val) => parse_portfolio_asset_types(val)?,
+        None => DEFAULT_PORTFOLIO_ASSET_TYPES.iter().map(|t| t.to_string()).collect(),
+    };
+
+    // Compute FIFO positions from trades and persist to positions table.
+    // Historical positions and other asset-type sets are rebuilt by
+    // get_portfolio instead, so the stored positions stay the default view.
+    if !args.fixed_income && as_of.is_none() && is_default_asset_types(&asset_types) {
+        let trades = db.query_trades_for_portfolio_of(DEFAULT_PORTFOLIO_ASSET_TYPES)?;
         let positions = calculate_positions(trades, args.verbose);
-        let count = db.upsert_positions(&positions)?;
-        eprintln!("Computed {} FIFO positions from trade data", count);
+        let count = db.replace_positions(&positions)?;
+        if count > 0 {
+            eprintln!("Computed {} FIFO positions from trade data", count);
+        }
     }
 
     // Validate filters
@@ -119,33 +179,68 @@ pub fn run(args: &PortfolioArgs, format: &OutputFormat) -> Result<()> {
         None => None,
     };
 
+    let mut unknown_ticker = None;
     let ticker = match args.ticker.as_ref() {
         Some(t) => {
             let input = t.trim().to_uppercase();
             // Resolve bare ticker to DB format (e.g., AAPL -> AAPL:US)
             match db.find_issuer_ticker(&input)? {
                 Some(resolved) => Some(resolved),
-                None => Some(input), // pass through as-is; query will return empty
+                None => {
+                    // pass through as-is; query will return empty
+                    unknown_ticker = Some(input.clone());
+                    Some(input)
+                }
             }
         }
         None => None,
     };
 
+    let sector = match args.sector {
+        Some(ref val) => Some(resolve_gics_sector(val)?.to_string()),
+        None => None,
+    };
+
+    if let Some(min_value) = args.min_value {
+        if !min_value.is_finite() || min_value < 0.0 {
+            anyhow::bail!("--min-value must be a non-negative number");
+        }
+    }
+
     let filter = PortfolioFilter {
         politician_id,
         ticker,
         party,
         state,
+        sector,
+        min_value: args.min_value,
         include_closed: args.include_closed,
+        as_of,
+        asset_types: Some(asset_types),
     };
 
-    let positions = db.get_portfolio(&filter)?;
+    if args.fixed_income {
+        return print_fixed_income(&db, &filter, format);
+    }
+
+    let positions = TradeStore::get_portfolio(&db, &filter)?;
 
     if positions.is_empty() {
         eprintln!("No portfolio positions found matching the given filters.");
+        if let Some(hint) = unknown_ticker.and_then(|t| hints::issuer_hint(&db, &t)) {
+            eprintln!("{}", hint);
+            return Ok(());
+        }
         eprintln!("Hint: Run 'capitoltraders sync' then 'capitoltraders enrich-prices' first.");
         return Ok(());
     }
+    if let Some(date) = as_of {
+        let unpriced = positions.iter().filter(|p| p.price_source.is_none()).count();
+        eprintln!(
+            "Positions as of {}, valued at the last end-of-day close on or before that date ({} unpriced)",
+            date, unpriced
+        );
+    }
 
     // Best-effort conflict enrichment: load committee jurisdictions and sector data
     let enriched_positions = match enrich_portfolio_with_conflicts(&db, positions) {
@@ -156,7 +251,7 @@ pub fn run(args: &PortfolioArgs, format: &OutputFormat) -> Result<()> {
                 e
             );
             // Fall back to unenriched positions
-            db.get_portfolio(&filter)?
+            TradeStore::get_portfolio(&db, &filter)?
                 .into_iter()
                 .map(EnrichedPortfolioPosition::from)
                 .collect()
@@ -227,6 +322,53 @@ pub fn run(args: &PortfolioArgs, format: &OutputFormat) -> Result<()> {
     Ok(())
 }
 
+/// Print `portfolio --fixed-income`: face-value bond exposure per politician.
+fn print_fixed_income(db: &Db, filter: &PortfolioFilter, format: &OutputFormat) -> Result<()> {
+    let rows = db.fixed_income_exposure(filter)?;
+    if rows.is_empty() {
+        eprintln!("No face-valued bond or treasury trades found matching the given filters.");
+        eprintln!("Hint: Run 'capitoltraders enrich-prices' to value fixed-income trades.");
+        return Ok(());
+    }
+    match format {
+        OutputFormat::Table => print_fixed_income_table(&rows),
+        OutputFormat::Json => print_json(&rows),
+        OutputFormat::Csv => print_fixed_income_csv(&rows)?,
+        OutputFormat::Markdown => print_fixed_income_markdown(&rows),
+        OutputFormat::Xml => print_fixed_income_xml(&rows),
+    }
+    Ok(())
+}
+
+/// Parse `--asset-type` for the FIFO portfolio. `unknown` (trades scraped
+/// before their type was known) is accepted alongside the API's asset types;
+/// fixed-income types are rejected because they carry no share estimates.
+fn parse_portfolio_asset_types(input: &str) -> Result<Vec<String>> {
+    let mut types = Vec::new();
+    for item in input.split(',') {
+        let item = item.trim().to_lowercase();
+        if item.is_empty() {
+            continue;
+        }
+        if pricing::is_fixed_income(&item) {
+            bail!(
+                "{} trades are valued at face value, not as positions; use --fixed-income",
+                item
+            );
+        }
+        if item != "unknown" {
+            validation::validate_asset_type(&item)?;
+        }
+        if !types.contains(&item) {
+            types.push(item);
+        }
+    }
+    if types.is_empty() {
+        bail!("--asset-type needs at least one asset type");
+    }
+    Ok(types)
+}
+
 /// Enrich portfolio positions with conflict detection data.
 ///
 /// Best-effort: loads sector data from issuers table and checks if sectors
@@ -238,17 +380,6 @@ fn enrich_portfolio_with_conflicts(
     // Load committee jurisdictions
     let jurisdictions = load_committee_jurisdictions()?;
 
-    // Build a set of unique tickers to query for sector data (bulk query to avoid N+1)
-    let tickers: Vec<String> = positions
-        .iter()
-        .map(|p| p.ticker.clone())
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
-
-    // Query all sectors in one go
-    let ticker_sectors: HashMap<String, Option<String>> = query_ticker_sectors(db, &tickers)?;
-
     // Group positions by politician_id to fetch committee data
     let politician_ids: Vec<String> = positions
         .iter()
@@ -267,17 +398,12 @@ fn enrich_portfolio_with_conflicts(
         .map(|pos| {
             let mut enriched = EnrichedPortfolioPosition::from(pos);
 
-            // Look up sector for this ticker
-            if let Some(sector_opt) = ticker_sectors.get(&enriched.ticker) {
-                enriched.gics_sector = sector_opt.clone();
-
-                // Check if sector is under politician's committee jurisdictions
-                if let Some(ref sector) = sector_opt {
-                    if let Some(committee_sectors) =
-                        politician_committee_sectors.get(&enriched.politician_id)
-                    {
-                        enriched.in_committee_sector = Some(committee_sectors.contains(sector));
-                    }
+            // Check if the position's sector is under politician's committee jurisdictions
+            if let Some(ref sector) = enriched.gics_sector {
+                if let Some(committee_sectors) =
+                    politician_committee_sectors.get(&enriched.politician_id)
+                {
+                    enriched.in_committee_sector = Some(committee_sectors.contains(sector));
                 }
             }
 
@@ -288,27 +414,21 @@ fn enrich_portfolio_with_conflicts(
     Ok(enriched)
 }
 
-/// Query gics_sector for all tickers from the issuers table.
-///
-/// Uses individual queries for simplicity (small N, acceptable for CLI).
-fn query_ticker_sectors(
-    db: &Db,
-    tickers: &[String],
-) -> Result<HashMap<String, Option<String>>> {
-    let conn = db.conn();
-    let mut map = HashMap::new();
-
-    for ticker in tickers {
-        let mut stmt = conn.prepare("SELECT gics_sector FROM issuers WHERE issuer_ticker = ?1")?;
-        let sector_result = stmt.query_row([ticker], |row| row.get::<_, Option<String>>(0));
-
-        // Handle not found gracefully (ticker might not be in issuers table)
-        let sector = sector_result.unwrap_or_default();
-
-        map.insert(ticker.clone(), sector);
-    }
-
-    Ok(map)
+/// Match user input against [`GICS_SECTORS`], ignoring case and accepting
+/// hyphens for spaces (`health-care` -> `Health Care`).
+fn resolve_gics_sector(input: &str) -> Result<&'static str> {
+    let normalized = input.trim().replace('-', " ");
+    GICS_SECTORS
+        .iter()
+        .find(|sector| sector.eq_ignore_ascii_case(&normalized))
+        .copied()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "unknown GICS sector '{}'. Valid values: {}",
+                input,
+                GICS_SECTORS.join(", ")
+            )
+        })
 }
 
 /// Build a mapping of politician_id -> set of sectors under their committee jurisdictions.
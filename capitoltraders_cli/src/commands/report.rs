@@ -0,0 +1,238 @@
+//! The `report` subcommand: a shareable dossier for a single politician.
+//!
+//! Assembles bio, committees, top holdings, best/worst closed trades,
+//! disclosure timeliness, donation summary, and committee trading score from
+//! the local database. Sections without data are left out.
+
+use anyhow::{bail, Result};
+use capitoltraders_lib::{
+    analytics::{calculate_closed_trades, compute_trade_metrics},
+    committee_jurisdiction::load_committee_jurisdictions,
+    conflict::calculate_committee_trading_score,
+    Db, DbPoliticianFilter, DisclosureLateness, DonationSummary, PortfolioFilter, TradeUniverse,
+};
+use clap::Args;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::hints;
+use crate::output::{print_json, print_politician_report_markdown, OutputFormat};
+
+/// Arguments for the `report` subcommand.
+#[derive(Args)]
+pub struct ReportArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Politician name (partial match)
+    #[arg(long)]
+    pub politician: String,
+
+    /// Rows per holdings and trades section (default: 5)
+    #[arg(long, default_value = "5")]
+    pub top: usize,
+}
+
+/// Full dossier for one politician. Empty sections are omitted from JSON.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct PoliticianReport {
+    pub politician_id: String,
+    pub name: String,
+    pub party: String,
+    pub state: String,
+    pub chamber: String,
+    pub trades: i64,
+    pub issuers: i64,
+    pub volume: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_traded: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub committees: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub top_holdings: Vec<ReportHolding>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub best_trades: Vec<ReportClosedTrade>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub worst_trades: Vec<ReportClosedTrade>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disclosure: Option<DisclosureLateness>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donations: Option<DonationSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committee_score: Option<ReportCommitteeScore>,
+}
+
+/// An open position in the report's holdings section.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReportHolding {
+    pub ticker: String,
+    pub shares_held: f64,
+    pub cost_basis: f64,
+    pub current_value: Option<f64>,
+    pub unrealized_pnl_pct: Option<f64>,
+}
+
+/// A FIFO-matched round trip in the best/worst trades sections.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReportClosedTrade {
+    pub ticker: String,
+    pub buy_date: String,
+    pub sell_date: String,
+    pub return_pct: f64,
+    pub holding_days: Option<i64>,
+}
+
+/// Committee trading score summary.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ReportCommitteeScore {
+    pub total_scored_trades: usize,
+    pub committee_related_trades: usize,
+    pub committee_trading_pct: f64,
+}
+
+pub fn run(args: &ReportArgs, format: &OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Csv | OutputFormat::Xml) {
+        bail!("report supports --output md (or table) and json");
+    }
+
+    let db = Db::open(&args.db)?;
+    let matches = db.find_politician_by_name(&args.politician)?;
+    if matches.is_empty() {
+        bail!(
+            "{}",
+            hints::with_hint(
+                format!("No politician found matching name: '{}'", args.politician),
+                hints::politician_hint(&db, &args.politician)
+            )
+        );
+    }
+    if matches.len() > 1 {
+        eprintln!(
+            "Warning: Multiple politicians match '{}'. Using first match: {}",
+            args.politician, matches[0].1
+        );
+    }
+    let (politician_id, name) = matches[0].clone();
+
+    let report = build_report(&db, &politician_id, &name, args.top)?;
+    match format {
+        OutputFormat::Json => print_json(&report),
+        _ => print_politician_report_markdown(&report),
+    }
+    Ok(())
+}
+
+fn build_report(db: &Db, politician_id: &str, name: &str, top: usize) -> Result<PoliticianReport> {
+    let bio = db
+        .query_politicians(&DbPoliticianFilter {
+            name: Some(name.to_string()),
+            include_inactive: true,
+            ..Default::default()
+        })?
+        .into_iter()
+        .find(|p| p.politician_id == politician_id);
+    let Some(bio) = bio else {
+        bail!("Politician '{}' not found in database", politician_id);
+    };
+
+    let mut positions = db.get_portfolio(&PortfolioFilter {
+        politician_id: Some(politician_id.to_string()),
+        ..Default::default()
+    })?;
+    positions.sort_by(|a, b| {
+        let value = |p: &capitoltraders_lib::PortfolioPosition| {
+            p.current_value.unwrap_or(p.shares_held * p.cost_basis)
+        };
+        value(b)
+            .partial_cmp(&value(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let top_holdings = positions
+        .into_iter()
+        .take(top)
+        .map(|p| ReportHolding {
+            ticker: p.ticker,
+            shares_held: p.shares_held,
+            cost_basis: p.cost_basis,
+            current_value: p.current_value,
+            unrealized_pnl_pct: p.unrealized_pnl_pct,
+        })
+        .collect();
+
+    let analytics_trades = TradeUniverse::priced_stocks(db)
+        .for_politician(politician_id)
+        .build()?
+        .analytics_trades();
+    let closed_trades = calculate_closed_trades(analytics_trades, false);
+
+    let mut round_trips: Vec<ReportClosedTrade> = closed_trades
+        .iter()
+        .map(|closed| {
+            let metrics = compute_trade_metrics(closed);
+            ReportClosedTrade {
+                ticker: closed.ticker.clone(),
+                buy_date: closed.buy_date.clone(),
+                sell_date: closed.sell_date.clone(),
+                return_pct: metrics.absolute_return,
+                holding_days: metrics.holding_days,
+            }
+        })
+        .collect();
+    round_trips.sort_by(|a, b| {
+        b.return_pct
+            .partial_cmp(&a.return_pct)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    // Winners and losers only, so a short history never lists a trade twice.
+    let best_trades: Vec<_> = round_trips
+        .iter()
+        .filter(|t| t.return_pct > 0.0)
+        .take(top)
+        .cloned()
+        .collect();
+    let worst_trades: Vec<_> = round_trips
+        .iter()
+        .rev()
+        .filter(|t| t.return_pct < 0.0)
+        .take(top)
+        .cloned()
+        .collect();
+
+    let committee_score = if bio.committees.is_empty() {
+        None
+    } else {
+        let score = calculate_committee_trading_score(
+            &closed_trades,
+            &bio.committees,
+            &load_committee_jurisdictions()?,
+            politician_id.to_string(),
+            bio.name.clone(),
+        );
+        (score.total_scored_trades > 0).then_some(ReportCommitteeScore {
+            total_scored_trades: score.total_scored_trades,
+            committee_related_trades: score.committee_related_trades,
+            committee_trading_pct: score.committee_trading_pct,
+        })
+    };
+
+    Ok(PoliticianReport {
+        politician_id: bio.politician_id,
+        name: bio.name,
+        party: bio.party,
+        state: bio.state,
+        chamber: bio.chamber,
+        trades: bio.trades,
+        issuers: bio.issuers,
+        volume: bio.volume,
+        last_traded: bio.last_traded,
+        committees: bio.committees,
+        top_holdings,
+        best_trades,
+        worst_trades,
+        disclosure: db.disclosure_lateness(politician_id)?,
+        donations: db.get_donation_summary(politician_id)?,
+        committee_score,
+    })
+}
@@ -0,0 +1,421 @@
+//! The `schema` subcommand: JSON Schema (draft 2020-12) for `--output json`.
+//!
+//! Database-backed outputs are described by schemas generated from their row
+//! types, so a field added to `DbTradeRow` shows up here without any manual
+//! edit. Live-scrape outputs serialize the vendored API types and use the
+//! hand-maintained documents in `schema/`.
+
+use anyhow::{bail, Result};
+use capitoltraders_lib::{
+    analysis::{EventProximityRow, IndexPoint, OverlapPair}, conflict::HearingProximityRow, ContributorAggRow, JurisdictionSectorRow, CoverageRow,
+    DbIssuerRow, DonationGeography, DonationRow, EmployerAggRow, FixedIncomeExposureRow, LabelCount, LabelReport, OccupationAggRow, SessionGroupRow, SnapshotInfo,
+    RawDonation, RawFecMapping, RawIssuer, RawPolitician, RawPosition, RawTrade, StateAggRow,
+    TradeRevisionRow,
+};
+use capitoltraders_lib::snapshot::SnapshotDiff;
+use clap::Args;
+use schemars::{schema_for, JsonSchema};
+use serde_json::{json, Map, Value};
+
+use crate::commands::analytics::{LeaderboardReport, TaxEstimateReport, TradeDetailRow};
+use crate::commands::anomalies::{
+    AnomalyReport, CrowdedTradeRow, NovelTradeRow, PreMoveRow, SellSignalRow,
+};
+use crate::commands::conflicts::{AffinityRow, ConflictRow, DonationCorrelationRow, SpouseConflictRow};
+use crate::commands::politicians::EnrichedDbPoliticianRow;
+use crate::commands::portfolio::EnrichedPortfolioPosition;
+use crate::commands::report::PoliticianReport;
+use crate::commands::trades::EnrichedDbTradeRow;
+
+/// Arguments for the `schema` subcommand.
+#[derive(Args)]
+pub struct SchemaArgs {
+    /// Print only the schema for one output type (e.g. trades, donations-by-employer)
+    #[arg(long)]
+    pub output_type: Option<String>,
+}
+
+/// One JSON output the CLI can produce.
+struct OutputSchema {
+    /// Stable name used with `--output-type`.
+    key: &'static str,
+    /// Invocation that produces this output.
+    command: &'static str,
+    schema: fn() -> Value,
+}
+
+fn generated<T: JsonSchema>() -> Value {
+    serde_json::to_value(schema_for!(T)).expect("schema serializes to JSON")
+}
+
+fn bundled(text: &str) -> Value {
+    serde_json::from_str(text).expect("bundled schema is valid JSON")
+}
+
+/// Every JSON output, in a fixed order so the emitted document is stable.
+fn catalog() -> Vec<OutputSchema> {
+    vec![
+        OutputSchema {
+            key: "trades",
+            command: "trades --db",
+            schema: generated::<Vec<EnrichedDbTradeRow>>,
+        },
+        OutputSchema {
+            key: "trade-revisions",
+            command: "trades --db --revisions",
+            schema: generated::<Vec<TradeRevisionRow>>,
+        },
+        OutputSchema {
+            key: "trades-live",
+            command: "trades",
+            schema: || bundled(include_str!("../../../schema/trade.schema.json")),
+        },
+        OutputSchema {
+            key: "politicians",
+            command: "politicians --db",
+            schema: generated::<Vec<EnrichedDbPoliticianRow>>,
+        },
+        OutputSchema {
+            key: "politicians-live",
+            command: "politicians",
+            schema: || bundled(include_str!("../../../schema/politician.schema.json")),
+        },
+        OutputSchema {
+            key: "issuers",
+            command: "issuers --db",
+            schema: generated::<Vec<DbIssuerRow>>,
+        },
+        OutputSchema {
+            key: "issuers-live",
+            command: "issuers",
+            schema: || bundled(include_str!("../../../schema/issuer.schema.json")),
+        },
+        OutputSchema {
+            key: "issuers-screen",
+            command: "issuers screen",
+            schema: generated::<Vec<DbIssuerRow>>,
+        },
+        OutputSchema {
+            key: "portfolio",
+            command: "portfolio",
+            schema: generated::<Vec<EnrichedPortfolioPosition>>,
+        },
+        OutputSchema {
+            key: "portfolio-fixed-income",
+            command: "portfolio --fixed-income",
+            schema: generated::<Vec<FixedIncomeExposureRow>>,
+        },
+        OutputSchema {
+            key: "donations",
+            command: "donations",
+            schema: generated::<Vec<DonationRow>>,
+        },
+        OutputSchema {
+            key: "donations-by-contributor",
+            command: "donations --group-by contributor",
+            schema: generated::<Vec<ContributorAggRow>>,
+        },
+        OutputSchema {
+            key: "donations-by-employer",
+            command: "donations --group-by employer",
+            schema: generated::<Vec<EmployerAggRow>>,
+        },
+        OutputSchema {
+            key: "donations-by-state",
+            command: "donations --group-by state",
+            schema: generated::<Vec<StateAggRow>>,
+        },
+        OutputSchema {
+            key: "donations-by-occupation",
+            command: "donations --by-occupation",
+            schema: generated::<Vec<OccupationAggRow>>,
+        },
+        OutputSchema {
+            key: "donations-geography",
+            command: "donations --geography",
+            schema: generated::<DonationGeography>,
+        },
+        OutputSchema {
+            key: "analytics",
+            command: "analytics",
+            schema: generated::<LeaderboardReport>,
+        },
+        OutputSchema {
+            key: "analytics-by-session",
+            command: "analytics --by-session",
+            schema: generated::<Vec<SessionGroupRow>>,
+        },
+        OutputSchema {
+            key: "analytics-labels",
+            command: "analytics labels --label",
+            schema: generated::<LabelReport>,
+        },
+        OutputSchema {
+            key: "analytics-labels-list",
+            command: "analytics labels --list",
+            schema: generated::<Vec<LabelCount>>,
+        },
+        OutputSchema {
+            key: "analytics-index",
+            command: "analytics index",
+            schema: generated::<Vec<IndexPoint>>,
+        },
+        OutputSchema {
+            key: "analytics-overlap",
+            command: "analytics overlap",
+            schema: generated::<Vec<OverlapPair>>,
+        },
+        OutputSchema {
+            key: "analytics-tax-estimate",
+            command: "analytics --tax-estimate",
+            schema: generated::<TaxEstimateReport>,
+        },
+        OutputSchema {
+            key: "analytics-trade-detail",
+            command: "analytics --trade-detail",
+            schema: generated::<Vec<TradeDetailRow>>,
+        },
+        OutputSchema {
+            key: "conflicts",
+            command: "conflicts",
+            schema: generated::<Vec<ConflictRow>>,
+        },
+        OutputSchema {
+            key: "conflicts-donations",
+            command: "conflicts --include-donations",
+            schema: generated::<Vec<DonationCorrelationRow>>,
+        },
+        OutputSchema {
+            key: "conflicts-hearings",
+            command: "conflicts hearings",
+            schema: generated::<Vec<HearingProximityRow>>,
+        },
+        OutputSchema {
+            key: "conflicts-spouse",
+            command: "conflicts spouse",
+            schema: generated::<Vec<SpouseConflictRow>>,
+        },
+        OutputSchema {
+            key: "conflicts-rank",
+            command: "conflicts rank",
+            schema: generated::<Vec<AffinityRow>>,
+        },
+        OutputSchema {
+            key: "conflicts-show-jurisdictions",
+            command: "conflicts show-jurisdictions",
+            schema: generated::<Vec<JurisdictionSectorRow>>,
+        },
+        OutputSchema {
+            key: "anomalies",
+            command: "anomalies",
+            schema: generated::<AnomalyReport>,
+        },
+        OutputSchema {
+            key: "anomalies-pre-move",
+            command: "anomalies --show-pre-move",
+            schema: generated::<Vec<PreMoveRow>>,
+        },
+        OutputSchema {
+            key: "anomalies-sells",
+            command: "anomalies --sells",
+            schema: generated::<Vec<SellSignalRow>>,
+        },
+        OutputSchema {
+            key: "anomalies-crowded",
+            command: "anomalies --crowded",
+            schema: generated::<Vec<CrowdedTradeRow>>,
+        },
+        OutputSchema {
+            key: "anomalies-novel",
+            command: "anomalies --novel",
+            schema: generated::<Vec<NovelTradeRow>>,
+        },
+        OutputSchema {
+            key: "anomalies-events",
+            command: "anomalies --events",
+            schema: generated::<Vec<EventProximityRow>>,
+        },
+        OutputSchema {
+            key: "db-coverage",
+            command: "db coverage",
+            schema: generated::<Vec<CoverageRow>>,
+        },
+        // `db export` writes one object per line; these describe a single line.
+        OutputSchema {
+            key: "db-export-trades",
+            command: "db export --table trades",
+            schema: generated::<RawTrade>,
+        },
+        OutputSchema {
+            key: "db-export-politicians",
+            command: "db export --table politicians",
+            schema: generated::<RawPolitician>,
+        },
+        OutputSchema {
+            key: "db-export-issuers",
+            command: "db export --table issuers",
+            schema: generated::<RawIssuer>,
+        },
+        OutputSchema {
+            key: "db-export-donations",
+            command: "db export --table donations",
+            schema: generated::<RawDonation>,
+        },
+        OutputSchema {
+            key: "db-export-positions",
+            command: "db export --table positions",
+            schema: generated::<RawPosition>,
+        },
+        OutputSchema {
+            key: "db-export-fec-mappings",
+            command: "db export --table fec_mappings",
+            schema: generated::<RawFecMapping>,
+        },
+        OutputSchema {
+            key: "report",
+            command: "report",
+            schema: generated::<PoliticianReport>,
+        },
+        OutputSchema {
+            key: "snapshot-list",
+            command: "snapshot list",
+            schema: generated::<Vec<SnapshotInfo>>,
+        },
+        OutputSchema {
+            key: "diff",
+            command: "diff --since-snapshot",
+            schema: generated::<SnapshotDiff>,
+        },
+    ]
+}
+
+/// Build the schema document: every output type keyed by name, or the bare
+/// schema for a single `output_type`.
+fn build_document(output_type: Option<&str>) -> Result<Value> {
+    let catalog = catalog();
+    if let Some(wanted) = output_type {
+        let Some(entry) = catalog.iter().find(|e| e.key == wanted) else {
+            let valid: Vec<&str> = catalog.iter().map(|e| e.key).collect();
+            bail!(
+                "unknown output type '{}'. Valid values: {}",
+                wanted,
+                valid.join(", ")
+            );
+        };
+        return Ok((entry.schema)());
+    }
+
+    let mut outputs = Map::new();
+    for entry in &catalog {
+        outputs.insert(
+            entry.key.to_string(),
+            json!({
+                "command": entry.command,
+                "format": "json",
+                "schema": (entry.schema)(),
+            }),
+        );
+    }
+    Ok(Value::Object(outputs))
+}
+
+/// Schemas are always printed as JSON, regardless of `--output`.
+pub fn run(args: &SchemaArgs) -> Result<()> {
+    let document = build_document(args.output_type.as_deref())?;
+    println!("{}", serde_json::to_string_pretty(&document)?);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capitoltraders_lib::DbTradeRow;
+
+    fn sample_trade_row() -> DbTradeRow {
+        DbTradeRow {
+            tx_id: 1,
+            pub_date: "2024-01-20".to_string(),
+            tx_date: "2024-01-15".to_string(),
+            tx_type: "buy".to_string(),
+            value: 8000,
+            price: Some(101.5),
+            size: None,
+            filing_url: "https://example.com/filing".to_string(),
+            reporting_gap: 5,
+            enriched_at: None,
+            trade_date_price: Some(100.0),
+            current_price: None,
+            price_enriched_at: Some("2024-02-01T00:00:00Z".to_string()),
+            estimated_shares: Some(80.0),
+            estimated_value: None,
+            politician_name: "Nancy Pelosi".to_string(),
+            party: "Democrat".to_string(),
+            state: "CA".to_string(),
+            chamber: "House".to_string(),
+            issuer_name: "Apple Inc".to_string(),
+            issuer_ticker: "AAPL".to_string(),
+            asset_type: "stock".to_string(),
+            committees: vec!["hsba".to_string()],
+            labels: vec![],
+            politician_id: "P000197".to_string(),
+            issuer_sector: None,
+        }
+    }
+
+    fn assert_valid(schema: &Value, instance: &Value) {
+        let validator = jsonschema::validator_for(schema).expect("schema compiles");
+        let errors: Vec<String> = validator
+            .iter_errors(instance)
+            .map(|e| format!("{} at {}", e, e.instance_path))
+            .collect();
+        assert!(errors.is_empty(), "schema violations: {:?}", errors);
+    }
+
+    #[test]
+    fn db_trade_row_validates_against_its_generated_schema() {
+        let row = sample_trade_row();
+        assert_valid(
+            &generated::<DbTradeRow>(),
+            &serde_json::to_value(&row).unwrap(),
+        );
+
+        // What `trades --db --output json` actually prints.
+        let out = vec![EnrichedDbTradeRow::from(row)];
+        let schema = build_document(Some("trades")).unwrap();
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        assert_valid(&schema, &serde_json::to_value(&out).unwrap());
+    }
+
+    #[test]
+    fn option_fields_are_nullable_and_docs_become_descriptions() {
+        let schema = generated::<DbTradeRow>();
+        let price_type = &schema["properties"]["price"]["type"];
+        assert!(
+            price_type.as_array().unwrap().contains(&json!("null")),
+            "price type: {}",
+            price_type
+        );
+        assert!(schema["description"]
+            .as_str()
+            .unwrap()
+            .contains("fully-joined trade row"));
+    }
+
+    #[test]
+    fn document_is_deterministic_and_covers_every_output() {
+        let first = serde_json::to_string_pretty(&build_document(None).unwrap()).unwrap();
+        let second = serde_json::to_string_pretty(&build_document(None).unwrap()).unwrap();
+        assert_eq!(first, second);
+
+        let doc = build_document(None).unwrap();
+        assert_eq!(doc.as_object().unwrap().len(), catalog().len());
+        assert_eq!(doc["trades"]["command"], "trades --db");
+        assert_eq!(doc["trades-live"]["schema"]["$id"], "trade.schema.json");
+        assert_eq!(doc["trade-revisions"]["command"], "trades --db --revisions");
+
+        assert!(build_document(Some("nope")).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+//! The `snapshot` and `diff` subcommands.
+//!
+//! `snapshot create <name>` records compact summaries of the database (see
+//! `capitoltraders_lib::snapshot`); `diff --since-snapshot <name>` reports
+//! new trades, changed positions, leaderboard moves and new anomaly signals
+//! since then. Sections an older snapshot cannot answer are reported as
+//! unavailable rather than failing the whole diff.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use capitoltraders_lib::snapshot::{self, DiffOptions};
+use capitoltraders_lib::Db;
+use chrono::Local;
+use clap::{Args, Subcommand};
+
+use crate::output::{
+    print_json, print_snapshot_diff_markdown, print_snapshot_list_markdown,
+    print_snapshot_list_table, OutputFormat,
+};
+
+/// Arguments for the `snapshot` subcommand.
+#[derive(Args)]
+pub struct SnapshotArgs {
+    #[command(subcommand)]
+    pub action: SnapshotAction,
+}
+
+#[derive(Subcommand)]
+pub enum SnapshotAction {
+    /// Record the current state under a name (replaces an existing snapshot)
+    Create(SnapshotNameArgs),
+    /// List stored snapshots
+    List(SnapshotDbArgs),
+    /// Delete a stored snapshot
+    Delete(SnapshotNameArgs),
+}
+
+#[derive(Args)]
+pub struct SnapshotNameArgs {
+    /// Snapshot name (letters, digits, '.', '-' or '_')
+    pub name: String,
+
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+}
+
+#[derive(Args)]
+pub struct SnapshotDbArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+}
+
+/// Arguments for the `diff` subcommand.
+#[derive(Args)]
+pub struct DiffArgs {
+    /// SQLite database path (required)
+    #[arg(long)]
+    pub db: PathBuf,
+
+    /// Snapshot to compare against (see `snapshot create`)
+    #[arg(long)]
+    pub since_snapshot: String,
+
+    /// Report politicians whose leaderboard rank moved more than this many places
+    #[arg(long, default_value = "5")]
+    pub rank_threshold: usize,
+}
+
+pub fn run(args: &SnapshotArgs, format: &OutputFormat) -> Result<()> {
+    match &args.action {
+        SnapshotAction::Create(args) => create(args, format),
+        SnapshotAction::List(args) => list(args, format),
+        SnapshotAction::Delete(args) => delete(args),
+    }
+}
+
+fn create(args: &SnapshotNameArgs, format: &OutputFormat) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    db.init()?;
+    let today = Local::now().naive_local().date();
+    let summary = snapshot::create_snapshot(&db, &args.name, today)?;
+
+    if matches!(format, OutputFormat::Json) {
+        print_json(&serde_json::json!({
+            "name": args.name,
+            "max_tx_id": summary.trades.max_tx_id,
+            "trades": summary.trades.trade_count,
+            "politicians_with_positions": summary.positions.len(),
+            "ranked_politicians": summary.ranks.len(),
+        }));
+    } else {
+        eprintln!(
+            "Snapshot '{}' saved: {} trades, {} politicians with open positions, {} ranked",
+            args.name,
+            summary.trades.trade_count,
+            summary.positions.len(),
+            summary.ranks.len()
+        );
+    }
+    Ok(())
+}
+
+fn list(args: &SnapshotDbArgs, format: &OutputFormat) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    db.init()?;
+    let snapshots = db.list_snapshots()?;
+    match format {
+        OutputFormat::Json => print_json(&snapshots),
+        OutputFormat::Markdown => print_snapshot_list_markdown(&snapshots),
+        OutputFormat::Table => print_snapshot_list_table(&snapshots),
+        OutputFormat::Csv | OutputFormat::Xml => {
+            bail!("snapshot list supports --output table, md and json")
+        }
+    }
+    Ok(())
+}
+
+fn delete(args: &SnapshotNameArgs) -> Result<()> {
+    let db = Db::open(&args.db)?;
+    db.init()?;
+    if !db.delete_snapshot(&args.name)? {
+        bail!("No snapshot named '{}'", args.name);
+    }
+    eprintln!("Deleted snapshot '{}'", args.name);
+    Ok(())
+}
+
+pub fn run_diff(args: &DiffArgs, format: &OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Csv | OutputFormat::Xml) {
+        bail!("diff supports --output md (or table) and json");
+    }
+
+    let db = Db::open(&args.db)?;
+    db.init()?;
+    let options = DiffOptions {
+        rank_threshold: args.rank_threshold,
+        today: Local::now().naive_local().date(),
+    };
+    let diff = snapshot::compare(&db, &args.since_snapshot, &options)?;
+    match format {
+        OutputFormat::Json => print_json(&diff),
+        _ => print_snapshot_diff_markdown(&diff),
+    }
+    Ok(())
+}
@@ -1,37 +1,65 @@
 //! The `sync` subcommand: ingest CapitolTrades data into SQLite.
 
-use std::path::PathBuf;
-use std::sync::Arc;
-
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use capitoltraders_lib::{
-    validation, Db, IssuerStatsRow, PoliticianStatsRow, ScrapeClient, ScrapeError,
-    ScrapedIssuerDetail, ScrapedTrade, ScrapedTradeDetail,
+    enrich_after_sync, shutdown, validation, AppConfig, CancellationToken, CapDetector, Db,
+    EnrichOptions, EnrichPhase, EnrichmentEntity, IssuerQueue, IssuerStatsRow, PaginationCap, PoliticianStatsRow,
+    ScrapeClient, ScrapedTrade, TradeFilter,
 };
-use chrono::NaiveDate;
-use clap::Args;
-use indicatif::{ProgressBar, ProgressStyle};
+use chrono::{Datelike, NaiveDate, Utc};
+use clap::{ArgGroup, Args};
 use std::collections::{HashMap, HashSet};
-use std::time::Duration;
-use tokio::sync::{mpsc, Semaphore};
-use tokio::task::JoinSet;
-use tokio::time::sleep;
+use std::fmt;
+use std::str::FromStr;
+
+use super::enrich::{
+    enrich_options, issuer_queue, paced_scraper, BarProgress, EnrichCommonArgs, IssuerQueueArgs,
+};
 
 /// Arguments for the `sync` subcommand.
+/// `--dry-run`, `--priority` and `--min-trades` only apply with `--enrich`.
 #[derive(Args)]
+#[command(group(
+    ArgGroup::new("enrich_flags")
+        .args(["dry_run", "priority", "min_trades"])
+        .multiple(true)
+        .requires("enrich")
+))]
 pub struct SyncArgs {
-    /// SQLite database path
-    #[arg(long, default_value = "capitoltraders.db")]
-    pub db: PathBuf,
+    #[command(flatten)]
+    pub common: EnrichCommonArgs,
 
     /// Full refresh: fetch all trades, politicians, and issuers
     #[arg(long)]
     pub full: bool,
 
-    /// Override the incremental cutoff date (YYYY-MM-DD, publication date)
+    /// Override the incremental cutoff date (YYYY-MM-DD or relative like 30d; publication date)
     #[arg(long)]
     pub since: Option<String>,
 
+    /// Only page the politicians directory, adding members who have not
+    /// traded yet; skips trade sync and enrichment
+    #[arg(long, conflicts_with_all = ["full", "since", "enrich", "with_trade_details"])]
+    pub politicians_only: bool,
+
+    /// Only page the issuer directory, refreshing issuer stats and the
+    /// "last traded by" preview; skips trade sync and enrichment
+    #[arg(
+        long,
+        conflicts_with_all = ["full", "since", "enrich", "with_trade_details", "politicians_only"]
+    )]
+    pub issuers_only: bool,
+
+    /// Sync trades in filtered passes to get past the site's pagination cap:
+    /// politician (one pass per stored member) or month (one pass per
+    /// publication month from --since, default 2012-01)
+    #[arg(
+        long,
+        value_name = "MODE",
+        conflicts_with_all = ["politicians_only", "issuers_only"]
+    )]
+    pub backfill_by: Option<String>,
+
     /// Refresh the full politician catalog (slow)
     #[arg(long)]
     pub refresh_politicians: bool,
@@ -52,44 +80,173 @@ pub struct SyncArgs {
     #[arg(long)]
     pub enrich: bool,
 
-    /// Show how many items would be enriched without fetching
-    #[arg(long, requires = "enrich")]
-    pub dry_run: bool,
-
     /// Maximum items to enrich per run (default: all)
     #[arg(long)]
     pub batch_size: Option<i64>,
 
-    /// Delay between trade detail requests in milliseconds
-    #[arg(long, default_value = "500")]
-    pub details_delay_ms: u64,
+    #[command(flatten)]
+    pub queue: IssuerQueueArgs,
+
+    /// Re-enrich this issuer even if already enriched (repeatable)
+    #[arg(long = "force-issuer", value_name = "ID")]
+    pub force_issuers: Vec<i64>,
+
+    /// Re-enrich this trade even if already enriched (repeatable)
+    #[arg(long = "force-trade", value_name = "TX_ID")]
+    pub force_trades: Vec<i64>,
 
-    /// Number of concurrent detail page fetches (1-10)
-    #[arg(long, default_value = "3")]
-    pub concurrency: usize,
+    /// Re-enrich this politician even if already enriched (repeatable)
+    #[arg(long = "force-politician", value_name = "ID")]
+    pub force_politicians: Vec<String>,
 
-    /// Stop enrichment after N consecutive HTTP failures
-    #[arg(long, default_value = "5")]
-    pub max_failures: usize,
+    /// Re-enrich every issuer
+    #[arg(long)]
+    pub force_all_issuers: bool,
+
+    /// Also clear price enrichment for trades touched by --force-* flags
+    #[arg(long)]
+    pub include_prices: bool,
 }
 
-pub async fn run(args: &SyncArgs, base_url: Option<&str>) -> Result<()> {
+impl SyncArgs {
+    fn has_force_flags(&self) -> bool {
+        !self.force_issuers.is_empty()
+            || !self.force_trades.is_empty()
+            || !self.force_politicians.is_empty()
+            || self.force_all_issuers
+    }
+}
+
+pub async fn run(args: &SyncArgs, config: &AppConfig) -> Result<()> {
     let _page_size = validation::validate_page_size(args.page_size)?;
-    if args.concurrency < 1 || args.concurrency > 10 {
-        return Err(anyhow!("--concurrency must be between 1 and 10"));
+    let cancel = shutdown::ctrl_c_token();
+    let enrich_opts = enrich_options(
+        args.common.concurrency,
+        args.common.max_failures,
+        args.batch_size,
+        args.common.dry_run,
+        &cancel,
+    )?;
+    let issuers = issuer_queue(&args.queue)?;
+    let backfill_by = match args.backfill_by {
+        Some(ref mode) => Some(mode.parse::<BackfillBy>().map_err(|e| anyhow!(e))?),
+        None => None,
+    };
+    let mut db = Db::open(&args.common.db)?;
+    db.init()?;
+
+    let result = sync_into(args, config, &mut db, &cancel, &enrich_opts, issuers, backfill_by).await;
+    // Interrupted and failed runs are the likeliest to leave orphaned rows,
+    // so the check runs however the sync ended.
+    result.and(report_integrity(&db, args))
+}
+
+/// The scraper for every CapitolTrades request in the run, listing pages
+/// and details alike, paced by `--details-delay-ms`.
+fn sync_scraper(args: &SyncArgs, config: &AppConfig) -> Result<ScrapeClient> {
+    Ok(paced_scraper(
+        ScrapeClient::from_config(config)?,
+        config,
+        args.common.details_delay_ms,
+        args.common.concurrency,
+    ))
+}
+
+/// Everything `sync` does once the database is open.
+async fn sync_into(
+    args: &SyncArgs,
+    config: &AppConfig,
+    db: &mut Db,
+    cancel: &CancellationToken,
+    enrich_opts: &EnrichOptions<'_>,
+    issuers: IssuerQueue,
+    backfill_by: Option<BackfillBy>,
+) -> Result<()> {
+    if args.politicians_only {
+        let scraper = sync_scraper(args, config)?;
+        let (seen, inserted) = sync_politician_directory(&scraper, db, cancel).await?;
+        eprintln!(
+            "Politician directory sync {}: {} members seen, {} new",
+            outcome_label(cancel),
+            seen,
+            inserted
+        );
+        return Ok(());
+    }
+
+    if args.issuers_only {
+        let scraper = sync_scraper(args, config)?;
+        let seen = sync_issuer_directory(&scraper, db, cancel).await?;
+        let reconciled = db.recompute_issuer_stats()?;
+        eprintln!(
+            "Issuer directory sync {}: {} issuers seen, {} last-trader hints replaced by local trades",
+            outcome_label(cancel),
+            seen,
+            reconciled
+        );
+        return Ok(());
     }
-    if args.max_failures < 1 {
-        return Err(anyhow!("--max-failures must be at least 1"));
+
+    let scraper = sync_scraper(args, config)?;
+    let sync_started = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    match backfill_by {
+        Some(mode) => backfill(args, mode, &scraper, db, cancel).await?,
+        None => sync_latest(args, &scraper, db, cancel).await?,
+    }
+
+    let revised = db.count_revised_trades_since(&sync_started)?;
+    if revised > 0 {
+        eprintln!(
+            "{} existing trades changed upstream; see `trades --db {} --revisions <TX_ID>`",
+            revised,
+            args.common.db.display()
+        );
+    }
+    if cancel.is_cancelled() {
+        return Ok(());
     }
-    let mut db = Db::open(&args.db)?;
-    db.init()?;
 
+    // Treat --with-trade-details as alias for --enrich
+    let should_enrich = args.enrich || args.with_trade_details;
+    if args.has_force_flags() && !should_enrich {
+        return Err(anyhow!("--force-* flags require --enrich"));
+    }
+    if should_enrich {
+        apply_force_resets(db, args)?;
+    }
+    let summaries =
+        enrich_after_sync(db, &scraper, enrich_opts, issuers, should_enrich, &BarProgress::default())
+            .await?;
+    if cancel.is_cancelled() {
+        let queued = summaries.last().map_or("items", |s| s.phase.as_str());
+        eprintln!("Sync interrupted: unenriched {} stay queued for the next run", queued);
+        return Ok(());
+    }
+    if let Some(committees) = summaries.iter().find(|s| s.phase == EnrichPhase::Politicians) {
+        if !committees.is_clean() {
+            return Err(anyhow!(
+                "committee membership refresh failed for {} committees",
+                committees.failed
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Page the unfiltered trades listing from the newest trade back to the
+/// stored checkpoint (or `--since`), or through everything on a full sync.
+async fn sync_latest(
+    args: &SyncArgs,
+    scraper: &ScrapeClient,
+    db: &mut Db,
+    cancel: &CancellationToken,
+) -> Result<()> {
     let mut full = args.full;
     let mut since_date: Option<NaiveDate> = None;
 
     if !full {
         if let Some(ref since) = args.since {
-            since_date = Some(validation::validate_date(since)?);
+            since_date = Some(validation::validate_since_date(since)?);
         } else if let Some(stored) = db.get_meta("last_trade_pub_date")? {
             since_date = Some(NaiveDate::parse_from_str(&stored, "%Y-%m-%d")?);
         } else if db.trade_count()? == 0 {
@@ -102,428 +259,510 @@ pub async fn run(args: &SyncArgs, base_url: Option<&str>) -> Result<()> {
     }
 
     if full {
-        eprintln!("Starting full sync into {}", args.db.display());
+        eprintln!("Starting full sync into {}", args.common.db.display());
     } else if let Some(date) = since_date {
         eprintln!(
             "Starting incremental sync into {} (since {})",
-            args.db.display(),
+            args.common.db.display(),
             date
         );
     } else {
-        eprintln!("Starting incremental sync into {}", args.db.display());
+        eprintln!("Starting incremental sync into {}", args.common.db.display());
     }
 
-    let scraper = match base_url
-        .map(|s| s.to_string())
-        .or_else(|| std::env::var("CAPITOLTRADES_BASE_URL").ok())
-    {
-        Some(url) => ScrapeClient::with_base_url(&url)?,
-        None => ScrapeClient::new()?,
-    };
-
     let trade_result = sync_trades(
-        &scraper,
-        &mut db,
+        scraper,
+        db,
+        &TradeFilter::default(),
         if full { None } else { since_date },
         args.with_trade_details,
-        args.details_delay_ms,
+        cancel,
     )
     .await?;
+    trade_result.store_stats(db)?;
 
-    if let Some(max_pub_date) = trade_result.max_pub_date {
-        db.set_meta("last_trade_pub_date", &max_pub_date.to_string())?;
-    }
-
-    eprintln!(
-        "Sync complete: {} trades ingested",
-        trade_result.trade_count
-    );
-
-    // Treat --with-trade-details as alias for --enrich
-    let should_enrich = args.enrich || args.with_trade_details;
-    if should_enrich {
-        let result = enrich_trades(
-            &scraper,
-            &db,
-            args.batch_size,
-            args.details_delay_ms,
-            args.dry_run,
-            args.concurrency,
-            args.max_failures,
-        )
-        .await?;
+    if trade_result.interrupted {
+        // Pages arrive newest first, so the pages never fetched are older
+        // than everything ingested. Advancing last_trade_pub_date would skip
+        // them; the next run starts from the previous checkpoint instead.
         eprintln!(
-            "Enrichment: {}/{} trades processed ({} failed)",
-            result.enriched, result.total, result.failed
+            "Sync interrupted: {} trades ingested; the next run resumes from the previous checkpoint",
+            trade_result.trade_count
         );
+    } else {
+        if let Some(max_pub_date) = trade_result.max_pub_date {
+            db.set_meta("last_trade_pub_date", &max_pub_date.to_string())?;
+        }
+        db.record_trade_sync()?;
 
-        let issuer_result = enrich_issuers(
-            &scraper,
-            &db,
-            args.batch_size,
-            args.details_delay_ms,
-            args.dry_run,
-            args.concurrency,
-            args.max_failures,
-        )
-        .await?;
         eprintln!(
-            "Issuer enrichment: {}/{} issuers processed ({} failed)",
-            issuer_result.enriched, issuer_result.total, issuer_result.failed
+            "Sync complete: {} trades ingested",
+            trade_result.trade_count
         );
     }
-
-    let _committee_count = enrich_politician_committees(
-        &scraper,
-        &db,
-        args.details_delay_ms,
-    )
-    .await?;
-
+    warn_capped(&trade_result);
     Ok(())
 }
 
-struct EnrichmentResult {
-    enriched: usize,
-    #[allow(dead_code)]
-    skipped: usize,
-    failed: usize,
-    total: usize,
+/// Tell the user the trades listing stopped short of the requested range.
+fn warn_capped(result: &TradeSyncResult) {
+    let Some(ref cap) = result.cap else {
+        return;
+    };
+    eprintln!("Warning: trade history is truncated: {}", cap);
+    if let Some(earliest) = result.earliest_pub_date {
+        eprintln!("  Earliest reachable trade was published {}", earliest);
+    }
+    eprintln!(
+        "  Full history needs narrower passes: run `sync --backfill-by politician` or `sync --backfill-by month`"
+    );
 }
 
-/// Circuit breaker that trips after N consecutive failures.
-/// Not a full circuit breaker with half-open state -- just a kill switch.
-struct CircuitBreaker {
-    consecutive_failures: usize,
-    threshold: usize,
+/// How `--backfill-by` splits the trades listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BackfillBy {
+    Politician,
+    Month,
 }
 
-impl CircuitBreaker {
-    fn new(threshold: usize) -> Self {
-        Self {
-            consecutive_failures: 0,
-            threshold,
+impl FromStr for BackfillBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "politician" => Ok(BackfillBy::Politician),
+            "month" => Ok(BackfillBy::Month),
+            other => Err(format!(
+                "unknown --backfill-by mode '{}': expected politician or month",
+                other
+            )),
         }
     }
-    fn record_success(&mut self) {
-        self.consecutive_failures = 0;
-    }
-    fn record_failure(&mut self) {
-        self.consecutive_failures += 1;
-    }
-    fn is_tripped(&self) -> bool {
-        self.consecutive_failures >= self.threshold
+}
+
+impl fmt::Display for BackfillBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BackfillBy::Politician => "politician",
+            BackfillBy::Month => "month",
+        })
     }
 }
 
-struct FetchResult<T> {
-    id: i64,
-    result: std::result::Result<T, ScrapeError>,
+/// First publication month a month backfill covers without `--since`; the
+/// STOCK Act disclosures CapitolTrades lists start in 2012.
+const BACKFILL_START_YEAR: i32 = 2012;
+
+/// One filtered pass of a backfill.
+struct BackfillShard {
+    label: String,
+    filter: TradeFilter,
+    /// `ingest_meta` key stamped when the shard completes; `None` for
+    /// shards that keep changing (the current month) and always re-run.
+    meta_key: Option<String>,
+}
+
+fn politician_shards(ids: &[String]) -> Vec<BackfillShard> {
+    ids.iter()
+        .map(|id| BackfillShard {
+            label: id.clone(),
+            filter: TradeFilter {
+                politician_id: Some(id.clone()),
+                pub_date: None,
+            },
+            meta_key: Some(format!("backfill:politician:{}", id)),
+        })
+        .collect()
+}
+
+/// One shard per publication month from `today`'s month back to `start`'s.
+fn month_shards(start: NaiveDate, today: NaiveDate) -> Vec<BackfillShard> {
+    let first_of = |date: NaiveDate| date.with_day(1).unwrap_or(date);
+    let start = first_of(start);
+    let current = first_of(today);
+    let mut shards = Vec::new();
+    let mut month = current;
+    while month >= start {
+        let next = month
+            .checked_add_months(chrono::Months::new(1))
+            .unwrap_or(month);
+        let label = month.format("%Y-%m").to_string();
+        shards.push(BackfillShard {
+            meta_key: (month != current).then(|| format!("backfill:month:{}", label)),
+            label,
+            filter: TradeFilter {
+                politician_id: None,
+                pub_date: Some((month, next.pred_opt().unwrap_or(month))),
+            },
+        });
+        match month.checked_sub_months(chrono::Months::new(1)) {
+            Some(prev) => month = prev,
+            None => break,
+        }
+    }
+    shards
 }
 
-async fn enrich_trades(
+/// `sync --backfill-by`: page filtered trade listings shard by shard.
+async fn backfill(
+    args: &SyncArgs,
+    mode: BackfillBy,
     scraper: &ScrapeClient,
-    db: &Db,
-    batch_size: Option<i64>,
-    detail_delay_ms: u64,
-    dry_run: bool,
-    concurrency: usize,
-    max_failures: usize,
-) -> Result<EnrichmentResult> {
-    if dry_run {
-        let total = db.count_unenriched_trades()?;
-        let selected = match batch_size {
-            Some(n) => n.min(total),
-            None => total,
-        };
+    db: &mut Db,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let since = match args.since {
+        Some(ref since) => Some(validation::validate_since_date(since)?),
+        None => None,
+    };
+    let shards = match mode {
+        BackfillBy::Politician => {
+            if since.is_some() {
+                bail!("--since only applies to --backfill-by month");
+            }
+            if db.politician_ids()?.is_empty() {
+                eprintln!("No politicians stored; paging the politician directory first");
+                sync_politician_directory(scraper, db, cancel).await?;
+            }
+            politician_shards(&db.politician_ids()?)
+        }
+        BackfillBy::Month => {
+            let start = since
+                .or_else(|| NaiveDate::from_ymd_opt(BACKFILL_START_YEAR, 1, 1))
+                .ok_or_else(|| anyhow!("invalid backfill start"))?;
+            month_shards(start, Utc::now().date_naive())
+        }
+    };
+    eprintln!(
+        "Starting {} backfill into {} ({} shards)",
+        mode,
+        args.common.db.display(),
+        shards.len()
+    );
+
+    let result = backfill_trades(scraper, db, &shards, args.full, cancel).await?;
+    if result.interrupted {
         eprintln!(
-            "{} trades would be enriched ({} selected)",
-            total, selected
+            "Backfill interrupted: {} trades ingested; {} of {} shards done, the rest run next time",
+            result.trade_count,
+            result.completed + result.skipped,
+            shards.len()
         );
-        return Ok(EnrichmentResult {
-            enriched: 0,
-            skipped: 0,
-            failed: 0,
-            total: total as usize,
-        });
+        return Ok(());
     }
 
-    let queue = db.get_unenriched_trade_ids(batch_size)?;
-    if queue.is_empty() {
-        eprintln!("No trades need enrichment");
-        return Ok(EnrichmentResult {
-            enriched: 0,
-            skipped: 0,
-            failed: 0,
-            total: 0,
-        });
+    // The plain sync resumes from this checkpoint; only seed it, since a
+    // backfill never sees trades newer than the last plain sync did.
+    if db.get_meta("last_trade_pub_date")?.is_none() {
+        if let Some(max_pub_date) = result.max_pub_date {
+            db.set_meta("last_trade_pub_date", &max_pub_date.to_string())?;
+        }
     }
-
-    let total = queue.len();
-    let pb = ProgressBar::new(total as u64);
-    pb.set_style(
-        ProgressStyle::with_template(
-            "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
-        )
-        .unwrap(),
+    db.record_trade_sync()?;
+    eprintln!(
+        "Backfill complete: {} trades ingested across {} shards ({} already done)",
+        result.trade_count, result.completed, result.skipped
     );
-    pb.set_message("enriching trades...");
-
-    let semaphore = Arc::new(Semaphore::new(concurrency));
-    let (tx, mut rx) = mpsc::channel::<FetchResult<ScrapedTradeDetail>>(concurrency * 2);
-    let mut join_set = JoinSet::new();
-
-    for tx_id in &queue {
-        let sem = Arc::clone(&semaphore);
-        let sender = tx.clone();
-        let scraper_clone = scraper.clone();
-        let id = *tx_id;
-        let delay = detail_delay_ms;
-
-        join_set.spawn(async move {
-            let _permit = sem.acquire().await.expect("semaphore closed");
-            if delay > 0 {
-                sleep(Duration::from_millis(delay)).await;
-            }
-            let result = scraper_clone.trade_detail(id).await;
-            let _ = sender.send(FetchResult { id, result }).await;
-        });
+    if !result.capped.is_empty() {
+        eprintln!(
+            "Warning: {} shards still hit the pagination cap and stay queued: {}",
+            result.capped.len(),
+            result.capped.join(", ")
+        );
     }
-    // Drop original sender so rx.recv() returns None when all spawned senders are dropped
-    drop(tx);
+    Ok(())
+}
 
-    let mut enriched = 0usize;
-    let mut failed = 0usize;
-    let mut breaker = CircuitBreaker::new(max_failures);
+#[derive(Default)]
+struct BackfillResult {
+    trade_count: usize,
+    max_pub_date: Option<NaiveDate>,
+    /// Shards paged this run.
+    completed: usize,
+    /// Shards skipped because an earlier run completed them.
+    skipped: usize,
+    /// Labels of shards whose own listing was still capped.
+    capped: Vec<String>,
+    interrupted: bool,
+}
 
-    while let Some(fetch) = rx.recv().await {
-        match fetch.result {
-            Ok(ref detail) => {
-                db.update_trade_detail(fetch.id, detail)?;
-                enriched += 1;
-                breaker.record_success();
-            }
-            Err(ref err) => {
-                pb.println(format!("  Warning: trade {} failed: {}", fetch.id, err));
-                failed += 1;
-                breaker.record_failure();
+/// Run each shard's filtered listing through [`sync_trades`], skipping
+/// shards an earlier run completed unless `restart`. Trades already stored
+/// are deduplicated by the normal upsert. A shard is stamped done in
+/// `ingest_meta` only when it was paged to the end without hitting the cap.
+///
+/// A shard's listing stats cover only its slice of each member's trades, so
+/// they are not stored; once the shards stop, politician stats are rebuilt
+/// from the trades table and issuers' last trader reconciled with it.
+async fn backfill_trades(
+    scraper: &ScrapeClient,
+    db: &mut Db,
+    shards: &[BackfillShard],
+    restart: bool,
+    cancel: &CancellationToken,
+) -> Result<BackfillResult> {
+    let mut result = BackfillResult::default();
+    for (i, shard) in shards.iter().enumerate() {
+        if let Some(ref key) = shard.meta_key {
+            if !restart && db.get_meta(key)?.is_some() {
+                result.skipped += 1;
+                continue;
             }
         }
-        pb.set_message(format!("{} ok, {} err", enriched, failed));
-        pb.inc(1);
-
-        if breaker.is_tripped() {
-            pb.println(format!(
-                "Circuit breaker tripped after {} consecutive failures, stopping enrichment",
-                max_failures
-            ));
-            join_set.abort_all();
+        eprintln!("Backfill shard {}/{}: {}", i + 1, shards.len(), shard.label);
+        let trades = sync_trades(scraper, db, &shard.filter, None, false, cancel).await?;
+        result.trade_count += trades.trade_count;
+        result.max_pub_date = result.max_pub_date.max(trades.max_pub_date);
+        if trades.interrupted {
+            result.interrupted = true;
             break;
         }
-    }
 
-    pb.finish_with_message(format!("done: {} enriched, {} failed", enriched, failed));
+        result.completed += 1;
+        if let Some(ref cap) = trades.cap {
+            eprintln!("  Warning: shard {} is still capped: {}", shard.label, cap);
+            result.capped.push(shard.label.clone());
+        } else if let Some(ref key) = shard.meta_key {
+            db.set_meta(key, &Utc::now().format("%Y-%m-%d %H:%M:%S").to_string())?;
+        }
 
-    Ok(EnrichmentResult {
-        enriched,
-        skipped: 0,
-        failed,
-        total,
-    })
+        if cancel.is_cancelled() {
+            result.interrupted = i + 1 < shards.len();
+            break;
+        }
+    }
+    if result.completed > 0 {
+        db.recompute_politician_stats()?;
+        db.recompute_issuer_stats()?;
+    }
+    Ok(result)
 }
 
-async fn enrich_issuers(
-    scraper: &ScrapeClient,
-    db: &Db,
-    batch_size: Option<i64>,
-    detail_delay_ms: u64,
-    dry_run: bool,
-    concurrency: usize,
-    max_failures: usize,
-) -> Result<EnrichmentResult> {
-    if dry_run {
-        let total = db.count_unenriched_issuers()?;
-        let selected = match batch_size {
-            Some(n) => n.min(total),
-            None => total,
-        };
+/// Warn when the batch left trades or positions without their parent rows,
+/// before the gap surfaces as a constraint error in a later batch.
+fn report_integrity(db: &Db, args: &SyncArgs) -> Result<()> {
+    let report = db.check_referential_integrity()?;
+    if !report.is_clean() {
+        super::db::print_integrity_report(&report);
         eprintln!(
-            "{} issuers would be enriched ({} selected)",
-            total, selected
+            "Hint: run `capitoltraders db check --db {} --repair` to insert placeholder parents",
+            args.common.db.display()
         );
-        return Ok(EnrichmentResult {
-            enriched: 0,
-            skipped: 0,
-            failed: 0,
-            total: total as usize,
-        });
     }
+    Ok(())
+}
 
-    let queue = db.get_unenriched_issuer_ids(batch_size)?;
-    if queue.is_empty() {
-        eprintln!("No issuers need enrichment");
-        return Ok(EnrichmentResult {
-            enriched: 0,
-            skipped: 0,
-            failed: 0,
-            total: 0,
-        });
+/// "complete" or "interrupted", for end-of-run summaries.
+fn outcome_label(cancel: &CancellationToken) -> &'static str {
+    if cancel.is_cancelled() {
+        "interrupted"
+    } else {
+        "complete"
     }
+}
 
-    let total = queue.len();
-    let pb = ProgressBar::new(total as u64);
-    pb.set_style(
-        ProgressStyle::with_template(
-            "[{elapsed_precise}] {bar:40.cyan/blue} {pos:>7}/{len:7} ({eta}) {msg}",
-        )
-        .unwrap(),
-    );
-    pb.set_message("enriching issuers...");
-
-    let semaphore = Arc::new(Semaphore::new(concurrency));
-    let (tx, mut rx) = mpsc::channel::<FetchResult<ScrapedIssuerDetail>>(concurrency * 2);
-    let mut join_set = JoinSet::new();
-
-    for issuer_id in &queue {
-        let sem = Arc::clone(&semaphore);
-        let sender = tx.clone();
-        let scraper_clone = scraper.clone();
-        let id = *issuer_id;
-        let delay = detail_delay_ms;
-
-        join_set.spawn(async move {
-            let _permit = sem.acquire().await.expect("semaphore closed");
-            if delay > 0 {
-                sleep(Duration::from_millis(delay)).await;
-            }
-            let result = scraper_clone.issuer_detail(id).await;
-            let _ = sender.send(FetchResult { id, result }).await;
-        });
+/// Clear enrichment state for entities named by --force-* flags so the normal
+/// enrichment queues pick them up again (still subject to --batch-size).
+fn apply_force_resets(db: &Db, args: &SyncArgs) -> Result<()> {
+    if !args.has_force_flags() {
+        return Ok(());
     }
-    // Drop original sender so rx.recv() returns None when all spawned senders are dropped
-    drop(tx);
-
-    let mut enriched = 0usize;
-    let mut failed = 0usize;
-    let mut breaker = CircuitBreaker::new(max_failures);
-
-    while let Some(fetch) = rx.recv().await {
-        match fetch.result {
-            Ok(ref detail) => {
-                db.update_issuer_detail(fetch.id, detail)?;
-                enriched += 1;
-                breaker.record_success();
-            }
-            Err(ref err) => {
-                pb.println(format!("  Warning: issuer {} failed: {}", fetch.id, err));
-                failed += 1;
-                breaker.record_failure();
-            }
+    let to_strings = |ids: &[i64]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    let requests = [
+        (EnrichmentEntity::Trade, "trades", to_strings(&args.force_trades)),
+        (EnrichmentEntity::Issuer, "issuers", to_strings(&args.force_issuers)),
+        (
+            EnrichmentEntity::Politician,
+            "politicians",
+            args.force_politicians.clone(),
+        ),
+    ];
+
+    for (entity, label, ids) in requests {
+        if ids.is_empty() || (entity == EnrichmentEntity::Issuer && args.force_all_issuers) {
+            continue;
         }
-        pb.set_message(format!("{} ok, {} err", enriched, failed));
-        pb.inc(1);
-
-        if breaker.is_tripped() {
-            pb.println(format!(
-                "Circuit breaker tripped after {} consecutive failures, stopping enrichment",
-                max_failures
-            ));
-            join_set.abort_all();
-            break;
+        if args.common.dry_run {
+            eprintln!("Would force-reset {}: {}", label, ids.join(", "));
+            continue;
+        }
+        let reset = db.reset_enrichment(entity, &ids, args.include_prices)?;
+        let missing: Vec<&String> = ids.iter().filter(|id| !reset.contains(id)).collect();
+        if !reset.is_empty() {
+            eprintln!("Force-reset {}: {}", label, reset.join(", "));
+        }
+        if !missing.is_empty() {
+            eprintln!(
+                "  Warning: {} not found: {}",
+                label,
+                missing.iter().map(|id| id.as_str()).collect::<Vec<_>>().join(", ")
+            );
         }
     }
 
-    pb.finish_with_message(format!("done: {} enriched, {} failed", enriched, failed));
-
-    Ok(EnrichmentResult {
-        enriched,
-        skipped: 0,
-        failed,
-        total,
-    })
+    if args.force_all_issuers {
+        if args.common.dry_run {
+            eprintln!("Would force-reset all issuers");
+        } else {
+            let count = db.reset_all_enrichment(EnrichmentEntity::Issuer, args.include_prices)?;
+            eprintln!("Force-reset all issuers ({})", count);
+        }
+    }
+    if args.include_prices && !args.common.dry_run {
+        eprintln!("Price enrichment cleared for affected trades; run enrich-prices to refresh");
+    }
+    Ok(())
 }
 
-async fn enrich_politician_committees(
+/// Run one incremental trade sync with no enrichment, as used by `watch`.
+///
+/// Resumes from the stored `last_trade_pub_date` (or does a full pass on an
+/// empty database) and returns the number of trades ingested. An interrupted
+/// pass keeps its trades but leaves the resume point where it was.
+pub(crate) async fn sync_incremental(
     scraper: &ScrapeClient,
-    db: &Db,
-    throttle_ms: u64,
+    db: &mut Db,
+    cancel: &CancellationToken,
 ) -> Result<usize> {
-    let mut memberships: Vec<(String, String)> = Vec::new();
-
-    let pb = ProgressBar::new_spinner();
-    pb.set_style(
-        ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] {msg}")
-            .unwrap(),
-    );
-    pb.enable_steady_tick(Duration::from_millis(120));
+    let since_date = match db.get_meta("last_trade_pub_date")? {
+        Some(stored) => Some(NaiveDate::parse_from_str(&stored, "%Y-%m-%d")?),
+        None => None,
+    };
 
-    for &(code, name) in validation::COMMITTEE_MAP {
-        let mut page = 1;
-        let mut committee_member_count = 0;
+    let trade_result =
+        sync_trades(scraper, db, &TradeFilter::default(), since_date, false, cancel).await?;
+    trade_result.store_stats(db)?;
+    if trade_result.interrupted {
+        return Ok(trade_result.trade_count);
+    }
+    warn_capped(&trade_result);
+    if let Some(max_pub_date) = trade_result.max_pub_date {
+        db.set_meta("last_trade_pub_date", &max_pub_date.to_string())?;
+    }
+    db.record_trade_sync()?;
 
-        loop {
-            let resp = scraper.politicians_by_committee(code, page).await?;
-            for card in &resp.data {
-                memberships.push((card.politician_id.clone(), code.to_string()));
-                committee_member_count += 1;
-            }
+    Ok(trade_result.trade_count)
+}
 
-            let total_pages = resp.total_pages.unwrap_or(1);
-            if page >= total_pages {
-                break;
-            }
+/// Page the politicians directory and insert any member missing from the
+/// database. Returns (members seen, members inserted).
+async fn sync_politician_directory(
+    scraper: &ScrapeClient,
+    db: &mut Db,
+    cancel: &CancellationToken,
+) -> Result<(usize, usize)> {
+    let mut page = 1;
+    let mut seen = 0;
+    let mut inserted = 0;
 
-            page += 1;
+    loop {
+        let resp = scraper.politicians_page(page).await?;
+        seen += resp.data.len();
+        inserted += db.upsert_politician_cards(&resp.data)?;
 
-            if throttle_ms > 0 {
-                sleep(Duration::from_millis(throttle_ms)).await;
-            }
+        let total_pages = resp.total_pages.unwrap_or(1);
+        eprintln!(
+            "Politicians page {}/{} ({} items)",
+            page,
+            total_pages,
+            resp.data.len()
+        );
+        if page >= total_pages || resp.data.is_empty() || cancel.is_cancelled() {
+            break;
         }
 
-        pb.set_message(format!(
-            "{}: {} members ({} total)",
-            name, committee_member_count, memberships.len()
-        ));
-
-        if throttle_ms > 0 {
-            sleep(Duration::from_millis(throttle_ms)).await;
-        }
+        page += 1;
     }
 
-    let inserted = db.replace_all_politician_committees(&memberships)?;
-    db.mark_politicians_enriched()?;
+    Ok((seen, inserted))
+}
+
+/// Page the issuer directory, storing each issuer with its stats and
+/// "last traded by" preview. Returns the number of issuers seen.
+async fn sync_issuer_directory(
+    scraper: &ScrapeClient,
+    db: &mut Db,
+    cancel: &CancellationToken,
+) -> Result<usize> {
+    let mut page = 1;
+    let mut seen = 0;
+
+    loop {
+        let resp = scraper.issuers_page(page).await?;
+        seen += resp.data.len();
+        db.upsert_scraped_issuer_list(&resp.data)?;
+
+        let total_pages = resp.total_pages.unwrap_or(1);
+        eprintln!(
+            "Issuers page {}/{} ({} items)",
+            page,
+            total_pages,
+            resp.data.len()
+        );
+        if page >= total_pages || resp.data.is_empty() || cancel.is_cancelled() {
+            break;
+        }
 
-    pb.finish_with_message(format!(
-        "done: {} memberships across {} committees",
-        memberships.len(),
-        validation::COMMITTEE_MAP.len()
-    ));
+        page += 1;
+    }
 
-    Ok(inserted)
+    Ok(seen)
 }
 
 struct TradeSyncResult {
     trade_count: usize,
     max_pub_date: Option<NaiveDate>,
+    earliest_pub_date: Option<NaiveDate>,
+    /// Stopped by cancellation before the last page; stats still cover the
+    /// pages that were ingested.
+    interrupted: bool,
+    /// Set when the site's pagination cap kept the pass from reaching
+    /// `since_date` (or the oldest trade, without one).
+    cap: Option<PaginationCap>,
+    /// Politician and issuer stats aggregated from the pages ingested.
+    /// Only an unfiltered pass stores them (see [`TradeSyncResult::store_stats`]);
+    /// a backfill shard sees a slice of each member's trades.
+    politician_rows: Vec<PoliticianStatsRow>,
+    issuer_rows: Vec<IssuerStatsRow>,
+}
+
+impl TradeSyncResult {
+    /// Write the pass's listing stats, then reconcile issuers' last trader
+    /// with the stored trades.
+    fn store_stats(&self, db: &mut Db) -> Result<()> {
+        db.upsert_politician_stats(&self.politician_rows)?;
+        db.upsert_issuer_stats(&self.issuer_rows)?;
+        db.recompute_issuer_stats()?;
+        Ok(())
+    }
 }
 
 async fn sync_trades(
     scraper: &ScrapeClient,
     db: &mut Db,
+    filter: &TradeFilter,
     since_date: Option<NaiveDate>,
     with_trade_details: bool,
-    details_delay_ms: u64,
+    cancel: &CancellationToken,
 ) -> Result<TradeSyncResult> {
     let mut page = 1;
+    let mut interrupted = false;
     let mut total_ingested = 0;
     let mut max_pub_date: Option<NaiveDate> = None;
+    let mut earliest_pub_date: Option<NaiveDate> = None;
     let mut total_pages = None;
+    let mut reached_since = false;
+    let mut detector = CapDetector::new();
 
     let mut issuer_stats: HashMap<i64, IssuerAgg> = HashMap::new();
     let mut politician_stats: HashMap<String, PoliticianAgg> = HashMap::new();
 
     loop {
-        let resp = scraper.trades_page(page).await?;
+        let resp = scraper.trades_page_filtered(filter, page).await?;
+        if !detector.observe(page, &resp) {
+            eprintln!("Trades page {} repeated earlier trades; stopping", page);
+            break;
+        }
         total_pages = total_pages.or(resp.total_pages);
         let oldest_date = resp
             .data
@@ -547,6 +786,11 @@ async fn sync_trades(
         if !trades.is_empty() {
             if with_trade_details {
                 for trade in &mut trades {
+                    // The page is still stored; trades left without a
+                    // filing URL are picked up by --enrich later.
+                    if cancel.is_cancelled() {
+                        break;
+                    }
                     match scraper.trade_detail(trade.tx_id).await {
                         Ok(detail) => {
                             trade.filing_url = detail.filing_url;
@@ -556,9 +800,6 @@ async fn sync_trades(
                             eprintln!("Failed to fetch trade {} detail: {}", trade.tx_id, err);
                         }
                     }
-                    if details_delay_ms > 0 {
-                        sleep(Duration::from_millis(details_delay_ms)).await;
-                    }
                 }
             }
 
@@ -574,6 +815,10 @@ async fn sync_trades(
                         Some(current) => current.max(trade_date),
                         None => trade_date,
                     });
+                    earliest_pub_date = Some(match earliest_pub_date {
+                        Some(current) => current.min(trade_date),
+                        None => trade_date,
+                    });
                 }
 
                 update_stats(&mut issuer_stats, &mut politician_stats, trade)?;
@@ -595,21 +840,31 @@ async fn sync_trades(
 
         if let (Some(since), Some(oldest)) = (since_date, oldest_date) {
             if oldest < since {
+                reached_since = true;
                 break;
             }
         }
 
+        if cancel.is_cancelled() {
+            interrupted = true;
+            break;
+        }
+
         page += 1;
     }
 
-    let politician_rows = build_politician_rows(politician_stats);
-    let issuer_rows = build_issuer_rows(issuer_stats);
-    db.upsert_politician_stats(&politician_rows)?;
-    db.upsert_issuer_stats(&issuer_rows)?;
-
     Ok(TradeSyncResult {
         trade_count: total_ingested,
         max_pub_date,
+        earliest_pub_date,
+        interrupted,
+        cap: if reached_since || interrupted {
+            None
+        } else {
+            detector.cap().cloned()
+        },
+        politician_rows: build_politician_rows(politician_stats),
+        issuer_rows: build_issuer_rows(issuer_stats),
     })
 }
 
@@ -692,60 +947,169 @@ fn build_issuer_rows(stats: HashMap<i64, IssuerAgg>) -> Vec<IssuerStatsRow> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use capitoltraders_lib::fixtures::{open_db, table_count, trades_listing_html};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    /// Six trades, newest first, alternating between two politicians.
+    const TRADES: [(i64, &str, &str); 6] = [
+        (6, "P000001", "2024-06-01"),
+        (5, "P000002", "2024-05-01"),
+        (4, "P000001", "2024-04-01"),
+        (3, "P000002", "2024-03-01"),
+        (2, "P000001", "2024-02-01"),
+        (1, "P000002", "2024-01-01"),
+    ];
+
+    /// A trades listing that serves two trades per page and stops paginating
+    /// after page 2: later pages repeat page 2. Filtered listings are small
+    /// enough to fit on one page.
+    struct CappedListing;
+
+    impl wiremock::Respond for CappedListing {
+        fn respond(&self, request: &Request) -> ResponseTemplate {
+            let query: HashMap<String, String> =
+                request.url.query_pairs().into_owned().collect();
+            let page: usize = query.get("page").and_then(|p| p.parse().ok()).unwrap_or(1);
+            let filtered = query.contains_key("politician") || query.contains_key("pubDate");
+            let body = if filtered {
+                let range = query.get("pubDate").and_then(|r| r.split_once(','));
+                let matching: Vec<(i64, &str, &str)> = TRADES
+                    .iter()
+                    .copied()
+                    .filter(|(_, pol, _)| query.get("politician").is_none_or(|p| p == pol))
+                    .filter(|(_, _, date)| {
+                        range.is_none_or(|(from, to)| *date >= from && *date <= to)
+                    })
+                    .collect();
+                trades_listing_html(&matching, 1, matching.len() as i64)
+            } else {
+                let start = (page.min(2) - 1) * 2;
+                trades_listing_html(&TRADES[start..start + 2], 3, TRADES.len() as i64)
+            };
+            ResponseTemplate::new(200).set_body_string(body)
+        }
+    }
 
-    #[test]
-    fn circuit_breaker_new_not_tripped() {
-        let cb = CircuitBreaker::new(3);
-        assert!(!cb.is_tripped());
+    /// `count_trades`, `volume` and `date_last_traded` from politician_stats.
+    fn politician_stats(db: &Db, politician_id: &str) -> (i64, i64, String) {
+        db.conn()
+            .query_row(
+                "SELECT count_trades, volume, date_last_traded FROM politician_stats
+                 WHERE politician_id = ?1",
+                [politician_id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap()
     }
 
-    #[test]
-    fn circuit_breaker_trips_at_threshold() {
-        let mut cb = CircuitBreaker::new(3);
-        cb.record_failure();
-        cb.record_failure();
-        assert!(!cb.is_tripped());
-        cb.record_failure();
-        assert!(cb.is_tripped());
+    async fn capped_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(CappedListing)
+            .mount(&server)
+            .await;
+        server
     }
 
-    #[test]
-    fn circuit_breaker_success_resets_count() {
-        let mut cb = CircuitBreaker::new(3);
-        cb.record_failure();
-        cb.record_failure();
-        cb.record_success();
-        cb.record_failure();
-        cb.record_failure();
-        assert!(!cb.is_tripped());
+    #[tokio::test]
+    async fn capped_listing_is_reported_and_politician_backfill_gets_past_it() {
+        let server = capped_server().await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let mut db = open_db();
+        let cancel = CancellationToken::new();
+
+        let plain = sync_trades(&scraper, &mut db, &TradeFilter::default(), None, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(plain.trade_count, 4);
+        assert_eq!(plain.cap, Some(PaginationCap::Repeated { page: 3 }));
+        assert_eq!(plain.earliest_pub_date, NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(table_count(&db, "trades"), 4);
+
+        let shards = politician_shards(&db.politician_ids().unwrap());
+        assert_eq!(shards.len(), 2);
+        let result = backfill_trades(&scraper, &mut db, &shards, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(result.completed, 2);
+        assert!(result.capped.is_empty());
+        // Trades 1 and 2 were out of reach of the plain listing.
+        assert_eq!(table_count(&db, "trades"), 6);
+        assert_eq!(politician_stats(&db, "P000002"), (3, 150_000, "2024-05-01".to_string()));
+        assert!(db.get_meta("backfill:politician:P000002").unwrap().is_some());
+
+        // Completed shards are skipped on the next run.
+        let rerun = backfill_trades(&scraper, &mut db, &shards, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!((rerun.completed, rerun.skipped), (0, 2));
     }
 
-    #[test]
-    fn circuit_breaker_stays_tripped() {
-        let mut cb = CircuitBreaker::new(2);
-        cb.record_failure();
-        cb.record_failure();
-        assert!(cb.is_tripped());
-        // Recording more failures keeps it tripped
-        cb.record_failure();
-        assert!(cb.is_tripped());
+    #[tokio::test]
+    async fn incremental_sync_within_reach_is_not_capped() {
+        let server = capped_server().await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let mut db = open_db();
+        let cancel = CancellationToken::new();
+
+        let since = NaiveDate::from_ymd_opt(2024, 5, 1);
+        let result = sync_trades(&scraper, &mut db, &TradeFilter::default(), since, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(result.trade_count, 2);
+        assert_eq!(result.cap, None);
     }
 
-    #[test]
-    fn circuit_breaker_threshold_one() {
-        let mut cb = CircuitBreaker::new(1);
-        assert!(!cb.is_tripped());
-        cb.record_failure();
-        assert!(cb.is_tripped());
+    #[tokio::test]
+    async fn month_backfill_resumes_and_reruns_the_current_month() {
+        let server = capped_server().await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let mut db = open_db();
+        let cancel = CancellationToken::new();
+
+        let shards = month_shards(
+            NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(),
+        );
+        let labels: Vec<&str> = shards.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["2024-06", "2024-05", "2024-04", "2024-03", "2024-02", "2024-01"]
+        );
+        assert_eq!(
+            shards[4].filter.pub_date,
+            Some((
+                NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            ))
+        );
+        assert!(shards[0].meta_key.is_none());
+
+        let result = backfill_trades(&scraper, &mut db, &shards, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(result.trade_count, 6);
+        assert_eq!(table_count(&db, "trades"), 6);
+        // Each shard holds one trade; the stats still count all three.
+        assert_eq!(politician_stats(&db, "P000001"), (3, 150_000, "2024-06-01".to_string()));
+
+        let rerun = backfill_trades(&scraper, &mut db, &shards, false, &cancel)
+            .await
+            .unwrap();
+        assert_eq!((rerun.completed, rerun.skipped), (1, 5));
+        // --full pages every shard again.
+        let restart = backfill_trades(&scraper, &mut db, &shards, true, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(restart.completed, 6);
     }
 
     #[test]
-    fn circuit_breaker_alternating_success_failure() {
-        let mut cb = CircuitBreaker::new(3);
-        for _ in 0..10 {
-            cb.record_failure();
-            cb.record_success();
-        }
-        assert!(!cb.is_tripped());
+    fn backfill_mode_parses() {
+        assert_eq!("Politician".parse::<BackfillBy>(), Ok(BackfillBy::Politician));
+        assert_eq!("month".parse::<BackfillBy>(), Ok(BackfillBy::Month));
+        assert!("week".parse::<BackfillBy>().is_err());
     }
 }
@@ -2,17 +2,30 @@
 //!
 //! Fetches contributions for politicians' committees and stores them in SQLite.
 //! Uses Semaphore + JoinSet + mpsc pattern for concurrent fetching with rate limiting.
+//! Concurrency is across committees only: each committee pages through its own
+//! keyset cursor sequentially, and all fetch tasks share the client's rate limiter. Pages
+//! are written by a single receiver, each together with its committee's cursor.
+//! On Ctrl-C each committee stops after the page in flight, so its stored
+//! cursor is where the next run resumes.
+//!
+//! OpenFEC can reorder or backfill receipts between runs, which shifts the
+//! keyset under a stored cursor. Before resuming, the page the cursor was
+//! reached through is refetched and its first sub_id compared with the one
+//! saved; on a mismatch the committee is re-scanned from a few days past the
+//! cursor's receipt date (pages run newest first), and sub_id dedup keeps
+//! the overlap from inserting twice.
 
 use anyhow::{bail, Result};
 use capitoltraders_lib::{
     committee::CommitteeResolver,
     openfec::{
-        rate_limiter::{with_retry, RateLimiter},
+        retry::{with_retry, RequestTracker},
         types::{Contribution, ScheduleAQuery},
         OpenFecClient, OpenFecError,
     },
-    Db,
+    shutdown, CancellationToken, Db, SyncPageAnchor,
 };
+use chrono::NaiveDate;
 use clap::Args;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::PathBuf;
@@ -21,6 +34,8 @@ use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, Semaphore};
 use tokio::task::JoinSet;
 
+use crate::hints;
+
 /// Donation sync CLI arguments.
 #[derive(Args)]
 pub struct SyncDonationsArgs {
@@ -39,8 +54,31 @@ pub struct SyncDonationsArgs {
     /// Donations per API page (default: 100)
     #[arg(long, default_value = "100")]
     pub batch_size: i32,
+
+    /// Sync every politician with FEC mappings, stalest first, checkpointing after each
+    #[arg(long, conflicts_with = "politician")]
+    pub all: bool,
+
+    /// Stop starting new politicians after this many minutes (requires --all)
+    #[arg(long, requires = "all")]
+    pub time_budget: Option<u64>,
+
+    /// Re-sync politicians already marked completed for the cycle (requires --all)
+    #[arg(long, requires = "all")]
+    pub full: bool,
+
+    /// Number of committees fetched concurrently (1-10)
+    #[arg(long, default_value = "3")]
+    pub concurrency: usize,
 }
 
+/// Number of politicians shown in the post-run staleness ranking.
+const NEXT_RANKING_DISPLAY: usize = 10;
+
+/// Days past a shifted cursor's receipt date that a self-healing re-scan
+/// starts from, re-checking donations already stored.
+const RESCAN_OVERLAP_DAYS: i64 = 14;
+
 /// Message sent from fetch tasks to receiver.
 enum DonationMessage {
     Page {
@@ -50,6 +88,16 @@ enum DonationMessage {
         cycle: Option<i32>,
         last_index: i64,
         last_date: String,
+        /// Where this page began, when it was fetched from a cursor.
+        anchor: Option<SyncPageAnchor>,
+        /// Part of a self-healing re-scan.
+        rescan: bool,
+    },
+    /// The keyset shifted under a stored cursor and a re-scan began.
+    SelfHealed {
+        committee_id: String,
+        /// Newest receipt date re-scanned, or `None` for the whole committee.
+        rescan_from: Option<String>,
     },
     Completed {
         politician_id: String,
@@ -89,16 +137,47 @@ impl CircuitBreaker {
     }
 }
 
+/// Pending fetch for one politician/committee pair, with an optional resume
+/// cursor and the anchor to verify it against.
+type CommitteeTask = (
+    String,
+    String,
+    String,
+    Option<(i64, String)>,
+    Option<SyncPageAnchor>,
+);
+
+/// Result of running the concurrent fetch pipeline over a set of committee tasks.
+#[derive(Default)]
+struct PipelineOutcome {
+    inserted: usize,
+    committees_processed: usize,
+    breaker_tripped: bool,
+    interrupted: bool,
+    /// Committees whose stored cursor failed verification and were re-scanned.
+    self_healed: usize,
+    /// Donations a re-scan fetched that were already stored.
+    rechecked: usize,
+}
+
 /// Run the donation sync pipeline.
-pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
+pub async fn run(args: &SyncDonationsArgs, client: OpenFecClient) -> Result<()> {
+    if args.concurrency < 1 || args.concurrency > 10 {
+        bail!("--concurrency must be between 1 and 10");
+    }
+    if args.all {
+        return run_all(args, client).await;
+    }
+
     let start_time = Instant::now();
+    let cancel = shutdown::ctrl_c_token();
 
     // Step 1: Setup - Open DB for politician lookup and committee resolution
     let setup_db = Db::open(&args.db)?;
     setup_db.init()?;
 
-    let client = Arc::new(OpenFecClient::new(api_key)?);
-    let rate_limiter = Arc::new(RateLimiter::default());
+    let tracker = Arc::new(RequestTracker::new());
+    let client = Arc::new(client.with_observer(tracker.clone()));
     let resolver = CommitteeResolver::new(
         Arc::clone(&client),
         Arc::new(Mutex::new(Db::open(&args.db)?)),
@@ -116,7 +195,13 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
             .collect::<Result<Vec<_>, _>>()?;
 
         if matches.is_empty() {
-            bail!("No politician found matching '{}'", name);
+            bail!(
+                "{}",
+                hints::with_hint(
+                    format!("No politician found matching '{}'", name),
+                    hints::politician_hint(&setup_db, name)
+                )
+            );
         } else if matches.len() > 1 {
             eprintln!("Multiple politicians match '{}':", name);
             for (id, full_name) in &matches {
@@ -153,82 +238,308 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
         politicians.len()
     );
 
+    // Prefetch metadata for committees already mapped to these politicians so
+    // resolution below does not look them up one by one.
+    let mut known_committees: Vec<String> = Vec::new();
+    for (politician_id, _) in &politicians {
+        if let Some(ids) = setup_db.get_committees_for_politician(politician_id)? {
+            known_committees.extend(ids);
+        }
+    }
+    let known_refs: Vec<&str> = known_committees.iter().map(String::as_str).collect();
+    match resolver.warm_cache(&known_refs).await {
+        Ok(0) => {}
+        Ok(fetched) => eprintln!("Fetched metadata for {} committee(s)", fetched),
+        Err(e) => eprintln!("Warning: Could not prefetch committee metadata: {}", e),
+    }
+
     // Step 3: For each politician, resolve committees and prepare sync tasks
-    type CommitteeTask = (String, String, String, Option<(i64, String)>);
     let mut committee_tasks: Vec<CommitteeTask> = Vec::new();
 
     for (politician_id, politician_name) in &politicians {
-        // Resolve committees
-        let committees = resolver.resolve_committees(politician_id).await?;
+        if cancel.is_cancelled() {
+            eprintln!("Interrupted before any donations were fetched");
+            return Ok(());
+        }
+        committee_tasks.extend(
+            collect_committee_tasks(
+                &setup_db,
+                &resolver,
+                politician_id,
+                politician_name,
+                args.cycle,
+                args.full,
+            )
+            .await?,
+        );
+    }
 
-        if committees.is_empty() {
-            eprintln!(
-                "Warning: No committees found for {} ({}), skipping",
-                politician_name, politician_id
-            );
+    if committee_tasks.is_empty() {
+        eprintln!("All committees are up to date");
+        return Ok(());
+    }
+
+    // Steps 4-5: Concurrent fetch pipeline with single-threaded DB writes
+    let outcome =
+        sync_committee_tasks(args, &client, &tracker, committee_tasks, start_time, &cancel)
+            .await?;
+
+    // Step 6: Summary
+    print_run_summary(&tracker, &outcome, start_time);
+
+    if outcome.breaker_tripped {
+        bail!("Sync halted due to rate limiting");
+    }
+
+    Ok(())
+}
+
+/// Sync every mapped politician in staleness order, within an optional time budget.
+///
+/// Each politician's committees are fetched to completion before moving on, so the
+/// cursors and completion markers written per page double as a checkpoint: an
+/// interrupted run resumes with the politician that still has an open cursor.
+async fn run_all(args: &SyncDonationsArgs, client: OpenFecClient) -> Result<()> {
+    let start_time = Instant::now();
+    let budget = args.time_budget.map(|m| Duration::from_secs(m * 60));
+    let cancel = shutdown::ctrl_c_token();
+
+    let setup_db = Db::open(&args.db)?;
+    setup_db.init()?;
+
+    let tracker = Arc::new(RequestTracker::new());
+    let client = Arc::new(client.with_observer(tracker.clone()));
+    let resolver = CommitteeResolver::new(
+        Arc::clone(&client),
+        Arc::new(Mutex::new(Db::open(&args.db)?)),
+    );
+
+    let ranking = setup_db.donation_sync_staleness_ranking(args.cycle)?;
+    if ranking.is_empty() {
+        eprintln!("No politicians with FEC mappings found");
+        return Ok(());
+    }
+
+    eprintln!(
+        "Starting donation sync for {} politician(s) in staleness order{}",
+        ranking.len(),
+        match args.time_budget {
+            Some(m) => format!(" (time budget: {} min)", m),
+            None => String::new(),
+        }
+    );
+
+    let mut per_politician: Vec<(String, String, usize)> = Vec::new();
+    let mut skipped_completed = 0usize;
+    let mut total_inserted = 0usize;
+    let mut total_committees = 0usize;
+    let mut total_self_healed = 0usize;
+    let mut total_rechecked = 0usize;
+    let mut breaker_tripped = false;
+    let mut budget_hit = false;
+
+    for candidate in &ranking {
+        if cancel.is_cancelled() {
+            break;
+        }
+        if budget_exhausted(start_time.elapsed(), budget) {
+            budget_hit = true;
+            break;
+        }
+
+        if !args.full && setup_db.is_donation_sync_completed(&candidate.politician_id, args.cycle)? {
+            skipped_completed += 1;
             continue;
         }
 
+        let tasks = collect_committee_tasks(
+            &setup_db,
+            &resolver,
+            &candidate.politician_id,
+            &candidate.name,
+            args.cycle,
+            args.full,
+        )
+        .await?;
+
+        let outcome = if tasks.is_empty() {
+            PipelineOutcome::default()
+        } else {
+            sync_committee_tasks(args, &client, &tracker, tasks, start_time, &cancel).await?
+        };
+
+        total_inserted += outcome.inserted;
+        total_committees += outcome.committees_processed;
+        total_self_healed += outcome.self_healed;
+        total_rechecked += outcome.rechecked;
+        per_politician.push((
+            candidate.politician_id.clone(),
+            candidate.name.clone(),
+            outcome.inserted,
+        ));
+
+        if outcome.breaker_tripped {
+            breaker_tripped = true;
+            break;
+        }
+    }
+
+    eprintln!();
+    if budget_hit {
         eprintln!(
-            "  {} ({}): {} committee(s)",
-            politician_name,
-            politician_id,
-            committees.len()
+            "Time budget exhausted after {} politician(s); re-run to continue",
+            per_politician.len()
         );
+    } else if cancel.is_cancelled() {
+        eprintln!(
+            "Interrupted after {} politician(s); re-run to continue from the saved cursors",
+            per_politician.len()
+        );
+    }
+    eprintln!("Per-politician results:");
+    for (politician_id, name, inserted) in &per_politician {
+        eprintln!("  {} ({}): {} donations inserted", name, politician_id, inserted);
+    }
+    if skipped_completed > 0 {
+        eprintln!(
+            "  Skipped {} politician(s) already completed for this cycle (use --full to re-sync)",
+            skipped_completed
+        );
+    }
 
-        // For each committee, check for existing cursor
-        for committee in &committees {
-            // Load cursor from DB (before spawning tasks)
-            let cursor = setup_db.load_sync_cursor(politician_id, &committee.committee_id, args.cycle)?;
+    print_run_summary(
+        &tracker,
+        &PipelineOutcome {
+            inserted: total_inserted,
+            committees_processed: total_committees,
+            breaker_tripped,
+            interrupted: cancel.is_cancelled(),
+            self_healed: total_self_healed,
+            rechecked: total_rechecked,
+        },
+        start_time,
+    );
 
-            // Check if sync completed recently (within 24 hours) for this specific cycle
-            if cursor.is_none() {
-                let cycle_clause = match args.cycle {
-                    Some(c) => format!("AND election_cycle = {}", c),
-                    None => "AND election_cycle IS NULL".to_string(),
-                };
-                let completed_recently: bool = setup_db.conn()
-                    .query_row(
-                        &format!(
-                            "SELECT EXISTS(
-                                SELECT 1 FROM donation_sync_meta
-                                WHERE politician_id = ?1
-                                  AND committee_id = ?2
-                                  {}
-                                  AND last_index IS NULL
-                                  AND datetime(last_synced_at) > datetime('now', '-24 hours')
-                            )", cycle_clause
-                        ),
-                        [politician_id.as_str(), committee.committee_id.as_str()],
-                        |row| row.get(0),
-                    )?;
-
-                if completed_recently {
-                    eprintln!(
-                        "    Skipping {} (completed within 24 hours)",
-                        committee.name
-                    );
-                    continue;
-                }
-            }
+    let next = setup_db.donation_sync_staleness_ranking(args.cycle)?;
+    eprintln!("Next staleness ranking:");
+    for (idx, candidate) in next.iter().take(NEXT_RANKING_DISPLAY).enumerate() {
+        eprintln!(
+            "  {}. {} ({}) - last synced: {}{}",
+            idx + 1,
+            candidate.name,
+            candidate.politician_id,
+            candidate.last_synced_at.as_deref().unwrap_or("never"),
+            if candidate.has_pending_cursor { " [in progress]" } else { "" }
+        );
+    }
 
-            committee_tasks.push((
-                politician_id.clone(),
-                committee.committee_id.clone(),
-                committee.name.clone(),
-                cursor,
-            ));
-        }
+    if breaker_tripped {
+        bail!("Sync halted due to rate limiting");
     }
 
-    if committee_tasks.is_empty() {
-        eprintln!("All committees are up to date");
-        return Ok(());
+    Ok(())
+}
+
+/// Returns true once the elapsed time has reached the (optional) budget.
+fn budget_exhausted(elapsed: Duration, budget: Option<Duration>) -> bool {
+    budget.is_some_and(|b| elapsed >= b)
+}
+
+/// Resolve a politician's committees and build fetch tasks, skipping committees
+/// whose sync completed within the last 24 hours.
+async fn collect_committee_tasks(
+    setup_db: &Db,
+    resolver: &CommitteeResolver,
+    politician_id: &str,
+    politician_name: &str,
+    cycle: Option<i32>,
+    full: bool,
+) -> Result<Vec<CommitteeTask>> {
+    let mut committee_tasks = Vec::new();
+
+    // Resolve committees
+    let committees = resolver.resolve_committees(politician_id).await?;
+
+    if committees.is_empty() {
+        eprintln!(
+            "Warning: No committees found for {} ({}), skipping",
+            politician_name, politician_id
+        );
+        return Ok(committee_tasks);
+    }
+
+    eprintln!(
+        "  {} ({}): {} committee(s)",
+        politician_name,
+        politician_id,
+        committees.len()
+    );
+
+    // For each committee, check for existing cursor
+    for committee in &committees {
+        // Load cursor from DB (before spawning tasks)
+        let cursor = setup_db.load_sync_cursor(politician_id, &committee.committee_id, cycle)?;
+        let anchor = match cursor {
+            Some(_) => setup_db.load_sync_anchor(politician_id, &committee.committee_id, cycle)?,
+            None => None,
+        };
+
+        // Check if sync completed recently (within 24 hours) for this specific
+        // cycle, unless --full asked to re-sync regardless
+        if cursor.is_none() && !full {
+            let cycle_clause = match cycle {
+                Some(c) => format!("AND election_cycle = {}", c),
+                None => "AND election_cycle IS NULL".to_string(),
+            };
+            let completed_recently: bool = setup_db.conn()
+                .query_row(
+                    &format!(
+                        "SELECT EXISTS(
+                            SELECT 1 FROM donation_sync_meta
+                            WHERE politician_id = ?1
+                              AND committee_id = ?2
+                              {}
+                              AND last_index IS NULL
+                              AND datetime(last_synced_at) > datetime('now', '-24 hours')
+                        )", cycle_clause
+                    ),
+                    [politician_id, committee.committee_id.as_str()],
+                    |row| row.get(0),
+                )?;
+
+            if completed_recently {
+                eprintln!(
+                    "    Skipping {} (completed within 24 hours)",
+                    committee.name
+                );
+                continue;
+            }
+        }
+
+        committee_tasks.push((
+            politician_id.to_string(),
+            committee.committee_id.clone(),
+            committee.name.clone(),
+            cursor,
+            anchor,
+        ));
     }
 
+    Ok(committee_tasks)
+}
+
+/// Fetch all pages for the given committee tasks concurrently and persist them.
+async fn sync_committee_tasks(
+    args: &SyncDonationsArgs,
+    client: &Arc<OpenFecClient>,
+    tracker: &Arc<RequestTracker>,
+    committee_tasks: Vec<CommitteeTask>,
+    start_time: Instant,
+    cancel: &CancellationToken,
+) -> Result<PipelineOutcome> {
     // Step 4: Concurrent committee fetch pipeline
-    const CONCURRENCY: usize = 3;
     const CIRCUIT_BREAKER_THRESHOLD: usize = 5;
+    let concurrency = args.concurrency;
 
     let pb = ProgressBar::new(committee_tasks.len() as u64);
     pb.set_style(
@@ -239,26 +550,57 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
     );
     pb.set_message("syncing donations...");
 
-    let semaphore = Arc::new(Semaphore::new(CONCURRENCY));
-    let (tx, mut rx) = mpsc::channel::<DonationMessage>(CONCURRENCY * 2);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let (tx, mut rx) = mpsc::channel::<DonationMessage>(concurrency * 2);
     let mut join_set = JoinSet::new();
 
     // Spawn tasks for each committee
-    for (politician_id, committee_id, _committee_name, cursor) in committee_tasks {
+    for (politician_id, committee_id, _committee_name, cursor, anchor) in committee_tasks {
         let sem = Arc::clone(&semaphore);
         let sender = tx.clone();
-        let client_clone = Arc::clone(&client);
-        let rl = Arc::clone(&rate_limiter);
+        let client_clone = Arc::clone(client);
+        let tracker = Arc::clone(tracker);
         let cycle = args.cycle;
         let per_page = args.batch_size;
+        let cancel = cancel.clone();
 
         join_set.spawn(async move {
             let _permit = sem.acquire().await.expect("semaphore closed");
 
             // Keyset pagination loop
             let mut current_cursor = cursor;
+            // Newest receipt date of a self-healing re-scan, once one starts
+            let mut rescan_from: Option<String> = None;
+            let mut rescan = false;
+
+            if let (Some((_, last_date)), Some(anchor)) = (&current_cursor, &anchor) {
+                match verify_anchor(&client_clone, &tracker, &committee_id, cycle, anchor).await {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        rescan_from = rescan_window_start(last_date);
+                        rescan = true;
+                        current_cursor = None;
+                        let _ = sender.send(DonationMessage::SelfHealed {
+                            committee_id: committee_id.clone(),
+                            rescan_from: rescan_from.clone(),
+                        }).await;
+                    }
+                    Err(e) => {
+                        let _ = sender.send(DonationMessage::Error {
+                            committee_id: committee_id.clone(),
+                            error: e,
+                        }).await;
+                        return;
+                    }
+                }
+            }
 
             loop {
+                // Checkpoint: every page sent so far carried its cursor
+                if cancel.is_cancelled() {
+                    break;
+                }
+
                 // Build query with cursor
                 let mut query = ScheduleAQuery::default()
                     .with_committee_id(&committee_id)
@@ -274,11 +616,15 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
                         .with_last_contribution_receipt_date(last_date);
                 }
 
-                // Fetch page from API with rate limiting and retry
+                if let Some(ref max_date) = rescan_from {
+                    query = query.with_max_date(max_date);
+                }
+
+                // Fetch page from API; the client paces, with_retry retries 429s
                 let client_ref = &client_clone;
                 let query_ref = &query;
                 let result = with_retry(
-                    &rl,
+                    &tracker,
                     3,
                     Duration::from_secs(60),
                     || async move { client_ref.get_schedule_a(query_ref).await },
@@ -297,6 +643,16 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
                             break;
                         }
 
+                        // First sub_id of a page fetched from a cursor, checked on resume
+                        let anchor = match (&current_cursor, &response.results[0].sub_id) {
+                            (Some((page_index, page_date)), Some(sub_id)) => Some(SyncPageAnchor {
+                                page_index: *page_index,
+                                page_receipt_date: page_date.clone(),
+                                first_sub_id: sub_id.clone(),
+                            }),
+                            _ => None,
+                        };
+
                         // Extract pagination cursor
                         if let Some(ref indexes) = response.pagination.last_indexes {
                             let last_index = indexes.last_index;
@@ -310,6 +666,8 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
                                 cycle,
                                 last_index,
                                 last_date: last_date.clone(),
+                                anchor,
+                                rescan,
                             }).await;
 
                             // Update cursor for next iteration
@@ -323,6 +681,8 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
                                 cycle,
                                 last_index: 0,
                                 last_date: String::new(),
+                                anchor: None,
+                                rescan,
                             }).await;
 
                             let _ = sender.send(DonationMessage::Completed {
@@ -354,6 +714,8 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
 
     let mut total_synced = 0usize;
     let mut committees_processed = 0usize;
+    let mut self_healed = 0usize;
+    let mut rechecked = 0usize;
     let mut breaker = CircuitBreaker::new(CIRCUIT_BREAKER_THRESHOLD);
 
     while let Some(message) = rx.recv().await {
@@ -365,31 +727,50 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
                 cycle,
                 last_index,
                 last_date,
+                anchor,
+                rescan,
             } => {
                 // Save donations with cursor atomically
-                let count = receiver_db.save_sync_cursor_with_donations(
+                let count = receiver_db.save_sync_page(
                     &politician_id,
                     &committee_id,
                     &contributions,
                     cycle,
-                    last_index,
-                    &last_date,
+                    anchor.as_ref(),
+                    (last_index, &last_date),
                 )?;
 
                 total_synced += count;
-                let budget_str = match rate_limiter.remaining_budget() {
-                    Some(b) => format!("{}", b),
-                    None => "?".to_string(),
+                if rescan {
+                    let keyed = contributions.iter().filter(|c| c.sub_id.is_some()).count();
+                    rechecked += keyed - count;
+                }
+                let budget_str = match client.request_budget() {
+                    (available, Some(burst)) => format!("{}/{}", available, burst),
+                    (_, None) => "unlimited".to_string(),
                 };
                 pb.set_message(format!(
-                    "{} donations synced | budget: {}/{} ({:.1}s)",
+                    "{} donations synced | budget: {} ({:.1}s)",
                     total_synced,
                     budget_str,
-                    rate_limiter.max_requests(),
                     start_time.elapsed().as_secs_f64()
                 ));
                 breaker.record_success();
             }
+            DonationMessage::SelfHealed {
+                committee_id,
+                rescan_from,
+            } => {
+                self_healed += 1;
+                pb.println(format!(
+                    "  Keyset shifted under the saved cursor for {}; re-scanning {}",
+                    committee_id,
+                    match rescan_from {
+                        Some(date) => format!("from {}", date),
+                        None => "from the start".to_string(),
+                    }
+                ));
+            }
             DonationMessage::Completed {
                 politician_id,
                 committee_id,
@@ -436,17 +817,65 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
     }
 
     pb.finish_with_message(format!(
-        "Sync complete: {} donations synced",
+        "Sync {}: {} donations synced",
+        if cancel.is_cancelled() { "interrupted" } else { "complete" },
         total_synced
     ));
 
-    // Step 6: Summary
+    Ok(PipelineOutcome {
+        inserted: total_synced,
+        committees_processed,
+        breaker_tripped: breaker.is_tripped(),
+        interrupted: cancel.is_cancelled(),
+        self_healed,
+        rechecked,
+    })
+}
+
+/// Refetch the page a stored cursor was reached through and check that it
+/// still starts with the sub_id saved with it.
+async fn verify_anchor(
+    client: &OpenFecClient,
+    tracker: &RequestTracker,
+    committee_id: &str,
+    cycle: Option<i32>,
+    anchor: &SyncPageAnchor,
+) -> Result<bool, OpenFecError> {
+    let mut query = ScheduleAQuery::default()
+        .with_committee_id(committee_id)
+        .with_per_page(1)
+        .with_last_index(anchor.page_index)
+        .with_last_contribution_receipt_date(&anchor.page_receipt_date);
+    if let Some(c) = cycle {
+        query = query.with_cycle(c);
+    }
+    let query_ref = &query;
+    let response = with_retry(tracker, 3, Duration::from_secs(60), || async move {
+        client.get_schedule_a(query_ref).await
+    })
+    .await?;
+    Ok(response.results.first().and_then(|c| c.sub_id.as_deref())
+        == Some(anchor.first_sub_id.as_str()))
+}
+
+/// Newest receipt date a self-healing re-scan fetches: the cursor's date
+/// plus [`RESCAN_OVERLAP_DAYS`]. `None` (re-scan everything) when the date
+/// does not parse.
+fn rescan_window_start(last_date: &str) -> Option<String> {
+    let date = NaiveDate::parse_from_str(last_date.get(..10)?, "%Y-%m-%d").ok()?;
+    Some((date + chrono::Duration::days(RESCAN_OVERLAP_DAYS)).format("%Y-%m-%d").to_string())
+}
+
+/// Print elapsed time and OpenFEC request statistics for a completed run.
+fn print_run_summary(tracker: &RequestTracker, outcome: &PipelineOutcome, start_time: Instant) {
     let elapsed = start_time.elapsed();
-    let api_summary = rate_limiter.tracker().summary();
+    let api_summary = tracker.summary();
     eprintln!();
     eprintln!(
-        "Donation sync complete: {} donations synced across {} committees",
-        total_synced, committees_processed
+        "Donation sync {}: {} donations synced across {} committees",
+        if outcome.interrupted { "interrupted" } else { "complete" },
+        outcome.inserted,
+        outcome.committees_processed
     );
     eprintln!(
         "  Elapsed time: {:.1}s",
@@ -459,16 +888,480 @@ pub async fn run(args: &SyncDonationsArgs, api_key: String) -> Result<()> {
         api_summary.requests_rate_limited,
         api_summary.requests_failed
     );
+    if outcome.self_healed > 0 {
+        eprintln!(
+            "  Self-healed {} shifted cursor(s); re-checked {} donations already stored",
+            outcome.self_healed, outcome.rechecked
+        );
+    }
+    if api_summary.total_throttle_secs > 0.0 {
+        eprintln!(
+            "  Time waiting on the rate limiter: {:.1}s",
+            api_summary.total_throttle_secs
+        );
+    }
     if api_summary.total_backoff_secs > 0.0 {
         eprintln!(
             "  Time in backoff: {:.1}s",
             api_summary.total_backoff_secs
         );
     }
+}
 
-    if breaker.is_tripped() {
-        bail!("Sync halted due to rate limiting");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_none_never_exhausted() {
+        assert!(!budget_exhausted(Duration::from_secs(86_400), None));
     }
 
-    Ok(())
+    #[test]
+    fn budget_exhausted_at_limit() {
+        let budget = Some(Duration::from_secs(600));
+        assert!(!budget_exhausted(Duration::from_secs(599), budget));
+        assert!(budget_exhausted(Duration::from_secs(600), budget));
+        assert!(budget_exhausted(Duration::from_secs(601), budget));
+    }
+
+    #[test]
+    fn budget_zero_stops_immediately() {
+        assert!(budget_exhausted(Duration::ZERO, Some(Duration::ZERO)));
+    }
+
+    fn temp_db_path(name: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "capitoltraders-{}-{}-{}.db",
+            name,
+            std::process::id(),
+            nanos
+        ))
+    }
+
+    fn test_args(db: PathBuf, concurrency: usize) -> SyncDonationsArgs {
+        SyncDonationsArgs {
+            db,
+            politician: None,
+            cycle: None,
+            batch_size: 100,
+            all: false,
+            time_budget: None,
+            full: false,
+            concurrency,
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_committee_keeps_its_cursor_and_others_complete() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let page = include_str!("../../../capitoltraders_lib/tests/fixtures/openfec_schedule_a.json");
+        let empty = r#"{"results": [], "pagination": {"count": 0, "per_page": 100, "last_indexes": null}}"#;
+
+        // C_OK: one page, then an empty page after the cursor
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .and(query_param("committee_id", "C_OK"))
+            .and(query_param("last_index", "230880619"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(empty))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .and(query_param("committee_id", "C_OK"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(page))
+            .mount(&server)
+            .await;
+        // C_FAIL: resumes from a stored cursor and the server errors
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .and(query_param("committee_id", "C_FAIL"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let db_path = temp_db_path("sync-donations");
+        {
+            let db = Db::open(&db_path).unwrap();
+            db.init().unwrap();
+            db.save_sync_cursor_with_donations("P000001", "C_FAIL", &[], None, 111, "2024-01-01")
+                .unwrap();
+        }
+
+        let client = Arc::new(
+            OpenFecClient::with_base_url(&server.uri(), "test-key".to_string()).unwrap(),
+        );
+        let tracker = Arc::new(RequestTracker::new());
+        let tasks: Vec<CommitteeTask> = vec![
+            ("P000001".to_string(), "C_OK".to_string(), "Ok Committee".to_string(), None, None),
+            (
+                "P000001".to_string(),
+                "C_FAIL".to_string(),
+                "Failing Committee".to_string(),
+                Some((111, "2024-01-01".to_string())),
+                None,
+            ),
+        ];
+
+        let outcome = sync_committee_tasks(
+            &test_args(db_path.clone(), 2),
+            &client,
+            &tracker,
+            tasks,
+            Instant::now(),
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.inserted, 2);
+        assert_eq!(outcome.committees_processed, 1);
+        assert!(!outcome.breaker_tripped);
+
+        let db = Db::open(&db_path).unwrap();
+        assert_eq!(db.load_sync_cursor("P000001", "C_OK", None).unwrap(), None);
+        assert_eq!(
+            db.load_sync_cursor("P000001", "C_FAIL", None).unwrap(),
+            Some((111, "2024-01-01".to_string()))
+        );
+        drop(db);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    /// Serves a page and cancels the run, as a Ctrl-C during the request would.
+    struct CancelOnRequest {
+        cancel: CancellationToken,
+        body: &'static str,
+    }
+
+    impl wiremock::Respond for CancelOnRequest {
+        fn respond(&self, _request: &wiremock::Request) -> wiremock::ResponseTemplate {
+            self.cancel.cancel();
+            wiremock::ResponseTemplate::new(200).set_body_string(self.body)
+        }
+    }
+
+    #[tokio::test]
+    async fn cancellation_mid_batch_stops_at_a_saved_cursor() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        let cancel = CancellationToken::new();
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .respond_with(CancelOnRequest {
+                cancel: cancel.clone(),
+                body: include_str!("../../../capitoltraders_lib/tests/fixtures/openfec_schedule_a.json"),
+            })
+            .mount(&server)
+            .await;
+
+        let db_path = temp_db_path("sync-donations-cancel");
+        Db::open(&db_path).unwrap().init().unwrap();
+
+        let client = Arc::new(
+            OpenFecClient::with_base_url(&server.uri(), "test-key".to_string()).unwrap(),
+        );
+        let tasks: Vec<CommitteeTask> = ["C_ONE", "C_TWO"]
+            .iter()
+            .map(|id| ("P000001".to_string(), id.to_string(), id.to_string(), None, None))
+            .collect();
+
+        let outcome = sync_committee_tasks(
+            &test_args(db_path.clone(), 1),
+            &client,
+            &Arc::new(RequestTracker::new()),
+            tasks,
+            Instant::now(),
+            &cancel,
+        )
+        .await
+        .unwrap();
+
+        // The page in flight is written; nothing after it is requested.
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(outcome.interrupted);
+        assert_eq!(outcome.inserted, 2);
+        assert_eq!(outcome.committees_processed, 0);
+
+        let fetched = requests[0]
+            .url
+            .query_pairs()
+            .find(|(k, _)| k == "committee_id")
+            .map(|(_, v)| v.into_owned())
+            .unwrap();
+        let queued = if fetched == "C_ONE" { "C_TWO" } else { "C_ONE" };
+
+        let db = Db::open(&db_path).unwrap();
+        assert_eq!(
+            db.load_sync_cursor("P000001", &fetched, None).unwrap(),
+            Some((230880619, "2024-03-14".to_string()))
+        );
+        assert_eq!(db.load_sync_cursor("P000001", queued, None).unwrap(), None);
+        let stored: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM donations", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, 2);
+        drop(db);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    /// Receipts as (sub_id, date, index), shared with the mock between runs.
+    type Receipts = Arc<Mutex<Vec<(&'static str, &'static str, i64)>>>;
+
+    /// A committee's receipts as OpenFEC pages them: newest first, ordered by
+    /// (receipt date, index), with `last_index` / `last_contribution_receipt_date`
+    /// selecting everything after a cursor. Records can be rewritten between
+    /// runs to shift the keyset.
+    struct KeysetServer {
+        records: Receipts,
+        cancel_on_request: Option<(usize, CancellationToken)>,
+        served: std::sync::atomic::AtomicUsize,
+    }
+
+    impl wiremock::Respond for KeysetServer {
+        fn respond(&self, request: &wiremock::Request) -> wiremock::ResponseTemplate {
+            let served = self.served.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            if let Some((n, ref cancel)) = self.cancel_on_request {
+                if served == n {
+                    cancel.cancel();
+                }
+            }
+            let param = |name: &str| {
+                request
+                    .url
+                    .query_pairs()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.into_owned())
+            };
+            let per_page: usize = param("per_page").map_or(100, |v| v.parse().unwrap());
+            let cursor = param("last_index").map(|index| {
+                (param("last_contribution_receipt_date").unwrap(), index.parse::<i64>().unwrap())
+            });
+            let max_date = param("max_date");
+
+            let mut records = self.records.lock().unwrap().clone();
+            records.sort_by(|a, b| (b.1, b.2).cmp(&(a.1, a.2)));
+            let page: Vec<_> = records
+                .into_iter()
+                .filter(|(_, date, _)| max_date.as_deref().is_none_or(|max| *date <= max))
+                .filter(|(_, date, index)| {
+                    cursor
+                        .as_ref()
+                        .is_none_or(|(d, i)| (*date, *index) < (d.as_str(), *i))
+                })
+                .take(per_page)
+                .collect();
+            let results: Vec<serde_json::Value> = page
+                .iter()
+                .map(|(sub_id, date, _)| {
+                    serde_json::json!({
+                        "sub_id": sub_id,
+                        "contribution_receipt_date": date,
+                        "contribution_receipt_amount": 100.0,
+                    })
+                })
+                .collect();
+            let last_indexes = page.last().map(|(_, date, index)| {
+                serde_json::json!({"last_index": index, "last_contribution_receipt_date": date})
+            });
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": results,
+                "pagination": {"count": results.len(), "per_page": per_page, "last_indexes": last_indexes},
+            }))
+        }
+    }
+
+    /// Eight receipts, two of them sharing a date with a third.
+    fn keyset_records() -> Vec<(&'static str, &'static str, i64)> {
+        vec![
+            ("S1", "2024-03-10", 10),
+            ("S2", "2024-03-09", 9),
+            ("S3", "2024-03-08", 8),
+            ("S4", "2024-03-08", 7),
+            ("S5", "2024-03-08", 6),
+            ("S6", "2024-03-07", 5),
+            ("S7", "2024-03-06", 4),
+            ("S8", "2024-03-05", 3),
+        ]
+    }
+
+    /// Sync two pages of `records` and stop, leaving a cursor after S4 whose
+    /// anchor is the page that began with S3. Returns the mock server.
+    async fn sync_two_pages(
+        db_path: &PathBuf,
+        records: &Receipts,
+    ) -> wiremock::MockServer {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer};
+
+        let server = MockServer::start().await;
+        let cancel = CancellationToken::new();
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .respond_with(KeysetServer {
+                records: Arc::clone(records),
+                cancel_on_request: Some((2, cancel.clone())),
+                served: Default::default(),
+            })
+            .mount(&server)
+            .await;
+        Db::open(db_path).unwrap().init().unwrap();
+
+        let client = Arc::new(
+            OpenFecClient::with_base_url(&server.uri(), "test-key".to_string()).unwrap(),
+        );
+        let mut args = test_args(db_path.clone(), 1);
+        args.batch_size = 2;
+        let tasks = vec![("P000001".to_string(), "C_KEY".to_string(), "Keyset".to_string(), None, None)];
+        let outcome = sync_committee_tasks(
+            &args,
+            &client,
+            &Arc::new(RequestTracker::new()),
+            tasks,
+            Instant::now(),
+            &cancel,
+        )
+        .await
+        .unwrap();
+        assert_eq!(outcome.inserted, 4);
+        assert!(outcome.interrupted);
+        server.reset().await;
+        server
+    }
+
+    /// Resume the committee from its stored cursor and anchor.
+    async fn resume(
+        db_path: &PathBuf,
+        server: &wiremock::MockServer,
+        records: &Receipts,
+    ) -> PipelineOutcome {
+        use wiremock::matchers::{method, path};
+        use wiremock::Mock;
+
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .respond_with(KeysetServer {
+                records: Arc::clone(records),
+                cancel_on_request: None,
+                served: Default::default(),
+            })
+            .mount(server)
+            .await;
+        let (cursor, anchor) = {
+            let db = Db::open(db_path).unwrap();
+            (
+                db.load_sync_cursor("P000001", "C_KEY", None).unwrap(),
+                db.load_sync_anchor("P000001", "C_KEY", None).unwrap(),
+            )
+        };
+        assert_eq!(cursor, Some((7, "2024-03-08".to_string())));
+        assert_eq!(anchor.as_ref().map(|a| a.first_sub_id.as_str()), Some("S3"));
+
+        let client = Arc::new(
+            OpenFecClient::with_base_url(&server.uri(), "test-key".to_string()).unwrap(),
+        );
+        let mut args = test_args(db_path.clone(), 1);
+        args.batch_size = 2;
+        let tasks = vec![("P000001".to_string(), "C_KEY".to_string(), "Keyset".to_string(), cursor, anchor)];
+        sync_committee_tasks(
+            &args,
+            &client,
+            &Arc::new(RequestTracker::new()),
+            tasks,
+            Instant::now(),
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn stored_sub_ids(db_path: &PathBuf) -> Vec<String> {
+        let db = Db::open(db_path).unwrap();
+        let mut stmt = db.conn().prepare("SELECT sub_id FROM donations ORDER BY sub_id").unwrap();
+        let ids = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap();
+        ids
+    }
+
+    #[tokio::test]
+    async fn stable_keyset_resumes_from_the_cursor() {
+        let db_path = temp_db_path("sync-donations-stable");
+        let records = Arc::new(Mutex::new(keyset_records()));
+        let server = sync_two_pages(&db_path, &records).await;
+
+        let outcome = resume(&db_path, &server, &records).await;
+
+        assert_eq!(outcome.self_healed, 0);
+        assert_eq!(outcome.rechecked, 0);
+        assert_eq!(outcome.inserted, 4);
+        assert_eq!(outcome.committees_processed, 1);
+        assert_eq!(stored_sub_ids(&db_path), ["S1", "S2", "S3", "S4", "S5", "S6", "S7", "S8"]);
+        let _ = std::fs::remove_file(&db_path);
+    }
+
+    #[tokio::test]
+    async fn shifted_keyset_self_heals_without_gaps_or_duplicates() {
+        let db_path = temp_db_path("sync-donations-shifted");
+        let records = Arc::new(Mutex::new(keyset_records()));
+        let server = sync_two_pages(&db_path, &records).await;
+
+        // Upstream re-indexes the 2024-03-08 receipts (S5 now sorts first) and
+        // backfills SB ahead of S2, so the stored cursor would skip both.
+        *records.lock().unwrap() = vec![
+            ("S1", "2024-03-10", 10),
+            ("SB", "2024-03-09", 11),
+            ("S2", "2024-03-09", 9),
+            ("S5", "2024-03-08", 8),
+            ("S3", "2024-03-08", 7),
+            ("S4", "2024-03-08", 6),
+            ("S6", "2024-03-07", 5),
+            ("S7", "2024-03-06", 4),
+            ("S8", "2024-03-05", 3),
+        ];
+
+        let outcome = resume(&db_path, &server, &records).await;
+
+        assert_eq!(outcome.self_healed, 1);
+        assert_eq!(outcome.rechecked, 4);
+        assert_eq!(outcome.inserted, 5);
+        assert_eq!(outcome.committees_processed, 1);
+        assert_eq!(
+            stored_sub_ids(&db_path),
+            ["S1", "S2", "S3", "S4", "S5", "S6", "S7", "S8", "SB"]
+        );
+
+        // The re-scan is bounded to the overlap window past the cursor date
+        let requests = server.received_requests().await.unwrap();
+        let max_dates: Vec<String> = requests
+            .iter()
+            .filter_map(|r| {
+                r.url
+                    .query_pairs()
+                    .find(|(k, _)| k == "max_date")
+                    .map(|(_, v)| v.into_owned())
+            })
+            .collect();
+        assert!(!max_dates.is_empty());
+        assert!(max_dates.iter().all(|d| d == "2024-03-22"));
+
+        let db = Db::open(&db_path).unwrap();
+        assert_eq!(db.load_sync_cursor("P000001", "C_KEY", None).unwrap(), None);
+        drop(db);
+        let _ = std::fs::remove_file(&db_path);
+    }
 }
@@ -6,19 +6,20 @@ use capitoltraders_lib::analytics::{
 };
 use capitoltraders_lib::types::Trade;
 use capitoltraders_lib::validation;
-use capitoltraders_lib::{Db, DbTradeFilter, DbTradeRow, ScrapeClient, ScrapedTrade};
+use capitoltraders_lib::{Db, DbTradeFilter, DbTradeRow, ScrapeClient, ScrapedTrade, TradeStore};
 use chrono::{NaiveDate, Utc};
 use clap::Args;
+use schemars::JsonSchema;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::time::Duration;
-use tokio::time::sleep;
 
+use crate::hints;
 use crate::output::{
     print_enriched_trades_csv, print_enriched_trades_markdown, print_enriched_trades_table,
-    print_enriched_trades_xml, print_json, print_trades_csv, print_trades_markdown,
-    print_trades_table, print_trades_xml, OutputFormat,
+    print_enriched_trades_xml, print_json, print_trade_revisions_csv,
+    print_trade_revisions_markdown, print_trade_revisions_table, print_trade_revisions_xml,
+    print_trades_csv, print_trades_markdown, print_trades_table, print_trades_xml, OutputFormat,
 };
 
 /// Arguments for the `trades` subcommand.
@@ -64,19 +65,19 @@ pub struct TradesArgs {
     #[arg(long, conflicts_with_all = ["tx_since", "tx_until"])]
     pub tx_days: Option<i64>,
 
-    /// Filter trades published on/after this date (YYYY-MM-DD)
+    /// Filter trades published on/after this date (YYYY-MM-DD, or relative: 30d, 6m, 1y, ytd)
     #[arg(long, conflicts_with = "days")]
     pub since: Option<String>,
 
-    /// Filter trades published on/before this date (YYYY-MM-DD)
+    /// Filter trades published on/before this date (YYYY-MM-DD or relative)
     #[arg(long, conflicts_with = "days")]
     pub until: Option<String>,
 
-    /// Filter by transaction date on/after (YYYY-MM-DD)
+    /// Filter by transaction date on/after (YYYY-MM-DD or relative)
     #[arg(long, conflicts_with = "tx_days")]
     pub tx_since: Option<String>,
 
-    /// Filter by transaction date on/before (YYYY-MM-DD)
+    /// Filter by transaction date on/before (YYYY-MM-DD or relative)
     #[arg(long, conflicts_with = "tx_days")]
     pub tx_until: Option<String>,
 
@@ -140,7 +141,8 @@ pub struct TradesArgs {
     #[arg(long)]
     pub asc: bool,
 
-    /// Delay between trade detail requests in milliseconds
+    /// Pace trade detail requests to one per N milliseconds (ignored when
+    /// the config sets `[capitoltrades] rate`)
     #[arg(long, default_value = "250")]
     pub details_delay_ms: u64,
 
@@ -151,6 +153,16 @@ pub struct TradesArgs {
     /// Show donation context for traded securities (requires synced donations and employer mappings)
     #[arg(long)]
     pub show_donor_context: bool,
+
+    /// List every trade in one disclosure filing, by filing URL or numeric
+    /// filing ID (requires --db; other filters are ignored)
+    #[arg(long, value_name = "URL|ID")]
+    pub filing: Option<String>,
+
+    /// Show recorded upstream edits of one trade (requires --db; other
+    /// filters are ignored)
+    #[arg(long, value_name = "TX_ID")]
+    pub revisions: Option<i64>,
 }
 
 /// Executes the trades subcommand: validates inputs, scrapes results,
@@ -165,6 +177,12 @@ pub async fn run(args: &TradesArgs, scraper: &ScrapeClient, format: &OutputForma
     if args.show_donor_context {
         eprintln!("Note: --show-donor-context requires --db mode.");
     }
+    if args.filing.is_some() {
+        bail!("--filing requires --db");
+    }
+    if args.revisions.is_some() {
+        bail!("--revisions requires --db");
+    }
 
     if args.committee.is_some() {
         bail!("--committee is not supported in scrape mode");
@@ -324,26 +342,26 @@ pub async fn run(args: &TradesArgs, scraper: &ScrapeClient, format: &OutputForma
         });
     }
 
-    // Parse absolute date filters
+    // Parse date filters (ISO or relative to today)
     let since_date = args
         .since
         .as_ref()
-        .map(|s| validation::validate_date(s))
+        .map(|s| validation::validate_since_date(s))
         .transpose()?;
     let until_date = args
         .until
         .as_ref()
-        .map(|s| validation::validate_date(s))
+        .map(|s| validation::validate_since_date(s))
         .transpose()?;
     let tx_since_date = args
         .tx_since
         .as_ref()
-        .map(|s| validation::validate_date(s))
+        .map(|s| validation::validate_since_date(s))
         .transpose()?;
     let tx_until_date = args
         .tx_until
         .as_ref()
-        .map(|s| validation::validate_date(s))
+        .map(|s| validation::validate_since_date(s))
         .transpose()?;
 
     if let (Some(s), Some(u)) = (since_date, until_date) {
@@ -417,9 +435,6 @@ pub async fn run(args: &TradesArgs, scraper: &ScrapeClient, format: &OutputForma
         let detail = scraper.trade_detail(trade.tx_id).await?;
         trade.filing_url = detail.filing_url;
         trade.filing_id = detail.filing_id;
-        if args.details_delay_ms > 0 {
-            sleep(Duration::from_millis(args.details_delay_ms)).await;
-        }
     }
 
     let mut out: Vec<Trade> = Vec::with_capacity(trades.len());
@@ -462,18 +477,6 @@ fn parse_date(value: &str) -> Option<NaiveDate> {
         .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
 }
 
-/// Capitalize a validated party string to match DB storage format.
-///
-/// The validation module returns lowercase ("democrat", "republican", "other")
-/// but the database stores the capitalized form from the API ("Democrat", etc.).
-fn capitalize_party(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(c) => c.to_uppercase().to_string() + chars.as_str(),
-    }
-}
-
 /// Executes the trades subcommand against the local SQLite database.
 ///
 /// Builds a [`DbTradeFilter`] from the subset of CLI flags supported on the
@@ -485,7 +488,6 @@ pub async fn run_db(
 ) -> Result<()> {
     // Bail on filters not supported by the DB query path
     let unsupported: &[(&str, bool)] = &[
-        ("--committee", args.committee.is_some()),
         ("--trade-size", args.trade_size.is_some()),
         ("--market-cap", args.market_cap.is_some()),
         ("--asset-type", args.asset_type.is_some()),
@@ -493,7 +495,6 @@ pub async fn run_db(
         ("--sector", args.sector.is_some()),
         ("--gender", args.gender.is_some()),
         ("--chamber", args.chamber.is_some()),
-        ("--politician-id", args.politician_id.is_some()),
         ("--issuer-state", args.issuer_state.is_some()),
         ("--country", args.country.is_some()),
         ("--issuer-id", args.issuer_id.is_some()),
@@ -506,13 +507,18 @@ pub async fn run_db(
         if *present {
             bail!(
                 "{} is not yet supported with --db. Supported filters: \
-                 --party, --state, --tx-type, --name, --issuer, --since, --until, --days",
+                 --party, --state, --committee, --tx-type, --name, --politician-id, --issuer, \
+                 --since, --until, --days",
                 flag
             );
         }
     }
 
-    let db = Db::open(db_path)?;
+    let db = Db::open_read_only(db_path)?;
+
+    if let Some(tx_id) = args.revisions {
+        return print_trade_revisions(&db, tx_id, format);
+    }
 
     // Build filter from supported args
     let mut filter = DbTradeFilter::default();
@@ -521,7 +527,7 @@ pub async fn run_db(
         let mut parts = Vec::new();
         for item in val.split(',') {
             let validated = validation::validate_party(item.trim())?;
-            parts.push(capitalize_party(&validated.to_string()));
+            parts.push(validated.to_string());
         }
         filter.party = Some(parts.join(","));
     }
@@ -535,6 +541,14 @@ pub async fn run_db(
         filter.state = Some(parts.join(","));
     }
 
+    if let Some(ref val) = args.committee {
+        let mut parts = Vec::new();
+        for item in val.split(',') {
+            parts.push(validation::validate_committee(item)?);
+        }
+        filter.committee = Some(parts.join(","));
+    }
+
     if let Some(ref val) = args.tx_type {
         let mut parts = Vec::new();
         for item in val.split(',') {
@@ -549,6 +563,14 @@ pub async fn run_db(
         filter.name = Some(validated.to_string());
     }
 
+    if let Some(ref val) = args.politician_id {
+        let mut ids = Vec::new();
+        for item in val.split(',') {
+            ids.push(validation::validate_politician_id(item.trim())?);
+        }
+        filter.politician_ids = Some(ids);
+    }
+
     if let Some(ref val) = args.issuer {
         let validated = validation::validate_search(val)?;
         filter.issuer = Some(validated.to_string());
@@ -562,12 +584,12 @@ pub async fn run_db(
         let since_date = today - chrono::Duration::days(validated);
         filter.since = Some(since_date.format("%Y-%m-%d").to_string());
     } else if let Some(ref val) = args.since {
-        let d = validation::validate_date(val)?;
+        let d = validation::parse_relative_date(val, today)?;
         filter.since = Some(d.format("%Y-%m-%d").to_string());
     }
 
     if let Some(ref val) = args.until {
-        let d = validation::validate_date(val)?;
+        let d = validation::parse_relative_date(val, today)?;
         filter.until = Some(d.format("%Y-%m-%d").to_string());
     }
 
@@ -582,8 +604,24 @@ pub async fn run_db(
 
     filter.limit = Some(args.page_size);
 
-    let rows = db.query_trades(&filter)?;
+    let rows = match args.filing {
+        Some(ref filing) => db.trades_by_filing(filing)?,
+        None => TradeStore::query_trades(&db, &filter)?,
+    };
     eprintln!("{} trades from database", rows.len());
+    if rows.is_empty() {
+        let hint_for = |hint: Option<String>| {
+            if let Some(hint) = hint {
+                eprintln!("{}", hint);
+            }
+        };
+        if let Some(ref name) = filter.name {
+            hint_for(hints::politician_hint(&db, name));
+        }
+        if let Some(ref issuer) = filter.issuer {
+            hint_for(hints::issuer_hint(&db, issuer));
+        }
+    }
 
     // Best-effort analytics enrichment: compute performance metrics for closed trades
     let metrics_map: HashMap<(String, String), TradeMetrics> = match load_analytics_metrics(&db) {
@@ -659,7 +697,7 @@ pub async fn run_db(
 ///
 /// Extends [`DbTradeRow`] with absolute_return and alpha for closed trades.
 /// All analytics fields are Option types for backward compatibility.
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Clone, JsonSchema)]
 pub struct EnrichedDbTradeRow {
     // Base DbTradeRow fields
     pub tx_id: i64,
@@ -730,14 +768,30 @@ impl From<DbTradeRow> for EnrichedDbTradeRow {
     }
 }
 
+/// Print `trades --revisions`: the recorded upstream edits of one trade.
+fn print_trade_revisions(store: &impl TradeStore, tx_id: i64, format: &OutputFormat) -> Result<()> {
+    let revisions = store.get_trade_revisions(tx_id)?;
+    eprintln!("{} revisions recorded for trade {}", revisions.len(), tx_id);
+    match format {
+        OutputFormat::Table => print_trade_revisions_table(&revisions),
+        OutputFormat::Json => print_json(&revisions),
+        OutputFormat::Csv => print_trade_revisions_csv(&revisions)?,
+        OutputFormat::Markdown => print_trade_revisions_markdown(&revisions),
+        OutputFormat::Xml => print_trade_revisions_xml(&revisions),
+    }
+    Ok(())
+}
+
 /// Load analytics metrics for all closed trades.
 ///
 /// Returns a HashMap keyed by (politician_id, ticker) with the most recent
 /// TradeMetrics for each sell trade. Best-effort: returns error if price
 /// enrichment data is unavailable.
-fn load_analytics_metrics(db: &Db) -> Result<HashMap<(String, String), TradeMetrics>> {
+fn load_analytics_metrics(
+    store: &impl TradeStore,
+) -> Result<HashMap<(String, String), TradeMetrics>> {
     // Query price-enriched trades for analytics
-    let analytics_rows = db.query_trades_for_analytics()?;
+    let analytics_rows = store.query_trades_for_analytics()?;
     if analytics_rows.is_empty() {
         bail!("no price-enriched trades available");
     }
@@ -0,0 +1,255 @@
+//! The `watch` subcommand: poll for new trades and emit notifications.
+//!
+//! Each cycle runs an incremental trade sync, then delivers trades newer than
+//! the last stored snapshot (see `capitoltraders_lib::watch`). Scrape failures
+//! back off exponentially instead of exiting.
+
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use capitoltraders_lib::watch::{
+    deliver_new_trades, ensure_baseline, TradeAlertFilter, TradeNotifier,
+};
+use capitoltraders_lib::{shutdown, CancellationToken, Db, DbTradeRow, ScrapeClient};
+use clap::Args;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use super::sync::sync_incremental;
+
+/// Longest wait between cycles after repeated failures.
+const MAX_BACKOFF: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Timeout for a single webhook delivery.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Arguments for the `watch` subcommand.
+#[derive(Args)]
+pub struct WatchArgs {
+    /// SQLite database path
+    #[arg(long, default_value = "capitoltraders.db")]
+    pub db: PathBuf,
+
+    /// Time between sync cycles, e.g. 90s, 30m, 2h (bare numbers are minutes)
+    #[arg(long, default_value = "30m")]
+    pub interval: String,
+
+    /// Shell command run once per new trade, with the trade as JSON on stdin
+    #[arg(long)]
+    pub notify_cmd: Option<String>,
+
+    /// URL that receives each new trade as a JSON POST
+    #[arg(long)]
+    pub webhook: Option<String>,
+
+    /// TOML file restricting which trades trigger notifications
+    #[arg(long)]
+    pub filter_file: Option<PathBuf>,
+
+    /// Run a single sync-and-notify cycle, then exit
+    #[arg(long)]
+    pub once: bool,
+}
+
+/// Delivers trades to a shell command and/or webhook.
+struct CliNotifier {
+    command: Option<String>,
+    webhook: Option<(reqwest::Client, String)>,
+}
+
+impl TradeNotifier for CliNotifier {
+    async fn notify(&mut self, trade: &DbTradeRow) -> Result<(), String> {
+        let payload = serde_json::to_vec(trade).map_err(|e| e.to_string())?;
+
+        if let Some(ref command) = self.command {
+            let mut child = Command::new("sh")
+                .arg("-c")
+                .arg(command)
+                .stdin(Stdio::piped())
+                .spawn()
+                .map_err(|e| format!("failed to start notify command: {}", e))?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(&payload)
+                    .await
+                    .map_err(|e| format!("failed to write to notify command: {}", e))?;
+            }
+            let status = child
+                .wait()
+                .await
+                .map_err(|e| format!("notify command failed: {}", e))?;
+            if !status.success() {
+                return Err(format!("notify command exited with {}", status));
+            }
+        }
+
+        if let Some((ref client, ref url)) = self.webhook {
+            let resp = client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(payload)
+                .send()
+                .await
+                .map_err(|e| format!("webhook request failed: {}", e))?;
+            if !resp.status().is_success() {
+                return Err(format!("webhook returned HTTP {}", resp.status()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub async fn run(args: &WatchArgs, scraper: &ScrapeClient) -> Result<()> {
+    if args.notify_cmd.is_none() && args.webhook.is_none() {
+        bail!("watch requires --notify-cmd and/or --webhook");
+    }
+    let interval = parse_interval(&args.interval)?;
+
+    let filter = match args.filter_file {
+        Some(ref path) => TradeAlertFilter::from_file(path)?,
+        None => TradeAlertFilter::default(),
+    };
+
+    let webhook = match args.webhook {
+        Some(ref url) => {
+            let client = reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build()?;
+            Some((client, url.clone()))
+        }
+        None => None,
+    };
+    let mut notifier = CliNotifier {
+        command: args.notify_cmd.clone(),
+        webhook,
+    };
+
+    let mut db = Db::open(&args.db)?;
+    db.init()?;
+    let cancel = shutdown::ctrl_c_token();
+
+    let baseline = ensure_baseline(&db)?;
+    eprintln!(
+        "Watching {} every {}s (notifying after tx_id {})",
+        args.db.display(),
+        interval.as_secs(),
+        baseline
+            .max_tx_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    );
+
+    let mut consecutive_failures: u32 = 0;
+    loop {
+        match run_cycle(scraper, &mut db, &filter, &mut notifier, &cancel).await {
+            Ok(()) => consecutive_failures = 0,
+            Err(err) => {
+                consecutive_failures += 1;
+                eprintln!(
+                    "Warning: watch cycle failed ({} in a row): {}",
+                    consecutive_failures, err
+                );
+            }
+        }
+
+        if args.once || cancel.is_cancelled() {
+            break;
+        }
+
+        let wait = backoff_delay(interval, consecutive_failures);
+        if consecutive_failures > 0 {
+            eprintln!("Backing off for {}s", wait.as_secs());
+        }
+        if !shutdown::pause(&cancel, wait).await {
+            break;
+        }
+    }
+    if cancel.is_cancelled() {
+        eprintln!("Watch stopped");
+    }
+
+    Ok(())
+}
+
+/// One sync + deliver pass. Delivery still runs when the sync fails, so
+/// notifications that failed on an earlier cycle are retried.
+async fn run_cycle(
+    scraper: &ScrapeClient,
+    db: &mut Db,
+    filter: &TradeAlertFilter,
+    notifier: &mut CliNotifier,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let sync_result = sync_incremental(scraper, db, cancel).await;
+
+    let report = deliver_new_trades(db, filter, notifier).await?;
+    if report.new_trades > 0 {
+        eprintln!(
+            "{} new trades, {} notifications sent",
+            report.new_trades, report.notified
+        );
+    }
+
+    sync_result.map(|_| ())
+}
+
+/// Parse an interval like "90s", "30m", "2h"; bare numbers are minutes.
+fn parse_interval(input: &str) -> Result<Duration> {
+    let trimmed = input.trim().to_lowercase();
+    let (number, unit_secs) = match trimmed.chars().last() {
+        Some('s') => (&trimmed[..trimmed.len() - 1], 1),
+        Some('m') => (&trimmed[..trimmed.len() - 1], 60),
+        Some('h') => (&trimmed[..trimmed.len() - 1], 3600),
+        _ => (trimmed.as_str(), 60),
+    };
+    let value: u64 = match number.trim().parse() {
+        Ok(v) if v > 0 => v,
+        _ => bail!(
+            "Invalid --interval value: '{}'. Use a positive number with s, m, or h (e.g. 30m)",
+            input
+        ),
+    };
+    Ok(Duration::from_secs(value * unit_secs))
+}
+
+/// Wait before the next cycle: the interval, doubled per consecutive failure,
+/// capped at [`MAX_BACKOFF`] (or the interval itself if that is longer).
+fn backoff_delay(interval: Duration, consecutive_failures: u32) -> Duration {
+    if consecutive_failures == 0 {
+        return interval;
+    }
+    let factor = 2u32.saturating_pow(consecutive_failures.min(16));
+    interval
+        .saturating_mul(factor)
+        .min(MAX_BACKOFF.max(interval))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_interval_units() {
+        assert_eq!(parse_interval("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_interval("30m").unwrap(), Duration::from_secs(1800));
+        assert_eq!(parse_interval("2H").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_interval("15").unwrap(), Duration::from_secs(900));
+    }
+
+    #[test]
+    fn parse_interval_rejects_invalid() {
+        assert!(parse_interval("0m").is_err());
+        assert!(parse_interval("soon").is_err());
+        assert!(parse_interval("").is_err());
+    }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let interval = Duration::from_secs(1800);
+        assert_eq!(backoff_delay(interval, 0), interval);
+        assert_eq!(backoff_delay(interval, 1), Duration::from_secs(3600));
+        assert_eq!(backoff_delay(interval, 2), Duration::from_secs(7200));
+        assert_eq!(backoff_delay(interval, 10), MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,28 @@
+//! "Did you mean" hints for politician names and tickers that matched
+//! nothing, drawn from the local database.
+//!
+//! A hint is best effort: when the lookup itself fails the command carries on
+//! without one.
+
+use capitoltraders_lib::suggest::DEFAULT_SUGGESTIONS;
+use capitoltraders_lib::{did_you_mean, Db};
+
+/// Politicians whose name resembles `name`, as a "Did you mean" line.
+pub fn politician_hint(db: &Db, name: &str) -> Option<String> {
+    let suggestions = db.suggest_politicians(name, DEFAULT_SUGGESTIONS).ok()?;
+    did_you_mean(&suggestions)
+}
+
+/// Issuers whose ticker or name resembles `query`, as a "Did you mean" line.
+pub fn issuer_hint(db: &Db, query: &str) -> Option<String> {
+    let suggestions = db.suggest_issuers(query, DEFAULT_SUGGESTIONS).ok()?;
+    did_you_mean(&suggestions)
+}
+
+/// `message` with `hint` on the line below it, when there is one.
+pub fn with_hint(message: String, hint: Option<String>) -> String {
+    match hint {
+        Some(hint) => format!("{}\n{}", message, hint),
+        None => message,
+    }
+}
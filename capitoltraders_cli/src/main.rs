@@ -6,12 +6,17 @@
 //! CSV, Markdown, or XML.
 
 mod commands;
+mod hints;
 mod output;
+mod universe_args;
 mod xml_output;
 
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+
 use anyhow::Result;
-use capitoltraders_lib::ScrapeClient;
-use clap::{Parser, Subcommand};
+use capitoltraders_lib::{AppConfig, OpenFecClient, ScrapeClient};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 
 use crate::output::OutputFormat;
 
@@ -20,14 +25,22 @@ use crate::output::OutputFormat;
 #[command(name = "capitoltraders")]
 #[command(about = "Query congressional trading data from CapitolTrades")]
 struct Cli {
-    /// Output format: table, json, csv, md, xml
-    #[arg(long, default_value = "table", global = true)]
-    output: String,
+    /// Output format: table, json, csv, md, xml (default: table, or `output` from the config file)
+    #[arg(long, global = true)]
+    output: Option<String>,
 
     /// Override the scraping base URL (or set CAPITOLTRADES_BASE_URL)
     #[arg(long, global = true)]
     base_url: Option<String>,
 
+    /// Config file (default: $XDG_CONFIG_HOME/capitoltraders/config.toml)
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Write XML in the unversioned pre-2 layout (deprecated; removed in the next release)
+    #[arg(long, global = true)]
+    xml_legacy: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -40,11 +53,13 @@ enum Commands {
     /// List politicians
     Politicians(commands::politicians::PoliticiansArgs),
     /// List or lookup issuers
-    Issuers(commands::issuers::IssuersArgs),
+    Issuers(Box<commands::issuers::IssuersArgs>),
     /// Sync data into a SQLite database
     Sync(commands::sync::SyncArgs),
     /// Sync FEC candidate ID mappings from congress-legislators dataset
     SyncFec(commands::sync_fec::SyncFecArgs),
+    /// Run one detail enrichment phase (trades, issuers, politicians)
+    Enrich(commands::enrich::EnrichArgs),
     /// Enrich trades with Yahoo Finance price data
     EnrichPrices(commands::enrich_prices::EnrichPricesArgs),
     /// View portfolio positions with P&L
@@ -55,12 +70,28 @@ enum Commands {
     Donations(commands::donations::DonationsArgs),
     /// Build employer-to-issuer mapping database
     MapEmployers(commands::map_employers::MapEmployersArgs),
+    /// Export or import ticker aliases used by price enrichment
+    Aliases(commands::aliases::AliasesArgs),
     /// View politician performance rankings and analytics
-    Analytics(commands::analytics::AnalyticsArgs),
+    Analytics(Box<commands::analytics::AnalyticsArgs>),
     /// View committee trading scores and donation-trade correlations
     Conflicts(commands::conflicts::ConflictsArgs),
     /// Detect unusual trading patterns (pre-move trades, volume spikes, sector concentration)
     Anomalies(Box<commands::anomalies::AnomaliesArgs>),
+    /// Poll for new trades and send notifications
+    Watch(commands::watch::WatchArgs),
+    /// Generate a Markdown (or JSON) dossier for one politician
+    Report(commands::report::ReportArgs),
+    /// Print JSON Schema documents for every JSON output type
+    Schema(commands::schema::SchemaArgs),
+    /// Inspect the effective configuration
+    Config(commands::config::ConfigArgs),
+    /// Inspect the local database (enrichment coverage)
+    Db(commands::db::DbArgs),
+    /// Record named snapshots of derived state for later diffs
+    Snapshot(commands::snapshot::SnapshotArgs),
+    /// Show what changed since a named snapshot
+    Diff(commands::snapshot::DiffArgs),
 }
 
 #[tokio::main]
@@ -76,9 +107,20 @@ async fn main() -> Result<()> {
         .with_target(false)
         .init();
 
-    let cli = Cli::parse();
+    // The config file can supply the default --db, so it is loaded before
+    // the command line is parsed.
+    let mut config = AppConfig::load(config_flag(std::env::args_os()).as_deref())?;
+    let mut command = Cli::command();
+    if let Some(db) = config.db.clone() {
+        command = with_db_default(command, db.as_os_str());
+    }
+    let cli = Cli::from_arg_matches(&command.get_matches()).unwrap_or_else(|e| e.exit());
+    let mut flags = AppConfig::default();
+    flags.output = cli.output.clone();
+    flags.capitoltrades.base_url = cli.base_url.clone();
+    config.layer(flags);
 
-    let format = match cli.output.as_str() {
+    let format = match config.output_format() {
         "json" => OutputFormat::Json,
         "csv" => OutputFormat::Csv,
         "md" | "markdown" => OutputFormat::Markdown,
@@ -86,20 +128,23 @@ async fn main() -> Result<()> {
         _ => OutputFormat::Table,
     };
 
-    let base_url = cli
-        .base_url
-        .clone()
-        .or_else(|| std::env::var("CAPITOLTRADES_BASE_URL").ok());
-    let scraper = match base_url.as_deref() {
-        Some(url) => ScrapeClient::with_base_url(url)?,
-        None => ScrapeClient::new()?,
-    };
+    if cli.xml_legacy {
+        xml_output::set_layout(xml_output::XmlLayout::Legacy);
+    }
+
+    let scraper = ScrapeClient::from_config(&config)?;
 
     match &cli.command {
         Commands::Trades(args) => {
             if let Some(ref db_path) = args.db {
                 commands::trades::run_db(args.as_ref(), db_path, &format).await?
             } else {
+                let scraper = commands::enrich::paced_scraper(
+                    scraper.clone(),
+                    &config,
+                    args.details_delay_ms,
+                    1,
+                );
                 commands::trades::run(args.as_ref(), &scraper, &format).await?
             }
         }
@@ -111,33 +156,86 @@ async fn main() -> Result<()> {
             }
         }
         Commands::Issuers(args) => {
-            if let Some(ref db_path) = args.db {
+            if let Some(commands::issuers::IssuersAction::Screen(ref screen)) = args.action {
+                commands::issuers::run_screen(screen, &format)?
+            } else if let Some(commands::issuers::IssuersAction::Merge(ref merge)) = args.action {
+                commands::issuers::run_merge(merge)?
+            } else if let Some(ref db_path) = args.db {
                 commands::issuers::run_db(args, db_path, &format)?
             } else {
                 commands::issuers::run(args, &scraper, &format).await?
             }
         }
-        Commands::Sync(args) => commands::sync::run(args, base_url.as_deref()).await?,
+        Commands::Sync(args) => commands::sync::run(args, &config).await?,
         Commands::SyncFec(args) => commands::sync_fec::run(args).await?,
-        Commands::EnrichPrices(args) => commands::enrich_prices::run(args).await?,
+        Commands::Enrich(args) => commands::enrich::run(args, &config).await?,
+        Commands::EnrichPrices(args) => commands::enrich_prices::run(args, &config).await?,
         Commands::Portfolio(args) => commands::portfolio::run(args, &format)?,
         Commands::SyncDonations(args) => {
-            let api_key = require_openfec_api_key()?;
-            commands::sync_donations::run(args, api_key).await?
+            let client = require_openfec_client(&config)?;
+            commands::sync_donations::run(args, client).await?
         }
         Commands::Donations(args) => commands::donations::run(args, &format)?,
         Commands::MapEmployers(args) => commands::map_employers::run(args)?,
-        Commands::Analytics(args) => commands::analytics::run(args, &format)?,
-        Commands::Conflicts(args) => commands::conflicts::run(args, &format)?,
+        Commands::Aliases(args) => commands::aliases::run(args)?,
+        Commands::Analytics(args) => commands::analytics::run(args, &config, &format)?,
+        Commands::Conflicts(args) => commands::conflicts::run(args, &config, &format)?,
         Commands::Anomalies(args) => commands::anomalies::run(args, &format)?,
+        Commands::Watch(args) => commands::watch::run(args, &scraper).await?,
+        Commands::Report(args) => commands::report::run(args, &format)?,
+        Commands::Schema(args) => commands::schema::run(args)?,
+        Commands::Config(args) => commands::config::run(args, &config, &format)?,
+        Commands::Db(args) => commands::db::run(args, &format)?,
+        Commands::Snapshot(args) => commands::snapshot::run(args, &format)?,
+        Commands::Diff(args) => commands::snapshot::run_diff(args, &format)?,
     }
 
     Ok(())
 }
 
-/// Require the OpenFEC API key from environment, providing helpful error if missing.
-pub fn require_openfec_api_key() -> Result<String> {
-    std::env::var("OPENFEC_API_KEY").map_err(|_| {
+/// Value of `--config` / `--config=<path>`, found before clap parses the
+/// full command line.
+fn config_flag(args: impl Iterator<Item = OsString>) -> Option<PathBuf> {
+    let mut args = args.skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--config" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.to_str().and_then(|a| a.strip_prefix("--config=")) {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+/// Make `db` the default for every subcommand whose `--db` is required.
+/// Optional `--db` flags are left alone, since they switch a command from
+/// live scraping to the local database.
+fn with_db_default(mut command: clap::Command, db: &OsStr) -> clap::Command {
+    let names: Vec<String> = command
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    for name in names {
+        command = command.mut_subcommand(name, |sub| with_db_default(sub, db));
+    }
+    if command
+        .get_arguments()
+        .any(|arg| arg.get_id() == "db" && arg.is_required_set())
+    {
+        command = command.mut_arg("db", |arg| {
+            arg.required(false).default_value(db.to_os_string())
+        });
+    }
+    command
+}
+
+/// Build the OpenFEC client, providing a helpful error if no API key is configured.
+pub fn require_openfec_client(config: &AppConfig) -> Result<OpenFecClient> {
+    OpenFecClient::from_config(config)?.ok_or_else(|| {
         anyhow::anyhow!(
             "OpenFEC API key not found.\n\n\
              To use donation-related features, you need an API key from api.data.gov:\n\
@@ -145,6 +243,7 @@ pub fn require_openfec_api_key() -> Result<String> {
              2. Check your email for the API key\n\
              3. Create a .env file in the project root:\n\
                 echo 'OPENFEC_API_KEY=your_key_here' > .env\n\
+                (or set api_key under [openfec] in the config file)\n\
              4. See .env.example for a template\n\n\
              Note: .env is gitignored and will not be committed."
         )
@@ -4,13 +4,18 @@
 //! for each format. Data is first mapped to flat row structs, then rendered.
 
 use anyhow::Result;
+use capitoltraders_lib::analysis::EventProximityRow;
+use capitoltraders_lib::conflict::HearingProximityRow;
+use capitoltraders_lib::snapshot::{NewAnomaly, PositionChange, RankChange, SectionDiff, SnapshotDiff};
 use capitoltraders_lib::types::{IssuerDetail, PoliticianDetail, Trade};
 use capitoltraders_lib::{
-    ContributorAggRow, DbIssuerRow, DbPoliticianRow, DbTradeRow, DonationRow, EmployerAggRow,
-    PortfolioPosition, StateAggRow,
+    ContributorAggRow, CoverageRow, DbIssuerRow, DbPoliticianRow, DbTradeRow, DonationGeography, DonationRow,
+    EmployerAggRow, FixedIncomeExposureRow, OccupationAggRow, PortfolioPosition, SessionGroupRow, SnapshotInfo, StateAggRow, TradeRevisionRow,
+    UniverseProvenance,
 };
 use serde::Serialize;
-use tabled::settings::Style;
+use tabled::settings::object::Segment;
+use tabled::settings::{Format, Modify, Style};
 use tabled::{Table, Tabled};
 
 use crate::xml_output;
@@ -174,25 +179,52 @@ pub fn print_issuers_table(issuers: &[IssuerDetail]) {
 
 // -- Markdown output --
 
+/// Escapes text for a Markdown table cell or inline run. Backslashes and
+/// pipes are backslash-escaped, and each run of line breaks (`\n`, `\r\n`,
+/// bare `\r`) collapses to a single space so a cell never spans lines.
+fn escape_markdown(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_break = false;
+    for c in s.chars() {
+        if c == '\n' || c == '\r' {
+            if !in_break {
+                out.push(' ');
+            }
+            in_break = true;
+            continue;
+        }
+        in_break = false;
+        if c == '\\' || c == '|' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Renders rows as a GitHub-flavored Markdown table, escaping every cell.
+/// All `print_*_markdown` functions go through here.
+fn markdown_table<T: Tabled>(rows: impl IntoIterator<Item = T>) -> String {
+    let mut table = Table::new(rows);
+    table
+        .with(Modify::new(Segment::all()).with(Format::content(escape_markdown)))
+        .with(Style::markdown());
+    table.to_string()
+}
+
 /// Prints trades as a GitHub-flavored Markdown table to stdout.
 pub fn print_trades_markdown(trades: &[Trade]) {
-    let mut table = Table::new(build_trade_rows(trades));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_trade_rows(trades)));
 }
 
 /// Prints politicians as a GitHub-flavored Markdown table to stdout.
 pub fn print_politicians_markdown(politicians: &[PoliticianDetail]) {
-    let mut table = Table::new(build_politician_rows(politicians));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_politician_rows(politicians)));
 }
 
 /// Prints issuers as a GitHub-flavored Markdown table to stdout.
 pub fn print_issuers_markdown(issuers: &[IssuerDetail]) {
-    let mut table = Table::new(build_issuer_rows(issuers));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_issuer_rows(issuers)));
 }
 
 // -- CSV output --
@@ -248,17 +280,17 @@ pub fn print_issuers_csv(issuers: &[IssuerDetail]) -> Result<()> {
 
 /// Prints trades as a well-formed XML document to stdout.
 pub fn print_trades_xml(trades: &[Trade]) {
-    println!("{}", xml_output::trades_to_xml(trades));
+    println!("{}", xml_output::to_xml(trades));
 }
 
 /// Prints politicians as a well-formed XML document to stdout.
 pub fn print_politicians_xml(politicians: &[PoliticianDetail]) {
-    println!("{}", xml_output::politicians_to_xml(politicians));
+    println!("{}", xml_output::to_xml(politicians));
 }
 
 /// Prints issuers as a well-formed XML document to stdout.
 pub fn print_issuers_xml(issuers: &[IssuerDetail]) {
-    println!("{}", xml_output::issuers_to_xml(issuers));
+    println!("{}", xml_output::to_xml(issuers));
 }
 
 // -- DB trade output --
@@ -330,9 +362,7 @@ pub fn print_db_trades_table(trades: &[DbTradeRow]) {
 /// Prints DB trades as a GitHub-flavored Markdown table to stdout.
 #[allow(dead_code)]
 pub fn print_db_trades_markdown(trades: &[DbTradeRow]) {
-    let mut table = Table::new(build_db_trade_rows(trades));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_db_trade_rows(trades)));
 }
 
 /// Prints DB trades as CSV to stdout. Fields are sanitized against formula injection.
@@ -354,7 +384,7 @@ pub fn print_db_trades_csv(trades: &[DbTradeRow]) -> Result<()> {
 /// Prints DB trades as a well-formed XML document to stdout.
 #[allow(dead_code)]
 pub fn print_db_trades_xml(trades: &[DbTradeRow]) {
-    println!("{}", xml_output::db_trades_to_xml(trades));
+    println!("{}", xml_output::to_xml(trades));
 }
 
 // -- Enriched DB trade output (with analytics) --
@@ -396,12 +426,10 @@ struct EnrichedDbTradeOutputRow {
     labels: String,
     #[tabled(rename = "Return")]
     #[serde(rename = "Return")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     absolute_return: Option<String>,
     #[tabled(rename = "Alpha")]
     #[serde(rename = "Alpha")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     alpha: Option<String>,
 }
@@ -451,14 +479,21 @@ pub fn print_enriched_trades_table(trades: &[crate::commands::trades::EnrichedDb
 
 /// Prints enriched DB trades as a GitHub-flavored Markdown table to stdout.
 pub fn print_enriched_trades_markdown(trades: &[crate::commands::trades::EnrichedDbTradeRow]) {
-    let mut table = Table::new(build_enriched_db_trade_rows(trades));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_enriched_db_trade_rows(trades)));
 }
 
 /// Prints enriched DB trades as CSV to stdout. Fields are sanitized against formula injection.
 pub fn print_enriched_trades_csv(trades: &[crate::commands::trades::EnrichedDbTradeRow]) -> Result<()> {
-    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    write_enriched_trades_csv(std::io::stdout(), trades)
+}
+
+/// Writes enriched DB trades as CSV to `writer`, quoting fields that contain
+/// delimiters, quotes, or line breaks.
+fn write_enriched_trades_csv<W: std::io::Write>(
+    writer: W,
+    trades: &[crate::commands::trades::EnrichedDbTradeRow],
+) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(writer);
     for mut row in build_enriched_db_trade_rows(trades) {
         row.politician = sanitize_csv_field(&row.politician);
         row.issuer = sanitize_csv_field(&row.issuer);
@@ -473,7 +508,7 @@ pub fn print_enriched_trades_csv(trades: &[crate::commands::trades::EnrichedDbTr
 
 /// Prints enriched DB trades as a well-formed XML document to stdout.
 pub fn print_enriched_trades_xml(trades: &[crate::commands::trades::EnrichedDbTradeRow]) {
-    println!("{}", xml_output::enriched_trades_to_xml(trades));
+    println!("{}", xml_output::to_xml(trades));
 }
 
 // -- DB politician output --
@@ -533,9 +568,7 @@ pub fn print_db_politicians_table(politicians: &[DbPoliticianRow]) {
 /// Prints DB politicians as a GitHub-flavored Markdown table to stdout.
 #[allow(dead_code)]
 pub fn print_db_politicians_markdown(politicians: &[DbPoliticianRow]) {
-    let mut table = Table::new(build_db_politician_rows(politicians));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_db_politician_rows(politicians)));
 }
 
 /// Prints DB politicians as CSV to stdout. Fields are sanitized against formula injection.
@@ -554,7 +587,7 @@ pub fn print_db_politicians_csv(politicians: &[DbPoliticianRow]) -> Result<()> {
 /// Prints DB politicians as a well-formed XML document to stdout.
 #[allow(dead_code)]
 pub fn print_db_politicians_xml(politicians: &[DbPoliticianRow]) {
-    println!("{}", xml_output::db_politicians_to_xml(politicians));
+    println!("{}", xml_output::to_xml(politicians));
 }
 
 // -- Enriched DB politician output (with analytics) --
@@ -587,22 +620,18 @@ struct EnrichedDbPoliticianOutputRow {
     volume: String,
     #[tabled(rename = "Closed")]
     #[serde(rename = "Closed")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_usize")]
     closed_trades: Option<usize>,
     #[tabled(rename = "Avg Ret")]
     #[serde(rename = "Avg Ret")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     avg_return: Option<String>,
     #[tabled(rename = "Win%")]
     #[serde(rename = "Win%")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     win_rate: Option<String>,
     #[tabled(rename = "Pctl")]
     #[serde(rename = "Pctl")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     percentile: Option<String>,
 }
@@ -652,9 +681,7 @@ pub fn print_enriched_politicians_table(
 pub fn print_enriched_politicians_markdown(
     politicians: &[crate::commands::politicians::EnrichedDbPoliticianRow],
 ) {
-    let mut table = Table::new(build_enriched_db_politician_rows(politicians));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_enriched_db_politician_rows(politicians)));
 }
 
 /// Prints enriched DB politicians as CSV to stdout. Fields are sanitized against formula injection.
@@ -675,7 +702,7 @@ pub fn print_enriched_politicians_csv(
 pub fn print_enriched_politicians_xml(
     politicians: &[crate::commands::politicians::EnrichedDbPoliticianRow],
 ) {
-    println!("{}", xml_output::enriched_politicians_to_xml(politicians));
+    println!("{}", xml_output::to_xml(politicians));
 }
 
 // -- DB issuer output --
@@ -713,6 +740,9 @@ struct DbIssuerOutputRow {
     #[tabled(rename = "Last Traded")]
     #[serde(rename = "Last Traded")]
     last_traded: String,
+    #[tabled(rename = "Last Trader")]
+    #[serde(rename = "Last Trader")]
+    last_trader: String,
 }
 
 /// Format a large number with T/B/M suffixes for readability.
@@ -760,6 +790,10 @@ fn build_db_issuer_rows(issuers: &[DbIssuerRow]) -> Vec<DbIssuerOutputRow> {
             trades: i.trades,
             volume: format_value(i.volume),
             last_traded: i.last_traded.clone().unwrap_or_else(|| "-".to_string()),
+            last_trader: i
+                .last_trader_name
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
         })
         .collect()
 }
@@ -771,9 +805,7 @@ pub fn print_db_issuers_table(issuers: &[DbIssuerRow]) {
 
 /// Prints DB issuers as a GitHub-flavored Markdown table to stdout.
 pub fn print_db_issuers_markdown(issuers: &[DbIssuerRow]) {
-    let mut table = Table::new(build_db_issuer_rows(issuers));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_db_issuer_rows(issuers)));
 }
 
 /// Prints DB issuers as CSV to stdout. Fields are sanitized against formula injection.
@@ -783,6 +815,7 @@ pub fn print_db_issuers_csv(issuers: &[DbIssuerRow]) -> Result<()> {
         row.name = sanitize_csv_field(&row.name);
         row.ticker = sanitize_csv_field(&row.ticker);
         row.sector = sanitize_csv_field(&row.sector);
+        row.last_trader = sanitize_csv_field(&row.last_trader);
         wtr.serialize(row)?;
     }
     wtr.flush()?;
@@ -791,7 +824,7 @@ pub fn print_db_issuers_csv(issuers: &[DbIssuerRow]) -> Result<()> {
 
 /// Prints DB issuers as a well-formed XML document to stdout.
 pub fn print_db_issuers_xml(issuers: &[DbIssuerRow]) {
-    println!("{}", xml_output::db_issuers_to_xml(issuers));
+    println!("{}", xml_output::to_xml(issuers));
 }
 
 // -- Portfolio output --
@@ -905,9 +938,7 @@ pub fn print_portfolio_table(positions: &[PortfolioPosition]) {
 /// Prints portfolio positions as a GitHub-flavored Markdown table to stdout.
 #[allow(dead_code)]
 pub fn print_portfolio_markdown(positions: &[PortfolioPosition]) {
-    let mut table = Table::new(build_portfolio_rows(positions));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_portfolio_rows(positions)));
 }
 
 /// Prints portfolio positions as CSV to stdout. Fields are sanitized against formula injection.
@@ -926,7 +957,7 @@ pub fn print_portfolio_csv(positions: &[PortfolioPosition]) -> Result<()> {
 /// Prints portfolio positions as a well-formed XML document to stdout.
 #[allow(dead_code)]
 pub fn print_portfolio_xml(positions: &[PortfolioPosition]) {
-    println!("{}", xml_output::portfolio_to_xml(positions));
+    println!("{}", xml_output::to_xml(positions));
 }
 
 // -- Enriched portfolio output (with conflict detection) --
@@ -960,14 +991,15 @@ struct EnrichedPortfolioRow {
     #[tabled(rename = "P&L %")]
     #[serde(rename = "P&L %")]
     unrealized_pnl_pct: String,
+    #[tabled(rename = "Price Source")]
+    #[serde(rename = "Price Source")]
+    price_source: String,
     #[tabled(rename = "Sector")]
     #[serde(rename = "Sector")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     gics_sector: Option<String>,
     #[tabled(rename = "Cmte?")]
     #[serde(rename = "Cmte?")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     #[tabled(display_with = "display_option_str")]
     in_committee_sector: Option<String>,
 }
@@ -1010,6 +1042,10 @@ fn build_enriched_portfolio_rows(
                     }
                 })
                 .unwrap_or_else(|| "-".to_string()),
+            price_source: p
+                .price_source
+                .map(|source| source.as_str().to_string())
+                .unwrap_or_else(|| "-".to_string()),
             gics_sector: p.gics_sector.clone(),
             in_committee_sector: p.in_committee_sector.map(|flag| {
                 if flag {
@@ -1033,9 +1069,7 @@ pub fn print_enriched_portfolio_table(
 pub fn print_enriched_portfolio_markdown(
     positions: &[crate::commands::portfolio::EnrichedPortfolioPosition],
 ) {
-    let mut table = Table::new(build_enriched_portfolio_rows(positions));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_enriched_portfolio_rows(positions)));
 }
 
 /// Prints enriched portfolio positions as CSV to stdout. Fields are sanitized against formula injection.
@@ -1056,7 +1090,102 @@ pub fn print_enriched_portfolio_csv(
 pub fn print_enriched_portfolio_xml(
     positions: &[crate::commands::portfolio::EnrichedPortfolioPosition],
 ) {
-    println!("{}", xml_output::enriched_portfolio_to_xml(positions));
+    println!("{}", xml_output::to_xml(positions));
+}
+
+// -- Fixed-income output --
+
+#[derive(Tabled)]
+struct FixedIncomeOutputRow {
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Party")]
+    party: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Trades")]
+    trade_count: i64,
+    #[tabled(rename = "Gov/Corp/Muni")]
+    mix: String,
+    #[tabled(rename = "Bought")]
+    bought: String,
+    #[tabled(rename = "Sold")]
+    sold: String,
+    #[tabled(rename = "Net Face Value")]
+    net: String,
+    #[tabled(rename = "Last Trade")]
+    last_trade_date: String,
+}
+
+fn build_fixed_income_rows(rows: &[FixedIncomeExposureRow]) -> Vec<FixedIncomeOutputRow> {
+    rows.iter()
+        .map(|r| FixedIncomeOutputRow {
+            politician: r.politician_name.clone(),
+            party: r.party.clone(),
+            state: r.state.clone(),
+            trade_count: r.trade_count,
+            mix: format!(
+                "{}/{}/{}",
+                r.government_trades, r.corporate_trades, r.municipal_trades
+            ),
+            bought: format_currency_with_commas(r.bought_face_value),
+            sold: format_currency_with_commas(r.sold_face_value),
+            net: format_signed_currency(r.net_face_value),
+            last_trade_date: r.last_trade_date.clone(),
+        })
+        .collect()
+}
+
+/// Prints fixed-income exposure by politician as an ASCII table to stdout.
+pub fn print_fixed_income_table(rows: &[FixedIncomeExposureRow]) {
+    println!("{}", Table::new(build_fixed_income_rows(rows)));
+}
+
+/// Prints fixed-income exposure by politician as a Markdown table to stdout.
+pub fn print_fixed_income_markdown(rows: &[FixedIncomeExposureRow]) {
+    println!("{}", markdown_table(build_fixed_income_rows(rows)));
+}
+
+/// Prints fixed-income exposure by politician as CSV to stdout.
+pub fn print_fixed_income_csv(rows: &[FixedIncomeExposureRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "politician_id",
+        "politician_name",
+        "party",
+        "state",
+        "trade_count",
+        "government_trades",
+        "corporate_trades",
+        "municipal_trades",
+        "bought_face_value",
+        "sold_face_value",
+        "net_face_value",
+        "last_trade_date",
+    ])?;
+    for r in rows {
+        wtr.write_record(&[
+            sanitize_csv_field(&r.politician_id),
+            sanitize_csv_field(&r.politician_name),
+            r.party.clone(),
+            r.state.clone(),
+            r.trade_count.to_string(),
+            r.government_trades.to_string(),
+            r.corporate_trades.to_string(),
+            r.municipal_trades.to_string(),
+            format!("{:.2}", r.bought_face_value),
+            format!("{:.2}", r.sold_face_value),
+            format!("{:.2}", r.net_face_value),
+            r.last_trade_date.clone(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints fixed-income exposure by politician as a well-formed XML document to stdout.
+pub fn print_fixed_income_xml(rows: &[FixedIncomeExposureRow]) {
+    println!("{}", xml_output::to_xml(rows));
 }
 
 // -- Donations output --
@@ -1082,6 +1211,9 @@ struct DonationOutputRow {
     #[tabled(rename = "Committee")]
     #[serde(rename = "Committee")]
     committee: String,
+    #[tabled(rename = "Class")]
+    #[serde(rename = "Class")]
+    source_class: String,
     #[tabled(rename = "Cycle")]
     #[serde(rename = "Cycle")]
     cycle: String,
@@ -1109,6 +1241,7 @@ fn build_donation_rows(donations: &[DonationRow]) -> Vec<DonationOutputRow> {
             } else {
                 d.committee_name.clone()
             },
+            source_class: d.source_class.to_string(),
             cycle: d.cycle.to_string(),
         })
         .collect()
@@ -1121,9 +1254,7 @@ pub fn print_donations_table(donations: &[DonationRow]) {
 
 /// Prints donations as a GitHub-flavored Markdown table to stdout.
 pub fn print_donations_markdown(donations: &[DonationRow]) {
-    let mut table = Table::new(build_donation_rows(donations));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_donation_rows(donations)));
 }
 
 /// Prints donations as CSV to stdout. Fields are sanitized against formula injection.
@@ -1140,7 +1271,85 @@ pub fn print_donations_csv(donations: &[DonationRow]) -> Result<()> {
 
 /// Prints donations as a well-formed XML document to stdout.
 pub fn print_donations_xml(donations: &[DonationRow]) {
-    println!("{}", xml_output::donations_to_xml(donations));
+    println!("{}", xml_output::to_xml(donations));
+}
+
+// -- Data coverage output --
+
+/// Flattened row representation of an enrichment coverage row for tabular output.
+#[derive(Tabled, Serialize)]
+struct CoverageOutputRow {
+    #[tabled(rename = "ID")]
+    #[serde(rename = "ID")]
+    id: String,
+    #[tabled(rename = "Name")]
+    #[serde(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Trades")]
+    #[serde(rename = "Trades")]
+    total_trades: i64,
+    #[tabled(rename = "Detail %")]
+    #[serde(rename = "Detail %")]
+    detail_enriched_pct: String,
+    #[tabled(rename = "Priced %")]
+    #[serde(rename = "Priced %")]
+    price_enriched_pct: String,
+    #[tabled(rename = "With Shares")]
+    #[serde(rename = "With Shares")]
+    with_estimated_shares: i64,
+    #[tabled(rename = "First Trade")]
+    #[serde(rename = "First Trade")]
+    earliest_trade: String,
+    #[tabled(rename = "Last Trade")]
+    #[serde(rename = "Last Trade")]
+    latest_trade: String,
+    #[tabled(rename = "Open Positions")]
+    #[serde(rename = "Open Positions")]
+    open_positions: i64,
+}
+
+fn build_coverage_rows(rows: &[CoverageRow]) -> Vec<CoverageOutputRow> {
+    rows.iter()
+        .map(|r| CoverageOutputRow {
+            id: r.id.clone(),
+            name: r.name.clone(),
+            total_trades: r.total_trades,
+            detail_enriched_pct: format!("{:.1}%", r.detail_enriched_pct),
+            price_enriched_pct: format!("{:.1}%", r.price_enriched_pct),
+            with_estimated_shares: r.with_estimated_shares,
+            earliest_trade: r.earliest_trade.clone().unwrap_or_default(),
+            latest_trade: r.latest_trade.clone().unwrap_or_default(),
+            open_positions: r.open_positions,
+        })
+        .collect()
+}
+
+/// Prints enrichment coverage rows as an ASCII table to stdout.
+pub fn print_coverage_table(rows: &[CoverageRow]) {
+    let mut table = Table::new(build_coverage_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints enrichment coverage rows as a GitHub-flavored Markdown table to stdout.
+pub fn print_coverage_markdown(rows: &[CoverageRow]) {
+    println!("{}", markdown_table(build_coverage_rows(rows)));
+}
+
+/// Prints enrichment coverage rows as CSV to stdout. Names are sanitized against formula injection.
+pub fn print_coverage_csv(rows: &[CoverageRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    for mut row in build_coverage_rows(rows) {
+        row.name = sanitize_csv_field(&row.name);
+        wtr.serialize(row)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints enrichment coverage rows as XML to stdout.
+pub fn print_coverage_xml(rows: &[CoverageRow]) {
+    println!("{}", xml_output::to_xml(rows));
 }
 
 // -- Contributor aggregation output --
@@ -1196,9 +1405,7 @@ pub fn print_contributor_agg_table(rows: &[ContributorAggRow]) {
 
 /// Prints contributor aggregations as a GitHub-flavored Markdown table to stdout.
 pub fn print_contributor_agg_markdown(rows: &[ContributorAggRow]) {
-    let mut table = Table::new(build_contributor_agg_rows(rows));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_contributor_agg_rows(rows)));
 }
 
 /// Prints contributor aggregations as CSV to stdout. Fields are sanitized against formula injection.
@@ -1214,7 +1421,7 @@ pub fn print_contributor_agg_csv(rows: &[ContributorAggRow]) -> Result<()> {
 
 /// Prints contributor aggregations as a well-formed XML document to stdout.
 pub fn print_contributor_agg_xml(rows: &[ContributorAggRow]) {
-    println!("{}", xml_output::contributor_agg_to_xml(rows));
+    println!("{}", xml_output::to_xml(rows));
 }
 
 // -- Employer aggregation output --
@@ -1258,9 +1465,7 @@ pub fn print_employer_agg_table(rows: &[EmployerAggRow]) {
 
 /// Prints employer aggregations as a GitHub-flavored Markdown table to stdout.
 pub fn print_employer_agg_markdown(rows: &[EmployerAggRow]) {
-    let mut table = Table::new(build_employer_agg_rows(rows));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_employer_agg_rows(rows)));
 }
 
 /// Prints employer aggregations as CSV to stdout. Fields are sanitized against formula injection.
@@ -1276,7 +1481,67 @@ pub fn print_employer_agg_csv(rows: &[EmployerAggRow]) -> Result<()> {
 
 /// Prints employer aggregations as a well-formed XML document to stdout.
 pub fn print_employer_agg_xml(rows: &[EmployerAggRow]) {
-    println!("{}", xml_output::employer_agg_to_xml(rows));
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- Occupation aggregation output --
+
+/// Flattened row representation of occupation aggregation for tabular output.
+#[derive(Tabled, Serialize, Clone)]
+struct OccupationAggOutputRow {
+    #[tabled(rename = "Occupation")]
+    #[serde(rename = "Occupation")]
+    occupation: String,
+    #[tabled(rename = "Total")]
+    #[serde(rename = "Total")]
+    total: String,
+    #[tabled(rename = "Count")]
+    #[serde(rename = "Count")]
+    count: i64,
+    #[tabled(rename = "Avg")]
+    #[serde(rename = "Avg")]
+    avg: String,
+    #[tabled(rename = "Contributors")]
+    #[serde(rename = "Contributors")]
+    contributors: i64,
+}
+
+fn build_occupation_agg_rows(rows: &[OccupationAggRow]) -> Vec<OccupationAggOutputRow> {
+    rows.iter()
+        .map(|r| OccupationAggOutputRow {
+            occupation: r.occupation.clone(),
+            total: format_currency_with_commas(r.total_amount),
+            count: r.donation_count,
+            avg: format_currency_with_commas(r.avg_amount),
+            contributors: r.contributor_count,
+        })
+        .collect()
+}
+
+/// Prints occupation aggregations as an ASCII table to stdout.
+pub fn print_occupation_agg_table(rows: &[OccupationAggRow]) {
+    println!("{}", Table::new(build_occupation_agg_rows(rows)));
+}
+
+/// Prints occupation aggregations as a GitHub-flavored Markdown table to stdout.
+pub fn print_occupation_agg_markdown(rows: &[OccupationAggRow]) {
+    println!("{}", markdown_table(build_occupation_agg_rows(rows)));
+}
+
+/// Prints occupation aggregations as CSV to stdout. Fields are sanitized against formula injection.
+pub fn print_occupation_agg_csv(rows: &[OccupationAggRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    for mut row in build_occupation_agg_rows(rows) {
+        row.occupation = sanitize_csv_field(&row.occupation);
+        wtr.serialize(row)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints occupation aggregations as a well-formed XML document to stdout.
+pub fn print_occupation_agg_xml(rows: &[OccupationAggRow]) {
+    println!("{}", xml_output::to_xml(rows));
 }
 
 // -- State aggregation output --
@@ -1320,9 +1585,7 @@ pub fn print_state_agg_table(rows: &[StateAggRow]) {
 
 /// Prints state aggregations as a GitHub-flavored Markdown table to stdout.
 pub fn print_state_agg_markdown(rows: &[StateAggRow]) {
-    let mut table = Table::new(build_state_agg_rows(rows));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_state_agg_rows(rows)));
 }
 
 /// Prints state aggregations as CSV to stdout.
@@ -1337,7 +1600,68 @@ pub fn print_state_agg_csv(rows: &[StateAggRow]) -> Result<()> {
 
 /// Prints state aggregations as a well-formed XML document to stdout.
 pub fn print_state_agg_xml(rows: &[StateAggRow]) {
-    println!("{}", xml_output::state_agg_to_xml(rows));
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- Donation geography output --
+
+/// Flattened row representation of a donation geography bucket for tabular output.
+#[derive(Tabled, Serialize, Clone)]
+struct GeographyOutputRow {
+    #[tabled(rename = "Bucket")]
+    #[serde(rename = "Bucket")]
+    bucket: String,
+    #[tabled(rename = "Count")]
+    #[serde(rename = "Count")]
+    count: i64,
+    #[tabled(rename = "Total")]
+    #[serde(rename = "Total")]
+    total: String,
+    #[tabled(rename = "% of Total")]
+    #[serde(rename = "% of Total")]
+    pct: String,
+    #[tabled(rename = "Mapping")]
+    #[serde(rename = "Mapping")]
+    mapping_version: String,
+}
+
+fn build_geography_rows(geography: &DonationGeography) -> Vec<GeographyOutputRow> {
+    geography
+        .buckets
+        .iter()
+        .map(|r| GeographyOutputRow {
+            bucket: r.bucket.to_string(),
+            count: r.donation_count,
+            total: format_currency_with_commas(r.total_amount),
+            pct: format!("{:.1}%", r.pct_of_amount),
+            mapping_version: geography.mapping_version.clone(),
+        })
+        .collect()
+}
+
+/// Prints a donation geography breakdown as an ASCII table to stdout.
+pub fn print_geography_table(geography: &DonationGeography) {
+    println!("{}", Table::new(build_geography_rows(geography)));
+}
+
+/// Prints a donation geography breakdown as a GitHub-flavored Markdown table to stdout.
+pub fn print_geography_markdown(geography: &DonationGeography) {
+    println!("{}", markdown_table(build_geography_rows(geography)));
+}
+
+/// Prints a donation geography breakdown as CSV to stdout.
+pub fn print_geography_csv(geography: &DonationGeography) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    for row in build_geography_rows(geography) {
+        wtr.serialize(row)?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints a donation geography breakdown as a well-formed XML document to stdout.
+pub fn print_geography_xml(geography: &DonationGeography) {
+    println!("{}", xml_output::geography_to_xml(geography));
 }
 
 // -- Leaderboard output --
@@ -1368,6 +1692,9 @@ struct LeaderboardOutputRow {
     #[tabled(rename = "Avg Return")]
     #[serde(rename = "AvgReturn")]
     avg_return: String,
+    #[tabled(rename = "Return 95% CI")]
+    #[serde(rename = "ReturnCI")]
+    return_ci: String,
     #[tabled(rename = "Alpha")]
     #[serde(rename = "Alpha")]
     alpha: String,
@@ -1377,6 +1704,12 @@ struct LeaderboardOutputRow {
     #[tabled(rename = "Pctl")]
     #[serde(rename = "Percentile")]
     percentile: String,
+    #[tabled(rename = "Recess Vol")]
+    #[serde(rename = "RecessVolume")]
+    recess_volume: String,
+    #[tabled(rename = "Style")]
+    #[serde(rename = "Style")]
+    style: String,
 }
 
 fn build_leaderboard_rows(rows: &[LeaderboardRow]) -> Vec<LeaderboardOutputRow> {
@@ -1393,6 +1726,10 @@ fn build_leaderboard_rows(rows: &[LeaderboardRow]) -> Vec<LeaderboardOutputRow>
             } else {
                 format!("{:.1}%", r.avg_return)
             },
+            return_ci: match (r.return_ci_low, r.return_ci_high) {
+                (Some(low), Some(high)) => format!("{:+.1}% to {:+.1}%", low, high),
+                _ => "N/A".to_string(),
+            },
             alpha: r.avg_alpha.map(|a| {
                 if a >= 0.0 {
                     format!("+{:.1}%", a)
@@ -1402,6 +1739,11 @@ fn build_leaderboard_rows(rows: &[LeaderboardRow]) -> Vec<LeaderboardOutputRow>
             }).unwrap_or_else(|| "N/A".to_string()),
             avg_hold: r.avg_holding_days.map(|d| format!("{:.0} days", d)).unwrap_or_else(|| "N/A".to_string()),
             percentile: format!("{:.0}%", r.percentile * 100.0),
+            recess_volume: r
+                .recess_volume_share
+                .map(|v| format!("{:.0}%", v * 100.0))
+                .unwrap_or_else(|| "N/A".to_string()),
+            style: r.trading_style.to_string(),
         })
         .collect()
 }
@@ -1413,13 +1755,12 @@ pub fn print_leaderboard_table(rows: &[LeaderboardRow]) {
 
 /// Prints leaderboard as a GitHub-flavored Markdown table to stdout.
 pub fn print_leaderboard_markdown(rows: &[LeaderboardRow]) {
-    let mut table = Table::new(build_leaderboard_rows(rows));
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(build_leaderboard_rows(rows)));
 }
 
-/// Prints leaderboard as CSV to stdout. Fields are sanitized against formula injection.
-pub fn print_leaderboard_csv(rows: &[LeaderboardRow]) -> Result<()> {
+/// Prints leaderboard as CSV to stdout, each row carrying the trade universe
+/// it was ranked on. Fields are sanitized against formula injection.
+pub fn print_leaderboard_csv(rows: &[LeaderboardRow], universe: &UniverseProvenance) -> Result<()> {
     let mut wtr = csv::Writer::from_writer(std::io::stdout());
     wtr.write_record([
         "rank",
@@ -1429,9 +1770,17 @@ pub fn print_leaderboard_csv(rows: &[LeaderboardRow]) -> Result<()> {
         "trades",
         "win_rate",
         "avg_return",
+        "return_ci_low",
+        "return_ci_high",
+        "win_rate_ci_low",
+        "win_rate_ci_high",
         "alpha",
         "avg_holding_days",
         "percentile",
+        "recess_volume_share",
+        "median_holding_days",
+        "trading_style",
+        "universe",
     ])?;
 
     for r in rows {
@@ -1443,9 +1792,17 @@ pub fn print_leaderboard_csv(rows: &[LeaderboardRow]) -> Result<()> {
             r.total_trades.to_string(),
             format!("{:.2}", r.win_rate),
             format!("{:.2}", r.avg_return),
+            r.return_ci_low.map(|v| format!("{:.2}", v)).unwrap_or_default(),
+            r.return_ci_high.map(|v| format!("{:.2}", v)).unwrap_or_default(),
+            r.win_rate_ci_low.map(|v| format!("{:.2}", v)).unwrap_or_default(),
+            r.win_rate_ci_high.map(|v| format!("{:.2}", v)).unwrap_or_default(),
             r.avg_alpha.map(|a| format!("{:.2}", a)).unwrap_or_default(),
             r.avg_holding_days.map(|d| format!("{:.2}", d)).unwrap_or_default(),
             format!("{:.2}", r.percentile),
+            r.recess_volume_share.map(|v| format!("{:.4}", v)).unwrap_or_default(),
+            r.median_holding_days.map(|d| d.to_string()).unwrap_or_default(),
+            r.trading_style.to_string(),
+            universe.to_string(),
         ])?;
     }
     wtr.flush()?;
@@ -1454,448 +1811,2205 @@ pub fn print_leaderboard_csv(rows: &[LeaderboardRow]) -> Result<()> {
 
 /// Prints leaderboard as a well-formed XML document to stdout.
 pub fn print_leaderboard_xml(rows: &[LeaderboardRow]) {
-    println!("{}", xml_output::leaderboard_to_xml(rows));
+    println!("{}", xml_output::to_xml(rows));
 }
 
-// -- JSON output --
+// -- Session / recess grouping output --
 
-/// Prints any serializable data as pretty-printed JSON to stdout.
-pub fn print_json<T: serde::Serialize>(data: &T) {
-    match serde_json::to_string_pretty(data) {
-        Ok(json) => println!("{}", json),
-        Err(e) => eprintln!("Failed to serialize to JSON: {}", e),
-    }
+#[derive(Tabled)]
+struct SessionGroupOutputRow {
+    #[tabled(rename = "Chamber")]
+    chamber: String,
+    #[tabled(rename = "Status")]
+    status: String,
+    #[tabled(rename = "Trades")]
+    trades: usize,
+    #[tabled(rename = "Politicians")]
+    politicians: usize,
+    #[tabled(rename = "Volume")]
+    volume: String,
+    #[tabled(rename = "Share")]
+    share: String,
+    #[tabled(rename = "Closed")]
+    closed_trades: usize,
+    #[tabled(rename = "Avg Return")]
+    avg_return: String,
 }
 
-/// Formats a dollar value with K/M suffixes for readability.
-fn format_value(value: i64) -> String {
-    if value >= 1_000_000 {
-        format!("${:.1}M", value as f64 / 1_000_000.0)
-    } else if value >= 1_000 {
-        format!("${:.1}K", value as f64 / 1_000.0)
-    } else {
-        format!("${}", value)
-    }
+fn build_session_group_rows(groups: &[SessionGroupRow]) -> Vec<SessionGroupOutputRow> {
+    groups
+        .iter()
+        .map(|g| SessionGroupOutputRow {
+            chamber: g.chamber.clone(),
+            status: g.session_status.as_str().to_string(),
+            trades: g.trades,
+            politicians: g.politicians,
+            volume: format_currency_with_commas(g.volume),
+            share: format!("{:.1}%", g.volume_share * 100.0),
+            closed_trades: g.closed_trades,
+            avg_return: g
+                .avg_return
+                .map(|r| format!("{:+.1}%", r))
+                .unwrap_or_else(|| "N/A".to_string()),
+        })
+        .collect()
 }
 
-// -- Conflict output (committee trading scores) --
+/// Prints session/recess groups as an ASCII table to stdout.
+pub fn print_session_groups_table(groups: &[SessionGroupRow]) {
+    println!("{}", Table::new(build_session_group_rows(groups)));
+}
 
-/// Prints conflict rows (committee trading scores) as ASCII table to stdout.
-pub fn print_conflict_table(rows: &[crate::commands::conflicts::ConflictRow]) {
-    use tabled::Tabled;
+/// Prints session/recess groups as a GitHub-flavored Markdown table to stdout.
+pub fn print_session_groups_markdown(groups: &[SessionGroupRow]) {
+    println!("{}", markdown_table(build_session_group_rows(groups)));
+}
 
-    #[derive(Tabled)]
-    struct ConflictTableRow {
-        #[tabled(rename = "Rank")]
-        rank: usize,
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Committees")]
-        committees: String,
-        #[tabled(rename = "Scored Trades")]
-        total_scored_trades: usize,
-        #[tabled(rename = "Committee Trades")]
-        committee_related_trades: usize,
-        #[tabled(rename = "Committee %")]
-        committee_trading_pct: String,
+/// Prints session/recess groups as CSV to stdout.
+pub fn print_session_groups_csv(groups: &[SessionGroupRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "chamber",
+        "session_status",
+        "trades",
+        "politicians",
+        "volume",
+        "volume_share",
+        "closed_trades",
+        "avg_return",
+    ])?;
+    for g in groups {
+        wtr.write_record(&[
+            sanitize_csv_field(&g.chamber),
+            g.session_status.as_str().to_string(),
+            g.trades.to_string(),
+            g.politicians.to_string(),
+            format!("{:.2}", g.volume),
+            format!("{:.4}", g.volume_share),
+            g.closed_trades.to_string(),
+            g.avg_return.map(|r| format!("{:.2}", r)).unwrap_or_default(),
+        ])?;
     }
+    wtr.flush()?;
+    Ok(())
+}
 
-    let table_rows: Vec<ConflictTableRow> = rows
+/// Prints session/recess groups as a well-formed XML document to stdout.
+pub fn print_session_groups_xml(groups: &[SessionGroupRow]) {
+    println!("{}", xml_output::to_xml(groups));
+}
+
+// -- Label performance output --
+
+use capitoltraders_lib::{LabelCount, LabelReport};
+
+#[derive(Tabled)]
+struct LabelPoliticianOutputRow {
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Trades")]
+    trades: usize,
+    #[tabled(rename = "Buy Volume")]
+    buy_volume: String,
+    #[tabled(rename = "Sell Volume")]
+    sell_volume: String,
+    #[tabled(rename = "Volume")]
+    volume: String,
+    #[tabled(rename = "Closed")]
+    closed_legs: usize,
+    #[tabled(rename = "Realized P&L")]
+    realized_pnl: String,
+}
+
+fn build_label_report_rows(report: &LabelReport) -> Vec<LabelPoliticianOutputRow> {
+    report
+        .by_politician
         .iter()
-        .map(|r| ConflictTableRow {
-            rank: r.rank,
-            politician_name: r.politician_name.clone(),
-            committees: r.committees.clone(),
-            total_scored_trades: r.total_scored_trades,
-            committee_related_trades: r.committee_related_trades,
-            committee_trading_pct: format!("{:.1}%", r.committee_trading_pct),
+        .map(|r| LabelPoliticianOutputRow {
+            politician: r.politician_name.clone(),
+            trades: r.trades,
+            buy_volume: format_value(r.buy_volume),
+            sell_volume: format_value(r.sell_volume),
+            volume: format_value(r.volume),
+            closed_legs: r.closed_legs,
+            realized_pnl: format_signed_currency(r.realized_pnl),
         })
-        .collect();
+        .collect()
+}
 
-    println!("{}", Table::new(table_rows));
+/// Prints a label's per-politician breakdown as an ASCII table to stdout.
+pub fn print_label_report_table(report: &LabelReport) {
+    println!("{}", Table::new(build_label_report_rows(report)));
 }
 
-/// Prints conflict rows as Markdown table to stdout.
-pub fn print_conflict_markdown(rows: &[crate::commands::conflicts::ConflictRow]) {
-    use tabled::Tabled;
+/// Prints a label's per-politician breakdown as a Markdown table to stdout.
+pub fn print_label_report_markdown(report: &LabelReport) {
+    println!("{}", markdown_table(build_label_report_rows(report)));
+}
 
-    #[derive(Tabled)]
-    struct ConflictTableRow {
-        #[tabled(rename = "Rank")]
-        rank: usize,
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Committees")]
-        committees: String,
-        #[tabled(rename = "Scored Trades")]
-        total_scored_trades: usize,
-        #[tabled(rename = "Committee Trades")]
-        committee_related_trades: usize,
-        #[tabled(rename = "Committee %")]
-        committee_trading_pct: String,
-    }
-
-    let table_rows: Vec<ConflictTableRow> = rows
-        .iter()
-        .map(|r| ConflictTableRow {
-            rank: r.rank,
-            politician_name: r.politician_name.clone(),
-            committees: r.committees.clone(),
-            total_scored_trades: r.total_scored_trades,
-            committee_related_trades: r.committee_related_trades,
-            committee_trading_pct: format!("{:.1}%", r.committee_trading_pct),
-        })
-        .collect();
-
-    let mut table = Table::new(table_rows);
-    table.with(Style::markdown());
-    println!("{}", table);
-}
-
-/// Prints conflict rows as CSV to stdout.
-pub fn print_conflict_csv(rows: &[crate::commands::conflicts::ConflictRow]) -> Result<()> {
+/// Prints a label's per-politician breakdown as CSV to stdout.
+pub fn print_label_report_csv(report: &LabelReport) -> Result<()> {
     let mut wtr = csv::Writer::from_writer(std::io::stdout());
     wtr.write_record([
-        "rank",
+        "label",
+        "politician_id",
         "politician",
-        "committees",
-        "total_scored_trades",
-        "committee_related_trades",
-        "committee_trading_pct",
+        "trades",
+        "buy_volume",
+        "sell_volume",
+        "volume",
+        "closed_legs",
+        "realized_pnl",
     ])?;
-
-    for row in rows {
+    for r in &report.by_politician {
         wtr.write_record(&[
-            row.rank.to_string(),
-            sanitize_csv_field(&row.politician_name),
-            sanitize_csv_field(&row.committees),
-            row.total_scored_trades.to_string(),
-            row.committee_related_trades.to_string(),
-            format!("{:.1}", row.committee_trading_pct),
+            sanitize_csv_field(&report.label),
+            r.politician_id.clone(),
+            sanitize_csv_field(&r.politician_name),
+            r.trades.to_string(),
+            r.buy_volume.to_string(),
+            r.sell_volume.to_string(),
+            r.volume.to_string(),
+            r.closed_legs.to_string(),
+            format!("{:.2}", r.realized_pnl),
         ])?;
     }
     wtr.flush()?;
     Ok(())
 }
 
-/// Prints conflict rows as XML to stdout.
-pub fn print_conflict_xml(rows: &[crate::commands::conflicts::ConflictRow]) {
-    println!("{}", xml_output::conflicts_to_xml(rows));
+/// Prints a label report as a well-formed XML document to stdout.
+pub fn print_label_report_xml(report: &LabelReport) {
+    println!("{}", xml_output::label_report_to_xml(report));
 }
 
-// -- Donation correlation output --
-
-/// Prints donation correlation rows as ASCII table to stdout.
-pub fn print_donation_correlation_table(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
-    use tabled::Tabled;
-
-    #[derive(Tabled)]
-    struct DonationTableRow {
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Ticker")]
-        ticker: String,
-        #[tabled(rename = "Donors")]
-        matching_donors: i64,
-        #[tabled(rename = "Total Donations")]
-        total_donations: String,
-        #[tabled(rename = "Donor Employers")]
-        donor_employers: String,
-    }
+#[derive(Tabled)]
+struct LabelCountOutputRow {
+    #[tabled(rename = "Label")]
+    label: String,
+    #[tabled(rename = "Trades")]
+    trades: usize,
+    #[tabled(rename = "Politicians")]
+    politicians: usize,
+    #[tabled(rename = "Volume")]
+    volume: String,
+}
 
-    let table_rows: Vec<DonationTableRow> = rows
+fn build_label_count_rows(counts: &[LabelCount]) -> Vec<LabelCountOutputRow> {
+    counts
         .iter()
-        .map(|r| DonationTableRow {
-            politician_name: r.politician_name.clone(),
-            ticker: r.ticker.clone(),
-            matching_donors: r.matching_donors,
-            total_donations: format!("${:.2}", r.total_donations),
-            donor_employers: r.donor_employers.clone(),
+        .map(|c| LabelCountOutputRow {
+            label: c.label.clone(),
+            trades: c.trades,
+            politicians: c.politicians,
+            volume: format_value(c.volume),
         })
-        .collect();
+        .collect()
+}
 
-    println!("{}", Table::new(table_rows));
+/// Prints label usage counts as an ASCII table to stdout.
+pub fn print_label_counts_table(counts: &[LabelCount]) {
+    println!("{}", Table::new(build_label_count_rows(counts)));
 }
 
-/// Prints donation correlation rows as Markdown table to stdout.
-pub fn print_donation_correlation_markdown(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
-    use tabled::Tabled;
+/// Prints label usage counts as a Markdown table to stdout.
+pub fn print_label_counts_markdown(counts: &[LabelCount]) {
+    println!("{}", markdown_table(build_label_count_rows(counts)));
+}
 
-    #[derive(Tabled)]
-    struct DonationTableRow {
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Ticker")]
-        ticker: String,
-        #[tabled(rename = "Donors")]
-        matching_donors: i64,
-        #[tabled(rename = "Total Donations")]
-        total_donations: String,
-        #[tabled(rename = "Donor Employers")]
-        donor_employers: String,
+/// Prints label usage counts as CSV to stdout.
+pub fn print_label_counts_csv(counts: &[LabelCount]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record(["label", "trades", "politicians", "volume"])?;
+    for c in counts {
+        wtr.write_record(&[
+            sanitize_csv_field(&c.label),
+            c.trades.to_string(),
+            c.politicians.to_string(),
+            c.volume.to_string(),
+        ])?;
     }
+    wtr.flush()?;
+    Ok(())
+}
 
-    let table_rows: Vec<DonationTableRow> = rows
+/// Prints label usage counts as a well-formed XML document to stdout.
+pub fn print_label_counts_xml(counts: &[LabelCount]) {
+    println!("{}", xml_output::to_xml(counts));
+}
+
+// -- Trading index output --
+
+use capitoltraders_lib::analysis::IndexPoint;
+
+#[derive(Tabled)]
+struct IndexPointOutputRow {
+    #[tabled(rename = "Date")]
+    date: String,
+    #[tabled(rename = "Bought")]
+    buy_value: String,
+    #[tabled(rename = "Sold")]
+    sell_value: String,
+    #[tabled(rename = "Net")]
+    net_value: String,
+    #[tabled(rename = "Raw")]
+    raw: String,
+    #[tabled(rename = "Smoothed")]
+    smoothed: String,
+}
+
+fn build_index_point_rows(points: &[IndexPoint]) -> Vec<IndexPointOutputRow> {
+    let index = |v: Option<f64>| v.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".to_string());
+    points
         .iter()
-        .map(|r| DonationTableRow {
-            politician_name: r.politician_name.clone(),
-            ticker: r.ticker.clone(),
-            matching_donors: r.matching_donors,
-            total_donations: format!("${:.2}", r.total_donations),
-            donor_employers: r.donor_employers.clone(),
+        .map(|p| IndexPointOutputRow {
+            date: p.date.clone(),
+            buy_value: format_value(p.buy_value),
+            sell_value: format_value(p.sell_value),
+            net_value: if p.net_value < 0 {
+                format!("-{}", format_value(-p.net_value))
+            } else {
+                format_value(p.net_value)
+            },
+            raw: index(p.raw),
+            smoothed: index(p.smoothed),
         })
-        .collect();
+        .collect()
+}
 
-    let mut table = Table::new(table_rows);
-    table.with(Style::markdown());
-    println!("{}", table);
+/// Prints a trading index series as an ASCII table to stdout.
+pub fn print_trading_index_table(points: &[IndexPoint]) {
+    println!("{}", Table::new(build_index_point_rows(points)));
 }
 
-/// Prints donation correlation rows as CSV to stdout.
-pub fn print_donation_correlation_csv(rows: &[crate::commands::conflicts::DonationCorrelationRow]) -> Result<()> {
-    let mut wtr = csv::Writer::from_writer(std::io::stdout());
-    wtr.write_record([
-        "politician",
-        "ticker",
-        "matching_donors",
-        "total_donations",
-        "donor_employers",
-    ])?;
+/// Prints a trading index series as a Markdown table to stdout.
+pub fn print_trading_index_markdown(points: &[IndexPoint]) {
+    println!("{}", markdown_table(build_index_point_rows(points)));
+}
 
-    for row in rows {
+/// Prints a trading index series as CSV to stdout; null values are empty.
+pub fn print_trading_index_csv(points: &[IndexPoint]) -> Result<()> {
+    let opt_f = |v: Option<f64>| v.map(|v| v.to_string()).unwrap_or_default();
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record(["date", "buy_value", "sell_value", "net_value", "raw", "smoothed"])?;
+    for p in points {
         wtr.write_record(&[
-            sanitize_csv_field(&row.politician_name),
-            sanitize_csv_field(&row.ticker),
-            row.matching_donors.to_string(),
-            format!("{:.2}", row.total_donations),
-            sanitize_csv_field(&row.donor_employers),
+            p.date.clone(),
+            p.buy_value.to_string(),
+            p.sell_value.to_string(),
+            p.net_value.to_string(),
+            opt_f(p.raw),
+            opt_f(p.smoothed),
         ])?;
     }
     wtr.flush()?;
     Ok(())
 }
 
-/// Prints donation correlation rows as XML to stdout.
-pub fn print_donation_correlation_xml(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
-    println!("{}", xml_output::donation_correlations_to_xml(rows));
+/// Prints a trading index series as a well-formed XML document to stdout.
+pub fn print_trading_index_xml(points: &[IndexPoint]) {
+    println!("{}", xml_output::to_xml(points));
 }
 
-// --- Anomaly output functions ---
+// -- Politician overlap output --
 
-/// Prints anomaly rows as ASCII table to stdout.
-pub fn print_anomaly_table(rows: &[crate::commands::anomalies::AnomalyRow]) {
-    use tabled::Tabled;
+use capitoltraders_lib::analysis::OverlapPair;
 
-    #[derive(Tabled)]
-    struct AnomalyTableRow {
-        #[tabled(rename = "#")]
-        rank: usize,
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Pre-Move")]
-        pre_move_count: usize,
-        #[tabled(rename = "Vol Ratio")]
-        volume_ratio: String,
-        #[tabled(rename = "HHI")]
-        hhi_score: String,
-        #[tabled(rename = "Score")]
-        composite_score: String,
-        #[tabled(rename = "Confidence")]
-        confidence: String,
-    }
+#[derive(Tabled)]
+struct OverlapOutputRow {
+    #[tabled(rename = "Politician A")]
+    politician_a: String,
+    #[tabled(rename = "Politician B")]
+    politician_b: String,
+    #[tabled(rename = "Shared")]
+    shared_issuers: usize,
+    #[tabled(rename = "Jaccard")]
+    jaccard: String,
+    #[tabled(rename = "Cosine")]
+    cosine: String,
+}
 
-    let table_rows: Vec<AnomalyTableRow> = rows
+fn build_overlap_rows(pairs: &[OverlapPair]) -> Vec<OverlapOutputRow> {
+    pairs
         .iter()
-        .map(|r| AnomalyTableRow {
-            rank: r.rank,
-            politician_name: r.politician_name.clone(),
-            pre_move_count: r.pre_move_count,
-            volume_ratio: format!("{:.1}x", r.volume_ratio),
-            hhi_score: format!("{:.3}", r.hhi_score),
-            composite_score: format!("{:.3}", r.composite_score),
-            confidence: format!("{:.0}%", r.confidence * 100.0),
+        .map(|p| OverlapOutputRow {
+            politician_a: format!("{} ({})", p.politician_a_name, p.politician_a_id),
+            politician_b: format!("{} ({})", p.politician_b_name, p.politician_b_id),
+            shared_issuers: p.shared_issuers,
+            jaccard: format!("{:.3}", p.jaccard),
+            cosine: format!("{:.3}", p.cosine),
         })
-        .collect();
+        .collect()
+}
 
-    let mut table = Table::new(table_rows);
-    table.with(Style::modern());
-    println!("{}", table);
+/// Prints politician overlap pairs as an ASCII table to stdout.
+pub fn print_overlap_table(pairs: &[OverlapPair]) {
+    println!("{}", Table::new(build_overlap_rows(pairs)));
 }
 
-/// Prints anomaly rows as Markdown table to stdout.
-pub fn print_anomaly_markdown(rows: &[crate::commands::anomalies::AnomalyRow]) {
-    use tabled::Tabled;
+/// Prints politician overlap pairs as a Markdown table to stdout.
+pub fn print_overlap_markdown(pairs: &[OverlapPair]) {
+    println!("{}", markdown_table(build_overlap_rows(pairs)));
+}
 
-    #[derive(Tabled)]
-    struct AnomalyTableRow {
-        #[tabled(rename = "#")]
-        rank: usize,
-        #[tabled(rename = "Politician")]
-        politician_name: String,
-        #[tabled(rename = "Pre-Move")]
-        pre_move_count: usize,
-        #[tabled(rename = "Vol Ratio")]
-        volume_ratio: String,
-        #[tabled(rename = "HHI")]
-        hhi_score: String,
-        #[tabled(rename = "Score")]
-        composite_score: String,
-        #[tabled(rename = "Confidence")]
-        confidence: String,
+/// Prints politician overlap pairs as CSV to stdout.
+pub fn print_overlap_csv(pairs: &[OverlapPair]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    for p in pairs {
+        wtr.serialize(p)?;
     }
+    wtr.flush()?;
+    Ok(())
+}
 
-    let table_rows: Vec<AnomalyTableRow> = rows
-        .iter()
-        .map(|r| AnomalyTableRow {
-            rank: r.rank,
-            politician_name: r.politician_name.clone(),
-            pre_move_count: r.pre_move_count,
-            volume_ratio: format!("{:.1}x", r.volume_ratio),
-            hhi_score: format!("{:.3}", r.hhi_score),
-            composite_score: format!("{:.3}", r.composite_score),
-            confidence: format!("{:.0}%", r.confidence * 100.0),
+/// Prints politician overlap pairs as a well-formed XML document to stdout.
+pub fn print_overlap_xml(pairs: &[OverlapPair]) {
+    println!("{}", xml_output::to_xml(pairs));
+}
+
+// -- Committee jurisdiction output --
+
+use capitoltraders_lib::JurisdictionSectorRow;
+
+#[derive(Tabled)]
+struct JurisdictionOutputRow {
+    #[tabled(rename = "Committee")]
+    committee: String,
+    #[tabled(rename = "Chamber")]
+    chamber: String,
+    #[tabled(rename = "Name")]
+    full_name: String,
+    #[tabled(rename = "Sector")]
+    sector: String,
+    #[tabled(rename = "Source")]
+    source: String,
+}
+
+fn build_jurisdiction_rows(rows: &[JurisdictionSectorRow]) -> Vec<JurisdictionOutputRow> {
+    rows.iter()
+        .map(|r| JurisdictionOutputRow {
+            committee: r.committee.clone(),
+            chamber: r.chamber.clone(),
+            full_name: r.full_name.clone(),
+            sector: r.sector.clone().unwrap_or_else(|| "-".to_string()),
+            source: r.source.to_string(),
         })
-        .collect();
+        .collect()
+}
 
-    let mut table = Table::new(table_rows);
-    table.with(Style::markdown());
-    println!("{}", table);
+/// Prints the effective committee jurisdiction mapping as an ASCII table to stdout.
+pub fn print_jurisdictions_table(rows: &[JurisdictionSectorRow]) {
+    println!("{}", Table::new(build_jurisdiction_rows(rows)));
 }
 
-/// Prints anomaly rows as CSV to stdout.
-pub fn print_anomaly_csv(rows: &[crate::commands::anomalies::AnomalyRow]) -> Result<()> {
-    let mut writer = csv::Writer::from_writer(std::io::stdout());
-    writer.write_record(["#", "Politician", "Pre-Move", "Vol Ratio", "HHI", "Score", "Confidence"])?;
-    for row in rows {
-        writer.write_record(&[
-            row.rank.to_string(),
-            sanitize_csv_field(&row.politician_name),
-            row.pre_move_count.to_string(),
-            format!("{:.1}", row.volume_ratio),
-            format!("{:.3}", row.hhi_score),
-            format!("{:.3}", row.composite_score),
-            format!("{:.2}", row.confidence),
-        ])?;
+/// Prints the effective committee jurisdiction mapping as a Markdown table to stdout.
+pub fn print_jurisdictions_markdown(rows: &[JurisdictionSectorRow]) {
+    println!("{}", markdown_table(build_jurisdiction_rows(rows)));
+}
+
+/// Prints the effective committee jurisdiction mapping as CSV to stdout.
+pub fn print_jurisdictions_csv(rows: &[JurisdictionSectorRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    for r in rows {
+        wtr.serialize(r)?;
     }
-    writer.flush()?;
+    wtr.flush()?;
     Ok(())
 }
 
-/// Prints anomaly rows as XML to stdout.
-pub fn print_anomaly_xml(rows: &[crate::commands::anomalies::AnomalyRow]) {
-    println!("{}", xml_output::anomalies_to_xml(rows));
+/// Prints the effective committee jurisdiction mapping as a well-formed XML document to stdout.
+pub fn print_jurisdictions_xml(rows: &[JurisdictionSectorRow]) {
+    println!("{}", xml_output::to_xml(rows));
 }
 
-/// Prints pre-move signal rows as ASCII table to stdout.
-pub fn print_pre_move_table(rows: &[crate::commands::anomalies::PreMoveRow]) {
+// -- Tax estimate output --
+
+use crate::commands::analytics::{TaxEstimateReport, TaxEstimateRow};
+
+#[derive(Tabled)]
+struct TaxEstimateOutputRow {
+    #[tabled(rename = "#")]
+    rank: usize,
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Party")]
+    party: String,
+    #[tabled(rename = "State")]
+    state: String,
+    #[tabled(rename = "Trades")]
+    trades: usize,
+    #[tabled(rename = "Short-Term Gain")]
+    short_term: String,
+    #[tabled(rename = "Long-Term Gain")]
+    long_term: String,
+    #[tabled(rename = "Undated")]
+    undated: usize,
+    #[tabled(rename = "Est. Tax (approx.)")]
+    estimated_tax: String,
+}
+
+fn format_signed_currency(value: f64) -> String {
+    if value < 0.0 {
+        format!("-{}", format_currency_with_commas(-value))
+    } else {
+        format_currency_with_commas(value)
+    }
+}
+
+fn build_tax_estimate_rows(rows: &[TaxEstimateRow]) -> Vec<TaxEstimateOutputRow> {
+    rows.iter()
+        .map(|r| TaxEstimateOutputRow {
+            rank: r.rank,
+            politician: r.politician_name.clone(),
+            party: r.party.clone(),
+            state: r.state.clone(),
+            trades: r.total_trades,
+            short_term: format_signed_currency(r.realized_short_term_gain),
+            long_term: format_signed_currency(r.realized_long_term_gain),
+            undated: r.unknown_term_trades,
+            estimated_tax: format_currency_with_commas(r.estimated_tax),
+        })
+        .collect()
+}
+
+/// Prints the short/long-term gain split and tax estimate as an ASCII table to stdout.
+pub fn print_tax_estimate_table(rows: &[TaxEstimateRow]) {
+    println!("{}", Table::new(build_tax_estimate_rows(rows)));
+}
+
+/// Prints the short/long-term gain split and tax estimate as a Markdown table to stdout.
+pub fn print_tax_estimate_markdown(rows: &[TaxEstimateRow]) {
+    println!("{}", markdown_table(build_tax_estimate_rows(rows)));
+}
+
+/// Prints the short/long-term gain split and tax estimate as CSV to stdout,
+/// each row carrying the trade universe it was computed from.
+pub fn print_tax_estimate_csv(rows: &[TaxEstimateRow], universe: &UniverseProvenance) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "rank",
+        "politician",
+        "party",
+        "state",
+        "trades",
+        "realized_short_term_gain",
+        "realized_long_term_gain",
+        "unknown_term_trades",
+        "estimated_tax_approx",
+        "universe",
+    ])?;
+
+    for r in rows {
+        wtr.write_record(&[
+            r.rank.to_string(),
+            sanitize_csv_field(&r.politician_name),
+            r.party.clone(),
+            r.state.clone(),
+            r.total_trades.to_string(),
+            format!("{:.2}", r.realized_short_term_gain),
+            format!("{:.2}", r.realized_long_term_gain),
+            r.unknown_term_trades.to_string(),
+            format!("{:.2}", r.estimated_tax),
+            universe.to_string(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints the tax estimate report as a well-formed XML document to stdout.
+pub fn print_tax_estimate_xml(report: &TaxEstimateReport) {
+    println!("{}", xml_output::tax_estimate_to_xml(report));
+}
+
+// -- Trade detail output --
+
+use crate::commands::analytics::TradeDetailRow;
+
+#[derive(Tabled)]
+struct TradeDetailOutputRow {
+    #[tabled(rename = "Sell TX")]
+    sell_tx_id: i64,
+    #[tabled(rename = "Sell Date")]
+    sell_date: String,
+    #[tabled(rename = "Sell Price")]
+    sell_price: String,
+    #[tabled(rename = "Buy TX")]
+    buy_tx_id: i64,
+    #[tabled(rename = "Buy Date")]
+    buy_date: String,
+    #[tabled(rename = "Buy Price")]
+    buy_price: String,
+    #[tabled(rename = "Shares")]
+    shares: String,
+    #[tabled(rename = "Return")]
+    return_pct: String,
+    #[tabled(rename = "Gain")]
+    realized_gain: String,
+    #[tabled(rename = "Sell Filing")]
+    sell_filing_url: String,
+    #[tabled(rename = "Buy Filing")]
+    buy_filing_url: String,
+}
+
+fn build_trade_detail_rows(rows: &[TradeDetailRow]) -> Vec<TradeDetailOutputRow> {
+    rows.iter()
+        .map(|r| TradeDetailOutputRow {
+            sell_tx_id: r.sell_tx_id,
+            sell_date: r.sell_date.clone(),
+            sell_price: format!("${:.2}", r.sell_price),
+            buy_tx_id: r.buy_tx_id,
+            buy_date: r.buy_date.clone(),
+            buy_price: format!("${:.2}", r.buy_price),
+            shares: format!("{:.2}", r.shares),
+            return_pct: format!("{:.1}%", r.return_pct),
+            realized_gain: format_signed_currency(r.realized_gain),
+            sell_filing_url: r.sell_filing_url.clone(),
+            buy_filing_url: r.buy_filing_url.clone(),
+        })
+        .collect()
+}
+
+/// Prints lot-matched trade legs as an ASCII table to stdout.
+pub fn print_trade_detail_table(rows: &[TradeDetailRow]) {
+    println!("{}", Table::new(build_trade_detail_rows(rows)));
+}
+
+/// Prints lot-matched trade legs as a Markdown table to stdout.
+pub fn print_trade_detail_markdown(rows: &[TradeDetailRow]) {
+    println!("{}", markdown_table(build_trade_detail_rows(rows)));
+}
+
+/// Prints lot-matched trade legs as CSV to stdout.
+pub fn print_trade_detail_csv(rows: &[TradeDetailRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "ticker",
+        "sell_tx_id",
+        "sell_date",
+        "sell_price",
+        "sell_filing_url",
+        "buy_tx_id",
+        "buy_date",
+        "buy_price",
+        "buy_filing_url",
+        "shares",
+        "return_pct",
+        "realized_gain",
+        "holding_days",
+    ])?;
+
+    for r in rows {
+        wtr.write_record(&[
+            sanitize_csv_field(&r.ticker),
+            r.sell_tx_id.to_string(),
+            r.sell_date.clone(),
+            format!("{:.2}", r.sell_price),
+            sanitize_csv_field(&r.sell_filing_url),
+            r.buy_tx_id.to_string(),
+            r.buy_date.clone(),
+            format!("{:.2}", r.buy_price),
+            sanitize_csv_field(&r.buy_filing_url),
+            format!("{:.4}", r.shares),
+            format!("{:.2}", r.return_pct),
+            format!("{:.2}", r.realized_gain),
+            r.holding_days.map(|d| d.to_string()).unwrap_or_default(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints lot-matched trade legs as a well-formed XML document to stdout.
+pub fn print_trade_detail_xml(rows: &[TradeDetailRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- JSON output --
+
+/// Prints any serializable data as pretty-printed JSON to stdout.
+pub fn print_json<T: serde::Serialize>(data: &T) {
+    match serde_json::to_string_pretty(data) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Failed to serialize to JSON: {}", e),
+    }
+}
+
+/// Formats a dollar value with K/M suffixes for readability.
+fn format_value(value: i64) -> String {
+    if value >= 1_000_000 {
+        format!("${:.1}M", value as f64 / 1_000_000.0)
+    } else if value >= 1_000 {
+        format!("${:.1}K", value as f64 / 1_000.0)
+    } else {
+        format!("${}", value)
+    }
+}
+
+// -- Conflict output (committee trading scores) --
+
+/// Prints conflict rows (committee trading scores) as ASCII table to stdout.
+pub fn print_conflict_table(rows: &[crate::commands::conflicts::ConflictRow]) {
     use tabled::Tabled;
 
     #[derive(Tabled)]
-    struct PreMoveTableRow {
+    struct ConflictTableRow {
+        #[tabled(rename = "Rank")]
+        rank: usize,
         #[tabled(rename = "Politician")]
         politician_name: String,
-        #[tabled(rename = "Ticker")]
-        ticker: String,
-        #[tabled(rename = "Date")]
-        tx_date: String,
-        #[tabled(rename = "Type")]
-        tx_type: String,
-        #[tabled(rename = "Price")]
-        trade_price: String,
-        #[tabled(rename = "30d Price")]
-        price_30d_later: String,
-        #[tabled(rename = "Change%")]
-        price_change_pct: String,
+        #[tabled(rename = "Committees")]
+        committees: String,
+        #[tabled(rename = "Scored Trades")]
+        total_scored_trades: usize,
+        #[tabled(rename = "Committee Trades")]
+        committee_related_trades: usize,
+        #[tabled(rename = "Committee %")]
+        committee_trading_pct: String,
     }
 
-    let table_rows: Vec<PreMoveTableRow> = rows
+    let table_rows: Vec<ConflictTableRow> = rows
         .iter()
-        .map(|r| PreMoveTableRow {
+        .map(|r| ConflictTableRow {
+            rank: r.rank,
             politician_name: r.politician_name.clone(),
-            ticker: r.ticker.clone(),
-            tx_date: r.tx_date.clone(),
-            tx_type: r.tx_type.clone(),
-            trade_price: format!("${:.2}", r.trade_price),
-            price_30d_later: format!("${:.2}", r.price_30d_later),
-            price_change_pct: if r.price_change_pct >= 0.0 {
-                format!("+{:.1}%", r.price_change_pct)
-            } else {
-                format!("{:.1}%", r.price_change_pct)
-            },
+            committees: r.committees.clone(),
+            total_scored_trades: r.total_scored_trades,
+            committee_related_trades: r.committee_related_trades,
+            committee_trading_pct: format!("{:.1}%", r.committee_trading_pct),
         })
         .collect();
 
-    let mut table = Table::new(table_rows);
-    table.with(Style::modern());
-    println!("{}", table);
+    println!("{}", Table::new(table_rows));
 }
 
-/// Prints pre-move signal rows as Markdown table to stdout.
-pub fn print_pre_move_markdown(rows: &[crate::commands::anomalies::PreMoveRow]) {
+/// Prints conflict rows as Markdown table to stdout.
+pub fn print_conflict_markdown(rows: &[crate::commands::conflicts::ConflictRow]) {
     use tabled::Tabled;
 
     #[derive(Tabled)]
-    struct PreMoveTableRow {
+    struct ConflictTableRow {
+        #[tabled(rename = "Rank")]
+        rank: usize,
         #[tabled(rename = "Politician")]
         politician_name: String,
-        #[tabled(rename = "Ticker")]
-        ticker: String,
-        #[tabled(rename = "Date")]
-        tx_date: String,
-        #[tabled(rename = "Type")]
-        tx_type: String,
-        #[tabled(rename = "Price")]
-        trade_price: String,
-        #[tabled(rename = "30d Price")]
-        price_30d_later: String,
-        #[tabled(rename = "Change%")]
-        price_change_pct: String,
+        #[tabled(rename = "Committees")]
+        committees: String,
+        #[tabled(rename = "Scored Trades")]
+        total_scored_trades: usize,
+        #[tabled(rename = "Committee Trades")]
+        committee_related_trades: usize,
+        #[tabled(rename = "Committee %")]
+        committee_trading_pct: String,
     }
 
-    let table_rows: Vec<PreMoveTableRow> = rows
+    let table_rows: Vec<ConflictTableRow> = rows
         .iter()
-        .map(|r| PreMoveTableRow {
+        .map(|r| ConflictTableRow {
+            rank: r.rank,
             politician_name: r.politician_name.clone(),
-            ticker: r.ticker.clone(),
-            tx_date: r.tx_date.clone(),
-            tx_type: r.tx_type.clone(),
-            trade_price: format!("${:.2}", r.trade_price),
-            price_30d_later: format!("${:.2}", r.price_30d_later),
-            price_change_pct: if r.price_change_pct >= 0.0 {
-                format!("+{:.1}%", r.price_change_pct)
-            } else {
-                format!("{:.1}%", r.price_change_pct)
-            },
+            committees: r.committees.clone(),
+            total_scored_trades: r.total_scored_trades,
+            committee_related_trades: r.committee_related_trades,
+            committee_trading_pct: format!("{:.1}%", r.committee_trading_pct),
         })
         .collect();
 
-    let mut table = Table::new(table_rows);
-    table.with(Style::markdown());
-    println!("{}", table);
+    println!("{}", markdown_table(table_rows));
 }
 
-/// Prints pre-move signal rows as CSV to stdout.
-pub fn print_pre_move_csv(rows: &[crate::commands::anomalies::PreMoveRow]) -> Result<()> {
-    let mut writer = csv::Writer::from_writer(std::io::stdout());
-    writer.write_record(["Politician", "Ticker", "Date", "Type", "Price", "30d Price", "Change%"])?;
+/// Prints conflict rows as CSV to stdout.
+pub fn print_conflict_csv(rows: &[crate::commands::conflicts::ConflictRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "rank",
+        "politician",
+        "committees",
+        "total_scored_trades",
+        "committee_related_trades",
+        "committee_trading_pct",
+    ])?;
+
     for row in rows {
-        writer.write_record(&[
+        wtr.write_record(&[
+            row.rank.to_string(),
             sanitize_csv_field(&row.politician_name),
-            row.ticker.clone(),
-            row.tx_date.clone(),
-            row.tx_type.clone(),
-            format!("{:.2}", row.trade_price),
-            format!("{:.2}", row.price_30d_later),
-            format!("{:.2}", row.price_change_pct),
+            sanitize_csv_field(&row.committees),
+            row.total_scored_trades.to_string(),
+            row.committee_related_trades.to_string(),
+            format!("{:.1}", row.committee_trading_pct),
         ])?;
     }
-    writer.flush()?;
+    wtr.flush()?;
     Ok(())
 }
 
-/// Prints pre-move signal rows as XML to stdout.
-pub fn print_pre_move_xml(rows: &[crate::commands::anomalies::PreMoveRow]) {
-    println!("{}", xml_output::pre_move_signals_to_xml(rows));
+/// Prints conflict rows as XML to stdout.
+pub fn print_conflict_xml(rows: &[crate::commands::conflicts::ConflictRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- Donation correlation output --
+
+/// Prints donation correlation rows as ASCII table to stdout.
+pub fn print_donation_correlation_table(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct DonationTableRow {
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Ticker")]
+        ticker: String,
+        #[tabled(rename = "Donors")]
+        matching_donors: i64,
+        #[tabled(rename = "Total Donations")]
+        total_donations: String,
+        #[tabled(rename = "Donor Employers")]
+        donor_employers: String,
+    }
+
+    let table_rows: Vec<DonationTableRow> = rows
+        .iter()
+        .map(|r| DonationTableRow {
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            matching_donors: r.matching_donors,
+            total_donations: format!("${:.2}", r.total_donations),
+            donor_employers: r.donor_employers.clone(),
+        })
+        .collect();
+
+    println!("{}", Table::new(table_rows));
+}
+
+/// Prints donation correlation rows as Markdown table to stdout.
+pub fn print_donation_correlation_markdown(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct DonationTableRow {
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Ticker")]
+        ticker: String,
+        #[tabled(rename = "Donors")]
+        matching_donors: i64,
+        #[tabled(rename = "Total Donations")]
+        total_donations: String,
+        #[tabled(rename = "Donor Employers")]
+        donor_employers: String,
+    }
+
+    let table_rows: Vec<DonationTableRow> = rows
+        .iter()
+        .map(|r| DonationTableRow {
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            matching_donors: r.matching_donors,
+            total_donations: format!("${:.2}", r.total_donations),
+            donor_employers: r.donor_employers.clone(),
+        })
+        .collect();
+
+    println!("{}", markdown_table(table_rows));
+}
+
+/// Prints donation correlation rows as CSV to stdout.
+pub fn print_donation_correlation_csv(rows: &[crate::commands::conflicts::DonationCorrelationRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "politician",
+        "ticker",
+        "matching_donors",
+        "total_donations",
+        "donor_employers",
+    ])?;
+
+    for row in rows {
+        wtr.write_record(&[
+            sanitize_csv_field(&row.politician_name),
+            sanitize_csv_field(&row.ticker),
+            row.matching_donors.to_string(),
+            format!("{:.2}", row.total_donations),
+            sanitize_csv_field(&row.donor_employers),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints donation correlation rows as XML to stdout.
+pub fn print_donation_correlation_xml(rows: &[crate::commands::conflicts::DonationCorrelationRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// --- Anomaly output functions ---
+
+/// Prints anomaly rows as ASCII table to stdout.
+pub fn print_anomaly_table(rows: &[crate::commands::anomalies::AnomalyRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct AnomalyTableRow {
+        #[tabled(rename = "#")]
+        rank: usize,
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Pre-Move")]
+        pre_move_count: usize,
+        #[tabled(rename = "Vol Ratio")]
+        volume_ratio: String,
+        #[tabled(rename = "HHI")]
+        hhi_score: String,
+        #[tabled(rename = "Score")]
+        composite_score: String,
+        #[tabled(rename = "Confidence")]
+        confidence: String,
+    }
+
+    let table_rows: Vec<AnomalyTableRow> = rows
+        .iter()
+        .map(|r| AnomalyTableRow {
+            rank: r.rank,
+            politician_name: r.politician_name.clone(),
+            pre_move_count: r.pre_move_count,
+            volume_ratio: format!("{:.1}x", r.volume_ratio),
+            hhi_score: format!("{:.3}", r.hhi_score),
+            composite_score: format!("{:.3}", r.composite_score),
+            confidence: format!("{:.0}%", r.confidence * 100.0),
+        })
+        .collect();
+
+    let mut table = Table::new(table_rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints anomaly rows as Markdown table to stdout.
+pub fn print_anomaly_markdown(rows: &[crate::commands::anomalies::AnomalyRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct AnomalyTableRow {
+        #[tabled(rename = "#")]
+        rank: usize,
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Pre-Move")]
+        pre_move_count: usize,
+        #[tabled(rename = "Vol Ratio")]
+        volume_ratio: String,
+        #[tabled(rename = "HHI")]
+        hhi_score: String,
+        #[tabled(rename = "Score")]
+        composite_score: String,
+        #[tabled(rename = "Confidence")]
+        confidence: String,
+    }
+
+    let table_rows: Vec<AnomalyTableRow> = rows
+        .iter()
+        .map(|r| AnomalyTableRow {
+            rank: r.rank,
+            politician_name: r.politician_name.clone(),
+            pre_move_count: r.pre_move_count,
+            volume_ratio: format!("{:.1}x", r.volume_ratio),
+            hhi_score: format!("{:.3}", r.hhi_score),
+            composite_score: format!("{:.3}", r.composite_score),
+            confidence: format!("{:.0}%", r.confidence * 100.0),
+        })
+        .collect();
+
+    println!("{}", markdown_table(table_rows));
+}
+
+/// Prints anomaly rows as CSV to stdout, each row carrying the trade universe
+/// the pre-move counts came from.
+pub fn print_anomaly_csv(
+    rows: &[crate::commands::anomalies::AnomalyRow],
+    universe: &UniverseProvenance,
+) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "#",
+        "Politician",
+        "Pre-Move",
+        "Vol Ratio",
+        "HHI",
+        "Score",
+        "Confidence",
+        "Universe",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            row.rank.to_string(),
+            sanitize_csv_field(&row.politician_name),
+            row.pre_move_count.to_string(),
+            format!("{:.1}", row.volume_ratio),
+            format!("{:.3}", row.hhi_score),
+            format!("{:.3}", row.composite_score),
+            format!("{:.2}", row.confidence),
+            universe.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints anomaly rows as XML to stdout.
+pub fn print_anomaly_xml(rows: &[crate::commands::anomalies::AnomalyRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+/// Prints pre-move signal rows as ASCII table to stdout.
+pub fn print_pre_move_table(rows: &[crate::commands::anomalies::PreMoveRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct PreMoveTableRow {
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Ticker")]
+        ticker: String,
+        #[tabled(rename = "Date")]
+        tx_date: String,
+        #[tabled(rename = "Type")]
+        tx_type: String,
+        #[tabled(rename = "Price")]
+        trade_price: String,
+        #[tabled(rename = "30d Price")]
+        price_30d_later: String,
+        #[tabled(rename = "Change%")]
+        price_change_pct: String,
+        #[tabled(rename = "Sold")]
+        realized_fraction: String,
+        #[tabled(rename = "Realized%")]
+        realized_return_pct: String,
+    }
+
+    let table_rows: Vec<PreMoveTableRow> = rows
+        .iter()
+        .map(|r| PreMoveTableRow {
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            tx_date: r.tx_date.clone(),
+            tx_type: r.tx_type.clone(),
+            trade_price: format!("${:.2}", r.trade_price),
+            price_30d_later: format!("${:.2}", r.price_30d_later),
+            price_change_pct: if r.price_change_pct >= 0.0 {
+                format!("+{:.1}%", r.price_change_pct)
+            } else {
+                format!("{:.1}%", r.price_change_pct)
+            },
+            realized_fraction: r
+                .realized_fraction
+                .map(|f| format!("{:.0}%", f * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            realized_return_pct: r
+                .realized_return_pct
+                .map(|p| format!("{:+.1}%", p))
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect();
+
+    let mut table = Table::new(table_rows);
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints pre-move signal rows as Markdown table to stdout.
+pub fn print_pre_move_markdown(rows: &[crate::commands::anomalies::PreMoveRow]) {
+    use tabled::Tabled;
+
+    #[derive(Tabled)]
+    struct PreMoveTableRow {
+        #[tabled(rename = "Politician")]
+        politician_name: String,
+        #[tabled(rename = "Ticker")]
+        ticker: String,
+        #[tabled(rename = "Date")]
+        tx_date: String,
+        #[tabled(rename = "Type")]
+        tx_type: String,
+        #[tabled(rename = "Price")]
+        trade_price: String,
+        #[tabled(rename = "30d Price")]
+        price_30d_later: String,
+        #[tabled(rename = "Change%")]
+        price_change_pct: String,
+        #[tabled(rename = "Sold")]
+        realized_fraction: String,
+        #[tabled(rename = "Realized%")]
+        realized_return_pct: String,
+    }
+
+    let table_rows: Vec<PreMoveTableRow> = rows
+        .iter()
+        .map(|r| PreMoveTableRow {
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            tx_date: r.tx_date.clone(),
+            tx_type: r.tx_type.clone(),
+            trade_price: format!("${:.2}", r.trade_price),
+            price_30d_later: format!("${:.2}", r.price_30d_later),
+            price_change_pct: if r.price_change_pct >= 0.0 {
+                format!("+{:.1}%", r.price_change_pct)
+            } else {
+                format!("{:.1}%", r.price_change_pct)
+            },
+            realized_fraction: r
+                .realized_fraction
+                .map(|f| format!("{:.0}%", f * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            realized_return_pct: r
+                .realized_return_pct
+                .map(|p| format!("{:+.1}%", p))
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect();
+
+    println!("{}", markdown_table(table_rows));
+}
+
+/// Prints pre-move signal rows as CSV to stdout.
+pub fn print_pre_move_csv(rows: &[crate::commands::anomalies::PreMoveRow]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "Politician", "Ticker", "Date", "Type", "Price", "30d Price", "Change%", "Sold",
+        "Realized%",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            sanitize_csv_field(&row.politician_name),
+            row.ticker.clone(),
+            row.tx_date.clone(),
+            row.tx_type.clone(),
+            format!("{:.2}", row.trade_price),
+            format!("{:.2}", row.price_30d_later),
+            format!("{:.2}", row.price_change_pct),
+            row.realized_fraction.map(|f| format!("{:.4}", f)).unwrap_or_default(),
+            row.realized_return_pct.map(|p| format!("{:.2}", p)).unwrap_or_default(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints pre-move signal rows as XML to stdout.
+pub fn print_pre_move_xml(rows: &[crate::commands::anomalies::PreMoveRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct SellSignalTableRow {
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Date")]
+    tx_date: String,
+    #[tabled(rename = "Price")]
+    sell_price: String,
+    #[tabled(rename = "Peak")]
+    trailing_peak: String,
+    #[tabled(rename = "Below Peak")]
+    below_peak_pct: String,
+    #[tabled(rename = "Drawdown After")]
+    drawdown_after_pct: String,
+    #[tabled(rename = "Prescient")]
+    prescient: String,
+}
+
+fn build_sell_signal_rows(
+    rows: &[crate::commands::anomalies::SellSignalRow],
+) -> Vec<SellSignalTableRow> {
+    rows.iter()
+        .map(|r| SellSignalTableRow {
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            tx_date: r.tx_date.clone(),
+            sell_price: format!("${:.2}", r.sell_price),
+            trailing_peak: format!("${:.2} ({})", r.trailing_peak, r.peak_date),
+            below_peak_pct: format!("{:.1}%", r.below_peak_pct),
+            drawdown_after_pct: format!("{:.1}%", r.drawdown_after_pct),
+            prescient: if r.prescient { "yes" } else { "" }.to_string(),
+        })
+        .collect()
+}
+
+/// Prints sell-discipline rows as an ASCII table to stdout.
+pub fn print_sell_signal_table(rows: &[crate::commands::anomalies::SellSignalRow]) {
+    let mut table = Table::new(build_sell_signal_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints sell-discipline rows as a Markdown table to stdout.
+pub fn print_sell_signal_markdown(rows: &[crate::commands::anomalies::SellSignalRow]) {
+    println!("{}", markdown_table(build_sell_signal_rows(rows)));
+}
+
+/// Prints sell-discipline rows as CSV to stdout.
+pub fn print_sell_signal_csv(rows: &[crate::commands::anomalies::SellSignalRow]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "politician",
+        "ticker",
+        "tx_date",
+        "sell_price",
+        "trailing_peak",
+        "peak_date",
+        "below_peak_pct",
+        "forward_low",
+        "drawdown_after_pct",
+        "prescient",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            sanitize_csv_field(&row.politician_name),
+            sanitize_csv_field(&row.ticker),
+            row.tx_date.clone(),
+            format!("{:.2}", row.sell_price),
+            format!("{:.2}", row.trailing_peak),
+            row.peak_date.clone(),
+            format!("{:.2}", row.below_peak_pct),
+            format!("{:.2}", row.forward_low),
+            format!("{:.2}", row.drawdown_after_pct),
+            row.prescient.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints sell-discipline rows as XML to stdout.
+pub fn print_sell_signal_xml(rows: &[crate::commands::anomalies::SellSignalRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct CrowdedTradeTableRow {
+    #[tabled(rename = "Issuer")]
+    issuer_name: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Window")]
+    window: String,
+    #[tabled(rename = "Politicians")]
+    politician_count: usize,
+    #[tabled(rename = "D/R/Other")]
+    parties: String,
+    #[tabled(rename = "Trades")]
+    trade_count: usize,
+    #[tabled(rename = "Value")]
+    total_value: String,
+    #[tabled(rename = "Names")]
+    politicians: String,
+}
+
+fn build_crowded_trade_rows(
+    rows: &[crate::commands::anomalies::CrowdedTradeRow],
+) -> Vec<CrowdedTradeTableRow> {
+    rows.iter()
+        .map(|r| CrowdedTradeTableRow {
+            issuer_name: r.issuer_name.clone(),
+            ticker: r.ticker.clone(),
+            window: format!("{} to {}", r.window_start, r.window_end),
+            politician_count: r.politician_count,
+            parties: format!("{}/{}/{}", r.democrats, r.republicans, r.other_party),
+            trade_count: r.trade_count,
+            total_value: format_value(r.total_value),
+            politicians: r.politicians.join(", "),
+        })
+        .collect()
+}
+
+/// Prints crowded trade windows as an ASCII table to stdout.
+pub fn print_crowded_trade_table(rows: &[crate::commands::anomalies::CrowdedTradeRow]) {
+    let mut table = Table::new(build_crowded_trade_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints crowded trade windows as a Markdown table to stdout.
+pub fn print_crowded_trade_markdown(rows: &[crate::commands::anomalies::CrowdedTradeRow]) {
+    println!("{}", markdown_table(build_crowded_trade_rows(rows)));
+}
+
+/// Prints crowded trade windows as CSV to stdout.
+pub fn print_crowded_trade_csv(rows: &[crate::commands::anomalies::CrowdedTradeRow]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "issuer_name",
+        "ticker",
+        "direction",
+        "window_start",
+        "window_end",
+        "politician_count",
+        "politicians",
+        "trade_count",
+        "total_value",
+        "democrats",
+        "republicans",
+        "other_party",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            sanitize_csv_field(&row.issuer_name),
+            sanitize_csv_field(&row.ticker),
+            row.direction.clone(),
+            row.window_start.clone(),
+            row.window_end.clone(),
+            row.politician_count.to_string(),
+            sanitize_csv_field(&row.politicians.join("; ")),
+            row.trade_count.to_string(),
+            row.total_value.to_string(),
+            row.democrats.to_string(),
+            row.republicans.to_string(),
+            row.other_party.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints crowded trade windows as XML to stdout.
+pub fn print_crowded_trade_xml(rows: &[crate::commands::anomalies::CrowdedTradeRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct NovelTradeTableRow {
+    #[tabled(rename = "Date")]
+    tx_date: String,
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Issuer")]
+    issuer_name: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Sector")]
+    sector: String,
+    #[tabled(rename = "Mkt Cap")]
+    mcap: String,
+    #[tabled(rename = "Type")]
+    tx_type: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Kind")]
+    kind: String,
+    #[tabled(rename = "Confidence")]
+    confidence: String,
+    #[tabled(rename = "Local/Scraped")]
+    trade_counts: String,
+}
+
+fn build_novel_trade_rows(
+    rows: &[crate::commands::anomalies::NovelTradeRow],
+) -> Vec<NovelTradeTableRow> {
+    rows.iter()
+        .map(|r| NovelTradeTableRow {
+            tx_date: r.tx_date.clone(),
+            politician_name: r.politician_name.clone(),
+            issuer_name: r.issuer_name.clone(),
+            ticker: r.ticker.clone(),
+            sector: r.sector.clone().unwrap_or_else(|| "-".to_string()),
+            mcap: r.mcap.map(format_large_number).unwrap_or_else(|| "-".to_string()),
+            tx_type: r.tx_type.clone(),
+            value: format_value(r.value),
+            kind: match r.previous_trade_date {
+                Some(ref previous) => format!("returning (last {})", previous),
+                None => r.kind.clone(),
+            },
+            confidence: r.confidence.clone(),
+            trade_counts: format!(
+                "{}/{}",
+                r.local_trade_count,
+                r.scraped_trade_count
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| "-".to_string())
+            ),
+        })
+        .collect()
+}
+
+/// Prints novel issuer trades as an ASCII table to stdout.
+pub fn print_novel_trade_table(rows: &[crate::commands::anomalies::NovelTradeRow]) {
+    let mut table = Table::new(build_novel_trade_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints novel issuer trades as a Markdown table to stdout.
+pub fn print_novel_trade_markdown(rows: &[crate::commands::anomalies::NovelTradeRow]) {
+    println!("{}", markdown_table(build_novel_trade_rows(rows)));
+}
+
+/// Prints novel issuer trades as CSV to stdout.
+pub fn print_novel_trade_csv(rows: &[crate::commands::anomalies::NovelTradeRow]) -> Result<()> {
+    let opt = |v: &Option<String>| v.as_deref().map(sanitize_csv_field).unwrap_or_default();
+    let num = |v: Option<i64>| v.map(|n| n.to_string()).unwrap_or_default();
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "politician_name",
+        "issuer_name",
+        "ticker",
+        "sector",
+        "mcap",
+        "tx_type",
+        "tx_date",
+        "value",
+        "kind",
+        "previous_trade_date",
+        "confidence",
+        "local_trade_count",
+        "scraped_trade_count",
+        "scraped_date_last_traded",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            sanitize_csv_field(&row.politician_name),
+            sanitize_csv_field(&row.issuer_name),
+            sanitize_csv_field(&row.ticker),
+            opt(&row.sector),
+            num(row.mcap),
+            row.tx_type.clone(),
+            row.tx_date.clone(),
+            row.value.to_string(),
+            row.kind.clone(),
+            opt(&row.previous_trade_date),
+            row.confidence.clone(),
+            row.local_trade_count.to_string(),
+            num(row.scraped_trade_count),
+            opt(&row.scraped_date_last_traded),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints novel issuer trades as XML to stdout.
+pub fn print_novel_trade_xml(rows: &[crate::commands::anomalies::NovelTradeRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct EventProximityTableRow {
+    #[tabled(rename = "Event Date")]
+    event_date: String,
+    #[tabled(rename = "Event")]
+    event_title: String,
+    #[tabled(rename = "Offset")]
+    day_offset: String,
+    #[tabled(rename = "Trade Date")]
+    tx_date: String,
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Type")]
+    tx_type: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Match")]
+    matched_on: String,
+}
+
+fn build_event_proximity_rows(rows: &[EventProximityRow]) -> Vec<EventProximityTableRow> {
+    rows.iter()
+        .map(|r| EventProximityTableRow {
+            event_date: r.event_date.clone(),
+            event_title: r.event_title.clone(),
+            day_offset: format!("{:+}d", r.day_offset),
+            tx_date: r.tx_date.clone(),
+            politician_name: r.politician_name.clone(),
+            ticker: r.ticker.clone(),
+            tx_type: r.tx_type.clone(),
+            value: format_value(r.value),
+            matched_on: r.matched_on.clone(),
+        })
+        .collect()
+}
+
+/// Prints trades near calendar events as an ASCII table to stdout.
+pub fn print_event_proximity_table(rows: &[EventProximityRow]) {
+    let mut table = Table::new(build_event_proximity_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints trades near calendar events as a Markdown table to stdout.
+pub fn print_event_proximity_markdown(rows: &[EventProximityRow]) {
+    println!("{}", markdown_table(build_event_proximity_rows(rows)));
+}
+
+/// Prints trades near calendar events as CSV to stdout.
+pub fn print_event_proximity_csv(rows: &[EventProximityRow]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "Event Date",
+        "Event",
+        "Offset",
+        "Trade Date",
+        "Politician",
+        "Ticker",
+        "Type",
+        "Value",
+        "Match",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            row.event_date.clone(),
+            sanitize_csv_field(&row.event_title),
+            row.day_offset.to_string(),
+            row.tx_date.clone(),
+            sanitize_csv_field(&row.politician_name),
+            row.ticker.clone(),
+            row.tx_type.clone(),
+            row.value.to_string(),
+            row.matched_on.clone(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints trades near calendar events as XML to stdout.
+pub fn print_event_proximity_xml(rows: &[EventProximityRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct HearingProximityTableRow {
+    #[tabled(rename = "Hearing Date")]
+    hearing_date: String,
+    #[tabled(rename = "Committee")]
+    committee: String,
+    #[tabled(rename = "Hearing")]
+    hearing_title: String,
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Trade Date")]
+    tx_date: String,
+    #[tabled(rename = "Offset")]
+    day_offset: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Sector")]
+    gics_sector: String,
+    #[tabled(rename = "Type")]
+    tx_type: String,
+    #[tabled(rename = "Value")]
+    value: String,
+}
+
+fn build_hearing_proximity_rows(rows: &[HearingProximityRow]) -> Vec<HearingProximityTableRow> {
+    rows.iter()
+        .map(|r| HearingProximityTableRow {
+            hearing_date: r.hearing_date.clone(),
+            committee: r.committee.clone(),
+            hearing_title: r.hearing_title.clone(),
+            politician_name: r.politician_name.clone(),
+            tx_date: r.tx_date.clone(),
+            day_offset: format!("{:+}d", r.day_offset),
+            ticker: r.ticker.clone(),
+            gics_sector: r.gics_sector.clone(),
+            tx_type: r.tx_type.clone(),
+            value: format_value(r.value),
+        })
+        .collect()
+}
+
+/// Prints hearing proximity rows as an ASCII table to stdout.
+pub fn print_hearing_proximity_table(rows: &[HearingProximityRow]) {
+    let mut table = Table::new(build_hearing_proximity_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints hearing proximity rows as a Markdown table to stdout.
+pub fn print_hearing_proximity_markdown(rows: &[HearingProximityRow]) {
+    println!("{}", markdown_table(build_hearing_proximity_rows(rows)));
+}
+
+/// Prints hearing proximity rows as CSV to stdout.
+pub fn print_hearing_proximity_csv(rows: &[HearingProximityRow]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "Hearing Date",
+        "Committee",
+        "Hearing",
+        "Politician",
+        "Trade Date",
+        "Offset",
+        "Ticker",
+        "Sector",
+        "Type",
+        "Value",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            row.hearing_date.clone(),
+            row.committee.clone(),
+            sanitize_csv_field(&row.hearing_title),
+            sanitize_csv_field(&row.politician_name),
+            row.tx_date.clone(),
+            row.day_offset.to_string(),
+            row.ticker.clone(),
+            row.gics_sector.clone(),
+            row.tx_type.clone(),
+            row.value.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints hearing proximity rows as XML to stdout.
+pub fn print_hearing_proximity_xml(rows: &[HearingProximityRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct SpouseConflictTableRow {
+    #[tabled(rename = "Rank")]
+    rank: usize,
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Committees")]
+    committees: String,
+    #[tabled(rename = "Self In-Jur")]
+    self_trades: String,
+    #[tabled(rename = "Self Volume")]
+    self_volume: String,
+    #[tabled(rename = "Spouse In-Jur")]
+    spouse_trades: String,
+    #[tabled(rename = "Spouse Volume")]
+    spouse_volume: String,
+    #[tabled(rename = "Spouse:Self")]
+    ratio: String,
+    #[tabled(rename = "Spouse Only")]
+    exclusive: String,
+}
+
+fn build_spouse_conflict_rows(
+    rows: &[crate::commands::conflicts::SpouseConflictRow],
+) -> Vec<SpouseConflictTableRow> {
+    rows.iter()
+        .map(|r| SpouseConflictTableRow {
+            rank: r.rank,
+            politician_name: r.politician_name.clone(),
+            committees: r.committees.clone(),
+            self_trades: format!("{}/{}", r.self_in_jurisdiction_trades, r.self_scored_trades),
+            self_volume: format_value(r.self_in_jurisdiction_volume),
+            spouse_trades: format!(
+                "{}/{}",
+                r.spouse_in_jurisdiction_trades, r.spouse_scored_trades
+            ),
+            spouse_volume: format_value(r.spouse_in_jurisdiction_volume),
+            ratio: r
+                .spouse_to_self_ratio
+                .map(|v| format!("{:.2}", v))
+                .unwrap_or_else(|| "-".to_string()),
+            exclusive: if r.spouse_exclusive { "yes" } else { "" }.to_string(),
+        })
+        .collect()
+}
+
+/// Prints own vs spouse committee trading rows as an ASCII table to stdout.
+pub fn print_spouse_conflict_table(rows: &[crate::commands::conflicts::SpouseConflictRow]) {
+    let mut table = Table::new(build_spouse_conflict_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints own vs spouse committee trading rows as a Markdown table to stdout.
+pub fn print_spouse_conflict_markdown(rows: &[crate::commands::conflicts::SpouseConflictRow]) {
+    println!("{}", markdown_table(build_spouse_conflict_rows(rows)));
+}
+
+/// Prints own vs spouse committee trading rows as CSV to stdout.
+pub fn print_spouse_conflict_csv(
+    rows: &[crate::commands::conflicts::SpouseConflictRow],
+) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "rank",
+        "politician",
+        "committees",
+        "self_scored_trades",
+        "self_in_jurisdiction_trades",
+        "self_in_jurisdiction_volume",
+        "spouse_scored_trades",
+        "spouse_in_jurisdiction_trades",
+        "spouse_in_jurisdiction_volume",
+        "spouse_committee_pct",
+        "spouse_to_self_ratio",
+        "spouse_exclusive",
+    ])?;
+    for row in rows {
+        wtr.write_record(&[
+            row.rank.to_string(),
+            sanitize_csv_field(&row.politician_name),
+            sanitize_csv_field(&row.committees),
+            row.self_scored_trades.to_string(),
+            row.self_in_jurisdiction_trades.to_string(),
+            row.self_in_jurisdiction_volume.to_string(),
+            row.spouse_scored_trades.to_string(),
+            row.spouse_in_jurisdiction_trades.to_string(),
+            row.spouse_in_jurisdiction_volume.to_string(),
+            format!("{:.1}", row.spouse_committee_pct),
+            row.spouse_to_self_ratio
+                .map(|v| format!("{:.4}", v))
+                .unwrap_or_default(),
+            row.spouse_exclusive.to_string(),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints own vs spouse committee trading rows as XML to stdout.
+pub fn print_spouse_conflict_xml(rows: &[crate::commands::conflicts::SpouseConflictRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+#[derive(Tabled)]
+struct AffinityTableRow {
+    #[tabled(rename = "Rank")]
+    rank: usize,
+    #[tabled(rename = "Politician")]
+    politician_name: String,
+    #[tabled(rename = "Score")]
+    score: String,
+    #[tabled(rename = "Matched Donations")]
+    donations: String,
+    #[tabled(rename = "Donation Share")]
+    donation_share: String,
+    #[tabled(rename = "Matched Volume")]
+    volume: String,
+    #[tabled(rename = "Trade Share")]
+    trade_share: String,
+    #[tabled(rename = "Tickers")]
+    tickers: usize,
+    #[tabled(rename = "Confidence")]
+    confidence: String,
+    #[tabled(rename = "Proximity")]
+    proximity: String,
+}
+
+fn build_affinity_rows(rows: &[crate::commands::conflicts::AffinityRow]) -> Vec<AffinityTableRow> {
+    rows.iter()
+        .map(|r| AffinityTableRow {
+            rank: r.rank,
+            politician_name: r.politician_name.clone(),
+            score: format!("{:.1}", r.score),
+            donations: format!("{} (${:.0})", r.matched_donations, r.matched_donation_total),
+            donation_share: format!("{:.1}%", r.donation_share_pct),
+            volume: format_value(r.matched_trade_volume),
+            trade_share: format!("{:.1}%", r.trade_share_pct),
+            tickers: r.matched_tickers,
+            confidence: format!("{:.2}", r.avg_confidence),
+            proximity: format!("{:.2}", r.proximity),
+        })
+        .collect()
+}
+
+/// Prints donation-trade affinity rows as an ASCII table to stdout.
+pub fn print_affinity_table(rows: &[crate::commands::conflicts::AffinityRow]) {
+    let mut table = Table::new(build_affinity_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints donation-trade affinity rows as a Markdown table to stdout.
+pub fn print_affinity_markdown(rows: &[crate::commands::conflicts::AffinityRow]) {
+    println!("{}", markdown_table(build_affinity_rows(rows)));
+}
+
+/// Prints donation-trade affinity rows as CSV to stdout.
+pub fn print_affinity_csv(rows: &[crate::commands::conflicts::AffinityRow]) -> Result<()> {
+    let mut wtr = csv::Writer::from_writer(std::io::stdout());
+    wtr.write_record([
+        "rank",
+        "politician",
+        "score",
+        "matched_donations",
+        "matched_tickers",
+        "matched_donation_total",
+        "donation_share_pct",
+        "matched_trade_volume",
+        "trade_share_pct",
+        "avg_confidence",
+        "proximity",
+    ])?;
+    for row in rows {
+        wtr.write_record(&[
+            row.rank.to_string(),
+            sanitize_csv_field(&row.politician_name),
+            format!("{:.4}", row.score),
+            row.matched_donations.to_string(),
+            row.matched_tickers.to_string(),
+            format!("{:.2}", row.matched_donation_total),
+            format!("{:.2}", row.donation_share_pct),
+            row.matched_trade_volume.to_string(),
+            format!("{:.2}", row.trade_share_pct),
+            format!("{:.4}", row.avg_confidence),
+            format!("{:.4}", row.proximity),
+        ])?;
+    }
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Prints donation-trade affinity rows as XML to stdout.
+pub fn print_affinity_xml(rows: &[crate::commands::conflicts::AffinityRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- Trade revisions (upstream edits between syncs) --
+
+/// Renders one tracked column as `old -> new`, or just the value if unchanged.
+fn revision_change(old: String, new: String) -> String {
+    if old == new {
+        new
+    } else {
+        format!("{} -> {}", old, new)
+    }
+}
+
+fn format_size_range(low: Option<i64>, high: Option<i64>) -> String {
+    match (low, high) {
+        (None, None) => "-".to_string(),
+        (low, high) => format!(
+            "{}-{}",
+            low.map(|v| v.to_string()).unwrap_or_default(),
+            high.map(|v| v.to_string()).unwrap_or_default()
+        ),
+    }
+}
+
+fn format_optional_price(price: Option<f64>) -> String {
+    price
+        .map(|p| format!("{:.2}", p))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[derive(Tabled)]
+struct TradeRevisionTableRow {
+    #[tabled(rename = "Observed")]
+    observed_at: String,
+    #[tabled(rename = "Value")]
+    value: String,
+    #[tabled(rename = "Trade Date")]
+    tx_date: String,
+    #[tabled(rename = "Type")]
+    tx_type: String,
+    #[tabled(rename = "Size Range")]
+    size_range: String,
+    #[tabled(rename = "Price")]
+    price: String,
+}
+
+fn build_trade_revision_rows(rows: &[TradeRevisionRow]) -> Vec<TradeRevisionTableRow> {
+    rows.iter()
+        .map(|r| TradeRevisionTableRow {
+            observed_at: r.observed_at.clone(),
+            value: revision_change(format_value(r.old_value), format_value(r.new_value)),
+            tx_date: revision_change(r.old_tx_date.clone(), r.new_tx_date.clone()),
+            tx_type: revision_change(r.old_tx_type.clone(), r.new_tx_type.clone()),
+            size_range: revision_change(
+                format_size_range(r.old_size_range_low, r.old_size_range_high),
+                format_size_range(r.new_size_range_low, r.new_size_range_high),
+            ),
+            price: revision_change(
+                format_optional_price(r.old_price),
+                format_optional_price(r.new_price),
+            ),
+        })
+        .collect()
+}
+
+/// Prints recorded trade revisions as an ASCII table to stdout.
+pub fn print_trade_revisions_table(rows: &[TradeRevisionRow]) {
+    let mut table = Table::new(build_trade_revision_rows(rows));
+    table.with(Style::modern());
+    println!("{}", table);
+}
+
+/// Prints recorded trade revisions as a Markdown table to stdout.
+pub fn print_trade_revisions_markdown(rows: &[TradeRevisionRow]) {
+    println!("{}", markdown_table(build_trade_revision_rows(rows)));
+}
+
+/// Prints recorded trade revisions as CSV to stdout, one old/new column pair per field.
+pub fn print_trade_revisions_csv(rows: &[TradeRevisionRow]) -> Result<()> {
+    let opt = |v: Option<i64>| v.map(|v| v.to_string()).unwrap_or_default();
+    let opt_f = |v: Option<f64>| v.map(|v| v.to_string()).unwrap_or_default();
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record([
+        "revision_id",
+        "tx_id",
+        "observed_at",
+        "old_value",
+        "new_value",
+        "old_tx_date",
+        "new_tx_date",
+        "old_tx_type",
+        "new_tx_type",
+        "old_size_range_low",
+        "new_size_range_low",
+        "old_size_range_high",
+        "new_size_range_high",
+        "old_price",
+        "new_price",
+    ])?;
+    for row in rows {
+        writer.write_record(&[
+            row.revision_id.to_string(),
+            row.tx_id.to_string(),
+            row.observed_at.clone(),
+            row.old_value.to_string(),
+            row.new_value.to_string(),
+            row.old_tx_date.clone(),
+            row.new_tx_date.clone(),
+            row.old_tx_type.clone(),
+            row.new_tx_type.clone(),
+            opt(row.old_size_range_low),
+            opt(row.new_size_range_low),
+            opt(row.old_size_range_high),
+            opt(row.new_size_range_high),
+            opt_f(row.old_price),
+            opt_f(row.new_price),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Prints recorded trade revisions as XML to stdout.
+pub fn print_trade_revisions_xml(rows: &[TradeRevisionRow]) {
+    println!("{}", xml_output::to_xml(rows));
+}
+
+// -- Politician report (Markdown dossier) --
+
+fn format_optional_pct(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{:+.1}%", v))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[derive(Tabled)]
+struct ReportHoldingRow {
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Shares")]
+    shares: String,
+    #[tabled(rename = "Avg Cost")]
+    cost_basis: String,
+    #[tabled(rename = "Value")]
+    current_value: String,
+    #[tabled(rename = "Unrealized")]
+    unrealized: String,
+}
+
+#[derive(Tabled)]
+struct ReportClosedTradeRow {
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Bought")]
+    buy_date: String,
+    #[tabled(rename = "Sold")]
+    sell_date: String,
+    #[tabled(rename = "Return")]
+    return_pct: String,
+    #[tabled(rename = "Days Held")]
+    holding_days: String,
+}
+
+#[derive(Tabled)]
+struct ReportSectorRow {
+    #[tabled(rename = "Sector")]
+    sector: String,
+    #[tabled(rename = "Amount")]
+    amount: String,
+    #[tabled(rename = "Employers")]
+    employers: i64,
+}
+
+fn closed_trade_section(
+    out: &mut String,
+    title: &str,
+    trades: &[crate::commands::report::ReportClosedTrade],
+) {
+    if trades.is_empty() {
+        return;
+    }
+    let rows: Vec<ReportClosedTradeRow> = trades
+        .iter()
+        .map(|t| ReportClosedTradeRow {
+            ticker: t.ticker.clone(),
+            buy_date: t.buy_date.clone(),
+            sell_date: t.sell_date.clone(),
+            return_pct: format!("{:+.1}%", t.return_pct),
+            holding_days: t
+                .holding_days
+                .map(|d| d.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        })
+        .collect();
+    out.push_str(&format!("\n## {}\n\n{}\n", title, markdown_table(rows)));
+}
+
+/// Renders a politician report as a Markdown document. Sections with no
+/// data are omitted entirely.
+pub fn render_politician_report_markdown(report: &crate::commands::report::PoliticianReport) -> String {
+    let mut out = format!("# {}\n\n", escape_markdown(&report.name));
+    out.push_str(&format!(
+        "- **Party:** {}\n- **State:** {}\n- **Chamber:** {}\n- **Trades:** {} across {} issuers ({} volume)\n",
+        escape_markdown(&report.party),
+        escape_markdown(&report.state),
+        escape_markdown(&report.chamber),
+        report.trades,
+        report.issuers,
+        format_value(report.volume),
+    ));
+    if let Some(ref last) = report.last_traded {
+        out.push_str(&format!("- **Last traded:** {}\n", last));
+    }
+
+    if !report.committees.is_empty() {
+        out.push_str("\n## Committees\n\n");
+        for committee in &report.committees {
+            out.push_str(&format!("- {}\n", escape_markdown(committee)));
+        }
+    }
+
+    if !report.top_holdings.is_empty() {
+        let rows: Vec<ReportHoldingRow> = report
+            .top_holdings
+            .iter()
+            .map(|h| ReportHoldingRow {
+                ticker: h.ticker.clone(),
+                shares: format_shares(h.shares_held),
+                cost_basis: format_currency(h.cost_basis),
+                current_value: h
+                    .current_value
+                    .map(format_currency_with_commas)
+                    .unwrap_or_else(|| "-".to_string()),
+                unrealized: format_optional_pct(h.unrealized_pnl_pct),
+            })
+            .collect();
+        out.push_str(&format!("\n## Top Holdings\n\n{}\n", markdown_table(rows)));
+    }
+
+    closed_trade_section(&mut out, "Best Trades", &report.best_trades);
+    closed_trade_section(&mut out, "Worst Trades", &report.worst_trades);
+
+    if let Some(ref d) = report.disclosure {
+        out.push_str(&format!(
+            "\n## Disclosure Timeliness\n\n- **Average gap:** {:.1} days over {} trades\n- **Longest gap:** {} days\n- **Filed after {} days:** {} ({:.1}%)\n",
+            d.avg_gap_days,
+            d.trade_count,
+            d.max_gap_days,
+            capitoltraders_lib::STOCK_ACT_DISCLOSURE_DAYS,
+            d.late_count,
+            d.late_count as f64 / d.trade_count as f64 * 100.0,
+        ));
+    }
+
+    if let Some(ref donations) = report.donations {
+        out.push_str(&format!(
+            "\n## Donations\n\n- **Total:** {} from {} contributions\n",
+            format_currency_with_commas(donations.total_amount),
+            donations.donation_count,
+        ));
+        if !donations.top_sectors.is_empty() {
+            let rows: Vec<ReportSectorRow> = donations
+                .top_sectors
+                .iter()
+                .map(|s| ReportSectorRow {
+                    sector: s.sector.clone(),
+                    amount: format_currency_with_commas(s.total_amount),
+                    employers: s.employer_count,
+                })
+                .collect();
+            out.push_str(&format!(
+                "\nTop sectors by matched donor employer:\n\n{}\n",
+                markdown_table(rows)
+            ));
+        }
+    }
+
+    if let Some(ref score) = report.committee_score {
+        out.push_str(&format!(
+            "\n## Committee Trading Score\n\n{} of {} sector-tagged closed trades ({:.1}%) were in sectors under the member's committees.\n\n_Based on current committee assignments._\n",
+            score.committee_related_trades,
+            score.total_scored_trades,
+            score.committee_trading_pct,
+        ));
+    }
+
+    out
+}
+
+/// Prints a politician report as Markdown to stdout.
+pub fn print_politician_report_markdown(report: &crate::commands::report::PoliticianReport) {
+    print!("{}", render_politician_report_markdown(report));
+}
+
+// -- Snapshots and diff --
+
+#[derive(Tabled)]
+struct SnapshotListRow {
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Created")]
+    created_at: String,
+    #[tabled(rename = "Schema")]
+    schema_version: i32,
+    #[tabled(rename = "Sections")]
+    sections: i64,
+    #[tabled(rename = "Bytes")]
+    bytes: i64,
+}
+
+fn build_snapshot_list_rows(snapshots: &[SnapshotInfo]) -> Vec<SnapshotListRow> {
+    snapshots
+        .iter()
+        .map(|s| SnapshotListRow {
+            name: s.name.clone(),
+            created_at: s.created_at.clone(),
+            schema_version: s.schema_version,
+            sections: s.sections,
+            bytes: s.bytes,
+        })
+        .collect()
+}
+
+/// Prints stored snapshots as an ASCII table to stdout.
+pub fn print_snapshot_list_table(snapshots: &[SnapshotInfo]) {
+    println!("{}", Table::new(build_snapshot_list_rows(snapshots)));
+}
+
+/// Prints stored snapshots as a GitHub-flavored Markdown table to stdout.
+pub fn print_snapshot_list_markdown(snapshots: &[SnapshotInfo]) {
+    println!("{}", markdown_table(build_snapshot_list_rows(snapshots)));
+}
+
+#[derive(Tabled)]
+struct PositionChangeRow {
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Ticker")]
+    ticker: String,
+    #[tabled(rename = "Change")]
+    change: String,
+    #[tabled(rename = "Shares")]
+    shares: String,
+}
+
+#[derive(Tabled)]
+struct RankChangeRow {
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Was")]
+    old_rank: usize,
+    #[tabled(rename = "Now")]
+    new_rank: usize,
+    #[tabled(rename = "Moved")]
+    moved: String,
+    #[tabled(rename = "Avg Return")]
+    avg_return: String,
+}
+
+#[derive(Tabled)]
+struct NewAnomalyRow {
+    #[tabled(rename = "Politician")]
+    politician: String,
+    #[tabled(rename = "Signal")]
+    signal: String,
+    #[tabled(rename = "Detail")]
+    detail: String,
+    #[tabled(rename = "Composite")]
+    composite: String,
+}
+
+/// Appends one diff section: a table of changes, "No changes.", or why the
+/// snapshot could not answer it.
+fn diff_section<T>(
+    out: &mut String,
+    title: &str,
+    section: &SectionDiff<T>,
+    table: impl Fn(&[T]) -> String,
+) {
+    match section {
+        SectionDiff::Compared { changes } if changes.is_empty() => {
+            out.push_str(&format!("\n## {}\n\nNo changes.\n", title));
+        }
+        SectionDiff::Compared { changes } => {
+            out.push_str(&format!(
+                "\n## {} ({})\n\n{}\n",
+                title,
+                changes.len(),
+                table(changes)
+            ));
+        }
+        SectionDiff::Unavailable { reason } => {
+            out.push_str(&format!(
+                "\n## {}\n\n_Unavailable: {}._\n",
+                title,
+                escape_markdown(reason)
+            ));
+        }
+    }
+}
+
+/// Renders a snapshot diff as Markdown, one section per summary.
+pub fn render_snapshot_diff_markdown(diff: &SnapshotDiff) -> String {
+    let mut out = format!(
+        "# Changes since snapshot '{}'\n\nTaken {} (schema version {}).\n",
+        escape_markdown(&diff.snapshot),
+        diff.created_at,
+        diff.snapshot_schema_version
+    );
+
+    diff_section(&mut out, "New Trades", &diff.new_trades, |trades| {
+        markdown_table(build_db_trade_rows(trades))
+    });
+    diff_section(&mut out, "Positions", &diff.positions, |changes| {
+        markdown_table(changes.iter().map(|p: &PositionChange| PositionChangeRow {
+            politician: p.politician_name.clone(),
+            ticker: p.ticker.clone(),
+            change: p.change.as_str().to_string(),
+            shares: p.shares_held.map(format_shares).unwrap_or_else(|| "-".to_string()),
+        }))
+    });
+    diff_section(&mut out, "Rank Moves", &diff.ranks, |changes| {
+        markdown_table(changes.iter().map(|r: &RankChange| RankChangeRow {
+            politician: r.politician_name.clone(),
+            old_rank: r.old_rank,
+            new_rank: r.new_rank,
+            moved: format!("{:+}", r.moved),
+            avg_return: format!("{:+.1}%", r.avg_return),
+        }))
+    });
+    diff_section(&mut out, "New Anomalies", &diff.anomalies, |changes| {
+        markdown_table(changes.iter().map(|a: &NewAnomaly| NewAnomalyRow {
+            politician: a.politician_name.clone(),
+            signal: a.signal.clone(),
+            detail: a.detail.clone(),
+            composite: format!("{:.2}", a.composite),
+        }))
+    });
+    out
+}
+
+/// Prints a snapshot diff as Markdown to stdout.
+pub fn print_snapshot_diff_markdown(diff: &SnapshotDiff) {
+    print!("{}", render_snapshot_diff_markdown(diff));
 }
 
 #[cfg(test)]
@@ -233,9 +233,7 @@ fn test_sanitize_csv_field_empty() {
 fn test_markdown_trades_structure() {
     let trades = load_trades_fixture();
     let rows = build_trade_rows(&trades);
-    let mut table = Table::new(&rows);
-    table.with(Style::markdown());
-    let md = table.to_string();
+    let md = markdown_table(&rows);
 
     // Should contain pipe chars and separator line
     assert!(md.contains('|'));
@@ -250,9 +248,7 @@ fn test_markdown_trades_structure() {
 fn test_markdown_politicians_headers() {
     let politicians = load_politicians_fixture();
     let rows = build_politician_rows(&politicians);
-    let mut table = Table::new(&rows);
-    table.with(Style::markdown());
-    let md = table.to_string();
+    let md = markdown_table(&rows);
 
     let header_line = md.lines().next().unwrap();
     assert!(header_line.contains("Name"));
@@ -264,9 +260,7 @@ fn test_markdown_politicians_headers() {
 #[test]
 fn test_markdown_empty_produces_headers_only() {
     let rows: Vec<TradeRow> = build_trade_rows(&[]);
-    let mut table = Table::new(&rows);
-    table.with(Style::markdown());
-    let md = table.to_string();
+    let md = markdown_table(&rows);
 
     // Should have header and separator but no data rows
     let lines: Vec<&str> = md.lines().collect();
@@ -281,6 +275,115 @@ fn test_markdown_empty_produces_headers_only() {
     }
 }
 
+// -- Adversarial field escaping --
+
+/// Names with every character that has broken a CSV or Markdown row:
+/// delimiters, quotes, pipes, CRLF and bare line breaks, and non-ASCII text.
+fn adversarial_trade_rows() -> Vec<crate::commands::trades::EnrichedDbTradeRow> {
+    let names = [
+        ("Berkshire Hathaway, Inc. \"Class B\"", "BRK.B"),
+        ("Pipe | Holdings \\ Trust", "PIPE"),
+        ("Line\r\nBreak\nCorp\r", "LBC"),
+        ("Société Générale 株式会社 🚀", "GLE"),
+        ("=HYPERLINK(\"http://x\")", "@SUM"),
+    ];
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, (issuer, ticker))| {
+            let mut row = sample_db_trade_row();
+            row.tx_id = i as i64 + 1;
+            row.issuer_name = issuer.to_string();
+            row.issuer_ticker = ticker.to_string();
+            row.politician_name = "O'Brien, \"Pat\"".to_string();
+            row.committees = vec!["Ways, Means".to_string(), "Rules|Admin".to_string()];
+            let mut trade = crate::commands::trades::EnrichedDbTradeRow::from(row);
+            // Only later rows carry analytics, so the CSV header cannot be
+            // inferred from the first record alone.
+            if i == names.len() - 1 {
+                trade.absolute_return = Some(12.5);
+                trade.alpha = Some(-1.25);
+            }
+            trade
+        })
+        .collect()
+}
+
+#[test]
+fn test_escape_markdown_collapses_breaks_and_escapes_pipes() {
+    assert_eq!(escape_markdown("a|b"), "a\\|b");
+    assert_eq!(escape_markdown("a\\|b"), "a\\\\\\|b");
+    assert_eq!(escape_markdown("one\r\ntwo\n\nthree\rfour"), "one two three four");
+    assert_eq!(escape_markdown("plain"), "plain");
+}
+
+#[test]
+fn test_adversarial_csv_matches_golden_and_round_trips() {
+    let trades = adversarial_trade_rows();
+    let mut buf = Vec::new();
+    write_enriched_trades_csv(&mut buf, &trades).unwrap();
+    let csv = String::from_utf8(buf).unwrap();
+    assert_eq!(csv, include_str!("testdata/adversarial_trades.csv"));
+
+    let header = "Date,Politician,Party,Issuer,Ticker,Type,Asset,Value,Committees,Labels,Return,Alpha";
+    assert_eq!(csv.matches(header).count(), 1);
+
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let records: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+    assert_eq!(records.len(), trades.len());
+    for (record, trade) in records.iter().zip(&trades) {
+        assert_eq!(record.len(), 12);
+        assert_eq!(&record[1], sanitize_csv_field(&trade.politician_name));
+        assert_eq!(&record[3], sanitize_csv_field(&trade.issuer_name));
+        assert_eq!(&record[4], sanitize_csv_field(&trade.issuer_ticker));
+        assert_eq!(&record[8], "Ways, Means, Rules|Admin");
+    }
+}
+
+#[test]
+fn test_adversarial_markdown_matches_golden() {
+    let md = markdown_table(build_enriched_db_trade_rows(&adversarial_trade_rows()));
+    assert_eq!(format!("{}\n", md), include_str!("testdata/adversarial_trades.md"));
+
+    // One header, one separator, one line per trade, and every row has the
+    // same number of unescaped cell boundaries as the header.
+    let lines: Vec<&str> = md.lines().collect();
+    assert_eq!(lines.len(), 2 + 5);
+    let boundaries = |line: &str| line.replace("\\\\", "").replace("\\|", "").matches('|').count();
+    for line in &lines {
+        assert_eq!(boundaries(line), boundaries(lines[0]), "{}", line);
+    }
+}
+
+#[test]
+fn test_adversarial_json_and_xml_round_trip() {
+    let trades = adversarial_trade_rows();
+    let json = serde_json::to_string(&trades).unwrap();
+    let parsed: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+    for (value, trade) in parsed.iter().zip(&trades) {
+        assert_eq!(value["issuer_name"], trade.issuer_name.as_str());
+        assert_eq!(value["politician_name"], trade.politician_name.as_str());
+    }
+
+    let xml = crate::xml_output::to_xml(&trades);
+    let mut reader = quick_xml::Reader::from_str(&xml);
+    let mut issuers = Vec::new();
+    let mut in_issuer = false;
+    loop {
+        match reader.read_event().unwrap() {
+            quick_xml::events::Event::Start(e) => in_issuer = e.name().as_ref() == b"issuer_name",
+            quick_xml::events::Event::Text(t) if in_issuer => {
+                issuers.push(t.unescape().unwrap().into_owned());
+            }
+            quick_xml::events::Event::End(_) => in_issuer = false,
+            quick_xml::events::Event::Eof => break,
+            _ => {}
+        }
+    }
+    let expected: Vec<&str> = trades.iter().map(|t| t.issuer_name.as_str()).collect();
+    assert_eq!(issuers, expected);
+}
+
 // -- DB trade output tests --
 
 fn sample_db_trade_row() -> DbTradeRow {
@@ -378,8 +481,8 @@ fn test_db_trade_csv_headers() {
 #[test]
 fn test_db_trade_xml_structure() {
     let trades = vec![sample_db_trade_row()];
-    let xml = xml_output::db_trades_to_xml(&trades);
-    assert!(xml.contains("<trades>"));
+    let xml = xml_output::to_xml(&trades);
+    assert!(xml.contains("<trades format_version=\"2\">"));
     assert!(xml.contains("<trade>"));
     assert!(xml.contains("<asset_type>stock</asset_type>"));
     assert!(xml.contains("<committees>"));
@@ -473,6 +576,8 @@ fn sample_db_issuer_row() -> DbIssuerRow {
         politicians: 85,
         volume: 50_000_000,
         last_traded: Some("2024-03-14".to_string()),
+        last_trader_politician_id: Some("P000197".to_string()),
+        last_trader_name: Some("Nancy Pelosi".to_string()),
         mcap: Some(3_500_000_000_000),
         trailing1: Some(225.5),
         trailing1_change: Some(0.0089),
@@ -504,6 +609,7 @@ fn test_db_issuer_output_row_mapping() {
     assert_eq!(row.trades, 500);
     assert_eq!(row.volume, "$50.0M");
     assert_eq!(row.last_traded, "2024-03-14");
+    assert_eq!(row.last_trader, "Nancy Pelosi");
 }
 
 #[test]
@@ -519,6 +625,8 @@ fn test_db_issuer_output_no_performance() {
         politicians: 0,
         volume: 0,
         last_traded: None,
+        last_trader_politician_id: None,
+        last_trader_name: None,
         mcap: None,
         trailing1: None,
         trailing1_change: None,
@@ -539,6 +647,7 @@ fn test_db_issuer_output_no_performance() {
     assert_eq!(rows[0].trailing30, "-");
     assert_eq!(rows[0].trailing365, "-");
     assert_eq!(rows[0].last_traded, "-");
+    assert_eq!(rows[0].last_trader, "-");
 }
 
 #[test]
@@ -563,7 +672,7 @@ fn test_db_issuer_csv_headers() {
     let header = csv.lines().next().unwrap();
     assert_eq!(
         header,
-        "Name,Ticker,Sector,Mcap,30D Return,YTD,Trades,Volume,Last Traded"
+        "Name,Ticker,Sector,Mcap,30D Return,YTD,Trades,Volume,Last Traded,Last Trader"
     );
 }
 
@@ -581,7 +690,9 @@ fn sample_portfolio_position_with_pnl() -> PortfolioPosition {
         current_price: Some(75.0),
         current_value: Some(7500.0),
         price_date: Some("2024-03-15".to_string()),
+        price_source: Some(capitoltraders_lib::PriceSource::Current),
         last_updated: "2024-03-16T00:00:00Z".to_string(),
+        gics_sector: None,
     }
 }
 
@@ -597,7 +708,9 @@ fn sample_portfolio_position_missing_price() -> PortfolioPosition {
         current_price: None,
         current_value: None,
         price_date: None,
+        price_source: None,
         last_updated: "2024-03-16T00:00:00Z".to_string(),
+        gics_sector: None,
     }
 }
 
@@ -662,7 +775,9 @@ fn test_portfolio_csv_sanitization() {
         current_price: None,
         current_value: None,
         price_date: None,
+        price_source: None,
         last_updated: "2024-03-16T00:00:00Z".to_string(),
+        gics_sector: None,
     };
 
     let rows = build_portfolio_rows(&[position]);
@@ -684,3 +799,182 @@ fn test_portfolio_csv_headers() {
         "Politician,Ticker,Shares,Avg Cost,Current Price,Current Value,Unrealized P&L,P&L %"
     );
 }
+
+// -- Politician report tests --
+
+fn sample_report() -> crate::commands::report::PoliticianReport {
+    crate::commands::report::PoliticianReport {
+        politician_id: "P000001".to_string(),
+        name: "Jane | Doe".to_string(),
+        party: "Democrat".to_string(),
+        state: "CA".to_string(),
+        chamber: "house".to_string(),
+        trades: 12,
+        issuers: 4,
+        volume: 250_000,
+        last_traded: None,
+        committees: vec![],
+        top_holdings: vec![],
+        best_trades: vec![],
+        worst_trades: vec![],
+        disclosure: None,
+        donations: None,
+        committee_score: None,
+    }
+}
+
+#[test]
+fn test_politician_report_omits_empty_sections() {
+    let md = render_politician_report_markdown(&sample_report());
+    assert!(md.starts_with("# Jane \\| Doe\n"));
+    assert!(md.contains("**Trades:** 12 across 4 issuers ($250.0K volume)"));
+    assert!(!md.contains("## "));
+    assert!(!md.contains("Last traded"));
+
+    let json = serde_json::to_value(sample_report()).unwrap();
+    assert!(json.get("top_holdings").is_none());
+    assert!(json.get("donations").is_none());
+}
+
+#[test]
+fn test_politician_report_renders_populated_sections() {
+    let mut report = sample_report();
+    report.committees = vec!["hsba".to_string()];
+    report.best_trades = vec![crate::commands::report::ReportClosedTrade {
+        ticker: "NVDA".to_string(),
+        buy_date: "2024-01-02".to_string(),
+        sell_date: "2024-06-03".to_string(),
+        return_pct: 120.5,
+        holding_days: Some(153),
+    }];
+    report.disclosure = Some(capitoltraders_lib::DisclosureLateness {
+        trade_count: 4,
+        avg_gap_days: 30.0,
+        max_gap_days: 60,
+        late_count: 1,
+    });
+    let md = render_politician_report_markdown(&report);
+    assert!(md.contains("## Committees\n\n- hsba\n"));
+    assert!(md.contains("## Best Trades"));
+    assert!(md.contains("+120.5%"));
+    assert!(!md.contains("## Worst Trades"));
+    assert!(md.contains("**Filed after 45 days:** 1 (25.0%)"));
+}
+
+// -- Trade detail output tests --
+
+fn sample_trade_detail_row(sell_tx_id: i64, buy_tx_id: i64, shares: f64) -> TradeDetailRow {
+    TradeDetailRow {
+        ticker: "AAPL".to_string(),
+        sell_tx_id,
+        sell_date: "2024-05-01".to_string(),
+        sell_price: 60.0,
+        sell_filing_url: format!("https://example.com/{}", sell_tx_id),
+        buy_tx_id,
+        buy_date: "2024-01-01".to_string(),
+        buy_price: 40.0,
+        buy_filing_url: format!("https://example.com/{}", buy_tx_id),
+        shares,
+        return_pct: 50.0,
+        realized_gain: -12.5,
+        holding_days: Some(121),
+    }
+}
+
+#[test]
+fn test_trade_detail_rows_show_both_filings() {
+    let rows = build_trade_detail_rows(&[
+        sample_trade_detail_row(5, 1, 56.6),
+        sample_trade_detail_row(5, 2, 10.0),
+    ]);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].sell_filing_url, "https://example.com/5");
+    assert_eq!(rows[1].buy_filing_url, "https://example.com/2");
+    assert_eq!(rows[0].shares, "56.60");
+    assert_eq!(rows[0].realized_gain, "-$12.50");
+}
+
+#[test]
+fn test_trade_detail_xml_structure() {
+    let xml = xml_output::to_xml(&[sample_trade_detail_row(5, 1, 56.6)]);
+    assert!(xml.contains("<trade_detail format_version=\"2\">"));
+    assert!(xml.contains("<leg>"));
+    assert!(xml.contains("<sell_tx_id>5</sell_tx_id>"));
+    assert!(xml.contains("<buy_filing_url>https://example.com/1</buy_filing_url>"));
+}
+
+fn sample_fixed_income_row() -> FixedIncomeExposureRow {
+    FixedIncomeExposureRow {
+        politician_id: "P000001".to_string(),
+        politician_name: "Jane Doe".to_string(),
+        party: "Democrat".to_string(),
+        state: "CA".to_string(),
+        trade_count: 3,
+        bought_face_value: 50_000.0,
+        sold_face_value: 65_000.0,
+        net_face_value: -15_000.0,
+        government_trades: 2,
+        corporate_trades: 1,
+        municipal_trades: 0,
+        last_trade_date: "2024-05-01".to_string(),
+    }
+}
+
+#[test]
+fn test_fixed_income_rows_format_mix_and_net() {
+    let rows = build_fixed_income_rows(&[sample_fixed_income_row()]);
+    assert_eq!(rows[0].mix, "2/1/0");
+    assert_eq!(rows[0].bought, "$50,000.00");
+    assert_eq!(rows[0].net, "-$15,000.00");
+}
+
+#[test]
+fn test_fixed_income_xml_structure() {
+    let xml = xml_output::to_xml(&[sample_fixed_income_row()]);
+    assert!(xml.contains("<fixed_income format_version=\"2\">"));
+    assert!(xml.contains("<politician>"));
+    assert!(xml.contains("<net_face_value>-15000.0</net_face_value>"));
+}
+
+#[test]
+fn test_snapshot_diff_markdown_sections() {
+    use capitoltraders_lib::snapshot::PositionChangeKind;
+
+    let diff = SnapshotDiff {
+        snapshot: "before-sync".to_string(),
+        created_at: "2025-10-01 12:00:00".to_string(),
+        snapshot_schema_version: 14,
+        new_trades: SectionDiff::Compared { changes: vec![] },
+        positions: SectionDiff::Compared {
+            changes: vec![PositionChange {
+                politician_id: "P000001".to_string(),
+                politician_name: "Jane Doe".to_string(),
+                ticker: "NVDA".to_string(),
+                change: PositionChangeKind::Closed,
+                shares_held: None,
+            }],
+        },
+        ranks: SectionDiff::Compared {
+            changes: vec![RankChange {
+                politician_id: "P000001".to_string(),
+                politician_name: "Jane Doe".to_string(),
+                old_rank: 9,
+                new_rank: 2,
+                moved: 7,
+                avg_return: 12.34,
+            }],
+        },
+        anomalies: SectionDiff::Unavailable {
+            reason: "snapshot has no anomalies section".to_string(),
+        },
+    };
+    let md = render_snapshot_diff_markdown(&diff);
+    assert!(md.starts_with("# Changes since snapshot 'before-sync'\n"));
+    assert!(md.contains("## New Trades\n\nNo changes.\n"));
+    assert!(md.contains("## Positions (1)"));
+    assert!(md.contains("| closed "));
+    assert!(md.contains("## Rank Moves (1)"));
+    assert!(md.contains("+7"));
+    assert!(md.contains("+12.3%"));
+    assert!(md.contains("## New Anomalies\n\n_Unavailable: snapshot has no anomalies section._\n"));
+}
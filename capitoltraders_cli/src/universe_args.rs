@@ -0,0 +1,35 @@
+//! Trade-universe flags shared by `analytics` and `anomalies`, so the same
+//! flags admit the same trades in both commands.
+
+use anyhow::Result;
+use capitoltraders_lib::{Db, TradeUniverse, Universe};
+use clap::Args;
+
+/// Trade-universe rules shared by `analytics` and `anomalies`, on top of the
+/// priced stock trades both start from ([`TradeUniverse::priced_stocks`]).
+#[derive(Args, Clone, Default)]
+pub struct UniverseArgs {
+    /// Drop trades a later filing reported again (same politician, issuer,
+    /// asset type, owner, type and date), keeping the latest filing
+    #[arg(long)]
+    pub exclude_superseded: bool,
+
+    /// Drop trades with inconsistent disclosure data: filed before the
+    /// transaction, a negative reporting gap, or an inverted value range
+    #[arg(long)]
+    pub exclude_quarantined: bool,
+}
+
+impl UniverseArgs {
+    /// Load the trade universe these rules describe.
+    pub fn load(&self, db: &Db) -> Result<Universe> {
+        let mut universe = TradeUniverse::priced_stocks(db);
+        if self.exclude_superseded {
+            universe = universe.exclude_superseded();
+        }
+        if self.exclude_quarantined {
+            universe = universe.exclude_quarantined();
+        }
+        Ok(universe.build()?)
+    }
+}
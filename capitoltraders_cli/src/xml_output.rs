@@ -3,38 +3,165 @@
 //! Types are first serialized to `serde_json::Value`, then walked recursively
 //! to emit XML via `quick_xml::Writer`. This avoids modifying the vendored crate
 //! with serde XML derives.
+//!
+//! Each row type implements [`XmlRecord`], which names its root and row
+//! elements. The document root carries a `format_version` attribute
+//! ([`XML_FORMAT_VERSION`]); list fields are written as repeated child
+//! elements and absent values are left out. `--xml-legacy` switches back to
+//! the unversioned layout ([`XmlLayout::Legacy`]).
 
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::Writer;
 use serde::Serialize;
 use std::io::Cursor;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use capitoltraders_lib::analysis::{EventProximityRow, IndexPoint, OverlapPair};
+use capitoltraders_lib::conflict::HearingProximityRow;
+use capitoltraders_lib::JurisdictionSectorRow;
 use capitoltraders_lib::types::{IssuerDetail, PoliticianDetail, Trade};
 use capitoltraders_lib::{
-    ContributorAggRow, DbIssuerRow, DbPoliticianRow, DbTradeRow, DonationRow, EmployerAggRow,
-    PortfolioPosition, StateAggRow,
+    ContributorAggRow, CoverageRow, DbIssuerRow, DbPoliticianRow, DbTradeRow, DonationGeography, DonationGeographyRow, DonationRow,
+    EmployerAggRow, FixedIncomeExposureRow, LabelCount, LabelPoliticianRow, LabelReport, OccupationAggRow, PortfolioPosition, SessionGroupRow, StateAggRow, TradeRevisionRow,
+};
+
+use crate::commands::analytics::{
+    LeaderboardRow, TaxEstimateReport, TaxEstimateRow, TradeDetailRow,
 };
+use crate::commands::anomalies::{
+    AnomalyRow, CrowdedTradeRow, NovelTradeRow, PreMoveRow, SellSignalRow,
+};
+use crate::commands::conflicts::{AffinityRow, ConflictRow, DonationCorrelationRow, SpouseConflictRow};
+use crate::commands::politicians::EnrichedDbPoliticianRow;
+use crate::commands::portfolio::EnrichedPortfolioPosition;
+use crate::commands::trades::EnrichedDbTradeRow;
+
+/// Value of the `format_version` attribute on the root element. Bump it when
+/// the element layout changes.
+pub const XML_FORMAT_VERSION: &str = "2";
+
+/// Element layout of an XML document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XmlLayout {
+    /// Versioned root; joined list fields split into child elements; empty
+    /// strings omitted; empty lists self-closing.
+    Versioned,
+    /// The layout before `format_version` existed, kept for one release
+    /// behind `--xml-legacy`.
+    Legacy,
+}
+
+static LEGACY_LAYOUT: AtomicBool = AtomicBool::new(false);
+
+/// Select the layout used by [`to_xml`] for the rest of the process.
+pub fn set_layout(layout: XmlLayout) {
+    LEGACY_LAYOUT.store(layout == XmlLayout::Legacy, Ordering::Relaxed);
+}
 
-use crate::commands::analytics::LeaderboardRow;
-use crate::commands::conflicts::{ConflictRow, DonationCorrelationRow};
+fn current_layout() -> XmlLayout {
+    if LEGACY_LAYOUT.load(Ordering::Relaxed) {
+        XmlLayout::Legacy
+    } else {
+        XmlLayout::Versioned
+    }
+}
+
+/// A row type written as one element of an XML document.
+pub trait XmlRecord: Serialize {
+    /// Root element of the document.
+    const ROOT: &'static str;
+    /// Element wrapping each row.
+    const ITEM: &'static str;
+    /// String fields holding a `", "`-joined list (as shown in tables).
+    /// [`XmlLayout::Versioned`] writes them as repeated child elements.
+    const JOINED_LISTS: &'static [&'static str] = &[];
+}
+
+macro_rules! xml_records {
+    ($($ty:ty => $root:literal / $item:literal $([$($list:literal),+])?;)*) => {
+        $(impl XmlRecord for $ty {
+            const ROOT: &'static str = $root;
+            const ITEM: &'static str = $item;
+            $(const JOINED_LISTS: &'static [&'static str] = &[$($list),+];)?
+        })*
+    };
+}
+
+xml_records! {
+    Trade => "trades" / "trade";
+    DbTradeRow => "trades" / "trade";
+    EnrichedDbTradeRow => "trades" / "trade";
+    PoliticianDetail => "politicians" / "politician";
+    DbPoliticianRow => "politicians" / "politician";
+    EnrichedDbPoliticianRow => "politicians" / "politician";
+    IssuerDetail => "issuers" / "issuer";
+    DbIssuerRow => "issuers" / "issuer";
+    PortfolioPosition => "portfolio" / "position";
+    EnrichedPortfolioPosition => "portfolio" / "position";
+    FixedIncomeExposureRow => "fixed_income" / "politician";
+    DonationRow => "donations" / "donation";
+    CoverageRow => "coverage" / "entity";
+    ContributorAggRow => "contributors" / "contributor";
+    EmployerAggRow => "employers" / "employer";
+    OccupationAggRow => "occupations" / "occupation";
+    StateAggRow => "states" / "state";
+    LeaderboardRow => "leaderboard" / "politician";
+    TradeDetailRow => "trade_detail" / "leg";
+    SessionGroupRow => "session_groups" / "group";
+    LabelCount => "labels" / "label";
+    IndexPoint => "trading_index" / "day";
+    OverlapPair => "overlap" / "pair";
+    ConflictRow => "conflicts" / "conflict" ["committees"];
+    DonationCorrelationRow => "donation_correlations" / "correlation";
+    JurisdictionSectorRow => "jurisdictions" / "sector";
+    AnomalyRow => "anomalies" / "anomaly";
+    PreMoveRow => "pre_move_signals" / "signal";
+    SellSignalRow => "sell_signals" / "sell";
+    NovelTradeRow => "novel_trades" / "trade";
+    CrowdedTradeRow => "crowded_trades" / "crowd";
+    TradeRevisionRow => "trade_revisions" / "revision";
+    EventProximityRow => "event_trades" / "trade";
+    HearingProximityRow => "hearing_trades" / "trade";
+    SpouseConflictRow => "spouse_conflicts" / "politician" ["committees"];
+    AffinityRow => "affinity_scores" / "politician";
+}
 
 /// Singularize common array field names for XML child elements.
 fn singular(field: &str) -> &str {
     match field {
         "committees" => "committee",
         "labels" => "label",
+        "politicians" => "politician",
         "eodPrices" => "priceSet",
         "priceSet" => "value",
         _ => field,
     }
 }
 
+/// Child element for items of an array field. The legacy layout reused the
+/// field name for anything [`singular`] does not know.
+fn child_tag(field: &str, layout: XmlLayout) -> String {
+    let known = singular(field);
+    if known != field || layout == XmlLayout::Legacy {
+        return known.to_string();
+    }
+    if let Some(stem) = field.strip_suffix("ies") {
+        format!("{}y", stem)
+    } else if let Some(stem) = field.strip_suffix('s').filter(|s| !s.is_empty()) {
+        stem.to_string()
+    } else {
+        "item".to_string()
+    }
+}
+
 /// Recursively write a serde_json::Value as XML elements.
 fn write_value<W: std::io::Write>(
     writer: &mut Writer<W>,
     tag: &str,
     value: &serde_json::Value,
+    layout: XmlLayout,
 ) -> Result<(), quick_xml::Error> {
+    let versioned = layout == XmlLayout::Versioned;
     match value {
         serde_json::Value::Null => {
             // Omit null fields entirely
@@ -54,23 +181,29 @@ fn write_value<W: std::io::Write>(
             writer.write_event(Event::Text(BytesText::new(&s)))?;
             writer.write_event(Event::End(BytesEnd::new(tag)))?;
         }
+        serde_json::Value::String(s) if versioned && s.is_empty() => {
+            // Rows map missing text to "", which is an absent value too
+        }
         serde_json::Value::String(s) => {
             writer.write_event(Event::Start(BytesStart::new(tag)))?;
             writer.write_event(Event::Text(BytesText::new(s)))?;
             writer.write_event(Event::End(BytesEnd::new(tag)))?;
         }
+        serde_json::Value::Array(arr) if versioned && arr.is_empty() => {
+            writer.write_event(Event::Empty(BytesStart::new(tag)))?;
+        }
         serde_json::Value::Array(arr) => {
             writer.write_event(Event::Start(BytesStart::new(tag)))?;
-            let child_tag = singular(tag);
+            let child = child_tag(tag, layout);
             for item in arr {
-                write_value(writer, child_tag, item)?;
+                write_value(writer, &child, item, layout)?;
             }
             writer.write_event(Event::End(BytesEnd::new(tag)))?;
         }
         serde_json::Value::Object(map) => {
             writer.write_event(Event::Start(BytesStart::new(tag)))?;
             for (key, val) in map {
-                write_value(writer, key, val)?;
+                write_value(writer, key, val, layout)?;
             }
             writer.write_event(Event::End(BytesEnd::new(tag)))?;
         }
@@ -78,30 +211,56 @@ fn write_value<W: std::io::Write>(
     Ok(())
 }
 
-/// Serialize a slice of Serialize items into an XML string.
-fn items_to_xml<T: Serialize>(root_tag: &str, item_tag: &str, items: &[T]) -> String {
+/// Split the record's joined list fields into JSON arrays.
+fn split_joined_lists(value: &mut serde_json::Value, fields: &[&str]) {
+    let serde_json::Value::Object(map) = value else {
+        return;
+    };
+    for field in fields {
+        if let Some(serde_json::Value::String(joined)) = map.get(*field) {
+            let items = joined
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| serde_json::Value::String(item.to_string()))
+                .collect();
+            map.insert(field.to_string(), serde_json::Value::Array(items));
+        }
+    }
+}
+
+/// Serialize rows into an XML document in the given layout.
+pub fn render<T: XmlRecord>(items: &[T], layout: XmlLayout) -> String {
     let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
 
     writer
         .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
         .expect("write xml decl");
 
+    let mut root = BytesStart::new(T::ROOT);
+    if layout == XmlLayout::Versioned {
+        root.push_attribute(("format_version", XML_FORMAT_VERSION));
+    }
+
     if items.is_empty() {
         writer
-            .write_event(Event::Empty(BytesStart::new(root_tag)))
+            .write_event(Event::Empty(root))
             .expect("write empty root");
     } else {
         writer
-            .write_event(Event::Start(BytesStart::new(root_tag)))
+            .write_event(Event::Start(root))
             .expect("write root start");
 
         for item in items {
-            let val = serde_json::to_value(item).expect("serialize to json value");
-            write_value(&mut writer, item_tag, &val).expect("write xml value");
+            let mut val = serde_json::to_value(item).expect("serialize to json value");
+            if layout == XmlLayout::Versioned {
+                split_joined_lists(&mut val, T::JOINED_LISTS);
+            }
+            write_value(&mut writer, T::ITEM, &val, layout).expect("write xml value");
         }
 
         writer
-            .write_event(Event::End(BytesEnd::new(root_tag)))
+            .write_event(Event::End(BytesEnd::new(T::ROOT)))
             .expect("write root end");
     }
 
@@ -109,106 +268,98 @@ fn items_to_xml<T: Serialize>(root_tag: &str, item_tag: &str, items: &[T]) -> St
     String::from_utf8(buf).expect("valid utf8")
 }
 
-/// Serializes a slice of trades into an XML string with `<trades>` as the root element.
-pub fn trades_to_xml(trades: &[Trade]) -> String {
-    items_to_xml("trades", "trade", trades)
-}
-
-/// Serializes a slice of politicians into an XML string with `<politicians>` as the root element.
-pub fn politicians_to_xml(politicians: &[PoliticianDetail]) -> String {
-    items_to_xml("politicians", "politician", politicians)
+/// Serialize rows into an XML document in the layout chosen by [`set_layout`].
+pub fn to_xml<T: XmlRecord>(items: &[T]) -> String {
+    render(items, current_layout())
 }
 
-/// Serializes a slice of issuers into an XML string with `<issuers>` as the root element.
-pub fn issuers_to_xml(issuers: &[IssuerDetail]) -> String {
-    items_to_xml("issuers", "issuer", issuers)
-}
-
-/// Serializes a slice of DB trade rows into an XML string with `<trades>` as the root element.
-#[allow(dead_code)]
-pub fn db_trades_to_xml(trades: &[DbTradeRow]) -> String {
-    items_to_xml("trades", "trade", trades)
-}
-
-/// Serializes enriched DB trade rows (with analytics) into an XML string with `<trades>` as the root element.
-pub fn enriched_trades_to_xml(trades: &[crate::commands::trades::EnrichedDbTradeRow]) -> String {
-    items_to_xml("trades", "trade", trades)
-}
-
-/// Serializes a slice of DB politician rows into an XML string with `<politicians>` as the root element.
-#[allow(dead_code)]
-pub fn db_politicians_to_xml(politicians: &[DbPoliticianRow]) -> String {
-    items_to_xml("politicians", "politician", politicians)
-}
-
-/// Serializes enriched DB politician rows (with analytics) into an XML string with `<politicians>` as the root element.
-pub fn enriched_politicians_to_xml(
-    politicians: &[crate::commands::politicians::EnrichedDbPoliticianRow],
-) -> String {
-    items_to_xml("politicians", "politician", politicians)
-}
-
-/// Serializes a slice of DB issuer rows into an XML string with `<issuers>` as the root element.
-pub fn db_issuers_to_xml(issuers: &[DbIssuerRow]) -> String {
-    items_to_xml("issuers", "issuer", issuers)
-}
-
-/// Serializes a slice of portfolio positions into an XML string with `<portfolio>` as the root element.
-#[allow(dead_code)]
-pub fn portfolio_to_xml(positions: &[PortfolioPosition]) -> String {
-    items_to_xml("portfolio", "position", positions)
-}
-
-/// Serializes enriched portfolio positions (with conflict detection) into an XML string with `<portfolio>` as the root element.
-pub fn enriched_portfolio_to_xml(
-    positions: &[crate::commands::portfolio::EnrichedPortfolioPosition],
-) -> String {
-    items_to_xml("portfolio", "position", positions)
-}
-
-/// Serializes donations into XML with `<donations>` root element.
-pub fn donations_to_xml(donations: &[DonationRow]) -> String {
-    items_to_xml("donations", "donation", donations)
-}
-
-/// Serializes contributor aggregations into XML.
-pub fn contributor_agg_to_xml(rows: &[ContributorAggRow]) -> String {
-    items_to_xml("contributors", "contributor", rows)
-}
-
-/// Serializes employer aggregations into XML.
-pub fn employer_agg_to_xml(rows: &[EmployerAggRow]) -> String {
-    items_to_xml("employers", "employer", rows)
-}
-
-/// Serializes state aggregations into XML.
-pub fn state_agg_to_xml(rows: &[StateAggRow]) -> String {
-    items_to_xml("states", "state", rows)
-}
-
-/// Serializes leaderboard into XML with `<leaderboard>` root element.
-pub fn leaderboard_to_xml(rows: &[LeaderboardRow]) -> String {
-    items_to_xml("leaderboard", "politician", rows)
-}
-
-/// Serializes conflict rows into XML with `<conflicts>` root element.
-pub fn conflicts_to_xml(rows: &[ConflictRow]) -> String {
-    items_to_xml("conflicts", "conflict", rows)
-}
-
-/// Serializes donation correlations into XML with `<donation_correlations>` root element.
-pub fn donation_correlations_to_xml(rows: &[DonationCorrelationRow]) -> String {
-    items_to_xml("donation_correlations", "correlation", rows)
+/// Serializes a donation geography breakdown into XML with `<geography>` as
+/// the root element; each bucket row carries the mapping version.
+pub fn geography_to_xml(geography: &DonationGeography) -> String {
+    #[derive(Serialize)]
+    struct Row<'a> {
+        #[serde(flatten)]
+        row: &'a DonationGeographyRow,
+        mapping_version: &'a str,
+    }
+    impl XmlRecord for Row<'_> {
+        const ROOT: &'static str = "geography";
+        const ITEM: &'static str = "region";
+    }
+    let rows: Vec<Row> = geography
+        .buckets
+        .iter()
+        .map(|row| Row {
+            row,
+            mapping_version: &geography.mapping_version,
+        })
+        .collect();
+    to_xml(&rows)
 }
 
-/// Serializes anomaly rows into XML with `<anomalies>` root element.
-pub fn anomalies_to_xml(rows: &[crate::commands::anomalies::AnomalyRow]) -> String {
-    items_to_xml("anomalies", "anomaly", rows)
+/// Serializes a tax estimate into XML with `<tax_estimates>` root element.
+/// The rates and approximation note are repeated on each politician.
+pub fn tax_estimate_to_xml(report: &TaxEstimateReport) -> String {
+    #[derive(Serialize)]
+    struct Row<'a> {
+        #[serde(flatten)]
+        row: &'a TaxEstimateRow,
+        approximate: bool,
+        short_rate: f64,
+        long_rate: f64,
+        basis: &'a str,
+        universe: String,
+    }
+    impl XmlRecord for Row<'_> {
+        const ROOT: &'static str = "tax_estimates";
+        const ITEM: &'static str = "politician";
+    }
+    let rows: Vec<Row> = report
+        .politicians
+        .iter()
+        .map(|row| Row {
+            row,
+            approximate: report.approximate,
+            short_rate: report.short_rate,
+            long_rate: report.long_rate,
+            basis: report.basis,
+            universe: report.universe.to_string(),
+        })
+        .collect();
+    to_xml(&rows)
 }
 
-/// Serializes pre-move signals into XML with `<pre_move_signals>` root element.
-pub fn pre_move_signals_to_xml(rows: &[crate::commands::anomalies::PreMoveRow]) -> String {
-    items_to_xml("pre_move_signals", "signal", rows)
+/// Serializes a label report into XML with `<label_performance>` root
+/// element, one `<politician>` per breakdown row. The label totals and P&L
+/// basis are repeated on each politician.
+pub fn label_report_to_xml(report: &LabelReport) -> String {
+    #[derive(Serialize)]
+    struct Row<'a> {
+        label: &'a str,
+        #[serde(flatten)]
+        row: &'a LabelPoliticianRow,
+        label_trades: usize,
+        label_volume: i64,
+        label_realized_pnl: f64,
+        pnl_basis: &'a str,
+    }
+    impl XmlRecord for Row<'_> {
+        const ROOT: &'static str = "label_performance";
+        const ITEM: &'static str = "politician";
+    }
+    let rows: Vec<Row> = report
+        .by_politician
+        .iter()
+        .map(|row| Row {
+            label: &report.label,
+            row,
+            label_trades: report.trades,
+            label_volume: report.volume,
+            label_realized_pnl: report.realized_pnl,
+            pnl_basis: report.pnl_basis,
+        })
+        .collect();
+    to_xml(&rows)
 }
 
 #[cfg(test)]
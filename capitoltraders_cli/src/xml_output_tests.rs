@@ -21,9 +21,9 @@ fn load_issuers_fixture() -> Vec<IssuerDetail> {
 #[test]
 fn test_trade_xml_wellformed() {
     let trades = load_trades_fixture();
-    let xml = trades_to_xml(&trades);
+    let xml = to_xml(&trades);
     assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
-    assert!(xml.contains("<trades>"));
+    assert!(xml.contains("<trades format_version=\"2\">"));
     assert!(xml.contains("</trades>"));
     assert!(xml.contains("<trade>"));
     assert!(xml.contains("<_txId>12345</_txId>"));
@@ -34,9 +34,9 @@ fn test_trade_xml_wellformed() {
 #[test]
 fn test_politician_xml_output() {
     let politicians = load_politicians_fixture();
-    let xml = politicians_to_xml(&politicians);
+    let xml = to_xml(&politicians);
     assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
-    assert!(xml.contains("<politicians>"));
+    assert!(xml.contains("<politicians format_version=\"2\">"));
     assert!(xml.contains("<politician>"));
     assert!(xml.contains("<firstName>Nancy</firstName>"));
     assert!(xml.contains("<lastName>Pelosi</lastName>"));
@@ -46,9 +46,9 @@ fn test_politician_xml_output() {
 #[test]
 fn test_issuer_xml_output() {
     let issuers = load_issuers_fixture();
-    let xml = issuers_to_xml(&issuers);
+    let xml = to_xml(&issuers);
     assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
-    assert!(xml.contains("<issuers>"));
+    assert!(xml.contains("<issuers format_version=\"2\">"));
     assert!(xml.contains("<issuer>"));
     assert!(xml.contains("<issuerName>Apple Inc</issuerName>"));
     assert!(xml.contains("<mcap>2800000000000</mcap>"));
@@ -57,7 +57,7 @@ fn test_issuer_xml_output() {
 #[test]
 fn test_null_fields_omitted() {
     let trades = load_trades_fixture();
-    let xml = trades_to_xml(&trades);
+    let xml = to_xml(&trades);
     // txTypeExtended is null in fixture, should not appear
     assert!(!xml.contains("<txTypeExtended>"));
     // comment is null in fixture, should not appear
@@ -66,9 +66,9 @@ fn test_null_fields_omitted() {
 
 #[test]
 fn test_empty_array_produces_self_closing_root() {
-    let xml = trades_to_xml(&[]);
+    let xml = to_xml::<Trade>(&[]);
     assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
-    assert!(xml.contains("<trades/>"));
+    assert!(xml.contains("<trades format_version=\"2\"/>"));
     assert!(!xml.contains("</trades>"));
 }
 
@@ -81,6 +81,7 @@ fn test_xml_special_chars_escaped() {
         &mut writer,
         "test",
         &serde_json::Value::String("AT&T <Corp> \"quoted\"".to_string()),
+        XmlLayout::Versioned,
     )
     .unwrap();
     let buf = writer.into_inner().into_inner();
@@ -114,21 +115,21 @@ fn assert_xml_parseable(xml: &str) {
 #[test]
 fn test_trade_xml_parseable() {
     let trades = load_trades_fixture();
-    let xml = trades_to_xml(&trades);
+    let xml = to_xml(&trades);
     assert_xml_parseable(&xml);
 }
 
 #[test]
 fn test_politician_xml_parseable() {
     let politicians = load_politicians_fixture();
-    let xml = politicians_to_xml(&politicians);
+    let xml = to_xml(&politicians);
     assert_xml_parseable(&xml);
 }
 
 #[test]
 fn test_issuer_xml_parseable() {
     let issuers = load_issuers_fixture();
-    let xml = issuers_to_xml(&issuers);
+    let xml = to_xml(&issuers);
     assert_xml_parseable(&xml);
 }
 
@@ -156,11 +157,9 @@ fn collect_element_names_at_depth(xml: &str, target_depth: usize) -> Vec<String>
             Ok(Event::End(_)) => {
                 depth -= 1;
             }
-            Ok(Event::Empty(e)) => {
-                if depth == target_depth {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    names.push(name);
-                }
+            Ok(Event::Empty(e)) if depth == target_depth => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                names.push(name);
             }
             Ok(Event::Eof) => break,
             Err(e) => panic!("XML parse error: {e}"),
@@ -202,11 +201,9 @@ fn collect_children_of(xml: &str, parent: &str) -> Vec<String> {
                     break;
                 }
             }
-            Ok(Event::Empty(e)) => {
-                if inside_parent && current_depth == parent_depth + 1 {
-                    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
-                    children.push(name);
-                }
+            Ok(Event::Empty(e)) if inside_parent && current_depth == parent_depth + 1 => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                children.push(name);
             }
             Ok(Event::Eof) => break,
             Err(e) => panic!("XML parse error: {e}"),
@@ -219,7 +216,7 @@ fn collect_children_of(xml: &str, parent: &str) -> Vec<String> {
 #[test]
 fn test_trade_xml_field_presence() {
     let trades = load_trades_fixture();
-    let xml = trades_to_xml(&trades);
+    let xml = to_xml(&trades);
 
     // Depth 2 = fields inside <trades><trade><FIELD>
     let fields = collect_element_names_at_depth(&xml, 2);
@@ -257,7 +254,7 @@ fn test_trade_xml_field_presence() {
 #[test]
 fn test_issuer_xml_nested_performance() {
     let issuers = load_issuers_fixture();
-    let xml = issuers_to_xml(&issuers);
+    let xml = to_xml(&issuers);
 
     let children = collect_children_of(&xml, "performance");
     assert!(
@@ -277,7 +274,7 @@ fn test_issuer_xml_nested_performance() {
 #[test]
 fn test_xml_array_child_singularization() {
     let trades = load_trades_fixture();
-    let trade_xml = trades_to_xml(&trades);
+    let trade_xml = to_xml(&trades);
 
     // <committees> should contain <committee> children
     let committee_children = collect_children_of(&trade_xml, "committees");
@@ -299,10 +296,322 @@ fn test_xml_array_child_singularization() {
 
     // <eodPrices> should contain <priceSet> children
     let issuers = load_issuers_fixture();
-    let issuer_xml = issuers_to_xml(&issuers);
+    let issuer_xml = to_xml(&issuers);
     let eod_children = collect_children_of(&issuer_xml, "eodPrices");
     assert!(
         eod_children.iter().all(|c| c == "priceSet"),
         "eodPrices children should be <priceSet>, got: {eod_children:?}"
     );
 }
+
+#[test]
+fn test_geography_xml_carries_mapping_version() {
+    use capitoltraders_lib::GeographyBucket;
+
+    let geography = DonationGeography {
+        politician_id: "P000001".to_string(),
+        politician_name: "Jane Doe".to_string(),
+        state: "CA".to_string(),
+        district: Some(11),
+        mapping_version: "fixture-1".to_string(),
+        buckets: vec![DonationGeographyRow {
+            bucket: GeographyBucket::InDistrict,
+            donation_count: 2,
+            total_amount: 750.0,
+            pct_of_amount: 100.0,
+        }],
+    };
+    let xml = geography_to_xml(&geography);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<geography format_version=\"2\">"));
+    assert!(xml.contains("<bucket>in_district</bucket>"));
+    assert!(xml.contains("<mapping_version>fixture-1</mapping_version>"));
+}
+
+#[test]
+fn test_event_proximity_xml_output() {
+    let rows = vec![EventProximityRow {
+        tx_id: 42,
+        politician_name: "Jane Doe".to_string(),
+        ticker: "PFE".to_string(),
+        tx_type: "buy".to_string(),
+        tx_date: "2024-03-05".to_string(),
+        value: 15000,
+        event_date: "2024-03-10".to_string(),
+        event_title: "Drug pricing hearing".to_string(),
+        day_offset: -5,
+        matched_on: "ticker".to_string(),
+    }];
+    let xml = to_xml(&rows);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<event_trades format_version=\"2\">"));
+    assert!(xml.contains("<day_offset>-5</day_offset>"));
+    assert!(xml.contains("<event_title>Drug pricing hearing</event_title>"));
+}
+
+#[test]
+fn test_hearing_proximity_xml_output() {
+    let rows = vec![HearingProximityRow {
+        politician_id: "P000001".to_string(),
+        politician_name: "John Doe".to_string(),
+        committee: "hsba".to_string(),
+        hearing_date: "2024-03-31".to_string(),
+        hearing_title: "Bank oversight".to_string(),
+        tx_id: 7,
+        ticker: "JPM".to_string(),
+        gics_sector: "Financials".to_string(),
+        tx_type: "buy".to_string(),
+        tx_date: "2024-03-25".to_string(),
+        day_offset: -6,
+        value: 15000,
+    }];
+    let xml = to_xml(&rows);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<hearing_trades format_version=\"2\">"));
+    assert!(xml.contains("<committee>hsba</committee>"));
+    assert!(xml.contains("<day_offset>-6</day_offset>"));
+}
+
+#[test]
+fn test_spouse_conflicts_xml_output() {
+    let rows = vec![SpouseConflictRow {
+        rank: 1,
+        politician_name: "John Doe".to_string(),
+        committees: "hsba".to_string(),
+        self_scored_trades: 2,
+        self_in_jurisdiction_trades: 0,
+        self_in_jurisdiction_volume: 0,
+        spouse_scored_trades: 2,
+        spouse_in_jurisdiction_trades: 2,
+        spouse_in_jurisdiction_volume: 58000,
+        spouse_committee_pct: 100.0,
+        spouse_to_self_ratio: None,
+        spouse_exclusive: true,
+    }];
+    let xml = to_xml(&rows);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<spouse_conflicts format_version=\"2\">"));
+    assert!(xml.contains("<spouse_exclusive>true</spouse_exclusive>"));
+    assert!(xml.contains("<spouse_in_jurisdiction_volume>58000</spouse_in_jurisdiction_volume>"));
+}
+
+#[test]
+fn test_tax_estimate_xml_is_labeled_approximate() {
+    let spec = capitoltraders_lib::UniverseSpec {
+        asset_types: vec!["stock".to_string()],
+        enriched_prices_only: true,
+        ..Default::default()
+    };
+    let report = TaxEstimateReport {
+        approximate: true,
+        short_rate: 0.37,
+        long_rate: 0.2,
+        basis: "Approximate",
+        politicians: vec![TaxEstimateRow {
+            rank: 1,
+            politician_name: "Jane Doe".to_string(),
+            party: "Democrat".to_string(),
+            state: "CA".to_string(),
+            total_trades: 5,
+            realized_short_term_gain: 150.0,
+            realized_long_term_gain: -60.0,
+            unknown_term_trades: 1,
+            estimated_tax: 55.5,
+        }],
+        universe: capitoltraders_lib::UniverseProvenance {
+            rules: spec.rules(),
+            spec,
+            trade_count: 5,
+        },
+    };
+    let xml = tax_estimate_to_xml(&report);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<tax_estimates format_version=\"2\">"));
+    assert!(xml.contains("<approximate>true</approximate>"));
+    assert!(xml.contains("<universe>5 trades (asset types: stock; enriched prices only)</universe>"));
+    assert!(xml.contains("<realized_long_term_gain>-60.0</realized_long_term_gain>"));
+}
+
+#[test]
+fn test_sell_signals_xml_output() {
+    let rows = vec![crate::commands::anomalies::SellSignalRow {
+        politician_name: "John Doe".to_string(),
+        ticker: "AAPL".to_string(),
+        tx_date: "2024-03-01".to_string(),
+        sell_price: 98.0,
+        trailing_peak: 100.0,
+        peak_date: "2024-02-27".to_string(),
+        below_peak_pct: 2.0,
+        forward_low: 80.0,
+        drawdown_after_pct: 18.37,
+        prescient: true,
+    }];
+    let xml = to_xml(&rows);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<sell_signals format_version=\"2\">"));
+    assert!(xml.contains("<sell>"));
+    assert!(xml.contains("<prescient>true</prescient>"));
+}
+
+#[test]
+fn test_crowded_trades_xml_output() {
+    let rows = vec![crate::commands::anomalies::CrowdedTradeRow {
+        issuer_name: "Apple Inc".to_string(),
+        ticker: "AAPL".to_string(),
+        direction: "buy".to_string(),
+        window_start: "2024-03-01".to_string(),
+        window_end: "2024-03-12".to_string(),
+        politician_count: 2,
+        politicians: vec!["Jane Roe".to_string(), "John Doe".to_string()],
+        trade_count: 3,
+        total_value: 24000,
+        democrats: 1,
+        republicans: 1,
+        other_party: 0,
+    }];
+    let xml = to_xml(&rows);
+    assert_xml_parseable(&xml);
+    assert!(xml.contains("<crowded_trades format_version=\"2\">"));
+    assert!(xml.contains("<crowd>"));
+    assert!(xml.contains("<politician>Jane Roe</politician>"));
+}
+
+fn golden_trade_rows() -> Vec<DbTradeRow> {
+    let base = DbTradeRow {
+        tx_id: 1001,
+        pub_date: "2024-03-20".to_string(),
+        tx_date: "2024-03-01".to_string(),
+        tx_type: "buy".to_string(),
+        value: 50000,
+        price: Some(182.5),
+        size: None,
+        filing_url: "https://example.com/filing/1001".to_string(),
+        reporting_gap: 19,
+        enriched_at: Some("2024-03-21T00:00:00Z".to_string()),
+        trade_date_price: None,
+        current_price: None,
+        price_enriched_at: None,
+        estimated_shares: None,
+        estimated_value: None,
+        politician_name: "Jane Doe".to_string(),
+        party: "Democrat".to_string(),
+        state: "CA".to_string(),
+        chamber: "house".to_string(),
+        issuer_name: "Apple Inc".to_string(),
+        issuer_ticker: "AAPL:US".to_string(),
+        asset_type: "stock".to_string(),
+        committees: vec!["hsba".to_string(), "hsif".to_string()],
+        labels: vec!["faang".to_string()],
+        politician_id: "P000001".to_string(),
+        issuer_sector: Some("information-technology".to_string()),
+    };
+    let bare = DbTradeRow {
+        tx_id: 1002,
+        tx_type: "sell".to_string(),
+        price: None,
+        enriched_at: None,
+        issuer_name: "Private Fund LP".to_string(),
+        issuer_ticker: String::new(),
+        asset_type: "hedge-pension-fund".to_string(),
+        committees: Vec::new(),
+        labels: Vec::new(),
+        issuer_sector: None,
+        ..base.clone()
+    };
+    vec![base, bare]
+}
+
+fn golden_donation_rows() -> Vec<DonationRow> {
+    let base = DonationRow {
+        sub_id: "SUB001".to_string(),
+        contributor_name: "SMITH, JOHN".to_string(),
+        contributor_employer: "ACME CORP".to_string(),
+        contributor_occupation: "ENGINEER".to_string(),
+        contributor_state: "CA".to_string(),
+        amount: 500.0,
+        date: "2024-01-15".to_string(),
+        cycle: 2024,
+        committee_name: "DOE FOR CONGRESS".to_string(),
+        committee_designation: "P".to_string(),
+        politician_name: "Jane Doe".to_string(),
+        source_class: capitoltraders_lib::CommitteeClass::Campaign,
+    };
+    let retired = DonationRow {
+        sub_id: "SUB002".to_string(),
+        contributor_name: "ROE, MARY".to_string(),
+        contributor_employer: String::new(),
+        contributor_occupation: "RETIRED".to_string(),
+        amount: 2900.0,
+        ..base.clone()
+    };
+    vec![base, retired]
+}
+
+/// Compare against a checked-in golden file, normalizing line endings.
+fn assert_golden(actual: &str, golden: &str, name: &str) {
+    assert_eq!(
+        actual.trim_end(),
+        golden.replace("\r\n", "\n").trim_end(),
+        "XML output differs from tests/fixtures/xml/{name}"
+    );
+}
+
+#[test]
+fn test_trades_xml_golden_versioned() {
+    let xml = render(&golden_trade_rows(), XmlLayout::Versioned);
+    assert_xml_parseable(&xml);
+    assert_golden(&xml, include_str!("../tests/fixtures/xml/trades.xml"), "trades.xml");
+}
+
+#[test]
+fn test_trades_xml_golden_legacy() {
+    let xml = render(&golden_trade_rows(), XmlLayout::Legacy);
+    assert_xml_parseable(&xml);
+    assert_golden(&xml, include_str!("../tests/fixtures/xml/trades_legacy.xml"), "trades_legacy.xml");
+}
+
+#[test]
+fn test_donations_xml_golden_versioned() {
+    let xml = render(&golden_donation_rows(), XmlLayout::Versioned);
+    assert_xml_parseable(&xml);
+    assert_golden(&xml, include_str!("../tests/fixtures/xml/donations.xml"), "donations.xml");
+}
+
+#[test]
+fn test_donations_xml_golden_legacy() {
+    let xml = render(&golden_donation_rows(), XmlLayout::Legacy);
+    assert_xml_parseable(&xml);
+    assert_golden(
+        &xml,
+        include_str!("../tests/fixtures/xml/donations_legacy.xml"),
+        "donations_legacy.xml",
+    );
+}
+
+#[test]
+fn test_joined_list_fields_nest_only_in_versioned_layout() {
+    let rows = vec![ConflictRow {
+        rank: 1,
+        politician_name: "Jane Doe".to_string(),
+        committees: "Armed Services, Intelligence".to_string(),
+        total_scored_trades: 10,
+        committee_related_trades: 4,
+        committee_trading_pct: 40.0,
+    }];
+    let xml = render(&rows, XmlLayout::Versioned);
+    assert_xml_parseable(&xml);
+    assert_eq!(collect_children_of(&xml, "committees"), vec!["committee", "committee"]);
+    assert!(xml.contains("<committee>Intelligence</committee>"));
+
+    let legacy = render(&rows, XmlLayout::Legacy);
+    assert!(legacy.contains("<committees>Armed Services, Intelligence</committees>"));
+    assert!(legacy.contains("<conflicts>"));
+}
+
+#[test]
+fn test_unknown_plural_fields_get_singular_children() {
+    assert_eq!(child_tag("holdings", XmlLayout::Versioned), "holding");
+    assert_eq!(child_tag("categories", XmlLayout::Versioned), "category");
+    assert_eq!(child_tag("labels", XmlLayout::Versioned), "label");
+    assert_eq!(child_tag("holdings", XmlLayout::Legacy), "holdings");
+}
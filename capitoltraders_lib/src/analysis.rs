@@ -1,10 +1,21 @@
 //! Trade analysis helpers for aggregating and summarizing trade data.
 //!
-//! All functions operate on slices of upstream `Trade` types and return
-//! standard collections. They are designed for use in summary views
-//! and do not perform network calls.
+//! Most functions operate on slices of upstream `Trade` types and return
+//! standard collections. [`trades_near_events`] works on stored
+//! [`DbTradeRow`]s instead, since issuer sectors only exist in the database.
+//! [`classify_trade_timing`] places a trade against the congressional
+//! session calendar. [`trading_index`] turns daily buy and sell value into
+//! a normalized net buying pressure series, and [`politician_overlap`]
+//! scores how alike two members' traded issuers are.
+//! Nothing here performs network calls.
 
+use crate::calendar::{SessionCalendar, SessionStatus};
+use crate::db::{AnalyticsTradeRow, DbTradeRow};
+use crate::events::{normalize_sector, Event};
+use crate::pricing::ticker_matches;
 use capitoltrades_api::types::Trade;
+use chrono::NaiveDate;
+use serde::Serialize;
 use std::collections::{BTreeMap, HashMap};
 
 /// Groups trades by political party, returning a map of party name to trades.
@@ -38,7 +49,7 @@ pub fn top_traded_issuers(trades: &[Trade], limit: usize) -> Vec<(String, usize)
         *counts.entry(trade.issuer.issuer_name.clone()).or_default() += 1;
     }
     let mut sorted: Vec<(String, usize)> = counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted.sort_by_key(|b| std::cmp::Reverse(b.1));
     sorted.truncate(limit);
     sorted
 }
@@ -58,6 +69,349 @@ pub fn total_volume(trades: &[Trade]) -> i64 {
     trades.iter().map(|t| t.value).sum()
 }
 
+/// A trade that falls within the window around a calendar event.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct EventProximityRow {
+    pub tx_id: i64,
+    pub politician_name: String,
+    pub ticker: String,
+    pub tx_type: String,
+    pub tx_date: String,
+    pub value: i64,
+    pub event_date: String,
+    pub event_title: String,
+    /// Days from the event to the trade; negative means before the event.
+    pub day_offset: i64,
+    /// "ticker" or "sector", whichever tied the trade to the event.
+    pub matched_on: String,
+}
+
+/// Pairs trades with events they fall within `window_days` of (inclusive).
+///
+/// An event with a ticker matches trades in that ticker (a bare symbol also
+/// matches the stored exchange-suffixed ticker, see [`ticker_matches`]); an
+/// event with a sector matches every trade whose issuer is in that sector. A trade near
+/// several events yields one row per event. Trades with an unparseable
+/// `tx_date` are skipped. Rows are ordered by event date, then offset.
+pub fn trades_near_events(
+    trades: &[DbTradeRow],
+    events: &[Event],
+    window_days: u32,
+) -> Vec<EventProximityRow> {
+    let window = i64::from(window_days);
+    let mut rows = Vec::new();
+
+    for trade in trades {
+        let Ok(tx_date) = NaiveDate::parse_from_str(&trade.tx_date, "%Y-%m-%d") else {
+            continue;
+        };
+        let sector = trade.issuer_sector.as_deref().map(normalize_sector);
+
+        for event in events {
+            let day_offset = (tx_date - event.date).num_days();
+            if day_offset.abs() > window {
+                continue;
+            }
+            let matched_on = if event
+                .ticker
+                .as_deref()
+                .is_some_and(|t| ticker_matches(t, &trade.issuer_ticker))
+            {
+                "ticker"
+            } else if event.sector.is_some() && event.sector == sector {
+                "sector"
+            } else {
+                continue;
+            };
+
+            rows.push(EventProximityRow {
+                tx_id: trade.tx_id,
+                politician_name: trade.politician_name.clone(),
+                ticker: trade.issuer_ticker.clone(),
+                tx_type: trade.tx_type.clone(),
+                tx_date: trade.tx_date.clone(),
+                value: trade.value,
+                event_date: event.date.format("%Y-%m-%d").to_string(),
+                event_title: event.title.clone(),
+                day_offset,
+                matched_on: matched_on.to_string(),
+            });
+        }
+    }
+
+    rows.sort_by(|a, b| {
+        a.event_date
+            .cmp(&b.event_date)
+            .then(a.day_offset.cmp(&b.day_offset))
+            .then(a.tx_id.cmp(&b.tx_id))
+    });
+    rows
+}
+
+/// Whether `chamber` was in session on `trade_date`, per `calendar`.
+///
+/// Dates the calendar does not cover for that chamber, and trades with no
+/// chamber, are [`SessionStatus::Unknown`].
+pub fn classify_trade_timing(
+    trade_date: NaiveDate,
+    chamber: &str,
+    calendar: &SessionCalendar,
+) -> SessionStatus {
+    calendar.status(chamber, trade_date)
+}
+
+/// Fill in `session_status` on analytics rows. Rows with an unparseable
+/// `tx_date` stay [`SessionStatus::Unknown`].
+pub fn tag_session_status(rows: &mut [AnalyticsTradeRow], calendar: &SessionCalendar) {
+    for row in rows {
+        row.session_status = NaiveDate::parse_from_str(&row.tx_date, "%Y-%m-%d")
+            .map(|date| classify_trade_timing(date, &row.chamber, calendar))
+            .unwrap_or(SessionStatus::Unknown);
+    }
+}
+
+/// One buy or sell feeding [`trading_index`].
+#[derive(Debug, Clone)]
+pub struct IndexTrade {
+    pub date: NaiveDate,
+    /// `buy` or `sell`; anything else is ignored.
+    pub tx_type: String,
+    /// Estimated dollar value.
+    pub value: i64,
+}
+
+/// Settings for [`trading_index`].
+#[derive(Debug, Clone, Copy)]
+pub struct TradingIndexParams {
+    /// Days of gross value averaged to normalize each day's net.
+    pub window_days: usize,
+    /// EMA span for the smoothed column; `None` leaves it empty.
+    pub ema_span: Option<usize>,
+}
+
+impl Default for TradingIndexParams {
+    fn default() -> Self {
+        Self {
+            window_days: 90,
+            ema_span: Some(20),
+        }
+    }
+}
+
+/// One day of the congress trading index.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IndexPoint {
+    pub date: String,
+    pub buy_value: i64,
+    pub sell_value: i64,
+    /// Buy value minus sell value.
+    pub net_value: i64,
+    /// Net value over the trailing average daily gross value. Null during
+    /// the bootstrap window and when the window saw no trading.
+    pub raw: Option<f64>,
+    /// EMA of `raw`, seeded with its first value.
+    pub smoothed: Option<f64>,
+}
+
+/// Daily net congressional buying pressure, normalized by recent activity.
+///
+/// The series runs from `start` (or the first trade) to the last trade,
+/// one point per calendar day. A day's `raw` value is its buy value minus
+/// sell value divided by the average daily gross (buy + sell) value over
+/// the `window_days` days before it. Days without trades count as zero in
+/// that average, so quiet stretches still move the window. The first
+/// `window_days` points have no full window and are null.
+///
+/// `smoothed` is an exponential moving average of `raw` with
+/// alpha = 2 / (span + 1). Null `raw` days leave the average untouched and
+/// are null themselves.
+pub fn trading_index(
+    trades: &[IndexTrade],
+    start: Option<NaiveDate>,
+    params: &TradingIndexParams,
+) -> Vec<IndexPoint> {
+    let mut daily: BTreeMap<NaiveDate, (i64, i64)> = BTreeMap::new();
+    for trade in trades {
+        let entry = daily.entry(trade.date).or_default();
+        match trade.tx_type.as_str() {
+            "buy" => entry.0 += trade.value,
+            "sell" => entry.1 += trade.value,
+            _ => {}
+        }
+    }
+    let Some(last) = daily.keys().next_back().copied() else {
+        return Vec::new();
+    };
+    let first = daily.keys().next().copied().unwrap_or(last);
+    let first = start.map_or(first, |s| s.min(first));
+
+    let alpha = params.ema_span.map(|span| 2.0 / (span.max(1) as f64 + 1.0));
+    let mut ema: Option<f64> = None;
+    let mut window: std::collections::VecDeque<i64> = std::collections::VecDeque::new();
+    let mut window_sum = 0i64;
+    let mut points = Vec::new();
+
+    for date in first.iter_days().take_while(|d| *d <= last) {
+        let (buy, sell) = daily.get(&date).copied().unwrap_or_default();
+        let raw = (window.len() == params.window_days && window_sum > 0)
+            .then(|| (buy - sell) as f64 * params.window_days as f64 / window_sum as f64);
+        let smoothed = match (raw, alpha) {
+            (Some(value), Some(alpha)) => {
+                let next = ema.map_or(value, |prev| alpha * value + (1.0 - alpha) * prev);
+                ema = Some(next);
+                Some(next)
+            }
+            _ => None,
+        };
+        points.push(IndexPoint {
+            date: date.format("%Y-%m-%d").to_string(),
+            buy_value: buy,
+            sell_value: sell,
+            net_value: buy - sell,
+            raw,
+            smoothed,
+        });
+
+        window.push_back(buy + sell);
+        window_sum += buy + sell;
+        if window.len() > params.window_days {
+            window_sum -= window.pop_front().unwrap_or(0);
+        }
+    }
+    points
+}
+
+/// One trade feeding [`politician_overlap`].
+#[derive(Debug, Clone)]
+pub struct OverlapTrade {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub issuer_id: i64,
+    /// Estimated dollar value.
+    pub value: i64,
+}
+
+/// Settings for [`politician_overlap`].
+#[derive(Debug, Clone, Copy)]
+pub struct OverlapOptions {
+    /// Issuers two politicians must both have traded to form a pair.
+    pub min_shared: usize,
+    /// Politicians with fewer trades are dropped before pairing.
+    pub min_trades: usize,
+}
+
+impl Default for OverlapOptions {
+    fn default() -> Self {
+        Self {
+            min_shared: 5,
+            min_trades: 10,
+        }
+    }
+}
+
+/// Two politicians who traded the same issuers. Emitted once per unordered
+/// pair, with `a` holding the smaller politician ID.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct OverlapPair {
+    pub politician_a_id: String,
+    pub politician_a_name: String,
+    pub politician_b_id: String,
+    pub politician_b_name: String,
+    /// Issuers both politicians traded.
+    pub shared_issuers: usize,
+    /// Shared issuers over issuers either politician traded.
+    pub jaccard: f64,
+    /// Cosine similarity of the two per-issuer dollar volume vectors.
+    pub cosine: f64,
+}
+
+/// One politician's traded issuers, ready for pairing.
+struct OverlapProfile<'a> {
+    id: &'a str,
+    name: &'a str,
+    volumes: HashMap<i64, f64>,
+    norm: f64,
+}
+
+/// Pairwise similarity between politicians based on the issuers they traded.
+///
+/// Politicians with fewer than `min_trades` trades, or fewer than
+/// `min_shared` distinct issuers (who could never reach the threshold), are
+/// pruned before the O(n²) pairing. Pairs sharing at least `min_shared`
+/// issuers are scored two ways: `jaccard` compares the issuer sets alone,
+/// `cosine` weights each issuer by the politician's total dollar volume in
+/// it. Results are sorted by Jaccard, then shared issuers, descending.
+pub fn politician_overlap(trades: &[OverlapTrade], opts: &OverlapOptions) -> Vec<OverlapPair> {
+    let mut by_politician: BTreeMap<&str, (&str, usize, HashMap<i64, f64>)> = BTreeMap::new();
+    for trade in trades {
+        let entry = by_politician
+            .entry(trade.politician_id.as_str())
+            .or_insert_with(|| (trade.politician_name.as_str(), 0, HashMap::new()));
+        entry.1 += 1;
+        *entry.2.entry(trade.issuer_id).or_default() += trade.value as f64;
+    }
+
+    let min_shared = opts.min_shared.max(1);
+    let profiles: Vec<OverlapProfile> = by_politician
+        .into_iter()
+        .filter(|(_, (_, count, volumes))| *count >= opts.min_trades && volumes.len() >= min_shared)
+        .map(|(id, (name, _, volumes))| {
+            let norm = volumes.values().map(|v| v * v).sum::<f64>().sqrt();
+            OverlapProfile {
+                id,
+                name,
+                volumes,
+                norm,
+            }
+        })
+        .collect();
+
+    let mut pairs = Vec::new();
+    for (i, a) in profiles.iter().enumerate() {
+        for b in &profiles[i + 1..] {
+            let (small, large) = if a.volumes.len() <= b.volumes.len() {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            let mut shared = 0usize;
+            let mut dot = 0.0;
+            for (issuer, volume) in &small.volumes {
+                if let Some(other) = large.volumes.get(issuer) {
+                    shared += 1;
+                    dot += volume * other;
+                }
+            }
+            if shared < min_shared {
+                continue;
+            }
+            let union = a.volumes.len() + b.volumes.len() - shared;
+            let norms = a.norm * b.norm;
+            pairs.push(OverlapPair {
+                politician_a_id: a.id.to_string(),
+                politician_a_name: a.name.to_string(),
+                politician_b_id: b.id.to_string(),
+                politician_b_name: b.name.to_string(),
+                shared_issuers: shared,
+                jaccard: shared as f64 / union as f64,
+                cosine: if norms > 0.0 { dot / norms } else { 0.0 },
+            });
+        }
+    }
+
+    pairs.sort_by(|x, y| {
+        y.jaccard
+            .total_cmp(&x.jaccard)
+            .then(y.shared_issuers.cmp(&x.shared_issuers))
+            .then_with(|| x.politician_a_id.cmp(&y.politician_a_id))
+            .then_with(|| x.politician_b_id.cmp(&y.politician_b_id))
+    });
+    pairs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +466,249 @@ mod tests {
         assert!(trades_by_month(&trades).is_empty());
         assert_eq!(total_volume(&trades), 0);
     }
+
+    fn event_trade(tx_id: i64, ticker: &str, sector: Option<&str>, tx_date: &str) -> DbTradeRow {
+        DbTradeRow {
+            tx_id,
+            pub_date: tx_date.to_string(),
+            tx_date: tx_date.to_string(),
+            tx_type: "buy".to_string(),
+            value: 15000,
+            price: None,
+            size: None,
+            filing_url: String::new(),
+            reporting_gap: 0,
+            enriched_at: None,
+            trade_date_price: None,
+            current_price: None,
+            price_enriched_at: None,
+            estimated_shares: None,
+            estimated_value: None,
+            politician_name: "Jane Doe".to_string(),
+            party: "democrat".to_string(),
+            state: "CA".to_string(),
+            chamber: "house".to_string(),
+            issuer_name: ticker.to_string(),
+            issuer_ticker: ticker.to_string(),
+            asset_type: "stock".to_string(),
+            committees: Vec::new(),
+            labels: Vec::new(),
+            politician_id: "P000001".to_string(),
+            issuer_sector: sector.map(str::to_string),
+        }
+    }
+
+    fn event(date: &str, title: &str, ticker: Option<&str>, sector: Option<&str>) -> Event {
+        Event {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            title: title.to_string(),
+            tags: Vec::new(),
+            ticker: ticker.map(str::to_string),
+            sector: sector.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_trades_near_events_window_is_inclusive() {
+        let events = vec![event("2024-03-15", "Hearing", Some("AAPL"), None)];
+        let trades = vec![
+            // Exactly 14 days before: included
+            event_trade(1, "AAPL:US", None, "2024-03-01"),
+            // 15 days before: excluded
+            event_trade(2, "AAPL:US", None, "2024-02-29"),
+            // 14 days after: included
+            event_trade(3, "AAPL:US", None, "2024-03-29"),
+            // In window but a different ticker
+            event_trade(4, "MSFT:US", None, "2024-03-15"),
+        ];
+
+        let rows = trades_near_events(&trades, &events, 14);
+        let ids: Vec<(i64, i64)> = rows.iter().map(|r| (r.tx_id, r.day_offset)).collect();
+        assert_eq!(ids, vec![(1, -14), (3, 14)]);
+        assert!(rows.iter().all(|r| r.matched_on == "ticker"));
+    }
+
+    #[test]
+    fn test_trades_near_events_sector_and_multiple_events() {
+        let events = vec![
+            event("2024-03-10", "Health markup", None, Some("health-care")),
+            event("2024-03-20", "Drug pricing hearing", Some("PFE"), None),
+        ];
+        let trades = vec![
+            event_trade(1, "PFE:US", Some("health-care"), "2024-03-15"),
+            event_trade(2, "JNJ:US", Some("Health Care"), "2024-03-11"),
+            event_trade(3, "XOM:US", Some("energy"), "2024-03-11"),
+        ];
+
+        let rows = trades_near_events(&trades, &events, 7);
+        let pairs: Vec<(i64, &str, i64)> = rows
+            .iter()
+            .map(|r| (r.tx_id, r.event_title.as_str(), r.day_offset))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (2, "Health markup", 1),
+                (1, "Health markup", 5),
+                (1, "Drug pricing hearing", -5),
+            ]
+        );
+        assert_eq!(rows[0].matched_on, "sector");
+        assert_eq!(rows[2].matched_on, "ticker");
+    }
+
+    fn index_trade(date: &str, tx_type: &str, value: i64) -> IndexTrade {
+        IndexTrade {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            tx_type: tx_type.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_trading_index_normalizes_and_smooths() {
+        let trades = vec![
+            index_trade("2024-01-01", "buy", 100),
+            index_trade("2024-01-02", "sell", 50),
+            // 2024-01-03 has no trades but still fills the window.
+            index_trade("2024-01-04", "buy", 60),
+            index_trade("2024-01-05", "sell", 30),
+            index_trade("2024-01-06", "buy", 30),
+            index_trade("2024-01-06", "exchange", 1_000),
+        ];
+        let params = TradingIndexParams {
+            window_days: 3,
+            ema_span: Some(3),
+        };
+        let points = trading_index(&trades, None, &params);
+
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[2].date, "2024-01-03");
+        assert_eq!(points[2].net_value, 0);
+        // Bootstrap: no full window yet.
+        assert!(points[..3].iter().all(|p| p.raw.is_none() && p.smoothed.is_none()));
+
+        // 01-04: window [100, 50, 0] averages 50; net 60.
+        let raw: Vec<f64> = points[3..].iter().map(|p| p.raw.unwrap()).collect();
+        let expected_raw = [1.2, -30.0 / (110.0 / 3.0), 1.0];
+        // alpha = 0.5, seeded with the first raw value.
+        let expected_ema = [1.2, 0.5 * expected_raw[1] + 0.6, 0.5 + 0.25 * expected_raw[1] + 0.3];
+        for i in 0..3 {
+            assert!((raw[i] - expected_raw[i]).abs() < 1e-9, "raw {}", i);
+            let smoothed = points[3 + i].smoothed.unwrap();
+            assert!((smoothed - expected_ema[i]).abs() < 1e-9, "ema {}", i);
+        }
+        // Exchanges are neither buying nor selling.
+        assert_eq!(points[5].buy_value, 30);
+    }
+
+    #[test]
+    fn test_trading_index_quiet_window_is_null() {
+        let trades = vec![
+            index_trade("2024-01-01", "buy", 10),
+            index_trade("2024-01-06", "buy", 10),
+        ];
+        let params = TradingIndexParams {
+            window_days: 3,
+            ema_span: None,
+        };
+        let points = trading_index(&trades, None, &params);
+
+        assert_eq!(points.len(), 6);
+        // 01-04 sees [10, 0, 0]: zero net against a non-zero average.
+        assert_eq!(points[3].raw, Some(0.0));
+        // 01-05 and 01-06 see only quiet days.
+        assert_eq!(points[4].raw, None);
+        assert_eq!(points[5].raw, None);
+        assert!(points.iter().all(|p| p.smoothed.is_none()));
+
+        // An explicit start before the first trade extends the series.
+        let start = NaiveDate::from_ymd_opt(2023, 12, 30);
+        let points = trading_index(&trades, start, &params);
+        assert_eq!(points[0].date, "2023-12-30");
+        assert_eq!(points.len(), 8);
+    }
+
+    fn overlap_trade(politician_id: &str, issuer_id: i64, value: i64) -> OverlapTrade {
+        OverlapTrade {
+            politician_id: politician_id.to_string(),
+            politician_name: format!("Member {}", politician_id),
+            issuer_id,
+            value,
+        }
+    }
+
+    fn overlap_fixture() -> Vec<OverlapTrade> {
+        vec![
+            // P000002: issuer 1 twice (300), issuer 2 (400).
+            overlap_trade("P000002", 1, 100),
+            overlap_trade("P000002", 1, 200),
+            overlap_trade("P000002", 2, 400),
+            // P000001: issuers 1, 2 and 3 (300, 400, 1200).
+            overlap_trade("P000001", 1, 300),
+            overlap_trade("P000001", 2, 400),
+            overlap_trade("P000001", 3, 1200),
+            // P000003: only two trades.
+            overlap_trade("P000003", 1, 10),
+            overlap_trade("P000003", 2, 10),
+            // P000004: three trades in a single issuer.
+            overlap_trade("P000004", 3, 50),
+            overlap_trade("P000004", 3, 50),
+            overlap_trade("P000004", 3, 50),
+        ]
+    }
+
+    #[test]
+    fn test_politician_overlap_jaccard_and_cosine() {
+        let opts = OverlapOptions {
+            min_shared: 2,
+            min_trades: 3,
+        };
+        let pairs = politician_overlap(&overlap_fixture(), &opts);
+
+        // P000003 has too few trades and P000004 too few issuers.
+        assert_eq!(pairs.len(), 1);
+        let pair = &pairs[0];
+        // Emitted once, smaller ID first.
+        assert_eq!(pair.politician_a_id, "P000001");
+        assert_eq!(pair.politician_b_id, "P000002");
+        assert_eq!(pair.politician_b_name, "Member P000002");
+        assert_eq!(pair.shared_issuers, 2);
+        assert!((pair.jaccard - 2.0 / 3.0).abs() < 1e-9);
+        // (300*300 + 400*400) / (1300 * 500)
+        assert!((pair.cosine - 5.0 / 13.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_politician_overlap_pruning_threshold() {
+        let opts = OverlapOptions {
+            min_shared: 2,
+            min_trades: 2,
+        };
+        let pairs = politician_overlap(&overlap_fixture(), &opts);
+        let ids: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|p| (p.politician_a_id.as_str(), p.politician_b_id.as_str()))
+            .collect();
+        // Identical issuer sets rank first; ties fall back to IDs.
+        assert_eq!(
+            ids,
+            vec![
+                ("P000002", "P000003"),
+                ("P000001", "P000002"),
+                ("P000001", "P000003"),
+            ]
+        );
+        assert_eq!(pairs[0].jaccard, 1.0);
+        assert!((pairs[0].cosine - 7.0 / (5.0 * 2f64.sqrt())).abs() < 1e-9);
+        // P000004 never reaches two distinct issuers.
+        assert!(pairs.iter().all(|p| p.politician_b_id != "P000004"));
+
+        // Raising min_shared above every overlap leaves nothing.
+        let opts = OverlapOptions {
+            min_shared: 3,
+            min_trades: 1,
+        };
+        assert!(politician_overlap(&overlap_fixture(), &opts).is_empty());
+    }
 }
@@ -5,6 +5,13 @@
 
 use std::collections::{HashMap, VecDeque};
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::calendar::SessionStatus;
+use crate::db::AnalyticsTradeRow;
+
 /// Epsilon constant for floating-point comparisons (same as portfolio.rs).
 const EPSILON: f64 = 0.0001;
 
@@ -24,10 +31,18 @@ pub struct AnalyticsTrade {
 }
 
 /// A closed trade (matched buy-sell pair via FIFO).
+///
+/// Each value is one lot leg: a sell that consumes several buy lots yields
+/// one `ClosedTrade` per lot, and a lot split across several sells appears
+/// once per sell, with `shares` attributing exactly the portion matched.
 #[derive(Debug, Clone)]
 pub struct ClosedTrade {
     pub politician_id: String,
     pub ticker: String,
+    /// tx_id of the buy (or receive) whose lot this sale closed.
+    pub buy_tx_id: i64,
+    /// tx_id of the sell that closed (part of) the lot.
+    pub sell_tx_id: i64,
     pub shares: f64,
     pub buy_price: f64,
     pub sell_price: f64,
@@ -38,6 +53,48 @@ pub struct ClosedTrade {
     pub buy_has_sector: bool,
     pub sell_has_sector: bool,
     pub gics_sector: Option<String>,
+    /// Short- or long-term, from the buy and sell dates.
+    pub term: GainTerm,
+}
+
+/// Capital gains holding-period bucket of a closed trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GainTerm {
+    /// Held fewer than 365 days.
+    Short,
+    /// Held 365 days or more.
+    Long,
+    /// A buy or sell date could not be parsed.
+    Unknown,
+}
+
+impl GainTerm {
+    /// Classify a holding period between two `YYYY-MM-DD` dates.
+    pub fn from_dates(buy_date: &str, sell_date: &str) -> Self {
+        match holding_period_days(buy_date, sell_date) {
+            Some(days) if days < 365 => GainTerm::Short,
+            Some(_) => GainTerm::Long,
+            None => GainTerm::Unknown,
+        }
+    }
+}
+
+/// Marginal rates used for the approximate tax estimate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaxRates {
+    pub short_term: f64,
+    pub long_term: f64,
+}
+
+impl Default for TaxRates {
+    /// Top federal brackets: 37% ordinary income, 20% long-term capital gains.
+    fn default() -> Self {
+        Self {
+            short_term: 0.37,
+            long_term: 0.20,
+        }
+    }
 }
 
 /// Trade performance metrics computed from a ClosedTrade.
@@ -51,6 +108,9 @@ pub struct TradeMetrics {
     pub benchmark_return: Option<f64>,
     pub alpha: Option<f64>,
     pub benchmark_type: Option<String>,
+    /// Dollar gain (or loss) realized: (sell - buy) * shares.
+    pub realized_gain: f64,
+    pub term: GainTerm,
 }
 
 /// Aggregated performance metrics for a politician.
@@ -65,10 +125,135 @@ pub struct PoliticianMetrics {
     pub avg_alpha_sector: Option<f64>,
     pub avg_holding_days: Option<i64>,
     pub percentile_rank: f64,
+    /// Net realized dollar gain on trades held under 365 days (losses offset gains).
+    pub realized_short_term_gain: f64,
+    /// Net realized dollar gain on trades held 365 days or more.
+    pub realized_long_term_gain: f64,
+    /// Closed trades left out of the term split because a date was unparseable.
+    /// They still count toward the return metrics above.
+    pub unknown_term_trades: usize,
+    /// Approximate tax on the term buckets; set by [`apply_tax_estimate`].
+    pub estimated_tax: Option<f64>,
+    /// Standard error of `avg_return`. This and the interval fields below
+    /// are None below [`IntervalConfig::min_trades`].
+    pub return_std_error: Option<f64>,
+    /// 95% confidence interval for `avg_return`.
+    pub return_ci_low: Option<f64>,
+    pub return_ci_high: Option<f64>,
+    /// 95% Wilson score interval for `win_rate`, in percent.
+    pub win_rate_ci_low: Option<f64>,
+    pub win_rate_ci_high: Option<f64>,
+    /// Fraction of estimated volume traded while the member's chamber was in
+    /// recess, over trades the session calendar covers; set by
+    /// [`apply_recess_volume_share`].
+    pub recess_volume_share: Option<f64>,
+    /// Median holding period of the closed trades. This and the fields
+    /// below are set by [`apply_trading_style`].
+    pub median_holding_days: Option<i64>,
+    /// Closing sells per year of activity.
+    pub round_trips_per_year: Option<f64>,
+    /// Priced stock trades per year of activity.
+    pub trades_per_year: Option<f64>,
+    pub trading_style: TradingStyle,
+}
+
+/// z-score for a two-sided 95% interval.
+const Z_95: f64 = 1.959_963_984_540_054;
+
+/// How [`aggregate_politician_metrics`] builds confidence intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IntervalConfig {
+    /// Politicians with fewer closed trades get no intervals.
+    pub min_trades: usize,
+    /// Bootstrap resamples for the mean-return interval. Zero uses the
+    /// normal approximation (mean +/- 1.96 standard errors) instead.
+    pub bootstrap_iterations: usize,
+    /// Seed for the bootstrap, so the same data always gives the same interval.
+    pub seed: u64,
+}
+
+impl Default for IntervalConfig {
+    fn default() -> Self {
+        Self {
+            min_trades: 5,
+            bootstrap_iterations: 1000,
+            seed: 42,
+        }
+    }
+}
+
+/// Wilson score interval for `successes` out of `n` trials, as fractions.
+///
+/// Unlike the normal approximation it stays inside [0, 1] and is sensible
+/// for small samples and rates near 0% or 100%. None when `n` is zero.
+pub fn wilson_interval(successes: usize, n: usize, z: f64) -> Option<(f64, f64)> {
+    if n == 0 {
+        return None;
+    }
+    let n = n as f64;
+    let p = successes as f64 / n;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denom;
+    let margin = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denom;
+    Some(((center - margin).max(0.0), (center + margin).min(1.0)))
+}
+
+/// Standard error of the mean (sample standard deviation / sqrt(n)).
+/// None for fewer than two values.
+fn std_error(values: &[f64]) -> Option<f64> {
+    let n = values.len();
+    if n < 2 {
+        return None;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    Some((variance / n as f64).sqrt())
+}
+
+/// Percentile bootstrap 95% interval for the mean of `values`.
+fn bootstrap_mean_interval(values: &[f64], iterations: usize, rng: &mut StdRng) -> (f64, f64) {
+    let n = values.len();
+    let mut means: Vec<f64> = (0..iterations)
+        .map(|_| (0..n).map(|_| values[rng.gen_range(0..n)]).sum::<f64>() / n as f64)
+        .collect();
+    means.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let low = ((iterations as f64) * 0.025).floor() as usize;
+    let high = (((iterations as f64) * 0.975).ceil() as usize).saturating_sub(1);
+    (means[low.min(iterations - 1)], means[high.min(iterations - 1)])
+}
+
+/// Stable per-politician seed (FNV-1a), so bootstrap draws do not depend on
+/// the order politicians are processed in.
+fn politician_seed(seed: u64, politician_id: &str) -> u64 {
+    politician_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325 ^ seed, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        })
+}
+
+/// Sort descending by a confidence-interval lower bound, so small samples
+/// with wide intervals fall below consistent performers. Politicians without
+/// an interval sort last, ordered by the point estimate `fallback`.
+pub fn sort_by_lower_bound(
+    metrics: &mut [PoliticianMetrics],
+    lower_bound: impl Fn(&PoliticianMetrics) -> Option<f64>,
+    fallback: impl Fn(&PoliticianMetrics) -> f64,
+) {
+    metrics.sort_by(|a, b| match (lower_bound(a), lower_bound(b)) {
+        (Some(x), Some(y)) => y.partial_cmp(&x).unwrap_or(std::cmp::Ordering::Equal),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => fallback(b)
+            .partial_cmp(&fallback(a))
+            .unwrap_or(std::cmp::Ordering::Equal),
+    });
 }
 
 /// Internal position tracker for FIFO matching (extends portfolio.rs pattern).
 struct AnalyticsLot {
+    tx_id: i64,
     shares: f64,
     cost_basis: f64,
     tx_date: String,
@@ -96,35 +281,20 @@ impl AnalyticsPosition {
         }
     }
 
-    fn buy(
-        &mut self,
-        shares: f64,
-        price: f64,
-        tx_date: String,
-        benchmark_price: Option<f64>,
-        has_sector_benchmark: bool,
-        gics_sector: Option<String>,
-    ) {
+    fn buy(&mut self, trade: AnalyticsTrade) {
         self.lots.push_back(AnalyticsLot {
-            shares,
-            cost_basis: price,
-            tx_date,
-            benchmark_price,
-            has_sector_benchmark,
-            gics_sector,
+            tx_id: trade.tx_id,
+            shares: trade.estimated_shares,
+            cost_basis: trade.trade_date_price,
+            tx_date: trade.tx_date,
+            benchmark_price: trade.benchmark_price,
+            has_sector_benchmark: trade.has_sector_benchmark,
+            gics_sector: trade.gics_sector,
         });
     }
 
-    fn sell(
-        &mut self,
-        shares: f64,
-        price: f64,
-        tx_date: String,
-        benchmark_price: Option<f64>,
-        has_sector_benchmark: bool,
-        _gics_sector: Option<String>,
-    ) {
-        let mut remaining = shares;
+    fn sell(&mut self, trade: AnalyticsTrade) {
+        let mut remaining = trade.estimated_shares;
 
         while remaining > EPSILON {
             let lot = match self.lots.front_mut() {
@@ -146,16 +316,19 @@ impl AnalyticsPosition {
             self.closed_trades.push(ClosedTrade {
                 politician_id: self.politician_id.clone(),
                 ticker: self.ticker.clone(),
+                buy_tx_id: lot.tx_id,
+                sell_tx_id: trade.tx_id,
                 shares: shares_to_sell,
                 buy_price: lot.cost_basis,
-                sell_price: price,
+                sell_price: trade.trade_date_price,
                 buy_date: lot.tx_date.clone(),
-                sell_date: tx_date.clone(),
+                sell_date: trade.tx_date.clone(),
                 buy_benchmark: lot.benchmark_price,
-                sell_benchmark: benchmark_price,
+                sell_benchmark: trade.benchmark_price,
                 buy_has_sector: lot.has_sector_benchmark,
-                sell_has_sector: has_sector_benchmark,
+                sell_has_sector: trade.has_sector_benchmark,
                 gics_sector: lot.gics_sector.clone(),
+                term: GainTerm::from_dates(&lot.tx_date, &trade.tx_date),
             });
 
             lot.shares -= shares_to_sell;
@@ -183,24 +356,10 @@ pub fn calculate_closed_trades(trades: Vec<AnalyticsTrade>, verbose: bool) -> Ve
 
         match trade.tx_type.as_str() {
             "buy" | "receive" => {
-                position.buy(
-                    trade.estimated_shares,
-                    trade.trade_date_price,
-                    trade.tx_date,
-                    trade.benchmark_price,
-                    trade.has_sector_benchmark,
-                    trade.gics_sector.clone(),
-                );
+                position.buy(trade);
             }
             "sell" => {
-                position.sell(
-                    trade.estimated_shares,
-                    trade.trade_date_price,
-                    trade.tx_date,
-                    trade.benchmark_price,
-                    trade.has_sector_benchmark,
-                    trade.gics_sector.clone(),
-                );
+                position.sell(trade);
             }
             "exchange" => {
                 if verbose {
@@ -300,12 +459,18 @@ pub fn compute_trade_metrics(closed: &ClosedTrade) -> TradeMetrics {
         benchmark_return,
         alpha,
         benchmark_type,
+        realized_gain: (closed.sell_price - closed.buy_price) * closed.shares,
+        term: closed.term,
     }
 }
 
 /// Aggregate trade metrics by politician, computing summary statistics and percentile ranks.
-/// Returns politicians sorted by avg_return descending.
-pub fn aggregate_politician_metrics(metrics: &[TradeMetrics]) -> Vec<PoliticianMetrics> {
+/// Returns politicians sorted by avg_return descending. Confidence intervals
+/// on return and win rate are built as described by `intervals`.
+pub fn aggregate_politician_metrics(
+    metrics: &[TradeMetrics],
+    intervals: &IntervalConfig,
+) -> Vec<PoliticianMetrics> {
     let mut politician_map: HashMap<String, Vec<&TradeMetrics>> = HashMap::new();
 
     // Group by politician_id
@@ -372,6 +537,32 @@ pub fn aggregate_politician_metrics(metrics: &[TradeMetrics]) -> Vec<PoliticianM
                 None
             };
 
+            let returns: Vec<f64> = trades.iter().map(|t| t.absolute_return).collect();
+            let enough = total_trades >= intervals.min_trades.max(2);
+            let return_std_error = if enough { std_error(&returns) } else { None };
+            let return_ci = return_std_error.map(|se| {
+                if intervals.bootstrap_iterations > 0 {
+                    let mut rng =
+                        StdRng::seed_from_u64(politician_seed(intervals.seed, &politician_id));
+                    bootstrap_mean_interval(&returns, intervals.bootstrap_iterations, &mut rng)
+                } else {
+                    (avg_return - Z_95 * se, avg_return + Z_95 * se)
+                }
+            });
+            let win_rate_ci = if enough {
+                wilson_interval(win_count, total_trades, Z_95)
+            } else {
+                None
+            };
+
+            let term_gain = |term: GainTerm| -> f64 {
+                trades
+                    .iter()
+                    .filter(|t| t.term == term)
+                    .map(|t| t.realized_gain)
+                    .sum()
+            };
+
             PoliticianMetrics {
                 politician_id,
                 total_trades,
@@ -382,6 +573,23 @@ pub fn aggregate_politician_metrics(metrics: &[TradeMetrics]) -> Vec<PoliticianM
                 avg_alpha_sector,
                 avg_holding_days,
                 percentile_rank: 0.0, // Computed after sorting
+                realized_short_term_gain: term_gain(GainTerm::Short),
+                realized_long_term_gain: term_gain(GainTerm::Long),
+                unknown_term_trades: trades
+                    .iter()
+                    .filter(|t| t.term == GainTerm::Unknown)
+                    .count(),
+                estimated_tax: None,
+                return_std_error,
+                return_ci_low: return_ci.map(|(low, _)| low),
+                return_ci_high: return_ci.map(|(_, high)| high),
+                win_rate_ci_low: win_rate_ci.map(|(low, _)| low * 100.0),
+                win_rate_ci_high: win_rate_ci.map(|(_, high)| high * 100.0),
+                recess_volume_share: None,
+                median_holding_days: None,
+                round_trips_per_year: None,
+                trades_per_year: None,
+                trading_style: TradingStyle::Unclassified,
             }
         })
         .collect();
@@ -407,6 +615,354 @@ pub fn aggregate_politician_metrics(metrics: &[TradeMetrics]) -> Vec<PoliticianM
     politician_metrics
 }
 
+/// Fill in `estimated_tax` for each politician at the given rates.
+///
+/// This is a rough figure, not a tax calculation: losses offset gains only
+/// within their own term bucket (no cross-term netting, carryforwards or
+/// $3,000 ordinary-income deduction), a net loss in a bucket contributes
+/// zero, and trades with an unknown term are left out.
+pub fn apply_tax_estimate(metrics: &mut [PoliticianMetrics], rates: TaxRates) {
+    for pm in metrics {
+        pm.estimated_tax = Some(
+            pm.realized_short_term_gain.max(0.0) * rates.short_term
+                + pm.realized_long_term_gain.max(0.0) * rates.long_term,
+        );
+    }
+}
+
+/// Estimated dollar volume of an analytics row.
+fn row_volume(row: &AnalyticsTradeRow) -> f64 {
+    row.estimated_shares * row.trade_date_price
+}
+
+/// Fill in `recess_volume_share` from rows tagged by
+/// [`crate::analysis::tag_session_status`]. Politicians with no covered
+/// trades keep None.
+pub fn apply_recess_volume_share(metrics: &mut [PoliticianMetrics], rows: &[AnalyticsTradeRow]) {
+    // (recess volume, covered volume) per politician
+    let mut volumes: HashMap<&str, (f64, f64)> = HashMap::new();
+    for row in rows {
+        if row.session_status == SessionStatus::Unknown {
+            continue;
+        }
+        let entry = volumes.entry(row.politician_id.as_str()).or_default();
+        if row.session_status == SessionStatus::Recess {
+            entry.0 += row_volume(row);
+        }
+        entry.1 += row_volume(row);
+    }
+    for pm in metrics {
+        pm.recess_volume_share = volumes
+            .get(pm.politician_id.as_str())
+            .filter(|(_, covered)| *covered > 0.0)
+            .map(|(recess, covered)| recess / covered);
+    }
+}
+
+/// Coarse trading-style label, from [`classify_trading_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TradingStyle {
+    /// Long median holding period and low turnover.
+    BuyAndHold,
+    /// Short holding periods, high turnover or frequent round trips.
+    ActiveTrader,
+    /// A large share of disclosed trades are in options.
+    OptionsHeavy,
+    /// Too few trades per year to call a style.
+    Inactive,
+    /// Enough history, but no single rule applies.
+    Mixed,
+    /// Not enough closed trades to classify.
+    #[default]
+    Unclassified,
+}
+
+impl TradingStyle {
+    pub const ALL: [TradingStyle; 6] = [
+        TradingStyle::BuyAndHold,
+        TradingStyle::ActiveTrader,
+        TradingStyle::OptionsHeavy,
+        TradingStyle::Inactive,
+        TradingStyle::Mixed,
+        TradingStyle::Unclassified,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradingStyle::BuyAndHold => "buy_and_hold",
+            TradingStyle::ActiveTrader => "active_trader",
+            TradingStyle::OptionsHeavy => "options_heavy",
+            TradingStyle::Inactive => "inactive",
+            TradingStyle::Mixed => "mixed",
+            TradingStyle::Unclassified => "unclassified",
+        }
+    }
+
+    /// Parse a label, accepting `-` or `_` separators in any case.
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_lowercase().replace('-', "_");
+        Self::ALL.into_iter().find(|s| s.as_str() == normalized)
+    }
+}
+
+impl std::fmt::Display for TradingStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Thresholds for [`classify_trading_style`]. Rules are checked in field
+/// order: options share, inactivity, sample size, active, buy-and-hold; a
+/// member matching none is [`TradingStyle::Mixed`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradingStyleConfig {
+    /// Option trades as a fraction of all disclosed trades at or above this
+    /// make a member options-heavy.
+    pub options_heavy_min_share: f64,
+    /// Fewer priced stock trades per year than this is inactive.
+    pub inactive_max_trades_per_year: f64,
+    /// Members with fewer closed trades are unclassified.
+    pub min_closed_trades: usize,
+    /// A median holding period at or below this many days is active...
+    pub active_max_median_days: i64,
+    /// ...as is annual turnover at or above this multiple...
+    pub active_min_turnover: f64,
+    /// ...or at least this many round trips per year.
+    pub active_min_round_trips_per_year: f64,
+    /// A median holding period at or above this many days is buy-and-hold,
+    /// provided turnover is at or below `buy_and_hold_max_turnover`.
+    pub buy_and_hold_min_median_days: i64,
+    pub buy_and_hold_max_turnover: f64,
+}
+
+impl Default for TradingStyleConfig {
+    fn default() -> Self {
+        Self {
+            options_heavy_min_share: 0.25,
+            inactive_max_trades_per_year: 2.0,
+            min_closed_trades: 5,
+            active_max_median_days: 90,
+            active_min_turnover: 2.0,
+            active_min_round_trips_per_year: 12.0,
+            buy_and_hold_min_median_days: 365,
+            buy_and_hold_max_turnover: 0.5,
+        }
+    }
+}
+
+/// Label a member from their closed-trade history (`median_holding_days`,
+/// `round_trips_per_year`, `trades_per_year` and `total_trades` on
+/// `metrics`), their option-trade share and annual turnover (volume traded
+/// over average book value). Missing inputs never satisfy a rule.
+pub fn classify_trading_style(
+    metrics: &PoliticianMetrics,
+    option_share: Option<f64>,
+    turnover: Option<f64>,
+    config: &TradingStyleConfig,
+) -> TradingStyle {
+    if option_share.is_some_and(|share| share >= config.options_heavy_min_share) {
+        return TradingStyle::OptionsHeavy;
+    }
+    if metrics
+        .trades_per_year
+        .is_some_and(|rate| rate < config.inactive_max_trades_per_year)
+    {
+        return TradingStyle::Inactive;
+    }
+    let Some(median_days) = metrics.median_holding_days else {
+        return TradingStyle::Unclassified;
+    };
+    if metrics.total_trades < config.min_closed_trades {
+        return TradingStyle::Unclassified;
+    }
+    if median_days <= config.active_max_median_days
+        || turnover.is_some_and(|t| t >= config.active_min_turnover)
+        || metrics
+            .round_trips_per_year
+            .is_some_and(|r| r >= config.active_min_round_trips_per_year)
+    {
+        return TradingStyle::ActiveTrader;
+    }
+    if median_days >= config.buy_and_hold_min_median_days
+        && turnover.is_none_or(|t| t <= config.buy_and_hold_max_turnover)
+    {
+        return TradingStyle::BuyAndHold;
+    }
+    TradingStyle::Mixed
+}
+
+/// Years between two `YYYY-MM-DD` dates, floored at one so a short history
+/// does not inflate per-year rates.
+fn activity_years(first: &str, last: &str) -> Option<f64> {
+    holding_period_days(first, last).map(|days| (days as f64).max(365.25) / 365.25)
+}
+
+/// Annual turnover of one member's rows (in trade order): volume traded per
+/// year over the average cost basis held after each trade. Sells reduce the
+/// book at average cost. None when nothing was ever held.
+fn annual_turnover(rows: &[&AnalyticsTradeRow], years: f64) -> Option<f64> {
+    // ticker -> (shares, cost basis)
+    let mut book: HashMap<&str, (f64, f64)> = HashMap::new();
+    let mut volume = 0.0;
+    let mut book_total = 0.0;
+    for row in rows {
+        volume += row_volume(row);
+        let (shares, cost) = book.entry(row.issuer_ticker.as_str()).or_default();
+        match row.tx_type.as_str() {
+            "buy" | "receive" => {
+                *shares += row.estimated_shares;
+                *cost += row_volume(row);
+            }
+            "sell" if *shares > EPSILON => {
+                let sold = row.estimated_shares.min(*shares);
+                *cost -= *cost * sold / *shares;
+                *shares -= sold;
+            }
+            _ => {}
+        }
+        book_total += book.values().map(|(_, cost)| cost).sum::<f64>();
+    }
+    let avg_book = book_total / rows.len() as f64;
+    (avg_book > EPSILON).then(|| volume / years / avg_book)
+}
+
+/// Fill in the trading-style inputs and label for each politician.
+///
+/// `rows` are the priced stock trades in scope (in trade order, as
+/// [`crate::db::Db::query_trades_for_analytics`] returns them) and `closed`
+/// the FIFO legs closed over the same span. `option_counts` maps politician
+/// id to (option trades, all trades). Activity is measured from a member's
+/// first trade to the latest trade in `rows`, so members who stopped
+/// trading read as inactive.
+pub fn apply_trading_style(
+    metrics: &mut [PoliticianMetrics],
+    rows: &[AnalyticsTradeRow],
+    closed: &[ClosedTrade],
+    option_counts: &HashMap<String, (usize, usize)>,
+    config: &TradingStyleConfig,
+) {
+    let Some(latest) = rows.iter().map(|r| r.tx_date.as_str()).max() else {
+        for pm in metrics {
+            pm.trading_style = TradingStyle::Unclassified;
+        }
+        return;
+    };
+    let mut by_politician: HashMap<&str, Vec<&AnalyticsTradeRow>> = HashMap::new();
+    for row in rows {
+        by_politician.entry(row.politician_id.as_str()).or_default().push(row);
+    }
+
+    for pm in metrics {
+        let mut holds: Vec<i64> = Vec::new();
+        let mut sells: Vec<i64> = Vec::new();
+        for leg in closed.iter().filter(|c| c.politician_id == pm.politician_id) {
+            holds.extend(holding_period_days(&leg.buy_date, &leg.sell_date));
+            sells.push(leg.sell_tx_id);
+        }
+        sells.sort_unstable();
+        sells.dedup();
+        holds.sort_unstable();
+        pm.median_holding_days = match holds.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(holds[n / 2]),
+            n => Some((holds[n / 2 - 1] + holds[n / 2]) / 2),
+        };
+
+        let own = by_politician.get(pm.politician_id.as_str());
+        let years = own
+            .and_then(|r| r.iter().map(|r| r.tx_date.as_str()).min())
+            .and_then(|first| activity_years(first, latest));
+        pm.trades_per_year = years.map(|y| own.map_or(0, |r| r.len()) as f64 / y);
+        pm.round_trips_per_year = years.map(|y| sells.len() as f64 / y);
+        let turnover = own.zip(years).and_then(|(r, y)| annual_turnover(r, y));
+        let option_share = option_counts
+            .get(&pm.politician_id)
+            .filter(|(_, total)| *total > 0)
+            .map(|(options, total)| *options as f64 / *total as f64);
+        pm.trading_style = classify_trading_style(pm, option_share, turnover, config);
+    }
+}
+
+/// Trading activity for one chamber and session status, from
+/// [`group_by_session`].
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SessionGroupRow {
+    pub chamber: String,
+    pub session_status: SessionStatus,
+    pub trades: usize,
+    pub politicians: usize,
+    /// Estimated volume (shares x trade-date price).
+    pub volume: f64,
+    /// Fraction of the chamber's volume, Unknown included.
+    pub volume_share: f64,
+    /// Closed lot legs whose buy fell in this status.
+    pub closed_trades: usize,
+    /// Mean return of those legs, in percent.
+    pub avg_return: Option<f64>,
+}
+
+/// Group tagged analytics rows by chamber and session status. Closed trades
+/// are attributed to the status on the day their buy was placed; legs whose
+/// buy is not among `rows` are left out.
+pub fn group_by_session(rows: &[AnalyticsTradeRow], closed: &[ClosedTrade]) -> Vec<SessionGroupRow> {
+    struct Acc<'a> {
+        trades: usize,
+        politicians: std::collections::HashSet<&'a str>,
+        volume: f64,
+        returns: Vec<f64>,
+    }
+
+    let mut groups: std::collections::BTreeMap<(&str, SessionStatus), Acc> =
+        std::collections::BTreeMap::new();
+    let mut buy_groups: HashMap<i64, (&str, SessionStatus)> = HashMap::new();
+    for row in rows {
+        let key = (row.chamber.as_str(), row.session_status);
+        let acc = groups.entry(key).or_insert_with(|| Acc {
+            trades: 0,
+            politicians: Default::default(),
+            volume: 0.0,
+            returns: Vec::new(),
+        });
+        acc.trades += 1;
+        acc.politicians.insert(row.politician_id.as_str());
+        acc.volume += row_volume(row);
+        buy_groups.insert(row.tx_id, key);
+    }
+    for trade in closed {
+        if let Some(acc) = buy_groups
+            .get(&trade.buy_tx_id)
+            .and_then(|key| groups.get_mut(key))
+        {
+            acc.returns.push(compute_trade_metrics(trade).absolute_return);
+        }
+    }
+
+    let mut chamber_volume: HashMap<&str, f64> = HashMap::new();
+    for ((chamber, _), acc) in &groups {
+        *chamber_volume.entry(chamber).or_default() += acc.volume;
+    }
+    groups
+        .into_iter()
+        .map(|((chamber, session_status), acc)| {
+            let total = chamber_volume.get(chamber).copied().unwrap_or(0.0);
+            SessionGroupRow {
+                chamber: chamber.to_string(),
+                session_status,
+                trades: acc.trades,
+                politicians: acc.politicians.len(),
+                volume: acc.volume,
+                volume_share: if total > 0.0 { acc.volume / total } else { 0.0 },
+                closed_trades: acc.returns.len(),
+                avg_return: (!acc.returns.is_empty())
+                    .then(|| acc.returns.iter().sum::<f64>() / acc.returns.len() as f64),
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,6 +991,8 @@ mod tests {
         let closed = ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "AAPL".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 50.0,
             sell_price: 75.0,
@@ -445,6 +1003,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: false,
             gics_sector: None,
+            term: GainTerm::Short,
         };
         assert_eq!(closed.shares, 100.0);
     }
@@ -541,6 +1100,57 @@ mod tests {
         assert_eq!(closed[1].sell_price, 80.0);
     }
 
+    #[test]
+    fn test_one_buy_split_across_three_sells() {
+        let trade = |tx_id: i64, tx_type: &str, tx_date: &str, shares: f64, price: f64| {
+            AnalyticsTrade {
+                tx_id,
+                politician_id: "P000001".to_string(),
+                ticker: "AAPL".to_string(),
+                tx_type: tx_type.to_string(),
+                tx_date: tx_date.to_string(),
+                estimated_shares: shares,
+                trade_date_price: price,
+                benchmark_price: None,
+                has_sector_benchmark: false,
+                gics_sector: None,
+            }
+        };
+        let trades = vec![
+            trade(1, "buy", "2024-01-01", 100.0, 40.0),
+            trade(2, "buy", "2024-01-15", 20.0, 45.0),
+            trade(3, "sell", "2024-03-01", 33.3, 50.0),
+            trade(4, "sell", "2024-04-01", 33.3, 55.0),
+            // Takes the rest of lot 1 and part of lot 2.
+            trade(5, "sell", "2024-05-01", 43.4, 60.0),
+        ];
+
+        let closed = calculate_closed_trades(trades, false);
+        let legs: Vec<(i64, i64, f64)> = closed
+            .iter()
+            .map(|c| (c.buy_tx_id, c.sell_tx_id, c.sell_price))
+            .collect();
+        assert_eq!(
+            legs,
+            vec![(1, 3, 50.0), (1, 4, 55.0), (1, 5, 60.0), (2, 5, 60.0)]
+        );
+
+        let lot1: f64 = closed
+            .iter()
+            .filter(|c| c.buy_tx_id == 1)
+            .map(|c| c.shares)
+            .sum();
+        assert_eq!(lot1, 100.0);
+        let sell5: f64 = closed
+            .iter()
+            .filter(|c| c.sell_tx_id == 5)
+            .map(|c| c.shares)
+            .sum();
+        assert!((sell5 - 43.4).abs() < EPSILON);
+        assert!((closed[3].shares - 10.0).abs() < EPSILON);
+        assert!(closed.iter().all(|c| c.buy_price == if c.buy_tx_id == 1 { 40.0 } else { 45.0 }));
+    }
+
     #[test]
     fn test_losing_trade() {
         let trades = vec![
@@ -773,6 +1383,8 @@ mod tests {
         let closed = ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "AAPL".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 50.0,
             sell_price: 75.0,
@@ -783,6 +1395,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: false,
             gics_sector: None,
+            term: GainTerm::Short,
         };
 
         let metrics = compute_trade_metrics(&closed);
@@ -805,6 +1418,8 @@ mod tests {
         let closed = ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "AAPL".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 50.0,
             sell_price: 75.0,
@@ -815,6 +1430,7 @@ mod tests {
             buy_has_sector: true,
             sell_has_sector: true,
             gics_sector: None,
+            term: GainTerm::Short,
         };
 
         let metrics = compute_trade_metrics(&closed);
@@ -829,6 +1445,8 @@ mod tests {
         let closed = ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "AAPL".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 50.0,
             sell_price: 75.0,
@@ -839,6 +1457,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: false,
             gics_sector: None,
+            term: GainTerm::Short,
         };
 
         let metrics = compute_trade_metrics(&closed);
@@ -852,6 +1471,8 @@ mod tests {
         let closed = ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "AAPL".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 50.0,
             sell_price: 75.0,
@@ -862,6 +1483,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: true, // Mixed: buy SPY, sell sector
             gics_sector: None,
+            term: GainTerm::Short,
         };
 
         let metrics = compute_trade_metrics(&closed);
@@ -881,6 +1503,8 @@ mod tests {
                 benchmark_return: Some(10.0),
                 alpha: Some(40.0),
                 benchmark_type: Some("spy".to_string()),
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
             TradeMetrics {
                 politician_id: "P000001".to_string(),
@@ -891,10 +1515,12 @@ mod tests {
                 benchmark_return: Some(5.0),
                 alpha: Some(-15.0),
                 benchmark_type: Some("spy".to_string()),
+                realized_gain: 0.0,
+                term: GainTerm::Long,
             },
         ];
 
-        let result = aggregate_politician_metrics(&metrics);
+        let result = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
         assert_eq!(result.len(), 1);
         let pol = &result[0];
         assert_eq!(pol.politician_id, "P000001");
@@ -908,6 +1534,115 @@ mod tests {
         assert!((pol.percentile_rank - 1.0).abs() < 0.01); // Only one politician -> 1.0
     }
 
+    fn return_metric(politician_id: &str, absolute_return: f64) -> TradeMetrics {
+        TradeMetrics {
+            politician_id: politician_id.to_string(),
+            ticker: "AAPL".to_string(),
+            absolute_return,
+            holding_days: Some(30),
+            annualized_return: None,
+            benchmark_return: None,
+            alpha: None,
+            benchmark_type: None,
+            realized_gain: 0.0,
+            term: GainTerm::Short,
+        }
+    }
+
+    #[test]
+    fn test_wilson_interval_known_values() {
+        // 8/10 at z = 1.96: the textbook (0.4902, 0.9433).
+        let (low, high) = wilson_interval(8, 10, 1.96).unwrap();
+        assert!((low - 0.4902).abs() < 1e-4, "low = {}", low);
+        assert!((high - 0.9433).abs() < 1e-4, "high = {}", high);
+
+        // 0/10 stays inside [0, 1] where the normal approximation collapses.
+        let (low, high) = wilson_interval(0, 10, 1.96).unwrap();
+        assert_eq!(low, 0.0);
+        assert!((high - 0.2775).abs() < 1e-4, "high = {}", high);
+
+        // 50/100 is symmetric around one half.
+        let (low, high) = wilson_interval(50, 100, 1.96).unwrap();
+        assert!((low - 0.4038).abs() < 1e-4 && (high - 0.5962).abs() < 1e-4);
+
+        assert_eq!(wilson_interval(0, 0, 1.96), None);
+    }
+
+    #[test]
+    fn test_aggregate_intervals_respect_min_trades_and_seed() {
+        let mut metrics: Vec<TradeMetrics> = [10.0, 12.0, 8.0, 11.0, 9.0]
+            .iter()
+            .map(|&r| return_metric("P000001", r))
+            .collect();
+        metrics.push(return_metric("P000002", 40.0));
+        metrics.push(return_metric("P000002", 60.0));
+
+        let config = IntervalConfig::default();
+        let result = aggregate_politician_metrics(&metrics, &config);
+        let steady = result.iter().find(|p| p.politician_id == "P000001").unwrap();
+        let lucky = result.iter().find(|p| p.politician_id == "P000002").unwrap();
+
+        // sd = sqrt(2.5), se = sqrt(2.5 / 5) ~ 0.7071
+        assert!((steady.return_std_error.unwrap() - 0.5f64.sqrt()).abs() < 1e-9);
+        let (low, high) = (steady.return_ci_low.unwrap(), steady.return_ci_high.unwrap());
+        assert!(low < 10.0 && 10.0 < high && low >= 8.0 && high <= 12.0);
+        assert!((steady.win_rate_ci_high.unwrap() - 100.0).abs() < 1e-9);
+        assert!(steady.win_rate_ci_low.unwrap() < 100.0);
+
+        // Below min_trades: no interval at all.
+        assert_eq!(lucky.return_std_error, None);
+        assert_eq!(lucky.return_ci_low, None);
+        assert_eq!(lucky.win_rate_ci_low, None);
+
+        // Same seed, same interval; the analytic fallback is mean +/- 1.96 se.
+        let again = aggregate_politician_metrics(&metrics, &config);
+        let steady_again = again.iter().find(|p| p.politician_id == "P000001").unwrap();
+        assert_eq!(steady_again.return_ci_low, steady.return_ci_low);
+        assert_eq!(steady_again.return_ci_high, steady.return_ci_high);
+
+        let analytic = aggregate_politician_metrics(
+            &metrics,
+            &IntervalConfig {
+                bootstrap_iterations: 0,
+                ..config
+            },
+        );
+        let steady_analytic = analytic.iter().find(|p| p.politician_id == "P000001").unwrap();
+        let se = 0.5f64.sqrt();
+        assert!((steady_analytic.return_ci_low.unwrap() - (10.0 - Z_95 * se)).abs() < 1e-9);
+        assert!((steady_analytic.return_ci_high.unwrap() - (10.0 + Z_95 * se)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lower_bound_ranking_differs_from_point_estimate() {
+        // P000001: three lucky trades with a high but noisy mean.
+        // P000002: many modest, consistent gains.
+        // P000003: two trades only, so no interval; it must sort last.
+        let mut metrics: Vec<TradeMetrics> = [90.0, -40.0, 70.0]
+            .iter()
+            .map(|&r| return_metric("P000001", r))
+            .collect();
+        metrics.extend(
+            [8.0, 10.0, 12.0, 9.0, 11.0, 10.0, 9.5, 10.5]
+                .iter()
+                .map(|&r| return_metric("P000002", r)),
+        );
+        metrics.push(return_metric("P000003", 200.0));
+        metrics.push(return_metric("P000003", 300.0));
+
+        let config = IntervalConfig {
+            min_trades: 3,
+            ..IntervalConfig::default()
+        };
+        let mut result = aggregate_politician_metrics(&metrics, &config);
+        let point_order: Vec<&str> = result.iter().map(|p| p.politician_id.as_str()).collect();
+        assert_eq!(point_order, vec!["P000003", "P000001", "P000002"]);
+
+        sort_by_lower_bound(&mut result, |p| p.return_ci_low, |p| p.avg_return);
+        let bound_order: Vec<&str> = result.iter().map(|p| p.politician_id.as_str()).collect();
+        assert_eq!(bound_order, vec!["P000002", "P000001", "P000003"]);
+    }
+
     #[test]
     fn test_aggregate_multiple_politicians() {
         let metrics = vec![
@@ -920,6 +1655,8 @@ mod tests {
                 benchmark_return: None,
                 alpha: None,
                 benchmark_type: None,
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
             TradeMetrics {
                 politician_id: "P000002".to_string(),
@@ -930,6 +1667,8 @@ mod tests {
                 benchmark_return: None,
                 alpha: None,
                 benchmark_type: None,
+                realized_gain: 0.0,
+                term: GainTerm::Long,
             },
             TradeMetrics {
                 politician_id: "P000003".to_string(),
@@ -940,10 +1679,12 @@ mod tests {
                 benchmark_return: None,
                 alpha: None,
                 benchmark_type: None,
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
         ];
 
-        let result = aggregate_politician_metrics(&metrics);
+        let result = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
         assert_eq!(result.len(), 3);
         // Should be sorted by avg_return descending
         assert_eq!(result[0].politician_id, "P000001"); // 50%
@@ -984,10 +1725,12 @@ mod tests {
                 benchmark_return: Some(20.0),
                 alpha: Some(30.0),
                 benchmark_type: Some("sector".to_string()),
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
         ];
 
-        let result = aggregate_politician_metrics(&metrics);
+        let result = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].avg_alpha_spy, None);
         assert!((result[0].avg_alpha_sector.unwrap() - 30.0).abs() < 0.01);
@@ -1005,6 +1748,8 @@ mod tests {
                 benchmark_return: Some(10.0),
                 alpha: Some(40.0),
                 benchmark_type: Some("spy".to_string()),
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
             TradeMetrics {
                 politician_id: "P000001".to_string(),
@@ -1015,10 +1760,12 @@ mod tests {
                 benchmark_return: Some(15.0),
                 alpha: Some(15.0),
                 benchmark_type: Some("sector".to_string()),
+                realized_gain: 0.0,
+                term: GainTerm::Long,
             },
         ];
 
-        let result = aggregate_politician_metrics(&metrics);
+        let result = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
         assert_eq!(result.len(), 1);
         assert!((result[0].avg_alpha_spy.unwrap() - 40.0).abs() < 0.01); // Only one SPY trade
         assert!((result[0].avg_alpha_sector.unwrap() - 15.0).abs() < 0.01); // Only one sector trade
@@ -1036,6 +1783,8 @@ mod tests {
                 benchmark_return: None,
                 alpha: None,
                 benchmark_type: None,
+                realized_gain: 0.0,
+                term: GainTerm::Short,
             },
             TradeMetrics {
                 politician_id: "P000001".to_string(),
@@ -1046,12 +1795,309 @@ mod tests {
                 benchmark_return: None,
                 alpha: None,
                 benchmark_type: None,
+                realized_gain: 0.0,
+                term: GainTerm::Unknown,
             },
         ];
 
-        let result = aggregate_politician_metrics(&metrics);
+        let result = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].total_trades, 2);
         assert_eq!(result[0].avg_holding_days, Some(182)); // Only one valid holding_days
     }
+
+    fn dated_trade(tx_id: i64, ticker: &str, tx_type: &str, tx_date: &str, shares: f64, price: f64) -> AnalyticsTrade {
+        AnalyticsTrade {
+            tx_id,
+            politician_id: "P000001".to_string(),
+            ticker: ticker.to_string(),
+            tx_type: tx_type.to_string(),
+            tx_date: tx_date.to_string(),
+            estimated_shares: shares,
+            trade_date_price: price,
+            benchmark_price: None,
+            has_sector_benchmark: false,
+            gics_sector: None,
+        }
+    }
+
+    #[test]
+    fn test_gain_term_365_day_boundary() {
+        assert_eq!(GainTerm::from_dates("2023-01-01", "2023-12-31"), GainTerm::Short); // 364 days
+        assert_eq!(GainTerm::from_dates("2023-01-01", "2024-01-01"), GainTerm::Long); // 365 days
+        assert_eq!(GainTerm::from_dates("2023-01-01", "not-a-date"), GainTerm::Unknown);
+    }
+
+    #[test]
+    fn test_tax_estimate_splits_terms_and_offsets_within_bucket() {
+        let trades = vec![
+            // One lot sold in two pieces straddling the boundary.
+            dated_trade(1, "AAPL", "buy", "2023-01-01", 10.0, 100.0),
+            dated_trade(2, "AAPL", "sell", "2023-12-31", 4.0, 150.0), // short: +200
+            dated_trade(3, "AAPL", "sell", "2024-01-01", 6.0, 90.0),  // long: -60
+            dated_trade(4, "MSFT", "buy", "2023-01-02", 5.0, 50.0),
+            dated_trade(5, "MSFT", "sell", "2024-01-02", 5.0, 70.0), // long: +100
+            dated_trade(6, "TSLA", "buy", "2024-03-01", 10.0, 20.0),
+            dated_trade(7, "TSLA", "sell", "2024-03-10", 10.0, 15.0), // short: -50
+            dated_trade(8, "NVDA", "buy", "2024-02-30", 1.0, 100.0),
+            dated_trade(9, "NVDA", "sell", "2024-06-01", 1.0, 1100.0), // unknown: +1000
+        ];
+
+        let closed = calculate_closed_trades(trades, false);
+        let aapl_terms: Vec<GainTerm> = closed
+            .iter()
+            .filter(|c| c.ticker == "AAPL")
+            .map(|c| c.term)
+            .collect();
+        assert_eq!(aapl_terms, vec![GainTerm::Short, GainTerm::Long]);
+
+        let metrics: Vec<TradeMetrics> = closed.iter().map(compute_trade_metrics).collect();
+        let mut politicians = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
+        assert_eq!(politicians.len(), 1);
+        let pm = &politicians[0];
+        assert_eq!(pm.total_trades, 5, "unknown-term trade still counts toward P&L");
+        assert!((pm.realized_short_term_gain - 150.0).abs() < 0.01);
+        assert!((pm.realized_long_term_gain - 40.0).abs() < 0.01);
+        assert_eq!(pm.unknown_term_trades, 1);
+        assert_eq!(pm.estimated_tax, None);
+
+        apply_tax_estimate(&mut politicians, TaxRates::default());
+        // 150 * 0.37 + 40 * 0.20
+        assert!((politicians[0].estimated_tax.unwrap() - 63.5).abs() < 0.01);
+
+        // A net loss in one bucket does not reduce tax owed on the other.
+        politicians[0].realized_long_term_gain = -500.0;
+        apply_tax_estimate(
+            &mut politicians,
+            TaxRates {
+                short_term: 0.5,
+                long_term: 0.1,
+            },
+        );
+        assert!((politicians[0].estimated_tax.unwrap() - 75.0).abs() < 0.01);
+    }
+
+    fn session_row(
+        tx_id: i64,
+        politician_id: &str,
+        chamber: &str,
+        tx: (&str, &str),
+        shares: f64,
+        price: f64,
+    ) -> AnalyticsTradeRow {
+        AnalyticsTradeRow {
+            tx_id,
+            politician_id: politician_id.to_string(),
+            issuer_ticker: "AAPL".to_string(),
+            tx_type: tx.0.to_string(),
+            tx_date: tx.1.to_string(),
+            estimated_shares: shares,
+            trade_date_price: price,
+            benchmark_price: None,
+            gics_sector: None,
+            chamber: chamber.to_string(),
+            session_status: SessionStatus::Unknown,
+        }
+    }
+
+    #[test]
+    fn test_recess_share_and_session_groups() {
+        let calendar = crate::calendar::SessionCalendar::bundled().unwrap();
+        let mut rows = vec![
+            session_row(1, "P000001", "house", ("buy", "2024-03-06"), 10.0, 100.0),
+            session_row(2, "P000001", "house", ("sell", "2024-08-15"), 10.0, 120.0),
+            // The Senate was still sitting when the House left for August.
+            session_row(3, "P000002", "senate", ("buy", "2024-07-30"), 5.0, 100.0),
+            session_row(4, "P000002", "senate", ("buy", "2019-06-03"), 5.0, 100.0),
+        ];
+        crate::analysis::tag_session_status(&mut rows, &calendar);
+        let statuses: Vec<SessionStatus> = rows.iter().map(|r| r.session_status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                SessionStatus::InSession,
+                SessionStatus::Recess,
+                SessionStatus::InSession,
+                SessionStatus::Unknown,
+            ]
+        );
+
+        let trades: Vec<AnalyticsTrade> = rows
+            .iter()
+            .map(|r| AnalyticsTrade {
+                tx_id: r.tx_id,
+                politician_id: r.politician_id.clone(),
+                ticker: r.issuer_ticker.clone(),
+                tx_type: r.tx_type.clone(),
+                tx_date: r.tx_date.clone(),
+                estimated_shares: r.estimated_shares,
+                trade_date_price: r.trade_date_price,
+                benchmark_price: None,
+                has_sector_benchmark: false,
+                gics_sector: None,
+            })
+            .collect();
+        let closed = calculate_closed_trades(trades, false);
+        let metrics: Vec<TradeMetrics> = closed.iter().map(compute_trade_metrics).collect();
+        let mut politicians = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
+        politicians.push(PoliticianMetrics {
+            politician_id: "P000002".to_string(),
+            ..politicians[0].clone()
+        });
+        apply_recess_volume_share(&mut politicians, &rows);
+        assert!((politicians[0].recess_volume_share.unwrap() - 1200.0 / 2200.0).abs() < 1e-9);
+        // The 2019 buy is outside the calendar, so only the covered one counts.
+        assert_eq!(politicians[1].recess_volume_share, Some(0.0));
+
+        let groups = group_by_session(&rows, &closed);
+        let keys: Vec<(&str, SessionStatus, usize)> = groups
+            .iter()
+            .map(|g| (g.chamber.as_str(), g.session_status, g.trades))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("house", SessionStatus::InSession, 1),
+                ("house", SessionStatus::Recess, 1),
+                ("senate", SessionStatus::InSession, 1),
+                ("senate", SessionStatus::Unknown, 1),
+            ]
+        );
+        // The round trip is credited to the session day the buy was placed.
+        assert_eq!(groups[0].closed_trades, 1);
+        assert!((groups[0].avg_return.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(groups[1].avg_return, None);
+        assert!((groups[0].volume_share - 1000.0 / 2200.0).abs() < 1e-9);
+        assert!((groups[3].volume_share - 0.5).abs() < 1e-9);
+    }
+
+    fn style_metrics(
+        total_trades: usize,
+        median_holding_days: Option<i64>,
+        round_trips_per_year: Option<f64>,
+        trades_per_year: Option<f64>,
+    ) -> PoliticianMetrics {
+        let mut pm = aggregate_politician_metrics(
+            &[return_metric("P000001", 5.0)],
+            &IntervalConfig::default(),
+        )
+        .remove(0);
+        pm.total_trades = total_trades;
+        pm.median_holding_days = median_holding_days;
+        pm.round_trips_per_year = round_trips_per_year;
+        pm.trades_per_year = trades_per_year;
+        pm
+    }
+
+    #[test]
+    fn test_trading_style_boundaries() {
+        let config = TradingStyleConfig::default();
+        let classify = |pm: &PoliticianMetrics, options: Option<f64>, turnover: Option<f64>| {
+            classify_trading_style(pm, options, turnover, &config)
+        };
+        let steady = style_metrics(10, Some(200), Some(2.0), Some(10.0));
+        assert_eq!(classify(&steady, None, Some(1.0)), TradingStyle::Mixed);
+
+        // Options share wins over every other rule, from 25% up.
+        assert_eq!(classify(&steady, Some(0.25), None), TradingStyle::OptionsHeavy);
+        assert_eq!(classify(&steady, Some(0.2499), Some(1.0)), TradingStyle::Mixed);
+
+        // Fewer than two trades a year is inactive, even with no closed trades.
+        let quiet = style_metrics(0, None, None, Some(1.99));
+        assert_eq!(classify(&quiet, None, None), TradingStyle::Inactive);
+        let enough = style_metrics(0, None, None, Some(2.0));
+        assert_eq!(classify(&enough, None, None), TradingStyle::Unclassified);
+
+        // Below five closed trades there is too little to go on.
+        let thin = style_metrics(4, Some(30), None, Some(10.0));
+        assert_eq!(classify(&thin, None, None), TradingStyle::Unclassified);
+        assert_eq!(
+            classify(&style_metrics(5, Some(30), None, Some(10.0)), None, None),
+            TradingStyle::ActiveTrader
+        );
+
+        // Active: median hold <= 90 days, turnover >= 2x or >= 12 round trips a year.
+        let hold = |days| style_metrics(10, Some(days), Some(2.0), Some(10.0));
+        assert_eq!(classify(&hold(90), None, None), TradingStyle::ActiveTrader);
+        assert_eq!(classify(&hold(91), None, None), TradingStyle::Mixed);
+        assert_eq!(classify(&steady, None, Some(2.0)), TradingStyle::ActiveTrader);
+        assert_eq!(classify(&steady, None, Some(1.99)), TradingStyle::Mixed);
+        let churn = |trips| style_metrics(10, Some(200), Some(trips), Some(30.0));
+        assert_eq!(classify(&churn(12.0), None, None), TradingStyle::ActiveTrader);
+        assert_eq!(classify(&churn(11.9), None, None), TradingStyle::Mixed);
+
+        // Buy-and-hold: median hold >= 365 days with turnover <= 0.5x.
+        assert_eq!(classify(&hold(365), None, Some(0.5)), TradingStyle::BuyAndHold);
+        assert_eq!(classify(&hold(365), None, None), TradingStyle::BuyAndHold);
+        assert_eq!(classify(&hold(364), None, Some(0.5)), TradingStyle::Mixed);
+        assert_eq!(classify(&hold(365), None, Some(0.51)), TradingStyle::Mixed);
+
+        // Overridden thresholds move the boundaries.
+        let loose = TradingStyleConfig {
+            active_max_median_days: 200,
+            ..config
+        };
+        assert_eq!(
+            classify_trading_style(&steady, None, None, &loose),
+            TradingStyle::ActiveTrader
+        );
+    }
+
+    #[test]
+    fn test_apply_trading_style() {
+        let mut rows = Vec::new();
+        // Ten round trips in AAPL, each held 10 days, over one year.
+        for i in 0..10 {
+            let (buy, sell) = (format!("2024-{:02}-01", i + 1), format!("2024-{:02}-11", i + 1));
+            rows.push(session_row(i * 2, "P000001", "house", ("buy", &buy), 10.0, 100.0));
+            rows.push(session_row(i * 2 + 1, "P000001", "house", ("sell", &sell), 10.0, 100.0));
+        }
+        let trades: Vec<AnalyticsTrade> = rows
+            .iter()
+            .map(|r| AnalyticsTrade {
+                tx_id: r.tx_id,
+                politician_id: r.politician_id.clone(),
+                ticker: r.issuer_ticker.clone(),
+                tx_type: r.tx_type.clone(),
+                tx_date: r.tx_date.clone(),
+                estimated_shares: r.estimated_shares,
+                trade_date_price: r.trade_date_price,
+                benchmark_price: None,
+                has_sector_benchmark: false,
+                gics_sector: None,
+            })
+            .collect();
+        let closed = calculate_closed_trades(trades, false);
+        let metrics: Vec<TradeMetrics> = closed.iter().map(compute_trade_metrics).collect();
+        let mut politicians = aggregate_politician_metrics(&metrics, &IntervalConfig::default());
+        politicians.push(PoliticianMetrics {
+            politician_id: "P000002".to_string(),
+            ..politicians[0].clone()
+        });
+
+        let mut option_counts = HashMap::new();
+        option_counts.insert("P000002".to_string(), (3, 4));
+        apply_trading_style(
+            &mut politicians,
+            &rows,
+            &closed,
+            &option_counts,
+            &TradingStyleConfig::default(),
+        );
+        assert_eq!(politicians[0].median_holding_days, Some(10));
+        assert!((politicians[0].round_trips_per_year.unwrap() - 10.0).abs() < 1e-9);
+        assert!((politicians[0].trades_per_year.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(politicians[0].trading_style, TradingStyle::ActiveTrader);
+        // No rows of their own: no activity figures, but options still label them.
+        assert_eq!(politicians[1].trades_per_year, None);
+        assert_eq!(politicians[1].trading_style, TradingStyle::OptionsHeavy);
+    }
+
+    #[test]
+    fn test_trading_style_parse() {
+        assert_eq!(TradingStyle::parse("buy-and-hold"), Some(TradingStyle::BuyAndHold));
+        assert_eq!(TradingStyle::parse("Options_Heavy"), Some(TradingStyle::OptionsHeavy));
+        assert_eq!(TradingStyle::parse("day-trader"), None);
+    }
+
 }
@@ -2,13 +2,32 @@
 //!
 //! This module provides pure computation functions for detecting unusual trading patterns:
 //! - Pre-move trade detection (trades before significant price changes)
+//! - Prescient sell detection (sells near a peak that precede a decline)
+//! - Crowded trade detection (many politicians entering or exiting one issuer at once)
+//! - Novel issuer detection (the first trade by any member in an issuer)
 //! - Unusual volume detection (trading frequency spikes)
 //! - Sector concentration scoring (HHI-based portfolio diversification)
 //! - Composite anomaly scoring (weighted combination of all signals)
+//!
+//! [`detect_unusual_volume_universe`] runs the volume scan over a loaded
+//! trade universe, as the composite score does; [`detect_unusual_volume_db`]
+//! runs it against every stored trade one politician at a time, so memory
+//! stays bounded on large databases.
+//!
+//! Thresholds and windows live in [`AnomalyConfig`], which can be loaded from
+//! a TOML or JSON file. Any field left out of the file keeps its default, and
+//! the defaults reproduce the detectors' original fixed behavior.
+
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
 
-use chrono::NaiveDate;
-use serde::Serialize;
-use std::collections::HashMap;
+use crate::analytics::{calculate_closed_trades, holding_period_days, AnalyticsTrade, ClosedTrade};
+use crate::db::{Db, DbError};
+use crate::universe::UniverseTrade;
 
 /// Input trade record with future price data for pre-move detection.
 #[derive(Debug, Clone)]
@@ -51,6 +70,66 @@ pub struct PreMoveSignal {
     pub price_30d_later: f64,
     pub price_change_pct: f64,
     pub direction: String,
+    /// Share of the buy's lot sold within the realized horizon (0-1). `None`
+    /// for sells, or when no [`RealizedCheck`] was given or the buy has no
+    /// share estimate.
+    pub realized_fraction: Option<f64>,
+    /// Share-weighted return, in %, of the part of the lot that was sold.
+    pub realized_return_pct: Option<f64>,
+}
+
+/// Cross-check of pre-move buys against FIFO-matched sales, for
+/// [`detect_pre_move_trades`].
+#[derive(Debug, Clone, Default)]
+pub struct RealizedCheck {
+    /// Matched sales, from [`calculate_closed_trades`].
+    pub closed_trades: Vec<ClosedTrade>,
+    /// Shares bought per buy tx_id; the denominator of the realized fraction.
+    pub buy_shares: HashMap<i64, f64>,
+    /// Sales more than this many days after the buy are ignored.
+    pub horizon_days: i64,
+    /// Keep only buys sold at a gain within the horizon, instead of tagging
+    /// every buy.
+    pub realized_only: bool,
+}
+
+impl RealizedCheck {
+    /// Run FIFO matching over chronologically ordered `trades`.
+    pub fn from_trades(trades: Vec<AnalyticsTrade>, horizon_days: i64, realized_only: bool) -> Self {
+        let buy_shares = trades
+            .iter()
+            .filter(|t| matches!(t.tx_type.as_str(), "buy" | "receive"))
+            .map(|t| (t.tx_id, t.estimated_shares))
+            .collect();
+        Self {
+            closed_trades: calculate_closed_trades(trades, false),
+            buy_shares,
+            horizon_days,
+            realized_only,
+        }
+    }
+
+    /// Realized fraction and return for one buy, from its lot's sales inside
+    /// the horizon.
+    fn realized(&self, by_buy: &HashMap<i64, Vec<&ClosedTrade>>, tx_id: i64) -> (Option<f64>, Option<f64>) {
+        let Some(&bought) = self.buy_shares.get(&tx_id).filter(|s| **s > 0.0) else {
+            return (None, None);
+        };
+        let mut sold = 0.0;
+        let mut weighted_return = 0.0;
+        for closed in by_buy.get(&tx_id).into_iter().flatten() {
+            let in_horizon = holding_period_days(&closed.buy_date, &closed.sell_date)
+                .is_some_and(|days| (0..=self.horizon_days).contains(&days));
+            if !in_horizon || closed.buy_price <= 0.0 {
+                continue;
+            }
+            sold += closed.shares;
+            weighted_return += closed.shares * (closed.sell_price - closed.buy_price) / closed.buy_price;
+        }
+        let fraction = (sold / bought).min(1.0);
+        let return_pct = (sold > 0.0).then(|| weighted_return / sold * 100.0);
+        (Some(fraction), return_pct)
+    }
 }
 
 /// Volume anomaly signal.
@@ -64,6 +143,37 @@ pub struct VolumeSignal {
     pub is_unusual: bool,
 }
 
+/// Window parameters for [`detect_unusual_volume_db`]; same meaning as the
+/// arguments of [`detect_unusual_volume`].
+#[derive(Debug, Clone, Copy)]
+pub struct VolumeParams {
+    pub reference_date: NaiveDate,
+    pub lookback_days: i64,
+    pub baseline_days: i64,
+    /// Recent/baseline ratio above which volume is unusual.
+    pub unusual_ratio: f64,
+}
+
+impl VolumeParams {
+    /// Windows and ratio from `config`, measured back from `reference_date`.
+    pub fn from_config(reference_date: NaiveDate, config: &VolumeConfig) -> Self {
+        Self {
+            reference_date,
+            lookback_days: config.lookback_days,
+            baseline_days: config.baseline_days,
+            unusual_ratio: config.unusual_ratio,
+        }
+    }
+}
+
+/// Volume signal for one politician, from [`detect_unusual_volume_db`].
+#[derive(Serialize, Debug, Clone)]
+pub struct PoliticianVolumeSignal {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub signal: VolumeSignal,
+}
+
 /// Sector concentration score (HHI-based).
 ///
 /// Measures portfolio diversification across GICS sectors.
@@ -87,15 +197,329 @@ pub struct AnomalyScore {
     pub confidence: f64,
 }
 
+/// Error loading an [`AnomalyConfig`].
+#[derive(Error, Debug)]
+pub enum AnomalyConfigError {
+    #[error("failed to read anomaly config: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("TOML parse error: {0}")]
+    TomlParse(String),
+    #[error("JSON parse error: {0}")]
+    JsonParse(#[from] serde_json::Error),
+    #[error("invalid anomaly config: {0}")]
+    Invalid(String),
+}
+
+/// Tunable thresholds for every anomaly detector.
+///
+/// Each section deserializes with `#[serde(default)]`, so a file only needs
+/// the values it changes:
+///
+/// ```toml
+/// [volume]
+/// unusual_ratio = 3.0
+///
+/// [crowded]
+/// min_politicians = 4
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AnomalyConfig {
+    pub pre_move: PreMoveConfig,
+    pub volume: VolumeConfig,
+    pub concentration: ConcentrationConfig,
+    pub composite: CompositeConfig,
+    pub crowded: CrowdedConfig,
+    pub events: EventsConfig,
+    pub sells: SellParams,
+    pub novel: NoveltyConfig,
+}
+
+/// Pre-move detection ([`detect_pre_move_trades`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PreMoveConfig {
+    /// Absolute 30-day price change, in %, that flags a trade.
+    pub threshold_pct: f64,
+    /// Days after a buy within which a sale counts as realizing the move.
+    pub realized_horizon_days: i64,
+}
+
+impl Default for PreMoveConfig {
+    fn default() -> Self {
+        Self {
+            threshold_pct: 10.0,
+            realized_horizon_days: 365,
+        }
+    }
+}
+
+/// Volume spike detection ([`detect_unusual_volume_db`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VolumeConfig {
+    /// Recent window, in days, ending on the reference date.
+    pub lookback_days: i64,
+    /// Baseline window, in days, before the recent window.
+    pub baseline_days: i64,
+    /// Recent/baseline ratio above which volume is unusual.
+    pub unusual_ratio: f64,
+}
+
+impl Default for VolumeConfig {
+    fn default() -> Self {
+        Self {
+            lookback_days: 90,
+            baseline_days: 365,
+            unusual_ratio: 2.0,
+        }
+    }
+}
+
+/// Sector concentration ([`calculate_sector_concentration`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConcentrationConfig {
+    /// HHI (0-1) above which a portfolio counts as concentrated.
+    pub hhi_threshold: f64,
+}
+
+impl Default for ConcentrationConfig {
+    fn default() -> Self {
+        Self { hhi_threshold: 0.25 }
+    }
+}
+
+/// Normalization and weights for [`calculate_composite_anomaly_score`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CompositeConfig {
+    /// Pre-move count that normalizes to 1.0.
+    pub pre_move_saturation: f64,
+    /// Volume ratio that normalizes to 1.0.
+    pub volume_saturation: f64,
+    pub pre_move_weight: f64,
+    pub volume_weight: f64,
+    pub concentration_weight: f64,
+}
+
+impl Default for CompositeConfig {
+    fn default() -> Self {
+        Self {
+            pre_move_saturation: 10.0,
+            volume_saturation: 5.0,
+            pre_move_weight: 1.0,
+            volume_weight: 1.0,
+            concentration_weight: 1.0,
+        }
+    }
+}
+
+/// Crowded trade detection ([`detect_crowded_trades`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CrowdedConfig {
+    pub window_days: i64,
+    pub min_politicians: usize,
+}
+
+impl Default for CrowdedConfig {
+    fn default() -> Self {
+        Self {
+            window_days: 14,
+            min_politicians: 5,
+        }
+    }
+}
+
+/// Event proximity listing (`anomalies --events`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventsConfig {
+    /// Days before and after each event.
+    pub window_days: u32,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self { window_days: 14 }
+    }
+}
+
+/// Novel issuer detection ([`detect_novel_issuer_trades`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NoveltyConfig {
+    /// Years without a local trade after which an issuer counts as returning
+    /// (`anomalies --novel --include-returning`).
+    pub returning_gap_years: u32,
+}
+
+impl Default for NoveltyConfig {
+    fn default() -> Self {
+        Self {
+            returning_gap_years: 3,
+        }
+    }
+}
+
+impl AnomalyConfig {
+    /// Load a config file, as JSON when the extension is `.json` and as TOML
+    /// otherwise. Missing fields keep their defaults.
+    pub fn load(path: &Path) -> Result<Self, AnomalyConfigError> {
+        let text = std::fs::read_to_string(path)?;
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            Self::from_json_str(&text)
+        } else {
+            Self::from_toml_str(&text)
+        }
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self, AnomalyConfigError> {
+        let config: Self =
+            toml::from_str(text).map_err(|e| AnomalyConfigError::TomlParse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn from_json_str(text: &str) -> Result<Self, AnomalyConfigError> {
+        let config: Self = serde_json::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reject values the detectors cannot use (empty windows, negative
+    /// thresholds, all-zero weights).
+    pub fn validate(&self) -> Result<(), AnomalyConfigError> {
+        let invalid = |msg: &str| Err(AnomalyConfigError::Invalid(msg.to_string()));
+        if self.pre_move.realized_horizon_days <= 0 {
+            return invalid("pre_move.realized_horizon_days must be positive");
+        }
+        if self.volume.lookback_days <= 0 || self.volume.baseline_days <= 0 {
+            return invalid("volume windows must be positive");
+        }
+        if !(0..=MAX_WINDOW_DAYS).contains(&self.crowded.window_days) {
+            return invalid("crowded.window_days must be between 0 and 3650");
+        }
+        if i64::from(self.events.window_days) > MAX_WINDOW_DAYS {
+            return invalid("events.window_days must be at most 3650");
+        }
+        if self.crowded.min_politicians == 0 {
+            return invalid("crowded.min_politicians must be at least 1");
+        }
+        if self.sells.peak_lookback_days <= 0 || self.sells.drawdown_days <= 0 {
+            return invalid("sells windows must be positive");
+        }
+        if self.sells.peak_lookback_days > MAX_WINDOW_DAYS || self.sells.drawdown_days > MAX_WINDOW_DAYS {
+            return invalid("sells windows must be at most 3650 days");
+        }
+        if self.novel.returning_gap_years == 0 {
+            return invalid("novel.returning_gap_years must be at least 1");
+        }
+        let c = &self.composite;
+        if c.pre_move_saturation <= 0.0 || c.volume_saturation <= 0.0 {
+            return invalid("composite saturation values must be positive");
+        }
+        let weights = [c.pre_move_weight, c.volume_weight, c.concentration_weight];
+        if weights.iter().any(|w| *w < 0.0) || weights.iter().sum::<f64>() <= 0.0 {
+            return invalid("composite weights must be non-negative and not all zero");
+        }
+        let thresholds = [
+            self.pre_move.threshold_pct,
+            self.volume.unusual_ratio,
+            self.concentration.hhi_threshold,
+            self.sells.near_peak_pct,
+            self.sells.decline_pct,
+        ];
+        if thresholds.iter().any(|t| *t < 0.0 || !t.is_finite()) {
+            return invalid("thresholds must be finite and non-negative");
+        }
+        Ok(())
+    }
+}
+
+/// Longest day window a detector accepts from flags or a config file.
+pub const MAX_WINDOW_DAYS: i64 = 3650;
+
+/// Days after a trade that pre-move detection looks for a price.
+pub const PRE_MOVE_HORIZON_DAYS: i64 = 30;
+
+/// Slack either side of [`PRE_MOVE_HORIZON_DAYS`] for the later price.
+const PRE_MOVE_TOLERANCE_DAYS: i64 = 2;
+
+/// Pre-move candidates from a trade universe: priced trades at least
+/// [`PRE_MOVE_HORIZON_DAYS`] old on `as_of`, newest first, each with the
+/// price of the earliest trade in the same ticker 28-32 days later
+/// (`None` when the universe has none).
+pub fn future_price_candidates(
+    trades: &[UniverseTrade],
+    as_of: NaiveDate,
+) -> Vec<TradeWithFuturePrice> {
+    let parse = |date: &str| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok();
+
+    // Priced trades per ticker, oldest first (universe order is by date)
+    let mut by_ticker: HashMap<&str, Vec<(NaiveDate, f64)>> = HashMap::new();
+    for trade in trades {
+        if let (Some(date), Some(price)) = (parse(&trade.tx_date), trade.trade_date_price) {
+            by_ticker.entry(&trade.issuer_ticker).or_default().push((date, price));
+        }
+    }
+
+    let cutoff = as_of - chrono::Duration::days(PRE_MOVE_HORIZON_DAYS);
+    let mut candidates: Vec<TradeWithFuturePrice> = trades
+        .iter()
+        .filter_map(|trade| {
+            let date = parse(&trade.tx_date)?;
+            let trade_price = trade.trade_date_price?;
+            if date > cutoff {
+                return None;
+            }
+            let from = date + chrono::Duration::days(PRE_MOVE_HORIZON_DAYS - PRE_MOVE_TOLERANCE_DAYS);
+            let to = date + chrono::Duration::days(PRE_MOVE_HORIZON_DAYS + PRE_MOVE_TOLERANCE_DAYS);
+            let series = &by_ticker[trade.issuer_ticker.as_str()];
+            let start = series.partition_point(|(d, _)| *d < from);
+            let price_30d_later = series
+                .get(start)
+                .filter(|(d, _)| *d <= to)
+                .map(|(_, price)| *price);
+            Some(TradeWithFuturePrice {
+                tx_id: trade.tx_id,
+                politician_id: trade.politician_id.clone(),
+                ticker: trade.issuer_ticker.clone(),
+                tx_date: trade.tx_date.clone(),
+                tx_type: trade.tx_type.clone(),
+                trade_price,
+                price_30d_later,
+            })
+        })
+        .collect();
+    candidates.reverse();
+    candidates
+}
+
 /// Detect pre-move trades (trades before significant price movements).
 ///
 /// Returns trades where the price changed by more than threshold_pct within 30 days.
 /// Excludes trades with no 30-day price data.
+///
+/// With `realized`, buy signals are tagged with how much of the lot was sold
+/// within the horizon and at what return; in realized-only mode, sells and
+/// buys that did not rise and get sold at a gain are dropped.
 pub fn detect_pre_move_trades(
     trades: &[TradeWithFuturePrice],
     threshold_pct: f64,
+    realized: Option<&RealizedCheck>,
 ) -> Vec<PreMoveSignal> {
     let mut signals = Vec::new();
+    let mut by_buy: HashMap<i64, Vec<&ClosedTrade>> = HashMap::new();
+    if let Some(check) = realized {
+        for closed in &check.closed_trades {
+            by_buy.entry(closed.buy_tx_id).or_default().push(closed);
+        }
+    }
 
     for trade in trades {
         // Skip trades without 30-day price
@@ -125,6 +549,19 @@ pub fn detect_pre_move_trades(
                 }
             };
 
+            let (realized_fraction, realized_return_pct) = match realized {
+                Some(check) if trade.tx_type == "buy" => check.realized(&by_buy, trade.tx_id),
+                _ => (None, None),
+            };
+            if realized.is_some_and(|c| c.realized_only) {
+                let sold_at_gain = price_change_pct > 0.0
+                    && realized_fraction.is_some_and(|f| f > 0.0)
+                    && realized_return_pct.is_some_and(|r| r > 0.0);
+                if !sold_at_gain {
+                    continue;
+                }
+            }
+
             signals.push(PreMoveSignal {
                 tx_id: trade.tx_id,
                 politician_id: trade.politician_id.clone(),
@@ -135,6 +572,8 @@ pub fn detect_pre_move_trades(
                 price_30d_later: price_30d,
                 price_change_pct,
                 direction,
+                realized_fraction,
+                realized_return_pct,
             });
         }
     }
@@ -144,7 +583,9 @@ pub fn detect_pre_move_trades(
 
 /// Detect unusual trading volume.
 ///
-/// Compares recent trading frequency to historical baseline. Division-by-zero safe.
+/// Compares recent trading frequency to historical baseline, flagging a
+/// ratio above the default [`VolumeConfig::unusual_ratio`]. Division-by-zero
+/// safe.
 pub fn detect_unusual_volume(
     trades: &[TradeVolumeRecord],
     politician_id: &str,
@@ -152,39 +593,97 @@ pub fn detect_unusual_volume(
     lookback_days: i64,
     baseline_days: i64,
 ) -> VolumeSignal {
-    // Filter trades for this politician
-    let politician_trades: Vec<&TradeVolumeRecord> = trades
+    let dates = trades
         .iter()
         .filter(|t| t.politician_id == politician_id)
-        .collect();
+        .map(|t| t.tx_date.as_str());
+    volume_signal(
+        dates,
+        &VolumeParams {
+            reference_date,
+            lookback_days,
+            baseline_days,
+            unusual_ratio: VolumeConfig::default().unusual_ratio,
+        },
+    )
+}
+
+/// Detect unusual trading volume for every politician with trades, reading
+/// one politician's trade dates at a time.
+///
+/// Produces the same signal as [`detect_unusual_volume`] over the full trade
+/// list, ordered by politician_id.
+pub fn detect_unusual_volume_db(
+    db: &Db,
+    params: &VolumeParams,
+) -> Result<Vec<PoliticianVolumeSignal>, DbError> {
+    let mut signals = Vec::new();
+    for (politician_id, politician_name) in db.trading_politicians()? {
+        let dates = db.trade_dates_for_politician(&politician_id)?;
+        let signal = volume_signal(dates.iter().map(String::as_str), params);
+        signals.push(PoliticianVolumeSignal {
+            politician_id,
+            politician_name,
+            signal,
+        });
+    }
+    Ok(signals)
+}
+
+/// Detect unusual trading volume for every politician in a trade universe.
+///
+/// Produces the same signal as [`detect_unusual_volume`] over `trades`,
+/// ordered by politician_id, so the volume part of a composite score sees
+/// the same trades as the pre-move part.
+pub fn detect_unusual_volume_universe(
+    trades: &[UniverseTrade],
+    params: &VolumeParams,
+) -> Vec<PoliticianVolumeSignal> {
+    let mut by_politician: BTreeMap<&str, (&str, Vec<&str>)> = BTreeMap::new();
+    for trade in trades {
+        by_politician
+            .entry(trade.politician_id.as_str())
+            .or_insert_with(|| (trade.politician_name.as_str(), Vec::new()))
+            .1
+            .push(trade.tx_date.as_str());
+    }
+    by_politician
+        .into_iter()
+        .map(|(politician_id, (politician_name, dates))| PoliticianVolumeSignal {
+            politician_id: politician_id.to_string(),
+            politician_name: politician_name.to_string(),
+            signal: volume_signal(dates.into_iter(), params),
+        })
+        .collect()
+}
+
+/// Recent-vs-baseline trade counts for one politician's trade dates.
+fn volume_signal<'a>(dates: impl Iterator<Item = &'a str>, params: &VolumeParams) -> VolumeSignal {
+    let VolumeParams {
+        reference_date,
+        lookback_days,
+        baseline_days,
+        unusual_ratio,
+    } = *params;
 
     // Calculate date boundaries
     let recent_start = reference_date - chrono::Duration::days(lookback_days);
     let baseline_start = recent_start - chrono::Duration::days(baseline_days);
 
-    // Count recent trades (within lookback window)
-    let recent_trade_count = politician_trades
-        .iter()
-        .filter(|t| {
-            if let Ok(tx_date) = NaiveDate::parse_from_str(&t.tx_date, "%Y-%m-%d") {
-                tx_date >= recent_start && tx_date <= reference_date
-            } else {
-                false
-            }
-        })
-        .count();
-
-    // Count historical trades (baseline window before recent window)
-    let historical_trade_count = politician_trades
-        .iter()
-        .filter(|t| {
-            if let Ok(tx_date) = NaiveDate::parse_from_str(&t.tx_date, "%Y-%m-%d") {
-                tx_date >= baseline_start && tx_date < recent_start
-            } else {
-                false
-            }
-        })
-        .count();
+    // Count recent trades (within lookback window) and historical trades
+    // (baseline window before recent window)
+    let mut recent_trade_count = 0;
+    let mut historical_trade_count = 0;
+    for date in dates {
+        let Ok(tx_date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else {
+            continue;
+        };
+        if tx_date >= recent_start && tx_date <= reference_date {
+            recent_trade_count += 1;
+        } else if tx_date >= baseline_start && tx_date < recent_start {
+            historical_trade_count += 1;
+        }
+    }
 
     // Calculate historical average per lookback window
     let num_lookback_windows = baseline_days as f64 / lookback_days as f64;
@@ -201,8 +700,7 @@ pub fn detect_unusual_volume(
         0.0
     };
 
-    // Flag as unusual if ratio > 2.0
-    let is_unusual = volume_ratio > 2.0;
+    let is_unusual = volume_ratio > unusual_ratio;
 
     VolumeSignal {
         recent_trade_count,
@@ -215,7 +713,10 @@ pub fn detect_unusual_volume(
 /// Calculate sector concentration score using HHI.
 ///
 /// Excludes positions with no sector or non-positive value. Division-by-zero safe.
-pub fn calculate_sector_concentration(positions: &[PortfolioPositionForHHI]) -> ConcentrationScore {
+pub fn calculate_sector_concentration(
+    positions: &[PortfolioPositionForHHI],
+    config: &ConcentrationConfig,
+) -> ConcentrationScore {
     // Filter positions with valid sector and positive value
     let valid_positions: Vec<&PortfolioPositionForHHI> = positions
         .iter()
@@ -265,8 +766,7 @@ pub fn calculate_sector_concentration(positions: &[PortfolioPositionForHHI]) ->
         .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
         .map(|(sector, _)| sector.clone());
 
-    // Flag as concentrated if HHI > 0.25
-    let is_concentrated = hhi_score > 0.25;
+    let is_concentrated = hhi_score > config.hhi_threshold;
 
     ConcentrationScore {
         sector_weights,
@@ -276,19 +776,490 @@ pub fn calculate_sector_concentration(positions: &[PortfolioPositionForHHI]) ->
     }
 }
 
+/// A sell trade to check against its issuer's end-of-day price series.
+#[derive(Debug, Clone)]
+pub struct SellTrade {
+    pub tx_id: i64,
+    pub politician_id: String,
+    pub issuer_id: i64,
+    pub ticker: String,
+    pub tx_date: String,
+    /// Trade-date close used as the sale price.
+    pub sell_price: f64,
+}
+
+/// Sell trades plus the EOD price series of every issuer they touch.
+#[derive(Debug, Clone, Default)]
+pub struct SellsWithSeries {
+    pub sells: Vec<SellTrade>,
+    /// Closing prices by issuer_id, ascending by date.
+    pub series: HashMap<i64, Vec<(NaiveDate, f64)>>,
+}
+
+/// Horizons and thresholds for [`detect_prescient_sells`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SellParams {
+    /// Days before the sale searched for the trailing peak (M).
+    pub peak_lookback_days: i64,
+    /// Days after the sale searched for the drawdown (N).
+    pub drawdown_days: i64,
+    /// A sale within this % of the trailing peak counts as "near the top" (X).
+    pub near_peak_pct: f64,
+    /// Minimum decline after the sale, in %, to flag it (Y).
+    pub decline_pct: f64,
+}
+
+impl Default for SellParams {
+    fn default() -> Self {
+        Self {
+            peak_lookback_days: 90,
+            drawdown_days: 30,
+            near_peak_pct: 5.0,
+            decline_pct: 10.0,
+        }
+    }
+}
+
+/// Sell-discipline metrics for one sale.
+#[derive(Serialize, Debug, Clone)]
+pub struct SellSignal {
+    pub tx_id: i64,
+    pub politician_id: String,
+    pub ticker: String,
+    pub tx_date: String,
+    pub sell_price: f64,
+    /// Highest close in the lookback window, including the sale price.
+    pub trailing_peak: f64,
+    pub peak_date: String,
+    /// How far below the trailing peak the sale was, in % (0 = sold at the peak).
+    pub below_peak_pct: f64,
+    /// Lowest close in the drawdown window.
+    pub forward_low: f64,
+    /// Decline from the sale price to the forward low, in % (0 if it never fell).
+    pub drawdown_after_pct: f64,
+    /// Sold near the peak and followed by a decline beyond the threshold.
+    pub is_prescient: bool,
+}
+
+/// Result of [`detect_prescient_sells`].
+#[derive(Debug, Clone, Default)]
+pub struct SellScan {
+    /// One signal per evaluated sale, in input order.
+    pub signals: Vec<SellSignal>,
+    /// Sales skipped because the issuer has no closes in the lookback or
+    /// drawdown window.
+    pub skipped_insufficient_history: usize,
+}
+
+impl SellScan {
+    pub fn prescient_count(&self) -> usize {
+        self.signals.iter().filter(|s| s.is_prescient).count()
+    }
+}
+
+/// Detect prescient sells: sales made near a trailing peak and followed by a
+/// decline.
+///
+/// For each sale, the trailing peak is the highest close in the
+/// `peak_lookback_days` up to and including the sale date, and the drawdown
+/// is measured to the lowest close in the following `drawdown_days`.
+pub fn detect_prescient_sells(input: &SellsWithSeries, params: &SellParams) -> SellScan {
+    let mut scan = SellScan::default();
+
+    for sell in &input.sells {
+        let (Ok(sale_date), Some(series)) = (
+            NaiveDate::parse_from_str(&sell.tx_date, "%Y-%m-%d"),
+            input.series.get(&sell.issuer_id),
+        ) else {
+            scan.skipped_insufficient_history += 1;
+            continue;
+        };
+        let lookback_start = sale_date
+            .checked_sub_days(Days::new(params.peak_lookback_days.max(0) as u64))
+            .unwrap_or(NaiveDate::MIN);
+        let horizon_end = sale_date
+            .checked_add_days(Days::new(params.drawdown_days.max(0) as u64))
+            .unwrap_or(NaiveDate::MAX);
+
+        let trailing = series
+            .iter()
+            .filter(|(d, _)| *d >= lookback_start && *d <= sale_date);
+        let forward_low = series
+            .iter()
+            .filter(|(d, _)| *d > sale_date && *d <= horizon_end)
+            .map(|(_, p)| *p)
+            .reduce(f64::min);
+
+        let mut peak = (sale_date, sell.sell_price);
+        let mut has_trailing = false;
+        for &(date, price) in trailing {
+            has_trailing = true;
+            if price > peak.1 {
+                peak = (date, price);
+            }
+        }
+        let Some(forward_low) = forward_low.filter(|_| has_trailing) else {
+            scan.skipped_insufficient_history += 1;
+            continue;
+        };
+
+        let below_peak_pct = if peak.1 > 0.0 {
+            (peak.1 - sell.sell_price) / peak.1 * 100.0
+        } else {
+            0.0
+        };
+        let drawdown_after_pct = if sell.sell_price > 0.0 {
+            ((sell.sell_price - forward_low) / sell.sell_price * 100.0).max(0.0)
+        } else {
+            0.0
+        };
+
+        scan.signals.push(SellSignal {
+            tx_id: sell.tx_id,
+            politician_id: sell.politician_id.clone(),
+            ticker: sell.ticker.clone(),
+            tx_date: sell.tx_date.clone(),
+            sell_price: sell.sell_price,
+            trailing_peak: peak.1,
+            peak_date: peak.0.format("%Y-%m-%d").to_string(),
+            below_peak_pct,
+            forward_low,
+            drawdown_after_pct,
+            is_prescient: below_peak_pct <= params.near_peak_pct
+                && drawdown_after_pct > params.decline_pct,
+        });
+    }
+
+    scan
+}
+
+/// Which side of the market [`detect_crowded_trades`] looks at.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeDirection {
+    /// Crowded entries.
+    #[default]
+    Buy,
+    /// Crowded exits.
+    Sell,
+}
+
+impl TradeDirection {
+    /// The `tx_type` value this direction matches.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradeDirection::Buy => "buy",
+            TradeDirection::Sell => "sell",
+        }
+    }
+}
+
+impl FromStr for TradeDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "buy" | "buys" => Ok(TradeDirection::Buy),
+            "sell" | "sells" => Ok(TradeDirection::Sell),
+            other => Err(format!(
+                "unknown direction '{}'. Valid values: buy, sell",
+                other
+            )),
+        }
+    }
+}
+
+/// One trade considered for crowding.
+#[derive(Debug, Clone)]
+pub struct CrowdingTrade {
+    pub tx_id: i64,
+    pub politician_id: String,
+    pub politician_name: String,
+    pub party: String,
+    pub issuer_id: i64,
+    pub issuer_name: String,
+    pub ticker: String,
+    pub tx_type: String,
+    pub tx_date: String,
+    pub value: i64,
+}
+
+/// Several politicians trading the same issuer in the same direction within
+/// a short span.
+#[derive(Serialize, Debug, Clone)]
+pub struct CrowdedTradeSignal {
+    pub issuer_id: i64,
+    pub issuer_name: String,
+    pub ticker: String,
+    pub direction: TradeDirection,
+    /// Date of the first trade in the crowd.
+    pub window_start: String,
+    /// Date of the last trade in the crowd.
+    pub window_end: String,
+    /// Distinct politician names, sorted.
+    pub politicians: Vec<String>,
+    pub trade_count: usize,
+    pub total_value: i64,
+    /// Distinct politicians per party.
+    pub democrats: usize,
+    pub republicans: usize,
+    pub other_party: usize,
+}
+
+impl CrowdedTradeSignal {
+    pub fn politician_count(&self) -> usize {
+        self.politicians.len()
+    }
+}
+
+/// Detect crowded trades: issuers that at least `min_politicians` distinct
+/// politicians traded in `direction` within `window_days` of each other.
+///
+/// Every trade anchors a window running `window_days` forward from its date
+/// (both ends inclusive). Qualifying windows of the same issuer that overlap
+/// are merged, so one run of crowding yields one signal covering the union.
+/// Signals are ordered by politician count, then total value, descending.
+pub fn detect_crowded_trades(
+    trades: &[CrowdingTrade],
+    window_days: i64,
+    min_politicians: usize,
+    direction: TradeDirection,
+) -> Vec<CrowdedTradeSignal> {
+    let mut by_issuer: HashMap<i64, Vec<(NaiveDate, &CrowdingTrade)>> = HashMap::new();
+    for trade in trades.iter().filter(|t| t.tx_type == direction.as_str()) {
+        if let Ok(date) = NaiveDate::parse_from_str(&trade.tx_date, "%Y-%m-%d") {
+            by_issuer.entry(trade.issuer_id).or_default().push((date, trade));
+        }
+    }
+
+    let mut signals = Vec::new();
+    for (_, mut issuer_trades) in by_issuer {
+        issuer_trades.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.tx_id.cmp(&b.1.tx_id)));
+
+        // Qualifying windows as [first, last] trade index ranges, already in
+        // start order, so merging is a single pass.
+        let mut runs: Vec<(usize, usize)> = Vec::new();
+        for start in 0..issuer_trades.len() {
+            let window_end = issuer_trades[start]
+                .0
+                .checked_add_days(Days::new(window_days.max(0) as u64))
+                .unwrap_or(NaiveDate::MAX);
+            let mut end = start;
+            while end + 1 < issuer_trades.len() && issuer_trades[end + 1].0 <= window_end {
+                end += 1;
+            }
+            let distinct: BTreeSet<&str> = issuer_trades[start..=end]
+                .iter()
+                .map(|(_, t)| t.politician_id.as_str())
+                .collect();
+            if distinct.len() < min_politicians {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(run) if start <= run.1 => run.1 = run.1.max(end),
+                _ => runs.push((start, end)),
+            }
+        }
+
+        for (first, last) in runs {
+            signals.push(crowd_signal(&issuer_trades[first..=last], direction));
+        }
+    }
+
+    signals.sort_by(|a, b| {
+        b.politician_count()
+            .cmp(&a.politician_count())
+            .then(b.total_value.cmp(&a.total_value))
+            .then(a.window_start.cmp(&b.window_start))
+            .then(a.issuer_id.cmp(&b.issuer_id))
+    });
+    signals
+}
+
+fn crowd_signal(run: &[(NaiveDate, &CrowdingTrade)], direction: TradeDirection) -> CrowdedTradeSignal {
+    let first = run[0].1;
+    let mut parties: HashMap<&str, &str> = HashMap::new();
+    for (_, trade) in run {
+        parties.insert(trade.politician_id.as_str(), trade.party.as_str());
+    }
+    let count_party = |party: &str| parties.values().filter(|p| p.eq_ignore_ascii_case(party)).count();
+    let democrats = count_party("democrat");
+    let republicans = count_party("republican");
+    let politicians: BTreeSet<&str> = run.iter().map(|(_, t)| t.politician_name.as_str()).collect();
+
+    CrowdedTradeSignal {
+        issuer_id: first.issuer_id,
+        issuer_name: first.issuer_name.clone(),
+        ticker: first.ticker.clone(),
+        direction,
+        window_start: run[0].0.format("%Y-%m-%d").to_string(),
+        window_end: run[run.len() - 1].0.format("%Y-%m-%d").to_string(),
+        politicians: politicians.into_iter().map(str::to_string).collect(),
+        trade_count: run.len(),
+        total_value: run.iter().map(|(_, t)| t.value).sum(),
+        democrats,
+        republicans,
+        other_party: parties.len() - democrats - republicans,
+    }
+}
+
+/// A trade in or after the novelty window, with the history needed to judge
+/// whether it is the first trade in its issuer.
+#[derive(Debug, Clone, Serialize)]
+pub struct NoveltyCandidate {
+    pub tx_id: i64,
+    pub politician_id: String,
+    pub politician_name: String,
+    pub issuer_id: i64,
+    pub issuer_name: String,
+    pub ticker: String,
+    pub sector: Option<String>,
+    /// Market cap from the scraped issuer performance, when known.
+    pub mcap: Option<i64>,
+    pub tx_type: String,
+    pub tx_date: String,
+    pub value: i64,
+    /// Latest local trade in the issuer before this one, by tx_date.
+    pub previous_trade_date: Option<String>,
+    /// Local trades in the issuer, of any date.
+    pub local_trade_count: i64,
+    /// `issuer_stats.count_trades`, when the issuer has scraped stats.
+    pub scraped_trade_count: Option<i64>,
+    /// `issuer_stats.date_last_traded`, when the issuer has scraped stats.
+    pub scraped_date_last_traded: Option<String>,
+}
+
+/// Whether a novel trade opens an issuer or returns to it after a long gap.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NoveltyKind {
+    /// No earlier local trade in the issuer.
+    FirstTrade,
+    /// The previous local trade is older than the returning gap.
+    Returning,
+}
+
+impl NoveltyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoveltyKind::FirstTrade => "first_trade",
+            NoveltyKind::Returning => "returning",
+        }
+    }
+}
+
+/// How well the scraped issuer stats back up a novelty signal. Ordered from
+/// weakest to strongest.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum NoveltyConfidence {
+    /// The scraped stats show trades the local database does not have, so
+    /// the issuer was probably traded before.
+    Low,
+    /// The issuer has no scraped stats to check against.
+    Unverified,
+    /// The scraped stats agree with the local history.
+    High,
+}
+
+impl NoveltyConfidence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoveltyConfidence::Low => "low",
+            NoveltyConfidence::Unverified => "unverified",
+            NoveltyConfidence::High => "high",
+        }
+    }
+}
+
+/// A trade in an issuer no member had traded before (locally), or had not
+/// traded for longer than the returning gap.
+#[derive(Serialize, Debug, Clone)]
+pub struct NoveltySignal {
+    #[serde(flatten)]
+    pub trade: NoveltyCandidate,
+    pub kind: NoveltyKind,
+    pub confidence: NoveltyConfidence,
+}
+
+/// Check a candidate against the issuer's scraped stats.
+///
+/// The local database only holds what has been synced, so an issuer can look
+/// new just because its older trades were never fetched. CapitolTrades'
+/// per-issuer stats cover the full history: more scraped trades than local
+/// ones, or a scraped last-traded date that falls before this trade but after
+/// the previous local one, both point to activity the local history misses.
+pub fn novelty_confidence(candidate: &NoveltyCandidate) -> NoveltyConfidence {
+    let Some(scraped_count) = candidate.scraped_trade_count else {
+        return NoveltyConfidence::Unverified;
+    };
+    if scraped_count > candidate.local_trade_count {
+        return NoveltyConfidence::Low;
+    }
+    if let Some(ref last) = candidate.scraped_date_last_traded {
+        let last = last.get(..10).unwrap_or(last);
+        let unseen_gap_trade = last < candidate.tx_date.as_str()
+            && candidate
+                .previous_trade_date
+                .as_deref()
+                .is_none_or(|prev| last > prev);
+        if unseen_gap_trade {
+            return NoveltyConfidence::Low;
+        }
+    }
+    NoveltyConfidence::High
+}
+
+/// Detect trades since `since` in issuers with no earlier local trade.
+///
+/// With `returning_gap_years`, trades whose previous local trade in the issuer
+/// is more than that many years older are included too, as
+/// [`NoveltyKind::Returning`]. Each signal's confidence comes from
+/// [`novelty_confidence`]. Signals are ordered by confidence (strongest
+/// first), then newest trade first.
+pub fn detect_novel_issuer_trades(
+    db: &Db,
+    since: NaiveDate,
+    returning_gap_years: Option<u32>,
+) -> Result<Vec<NoveltySignal>, DbError> {
+    let gap_days = returning_gap_years.map(|years| i64::from(years) * 365);
+    let candidates =
+        db.query_novelty_candidates(&since.format("%Y-%m-%d").to_string(), gap_days)?;
+
+    let mut signals: Vec<NoveltySignal> = candidates
+        .into_iter()
+        .map(|trade| NoveltySignal {
+            kind: if trade.previous_trade_date.is_some() {
+                NoveltyKind::Returning
+            } else {
+                NoveltyKind::FirstTrade
+            },
+            confidence: novelty_confidence(&trade),
+            trade,
+        })
+        .collect();
+    signals.sort_by(|a, b| {
+        b.confidence
+            .cmp(&a.confidence)
+            .then(b.trade.tx_date.cmp(&a.trade.tx_date))
+            .then(a.trade.tx_id.cmp(&b.trade.tx_id))
+    });
+    Ok(signals)
+}
+
 /// Calculate composite anomaly score from individual signals.
 ///
-/// Normalizes and weights all signals. Confidence reflects data availability.
+/// Normalizes each signal to 0-1 and takes their weighted average.
+/// Confidence reflects data availability.
 pub fn calculate_composite_anomaly_score(
     pre_move_count: usize,
     volume_ratio: f64,
     hhi_score: f64,
+    config: &CompositeConfig,
 ) -> AnomalyScore {
-    // Normalize pre-move count: divide by 10, cap at 1.0
-    let pre_move_norm = (pre_move_count as f64 / 10.0).min(1.0);
-
-    // Normalize volume ratio: divide by 5.0, cap at 1.0
-    let volume_norm = (volume_ratio / 5.0).min(1.0);
+    let pre_move_norm = (pre_move_count as f64 / config.pre_move_saturation).min(1.0);
+    let volume_norm = (volume_ratio / config.volume_saturation).min(1.0);
 
     // Concentration is already 0-1 (HHI directly)
     let concentration_norm = hhi_score.min(1.0);
@@ -305,8 +1276,12 @@ pub fn calculate_composite_anomaly_score(
         signal_count += 1;
     }
 
-    // Calculate composite as average of all signals (equal weights 33.3% each)
-    let composite = (pre_move_norm + volume_norm + concentration_norm) / 3.0;
+    // Equal weights (the default) give the plain average of the three.
+    let total_weight = config.pre_move_weight + config.volume_weight + config.concentration_weight;
+    let composite = (pre_move_norm * config.pre_move_weight
+        + volume_norm * config.volume_weight
+        + concentration_norm * config.concentration_weight)
+        / total_weight;
 
     // Confidence is proportion of available signals (0-1)
     let confidence = signal_count as f64 / 3.0;
@@ -324,6 +1299,96 @@ pub fn calculate_composite_anomaly_score(
 mod tests {
     use super::*;
 
+    // Prescient sell tests
+
+    /// Rises 100 -> 150 over 60 days, crashes to 90 by day 90, then flat.
+    fn peak_then_crash_series() -> Vec<(NaiveDate, f64)> {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        (0..=150)
+            .map(|day| {
+                let price = if day <= 60 {
+                    100.0 + 50.0 * day as f64 / 60.0
+                } else if day <= 90 {
+                    150.0 - 60.0 * (day - 60) as f64 / 30.0
+                } else {
+                    90.0
+                };
+                (start + chrono::Duration::days(day), price)
+            })
+            .collect()
+    }
+
+    fn sell_on(tx_id: i64, issuer_id: i64, day: i64, series: &[(NaiveDate, f64)]) -> SellTrade {
+        let (date, price) = series[day as usize];
+        SellTrade {
+            tx_id,
+            politician_id: "P000001".to_string(),
+            issuer_id,
+            ticker: "XYZ".to_string(),
+            tx_date: date.format("%Y-%m-%d").to_string(),
+            sell_price: price,
+        }
+    }
+
+    #[test]
+    fn test_prescient_sell_near_top_flagged_after_crash_not() {
+        let series = peak_then_crash_series();
+        let input = SellsWithSeries {
+            sells: vec![sell_on(1, 7, 58, &series), sell_on(2, 7, 95, &series)],
+            series: HashMap::from([(7, series)]),
+        };
+
+        let scan = detect_prescient_sells(&input, &SellParams::default());
+        assert_eq!(scan.skipped_insufficient_history, 0);
+        assert_eq!(scan.signals.len(), 2);
+
+        let near_top = &scan.signals[0];
+        assert!(near_top.is_prescient);
+        assert!(near_top.below_peak_pct < 5.0);
+        assert!((near_top.forward_low - 94.0).abs() < 1e-9);
+        assert!(near_top.drawdown_after_pct > 30.0);
+
+        let after_crash = &scan.signals[1];
+        assert!(!after_crash.is_prescient);
+        assert!((after_crash.trailing_peak - 150.0).abs() < 1e-9);
+        assert_eq!(after_crash.peak_date, "2024-03-01");
+        assert!((after_crash.below_peak_pct - 40.0).abs() < 1e-9);
+
+        assert_eq!(scan.prescient_count(), 1);
+    }
+
+    #[test]
+    fn test_prescient_sell_skips_insufficient_history() {
+        let series = peak_then_crash_series();
+        let last_day = sell_on(1, 7, 150, &series);
+        let unknown_issuer = sell_on(2, 8, 30, &series);
+        let input = SellsWithSeries {
+            sells: vec![last_day, unknown_issuer],
+            series: HashMap::from([(7, series)]),
+        };
+
+        let scan = detect_prescient_sells(&input, &SellParams::default());
+        assert!(scan.signals.is_empty());
+        assert_eq!(scan.skipped_insufficient_history, 2);
+    }
+
+    #[test]
+    fn test_prescient_sell_windows_past_the_calendar_do_not_overflow() {
+        let series = peak_then_crash_series();
+        let input = SellsWithSeries {
+            sells: vec![sell_on(1, 7, 58, &series)],
+            series: HashMap::from([(7, series)]),
+        };
+        let params = SellParams {
+            peak_lookback_days: i64::MAX,
+            drawdown_days: i64::MAX,
+            ..SellParams::default()
+        };
+        let scan = detect_prescient_sells(&input, &params);
+        assert_eq!(scan.signals.len(), 1);
+        assert!((scan.signals[0].forward_low - 90.0).abs() < 1e-9);
+    }
+
     // Pre-move detection tests
     #[test]
     fn test_pre_move_basic_detection() {
@@ -337,7 +1402,7 @@ mod tests {
             price_30d_later: Some(115.0),
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 1);
         assert_eq!(signals[0].tx_id, 1);
         assert!((signals[0].price_change_pct - 15.0).abs() < 0.01);
@@ -356,7 +1421,7 @@ mod tests {
             price_30d_later: Some(105.0),
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 0);
     }
 
@@ -372,7 +1437,7 @@ mod tests {
             price_30d_later: None,
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 0);
     }
 
@@ -388,16 +1453,51 @@ mod tests {
             price_30d_later: Some(85.0),
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 1);
         assert!((signals[0].price_change_pct - (-15.0)).abs() < 0.01);
         assert_eq!(signals[0].direction, "sell_before_drop");
     }
 
+    #[test]
+    fn test_future_price_candidates_pairs_prices_30_days_on() {
+        let trade = |tx_id: i64, ticker: &str, tx_date: &str, price: Option<f64>| UniverseTrade {
+            tx_id,
+            politician_id: "P000001".to_string(),
+            politician_name: "Jane Doe".to_string(),
+            issuer_ticker: ticker.to_string(),
+            asset_type: "stock".to_string(),
+            tx_type: "buy".to_string(),
+            tx_date: tx_date.to_string(),
+            estimated_shares: price.map(|_| 10.0),
+            trade_date_price: price,
+            benchmark_price: None,
+            gics_sector: None,
+            chamber: "senate".to_string(),
+        };
+        let trades = vec![
+            trade(1, "AAPL", "2024-01-01", Some(100.0)),
+            trade(2, "MSFT", "2024-01-20", Some(300.0)),
+            trade(3, "AAPL", "2024-01-29", Some(90.0)),
+            trade(4, "AAPL", "2024-02-01", Some(120.0)),
+            trade(5, "AAPL", "2024-02-05", None),
+        ];
+        let as_of = NaiveDate::from_ymd_opt(2024, 2, 28).unwrap();
+
+        let candidates = future_price_candidates(&trades, as_of);
+        // Trade 4 is under 30 days old and trade 5 has no price.
+        let ids: Vec<i64> = candidates.iter().map(|c| c.tx_id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+        // Day 28 (trade 3) is the earliest AAPL price in the 28-32 day window.
+        assert_eq!(candidates[2].price_30d_later, Some(90.0));
+        assert_eq!(candidates[1].price_30d_later, None);
+        assert_eq!(candidates[0].price_30d_later, None);
+    }
+
     #[test]
     fn test_pre_move_empty_input() {
         let trades: Vec<TradeWithFuturePrice> = vec![];
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 0);
     }
 
@@ -413,7 +1513,7 @@ mod tests {
             price_30d_later: Some(85.0),
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 1);
         assert_eq!(signals[0].direction, "buy_before_drop");
     }
@@ -430,11 +1530,97 @@ mod tests {
             price_30d_later: Some(115.0),
         }];
 
-        let signals = detect_pre_move_trades(&trades, 10.0);
+        let signals = detect_pre_move_trades(&trades, 10.0, None);
         assert_eq!(signals.len(), 1);
         assert_eq!(signals[0].direction, "sell_before_rise");
     }
 
+    fn analytics_trade(tx_id: i64, ticker: &str, tx_type: &str, tx_date: &str, shares: f64, price: f64) -> AnalyticsTrade {
+        AnalyticsTrade {
+            tx_id,
+            politician_id: "P000001".to_string(),
+            ticker: ticker.to_string(),
+            tx_type: tx_type.to_string(),
+            tx_date: tx_date.to_string(),
+            estimated_shares: shares,
+            trade_date_price: price,
+            benchmark_price: None,
+            has_sector_benchmark: false,
+            gics_sector: None,
+        }
+    }
+
+    fn rising_buy(tx_id: i64, ticker: &str) -> TradeWithFuturePrice {
+        TradeWithFuturePrice {
+            tx_id,
+            politician_id: "P000001".to_string(),
+            ticker: ticker.to_string(),
+            tx_date: "2024-01-02".to_string(),
+            tx_type: "buy".to_string(),
+            trade_price: 100.0,
+            price_30d_later: Some(120.0),
+        }
+    }
+
+    #[test]
+    fn test_pre_move_realized_only_keeps_sold_buy() {
+        // AAPL bought and fully sold at +20%; MSFT bought and never sold.
+        let check = RealizedCheck::from_trades(
+            vec![
+                analytics_trade(1, "AAPL", "buy", "2024-01-02", 10.0, 100.0),
+                analytics_trade(2, "MSFT", "buy", "2024-01-02", 10.0, 100.0),
+                analytics_trade(3, "AAPL", "sell", "2024-02-15", 10.0, 120.0),
+            ],
+            365,
+            false,
+        );
+        let trades = vec![rising_buy(1, "AAPL"), rising_buy(2, "MSFT")];
+
+        let tagged = detect_pre_move_trades(&trades, 10.0, Some(&check));
+        assert_eq!(tagged.len(), 2);
+        assert_eq!(tagged[0].realized_fraction, Some(1.0));
+        assert!((tagged[0].realized_return_pct.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(tagged[1].realized_fraction, Some(0.0));
+        assert_eq!(tagged[1].realized_return_pct, None);
+
+        let realized_only = RealizedCheck {
+            realized_only: true,
+            ..check
+        };
+        let kept = detect_pre_move_trades(&trades, 10.0, Some(&realized_only));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].tx_id, 1);
+    }
+
+    #[test]
+    fn test_pre_move_realized_partial_sale_and_horizon() {
+        // Half sold after 44 days, the rest more than a year later.
+        let check = RealizedCheck::from_trades(
+            vec![
+                analytics_trade(1, "AAPL", "buy", "2024-01-02", 10.0, 100.0),
+                analytics_trade(2, "AAPL", "sell", "2024-02-15", 5.0, 130.0),
+                analytics_trade(3, "AAPL", "sell", "2025-06-01", 5.0, 90.0),
+            ],
+            90,
+            true,
+        );
+        let signals = detect_pre_move_trades(&[rising_buy(1, "AAPL")], 10.0, Some(&check));
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].realized_fraction, Some(0.5));
+        assert!((signals[0].realized_return_pct.unwrap() - 30.0).abs() < 1e-9);
+
+        // Sells are never tagged and are dropped in realized-only mode.
+        let mut sell = rising_buy(4, "AAPL");
+        sell.tx_type = "sell".to_string();
+        assert!(detect_pre_move_trades(&[sell.clone()], 10.0, Some(&check)).is_empty());
+        let tag_only = RealizedCheck {
+            realized_only: false,
+            ..check
+        };
+        let tagged = detect_pre_move_trades(&[sell], 10.0, Some(&tag_only));
+        assert_eq!(tagged[0].realized_fraction, None);
+    }
+
     // Volume detection tests
     #[test]
     fn test_volume_unusual_spike() {
@@ -742,7 +1928,7 @@ mod tests {
             },
         ];
 
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 1);
         assert!((score.sector_weights["Information Technology"] - 100.0).abs() < 0.01);
         assert!((score.hhi_score - 1.0).abs() < 0.01);
@@ -765,7 +1951,7 @@ mod tests {
             },
         ];
 
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 2);
         assert!((score.sector_weights["Information Technology"] - 50.0).abs() < 0.01);
         assert!((score.sector_weights["Financials"] - 50.0).abs() < 0.01);
@@ -798,7 +1984,7 @@ mod tests {
             },
         ];
 
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 4);
         assert!((score.hhi_score - 0.25).abs() < 0.01); // 4 * 0.25^2 = 0.25
         assert!(!score.is_concentrated); // 0.25 == 0.25 (not >)
@@ -819,7 +2005,7 @@ mod tests {
             },
         ];
 
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 1);
         assert!((score.sector_weights["Information Technology"] - 100.0).abs() < 0.01);
         assert!((score.hhi_score - 1.0).abs() < 0.01);
@@ -829,7 +2015,7 @@ mod tests {
     #[test]
     fn test_hhi_empty_positions() {
         let positions: Vec<PortfolioPositionForHHI> = vec![];
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 0);
         assert_eq!(score.hhi_score, 0.0);
         assert_eq!(score.dominant_sector, None);
@@ -851,7 +2037,7 @@ mod tests {
             },
         ];
 
-        let score = calculate_sector_concentration(&positions);
+        let score = calculate_sector_concentration(&positions, &ConcentrationConfig::default());
         assert_eq!(score.sector_weights.len(), 1);
         assert!((score.hhi_score - 1.0).abs() < 0.01);
     }
@@ -859,7 +2045,7 @@ mod tests {
     // Composite score tests
     #[test]
     fn test_composite_all_signals() {
-        let score = calculate_composite_anomaly_score(5, 3.0, 0.4);
+        let score = calculate_composite_anomaly_score(5, 3.0, 0.4, &CompositeConfig::default());
         assert!((score.pre_move_norm - 0.5).abs() < 0.01); // 5/10 = 0.5
         assert!((score.volume_norm - 0.6).abs() < 0.01); // 3.0/5.0 = 0.6
         assert!((score.concentration_norm - 0.4).abs() < 0.01); // 0.4 directly
@@ -869,7 +2055,7 @@ mod tests {
 
     #[test]
     fn test_composite_no_signals() {
-        let score = calculate_composite_anomaly_score(0, 0.0, 0.0);
+        let score = calculate_composite_anomaly_score(0, 0.0, 0.0, &CompositeConfig::default());
         assert_eq!(score.pre_move_norm, 0.0);
         assert_eq!(score.volume_norm, 0.0);
         assert_eq!(score.concentration_norm, 0.0);
@@ -879,11 +2065,261 @@ mod tests {
 
     #[test]
     fn test_composite_capped_at_one() {
-        let score = calculate_composite_anomaly_score(15, 10.0, 1.0);
+        let score = calculate_composite_anomaly_score(15, 10.0, 1.0, &CompositeConfig::default());
         assert!((score.pre_move_norm - 1.0).abs() < 0.01); // 15/10 = 1.5, capped to 1.0
         assert!((score.volume_norm - 1.0).abs() < 0.01); // 10/5 = 2.0, capped to 1.0
         assert!((score.concentration_norm - 1.0).abs() < 0.01); // 1.0 directly
         assert!((score.composite - 1.0).abs() < 0.01); // (1.0 + 1.0 + 1.0)/3 = 1.0
         assert!((score.confidence - 1.0).abs() < 0.01);
     }
+
+    fn crowd_trade(tx_id: i64, politician: &str, party: &str, day: i64, tx_type: &str) -> CrowdingTrade {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap() + chrono::Duration::days(day);
+        CrowdingTrade {
+            tx_id,
+            politician_id: politician.to_string(),
+            politician_name: format!("Member {}", politician),
+            party: party.to_string(),
+            issuer_id: 7,
+            issuer_name: "Lockheed Martin Corp".to_string(),
+            ticker: "LMT:US".to_string(),
+            tx_type: tx_type.to_string(),
+            tx_date: date.format("%Y-%m-%d").to_string(),
+            value: 15000,
+        }
+    }
+
+    /// Six buys by six politicians, four days apart: days 0..=20.
+    fn six_buys() -> Vec<CrowdingTrade> {
+        ["P1", "P2", "P3", "P4", "P5", "P6"]
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let party = if i % 2 == 0 { "Democrat" } else { "Republican" };
+                crowd_trade(i as i64 + 1, p, party, i as i64 * 4, "buy")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_crowded_trades_merge_overlapping_windows() {
+        // A 12-day window reaches exactly the fourth buy (day 0 -> day 12), so
+        // the windows anchored on days 0, 4 and 8 all qualify and overlap.
+        let signals = detect_crowded_trades(&six_buys(), 12, 4, TradeDirection::Buy);
+        assert_eq!(signals.len(), 1);
+        let s = &signals[0];
+        assert_eq!(s.window_start, "2024-03-01");
+        assert_eq!(s.window_end, "2024-03-21");
+        assert_eq!(s.politician_count(), 6);
+        assert_eq!(s.trade_count, 6);
+        assert_eq!(s.total_value, 90000);
+        assert_eq!((s.democrats, s.republicans, s.other_party), (3, 3, 0));
+        assert_eq!(s.direction, TradeDirection::Buy);
+
+        // One day shorter and no window holds four buys.
+        assert!(detect_crowded_trades(&six_buys(), 11, 4, TradeDirection::Buy).is_empty());
+        // A window past the end of the calendar holds every buy.
+        let signals = detect_crowded_trades(&six_buys(), i64::MAX, 6, TradeDirection::Buy);
+        assert_eq!(signals.len(), 1);
+    }
+
+    #[test]
+    fn test_crowded_trades_separate_runs_are_not_merged() {
+        // Windows anchored on days 0 and 12 qualify; day 0's ends at day 8,
+        // before day 12's starts, so they stay separate signals.
+        let mut trades = six_buys();
+        for t in trades.iter_mut().skip(3) {
+            let date = NaiveDate::parse_from_str(&t.tx_date, "%Y-%m-%d").unwrap();
+            t.tx_date = (date + chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+        }
+        let signals = detect_crowded_trades(&trades, 8, 3, TradeDirection::Buy);
+        assert_eq!(signals.len(), 2);
+        let mut windows: Vec<_> = signals
+            .iter()
+            .map(|s| (s.window_start.as_str(), s.window_end.as_str()))
+            .collect();
+        windows.sort();
+        assert_eq!(
+            windows,
+            vec![("2024-03-01", "2024-03-09"), ("2024-03-14", "2024-03-22")]
+        );
+    }
+
+    #[test]
+    fn test_crowded_exits_use_sells_only() {
+        let mut trades = six_buys();
+        trades.push(crowd_trade(10, "P1", "Democrat", 1, "sell"));
+        trades.push(crowd_trade(11, "P2", "Republican", 2, "sell"));
+        trades.push(crowd_trade(12, "P7", "Independent", 3, "sell"));
+        // The same politician selling twice still counts once.
+        trades.push(crowd_trade(13, "P7", "Independent", 4, "sell"));
+
+        let exits = detect_crowded_trades(&trades, 14, 3, TradeDirection::Sell);
+        assert_eq!(exits.len(), 1);
+        assert_eq!(exits[0].direction, TradeDirection::Sell);
+        assert_eq!(exits[0].trade_count, 4);
+        assert_eq!(exits[0].politician_count(), 3);
+        assert_eq!(exits[0].other_party, 1);
+        assert!(detect_crowded_trades(&trades, 14, 4, TradeDirection::Sell).is_empty());
+        assert_eq!("sells".parse::<TradeDirection>(), Ok(TradeDirection::Sell));
+    }
+
+    #[test]
+    fn test_anomaly_config_defaults_match_fixed_behavior() {
+        let config = AnomalyConfig::default();
+        assert_eq!(config.pre_move.threshold_pct, 10.0);
+        assert_eq!(config.volume.unusual_ratio, 2.0);
+        assert_eq!(config.concentration.hhi_threshold, 0.25);
+        assert_eq!(config.crowded.min_politicians, 5);
+        assert_eq!(config.sells, SellParams::default());
+
+        // Equal weights reproduce the plain three-way average.
+        let score = calculate_composite_anomaly_score(5, 3.0, 0.4, &config.composite);
+        assert!((score.composite - (0.5 + 0.6 + 0.4) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_anomaly_config_partial_toml_merges_over_defaults() {
+        let config = AnomalyConfig::from_toml_str(
+            r#"
+[volume]
+unusual_ratio = 3.5
+
+[sells]
+decline_pct = 15.0
+"#,
+        )
+        .unwrap();
+        let defaults = AnomalyConfig::default();
+
+        assert_eq!(config.volume.unusual_ratio, 3.5);
+        assert_eq!(config.volume.lookback_days, defaults.volume.lookback_days);
+        assert_eq!(config.volume.baseline_days, defaults.volume.baseline_days);
+        assert_eq!(config.sells.decline_pct, 15.0);
+        assert_eq!(config.sells.near_peak_pct, defaults.sells.near_peak_pct);
+        assert_eq!(config.pre_move, defaults.pre_move);
+        assert_eq!(config.composite, defaults.composite);
+
+        assert_eq!(AnomalyConfig::from_toml_str("").unwrap(), defaults);
+    }
+
+    #[test]
+    fn test_anomaly_config_partial_json_merges_over_defaults() {
+        let config =
+            AnomalyConfig::from_json_str(r#"{"concentration": {"hhi_threshold": 0.4}}"#).unwrap();
+        assert_eq!(config.concentration.hhi_threshold, 0.4);
+        assert_eq!(config.crowded, CrowdedConfig::default());
+
+        // HHI 0.34 is concentrated by default but not at 0.4.
+        let positions = vec![
+            PortfolioPositionForHHI {
+                ticker: "A".to_string(),
+                gics_sector: Some("Tech".to_string()),
+                estimated_value: 50.0,
+            },
+            PortfolioPositionForHHI {
+                ticker: "B".to_string(),
+                gics_sector: Some("Energy".to_string()),
+                estimated_value: 30.0,
+            },
+            PortfolioPositionForHHI {
+                ticker: "C".to_string(),
+                gics_sector: Some("Health".to_string()),
+                estimated_value: 20.0,
+            },
+        ];
+        let default_hhi = ConcentrationConfig::default();
+        assert!(calculate_sector_concentration(&positions, &default_hhi).is_concentrated);
+        assert!(!calculate_sector_concentration(&positions, &config.concentration).is_concentrated);
+    }
+
+    #[test]
+    fn test_anomaly_config_rejects_bad_input() {
+        assert!(matches!(
+            AnomalyConfig::from_toml_str("[volume]\nunusal_ratio = 3.0\n"),
+            Err(AnomalyConfigError::TomlParse(_))
+        ));
+        assert!(matches!(
+            AnomalyConfig::from_toml_str("[volume]\nlookback_days = 0\n"),
+            Err(AnomalyConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            AnomalyConfig::from_toml_str("[sells]\ndrawdown_days = 9223372036854775807\n"),
+            Err(AnomalyConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            AnomalyConfig::from_toml_str("[events]\nwindow_days = 4000000000\n"),
+            Err(AnomalyConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            AnomalyConfig::from_toml_str("[crowded]\nwindow_days = 9223372036854775807\n"),
+            Err(AnomalyConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            AnomalyConfig::from_json_str(
+                r#"{"composite": {"pre_move_weight": 0, "volume_weight": 0, "concentration_weight": 0}}"#
+            ),
+            Err(AnomalyConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_unusual_ratio_threshold_is_configurable() {
+        let reference = NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+        // 3 recent trades vs 4 baseline trades over 4x the window: ratio 3.0.
+        let dates = [
+            "2024-06-01", "2024-05-20", "2024-05-10",
+            "2024-03-01", "2024-01-15", "2023-12-01", "2023-10-01",
+        ];
+        let mut volume = VolumeConfig {
+            lookback_days: 90,
+            baseline_days: 360,
+            unusual_ratio: 2.0,
+        };
+        let flagged = volume_signal(dates.into_iter(), &VolumeParams::from_config(reference, &volume));
+        volume.unusual_ratio = 3.5;
+        let quiet = volume_signal(dates.into_iter(), &VolumeParams::from_config(reference, &volume));
+        assert_eq!(flagged.volume_ratio, quiet.volume_ratio);
+        assert!(flagged.is_unusual);
+        assert!(!quiet.is_unusual);
+    }
+
+    fn novelty_candidate(previous: Option<&str>, scraped: Option<(i64, &str)>) -> NoveltyCandidate {
+        NoveltyCandidate {
+            tx_id: 1,
+            politician_id: "P000001".to_string(),
+            politician_name: "John Doe".to_string(),
+            issuer_id: 100,
+            issuer_name: "Issuer".to_string(),
+            ticker: "ISS:US".to_string(),
+            sector: None,
+            mcap: None,
+            tx_type: "buy".to_string(),
+            tx_date: "2024-06-01".to_string(),
+            value: 8000,
+            previous_trade_date: previous.map(str::to_string),
+            local_trade_count: if previous.is_some() { 2 } else { 1 },
+            scraped_trade_count: scraped.map(|(count, _)| count),
+            scraped_date_last_traded: scraped.map(|(_, date)| date.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_novelty_confidence_against_scraped_last_traded() {
+        use NoveltyConfidence::*;
+        assert_eq!(novelty_confidence(&novelty_candidate(None, None)), Unverified);
+        assert_eq!(novelty_confidence(&novelty_candidate(None, Some((1, "2024-06-01")))), High);
+        // Scraped activity before this trade that the local history lacks.
+        assert_eq!(novelty_confidence(&novelty_candidate(None, Some((1, "2023-11-20")))), Low);
+        assert_eq!(
+            novelty_confidence(&novelty_candidate(Some("2019-01-01"), Some((2, "2022-03-01T00:00:00")))),
+            Low
+        );
+        // The scraped last trade is the local previous trade: consistent.
+        assert_eq!(
+            novelty_confidence(&novelty_candidate(Some("2019-01-01"), Some((2, "2019-01-01")))),
+            High
+        );
+        assert_eq!(novelty_confidence(&novelty_candidate(None, Some((4, "2024-06-01")))), Low);
+    }
 }
@@ -1,6 +1,10 @@
-//! In-memory TTL cache backed by `DashMap` for concurrent access.
+//! In-memory caches: a TTL cache backed by `DashMap` for concurrent access,
+//! and a bounded LRU cache for price client responses.
 
 use dashmap::DashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 /// A single cached value with its expiration time.
@@ -55,6 +59,84 @@ impl MemoryCache {
     }
 }
 
+/// Thread-safe cache holding at most `capacity` entries, evicting the least
+/// recently used one when full.
+///
+/// A capacity of zero disables the cache: lookups always miss and inserts
+/// are dropped.
+pub struct LruCache<K, V> {
+    capacity: usize,
+    inner: Mutex<LruState<K, V>>,
+}
+
+struct LruState<K, V> {
+    /// Value and last-use stamp per key.
+    entries: HashMap<K, (V, u64)>,
+    /// Keys by last-use stamp; the first entry is the eviction candidate.
+    order: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(LruState {
+                entries: HashMap::new(),
+                order: BTreeMap::new(),
+                clock: 0,
+            }),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a clone of the cached value and marks it most recently used.
+    pub fn get(&self, key: &K) -> Option<V> {
+        if self.capacity == 0 {
+            return None;
+        }
+        let mut state = self.inner.lock().expect("lru cache poisoned");
+        state.clock += 1;
+        let now = state.clock;
+        let (value, stamp) = state.entries.get_mut(key)?;
+        let value = value.clone();
+        let old = std::mem::replace(stamp, now);
+        state.order.remove(&old);
+        state.order.insert(now, key.clone());
+        Some(value)
+    }
+
+    /// Inserts or replaces `key`, evicting the least recently used entry if
+    /// the cache is full.
+    pub fn insert(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.inner.lock().expect("lru cache poisoned");
+        state.clock += 1;
+        let now = state.clock;
+        if let Some((_, old)) = state.entries.insert(key.clone(), (value, now)) {
+            state.order.remove(&old);
+        } else if state.entries.len() > self.capacity {
+            if let Some((_, evicted)) = state.order.pop_first() {
+                state.entries.remove(&evicted);
+            }
+        }
+        state.order.insert(now, key);
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().expect("lru cache poisoned").entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +179,36 @@ mod tests {
         assert_eq!(cache.get("a"), None);
         assert_eq!(cache.get("b"), None);
     }
+
+    #[test]
+    fn lru_evicts_least_recently_used() {
+        let cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // Touch "a" so "b" becomes the eviction candidate.
+        assert_eq!(cache.get(&"a"), Some(1));
+        cache.insert("c", 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(1));
+        assert_eq!(cache.get(&"c"), Some(3));
+    }
+
+    #[test]
+    fn lru_replace_keeps_size() {
+        let cache = LruCache::new(2);
+        cache.insert("a", 1);
+        cache.insert("a", 2);
+        cache.insert("b", 3);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a"), Some(2));
+    }
+
+    #[test]
+    fn lru_zero_capacity_disables() {
+        let cache = LruCache::new(0);
+        cache.insert("a", 1);
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&"a"), None);
+    }
 }
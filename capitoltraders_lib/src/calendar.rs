@@ -0,0 +1,311 @@
+//! Congressional session calendar: whether a chamber was in session or in
+//! recess on a given day.
+//!
+//! Calendars are plain CSV with a version header:
+//!
+//! ```text
+//! # version: 2023-2025-work-periods
+//! chamber,start,end,status
+//! house,2024-07-26,2024-09-08,recess
+//! senate,2024-07-08,2024-08-01,session
+//! ```
+//!
+//! Ranges are inclusive and must not overlap within a chamber in one file.
+//! The House and Senate keep different schedules, so every lookup names a
+//! chamber. Days no range covers are [`SessionStatus::Unknown`].
+//!
+//! The bundled file (`seed_data/session_calendar.csv`, embedded with
+//! `include_str!` like `zip_district.rs`) covers the major work periods of
+//! recent years. A user file named by `session_calendar_file` in the config
+//! file is layered on top with [`SessionCalendar::extend`]: it can add years
+//! the bundled file lacks or correct individual ranges.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Error types for session calendar files.
+#[derive(Error, Debug)]
+pub enum CalendarError {
+    #[error("Failed to read session calendar {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Session calendar is missing a '# version: ...' line")]
+    MissingVersion,
+    #[error("Invalid session calendar row at line {line}: {message}")]
+    InvalidRow { line: usize, message: String },
+}
+
+/// Whether a chamber was sitting on a given day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SessionStatus {
+    InSession,
+    Recess,
+    /// Outside the calendar's coverage for that chamber, or no chamber.
+    Unknown,
+}
+
+impl SessionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionStatus::InSession => "in_session",
+            SessionStatus::Recess => "recess",
+            SessionStatus::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SessionRange {
+    start: NaiveDate,
+    end: NaiveDate,
+    status: SessionStatus,
+}
+
+/// Parsed session calendar, keyed by lowercase chamber.
+#[derive(Debug, Clone)]
+pub struct SessionCalendar {
+    version: String,
+    /// Per chamber, one list of ranges per layer; later layers win.
+    layers: HashMap<String, Vec<Vec<SessionRange>>>,
+}
+
+impl SessionCalendar {
+    /// Parse calendar CSV content. Blank lines and `#` comments are skipped;
+    /// a `chamber,start,end,status` header row is optional.
+    pub fn parse(content: &str) -> Result<Self, CalendarError> {
+        let mut version = None;
+        let mut ranges: HashMap<String, Vec<SessionRange>> = HashMap::new();
+
+        for (idx, raw_line) in content.lines().enumerate() {
+            let line_no = idx + 1;
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(value) = comment.trim().strip_prefix("version:") {
+                    version = Some(value.trim().to_string());
+                }
+                continue;
+            }
+
+            let invalid = |message: String| CalendarError::InvalidRow {
+                line: line_no,
+                message,
+            };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 4 {
+                return Err(invalid(format!("expected 4 fields, found {}", fields.len())));
+            }
+            if fields[0].eq_ignore_ascii_case("chamber") {
+                continue;
+            }
+
+            let chamber = fields[0].to_lowercase();
+            if chamber != "house" && chamber != "senate" {
+                return Err(invalid(format!(
+                    "chamber '{}' must be 'house' or 'senate'",
+                    fields[0]
+                )));
+            }
+            let date = |field: &str| {
+                NaiveDate::parse_from_str(field, "%Y-%m-%d")
+                    .map_err(|_| invalid(format!("'{}' is not a YYYY-MM-DD date", field)))
+            };
+            let start = date(fields[1])?;
+            let end = date(fields[2])?;
+            if end < start {
+                return Err(invalid(format!("range ends ({}) before it starts ({})", end, start)));
+            }
+            let status = match fields[3].to_lowercase().as_str() {
+                "session" | "in_session" => SessionStatus::InSession,
+                "recess" => SessionStatus::Recess,
+                other => {
+                    return Err(invalid(format!(
+                        "status '{}' must be 'session' or 'recess'",
+                        other
+                    )))
+                }
+            };
+
+            let chamber_ranges = ranges.entry(chamber.clone()).or_default();
+            if let Some(clash) = chamber_ranges
+                .iter()
+                .find(|r| r.start <= end && start <= r.end)
+            {
+                return Err(invalid(format!(
+                    "{} range {}..{} overlaps {}..{}",
+                    chamber, start, end, clash.start, clash.end
+                )));
+            }
+            chamber_ranges.push(SessionRange { start, end, status });
+        }
+
+        Ok(Self {
+            version: version.ok_or(CalendarError::MissingVersion)?,
+            layers: ranges
+                .into_iter()
+                .map(|(chamber, mut ranges)| {
+                    ranges.sort_by_key(|r| r.start);
+                    (chamber, vec![ranges])
+                })
+                .collect(),
+        })
+    }
+
+    /// Load a user-supplied calendar file on its own.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CalendarError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|source| CalendarError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::parse(&content)
+    }
+
+    /// Load the calendar embedded at compile time.
+    pub fn bundled() -> Result<Self, CalendarError> {
+        Self::parse(include_str!("../../seed_data/session_calendar.csv"))
+    }
+
+    /// The bundled calendar, extended with `user_file` when one is given.
+    pub fn load(user_file: Option<&Path>) -> Result<Self, CalendarError> {
+        let mut calendar = Self::bundled()?;
+        if let Some(path) = user_file {
+            calendar.extend(Self::from_file(path)?);
+        }
+        Ok(calendar)
+    }
+
+    /// Layer `other` on top: where its ranges overlap ours, its status wins.
+    pub fn extend(&mut self, other: SessionCalendar) {
+        self.version = format!("{}+{}", self.version, other.version);
+        for (chamber, layers) in other.layers {
+            self.layers.entry(chamber).or_default().extend(layers);
+        }
+    }
+
+    /// Version label(s), joined with `+` once extended.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Status of `chamber` (`house` or `senate`, any case) on `date`.
+    pub fn status(&self, chamber: &str, date: NaiveDate) -> SessionStatus {
+        let Some(layers) = self.layers.get(&chamber.trim().to_lowercase()) else {
+            return SessionStatus::Unknown;
+        };
+        layers
+            .iter()
+            .rev()
+            .find_map(|ranges| {
+                let at = ranges.partition_point(|r| r.start <= date);
+                at.checked_sub(1)
+                    .map(|i| &ranges[i])
+                    .filter(|r| date <= r.end)
+                    .map(|r| r.status)
+            })
+            .unwrap_or(SessionStatus::Unknown)
+    }
+
+    /// First and last day any layer covers for `chamber`. Coverage can have
+    /// gaps in between; those days are still Unknown.
+    pub fn coverage(&self, chamber: &str) -> Option<(NaiveDate, NaiveDate)> {
+        let ranges = self
+            .layers
+            .get(&chamber.trim().to_lowercase())?
+            .iter()
+            .flatten();
+        let start = ranges.clone().map(|r| r.start).min()?;
+        let end = ranges.map(|r| r.end).max()?;
+        Some((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_bundled_calendar_pins_each_chamber() {
+        let calendar = SessionCalendar::bundled().unwrap();
+        assert_eq!(calendar.version(), "2023-2025-work-periods");
+
+        // August 2024 district work period.
+        assert_eq!(calendar.status("house", date("2024-08-15")), SessionStatus::Recess);
+        assert_eq!(calendar.status("senate", date("2024-08-15")), SessionStatus::Recess);
+        assert_eq!(calendar.status("house", date("2024-03-06")), SessionStatus::InSession);
+        assert_eq!(calendar.status("senate", date("2024-03-06")), SessionStatus::InSession);
+
+        // The Senate sat into late July 2024 after the House had left.
+        assert_eq!(calendar.status("House", date("2024-07-30")), SessionStatus::Recess);
+        assert_eq!(calendar.status("Senate", date("2024-07-30")), SessionStatus::InSession);
+    }
+
+    #[test]
+    fn test_outside_coverage_is_unknown() {
+        let calendar = SessionCalendar::bundled().unwrap();
+        assert_eq!(calendar.status("house", date("2019-06-03")), SessionStatus::Unknown);
+        assert_eq!(calendar.status("senate", date("2031-06-03")), SessionStatus::Unknown);
+        assert_eq!(calendar.status("", date("2024-03-06")), SessionStatus::Unknown);
+        assert_eq!(
+            calendar.coverage("house"),
+            Some((date("2023-01-03"), date("2025-12-31")))
+        );
+    }
+
+    #[test]
+    fn test_user_rows_extend_and_override() {
+        let mut calendar = SessionCalendar::bundled().unwrap();
+        calendar.extend(
+            SessionCalendar::parse(
+                "# version: local\n\
+                 house,2019-01-03,2019-12-31,session\n\
+                 house,2024-08-12,2024-08-13,session\n",
+            )
+            .unwrap(),
+        );
+        assert_eq!(calendar.version(), "2023-2025-work-periods+local");
+        assert_eq!(calendar.status("house", date("2019-06-03")), SessionStatus::InSession);
+        assert_eq!(calendar.status("house", date("2024-08-12")), SessionStatus::InSession);
+        assert_eq!(calendar.status("house", date("2024-08-14")), SessionStatus::Recess);
+        assert_eq!(calendar.status("senate", date("2019-06-03")), SessionStatus::Unknown);
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_rows() {
+        let cases = [
+            "house,2024-01-01,2024-01-31\n",
+            "congress,2024-01-01,2024-01-31,session\n",
+            "house,2024-02-01,2024-01-31,session\n",
+            "house,2024-01-01,2024-01-31,adjourned\n",
+            "house,2024-01-01,2024-01-31,session\nhouse,2024-01-31,2024-02-10,recess\n",
+        ];
+        for body in cases {
+            let content = format!("# version: t\n{}", body);
+            assert!(
+                matches!(
+                    SessionCalendar::parse(&content),
+                    Err(CalendarError::InvalidRow { .. })
+                ),
+                "{body}"
+            );
+        }
+        assert!(matches!(
+            SessionCalendar::parse("house,2024-01-01,2024-01-31,session\n"),
+            Err(CalendarError::MissingVersion)
+        ));
+    }
+}
@@ -1,16 +1,17 @@
 //! Caching and rate-limiting wrapper around the API client.
 
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use capitoltrades_api::types::{
     IssuerDetail, PaginatedResponse, PoliticianDetail, Response, Trade,
 };
-use capitoltrades_api::{Client, IssuerQuery, PoliticianQuery, TradeQuery};
+use capitoltrades_api::{Client, IssuerQuery, PoliticianQuery, Query, TradeQuery};
 use rand::Rng;
 
 use crate::cache::MemoryCache;
 use crate::error::CapitolTradesError;
+use crate::observer::{self, ObserverSlot, RequestObserver, Service};
 
 /// API client wrapper that adds in-memory caching and rate limiting.
 ///
@@ -22,6 +23,7 @@ pub struct CachedClient {
     cache: MemoryCache,
     /// Tracks when the last HTTP request was sent, for rate limiting.
     last_request: Mutex<Option<Instant>>,
+    observer: ObserverSlot,
 }
 
 struct RetryConfig {
@@ -58,6 +60,7 @@ impl CachedClient {
             inner: Client::new(),
             cache,
             last_request: Mutex::new(None),
+            observer: ObserverSlot::default(),
         }
     }
 
@@ -67,9 +70,38 @@ impl CachedClient {
             inner: Client::with_base_url(base_url),
             cache,
             last_request: Mutex::new(None),
+            observer: ObserverSlot::default(),
         }
     }
 
+    /// Report every API request to `observer` (see [`crate::observer`]).
+    ///
+    /// The vendored API client only surfaces a status code on failure, so
+    /// successful responses are reported as `Some(200)`.
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = ObserverSlot::new(observer);
+        self
+    }
+
+    /// Await an API call, reporting it to the observer if one is set.
+    async fn observed<T>(
+        &self,
+        endpoint: &'static str,
+        request: impl std::future::Future<Output = Result<T, capitoltrades_api::Error>>,
+    ) -> Result<T, CapitolTradesError> {
+        let span = self.observer.start(Service::CapitolTrades, endpoint);
+        let result = request.await;
+        if span.is_some() {
+            let status = match &result {
+                Ok(_) => Some(200),
+                Err(capitoltrades_api::Error::HttpStatus { status, .. }) => Some(*status),
+                Err(capitoltrades_api::Error::RequestFailed) => None,
+            };
+            observer::finish(span, status);
+        }
+        Ok(result?)
+    }
+
     async fn rate_limit(&self) {
         let sleep_dur = {
             let last = self.last_request.lock().unwrap_or_else(|e| e.into_inner());
@@ -135,7 +167,7 @@ impl CachedClient {
         let resp = self
             .with_retry("trades", || async {
                 self.rate_limit().await;
-                Ok(self.inner.get_trades(query).await?)
+                self.observed("trades", self.inner.get_trades(query)).await
             })
             .await?;
         if let Ok(json) = serde_json::to_string(&resp) {
@@ -159,7 +191,7 @@ impl CachedClient {
         let resp = self
             .with_retry("politicians", || async {
                 self.rate_limit().await;
-                Ok(self.inner.get_politicians(query).await?)
+                self.observed("politicians", self.inner.get_politicians(query)).await
             })
             .await?;
         if let Ok(json) = serde_json::to_string(&resp) {
@@ -183,7 +215,7 @@ impl CachedClient {
         let resp = self
             .with_retry("issuer", || async {
                 self.rate_limit().await;
-                Ok(self.inner.get_issuer(issuer_id).await?)
+                self.observed("issuer", self.inner.get_issuer(issuer_id)).await
             })
             .await?;
         if let Ok(json) = serde_json::to_string(&resp) {
@@ -207,7 +239,7 @@ impl CachedClient {
         let resp = self
             .with_retry("issuers", || async {
                 self.rate_limit().await;
-                Ok(self.inner.get_issuers(query).await?)
+                self.observed("issuers", self.inner.get_issuers(query)).await
             })
             .await?;
         if let Ok(json) = serde_json::to_string(&resp) {
@@ -216,12 +248,58 @@ impl CachedClient {
         Ok(resp)
     }
 
+    /// Looks up an issuer by exact ticker using the API's issuer search.
+    ///
+    /// Search is fuzzy, so results are filtered to ticker equality
+    /// (case-insensitive); a bare ticker like `AAPL` also matches an
+    /// exchange-suffixed `AAPL:US`. Returns `None` when nothing matches
+    /// exactly, never the closest near-match.
+    pub async fn get_issuer_by_ticker(
+        &self,
+        ticker: &str,
+    ) -> Result<Option<IssuerDetail>, CapitolTradesError> {
+        let ticker = ticker.trim();
+        if ticker.is_empty() {
+            return Ok(None);
+        }
+        let query = IssuerQuery::default().with_search(ticker).with_page_size(100);
+        let resp = self.get_issuers(&query).await?;
+        Ok(best_ticker_match(resp.data, ticker))
+    }
+
     /// Removes all entries from the cache.
     pub fn clear_cache(&self) {
         self.cache.clear();
     }
 }
 
+/// Picks the issuer whose ticker equals `ticker`, preferring a full match
+/// (including any exchange suffix) over a base-symbol match, then the issuer
+/// with the most trades.
+fn best_ticker_match(issuers: Vec<IssuerDetail>, ticker: &str) -> Option<IssuerDetail> {
+    let rank = |issuer: &IssuerDetail| -> Option<u8> {
+        let candidate = issuer.issuer_ticker.as_deref()?.trim();
+        if candidate.eq_ignore_ascii_case(ticker) {
+            Some(2)
+        } else if !ticker.contains(':')
+            && candidate
+                .split(':')
+                .next()
+                .is_some_and(|base| base.eq_ignore_ascii_case(ticker))
+        {
+            Some(1)
+        } else {
+            None
+        }
+    };
+
+    issuers
+        .into_iter()
+        .filter_map(|issuer| rank(&issuer).map(|r| (r, issuer)))
+        .max_by_key(|(r, issuer)| (*r, issuer.stats.count_trades))
+        .map(|(_, issuer)| issuer)
+}
+
 fn is_retryable(err: &CapitolTradesError) -> bool {
     match err {
         CapitolTradesError::Api(api_err) => match api_err {
@@ -355,3 +433,84 @@ fn query_to_cache_key_issuer(query: &IssuerQuery) -> String {
         query.common.sort_direction as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Issuers fixture response holding one copy of the first issuer per (id, ticker, countTrades).
+    fn issuers_with_tickers(tickers: &[(i64, &str, i64)]) -> serde_json::Value {
+        let json = include_str!("../../capitoltrades_api/tests/fixtures/issuers.json");
+        let mut resp: serde_json::Value = serde_json::from_str(json).unwrap();
+        let template = resp["data"][0].clone();
+        let data: Vec<serde_json::Value> = tickers
+            .iter()
+            .map(|(id, ticker, count)| {
+                let mut issuer = template.clone();
+                issuer["_issuerId"] = (*id).into();
+                issuer["issuerTicker"] = (*ticker).into();
+                issuer["stats"]["countTrades"] = (*count).into();
+                issuer
+            })
+            .collect();
+        resp["data"] = data.into();
+        resp
+    }
+
+    fn parse_issuers(value: serde_json::Value) -> Vec<IssuerDetail> {
+        serde_json::from_value(value["data"].clone()).unwrap()
+    }
+
+    #[test]
+    fn best_ticker_match_rejects_near_matches() {
+        let issuers = parse_issuers(issuers_with_tickers(&[
+            (1, "AAPLX", 50),
+            (2, "AAP:US", 40),
+            (3, "APPL", 30),
+        ]));
+        assert!(best_ticker_match(issuers, "AAPL").is_none());
+    }
+
+    #[test]
+    fn best_ticker_match_prefers_full_then_most_traded() {
+        let issuers = parse_issuers(issuers_with_tickers(&[
+            (1, "brk/b:us", 5),
+            (2, "BRK/B:LN", 500),
+            (3, "BRK/BX", 900),
+        ]));
+        assert_eq!(
+            best_ticker_match(issuers, "BRK/B:US").map(|i| i.issuer_id),
+            Some(1)
+        );
+
+        let issuers = parse_issuers(issuers_with_tickers(&[
+            (1, "BRK/B:US", 5),
+            (2, "BRK/B:LN", 500),
+        ]));
+        assert_eq!(
+            best_ticker_match(issuers, "brk/b").map(|i| i.issuer_id),
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn get_issuer_by_ticker_searches_by_ticker() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/issuers"))
+            .and(query_param("search", "msft"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issuers_with_tickers(&[
+                (10, "MSFTX", 3),
+                (11, "MSFT:US", 7),
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = CachedClient::with_base_url(&server.uri(), MemoryCache::new(Duration::from_secs(60)));
+        let issuer = client.get_issuer_by_ticker("msft").await.unwrap();
+        assert_eq!(issuer.map(|i| i.issuer_id), Some(11));
+    }
+}
@@ -2,10 +2,31 @@
 //!
 //! Provides CommitteeResolver for mapping CapitolTrades politician IDs to their
 //! authorized FEC committees, using a tiered cache strategy (DashMap -> SQLite -> API).
+//!
+//! ## Committee classes
+//!
+//! [`CommitteeClass::classify`] maps the FEC `designation` and `committee_type`
+//! codes stored in `fec_committees` to readable classes. Designation is checked
+//! first, then committee type:
+//!
+//! | Designation | Committee type | Class | Notes |
+//! |---|---|---|---|
+//! | `D` | any | `leadership_pac` | Leadership PAC |
+//! | `J` | any | `joint_fundraising` | Joint fundraising committee |
+//! | `A`, `P` | `H`, `S`, `P` | `campaign` | Authorized / principal campaign committee |
+//! | other | `H`, `S`, `P` | `other` | Candidate committee without an authorized designation |
+//! | other | `X`, `Y`, `Z` | `party` | Party committee (non-qualified, qualified, national non-federal) |
+//! | other | `N`, `Q`, `O` | `pac` | PAC (non-qualified, qualified, super PAC) |
+//! | other | anything else / missing | `other` | Communication cost, IE filers, hybrid PACs, unknown |
+//!
+//! Donations inherit the class of the committee that received them (`source_class`),
+//! which separates money flowing to a campaign from money routed through PACs
+//! and party committees.
 
 use dashmap::DashMap;
 use rusqlite::OptionalExtension;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use thiserror::Error;
 
@@ -26,6 +47,8 @@ pub enum CommitteeError {
 
 /// Classification of FEC committee types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum CommitteeClass {
     Campaign,
     LeadershipPac,
@@ -72,6 +95,56 @@ impl CommitteeClass {
     }
 }
 
+impl CommitteeClass {
+    /// All classes, in display order.
+    pub const ALL: [CommitteeClass; 6] = [
+        Self::Campaign,
+        Self::LeadershipPac,
+        Self::JointFundraising,
+        Self::Party,
+        Self::Pac,
+        Self::Other,
+    ];
+
+    /// SQL CASE expression equivalent to [`CommitteeClass::classify`], yielding
+    /// the Display string of the class. Column arguments are inserted verbatim.
+    pub(crate) fn sql_case(type_col: &str, designation_col: &str) -> String {
+        format!(
+            "CASE
+                WHEN {d} = 'D' THEN 'leadership_pac'
+                WHEN {d} = 'J' THEN 'joint_fundraising'
+                WHEN {t} IN ('H', 'S', 'P') AND {d} IN ('A', 'P') THEN 'campaign'
+                WHEN {t} IN ('H', 'S', 'P') THEN 'other'
+                WHEN {t} IN ('X', 'Y', 'Z') THEN 'party'
+                WHEN {t} IN ('N', 'Q', 'O') THEN 'pac'
+                ELSE 'other'
+            END",
+            t = type_col,
+            d = designation_col
+        )
+    }
+}
+
+impl std::str::FromStr for CommitteeClass {
+    type Err = String;
+
+    /// Parse a class name as printed by Display; hyphens are accepted for underscores.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|class| class.to_string() == normalized)
+            .ok_or_else(|| {
+                let valid: Vec<String> = Self::ALL.iter().map(|c| c.to_string()).collect();
+                format!(
+                    "Invalid committee class '{}'. Valid options: {}",
+                    s,
+                    valid.join(", ")
+                )
+            })
+    }
+}
+
 impl std::fmt::Display for CommitteeClass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -93,11 +166,30 @@ pub struct ResolvedCommittee {
     pub classification: CommitteeClass,
 }
 
+impl ResolvedCommittee {
+    fn from_api(committee: &crate::openfec::types::Committee) -> Self {
+        Self {
+            committee_id: committee.committee_id.clone(),
+            name: committee.name.clone(),
+            classification: CommitteeClass::classify(
+                committee.committee_type.as_deref(),
+                committee.designation.as_deref(),
+            ),
+        }
+    }
+}
+
 /// Committee resolver with three-tier caching (DashMap -> SQLite -> API).
+///
+/// Two caches sit in front of SQLite: committees per politician, filled by
+/// [`resolve_committees`](Self::resolve_committees), and committee metadata by
+/// ID, filled by [`warm_cache`](Self::warm_cache) and consulted before
+/// `fec_committees` when resolving.
 pub struct CommitteeResolver {
     client: Arc<OpenFecClient>,
     db: Arc<Mutex<Db>>,
     cache: DashMap<String, Vec<ResolvedCommittee>>,
+    committees: DashMap<String, ResolvedCommittee>,
 }
 
 impl CommitteeResolver {
@@ -107,7 +199,86 @@ impl CommitteeResolver {
             client,
             db,
             cache: DashMap::new(),
+            committees: DashMap::new(),
+        }
+    }
+
+    /// Prefetch metadata for `committee_ids` so later resolution does not
+    /// look them up one at a time.
+    ///
+    /// IDs already in memory are skipped, the rest are read from
+    /// `fec_committees` in one query, and only committees missing from both
+    /// are fetched from OpenFEC -- once each, however often they appear in
+    /// `committee_ids`. Fetches go through the client's rate limiter and are
+    /// stored in `fec_committees` as they arrive, so an error part way
+    /// through keeps what was already fetched.
+    ///
+    /// Returns the number of committees fetched from the API. A committee
+    /// OpenFEC does not know is not cached and is asked for again next time.
+    pub async fn warm_cache(&self, committee_ids: &[&str]) -> Result<usize, CommitteeError> {
+        let mut seen = HashSet::new();
+        let uncached: Vec<&str> = committee_ids
+            .iter()
+            .copied()
+            .filter(|id| !self.committees.contains_key(*id) && seen.insert(*id))
+            .collect();
+        if uncached.is_empty() {
+            return Ok(0);
         }
+
+        let missing: Vec<&str> = {
+            let db = self.db.lock().expect("db mutex poisoned");
+            let placeholders: Vec<String> =
+                (1..=uncached.len()).map(|i| format!("?{}", i)).collect();
+            let mut stmt = db.conn().prepare(&format!(
+                "SELECT committee_id, name, committee_type, designation
+                 FROM fec_committees WHERE committee_id IN ({})",
+                placeholders.join(", ")
+            ))?;
+            let rows = stmt.query_map(rusqlite::params_from_iter(uncached.iter()), |row| {
+                let committee_type: Option<String> = row.get(2)?;
+                let designation: Option<String> = row.get(3)?;
+                Ok(ResolvedCommittee {
+                    committee_id: row.get(0)?,
+                    name: row.get(1)?,
+                    classification: CommitteeClass::classify(
+                        committee_type.as_deref(),
+                        designation.as_deref(),
+                    ),
+                })
+            })?;
+            for row in rows {
+                let committee = row?;
+                self.committees
+                    .insert(committee.committee_id.clone(), committee);
+            }
+            uncached
+                .into_iter()
+                .filter(|id| !self.committees.contains_key(*id))
+                .collect()
+        };
+
+        let mut fetched = 0;
+        for committee_id in missing {
+            let response = self.client.get_committee(committee_id).await?;
+            let Some(committee) = response.results.into_iter().next() else {
+                tracing::warn!("Committee {} not found in OpenFEC", committee_id);
+                continue;
+            };
+            self.db
+                .lock()
+                .expect("db mutex poisoned")
+                .upsert_committee(&committee)?;
+            self.committees
+                .insert(committee_id.to_string(), ResolvedCommittee::from_api(&committee));
+            fetched += 1;
+        }
+        Ok(fetched)
+    }
+
+    /// Metadata for one committee, if it is in the in-memory cache.
+    pub fn cached_committee(&self, committee_id: &str) -> Option<ResolvedCommittee> {
+        self.committees.get(committee_id).map(|c| c.clone())
     }
 
     /// Resolve committees for a politician using three-tier cache.
@@ -133,6 +304,11 @@ impl CommitteeResolver {
                     // Build ResolvedCommittee entries from committee metadata
                     let mut resolved = Vec::new();
                     for committee_id in &committee_ids {
+                        if let Some(cached) = self.committees.get(committee_id) {
+                            resolved.push(cached.clone());
+                            continue;
+                        }
+
                         // Query fec_committees table for metadata
                         let metadata: Option<(String, Option<String>, Option<String>)> = db
                             .conn()
@@ -220,17 +396,12 @@ impl CommitteeResolver {
         };
 
         // Classify and build ResolvedCommittee entries
-        let resolved: Vec<ResolvedCommittee> = committees
-            .iter()
-            .map(|c| ResolvedCommittee {
-                committee_id: c.committee_id.clone(),
-                name: c.name.clone(),
-                classification: CommitteeClass::classify(
-                    c.committee_type.as_deref(),
-                    c.designation.as_deref(),
-                ),
-            })
-            .collect();
+        let resolved: Vec<ResolvedCommittee> =
+            committees.iter().map(ResolvedCommittee::from_api).collect();
+        for committee in &resolved {
+            self.committees
+                .insert(committee.committee_id.clone(), committee.clone());
+        }
 
         // Store committees in DB (acquire lock again for writes)
         let db = self.db.lock().expect("db mutex poisoned");
@@ -254,9 +425,10 @@ impl CommitteeResolver {
         self.cache.len()
     }
 
-    /// Clear the memory cache (for testing or cache invalidation).
+    /// Clear the memory caches (for testing or cache invalidation).
     pub fn clear_cache(&self) {
         self.cache.clear();
+        self.committees.clear();
     }
 }
 
@@ -345,4 +517,16 @@ mod tests {
             CommitteeClass::Other
         );
     }
+
+    #[test]
+    fn test_committee_class_from_str_round_trip() {
+        for class in CommitteeClass::ALL {
+            assert_eq!(class.to_string().parse::<CommitteeClass>(), Ok(class));
+        }
+        assert_eq!(
+            "Leadership-PAC".parse::<CommitteeClass>(),
+            Ok(CommitteeClass::LeadershipPac)
+        );
+        assert!("grassroots".parse::<CommitteeClass>().is_err());
+    }
 }
@@ -3,11 +3,23 @@
 //! This module provides compile-time YAML-based mapping of congressional committee
 //! short codes (from CapitolTrades scrape data) to GICS sectors under each committee's
 //! legislative jurisdiction.
+//!
+//! A user override file (TOML or CSV, see [`JurisdictionOverrides`]) can add
+//! or remove sectors and add committees the bundled mapping leaves out;
+//! [`load_committee_jurisdictions_with`] merges it over the bundled mapping
+//! and records where each sector came from.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
 
-use serde::Deserialize;
-use std::collections::HashSet;
+use chrono::NaiveDate;
 
+use crate::congress_committee::CongressCommitteeResolver;
 use crate::sector_mapping::{validate_sector, SectorMappingError};
+use crate::validation::COMMITTEE_MAP;
 
 /// A single committee-to-sectors jurisdiction mapping.
 #[derive(Deserialize, Debug, Clone)]
@@ -78,6 +90,536 @@ pub fn load_committee_jurisdictions() -> Result<Vec<CommitteeJurisdiction>, Sect
     parse_committee_jurisdictions(yaml_content)
 }
 
+/// Error types for jurisdiction override files.
+#[derive(Error, Debug)]
+pub enum JurisdictionOverrideError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("{path}: {message}")]
+    Parse { path: String, message: String },
+    #[error("{path}:{line}: {message}")]
+    Invalid {
+        path: String,
+        line: usize,
+        message: String,
+    },
+    #[error(transparent)]
+    Bundled(#[from] SectorMappingError),
+}
+
+/// What an override line does to a committee's sectors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverrideAction {
+    Add,
+    Remove,
+}
+
+/// One validated override line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JurisdictionOverride {
+    pub committee: String,
+    pub action: OverrideAction,
+    /// Official GICS spelling.
+    pub sector: String,
+    /// 1-based line in the override file.
+    pub line: usize,
+}
+
+/// Sector changes read from a user override file.
+///
+/// TOML files hold one `[[committee]]` table per committee:
+///
+/// ```toml
+/// [[committee]]
+/// code = "hsif"
+/// add = ["Information Technology"]
+/// remove = ["Consumer Staples"]
+///
+/// [[committee]]
+/// code = "hssy"                 # not in the bundled mapping: a new committee
+/// full_name = "Science, Space, and Technology"
+/// add = ["Industrials"]
+/// ```
+///
+/// CSV files (`.csv`) hold `committee,action,sector` rows with action `add`
+/// or `remove`. Sectors are checked against [`crate::sector_mapping::GICS_SECTORS`]
+/// while parsing; committee codes are checked when merging.
+#[derive(Debug, Clone, Default)]
+pub struct JurisdictionOverrides {
+    /// File name used in error messages and the merge report.
+    pub source: String,
+    pub entries: Vec<JurisdictionOverride>,
+    /// `full_name` given for committees, keyed by code.
+    full_names: HashMap<String, String>,
+    /// Committees named by a TOML table, with the line of their `code`, so
+    /// a table without sector changes still declares a new committee.
+    declared: Vec<(String, usize)>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OverrideTomlFile {
+    #[serde(default)]
+    committee: Vec<OverrideTomlEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OverrideTomlEntry {
+    code: toml::Spanned<String>,
+    full_name: Option<String>,
+    #[serde(default)]
+    add: Vec<toml::Spanned<String>>,
+    #[serde(default)]
+    remove: Vec<toml::Spanned<String>>,
+}
+
+#[derive(Deserialize)]
+struct OverrideCsvRow {
+    committee: String,
+    action: String,
+    sector: String,
+}
+
+impl JurisdictionOverrides {
+    /// Read an override file; `.csv` files are parsed as CSV, anything else
+    /// as TOML.
+    pub fn from_file(path: &Path) -> Result<Self, JurisdictionOverrideError> {
+        let source = path.display().to_string();
+        let content =
+            std::fs::read_to_string(path).map_err(|err| JurisdictionOverrideError::Io {
+                path: source.clone(),
+                source: err,
+            })?;
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        if is_csv {
+            Self::parse_csv(&content, &source)
+        } else {
+            Self::parse_toml(&content, &source)
+        }
+    }
+
+    /// Parse TOML override content; `source` names it in errors.
+    pub fn parse_toml(content: &str, source: &str) -> Result<Self, JurisdictionOverrideError> {
+        let file: OverrideTomlFile =
+            toml::from_str(content).map_err(|err| JurisdictionOverrideError::Parse {
+                path: source.to_string(),
+                message: err.to_string(),
+            })?;
+        let line_of = |span: std::ops::Range<usize>| {
+            content[..span.start.min(content.len())].matches('\n').count() + 1
+        };
+
+        let mut overrides = Self {
+            source: source.to_string(),
+            ..Self::default()
+        };
+        for entry in file.committee {
+            let line = line_of(entry.code.span());
+            let committee = normalize_code(entry.code.get_ref());
+            if committee.is_empty() {
+                return Err(invalid(source, line, "empty committee code".to_string()));
+            }
+            if let Some(name) = entry.full_name {
+                overrides.full_names.insert(committee.clone(), name);
+            }
+            overrides.declared.push((committee.clone(), line));
+            let actions = entry
+                .add
+                .into_iter()
+                .map(|s| (OverrideAction::Add, s))
+                .chain(entry.remove.into_iter().map(|s| (OverrideAction::Remove, s)));
+            for (action, sector) in actions {
+                let line = line_of(sector.span());
+                overrides.push(&committee, action, sector.get_ref(), line)?;
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// Parse CSV override content (`committee,action,sector` header).
+    pub fn parse_csv(content: &str, source: &str) -> Result<Self, JurisdictionOverrideError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes());
+        let mut overrides = Self {
+            source: source.to_string(),
+            ..Self::default()
+        };
+        let headers = reader
+            .headers()
+            .map_err(|err| invalid(source, 1, err.to_string()))?
+            .clone();
+        for result in reader.records() {
+            let record = result.map_err(|err| {
+                let line = err.position().map_or(0, |p| p.line() as usize);
+                invalid(source, line, err.to_string())
+            })?;
+            let line = record.position().map_or(0, |p| p.line() as usize);
+            let row: OverrideCsvRow = record
+                .deserialize(Some(&headers))
+                .map_err(|err| invalid(source, line, err.to_string()))?;
+            let action = match row.action.to_ascii_lowercase().as_str() {
+                "add" => OverrideAction::Add,
+                "remove" => OverrideAction::Remove,
+                other => {
+                    return Err(invalid(
+                        source,
+                        line,
+                        format!("unknown action '{}': expected add or remove", other),
+                    ))
+                }
+            };
+            let committee = normalize_code(&row.committee);
+            if committee.is_empty() {
+                return Err(invalid(source, line, "empty committee code".to_string()));
+            }
+            overrides.push(&committee, action, &row.sector, line)?;
+        }
+        Ok(overrides)
+    }
+
+    fn push(
+        &mut self,
+        committee: &str,
+        action: OverrideAction,
+        sector: &str,
+        line: usize,
+    ) -> Result<(), JurisdictionOverrideError> {
+        let sector = validate_sector(sector).map_err(|_| {
+            invalid(
+                &self.source,
+                line,
+                format!(
+                    "invalid GICS sector '{}' for committee '{}'",
+                    sector, committee
+                ),
+            )
+        })?;
+        self.entries.push(JurisdictionOverride {
+            committee: committee.to_string(),
+            action,
+            sector,
+            line,
+        });
+        Ok(())
+    }
+}
+
+fn normalize_code(code: &str) -> String {
+    code.trim().to_ascii_lowercase()
+}
+
+fn invalid(path: &str, line: usize, message: String) -> JurisdictionOverrideError {
+    JurisdictionOverrideError::Invalid {
+        path: path.to_string(),
+        line,
+        message,
+    }
+}
+
+/// Where a sector in the effective mapping came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SectorSource {
+    Bundled,
+    Override,
+}
+
+impl fmt::Display for SectorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SectorSource::Bundled => "bundled",
+            SectorSource::Override => "override",
+        })
+    }
+}
+
+/// One effect of merging an override file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JurisdictionChange {
+    NewCommittee { committee: String },
+    Added { committee: String, sector: String },
+    Removed { committee: String, sector: String },
+    /// An add of a sector the committee already had.
+    AlreadyPresent { committee: String, sector: String },
+    /// A remove of a sector the committee did not have.
+    NotPresent { committee: String, sector: String },
+}
+
+impl fmt::Display for JurisdictionChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JurisdictionChange::NewCommittee { committee } => {
+                write!(f, "{}: new committee", committee)
+            }
+            JurisdictionChange::Added { committee, sector } => {
+                write!(f, "{}: added {}", committee, sector)
+            }
+            JurisdictionChange::Removed { committee, sector } => {
+                write!(f, "{}: removed {}", committee, sector)
+            }
+            JurisdictionChange::AlreadyPresent { committee, sector } => {
+                write!(f, "{}: {} already mapped, add ignored", committee, sector)
+            }
+            JurisdictionChange::NotPresent { committee, sector } => {
+                write!(f, "{}: {} not mapped, remove ignored", committee, sector)
+            }
+        }
+    }
+}
+
+/// What merging an override file changed, in file order.
+#[derive(Debug, Clone, Default)]
+pub struct JurisdictionMergeReport {
+    pub source: String,
+    pub changes: Vec<JurisdictionChange>,
+}
+
+impl JurisdictionMergeReport {
+    /// Counts of (added, removed, new committees, ignored lines).
+    pub fn counts(&self) -> (usize, usize, usize, usize) {
+        let mut counts = (0, 0, 0, 0);
+        for change in &self.changes {
+            match change {
+                JurisdictionChange::Added { .. } => counts.0 += 1,
+                JurisdictionChange::Removed { .. } => counts.1 += 1,
+                JurisdictionChange::NewCommittee { .. } => counts.2 += 1,
+                JurisdictionChange::AlreadyPresent { .. }
+                | JurisdictionChange::NotPresent { .. } => counts.3 += 1,
+            }
+        }
+        counts
+    }
+}
+
+/// One committee sector of the effective mapping, for
+/// `conflicts show-jurisdictions`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct JurisdictionSectorRow {
+    pub committee: String,
+    pub chamber: String,
+    pub full_name: String,
+    /// `None` for a committee mapped to no sector.
+    pub sector: Option<String>,
+    pub source: SectorSource,
+}
+
+/// The bundled mapping with an override file merged in.
+#[derive(Debug, Clone)]
+pub struct MergedJurisdictions {
+    pub jurisdictions: Vec<CommitteeJurisdiction>,
+    /// `None` when no override file was given.
+    pub report: Option<JurisdictionMergeReport>,
+    overridden: HashSet<(String, String)>,
+    new_committees: HashSet<String>,
+}
+
+impl MergedJurisdictions {
+    /// Where `sector` under `committee` came from; `None` when unmapped.
+    pub fn source_of(&self, committee: &str, sector: &str) -> Option<SectorSource> {
+        let jurisdiction = self
+            .jurisdictions
+            .iter()
+            .find(|j| j.committee_name == committee)?;
+        if !jurisdiction.sectors.iter().any(|s| s == sector) {
+            return None;
+        }
+        Some(
+            if self
+                .overridden
+                .contains(&(committee.to_string(), sector.to_string()))
+            {
+                SectorSource::Override
+            } else {
+                SectorSource::Bundled
+            },
+        )
+    }
+
+    /// Every committee sector with its provenance, in mapping order.
+    pub fn rows(&self) -> Vec<JurisdictionSectorRow> {
+        let mut rows = Vec::new();
+        for j in &self.jurisdictions {
+            let row = |sector: Option<String>, source| JurisdictionSectorRow {
+                committee: j.committee_name.clone(),
+                chamber: j.chamber.clone(),
+                full_name: j.full_name.clone(),
+                sector,
+                source,
+            };
+            if j.sectors.is_empty() {
+                let source = if self.new_committees.contains(&j.committee_name) {
+                    SectorSource::Override
+                } else {
+                    SectorSource::Bundled
+                };
+                rows.push(row(None, source));
+            }
+            for sector in &j.sectors {
+                let source = self
+                    .source_of(&j.committee_name, sector)
+                    .unwrap_or(SectorSource::Bundled);
+                rows.push(row(Some(sector.clone()), source));
+            }
+        }
+        rows
+    }
+}
+
+/// Merge `overrides` over `bundled`; the override wins every conflict.
+///
+/// A committee code missing from `bundled` must be a known CapitolTrades
+/// committee code ([`COMMITTEE_MAP`]) or defined by `resolver`; it is added
+/// as a new committee. Adding a sector the committee already has, or
+/// removing one it lacks, changes nothing and is reported.
+pub fn merge_jurisdiction_overrides(
+    bundled: Vec<CommitteeJurisdiction>,
+    overrides: &JurisdictionOverrides,
+    resolver: &CongressCommitteeResolver,
+) -> Result<MergedJurisdictions, JurisdictionOverrideError> {
+    let mut merged = MergedJurisdictions {
+        jurisdictions: bundled,
+        report: None,
+        overridden: HashSet::new(),
+        new_committees: HashSet::new(),
+    };
+    let mut report = JurisdictionMergeReport {
+        source: overrides.source.clone(),
+        changes: Vec::new(),
+    };
+
+    let declared = overrides.declared.iter().map(|(code, line)| (code, *line, None));
+    let entries = overrides
+        .entries
+        .iter()
+        .map(|entry| (&entry.committee, entry.line, Some(entry)));
+    for (code, line, entry) in declared.chain(entries) {
+        let code = code.as_str();
+        let index = match merged
+            .jurisdictions
+            .iter()
+            .position(|j| j.committee_name == code)
+        {
+            Some(index) => index,
+            None => {
+                let jurisdiction =
+                    new_committee(code, overrides.full_names.get(code), resolver).ok_or_else(
+                        || {
+                            invalid(
+                                &overrides.source,
+                                line,
+                                format!("unknown committee code '{}'", code),
+                            )
+                        },
+                    )?;
+                merged.jurisdictions.push(jurisdiction);
+                merged.new_committees.insert(code.to_string());
+                report.changes.push(JurisdictionChange::NewCommittee {
+                    committee: code.to_string(),
+                });
+                merged.jurisdictions.len() - 1
+            }
+        };
+        let Some(entry) = entry else {
+            continue;
+        };
+
+        let sectors = &mut merged.jurisdictions[index].sectors;
+        let key = (code.to_string(), entry.sector.clone());
+        let committee = code.to_string();
+        let sector = entry.sector.clone();
+        let present = sectors.iter().position(|s| *s == entry.sector);
+        match (entry.action, present) {
+            (OverrideAction::Add, Some(_)) => {
+                report
+                    .changes
+                    .push(JurisdictionChange::AlreadyPresent { committee, sector });
+            }
+            (OverrideAction::Add, None) => {
+                sectors.push(entry.sector.clone());
+                merged.overridden.insert(key);
+                report
+                    .changes
+                    .push(JurisdictionChange::Added { committee, sector });
+            }
+            (OverrideAction::Remove, Some(pos)) => {
+                sectors.remove(pos);
+                merged.overridden.remove(&key);
+                report
+                    .changes
+                    .push(JurisdictionChange::Removed { committee, sector });
+            }
+            (OverrideAction::Remove, None) => {
+                report
+                    .changes
+                    .push(JurisdictionChange::NotPresent { committee, sector });
+            }
+        }
+    }
+
+    merged.report = Some(report);
+    Ok(merged)
+}
+
+/// An empty jurisdiction for a known committee code the bundled mapping
+/// lacks; `None` when the code is unknown.
+fn new_committee(
+    code: &str,
+    full_name: Option<&String>,
+    resolver: &CongressCommitteeResolver,
+) -> Option<CommitteeJurisdiction> {
+    let listed = COMMITTEE_MAP.iter().find(|(c, _)| *c == code);
+    if listed.is_none() && !resolver.knows(code) {
+        return None;
+    }
+    let chamber = match code.chars().next() {
+        Some('h') => "House",
+        Some('s') => "Senate",
+        _ => return None,
+    };
+    let full_name = full_name.cloned().unwrap_or_else(|| match listed {
+        Some((_, name)) => name
+            .split_once(" - ")
+            .map_or(name.to_string(), |(_, n)| n.to_string()),
+        None => code.to_string(),
+    });
+    Some(CommitteeJurisdiction {
+        committee_name: code.to_string(),
+        chamber: chamber.to_string(),
+        full_name,
+        sectors: Vec::new(),
+        notes: None,
+    })
+}
+
+/// The bundled mapping, with `override_file` merged in when given.
+pub fn load_committee_jurisdictions_with(
+    override_file: Option<&Path>,
+    resolver: &CongressCommitteeResolver,
+) -> Result<MergedJurisdictions, JurisdictionOverrideError> {
+    let bundled = load_committee_jurisdictions()?;
+    match override_file {
+        Some(path) => {
+            let overrides = JurisdictionOverrides::from_file(path)?;
+            merge_jurisdiction_overrides(bundled, &overrides, resolver)
+        }
+        None => Ok(MergedJurisdictions {
+            jurisdictions: bundled,
+            report: None,
+            overridden: HashSet::new(),
+            new_committees: HashSet::new(),
+        }),
+    }
+}
+
 /// Validate committee jurisdictions against GICS sectors and chamber rules.
 ///
 /// # Arguments
@@ -150,6 +692,30 @@ pub fn get_committee_sectors(
     sectors
 }
 
+/// Like [`get_committee_sectors`], but each code is first resolved against
+/// the committee structure in effect on `date`.
+///
+/// A committee that had been dissolved by `date` contributes its successor's
+/// sectors; one that did not exist yet contributes none. Codes the resolver
+/// does not define are looked up as given.
+pub fn get_committee_sectors_as_of(
+    jurisdictions: &[CommitteeJurisdiction],
+    resolver: &CongressCommitteeResolver,
+    politician_committees: &[String],
+    date: NaiveDate,
+) -> HashSet<String> {
+    let resolved: Vec<String> = politician_committees
+        .iter()
+        .filter_map(|code| {
+            if !resolver.knows(code) {
+                return Some(code.clone());
+            }
+            resolver.resolve_as_of(code, date).map(|c| c.code)
+        })
+        .collect();
+    get_committee_sectors(jurisdictions, &resolved)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,6 +800,28 @@ mod tests {
         assert_eq!(sectors.len(), 0, "Ways and Means should have no sector-specific jurisdiction");
     }
 
+    #[test]
+    fn test_get_committee_sectors_as_of_follows_successor() {
+        let jurisdictions = load_committee_jurisdictions().unwrap();
+        let resolver = CongressCommitteeResolver::bundled().unwrap();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        // The Climate Crisis select committee has no jurisdiction entry of
+        // its own; once dissolved it maps to Energy and Commerce.
+        let climate = vec!["hscn".to_string()];
+        assert!(get_committee_sectors_as_of(&jurisdictions, &resolver, &climate, date("2020-06-01")).is_empty());
+        let after = get_committee_sectors_as_of(&jurisdictions, &resolver, &climate, date("2024-06-01"));
+        assert!(after.contains("Energy"));
+
+        // Codes outside the definition file are looked up as given.
+        let banking = vec!["hsba".to_string()];
+        let empty = CongressCommitteeResolver::default();
+        assert_eq!(
+            get_committee_sectors_as_of(&jurisdictions, &empty, &banking, date("2019-06-01")),
+            get_committee_sectors(&jurisdictions, &banking)
+        );
+    }
+
     #[test]
     fn test_chamber_validation() {
         let yaml = r#"
@@ -253,4 +841,156 @@ committees:
         let result = validate_committee_jurisdictions(&jurisdictions);
         assert!(result.is_ok(), "All loaded jurisdictions should be valid");
     }
+
+    fn merge_toml(toml: &str) -> Result<MergedJurisdictions, JurisdictionOverrideError> {
+        let overrides = JurisdictionOverrides::parse_toml(toml, "overrides.toml")?;
+        merge_jurisdiction_overrides(
+            load_committee_jurisdictions().unwrap(),
+            &overrides,
+            &CongressCommitteeResolver::default(),
+        )
+    }
+
+    fn sectors_of<'a>(merged: &'a MergedJurisdictions, code: &str) -> &'a [String] {
+        &merged
+            .jurisdictions
+            .iter()
+            .find(|j| j.committee_name == code)
+            .unwrap()
+            .sectors
+    }
+
+    #[test]
+    fn test_override_adds_and_removes_sectors() {
+        let merged = merge_toml(
+            r#"
+[[committee]]
+code = "HSIF"
+add = ["information technology"]
+remove = ["Consumer Staples", "Financials"]
+"#,
+        )
+        .unwrap();
+
+        let sectors = sectors_of(&merged, "hsif");
+        assert!(sectors.contains(&"Information Technology".to_string()));
+        assert!(!sectors.contains(&"Consumer Staples".to_string()));
+        assert_eq!(
+            merged.source_of("hsif", "Information Technology"),
+            Some(SectorSource::Override)
+        );
+        assert_eq!(merged.source_of("hsif", "Energy"), Some(SectorSource::Bundled));
+        assert_eq!(merged.source_of("hsif", "Consumer Staples"), None);
+
+        let report = merged.report.as_ref().unwrap();
+        assert_eq!(report.counts(), (1, 1, 0, 1));
+        assert_eq!(
+            report.changes[2],
+            JurisdictionChange::NotPresent {
+                committee: "hsif".to_string(),
+                sector: "Financials".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_override_adds_new_committee() {
+        let merged = merge_toml(
+            r#"
+[[committee]]
+code = "hsap"
+add = ["Industrials", "Health Care"]
+
+[[committee]]
+code = "hssy"
+full_name = "Science"
+"#,
+        )
+        .unwrap();
+
+        let appropriations = merged
+            .jurisdictions
+            .iter()
+            .find(|j| j.committee_name == "hsap")
+            .unwrap();
+        assert_eq!(appropriations.chamber, "House");
+        assert_eq!(appropriations.full_name, "Appropriations");
+        assert_eq!(appropriations.sectors, vec!["Industrials", "Health Care"]);
+        assert!(validate_committee_jurisdictions(&merged.jurisdictions).is_ok());
+
+        let rows = merged.rows();
+        let science: Vec<_> = rows.iter().filter(|r| r.committee == "hssy").collect();
+        assert_eq!(science.len(), 1);
+        assert_eq!(science[0].full_name, "Science");
+        assert_eq!(science[0].sector, None);
+        assert_eq!(science[0].source, SectorSource::Override);
+        assert_eq!(merged.report.unwrap().counts(), (2, 0, 2, 0));
+    }
+
+    #[test]
+    fn test_override_rows_carry_provenance() {
+        let merged = merge_toml(
+            r#"
+[[committee]]
+code = "hsba"
+add = ["Real Estate"]
+"#,
+        )
+        .unwrap();
+        let rows: Vec<_> = merged
+            .rows()
+            .into_iter()
+            .filter(|r| r.committee == "hsba")
+            .map(|r| (r.sector.unwrap(), r.source))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("Financials".to_string(), SectorSource::Bundled),
+                ("Real Estate".to_string(), SectorSource::Override),
+            ]
+        );
+        let json = serde_json::to_value(&merged.rows()[0]).unwrap();
+        assert_eq!(json["source"], "bundled");
+    }
+
+    #[test]
+    fn test_override_rejects_invalid_sector_with_line() {
+        let err = JurisdictionOverrides::parse_toml(
+            "[[committee]]\ncode = \"hsba\"\nadd = [\n  \"Financials\",\n  \"Crypto\",\n]\n",
+            "overrides.toml",
+        )
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("overrides.toml:5:"), "{}", message);
+        assert!(message.contains("Crypto"), "{}", message);
+    }
+
+    #[test]
+    fn test_override_rejects_unknown_committee_with_line() {
+        let err = merge_toml("\n[[committee]]\ncode = \"hszz\"\nadd = [\"Energy\"]\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "overrides.toml:3: unknown committee code 'hszz'"
+        );
+    }
+
+    #[test]
+    fn test_override_csv() {
+        let overrides = JurisdictionOverrides::parse_csv(
+            "committee,action,sector\nhsif,remove,Consumer Staples\nhsap,add,Energy\n",
+            "overrides.csv",
+        )
+        .unwrap();
+        assert_eq!(overrides.entries.len(), 2);
+        assert_eq!(overrides.entries[1].line, 3);
+        assert_eq!(overrides.entries[1].action, OverrideAction::Add);
+
+        let err = JurisdictionOverrides::parse_csv(
+            "committee,action,sector\nhsif,remove,Energy\nhsif,replace,Energy\n",
+            "overrides.csv",
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("overrides.csv:3:"), "{}", err);
+    }
 }
@@ -0,0 +1,676 @@
+//! Layered application configuration.
+//!
+//! Settings are resolved from four layers, later layers winning:
+//!
+//! 1. built-in defaults ([`AppConfig::defaults`])
+//! 2. a TOML config file, by default `$XDG_CONFIG_HOME/capitoltraders/config.toml`
+//!    (falling back to `~/.config/capitoltraders/config.toml`)
+//! 3. environment variables (`OPENFEC_API_KEY`, `TIINGO_API_KEY`, ...)
+//! 4. command-line flags, applied by the CLI with [`AppConfig::layer`]
+//!
+//! Every resolved value remembers which layer it came from, so the effective
+//! configuration can be printed with its provenance ([`AppConfig::entries`]).
+//!
+//! ```toml
+//! db = "/data/capitoltraders.db"
+//! output = "json"
+//! committees_file = "/data/congress_committees.yml"  # instead of the bundled definitions
+//! session_calendar_file = "/data/session_calendar.csv"  # extends the bundled calendar
+//! exchange_suffixes_file = "/data/exchange_suffixes.yml"  # extends the bundled suffix table
+//!
+//! [openfec]
+//! api_key = "..."
+//!
+//! [tiingo]
+//! api_key = "..."
+//! rate = { burst = 5, period_ms = 1000 }
+//! cache_capacity = 0  # disable the price series cache
+//!
+//! [trading_style]  # overrides for `analytics` style labels
+//! active_max_median_days = 60
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::analytics::TradingStyleConfig;
+use crate::ratelimit::Rate;
+
+/// Error types for configuration loading.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {error}")]
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    #[error("Invalid config file {path}: {message}")]
+    Invalid { path: PathBuf, message: String },
+}
+
+/// Layer a configuration value was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind", content = "name")]
+pub enum ConfigSource {
+    Default,
+    File,
+    /// Environment variable, by name.
+    Env(&'static str),
+    Flag,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => f.write_str("default"),
+            ConfigSource::File => f.write_str("config file"),
+            ConfigSource::Env(name) => write!(f, "env {}", name),
+            ConfigSource::Flag => f.write_str("flag"),
+        }
+    }
+}
+
+/// Request pacing for one client: `burst` requests per `period_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateConfig {
+    pub burst: u32,
+    pub period_ms: u64,
+}
+
+impl RateConfig {
+    pub fn to_rate(self) -> Rate {
+        Rate::new(self.burst, Duration::from_millis(self.period_ms))
+    }
+}
+
+impl From<Rate> for RateConfig {
+    fn from(rate: Rate) -> Self {
+        Self {
+            burst: rate.burst(),
+            period_ms: rate.period().as_millis() as u64,
+        }
+    }
+}
+
+/// CapitolTrades scraping settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScrapeConfig {
+    pub base_url: Option<String>,
+    /// Unlimited when unset.
+    pub rate: Option<RateConfig>,
+}
+
+/// Settings for a keyed REST API (OpenFEC, Tiingo).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiConfig {
+    pub base_url: Option<String>,
+    pub api_key: Option<String>,
+    pub rate: Option<RateConfig>,
+    /// Price series kept in memory (Tiingo only); 0 disables the cache.
+    pub cache_capacity: Option<usize>,
+}
+
+/// Yahoo Finance settings. The Yahoo connector has a fixed endpoint, so only
+/// pacing and caching are configurable.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct YahooConfig {
+    pub rate: Option<RateConfig>,
+    /// Quote series kept in memory; 0 disables the cache.
+    pub cache_capacity: Option<usize>,
+}
+
+/// Overrides for [`crate::analytics::TradingStyleConfig`]; unset fields keep
+/// the built-in threshold.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TradingStyleOverrides {
+    pub options_heavy_min_share: Option<f64>,
+    pub inactive_max_trades_per_year: Option<f64>,
+    pub min_closed_trades: Option<usize>,
+    pub active_max_median_days: Option<i64>,
+    pub active_min_turnover: Option<f64>,
+    pub active_min_round_trips_per_year: Option<f64>,
+    pub buy_and_hold_min_median_days: Option<i64>,
+    pub buy_and_hold_max_turnover: Option<f64>,
+}
+
+/// Effective configuration. Deserializes from the config file format; unset
+/// fields fall through to lower layers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppConfig {
+    /// Database used by commands that require `--db` when the flag is omitted.
+    pub db: Option<PathBuf>,
+    /// Default `--output` format.
+    pub output: Option<String>,
+    /// Congressional committee definitions used instead of the bundled file.
+    pub committees_file: Option<PathBuf>,
+    /// Session calendar rows layered over the bundled calendar.
+    pub session_calendar_file: Option<PathBuf>,
+    /// Per-country Yahoo exchange suffixes layered over the bundled table.
+    pub exchange_suffixes_file: Option<PathBuf>,
+    pub capitoltrades: ScrapeConfig,
+    pub openfec: ApiConfig,
+    pub tiingo: ApiConfig,
+    pub yahoo: YahooConfig,
+    pub trading_style: TradingStyleOverrides,
+    #[serde(skip)]
+    sources: BTreeMap<&'static str, ConfigSource>,
+    /// Config file that was loaded, if any.
+    #[serde(skip)]
+    file: Option<PathBuf>,
+}
+
+/// One resolved setting, as printed by `config show`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigEntry {
+    pub key: &'static str,
+    /// Display value; API keys are redacted.
+    pub value: String,
+    pub source: ConfigSource,
+}
+
+/// Environment variables read by [`AppConfig::from_env`], with the key each sets.
+pub const ENV_VARS: &[(&str, &str)] = &[
+    ("CAPITOLTRADERS_DB", "db"),
+    ("CAPITOLTRADERS_OUTPUT", "output"),
+    ("CAPITOLTRADES_BASE_URL", "capitoltrades.base_url"),
+    ("OPENFEC_API_KEY", "openfec.api_key"),
+    ("OPENFEC_BASE_URL", "openfec.base_url"),
+    ("TIINGO_API_KEY", "tiingo.api_key"),
+    ("TIINGO_BASE_URL", "tiingo.base_url"),
+];
+
+/// Default config file location: `$XDG_CONFIG_HOME/capitoltraders/config.toml`,
+/// or `$HOME/.config/capitoltraders/config.toml` when XDG is unset.
+pub fn default_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(|v| !v.is_empty())
+                .map(|home| PathBuf::from(home).join(".config"))
+        })?;
+    Some(base.join("capitoltraders").join("config.toml"))
+}
+
+/// Mask a secret, keeping the last four characters of long values so keys
+/// can still be told apart.
+pub fn redact(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() >= 12 {
+        let tail: String = chars[chars.len() - 4..].iter().collect();
+        format!("********{}", tail)
+    } else {
+        "********".to_string()
+    }
+}
+
+impl AppConfig {
+    /// Built-in defaults: table output, the public service URLs and each
+    /// client's default rate.
+    pub fn defaults() -> Self {
+        let mut config = Self {
+            output: Some("table".to_string()),
+            capitoltrades: ScrapeConfig {
+                base_url: Some(crate::scrape::DEFAULT_BASE_URL.to_string()),
+                rate: None,
+            },
+            openfec: ApiConfig {
+                base_url: Some(crate::openfec::client::DEFAULT_BASE_URL.to_string()),
+                api_key: None,
+                rate: Some(crate::openfec::client::DEFAULT_RATE.into()),
+                cache_capacity: None,
+            },
+            tiingo: ApiConfig {
+                base_url: Some(crate::tiingo::client::DEFAULT_BASE_URL.to_string()),
+                api_key: None,
+                rate: Some(crate::tiingo::client::DEFAULT_RATE.into()),
+                cache_capacity: Some(crate::tiingo::client::DEFAULT_CACHE_CAPACITY),
+            },
+            yahoo: YahooConfig {
+                rate: Some(crate::yahoo::DEFAULT_RATE.into()),
+                cache_capacity: Some(crate::yahoo::DEFAULT_CACHE_CAPACITY),
+            },
+            ..Self::default()
+        };
+        for key in config.set_keys() {
+            config.sources.insert(key, ConfigSource::Default);
+        }
+        config
+    }
+
+    /// Parse the config file format. `path` is only used in error messages.
+    pub fn from_toml_str(content: &str, path: &Path) -> Result<Self, ConfigError> {
+        let mut config: AppConfig = toml::from_str(content).map_err(|e| ConfigError::Invalid {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        for rate in [
+            config.capitoltrades.rate,
+            config.openfec.rate,
+            config.tiingo.rate,
+            config.yahoo.rate,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if rate.burst == 0 || rate.period_ms == 0 {
+                return Err(ConfigError::Invalid {
+                    path: path.to_path_buf(),
+                    message: "rate burst and period_ms must be positive".to_string(),
+                });
+            }
+        }
+        for key in config.set_keys() {
+            config.sources.insert(key, ConfigSource::File);
+        }
+        Ok(config)
+    }
+
+    /// Read and parse a config file.
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|error| ConfigError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        let mut config = Self::from_toml_str(&content, path)?;
+        config.file = Some(path.to_path_buf());
+        Ok(config)
+    }
+
+    /// Settings from environment variables (see [`ENV_VARS`]). `lookup`
+    /// returns a variable's value; empty values count as unset.
+    pub fn from_env(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name: &str| lookup(name).filter(|v| !v.is_empty());
+        let mut config = Self {
+            db: var("CAPITOLTRADERS_DB").map(PathBuf::from),
+            output: var("CAPITOLTRADERS_OUTPUT"),
+            capitoltrades: ScrapeConfig {
+                base_url: var("CAPITOLTRADES_BASE_URL"),
+                rate: None,
+            },
+            openfec: ApiConfig {
+                base_url: var("OPENFEC_BASE_URL"),
+                api_key: var("OPENFEC_API_KEY"),
+                rate: None,
+                cache_capacity: None,
+            },
+            tiingo: ApiConfig {
+                base_url: var("TIINGO_BASE_URL"),
+                api_key: var("TIINGO_API_KEY"),
+                rate: None,
+                cache_capacity: None,
+            },
+            ..Self::default()
+        };
+        for key in config.set_keys() {
+            let (name, _) = ENV_VARS
+                .iter()
+                .find(|(_, k)| *k == key)
+                .expect("every env-settable key is listed in ENV_VARS");
+            config.sources.insert(key, ConfigSource::Env(name));
+        }
+        config
+    }
+
+    /// Resolve defaults, the config file and the process environment.
+    ///
+    /// An explicit `path` must exist; the default path is skipped when the
+    /// file is missing.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let mut config = Self::defaults();
+        let file = match path {
+            Some(p) => Some(Self::from_file(p)?),
+            None => match default_config_path() {
+                Some(p) if p.is_file() => Some(Self::from_file(&p)?),
+                _ => None,
+            },
+        };
+        if let Some(file) = file {
+            config.layer(file);
+        }
+        config.layer(Self::from_env(|name| std::env::var(name).ok()));
+        Ok(config)
+    }
+
+    /// Overlay every value set in `upper`, keeping the source recorded for
+    /// it. Values without one (a config built by hand from command-line
+    /// flags) are attributed to [`ConfigSource::Flag`].
+    pub fn layer(&mut self, upper: AppConfig) {
+        let mut sources = upper.sources.clone();
+        for key in upper.set_keys() {
+            sources.entry(key).or_insert(ConfigSource::Flag);
+        }
+        if upper.file.is_some() {
+            self.file = upper.file.clone();
+        }
+        merge(&mut self.db, upper.db);
+        merge(&mut self.output, upper.output);
+        merge(&mut self.committees_file, upper.committees_file);
+        merge(&mut self.session_calendar_file, upper.session_calendar_file);
+        merge(&mut self.exchange_suffixes_file, upper.exchange_suffixes_file);
+        merge(&mut self.capitoltrades.base_url, upper.capitoltrades.base_url);
+        merge(&mut self.capitoltrades.rate, upper.capitoltrades.rate);
+        merge(&mut self.openfec.base_url, upper.openfec.base_url);
+        merge(&mut self.openfec.api_key, upper.openfec.api_key);
+        merge(&mut self.openfec.rate, upper.openfec.rate);
+        merge(&mut self.tiingo.base_url, upper.tiingo.base_url);
+        merge(&mut self.tiingo.api_key, upper.tiingo.api_key);
+        merge(&mut self.tiingo.rate, upper.tiingo.rate);
+        merge(&mut self.tiingo.cache_capacity, upper.tiingo.cache_capacity);
+        merge(&mut self.yahoo.rate, upper.yahoo.rate);
+        merge(&mut self.yahoo.cache_capacity, upper.yahoo.cache_capacity);
+        let (style, upper_style) = (&mut self.trading_style, upper.trading_style);
+        merge(&mut style.options_heavy_min_share, upper_style.options_heavy_min_share);
+        merge(
+            &mut style.inactive_max_trades_per_year,
+            upper_style.inactive_max_trades_per_year,
+        );
+        merge(&mut style.min_closed_trades, upper_style.min_closed_trades);
+        merge(&mut style.active_max_median_days, upper_style.active_max_median_days);
+        merge(&mut style.active_min_turnover, upper_style.active_min_turnover);
+        merge(
+            &mut style.active_min_round_trips_per_year,
+            upper_style.active_min_round_trips_per_year,
+        );
+        merge(
+            &mut style.buy_and_hold_min_median_days,
+            upper_style.buy_and_hold_min_median_days,
+        );
+        merge(&mut style.buy_and_hold_max_turnover, upper_style.buy_and_hold_max_turnover);
+        self.sources.extend(sources);
+    }
+
+    /// Which layer supplied `key` (dotted, e.g. `tiingo.api_key`).
+    pub fn source(&self, key: &str) -> Option<ConfigSource> {
+        self.sources.get(key).copied()
+    }
+
+    /// The config file that was loaded, if any.
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// Output format name, defaulting to `table`.
+    pub fn output_format(&self) -> &str {
+        self.output.as_deref().unwrap_or("table")
+    }
+
+    /// Trading-style thresholds: the built-in defaults with any
+    /// `[trading_style]` overrides applied.
+    pub fn trading_style_config(&self) -> TradingStyleConfig {
+        let o = &self.trading_style;
+        let d = TradingStyleConfig::default();
+        TradingStyleConfig {
+            options_heavy_min_share: o.options_heavy_min_share.unwrap_or(d.options_heavy_min_share),
+            inactive_max_trades_per_year: o
+                .inactive_max_trades_per_year
+                .unwrap_or(d.inactive_max_trades_per_year),
+            min_closed_trades: o.min_closed_trades.unwrap_or(d.min_closed_trades),
+            active_max_median_days: o.active_max_median_days.unwrap_or(d.active_max_median_days),
+            active_min_turnover: o.active_min_turnover.unwrap_or(d.active_min_turnover),
+            active_min_round_trips_per_year: o
+                .active_min_round_trips_per_year
+                .unwrap_or(d.active_min_round_trips_per_year),
+            buy_and_hold_min_median_days: o
+                .buy_and_hold_min_median_days
+                .unwrap_or(d.buy_and_hold_min_median_days),
+            buy_and_hold_max_turnover: o
+                .buy_and_hold_max_turnover
+                .unwrap_or(d.buy_and_hold_max_turnover),
+        }
+    }
+
+    /// Every set value in a fixed order, with API keys redacted.
+    pub fn entries(&self) -> Vec<ConfigEntry> {
+        self.values()
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = value?;
+                let value = if key.ends_with("api_key") {
+                    redact(&value)
+                } else {
+                    value
+                };
+                Some(ConfigEntry {
+                    key,
+                    value,
+                    source: self.source(key).unwrap_or(ConfigSource::Default),
+                })
+            })
+            .collect()
+    }
+
+    fn values(&self) -> Vec<(&'static str, Option<String>)> {
+        let rate = |r: Option<RateConfig>| {
+            r.map(|r| match r.period_ms % 1000 {
+                0 => format!("{} per {}s", r.burst, r.period_ms / 1000),
+                _ => format!("{} per {}ms", r.burst, r.period_ms),
+            })
+        };
+        let mut values = vec![
+            ("db", self.db.as_ref().map(|p| p.display().to_string())),
+            ("output", self.output.clone()),
+            (
+                "committees_file",
+                self.committees_file.as_ref().map(|p| p.display().to_string()),
+            ),
+            (
+                "session_calendar_file",
+                self.session_calendar_file.as_ref().map(|p| p.display().to_string()),
+            ),
+            (
+                "exchange_suffixes_file",
+                self.exchange_suffixes_file.as_ref().map(|p| p.display().to_string()),
+            ),
+            ("capitoltrades.base_url", self.capitoltrades.base_url.clone()),
+            ("capitoltrades.rate", rate(self.capitoltrades.rate)),
+            ("openfec.base_url", self.openfec.base_url.clone()),
+            ("openfec.api_key", self.openfec.api_key.clone()),
+            ("openfec.rate", rate(self.openfec.rate)),
+            ("tiingo.base_url", self.tiingo.base_url.clone()),
+            ("tiingo.api_key", self.tiingo.api_key.clone()),
+            ("tiingo.rate", rate(self.tiingo.rate)),
+            ("tiingo.cache_capacity", self.tiingo.cache_capacity.map(|n| n.to_string())),
+            ("yahoo.rate", rate(self.yahoo.rate)),
+            ("yahoo.cache_capacity", self.yahoo.cache_capacity.map(|n| n.to_string())),
+        ];
+        let style = &self.trading_style;
+        let num = |v: Option<f64>| v.map(|v| v.to_string());
+        values.extend([
+            ("trading_style.options_heavy_min_share", num(style.options_heavy_min_share)),
+            (
+                "trading_style.inactive_max_trades_per_year",
+                num(style.inactive_max_trades_per_year),
+            ),
+            (
+                "trading_style.min_closed_trades",
+                style.min_closed_trades.map(|n| n.to_string()),
+            ),
+            (
+                "trading_style.active_max_median_days",
+                style.active_max_median_days.map(|n| n.to_string()),
+            ),
+            ("trading_style.active_min_turnover", num(style.active_min_turnover)),
+            (
+                "trading_style.active_min_round_trips_per_year",
+                num(style.active_min_round_trips_per_year),
+            ),
+            (
+                "trading_style.buy_and_hold_min_median_days",
+                style.buy_and_hold_min_median_days.map(|n| n.to_string()),
+            ),
+            ("trading_style.buy_and_hold_max_turnover", num(style.buy_and_hold_max_turnover)),
+        ]);
+        values
+    }
+
+    fn set_keys(&self) -> Vec<&'static str> {
+        self.values()
+            .into_iter()
+            .filter(|(_, v)| v.is_some())
+            .map(|(k, _)| k)
+            .collect()
+    }
+}
+
+fn merge<T>(slot: &mut Option<T>, upper: Option<T>) {
+    if upper.is_some() {
+        *slot = upper;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
+    const FILE: &str = r#"
+db = "/data/file.db"
+output = "csv"
+committees_file = "/data/committees.yml"
+session_calendar_file = "/data/calendar.csv"
+exchange_suffixes_file = "/data/suffixes.yml"
+
+[openfec]
+api_key = "file-openfec-key-0000"
+
+[tiingo]
+api_key = "file-tiingo-key-1111"
+base_url = "http://file.example"
+rate = { burst = 2, period_ms = 1000 }
+cache_capacity = 0
+"#;
+
+    #[test]
+    fn layers_apply_in_precedence_order() {
+        let mut config = AppConfig::defaults();
+        config.layer(AppConfig::from_toml_str(FILE, Path::new("config.toml")).unwrap());
+        config.layer(AppConfig::from_env(env(&[
+            ("TIINGO_API_KEY", "env-tiingo-key-2222"),
+            ("CAPITOLTRADERS_OUTPUT", "json"),
+            ("OPENFEC_BASE_URL", ""),
+        ])));
+        config.layer(AppConfig {
+            output: Some("xml".to_string()),
+            ..AppConfig::default()
+        });
+
+        // default < file
+        assert_eq!(config.yahoo.rate, Some(crate::yahoo::DEFAULT_RATE.into()));
+        assert_eq!(config.source("yahoo.rate"), Some(ConfigSource::Default));
+        assert_eq!(config.db, Some(PathBuf::from("/data/file.db")));
+        assert_eq!(config.source("db"), Some(ConfigSource::File));
+        assert_eq!(config.committees_file, Some(PathBuf::from("/data/committees.yml")));
+        assert_eq!(
+            config.session_calendar_file,
+            Some(PathBuf::from("/data/calendar.csv"))
+        );
+        assert_eq!(
+            config.exchange_suffixes_file,
+            Some(PathBuf::from("/data/suffixes.yml"))
+        );
+        assert_eq!(config.tiingo.rate.unwrap().to_rate().burst(), 2);
+        assert_eq!(config.tiingo.cache_capacity, Some(0));
+        assert_eq!(
+            config.yahoo.cache_capacity,
+            Some(crate::yahoo::DEFAULT_CACHE_CAPACITY)
+        );
+        // file < env
+        assert_eq!(config.tiingo.api_key.as_deref(), Some("env-tiingo-key-2222"));
+        assert_eq!(
+            config.source("tiingo.api_key"),
+            Some(ConfigSource::Env("TIINGO_API_KEY"))
+        );
+        assert_eq!(config.openfec.api_key.as_deref(), Some("file-openfec-key-0000"));
+        // Empty env values do not override.
+        assert_eq!(config.source("openfec.base_url"), Some(ConfigSource::Default));
+        // env < flag
+        assert_eq!(config.output_format(), "xml");
+        assert_eq!(config.source("output"), Some(ConfigSource::Flag));
+    }
+
+    #[test]
+    fn entries_redact_api_keys() {
+        let mut config = AppConfig::defaults();
+        config.layer(AppConfig::from_env(env(&[
+            ("OPENFEC_API_KEY", "abcdefghijklmnopWXYZ"),
+            ("TIINGO_API_KEY", "short"),
+        ])));
+        let entries = config.entries();
+        let rendered = format!("{:?}", entries);
+        assert!(!rendered.contains("abcdefghijklmnop"));
+        assert!(!rendered.contains("short"));
+
+        let openfec = entries.iter().find(|e| e.key == "openfec.api_key").unwrap();
+        assert_eq!(openfec.value, "********WXYZ");
+        assert_eq!(openfec.source, ConfigSource::Env("OPENFEC_API_KEY"));
+        let tiingo = entries.iter().find(|e| e.key == "tiingo.api_key").unwrap();
+        assert_eq!(tiingo.value, "********");
+        // Unset values are left out rather than shown empty.
+        assert!(entries.iter().all(|e| e.key != "db"));
+    }
+
+    #[test]
+    fn trading_style_overrides_layer_over_defaults() {
+        let mut config = AppConfig::defaults();
+        assert_eq!(config.trading_style_config(), TradingStyleConfig::default());
+        config.layer(
+            AppConfig::from_toml_str(
+                "[trading_style]\nactive_max_median_days = 60\noptions_heavy_min_share = 0.5\n",
+                Path::new("config.toml"),
+            )
+            .unwrap(),
+        );
+        let style = config.trading_style_config();
+        assert_eq!(style.active_max_median_days, 60);
+        assert_eq!(style.options_heavy_min_share, 0.5);
+        assert_eq!(
+            style.min_closed_trades,
+            TradingStyleConfig::default().min_closed_trades
+        );
+        assert_eq!(
+            config.source("trading_style.active_max_median_days"),
+            Some(ConfigSource::File)
+        );
+        assert!(matches!(
+            AppConfig::from_toml_str("[trading_style]\nmin_days = 1\n", Path::new("config.toml")),
+            Err(ConfigError::Invalid { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_zero_rates() {
+        let path = Path::new("config.toml");
+        assert!(matches!(
+            AppConfig::from_toml_str("[yahoo]\napi_key = \"x\"\n", path),
+            Err(ConfigError::Invalid { .. })
+        ));
+        assert!(matches!(
+            AppConfig::from_toml_str("[yahoo]\nrate = { burst = 0, period_ms = 10 }\n", path),
+            Err(ConfigError::Invalid { .. })
+        ));
+        assert!(matches!(
+            AppConfig::load(Some(Path::new("/nonexistent/capitoltraders.toml"))),
+            Err(ConfigError::Io { .. })
+        ));
+    }
+}
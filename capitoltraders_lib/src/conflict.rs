@@ -4,11 +4,23 @@
 //! conflicts of interest in politician trading patterns, including:
 //! - Committee trading scores (percentage of trades in committee-related sectors)
 //! - Donation-trade correlations (matching donor employers to traded issuers)
+//! - Hearing proximity (members trading in-jurisdiction issuers ahead of their
+//!   own committee's hearings)
+//! - Owner split (own-account vs spouse-account trading in committee sectors)
+//! - Donation-trade affinity (one comparable score per politician, for ranking)
 
+use chrono::{Days, NaiveDate};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 
 use crate::analytics::ClosedTrade;
-use crate::committee_jurisdiction::{get_committee_sectors, CommitteeJurisdiction};
+use crate::committee_jurisdiction::{
+    get_committee_sectors, get_committee_sectors_as_of, CommitteeJurisdiction,
+};
+use crate::congress_committee::{congress_for_date, CongressCommitteeResolver};
+use crate::db::{AffinityInput, Db, DbError};
+use crate::events::CommitteeHearing;
+use crate::validation;
 
 /// Committee trading score for a politician.
 ///
@@ -71,7 +83,7 @@ pub struct ConflictSummary {
 /// ```
 /// use capitoltraders_lib::conflict::calculate_committee_trading_score;
 /// use capitoltraders_lib::committee_jurisdiction::load_committee_jurisdictions;
-/// use capitoltraders_lib::analytics::ClosedTrade;
+/// use capitoltraders_lib::analytics::{ClosedTrade, GainTerm};
 ///
 /// let jurisdictions = load_committee_jurisdictions().unwrap();
 /// let committees = vec!["hsba".to_string()]; // House Financial Services
@@ -79,6 +91,8 @@ pub struct ConflictSummary {
 ///     ClosedTrade {
 ///         politician_id: "P000001".to_string(),
 ///         ticker: "JPM".to_string(),
+///         buy_tx_id: 1,
+///         sell_tx_id: 2,
 ///         shares: 100.0,
 ///         buy_price: 100.0,
 ///         sell_price: 150.0,
@@ -89,6 +103,7 @@ pub struct ConflictSummary {
 ///         buy_has_sector: false,
 ///         sell_has_sector: false,
 ///         gics_sector: Some("Financials".to_string()),
+///         term: GainTerm::Short,
 ///     },
 /// ];
 ///
@@ -109,8 +124,32 @@ pub fn calculate_committee_trading_score(
     politician_id: String,
     politician_name: String,
 ) -> CommitteeTradingScore {
-    // Build set of sectors under politician's committee jurisdictions
-    let committee_sectors = get_committee_sectors(committee_jurisdictions, politician_committees);
+    calculate_committee_trading_score_as_of(
+        closed_trades,
+        politician_committees,
+        committee_jurisdictions,
+        &CongressCommitteeResolver::default(),
+        politician_id,
+        politician_name,
+    )
+}
+
+/// [`calculate_committee_trading_score`] with each trade scored against the
+/// committee structure in effect on its sell date, so a committee renamed or
+/// dissolved since then maps through `resolver` (see
+/// [`get_committee_sectors_as_of`]). Trades with an unparseable sell date use
+/// the committee codes as given.
+pub fn calculate_committee_trading_score_as_of(
+    closed_trades: &[ClosedTrade],
+    politician_committees: &[String],
+    committee_jurisdictions: &[CommitteeJurisdiction],
+    resolver: &CongressCommitteeResolver,
+    politician_id: String,
+    politician_name: String,
+) -> CommitteeTradingScore {
+    let current_sectors = get_committee_sectors(committee_jurisdictions, politician_committees);
+    // Sector sets only change between congresses.
+    let mut sectors_by_congress: HashMap<u32, HashSet<String>> = HashMap::new();
 
     // Filter trades to those with known gics_sector
     let trades_with_sector: Vec<&ClosedTrade> = closed_trades
@@ -122,10 +161,22 @@ pub fn calculate_committee_trading_score(
     let committee_related_count = trades_with_sector
         .iter()
         .filter(|t| {
-            if let Some(ref sector) = t.gics_sector {
-                committee_sectors.contains(sector)
-            } else {
-                false
+            let Some(ref sector) = t.gics_sector else {
+                return false;
+            };
+            match NaiveDate::parse_from_str(&t.sell_date, "%Y-%m-%d") {
+                Ok(date) => sectors_by_congress
+                    .entry(congress_for_date(date))
+                    .or_insert_with(|| {
+                        get_committee_sectors_as_of(
+                            committee_jurisdictions,
+                            resolver,
+                            politician_committees,
+                            date,
+                        )
+                    })
+                    .contains(sector),
+                Err(_) => current_sectors.contains(sector),
             }
         })
         .count();
@@ -148,9 +199,382 @@ pub fn calculate_committee_trading_score(
     }
 }
 
+/// Account class of a trade, derived from its normalized `owner`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnerClass {
+    /// The politician's own account.
+    #[serde(rename = "self")]
+    SelfOwned,
+    /// Spouse or joint account.
+    Spouse,
+    /// Child, undisclosed, or unrecognized owner.
+    Other,
+}
+
+impl OwnerClass {
+    /// Classify a stored owner string via [`validation::validate_owner`].
+    pub fn from_owner(owner: &str) -> Self {
+        match validation::validate_owner(owner) {
+            Ok("self") => OwnerClass::SelfOwned,
+            Ok("spouse") | Ok("joint") => OwnerClass::Spouse,
+            _ => OwnerClass::Other,
+        }
+    }
+}
+
+/// Trade counts and volume for one owner class. Only trades whose issuer has
+/// a GICS sector are scored, matching [`calculate_committee_trading_score`].
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct OwnerVolume {
+    pub scored_trades: i64,
+    pub scored_volume: i64,
+    pub in_jurisdiction_trades: i64,
+    pub in_jurisdiction_volume: i64,
+}
+
+impl OwnerVolume {
+    /// Share of scored trades in committee-jurisdiction sectors (0-100).
+    pub fn committee_trading_pct(&self) -> f64 {
+        if self.scored_trades > 0 {
+            self.in_jurisdiction_trades as f64 / self.scored_trades as f64 * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Committee trading split by owner class for one politician.
+#[derive(Serialize, Debug, Clone)]
+pub struct OwnerCommitteeScore {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub committee_names: Vec<String>,
+    pub self_owned: OwnerVolume,
+    pub spouse: OwnerVolume,
+    pub other: OwnerVolume,
+    /// Spouse/joint in-jurisdiction volume divided by own-account
+    /// in-jurisdiction volume; `None` when the own-account volume is zero.
+    pub spouse_to_self_ratio: Option<f64>,
+    /// True when there is in-jurisdiction trading and all of it is in
+    /// spouse or joint accounts.
+    pub spouse_exclusive: bool,
+}
+
+impl OwnerCommitteeScore {
+    pub fn get(&self, class: OwnerClass) -> &OwnerVolume {
+        match class {
+            OwnerClass::SelfOwned => &self.self_owned,
+            OwnerClass::Spouse => &self.spouse,
+            OwnerClass::Other => &self.other,
+        }
+    }
+}
+
+/// Committee trading score for one politician, split into own-account,
+/// spouse/joint and other owners, using trade volume rather than closed
+/// trades so that open positions count too.
+///
+/// Returns `None` when the politician is not in the database. A politician
+/// with no committee assignments gets zero in-jurisdiction volume.
+pub fn calculate_committee_trading_score_by_owner(
+    db: &Db,
+    politician_id: &str,
+    committee_jurisdictions: &[CommitteeJurisdiction],
+) -> Result<Option<OwnerCommitteeScore>, DbError> {
+    let Some((politician_name, committees)) = db.get_politician_with_committees(politician_id)?
+    else {
+        return Ok(None);
+    };
+    let committee_sectors = get_committee_sectors(committee_jurisdictions, &committees);
+
+    let mut self_owned = OwnerVolume::default();
+    let mut spouse = OwnerVolume::default();
+    let mut other = OwnerVolume::default();
+    for row in db.query_owner_sector_volumes(politician_id)? {
+        let Some(sector) = row.gics_sector else {
+            continue;
+        };
+        let bucket = match OwnerClass::from_owner(&row.owner) {
+            OwnerClass::SelfOwned => &mut self_owned,
+            OwnerClass::Spouse => &mut spouse,
+            OwnerClass::Other => &mut other,
+        };
+        bucket.scored_trades += row.trade_count;
+        bucket.scored_volume += row.volume;
+        if committee_sectors.contains(&sector) {
+            bucket.in_jurisdiction_trades += row.trade_count;
+            bucket.in_jurisdiction_volume += row.volume;
+        }
+    }
+
+    let spouse_to_self_ratio = (self_owned.in_jurisdiction_volume > 0).then(|| {
+        spouse.in_jurisdiction_volume as f64 / self_owned.in_jurisdiction_volume as f64
+    });
+    let spouse_exclusive = spouse.in_jurisdiction_trades > 0
+        && self_owned.in_jurisdiction_trades == 0
+        && other.in_jurisdiction_trades == 0;
+
+    Ok(Some(OwnerCommitteeScore {
+        politician_id: politician_id.to_string(),
+        politician_name,
+        committee_names: committees,
+        self_owned,
+        spouse,
+        other,
+        spouse_to_self_ratio,
+        spouse_exclusive,
+    }))
+}
+
+/// A committee member's in-jurisdiction trade ahead of that committee's hearing.
+#[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HearingProximityRow {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub committee: String,
+    pub hearing_date: String,
+    pub hearing_title: String,
+    pub tx_id: i64,
+    pub ticker: String,
+    pub gics_sector: String,
+    pub tx_type: String,
+    pub tx_date: String,
+    /// Days from the hearing to the trade; always zero or negative.
+    pub day_offset: i64,
+    pub value: i64,
+}
+
+/// Result of [`hearing_proximity_analysis`].
+#[derive(Serialize, Debug, Clone)]
+pub struct HearingProximityReport {
+    pub rows: Vec<HearingProximityRow>,
+    /// True when membership was checked against the trade date using the
+    /// committee history table; false means current membership was used.
+    pub used_committee_history: bool,
+    /// Hearing committees with no jurisdiction mapping (no rows possible).
+    pub unmapped_committees: Vec<String>,
+}
+
+/// Find trades by members of each hearing's committee, in issuers whose GICS
+/// sector falls under that committee's jurisdiction, made in the
+/// `window_days` before the hearing (the hearing day itself included).
+///
+/// Membership is taken from `politician_committee_history` when present, so
+/// members who joined after the trade are excluded; otherwise current
+/// committee assignments are used and the report says so. The hearing
+/// committee's jurisdiction is the one in effect on the hearing date.
+pub fn hearing_proximity_analysis(
+    db: &Db,
+    hearings: &[CommitteeHearing],
+    committee_jurisdictions: &[CommitteeJurisdiction],
+    resolver: &CongressCommitteeResolver,
+    window_days: u32,
+) -> Result<HearingProximityReport, DbError> {
+    let use_history = db.has_committee_history()?;
+    let mut rows = Vec::new();
+    let mut unmapped_committees: Vec<String> = Vec::new();
+
+    for hearing in hearings {
+        let committee = std::slice::from_ref(&hearing.committee);
+        let mut sectors: Vec<String> =
+            get_committee_sectors_as_of(committee_jurisdictions, resolver, committee, hearing.date)
+                .into_iter()
+                .collect();
+        if sectors.is_empty() {
+            if !unmapped_committees.contains(&hearing.committee) {
+                unmapped_committees.push(hearing.committee.clone());
+            }
+            continue;
+        }
+        sectors.sort();
+
+        let from = hearing
+            .date
+            .checked_sub_days(Days::new(u64::from(window_days)))
+            .unwrap_or(NaiveDate::MIN);
+        let trades =
+            db.query_committee_sector_trades(&hearing.committee, &sectors, from, hearing.date, use_history)?;
+
+        for trade in trades {
+            let Ok(tx_date) = NaiveDate::parse_from_str(&trade.tx_date, "%Y-%m-%d") else {
+                continue;
+            };
+            rows.push(HearingProximityRow {
+                politician_id: trade.politician_id,
+                politician_name: trade.politician_name,
+                committee: hearing.committee.clone(),
+                hearing_date: hearing.date.format("%Y-%m-%d").to_string(),
+                hearing_title: hearing.title.clone(),
+                tx_id: trade.tx_id,
+                ticker: trade.ticker,
+                gics_sector: trade.gics_sector,
+                tx_type: trade.tx_type,
+                tx_date: trade.tx_date,
+                day_offset: (tx_date - hearing.date).num_days(),
+                value: trade.value,
+            });
+        }
+    }
+
+    Ok(HearingProximityReport {
+        rows,
+        used_committee_history: use_history,
+        unmapped_committees,
+    })
+}
+
+/// Options for [`donation_trade_affinity`].
+#[derive(Debug, Clone, Copy)]
+pub struct AffinityOptions {
+    /// Minimum employer mapping confidence for a donation to count (0-1).
+    pub min_confidence: f64,
+    /// Donations this many days or more from the nearest trade in the same
+    /// ticker get no proximity weight.
+    pub proximity_days: i64,
+}
+
+impl Default for AffinityOptions {
+    fn default() -> Self {
+        Self {
+            min_confidence: 0.90,
+            proximity_days: 180,
+        }
+    }
+}
+
+/// A politician's donation-trade affinity with its components broken out.
+#[derive(Serialize, Debug, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct AffinityScore {
+    pub politician_id: String,
+    pub politician_name: String,
+    /// `100 * sqrt(donation_share * trade_share) * avg_confidence * (0.5 + 0.5 * proximity)`.
+    pub score: f64,
+    pub matched_donation_count: usize,
+    pub matched_tickers: usize,
+    pub matched_donation_total: f64,
+    pub donation_total: f64,
+    /// Matched share of all donation dollars (0-1).
+    pub donation_share: f64,
+    pub matched_trade_volume: i64,
+    pub trade_volume: i64,
+    /// Matched share of all trade volume (0-1).
+    pub trade_share: f64,
+    /// Dollar-weighted mean mapping confidence of matched donations (0-1).
+    pub avg_confidence: f64,
+    /// Dollar-weighted closeness of matched donations to a trade in the same
+    /// ticker (1 = same day, 0 = `proximity_days` or more apart, or undated).
+    pub proximity: f64,
+}
+
+/// Result of [`donation_trade_affinity`].
+#[derive(Serialize, Debug, Clone)]
+pub struct AffinityReport {
+    /// Highest score first.
+    pub scores: Vec<AffinityScore>,
+    /// Trading politicians with no synced donations. They are not scored,
+    /// since a zero would read as an absence of conflict.
+    pub unscored_no_donations: usize,
+}
+
+/// Score every politician's overall pattern of trading stock of companies
+/// whose employees donate to them.
+///
+/// Components are shares of the politician's own totals, so a member with
+/// heavy trading and fundraising does not outrank a smaller one on dollar
+/// volume alone. The geometric mean of the two shares is scaled by mapping
+/// confidence and by how close in time donations fall to trades.
+pub fn donation_trade_affinity(
+    db: &Db,
+    opts: &AffinityOptions,
+) -> Result<AffinityReport, DbError> {
+    let inputs = db.donation_trade_affinity_inputs(opts.min_confidence)?;
+    Ok(score_affinity(&inputs, opts))
+}
+
+/// Scoring half of [`donation_trade_affinity`], on already-loaded inputs.
+pub fn score_affinity(inputs: &[AffinityInput], opts: &AffinityOptions) -> AffinityReport {
+    let share = |part: f64, whole: f64| if whole > 0.0 { (part / whole).min(1.0) } else { 0.0 };
+    let mut unscored_no_donations = 0;
+    let mut scores = Vec::new();
+
+    for input in inputs {
+        if input.donation_count == 0 {
+            unscored_no_donations += 1;
+            continue;
+        }
+
+        let matched_donation_total: f64 = input.matched_donations.iter().map(|d| d.amount).sum();
+        let weighted_mean = |f: &dyn Fn(&crate::db::MatchedDonation) -> f64| {
+            if matched_donation_total > 0.0 {
+                input
+                    .matched_donations
+                    .iter()
+                    .map(|d| d.amount * f(d))
+                    .sum::<f64>()
+                    / matched_donation_total
+            } else {
+                0.0
+            }
+        };
+        let avg_confidence = weighted_mean(&|d| d.confidence);
+        let proximity = weighted_mean(&|d| match d.days_to_nearest_trade {
+            Some(days) if opts.proximity_days > 0 => {
+                (1.0 - days as f64 / opts.proximity_days as f64).max(0.0)
+            }
+            _ => 0.0,
+        });
+
+        let donation_share = share(matched_donation_total, input.donation_total);
+        let trade_share = share(input.matched_trade_volume as f64, input.trade_volume as f64);
+        let score = 100.0
+            * (donation_share * trade_share).sqrt()
+            * avg_confidence
+            * (0.5 + 0.5 * proximity);
+
+        let mut tickers: Vec<&str> = input
+            .matched_donations
+            .iter()
+            .map(|d| d.ticker.as_str())
+            .collect();
+        tickers.sort_unstable();
+        tickers.dedup();
+
+        scores.push(AffinityScore {
+            politician_id: input.politician_id.clone(),
+            politician_name: input.politician_name.clone(),
+            score,
+            matched_donation_count: input.matched_donations.len(),
+            matched_tickers: tickers.len(),
+            matched_donation_total,
+            donation_total: input.donation_total,
+            donation_share,
+            matched_trade_volume: input.matched_trade_volume,
+            trade_volume: input.trade_volume,
+            trade_share,
+            avg_confidence,
+            proximity,
+        });
+    }
+
+    scores.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.politician_name.cmp(&b.politician_name))
+    });
+    AffinityReport {
+        scores,
+        unscored_no_donations,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::analytics::GainTerm;
     use crate::committee_jurisdiction::load_committee_jurisdictions;
 
     #[test]
@@ -162,6 +586,8 @@ mod tests {
             ClosedTrade {
                 politician_id: "P000001".to_string(),
                 ticker: "JPM".to_string(),
+                buy_tx_id: 1,
+                sell_tx_id: 2,
                 shares: 100.0,
                 buy_price: 100.0,
                 sell_price: 150.0,
@@ -172,10 +598,13 @@ mod tests {
                 buy_has_sector: false,
                 sell_has_sector: false,
                 gics_sector: Some("Financials".to_string()),
+                term: GainTerm::Short,
             },
             ClosedTrade {
                 politician_id: "P000001".to_string(),
                 ticker: "JPM".to_string(),
+                buy_tx_id: 1,
+                sell_tx_id: 2,
                 shares: 50.0,
                 buy_price: 100.0,
                 sell_price: 150.0,
@@ -186,10 +615,13 @@ mod tests {
                 buy_has_sector: false,
                 sell_has_sector: false,
                 gics_sector: Some("Financials".to_string()),
+                term: GainTerm::Short,
             },
             ClosedTrade {
                 politician_id: "P000001".to_string(),
                 ticker: "XOM".to_string(),
+                buy_tx_id: 1,
+                sell_tx_id: 2,
                 shares: 100.0,
                 buy_price: 80.0,
                 sell_price: 90.0,
@@ -200,6 +632,7 @@ mod tests {
                 buy_has_sector: false,
                 sell_has_sector: false,
                 gics_sector: Some("Energy".to_string()),
+                term: GainTerm::Short,
             },
         ];
 
@@ -224,6 +657,8 @@ mod tests {
         let trades = vec![ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "JPM".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 100.0,
             sell_price: 150.0,
@@ -234,6 +669,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: false,
             gics_sector: Some("Financials".to_string()),
+            term: GainTerm::Short,
         }];
 
         let score = calculate_committee_trading_score(
@@ -277,6 +713,8 @@ mod tests {
             ClosedTrade {
                 politician_id: "P000001".to_string(),
                 ticker: "JPM".to_string(),
+                buy_tx_id: 1,
+                sell_tx_id: 2,
                 shares: 100.0,
                 buy_price: 100.0,
                 sell_price: 150.0,
@@ -287,10 +725,13 @@ mod tests {
                 buy_has_sector: false,
                 sell_has_sector: false,
                 gics_sector: Some("Financials".to_string()),
+                term: GainTerm::Short,
             },
             ClosedTrade {
                 politician_id: "P000001".to_string(),
                 ticker: "UNKNOWN".to_string(),
+                buy_tx_id: 1,
+                sell_tx_id: 2,
                 shares: 100.0,
                 buy_price: 100.0,
                 sell_price: 150.0,
@@ -301,6 +742,7 @@ mod tests {
                 buy_has_sector: false,
                 sell_has_sector: false,
                 gics_sector: None, // NULL sector
+                term: GainTerm::Short,
             },
         ];
 
@@ -328,6 +770,8 @@ mod tests {
         let trades = vec![ClosedTrade {
             politician_id: "P000001".to_string(),
             ticker: "JNJ".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
             shares: 100.0,
             buy_price: 150.0,
             sell_price: 160.0,
@@ -338,6 +782,7 @@ mod tests {
             buy_has_sector: false,
             sell_has_sector: false,
             gics_sector: Some("Health Care".to_string()),
+            term: GainTerm::Short,
         }];
 
         let score = calculate_committee_trading_score(
@@ -371,6 +816,52 @@ mod tests {
         assert!(score.disclaimer.contains("current committee assignments"));
     }
 
+    #[test]
+    fn test_committee_trading_score_as_of_uses_committee_at_sell_date() {
+        let jurisdictions = load_committee_jurisdictions().unwrap();
+        let resolver = CongressCommitteeResolver::bundled().unwrap();
+        let committees = vec!["hscn".to_string()]; // Climate Crisis, dissolved after the 117th
+        let energy_trade = |sell_date: &str| ClosedTrade {
+            politician_id: "P000001".to_string(),
+            ticker: "XOM".to_string(),
+            buy_tx_id: 1,
+            sell_tx_id: 2,
+            shares: 100.0,
+            buy_price: 80.0,
+            sell_price: 90.0,
+            buy_date: "2019-02-01".to_string(),
+            sell_date: sell_date.to_string(),
+            buy_benchmark: None,
+            sell_benchmark: None,
+            buy_has_sector: false,
+            sell_has_sector: false,
+            gics_sector: Some("Energy".to_string()),
+            term: GainTerm::Long,
+        };
+        let trades = vec![energy_trade("2020-06-01"), energy_trade("2024-06-01")];
+
+        let score = calculate_committee_trading_score_as_of(
+            &trades,
+            &committees,
+            &jurisdictions,
+            &resolver,
+            "P000001".to_string(),
+            "John Doe".to_string(),
+        );
+        // Only the 2024 sale falls under the successor (Energy and Commerce).
+        assert_eq!(score.total_scored_trades, 2);
+        assert_eq!(score.committee_related_trades, 1);
+
+        let undated = calculate_committee_trading_score(
+            &trades,
+            &committees,
+            &jurisdictions,
+            "P000001".to_string(),
+            "John Doe".to_string(),
+        );
+        assert_eq!(undated.committee_related_trades, 0);
+    }
+
     #[test]
     fn test_donation_trade_correlation_type() {
         let correlation = DonationTradeCorrelation {
@@ -387,4 +878,89 @@ mod tests {
         assert_eq!(correlation.ticker, "JPM");
         assert_eq!(correlation.matching_donor_count, 5);
     }
+
+    fn affinity_input(
+        id: &str,
+        donation_total: f64,
+        trade_volume: i64,
+        matched_trade_volume: i64,
+        matched: &[(&str, f64, Option<i64>)],
+    ) -> AffinityInput {
+        AffinityInput {
+            politician_id: id.to_string(),
+            politician_name: id.to_string(),
+            donation_count: if donation_total > 0.0 { 10 } else { 0 },
+            donation_total,
+            trade_volume,
+            matched_trade_volume,
+            matched_donations: matched
+                .iter()
+                .map(|&(ticker, amount, days)| crate::db::MatchedDonation {
+                    ticker: ticker.to_string(),
+                    amount,
+                    confidence: 1.0,
+                    days_to_nearest_trade: days,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_affinity_normalizes_away_raw_volume() {
+        // Small member: half of donations and trades tied to one company.
+        let small = affinity_input("SMALL", 10_000.0, 100_000, 50_000, &[("ACME", 5_000.0, Some(0))]);
+        // Large member: 100x the matched dollars, but a tiny slice of activity.
+        let large = affinity_input(
+            "LARGE",
+            10_000_000.0,
+            500_000_000,
+            5_000_000,
+            &[("MEGA", 500_000.0, Some(0))],
+        );
+        let report = score_affinity(&[large, small], &AffinityOptions::default());
+
+        assert_eq!(report.scores.len(), 2);
+        assert_eq!(report.scores[0].politician_id, "SMALL");
+        assert!((report.scores[0].score - 50.0).abs() < 1e-9);
+        assert!((report.scores[0].donation_share - 0.5).abs() < 1e-9);
+        let large = &report.scores[1];
+        assert!(large.matched_donation_total > report.scores[0].matched_donation_total);
+        // sqrt(0.05 * 0.01) * 100
+        assert!((large.score - (0.05f64 * 0.01).sqrt() * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_affinity_excludes_members_without_donations() {
+        let unsynced = affinity_input("NONE", 0.0, 100_000, 0, &[]);
+        let clean = affinity_input("CLEAN", 10_000.0, 100_000, 0, &[]);
+        let report = score_affinity(&[unsynced, clean], &AffinityOptions::default());
+
+        assert_eq!(report.unscored_no_donations, 1);
+        assert_eq!(report.scores.len(), 1);
+        assert_eq!(report.scores[0].politician_id, "CLEAN");
+        assert_eq!(report.scores[0].score, 0.0);
+    }
+
+    #[test]
+    fn test_affinity_proximity_weighting() {
+        let opts = AffinityOptions {
+            min_confidence: 0.9,
+            proximity_days: 100,
+        };
+        let near = affinity_input("NEAR", 1_000.0, 1_000, 1_000, &[("X", 1_000.0, Some(0))]);
+        let mid = affinity_input("MID", 1_000.0, 1_000, 1_000, &[("X", 1_000.0, Some(50))]);
+        let far = affinity_input("FAR", 1_000.0, 1_000, 1_000, &[("X", 1_000.0, Some(400))]);
+        let undated = affinity_input("UNDATED", 1_000.0, 1_000, 1_000, &[("X", 1_000.0, None)]);
+        let report = score_affinity(&[far, undated, mid, near], &opts);
+
+        let score_of = |id: &str| {
+            report.scores.iter().find(|s| s.politician_id == id).unwrap().score
+        };
+        assert!((score_of("NEAR") - 100.0).abs() < 1e-9);
+        assert!((score_of("MID") - 75.0).abs() < 1e-9);
+        assert!((score_of("FAR") - 50.0).abs() < 1e-9);
+        assert!((score_of("UNDATED") - 50.0).abs() < 1e-9);
+        // Ties break by name.
+        assert_eq!(report.scores[2].politician_id, "FAR");
+    }
 }
@@ -0,0 +1,351 @@
+//! Congressional committee definitions across congresses.
+//!
+//! Committee short codes outlive the committees' names: `hsgo` was "Oversight
+//! and Reform" in the 116th Congress and "Oversight and Accountability" in the
+//! 118th, and select committees such as `hscn` were dissolved outright. This
+//! module loads a versioned definition file (bundled from
+//! `seed_data/congress_committees.yml`, overridable with `committees_file` in
+//! the config file) and resolves a code against the committee structure in
+//! effect on a given date.
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const BUNDLED: &str = include_str!("../../seed_data/congress_committees.yml");
+
+/// Successor chains longer than this are treated as a cycle.
+const MAX_SUCCESSOR_DEPTH: usize = 8;
+
+/// Errors loading a committee definition file.
+#[derive(Error, Debug)]
+pub enum CongressCommitteeError {
+    #[error("Failed to read committee file {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse committee YAML: {0}")]
+    YamlParse(#[from] serde_yml::Error),
+    #[error("Invalid committee definition: {0}")]
+    Invalid(String),
+}
+
+/// One name a committee carried, for an inclusive range of congresses.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CommitteeNameSpan {
+    pub name: String,
+    pub from: u32,
+    /// Last congress with this name; `None` while it is current.
+    pub to: Option<u32>,
+}
+
+impl CommitteeNameSpan {
+    fn covers(&self, congress: u32) -> bool {
+        congress >= self.from && self.to.is_none_or(|to| congress <= to)
+    }
+}
+
+/// A committee code and its history.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CommitteeDefinition {
+    pub code: String,
+    pub chamber: String,
+    pub names: Vec<CommitteeNameSpan>,
+    /// Code that took over this committee's jurisdiction after it ended.
+    pub successor: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct CommitteeFile {
+    version: u32,
+    committees: Vec<CommitteeDefinition>,
+}
+
+/// A committee code resolved for one congress.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResolvedCongressCommittee {
+    /// Code of the committee in effect; differs from the code asked for when
+    /// it resolved through a successor.
+    pub code: String,
+    pub name: String,
+    pub chamber: String,
+    /// Congress the code was resolved for.
+    pub congress: u32,
+    /// First congress the resolved name was in effect.
+    pub valid_from: u32,
+    /// Last congress the resolved name was in effect; `None` while current.
+    pub valid_to: Option<u32>,
+    /// The code asked for, when it was no longer in effect and resolved to
+    /// its successor.
+    pub successor_of: Option<String>,
+}
+
+/// Congress in session on `date`. Each congress starts on January 3 of an
+/// odd year (the 116th on 2019-01-03).
+pub fn congress_for_date(date: NaiveDate) -> u32 {
+    let mut year = date.year();
+    if year % 2 != 0 && (date.month(), date.day()) < (1, 3) {
+        year -= 1;
+    }
+    ((year - 1789).max(0) / 2 + 1) as u32
+}
+
+/// Committee definitions keyed by code.
+#[derive(Debug, Clone, Default)]
+pub struct CongressCommitteeResolver {
+    version: u32,
+    committees: HashMap<String, CommitteeDefinition>,
+}
+
+impl CongressCommitteeResolver {
+    /// Definitions bundled with this build.
+    pub fn bundled() -> Result<Self, CongressCommitteeError> {
+        Self::from_yaml(BUNDLED)
+    }
+
+    /// Definitions from `path` when given, otherwise the bundled file.
+    pub fn load(path: Option<&Path>) -> Result<Self, CongressCommitteeError> {
+        match path {
+            Some(path) => Self::from_file(path),
+            None => Self::bundled(),
+        }
+    }
+
+    /// Definitions from a YAML file in the bundled format.
+    pub fn from_file(path: &Path) -> Result<Self, CongressCommitteeError> {
+        let content = std::fs::read_to_string(path).map_err(|source| CongressCommitteeError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_yaml(&content)
+    }
+
+    /// Parse and validate definitions: chambers are House or Senate, name
+    /// spans are ordered and non-overlapping, and successors are known codes.
+    pub fn from_yaml(yaml_content: &str) -> Result<Self, CongressCommitteeError> {
+        let file: CommitteeFile = serde_yml::from_str(yaml_content)?;
+        let mut committees = HashMap::new();
+        for committee in file.committees {
+            if committee.chamber != "House" && committee.chamber != "Senate" {
+                return Err(CongressCommitteeError::Invalid(format!(
+                    "committee '{}' has chamber '{}'; must be 'House' or 'Senate'",
+                    committee.code, committee.chamber
+                )));
+            }
+            if committee.names.is_empty() {
+                return Err(CongressCommitteeError::Invalid(format!(
+                    "committee '{}' has no names",
+                    committee.code
+                )));
+            }
+            let mut previous_to: Option<u32> = None;
+            for (i, span) in committee.names.iter().enumerate() {
+                if span.to.is_some_and(|to| to < span.from) {
+                    return Err(CongressCommitteeError::Invalid(format!(
+                        "committee '{}' name '{}' ends before it starts",
+                        committee.code, span.name
+                    )));
+                }
+                if i > 0 && previous_to.is_none_or(|to| span.from <= to) {
+                    return Err(CongressCommitteeError::Invalid(format!(
+                        "committee '{}' name '{}' overlaps the name before it",
+                        committee.code, span.name
+                    )));
+                }
+                previous_to = span.to;
+            }
+            let code = committee.code.clone();
+            if committees.insert(code.clone(), committee).is_some() {
+                return Err(CongressCommitteeError::Invalid(format!(
+                    "committee '{}' is defined twice",
+                    code
+                )));
+            }
+        }
+        for committee in committees.values() {
+            if let Some(ref successor) = committee.successor {
+                if !committees.contains_key(successor) {
+                    return Err(CongressCommitteeError::Invalid(format!(
+                        "committee '{}' names unknown successor '{}'",
+                        committee.code, successor
+                    )));
+                }
+            }
+        }
+        Ok(Self {
+            version: file.version,
+            committees,
+        })
+    }
+
+    /// Version declared by the definition file.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Whether the file defines `code` in any congress.
+    pub fn knows(&self, code: &str) -> bool {
+        self.committees.contains_key(code)
+    }
+
+    /// Resolve `code` for `congress`. A code that had ended by then resolves
+    /// to its successor when one is defined. `None` when the code is unknown,
+    /// did not exist yet, or ended without a successor.
+    pub fn resolve(&self, code: &str, congress: u32) -> Option<ResolvedCongressCommittee> {
+        let mut current = self.committees.get(code)?;
+        for _ in 0..MAX_SUCCESSOR_DEPTH {
+            if let Some(span) = current.names.iter().find(|s| s.covers(congress)) {
+                return Some(ResolvedCongressCommittee {
+                    code: current.code.clone(),
+                    name: span.name.clone(),
+                    chamber: current.chamber.clone(),
+                    congress,
+                    valid_from: span.from,
+                    valid_to: span.to,
+                    successor_of: (current.code != code).then(|| code.to_string()),
+                });
+            }
+            let ended = current
+                .names
+                .iter()
+                .filter_map(|s| s.to)
+                .max()
+                .is_some_and(|to| congress > to);
+            if !ended {
+                return None;
+            }
+            current = self.committees.get(current.successor.as_deref()?)?;
+        }
+        None
+    }
+
+    /// Resolve `code` against the committee structure in effect on `date`.
+    pub fn resolve_as_of(&self, code: &str, date: NaiveDate) -> Option<ResolvedCongressCommittee> {
+        self.resolve(code, congress_for_date(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_congress_for_date_boundaries() {
+        assert_eq!(congress_for_date(date("2019-01-03")), 116);
+        assert_eq!(congress_for_date(date("2021-01-02")), 116);
+        assert_eq!(congress_for_date(date("2021-01-03")), 117);
+        assert_eq!(congress_for_date(date("2024-06-01")), 118);
+        assert_eq!(congress_for_date(date("2025-01-02")), 118);
+        assert_eq!(congress_for_date(date("2025-01-03")), 119);
+    }
+
+    #[test]
+    fn test_bundled_definitions_load() {
+        let resolver = CongressCommitteeResolver::bundled().unwrap();
+        assert!(resolver.version() >= 1);
+        for code in resolver.committees.keys() {
+            assert!(
+                crate::validation::COMMITTEE_MAP.iter().any(|(c, _)| c == code),
+                "{} is not a CapitolTrades committee code",
+                code
+            );
+        }
+    }
+
+    #[test]
+    fn test_renamed_committee_resolves_by_trade_date() {
+        let resolver = CongressCommitteeResolver::bundled().unwrap();
+
+        let in_2019 = resolver.resolve_as_of("hsgo", date("2019-06-01")).unwrap();
+        assert_eq!(in_2019.name, "Oversight and Reform");
+        assert_eq!((in_2019.valid_from, in_2019.valid_to), (116, Some(117)));
+
+        let in_2024 = resolver.resolve_as_of("hsgo", date("2024-06-01")).unwrap();
+        assert_eq!(in_2024.name, "Oversight and Accountability");
+        assert_eq!((in_2024.valid_from, in_2024.valid_to), (118, Some(118)));
+        assert_eq!(in_2024.code, "hsgo");
+        assert_eq!(in_2024.successor_of, None);
+
+        let ed_2019 = resolver.resolve_as_of("hsed", date("2019-06-01")).unwrap();
+        let ed_2024 = resolver.resolve_as_of("hsed", date("2024-06-01")).unwrap();
+        assert_eq!(ed_2019.name, "Education and Labor");
+        assert_eq!(ed_2024.name, "Education and the Workforce");
+    }
+
+    #[test]
+    fn test_dissolved_committee_resolves_to_successor() {
+        let resolver = CongressCommitteeResolver::bundled().unwrap();
+
+        let in_session = resolver.resolve_as_of("hscn", date("2020-03-01")).unwrap();
+        assert_eq!(in_session.code, "hscn");
+        assert_eq!(in_session.successor_of, None);
+
+        let after = resolver.resolve_as_of("hscn", date("2024-03-01")).unwrap();
+        assert_eq!(after.code, "hsif");
+        assert_eq!(after.name, "Energy and Commerce");
+        assert_eq!(after.successor_of.as_deref(), Some("hscn"));
+
+        // Created in the 118th Congress, so nothing to resolve to in 2019.
+        assert_eq!(resolver.resolve_as_of("hszs", date("2019-06-01")), None);
+        assert_eq!(resolver.resolve_as_of("unknown", date("2024-06-01")), None);
+    }
+
+    #[test]
+    fn test_override_file_is_validated() {
+        let overlapping = r#"
+version: 2
+committees:
+  - code: hsxx
+    chamber: House
+    names:
+      - name: First
+        from: 110
+        to: 117
+      - name: Second
+        from: 117
+"#;
+        assert!(matches!(
+            CongressCommitteeResolver::from_yaml(overlapping),
+            Err(CongressCommitteeError::Invalid(_))
+        ));
+
+        let dangling = r#"
+version: 2
+committees:
+  - code: hsxx
+    chamber: House
+    names:
+      - name: Only
+        from: 110
+        to: 112
+    successor: hsyy
+"#;
+        assert!(CongressCommitteeResolver::from_yaml(dangling).is_err());
+
+        let path = std::env::temp_dir().join(format!(
+            "capitoltraders-committees-{}.yml",
+            std::process::id()
+        ));
+        std::fs::write(
+            &path,
+            "version: 7\ncommittees:\n  - code: hsxx\n    chamber: House\n    names:\n      - name: Only\n        from: 110\n",
+        )
+        .unwrap();
+        let resolver = CongressCommitteeResolver::load(Some(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(resolver.version(), 7);
+        assert_eq!(resolver.resolve("hsxx", 119).unwrap().name, "Only");
+        assert!(!resolver.knows("hsgo"));
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! This module provides pure logic for matching employer names from FEC donation data
 //! to issuer records. It includes normalization, blacklisting, exact matching, and
-//! configurable fuzzy matching via Jaro-Winkler similarity.
+//! configurable fuzzy matching via Jaro-Winkler similarity. Contributor
+//! occupations get a lighter normalization that collapses common spellings of
+//! the same job for occupation aggregation.
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -14,6 +16,10 @@ pub enum EmployerMappingError {
     TomlParse(String),
     #[error("Invalid seed data: {0}")]
     InvalidSeedData(String),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("line {line}: {message}")]
+    InvalidRow { line: u64, message: String },
 }
 
 /// Type of match found.
@@ -140,6 +146,65 @@ pub fn normalize_employer(raw: &str) -> String {
     words.join(" ")
 }
 
+/// Occupation spellings collapsed by [`normalize_occupation`], as
+/// (variant, group). Variants are compared trimmed and uppercased; each group
+/// name is listed as its own variant so differently-cased entries merge too.
+pub const OCCUPATION_VARIANTS: &[(&str, &str)] = &[
+    ("ATTORNEY", "ATTORNEY"),
+    ("LAWYER", "ATTORNEY"),
+    ("ATTORNEY AT LAW", "ATTORNEY"),
+    ("PHYSICIAN", "PHYSICIAN"),
+    ("DOCTOR", "PHYSICIAN"),
+    ("MEDICAL DOCTOR", "PHYSICIAN"),
+    ("MD", "PHYSICIAN"),
+    ("M.D.", "PHYSICIAN"),
+    ("HOMEMAKER", "HOMEMAKER"),
+    ("HOUSEWIFE", "HOMEMAKER"),
+    ("HOUSE WIFE", "HOMEMAKER"),
+    ("CEO", "CEO"),
+    ("C.E.O.", "CEO"),
+    ("CHIEF EXECUTIVE OFFICER", "CEO"),
+    ("NOT EMPLOYED", "NOT EMPLOYED"),
+    ("NOT-EMPLOYED", "NOT EMPLOYED"),
+    ("NOT CURRENTLY EMPLOYED", "NOT EMPLOYED"),
+    ("UNEMPLOYED", "NOT EMPLOYED"),
+    ("NONE", "NOT EMPLOYED"),
+];
+
+/// Group label for donations without an occupation.
+pub const UNKNOWN_OCCUPATION: &str = "Unknown";
+
+/// Collapse a contributor occupation onto its group in
+/// [`OCCUPATION_VARIANTS`]. Unmapped occupations pass through trimmed but
+/// otherwise unchanged; blank input is [`UNKNOWN_OCCUPATION`].
+pub fn normalize_occupation(raw: &str) -> String {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return UNKNOWN_OCCUPATION.to_string();
+    }
+    let key = trimmed.to_uppercase();
+    OCCUPATION_VARIANTS
+        .iter()
+        .find(|(variant, _)| *variant == key)
+        .map(|(_, group)| group.to_string())
+        .unwrap_or_else(|| trimmed.to_string())
+}
+
+/// SQL expression applying [`normalize_occupation`] to `column` (a NULL
+/// column is [`UNKNOWN_OCCUPATION`]), for grouping inside a query.
+pub fn occupation_group_sql(column: &str) -> String {
+    let mut sql = format!(
+        "CASE WHEN {col} IS NULL OR TRIM({col}) = '' THEN '{unknown}' ELSE CASE UPPER(TRIM({col}))",
+        col = column,
+        unknown = UNKNOWN_OCCUPATION
+    );
+    for (variant, group) in OCCUPATION_VARIANTS {
+        sql.push_str(&format!(" WHEN '{}' THEN '{}'", variant, group));
+    }
+    sql.push_str(&format!(" ELSE TRIM({}) END END", column));
+    sql
+}
+
 /// Match an employer name to a list of issuers.
 ///
 /// # Arguments
@@ -228,10 +293,132 @@ pub fn load_seed_data() -> Result<Vec<SeedMapping>, EmployerMappingError> {
     Ok(seed_file.mapping)
 }
 
+/// One row of the employer mapping CSV written by
+/// [`write_employer_mappings_csv`] and read by [`parse_employer_mappings_csv`].
+///
+/// Carries the same fields as a [`SeedMapping`] entry plus the bookkeeping
+/// columns of `employer_mappings`, so an export restores the table exactly.
+/// `employer_names` holds the raw (lowercased) employer strings that resolve
+/// to this mapping through `employer_lookup`, one per line within the cell.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EmployerMappingRecord {
+    pub normalized_employer: String,
+    pub employer_names: String,
+    pub issuer_ticker: String,
+    pub confidence: f64,
+    pub match_type: String,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub last_updated: String,
+}
+
+impl EmployerMappingRecord {
+    /// Raw employer variants, skipping blank entries.
+    pub fn employer_name_list(&self) -> impl Iterator<Item = &str> {
+        self.employer_names
+            .split('\n')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+    }
+}
+
+/// Parse an employer mapping CSV.
+///
+/// Rows with an invalid ticker, a confidence outside `[0, 1]`, an empty
+/// normalized employer, or a key already seen earlier in the file are
+/// rejected with their line number.
+pub fn parse_employer_mappings_csv<R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<EmployerMappingRecord>, EmployerMappingError> {
+    let mut rdr = csv::Reader::from_reader(reader);
+    let headers = rdr.headers()?.clone();
+    let mut seen = std::collections::HashSet::new();
+    let mut records = Vec::new();
+
+    for result in rdr.records() {
+        let row = result?;
+        let line = row.position().map_or(0, |pos| pos.line());
+        let invalid = |message: String| EmployerMappingError::InvalidRow { line, message };
+        let record: EmployerMappingRecord = row
+            .deserialize(Some(&headers))
+            .map_err(|e| invalid(e.to_string()))?;
+
+        if record.normalized_employer.trim().is_empty() {
+            return Err(invalid("normalized_employer is empty".to_string()));
+        }
+        if !crate::ticker_alias::is_valid_ticker(&record.issuer_ticker) {
+            return Err(invalid(format!(
+                "invalid issuer_ticker '{}'",
+                record.issuer_ticker
+            )));
+        }
+        if !(0.0..=1.0).contains(&record.confidence) {
+            return Err(invalid(format!(
+                "confidence {} is outside [0, 1]",
+                record.confidence
+            )));
+        }
+        if !seen.insert(record.normalized_employer.clone()) {
+            return Err(invalid(format!(
+                "duplicate normalized_employer '{}'",
+                record.normalized_employer
+            )));
+        }
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Write employer mappings as CSV (the format [`parse_employer_mappings_csv`] reads).
+pub fn write_employer_mappings_csv<W: std::io::Write>(
+    writer: W,
+    records: &[EmployerMappingRecord],
+) -> Result<(), EmployerMappingError> {
+    let mut wtr = csv::Writer::from_writer(writer);
+    for record in records {
+        wtr.serialize(record)?;
+    }
+    wtr.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalize_occupation_collapses_variants() {
+        assert_eq!(normalize_occupation("Lawyer"), "ATTORNEY");
+        assert_eq!(normalize_occupation("  attorney "), "ATTORNEY");
+        assert_eq!(normalize_occupation("M.D."), "PHYSICIAN");
+        assert_eq!(normalize_occupation("Doctor"), "PHYSICIAN");
+        assert_eq!(normalize_occupation("HOUSEWIFE"), "HOMEMAKER");
+        assert_eq!(normalize_occupation("Chief Executive Officer"), "CEO");
+        assert_eq!(normalize_occupation("NOT-EMPLOYED"), "NOT EMPLOYED");
+        assert_eq!(normalize_occupation("None"), "NOT EMPLOYED");
+    }
+
+    #[test]
+    fn test_normalize_occupation_passes_unmapped_through() {
+        assert_eq!(normalize_occupation(" Software Engineer "), "Software Engineer");
+        assert_eq!(normalize_occupation("RETIRED"), "RETIRED");
+        assert_eq!(normalize_occupation(""), UNKNOWN_OCCUPATION);
+        assert_eq!(normalize_occupation("   "), UNKNOWN_OCCUPATION);
+    }
+
+    #[test]
+    fn test_occupation_variants_are_uppercase_and_groups_map_to_themselves() {
+        for (variant, group) in OCCUPATION_VARIANTS {
+            assert_eq!(*variant, variant.trim().to_uppercase());
+            assert!(
+                OCCUPATION_VARIANTS.contains(&(group, group)),
+                "group {} is not listed as its own variant",
+                group
+            );
+        }
+    }
+
     #[test]
     fn test_normalize_basic() {
         assert_eq!(normalize_employer("Apple Inc"), "apple");
@@ -367,4 +554,24 @@ mod tests {
             assert_eq!(first.confidence, 1.0);
         }
     }
+
+    #[test]
+    fn test_mapping_csv_rejects_bad_rows_with_line_numbers() {
+        let header = "normalized_employer,employer_names,issuer_ticker,confidence,match_type,notes,created_at,last_updated\n";
+        let good = "apple,\"apple inc\napple\",AAPL:US,1.0,exact,,2024-01-01 00:00:00,2024-01-01 00:00:00\n";
+
+        let csv = format!("{header}{good}google,,GOOGL:US,1.5,fuzzy,,t,t\n");
+        let err = parse_employer_mappings_csv(csv.as_bytes()).unwrap_err();
+        assert!(matches!(err, EmployerMappingError::InvalidRow { line: 4, .. }), "{err}");
+
+        let csv = format!("{header}google,,GOOG L,0.9,fuzzy,,t,t\n");
+        let err = parse_employer_mappings_csv(csv.as_bytes()).unwrap_err();
+        assert!(matches!(err, EmployerMappingError::InvalidRow { line: 2, .. }), "{err}");
+
+        let records = parse_employer_mappings_csv(format!("{header}{good}").as_bytes()).unwrap();
+        assert_eq!(
+            records[0].employer_name_list().collect::<Vec<_>>(),
+            vec!["apple inc", "apple"]
+        );
+    }
 }
@@ -0,0 +1,775 @@
+//! Detail enrichment phases that follow a trade sync.
+//!
+//! Trades and issuers are filled in from their detail pages; politicians get
+//! their committee memberships from the committee-filtered listings. Each
+//! phase is an [`EnrichTask`] run by one driver that owns the shared
+//! mechanics: bounded fetch concurrency, the consecutive-failure circuit
+//! breaker, cancellation, and progress reporting through a [`ProgressSink`].
+//! Fetch pacing and retries happen inside [`ScrapeClient`].
+//!
+//! [`enrich_trades`], [`enrich_issuers`] and [`enrich_politicians`] run one
+//! phase; [`enrich_after_sync`] composes them the way `sync --enrich` does.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::db::{Db, DbError, IssuerEnrichmentPriority};
+use crate::scrape::{ScrapeClient, ScrapeError, ScrapedIssuerDetail, ScrapedTradeDetail};
+use crate::shutdown::CancellationToken;
+use crate::validation::COMMITTEE_MAP;
+
+/// One enrichment phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrichPhase {
+    Trades,
+    Issuers,
+    Politicians,
+}
+
+impl EnrichPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Trades => "trades",
+            Self::Issuers => "issuers",
+            Self::Politicians => "politicians",
+        }
+    }
+
+    /// What one fetch covers: a trade, an issuer, or a committee listing.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Trades => "trade",
+            Self::Issuers => "issuer",
+            Self::Politicians => "committee",
+        }
+    }
+}
+
+impl std::fmt::Display for EnrichPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Limits and stop conditions shared by every phase. Fetches are paced by
+/// the [`ScrapeClient`]'s limiter (see [`crate::ratelimit`]).
+#[derive(Debug, Clone, Copy)]
+pub struct EnrichOptions<'a> {
+    /// Fetches in flight at once.
+    pub concurrency: usize,
+    /// Stop after this many consecutive failed fetches.
+    pub max_failures: usize,
+    /// Most items to fetch this run; `None` for the whole queue. Ignored by
+    /// the politician phase, whose memberships are replaced as a whole.
+    pub batch_size: Option<i64>,
+    /// Count the queue without fetching anything.
+    pub dry_run: bool,
+    pub cancel: &'a CancellationToken,
+}
+
+/// Which unenriched issuers to fetch, and in what order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IssuerQueue {
+    pub priority: IssuerEnrichmentPriority,
+    /// Leave issuers traded fewer times than this unenriched.
+    pub min_trades: Option<i64>,
+}
+
+/// Receives progress from a running phase. Every method defaults to doing
+/// nothing, so a sink only implements what it displays.
+pub trait ProgressSink: Send + Sync {
+    /// Fetching is about to start for `total` items.
+    fn start(&self, _phase: EnrichPhase, _total: usize) {}
+    /// One fetch finished; counts are running totals.
+    fn advance(&self, _enriched: usize, _failed: usize) {}
+    /// A fetch failed or the circuit breaker tripped.
+    fn warn(&self, _message: &str) {}
+    /// Fetching stopped.
+    fn finish(&self, _message: &str) {}
+    /// The phase is over, including dry runs and empty queues.
+    fn phase_done(&self, _summary: &EnrichSummary) {}
+}
+
+/// A [`ProgressSink`] that discards everything.
+#[derive(Debug, Default)]
+pub struct NoProgress;
+
+impl ProgressSink for NoProgress {}
+
+/// Outcome of one phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnrichSummary {
+    pub phase: EnrichPhase,
+    /// Items waiting before the batch limit.
+    pub pending: usize,
+    /// Items selected for this run.
+    pub total: usize,
+    pub enriched: usize,
+    pub failed: usize,
+    /// Issuers left out by [`IssuerQueue::min_trades`].
+    pub skipped: usize,
+    /// Stopped by the circuit breaker.
+    pub tripped: bool,
+    /// Stopped by cancellation.
+    pub interrupted: bool,
+    pub dry_run: bool,
+}
+
+impl EnrichSummary {
+    fn new(phase: EnrichPhase, dry_run: bool) -> Self {
+        Self {
+            phase,
+            pending: 0,
+            total: 0,
+            enriched: 0,
+            failed: 0,
+            skipped: 0,
+            tripped: false,
+            interrupted: false,
+            dry_run,
+        }
+    }
+
+    /// No fetch failed and the circuit breaker held.
+    pub fn is_clean(&self) -> bool {
+        self.failed == 0 && !self.tripped
+    }
+}
+
+/// A unit of enrichment work: where its queue comes from, how one item is
+/// fetched, and how the result is stored.
+pub trait EnrichTask {
+    type Id: Clone + Display + Send + 'static;
+    type Detail: Send + 'static;
+
+    fn phase(&self) -> EnrichPhase;
+
+    /// Items waiting, before any batch limit.
+    fn count_pending(&self, db: &Db) -> Result<usize, DbError>;
+
+    /// Items held back from the queue on purpose.
+    fn count_skipped(&self, _db: &Db) -> Result<usize, DbError> {
+        Ok(0)
+    }
+
+    /// Items to fetch this run, in order.
+    fn queue(&self, db: &Db, limit: Option<i64>) -> Result<Vec<Self::Id>, DbError>;
+
+    /// Fetch one item. The future owns what it needs so it can be spawned.
+    fn fetch(
+        &self,
+        scraper: &ScrapeClient,
+        id: Self::Id,
+    ) -> impl Future<Output = Result<Self::Detail, ScrapeError>> + Send + 'static;
+
+    fn store(&mut self, db: &Db, id: &Self::Id, detail: Self::Detail) -> Result<(), DbError>;
+
+    /// Runs once fetching stops. `complete` is false when any queued item
+    /// was not stored (failure, circuit breaker or cancellation).
+    fn finish(&mut self, _db: &Db, _complete: bool) -> Result<(), DbError> {
+        Ok(())
+    }
+}
+
+/// Circuit breaker that trips after N consecutive failures.
+/// Not a full circuit breaker with half-open state -- just a kill switch.
+struct CircuitBreaker {
+    consecutive_failures: usize,
+    threshold: usize,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: usize) -> Self {
+        Self {
+            consecutive_failures: 0,
+            threshold,
+        }
+    }
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+    }
+    fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+    }
+    fn is_tripped(&self) -> bool {
+        self.consecutive_failures >= self.threshold
+    }
+}
+
+struct FetchResult<I, T> {
+    id: I,
+    result: Result<T, ScrapeError>,
+}
+
+/// Run one [`EnrichTask`]: fetch its queue with bounded concurrency and
+/// store each result as it lands.
+///
+/// Fetches start in queue order behind a semaphore, paced by the scraper's
+/// limiter. Once `cancel` fires, queued fetches are dropped and in-flight
+/// ones still land. Failures are counted, not returned; only database
+/// errors end the run early.
+pub async fn run_enrichment<T: EnrichTask>(
+    task: &mut T,
+    db: &Db,
+    scraper: &ScrapeClient,
+    opts: &EnrichOptions<'_>,
+    progress: &dyn ProgressSink,
+) -> Result<EnrichSummary, DbError> {
+    let phase = task.phase();
+    let mut summary = EnrichSummary::new(phase, opts.dry_run);
+    summary.skipped = task.count_skipped(db)?;
+    summary.pending = task.count_pending(db)?;
+
+    let queue = task.queue(db, opts.batch_size)?;
+    summary.total = queue.len();
+    if opts.dry_run {
+        progress.phase_done(&summary);
+        return Ok(summary);
+    }
+    if queue.is_empty() {
+        task.finish(db, true)?;
+        progress.phase_done(&summary);
+        return Ok(summary);
+    }
+
+    progress.start(phase, summary.total);
+
+    let concurrency = opts.concurrency.max(1);
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let (tx, mut rx) = mpsc::channel::<FetchResult<T::Id, T::Detail>>(concurrency * 2);
+    let mut join_set = JoinSet::new();
+
+    for id in queue {
+        let sem = Arc::clone(&semaphore);
+        let sender = tx.clone();
+        let fetch = task.fetch(scraper, id.clone());
+        let cancel = opts.cancel.clone();
+
+        join_set.spawn(async move {
+            let _permit = sem.acquire().await.expect("semaphore closed");
+            // Queued fetches are dropped once cancelled; in-flight ones land.
+            if cancel.is_cancelled() {
+                return;
+            }
+            let result = fetch.await;
+            let _ = sender.send(FetchResult { id, result }).await;
+        });
+    }
+    // Drop original sender so rx.recv() returns None when all spawned senders are dropped
+    drop(tx);
+
+    let mut breaker = CircuitBreaker::new(opts.max_failures);
+    while let Some(fetch) = rx.recv().await {
+        match fetch.result {
+            Ok(detail) => {
+                task.store(db, &fetch.id, detail)?;
+                summary.enriched += 1;
+                breaker.record_success();
+            }
+            Err(ref err) => {
+                progress.warn(&format!(
+                    "  Warning: {} {} failed: {}",
+                    phase.unit(),
+                    fetch.id,
+                    err
+                ));
+                summary.failed += 1;
+                breaker.record_failure();
+            }
+        }
+        progress.advance(summary.enriched, summary.failed);
+
+        if breaker.is_tripped() {
+            progress.warn(&format!(
+                "Circuit breaker tripped after {} consecutive failures, stopping enrichment",
+                opts.max_failures
+            ));
+            summary.tripped = true;
+            join_set.abort_all();
+            break;
+        }
+    }
+
+    summary.interrupted = opts.cancel.is_cancelled();
+    let complete = !summary.interrupted && !summary.tripped && summary.enriched == summary.total;
+    task.finish(db, complete)?;
+
+    progress.finish(&format!(
+        "{}: {} enriched, {} failed",
+        if summary.interrupted { "interrupted" } else { "done" },
+        summary.enriched,
+        summary.failed
+    ));
+    progress.phase_done(&summary);
+    Ok(summary)
+}
+
+/// Trade detail pages: filing URLs, committees, labels and asset types.
+struct TradeDetails;
+
+impl EnrichTask for TradeDetails {
+    type Id = i64;
+    type Detail = ScrapedTradeDetail;
+
+    fn phase(&self) -> EnrichPhase {
+        EnrichPhase::Trades
+    }
+
+    fn count_pending(&self, db: &Db) -> Result<usize, DbError> {
+        Ok(db.count_unenriched_trades()? as usize)
+    }
+
+    fn queue(&self, db: &Db, limit: Option<i64>) -> Result<Vec<i64>, DbError> {
+        db.get_unenriched_trade_ids(limit)
+    }
+
+    fn fetch(
+        &self,
+        scraper: &ScrapeClient,
+        id: i64,
+    ) -> impl Future<Output = Result<ScrapedTradeDetail, ScrapeError>> + Send + 'static {
+        let scraper = scraper.clone();
+        async move { scraper.trade_detail(id).await }
+    }
+
+    fn store(&mut self, db: &Db, id: &i64, detail: ScrapedTradeDetail) -> Result<(), DbError> {
+        db.update_trade_detail(*id, &detail)
+    }
+}
+
+/// Issuer detail pages: sector, performance and end-of-day prices.
+struct IssuerDetails(IssuerQueue);
+
+impl EnrichTask for IssuerDetails {
+    type Id = i64;
+    type Detail = ScrapedIssuerDetail;
+
+    fn phase(&self) -> EnrichPhase {
+        EnrichPhase::Issuers
+    }
+
+    fn count_pending(&self, db: &Db) -> Result<usize, DbError> {
+        let all = db.count_unenriched_issuers()? as usize;
+        Ok(all.saturating_sub(self.count_skipped(db)?))
+    }
+
+    fn count_skipped(&self, db: &Db) -> Result<usize, DbError> {
+        match self.0.min_trades {
+            Some(n) => Ok(db.count_unenriched_issuers_below(n)? as usize),
+            None => Ok(0),
+        }
+    }
+
+    fn queue(&self, db: &Db, limit: Option<i64>) -> Result<Vec<i64>, DbError> {
+        db.get_unenriched_issuer_ids(self.0.priority, self.0.min_trades, limit)
+    }
+
+    fn fetch(
+        &self,
+        scraper: &ScrapeClient,
+        id: i64,
+    ) -> impl Future<Output = Result<ScrapedIssuerDetail, ScrapeError>> + Send + 'static {
+        let scraper = scraper.clone();
+        async move { scraper.issuer_detail(id).await }
+    }
+
+    fn store(&mut self, db: &Db, id: &i64, detail: ScrapedIssuerDetail) -> Result<(), DbError> {
+        db.update_issuer_detail(*id, &detail)
+    }
+}
+
+/// Committee memberships from the committee-filtered politician listings.
+///
+/// Memberships are replaced as a whole, so they are only written once every
+/// committee has been fetched; an interrupted or failed pass writes nothing.
+struct CommitteeMemberships {
+    cancel: CancellationToken,
+    memberships: Vec<(String, String)>,
+}
+
+impl EnrichTask for CommitteeMemberships {
+    type Id = &'static str;
+    type Detail = Vec<String>;
+
+    fn phase(&self) -> EnrichPhase {
+        EnrichPhase::Politicians
+    }
+
+    fn count_pending(&self, _db: &Db) -> Result<usize, DbError> {
+        Ok(COMMITTEE_MAP.len())
+    }
+
+    fn queue(&self, _db: &Db, _limit: Option<i64>) -> Result<Vec<&'static str>, DbError> {
+        Ok(COMMITTEE_MAP.iter().map(|&(code, _)| code).collect())
+    }
+
+    fn fetch(
+        &self,
+        scraper: &ScrapeClient,
+        code: &'static str,
+    ) -> impl Future<Output = Result<Vec<String>, ScrapeError>> + Send + 'static {
+        let scraper = scraper.clone();
+        let cancel = self.cancel.clone();
+        async move {
+            let mut members = Vec::new();
+            let mut page = 1;
+            loop {
+                let resp = scraper.politicians_by_committee(code, page).await?;
+                members.extend(resp.data.into_iter().map(|card| card.politician_id));
+                if page >= resp.total_pages.unwrap_or(1) {
+                    break;
+                }
+                page += 1;
+                // A cut-short listing is discarded by finish().
+                if cancel.is_cancelled() {
+                    break;
+                }
+            }
+            Ok(members)
+        }
+    }
+
+    fn store(&mut self, _db: &Db, code: &&'static str, members: Vec<String>) -> Result<(), DbError> {
+        self.memberships
+            .extend(members.into_iter().map(|id| (id, code.to_string())));
+        Ok(())
+    }
+
+    fn finish(&mut self, db: &Db, complete: bool) -> Result<(), DbError> {
+        if complete {
+            db.replace_all_politician_committees(&self.memberships)?;
+            db.mark_politicians_enriched()?;
+        }
+        Ok(())
+    }
+}
+
+/// Fill in unenriched trades from their detail pages.
+pub async fn enrich_trades(
+    db: &Db,
+    scraper: &ScrapeClient,
+    opts: &EnrichOptions<'_>,
+    progress: &dyn ProgressSink,
+) -> Result<EnrichSummary, DbError> {
+    run_enrichment(&mut TradeDetails, db, scraper, opts, progress).await
+}
+
+/// Fill in unenriched issuers from their detail pages, in `queue` order.
+pub async fn enrich_issuers(
+    db: &Db,
+    scraper: &ScrapeClient,
+    opts: &EnrichOptions<'_>,
+    queue: IssuerQueue,
+    progress: &dyn ProgressSink,
+) -> Result<EnrichSummary, DbError> {
+    run_enrichment(&mut IssuerDetails(queue), db, scraper, opts, progress).await
+}
+
+/// Rebuild committee memberships from every committee's listing and mark
+/// politicians enriched. Nothing is written unless every listing was
+/// fetched.
+pub async fn enrich_politicians(
+    db: &Db,
+    scraper: &ScrapeClient,
+    opts: &EnrichOptions<'_>,
+    progress: &dyn ProgressSink,
+) -> Result<EnrichSummary, DbError> {
+    let mut task = CommitteeMemberships {
+        cancel: opts.cancel.clone(),
+        memberships: Vec::new(),
+    };
+    run_enrichment(&mut task, db, scraper, opts, progress).await
+}
+
+/// The enrichment that follows `sync`: with `details`, trades then issuers
+/// (recomputing issuer stats afterwards), then committee memberships.
+///
+/// Stops after any phase that was cancelled. Memberships are refreshed
+/// even on a dry run and without `details`, one committee at a time, as
+/// sync has always done. Returns the summary of every phase that ran.
+pub async fn enrich_after_sync(
+    db: &Db,
+    scraper: &ScrapeClient,
+    opts: &EnrichOptions<'_>,
+    issuers: IssuerQueue,
+    details: bool,
+    progress: &dyn ProgressSink,
+) -> Result<Vec<EnrichSummary>, DbError> {
+    let mut summaries = Vec::new();
+    if details {
+        summaries.push(enrich_trades(db, scraper, opts, progress).await?);
+        if opts.cancel.is_cancelled() {
+            return Ok(summaries);
+        }
+        summaries.push(enrich_issuers(db, scraper, opts, issuers, progress).await?);
+        if !opts.dry_run {
+            db.recompute_issuer_stats()?;
+        }
+        if opts.cancel.is_cancelled() {
+            return Ok(summaries);
+        }
+    }
+
+    let committee_opts = EnrichOptions {
+        concurrency: 1,
+        dry_run: false,
+        ..*opts
+    };
+    summaries.push(enrich_politicians(db, scraper, &committee_opts, progress).await?);
+    Ok(summaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::FixtureDb;
+    use wiremock::matchers::{method, path, path_regex, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn fixture_db() -> FixtureDb {
+        FixtureDb::builder()
+            .politicians(2)
+            .issuers(3)
+            .trades_per_politician(3)
+            .build()
+            .unwrap()
+    }
+
+    fn options(cancel: &CancellationToken) -> EnrichOptions<'_> {
+        EnrichOptions {
+            concurrency: 2,
+            max_failures: 3,
+            batch_size: None,
+            dry_run: false,
+            cancel,
+        }
+    }
+
+    /// Replays the detail fixtures for any ID, and the small directory page
+    /// as every committee listing.
+    async fn replay_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/trades/\d+$"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+                "../tests/fixtures/trade_detail_stock.html"
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/issuers/\d+$"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+                "../tests/fixtures/issuer_detail_with_performance.html"
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/politicians"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+                "../tests/fixtures/politicians_directory.html"
+            )))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn enrichment_state(db: &Db) -> (i64, i64, i64, i64) {
+        let memberships: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM politician_committees", [], |row| row.get(0))
+            .unwrap();
+        (
+            db.count_unenriched_trades().unwrap(),
+            db.count_unenriched_issuers().unwrap(),
+            db.count_unenriched_politicians().unwrap(),
+            memberships,
+        )
+    }
+
+    #[tokio::test]
+    async fn standalone_phases_match_composed_sync_enrichment() {
+        let server = replay_server().await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let cancel = CancellationToken::new();
+        let opts = options(&cancel);
+
+        let standalone = fixture_db();
+        let trades = enrich_trades(&standalone, &scraper, &opts, &NoProgress).await.unwrap();
+        let issuers = enrich_issuers(&standalone, &scraper, &opts, IssuerQueue::default(), &NoProgress)
+            .await
+            .unwrap();
+        standalone.recompute_issuer_stats().unwrap();
+        let politicians = enrich_politicians(&standalone, &scraper, &opts, &NoProgress)
+            .await
+            .unwrap();
+
+        assert_eq!((trades.total, trades.enriched, trades.failed), (6, 6, 0));
+        assert_eq!(issuers.enriched, issuers.total);
+        assert_eq!(politicians.enriched, COMMITTEE_MAP.len());
+        assert!(trades.is_clean() && issuers.is_clean() && politicians.is_clean());
+
+        let composed = fixture_db();
+        let summaries =
+            enrich_after_sync(&composed, &scraper, &opts, IssuerQueue::default(), true, &NoProgress)
+                .await
+                .unwrap();
+        assert_eq!(summaries, vec![trades, issuers, politicians]);
+        assert_eq!(enrichment_state(&standalone), enrichment_state(&composed));
+        assert_eq!(enrichment_state(&composed).0, 0);
+    }
+
+    #[tokio::test]
+    async fn dry_run_counts_without_fetching() {
+        let server = replay_server().await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let cancel = CancellationToken::new();
+        let opts = EnrichOptions {
+            dry_run: true,
+            batch_size: Some(4),
+            ..options(&cancel)
+        };
+        let db = fixture_db();
+
+        let trades = enrich_trades(&db, &scraper, &opts, &NoProgress).await.unwrap();
+        assert_eq!((trades.pending, trades.total, trades.enriched), (6, 4, 0));
+        let queue = IssuerQueue {
+            min_trades: Some(1_000),
+            ..Default::default()
+        };
+        let issuers = enrich_issuers(&db, &scraper, &opts, queue, &NoProgress).await.unwrap();
+        assert_eq!((issuers.pending, issuers.total), (0, 0));
+        assert_eq!(issuers.skipped, db.count_unenriched_issuers().unwrap() as usize);
+
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_committee_leaves_memberships_unchanged() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/politicians"))
+            .and(query_param("committee", "hsag"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/politicians"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(include_str!(
+                "../tests/fixtures/politicians_directory.html"
+            )))
+            .mount(&server)
+            .await;
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let cancel = CancellationToken::new();
+        let db = fixture_db();
+        let before = enrichment_state(&db);
+
+        let summary = enrich_politicians(&db, &scraper, &options(&cancel), &NoProgress)
+            .await
+            .unwrap();
+        assert_eq!(summary.failed, 1);
+        assert!(!summary.is_clean());
+        assert_eq!(enrichment_state(&db), before);
+    }
+
+    /// Serves a trade detail page and cancels the run, as a Ctrl-C during
+    /// the request would.
+    struct CancelOnRequest(CancellationToken);
+
+    impl wiremock::Respond for CancelOnRequest {
+        fn respond(&self, _request: &wiremock::Request) -> ResponseTemplate {
+            self.0.cancel();
+            ResponseTemplate::new(200)
+                .set_body_string(include_str!("../tests/fixtures/trade_detail_stock.html"))
+        }
+    }
+
+    #[tokio::test]
+    async fn cancelled_enrichment_writes_in_flight_detail_and_stops() {
+        let server = MockServer::start().await;
+        let cancel = CancellationToken::new();
+        Mock::given(method("GET"))
+            .respond_with(CancelOnRequest(cancel.clone()))
+            .mount(&server)
+            .await;
+
+        let db = FixtureDb::builder()
+            .politicians(1)
+            .trades_per_politician(4)
+            .build()
+            .unwrap();
+        let queued = db.get_unenriched_trade_ids(None).unwrap();
+        assert_eq!(queued.len(), 4);
+
+        let scraper = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let opts = EnrichOptions {
+            concurrency: 1,
+            ..options(&cancel)
+        };
+        let result = enrich_trades(&db, &scraper, &opts, &NoProgress).await.unwrap();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+        assert_eq!((result.enriched, result.failed, result.total), (1, 0, 4));
+        assert!(result.interrupted);
+        assert_eq!(db.get_unenriched_trade_ids(None).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn circuit_breaker_new_not_tripped() {
+        let cb = CircuitBreaker::new(3);
+        assert!(!cb.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_trips_at_threshold() {
+        let mut cb = CircuitBreaker::new(3);
+        cb.record_failure();
+        cb.record_failure();
+        assert!(!cb.is_tripped());
+        cb.record_failure();
+        assert!(cb.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_success_resets_count() {
+        let mut cb = CircuitBreaker::new(3);
+        cb.record_failure();
+        cb.record_failure();
+        cb.record_success();
+        cb.record_failure();
+        cb.record_failure();
+        assert!(!cb.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_stays_tripped() {
+        let mut cb = CircuitBreaker::new(2);
+        cb.record_failure();
+        cb.record_failure();
+        assert!(cb.is_tripped());
+        // Recording more failures keeps it tripped
+        cb.record_failure();
+        assert!(cb.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_threshold_one() {
+        let mut cb = CircuitBreaker::new(1);
+        assert!(!cb.is_tripped());
+        cb.record_failure();
+        assert!(cb.is_tripped());
+    }
+
+    #[test]
+    fn circuit_breaker_alternating_success_failure() {
+        let mut cb = CircuitBreaker::new(3);
+        for _ in 0..10 {
+            cb.record_failure();
+            cb.record_success();
+        }
+        assert!(!cb.is_tripped());
+    }
+}
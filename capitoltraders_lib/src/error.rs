@@ -2,8 +2,19 @@
 
 use std::fmt;
 
+use crate::db::DbError;
+use crate::openfec::OpenFecError;
+use crate::scrape::ScrapeError;
+use crate::tiingo::TiingoError;
+use crate::yahoo::YahooError;
+
 /// Errors produced by the library layer, wrapping upstream API errors
 /// and adding cache, serialization, and input validation failures.
+///
+/// Each module keeps its own error type; the wrapping variants below let
+/// callers that mix modules propagate all of them with `?` into this one
+/// type. The variant records which source failed, and
+/// [`std::error::Error::source`] returns the wrapped error.
 #[derive(Debug)]
 pub enum CapitolTradesError {
     /// An error from the underlying API client.
@@ -14,6 +25,16 @@ pub enum CapitolTradesError {
     Serialization(serde_json::Error),
     /// User-provided input failed validation.
     InvalidInput(String),
+    /// Scraping capitoltrades.com failed.
+    Scrape(ScrapeError),
+    /// A local SQLite database operation failed.
+    Db(DbError),
+    /// An OpenFEC API request failed.
+    OpenFec(OpenFecError),
+    /// A Yahoo Finance price lookup failed.
+    Yahoo(YahooError),
+    /// A Tiingo price lookup failed.
+    Tiingo(TiingoError),
 }
 
 impl fmt::Display for CapitolTradesError {
@@ -23,6 +44,11 @@ impl fmt::Display for CapitolTradesError {
             Self::Cache(msg) => write!(f, "Cache error: {}", msg),
             Self::Serialization(e) => write!(f, "Serialization error: {}", e),
             Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            Self::Scrape(e) => write!(f, "Scrape error: {}", e),
+            Self::Db(e) => write!(f, "Database error: {}", e),
+            Self::OpenFec(e) => write!(f, "OpenFEC error: {}", e),
+            Self::Yahoo(e) => write!(f, "Yahoo Finance error: {}", e),
+            Self::Tiingo(e) => write!(f, "Tiingo error: {}", e),
         }
     }
 }
@@ -32,7 +58,12 @@ impl std::error::Error for CapitolTradesError {
         match self {
             Self::Api(e) => Some(e),
             Self::Serialization(e) => Some(e),
-            _ => None,
+            Self::Scrape(e) => Some(e),
+            Self::Db(e) => Some(e),
+            Self::OpenFec(e) => Some(e),
+            Self::Yahoo(e) => Some(e),
+            Self::Tiingo(e) => Some(e),
+            Self::Cache(_) | Self::InvalidInput(_) => None,
         }
     }
 }
@@ -48,3 +79,66 @@ impl From<serde_json::Error> for CapitolTradesError {
         Self::Serialization(e)
     }
 }
+
+impl From<ScrapeError> for CapitolTradesError {
+    fn from(e: ScrapeError) -> Self {
+        Self::Scrape(e)
+    }
+}
+
+impl From<DbError> for CapitolTradesError {
+    fn from(e: DbError) -> Self {
+        Self::Db(e)
+    }
+}
+
+impl From<OpenFecError> for CapitolTradesError {
+    fn from(e: OpenFecError) -> Self {
+        Self::OpenFec(e)
+    }
+}
+
+impl From<YahooError> for CapitolTradesError {
+    fn from(e: YahooError) -> Self {
+        Self::Yahoo(e)
+    }
+}
+
+impl From<TiingoError> for CapitolTradesError {
+    fn from(e: TiingoError) -> Self {
+        Self::Tiingo(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    fn open_missing_db() -> Result<crate::db::Db, CapitolTradesError> {
+        let db = crate::db::Db::open_read_only("/nonexistent/capitoltraders/missing.db")?;
+        Ok(db)
+    }
+
+    #[test]
+    fn test_question_mark_converts_module_errors() {
+        let err = open_missing_db().err().expect("missing database fails to open");
+        assert!(matches!(err, CapitolTradesError::Db(DbError::Sqlite(_))));
+        assert!(err.to_string().starts_with("Database error: sqlite error:"));
+    }
+
+    #[test]
+    fn test_source_chain_reaches_root_cause() {
+        let sqlite = rusqlite::Error::InvalidQuery;
+        let err = CapitolTradesError::from(DbError::from(sqlite));
+
+        let db_err = err.source().expect("wrapped DbError");
+        assert!(db_err.to_string().starts_with("sqlite error:"));
+        let root = db_err.source().expect("rusqlite error under DbError");
+        assert!(root.downcast_ref::<rusqlite::Error>().is_some());
+
+        let fec = CapitolTradesError::from(OpenFecError::RateLimited);
+        assert!(fec.to_string().starts_with("OpenFEC error:"));
+        assert!(fec.source().unwrap().downcast_ref::<OpenFecError>().is_some());
+    }
+}
@@ -0,0 +1,241 @@
+//! User-supplied calendar of legislative events (hearings, markups, votes).
+//!
+//! Calendars are CSV with a header row:
+//!
+//! ```text
+//! date,title,tags,ticker,sector
+//! 2024-03-12,Senate Banking hearing on bank capital,banking;hearing,,financials
+//! 2024-04-02,"Armed Services markup, FY25 NDAA",defense;markup,LMT,
+//! ```
+//!
+//! `tags` is a `;`-separated list and may be empty. Each event needs a
+//! `ticker`, a `sector`, or both; a sector-only event applies to every
+//! issuer in that sector. Sectors use the CapitolTrades slugs stored on
+//! issuers (`information-technology`, `health-care`, ...); spaces and
+//! underscores are accepted and normalized to hyphens.
+//!
+//! Committee hearing schedules use a separate, committee-aware format
+//! (`date,committee,title`); see [`parse_hearings`].
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::validation;
+
+/// Error types for event calendar files.
+#[derive(Error, Debug)]
+pub enum EventsError {
+    #[error("Failed to read events file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse events CSV: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Invalid event at line {line}: {message}")]
+    InvalidEvent { line: u64, message: String },
+}
+
+/// A dated event from the calendar.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Event {
+    pub date: NaiveDate,
+    pub title: String,
+    pub tags: Vec<String>,
+    /// Uppercased ticker the event concerns, if any.
+    pub ticker: Option<String>,
+    /// Normalized sector slug the event concerns, if any.
+    pub sector: Option<String>,
+}
+
+/// A scheduled hearing of one committee.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommitteeHearing {
+    pub date: NaiveDate,
+    /// CapitolTrades committee code (e.g. `hsba`).
+    pub committee: String,
+    pub title: String,
+}
+
+#[derive(Deserialize)]
+struct RawEvent {
+    date: String,
+    title: String,
+    #[serde(default)]
+    tags: Option<String>,
+    #[serde(default)]
+    ticker: Option<String>,
+    #[serde(default)]
+    sector: Option<String>,
+}
+
+/// Parse calendar CSV content. Rows are returned sorted by date.
+pub fn parse_events(content: &str) -> Result<Vec<Event>, EventsError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes());
+
+    let headers = reader.headers()?.clone();
+    let mut events = Vec::new();
+    for result in reader.records() {
+        let record = result?;
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+        let raw: RawEvent = record.deserialize(Some(&headers))?;
+        let invalid = |message: String| EventsError::InvalidEvent { line, message };
+
+        let date = NaiveDate::parse_from_str(&raw.date, "%Y-%m-%d")
+            .map_err(|_| invalid(format!("date '{}' must be YYYY-MM-DD", raw.date)))?;
+        if raw.title.is_empty() {
+            return Err(invalid("title is empty".to_string()));
+        }
+        let ticker = non_empty(raw.ticker).map(|t| t.to_uppercase());
+        let sector = non_empty(raw.sector).map(|s| normalize_sector(&s));
+        if ticker.is_none() && sector.is_none() {
+            return Err(invalid(format!(
+                "event '{}' needs a ticker or a sector",
+                raw.title
+            )));
+        }
+        let tags = raw
+            .tags
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        events.push(Event {
+            date,
+            title: raw.title,
+            tags,
+            ticker,
+            sector,
+        });
+    }
+
+    events.sort_by_key(|e| e.date);
+    Ok(events)
+}
+
+#[derive(Deserialize)]
+struct RawHearing {
+    date: String,
+    committee: String,
+    #[serde(default)]
+    title: Option<String>,
+}
+
+/// Parse a hearing schedule (`date,committee,title`). The committee may be a
+/// code (`ssfi`) or full name (`Senate - Finance`); it is stored as the code.
+/// Rows are returned sorted by date.
+pub fn parse_hearings(content: &str) -> Result<Vec<CommitteeHearing>, EventsError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .comment(Some(b'#'))
+        .from_reader(content.as_bytes());
+
+    let headers = reader.headers()?.clone();
+    let mut hearings = Vec::new();
+    for result in reader.records() {
+        let record = result?;
+        let line = record.position().map(|p| p.line()).unwrap_or(0);
+        let raw: RawHearing = record.deserialize(Some(&headers))?;
+        let invalid = |message: String| EventsError::InvalidEvent { line, message };
+
+        let date = NaiveDate::parse_from_str(&raw.date, "%Y-%m-%d")
+            .map_err(|_| invalid(format!("date '{}' must be YYYY-MM-DD", raw.date)))?;
+        let committee =
+            validation::validate_committee(&raw.committee).map_err(|e| invalid(e.to_string()))?;
+
+        hearings.push(CommitteeHearing {
+            date,
+            committee,
+            title: non_empty(raw.title).unwrap_or_default(),
+        });
+    }
+
+    hearings.sort_by_key(|h| h.date);
+    Ok(hearings)
+}
+
+/// Load and validate a hearing schedule file.
+pub fn load_hearings(path: impl AsRef<Path>) -> Result<Vec<CommitteeHearing>, EventsError> {
+    let content = std::fs::read_to_string(path)?;
+    parse_hearings(&content)
+}
+
+/// Load and validate a calendar file.
+pub fn load_events(path: impl AsRef<Path>) -> Result<Vec<Event>, EventsError> {
+    let content = std::fs::read_to_string(path)?;
+    parse_events(&content)
+}
+
+/// Normalize a sector name to the hyphenated lowercase slug form.
+pub fn normalize_sector(sector: &str) -> String {
+    sector
+        .trim()
+        .to_lowercase()
+        .replace([' ', '_'], "-")
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_titles_tags_and_sector_slugs() {
+        let csv = "date,title,tags,ticker,sector\n\
+                   2024-04-02,\"Armed Services markup, FY25 NDAA\",defense; markup,lmt,\n\
+                   2024-03-12,Banking hearing,,,Health Care\n";
+        let events = parse_events(csv).unwrap();
+        assert_eq!(events.len(), 2);
+        // Sorted by date
+        assert_eq!(events[0].title, "Banking hearing");
+        assert_eq!(events[0].sector.as_deref(), Some("health-care"));
+        assert!(events[0].tags.is_empty());
+        assert_eq!(events[1].title, "Armed Services markup, FY25 NDAA");
+        assert_eq!(events[1].ticker.as_deref(), Some("LMT"));
+        assert_eq!(events[1].tags, vec!["defense", "markup"]);
+    }
+
+    #[test]
+    fn rejects_bad_date_and_untargeted_event() {
+        let bad_date = "date,title,tags,ticker,sector\n03/12/2024,Hearing,,AAPL,\n";
+        match parse_events(bad_date) {
+            Err(EventsError::InvalidEvent { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected InvalidEvent, got {:?}", other),
+        }
+
+        let untargeted = "date,title,tags,ticker,sector\n2024-03-12,Hearing,oversight,,\n";
+        assert!(matches!(
+            parse_events(untargeted),
+            Err(EventsError::InvalidEvent { .. })
+        ));
+    }
+
+    #[test]
+    fn parses_hearings_with_codes_and_full_names() {
+        let csv = "date,committee,title\n\
+                   2024-05-01,Senate - Finance,Tax extenders\n\
+                   2024-04-10,HSBA,\"Oversight of regulators, part 2\"\n";
+        let hearings = parse_hearings(csv).unwrap();
+        assert_eq!(hearings.len(), 2);
+        assert_eq!(hearings[0].committee, "hsba");
+        assert_eq!(hearings[0].title, "Oversight of regulators, part 2");
+        assert_eq!(hearings[1].committee, "ssfi");
+
+        let unknown = "date,committee,title\n2024-05-01,Not A Committee,x\n";
+        assert!(matches!(
+            parse_hearings(unknown),
+            Err(EventsError::InvalidEvent { line: 2, .. })
+        ));
+    }
+}
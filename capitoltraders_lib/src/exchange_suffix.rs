@@ -0,0 +1,137 @@
+//! Yahoo Finance exchange suffixes for non-US issuers.
+//!
+//! A foreign issuer's stored ticker is often the bare local symbol (`SHEL`,
+//! `SAP`), which Yahoo only prices with an exchange suffix (`SHEL.L`,
+//! `SAP.DE`). [`ExchangeSuffixes`] maps an issuer's country to the suffixes
+//! worth trying, in order; [`crate::pricing::resolve_yahoo_candidates`]
+//! turns them into candidate tickers.
+//!
+//! The bundled table (`seed_data/exchange_suffixes.yml`) follows the same
+//! compile-time `include_str!` pattern as `ticker_alias.rs`. A user file
+//! named by `exchange_suffixes_file` in the config file is layered on top
+//! with [`ExchangeSuffixes::extend`].
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use thiserror::Error;
+
+/// Error types for exchange suffix files.
+#[derive(Error, Debug)]
+pub enum ExchangeSuffixError {
+    #[error("Failed to parse exchange suffix YAML: {0}")]
+    YamlParse(#[from] serde_yml::Error),
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("invalid suffix '{suffix}' for country '{country}'")]
+    InvalidSuffix { country: String, suffix: String },
+}
+
+#[derive(Deserialize)]
+struct ExchangeSuffixFile {
+    countries: BTreeMap<String, Vec<String>>,
+}
+
+/// Exchange suffixes to try per issuer country, keyed by lowercase ISO
+/// 3166-1 alpha-2 code.
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeSuffixes {
+    by_country: HashMap<String, Vec<String>>,
+}
+
+impl ExchangeSuffixes {
+    /// Parse suffix YAML content. Suffixes are stored upper-case without the
+    /// leading dot, so `.t` and `T` are the same entry.
+    pub fn parse(yaml_content: &str) -> Result<Self, ExchangeSuffixError> {
+        let file: ExchangeSuffixFile = serde_yml::from_str(yaml_content)?;
+        let mut by_country = HashMap::new();
+        for (country, suffixes) in file.countries {
+            let country = country.trim().to_lowercase();
+            let suffixes = suffixes
+                .iter()
+                .map(|s| {
+                    let suffix = s.trim().trim_start_matches('.').to_uppercase();
+                    if suffix.is_empty() || !suffix.chars().all(|c| c.is_ascii_alphanumeric()) {
+                        return Err(ExchangeSuffixError::InvalidSuffix {
+                            country: country.clone(),
+                            suffix: s.clone(),
+                        });
+                    }
+                    Ok(suffix)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            by_country.insert(country, suffixes);
+        }
+        Ok(Self { by_country })
+    }
+
+    /// Load a user-supplied suffix file on its own.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ExchangeSuffixError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|source| ExchangeSuffixError::Io {
+            path: path.display().to_string(),
+            source,
+        })?;
+        Self::parse(&content)
+    }
+
+    /// Load the table embedded at compile time.
+    pub fn bundled() -> Result<Self, ExchangeSuffixError> {
+        Self::parse(include_str!("../../seed_data/exchange_suffixes.yml"))
+    }
+
+    /// The bundled table, extended with `user_file` when one is given.
+    pub fn load(user_file: Option<&Path>) -> Result<Self, ExchangeSuffixError> {
+        let mut suffixes = Self::bundled()?;
+        if let Some(path) = user_file {
+            suffixes.extend(Self::from_file(path)?);
+        }
+        Ok(suffixes)
+    }
+
+    /// Layer `other` on top: each country it lists takes its suffixes.
+    pub fn extend(&mut self, other: ExchangeSuffixes) {
+        self.by_country.extend(other.by_country);
+    }
+
+    /// Suffixes to try for `country`, in order; empty for US issuers and
+    /// countries not in the table.
+    pub fn for_country(&self, country: &str) -> &[String] {
+        self.by_country
+            .get(&country.trim().to_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_table_parses() {
+        let suffixes = ExchangeSuffixes::bundled().unwrap();
+        assert_eq!(suffixes.for_country("JP"), ["T"]);
+        assert_eq!(suffixes.for_country("de"), ["DE", "F"]);
+        assert!(suffixes.for_country("us").is_empty());
+        assert!(suffixes.for_country("").is_empty());
+    }
+
+    #[test]
+    fn test_user_file_replaces_country_list() {
+        let mut suffixes = ExchangeSuffixes::bundled().unwrap();
+        suffixes.extend(ExchangeSuffixes::parse("countries:\n  de: [.f]\n  lu: [LU]\n").unwrap());
+        assert_eq!(suffixes.for_country("de"), ["F"]);
+        assert_eq!(suffixes.for_country("lu"), ["LU"]);
+        assert_eq!(suffixes.for_country("jp"), ["T"]);
+    }
+
+    #[test]
+    fn test_invalid_suffix_rejected() {
+        let err = ExchangeSuffixes::parse("countries:\n  jp: [\"T O\"]\n").unwrap_err();
+        assert!(matches!(err, ExchangeSuffixError::InvalidSuffix { .. }));
+    }
+}
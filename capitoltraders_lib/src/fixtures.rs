@@ -0,0 +1,735 @@
+//! Synthetic, seeded test data for code built on this crate.
+//!
+//! Enabled by the `testkit` feature (and always in this crate's own tests).
+//! [`FixtureDb::builder`] creates an in-memory [`Db`] holding a small,
+//! coherent data set: politicians, issuers, trades, and optionally price
+//! enrichment and FEC donations. The same seed always yields the same rows,
+//! so assertions on generated data are stable. Generation uses ChaCha8,
+//! whose output, unlike `StdRng`'s, is fixed across `rand` releases.
+//!
+//! ```rust,ignore
+//! use capitoltraders_lib::fixtures::{assert_table_count, FixtureDb};
+//!
+//! let fx = FixtureDb::builder()
+//!     .politicians(3)
+//!     .trades_per_politician(20)
+//!     .with_price_enrichment()
+//!     .build()?;
+//! assert_table_count(&fx, "trades", 60);
+//! let rows = fx.query_trades_for_analytics()?;
+//! ```
+//!
+//! The lower-level helpers ([`open_db`], [`scraped_trade`],
+//! [`insert_politician`], ...) insert single rows with fixed values for
+//! tests that need exact control.
+
+use std::ops::Deref;
+
+use chrono::{Duration, NaiveDate};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rusqlite::params;
+
+use crate::db::{Db, DbError};
+use crate::openfec::types::{Committee, Contribution};
+use crate::scrape::{ScrapedIssuer, ScrapedPolitician, ScrapedTrade};
+
+const FIRST_NAMES: &[&str] = &[
+    "Alex", "Jordan", "Morgan", "Casey", "Taylor", "Riley", "Avery", "Quinn", "Jamie", "Drew",
+];
+const LAST_NAMES: &[&str] = &[
+    "Harper", "Ellison", "Whitaker", "Navarro", "Brooks", "Okafor", "Lindqvist", "Castillo",
+    "Pryor", "Delgado",
+];
+const STATES: &[&str] = &["CA", "TX", "NY", "FL", "OH", "PA", "GA", "WA", "AZ", "MI"];
+const ISSUERS: &[(&str, &str, &str)] = &[
+    ("Apple Inc", "AAPL:US", "information-technology"),
+    ("Microsoft Corp", "MSFT:US", "information-technology"),
+    ("JPMorgan Chase & Co", "JPM:US", "financials"),
+    ("Exxon Mobil Corp", "XOM:US", "energy"),
+    ("Lockheed Martin Corp", "LMT:US", "industrials"),
+    ("Pfizer Inc", "PFE:US", "health-care"),
+    ("Coca-Cola Co", "KO:US", "consumer-staples"),
+    ("Walmart Inc", "WMT:US", "consumer-staples"),
+    ("NVIDIA Corp", "NVDA:US", "information-technology"),
+    ("Boeing Co", "BA:US", "industrials"),
+];
+const EMPLOYERS: &[&str] = &[
+    "Apple Inc",
+    "JPMorgan Chase",
+    "Exxon Mobil",
+    "Lockheed Martin",
+    "Self-Employed",
+    "Retired",
+    "State University",
+    "Not Employed",
+];
+/// Trade values at the midpoints of the common disclosure brackets.
+const TRADE_VALUES: &[i64] = &[8_000, 32_500, 75_000, 175_000, 375_000];
+const DONATION_AMOUNTS: &[f64] = &[25.0, 100.0, 250.0, 500.0, 1_000.0, 2_900.0, 3_300.0];
+
+/// An in-memory database populated by [`FixtureDbBuilder::build`].
+///
+/// Derefs to [`Db`], so every query method is available directly.
+pub struct FixtureDb {
+    db: Db,
+    /// Generated politician IDs (`F000001`, `F000002`, ...).
+    pub politician_ids: Vec<String>,
+    /// Generated issuer IDs (`1..=issuers`).
+    pub issuer_ids: Vec<i64>,
+    /// Seed the data was generated from.
+    pub seed: u64,
+}
+
+impl FixtureDb {
+    pub fn builder() -> FixtureDbBuilder {
+        FixtureDbBuilder::default()
+    }
+
+    pub fn into_db(self) -> Db {
+        self.db
+    }
+}
+
+impl Deref for FixtureDb {
+    type Target = Db;
+
+    fn deref(&self) -> &Db {
+        &self.db
+    }
+}
+
+/// Builder for [`FixtureDb`]. Defaults: seed 42, 3 politicians, 8 issuers,
+/// 10 trades per politician, no price enrichment, no donations.
+#[derive(Debug, Clone)]
+pub struct FixtureDbBuilder {
+    seed: u64,
+    politicians: usize,
+    issuers: usize,
+    trades_per_politician: usize,
+    price_enrichment: bool,
+    donations_per_politician: usize,
+    start_date: NaiveDate,
+}
+
+impl Default for FixtureDbBuilder {
+    fn default() -> Self {
+        Self {
+            seed: 42,
+            politicians: 3,
+            issuers: 8,
+            trades_per_politician: 10,
+            price_enrichment: false,
+            donations_per_politician: 0,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 2).expect("valid date"),
+        }
+    }
+}
+
+impl FixtureDbBuilder {
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn politicians(mut self, n: usize) -> Self {
+        self.politicians = n;
+        self
+    }
+
+    /// Number of issuers trades are spread across (at least 1).
+    pub fn issuers(mut self, n: usize) -> Self {
+        self.issuers = n.max(1);
+        self
+    }
+
+    pub fn trades_per_politician(mut self, n: usize) -> Self {
+        self.trades_per_politician = n;
+        self
+    }
+
+    /// Give every trade a trade-date price and estimated share count, as
+    /// `enrich-prices` would, so FIFO analytics and portfolio queries see it.
+    pub fn with_price_enrichment(mut self) -> Self {
+        self.price_enrichment = true;
+        self
+    }
+
+    /// Give each politician one synced FEC committee holding `n` donations.
+    pub fn with_donations(mut self, n: usize) -> Self {
+        self.donations_per_politician = n;
+        self
+    }
+
+    /// Earliest transaction date; trades fall within the following year.
+    pub fn start_date(mut self, date: NaiveDate) -> Self {
+        self.start_date = date;
+        self
+    }
+
+    pub fn build(self) -> Result<FixtureDb, DbError> {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let mut db = Db::open_in_memory()?;
+        db.init()?;
+
+        let politicians: Vec<PoliticianSpec> = (0..self.politicians)
+            .map(|_| PoliticianSpec {
+                state: pick(&mut rng, STATES),
+                chamber: pick(&mut rng, &["house", "senate"]),
+                dob: format!(
+                    "19{}-0{}-1{}",
+                    rng.gen_range(45..85),
+                    rng.gen_range(1..10),
+                    rng.gen_range(0..10)
+                ),
+                first_name: pick(&mut rng, FIRST_NAMES),
+                last_name: pick(&mut rng, LAST_NAMES),
+                gender: pick(&mut rng, &["female", "male"]),
+                party: pick(&mut rng, &["Democrat", "Republican"]),
+            })
+            .collect();
+        let politician_ids: Vec<String> =
+            (1..=self.politicians).map(|i| format!("F{:06}", i)).collect();
+
+        // Past the fixed list, names and tickers get a numeric suffix.
+        let issuers: Vec<IssuerSpec> = (0..self.issuers)
+            .map(|i| {
+                let (name, ticker, sector) = ISSUERS[i % ISSUERS.len()];
+                match i / ISSUERS.len() {
+                    0 => IssuerSpec {
+                        name: name.to_string(),
+                        ticker: ticker.to_string(),
+                        sector,
+                    },
+                    round => IssuerSpec {
+                        name: format!("{} {}", name, round + 1),
+                        ticker: ticker.replace(":US", &format!("{}:US", round + 1)),
+                        sector,
+                    },
+                }
+            })
+            .collect();
+        let issuer_ids: Vec<i64> = (1..=self.issuers as i64).collect();
+        let base_prices: Vec<f64> = issuers.iter().map(|_| rng.gen_range(20.0..500.0)).collect();
+
+        let mut trades = Vec::with_capacity(self.politicians * self.trades_per_politician);
+        for (p, politician) in politicians.iter().enumerate() {
+            for _ in 0..self.trades_per_politician {
+                let tx_id = trades.len() as i64 + 1;
+                let issuer = rng.gen_range(0..issuers.len());
+                let tx_date = self.start_date + Duration::days(rng.gen_range(0..365));
+                let gap = rng.gen_range(1..=45);
+                trades.push(ScrapedTrade {
+                    tx_id,
+                    politician_id: politician_ids[p].clone(),
+                    issuer_id: issuer_ids[issuer],
+                    chamber: politician.chamber.to_string(),
+                    comment: None,
+                    issuer: issuers[issuer].scraped(),
+                    owner: pick(&mut rng, &["self", "self", "spouse", "joint"]).to_string(),
+                    politician: politician.scraped(),
+                    price: None,
+                    pub_date: format!(
+                        "{}T00:00:00Z",
+                        (tx_date + Duration::days(gap)).format("%Y-%m-%d")
+                    ),
+                    reporting_gap: gap,
+                    tx_date: tx_date.format("%Y-%m-%d").to_string(),
+                    tx_type: if rng.gen_bool(0.6) { "buy" } else { "sell" }.to_string(),
+                    tx_type_extended: None,
+                    value: pick(&mut rng, TRADE_VALUES),
+                    filing_url: Some(format!("https://example.com/filings/{}", 1000 + tx_id / 4)),
+                    filing_id: Some(1000 + tx_id / 4),
+                });
+            }
+        }
+        // Chronological upsert order keeps FIFO queries ordered like a real sync.
+        trades.sort_by(|a, b| a.tx_date.cmp(&b.tx_date).then(a.tx_id.cmp(&b.tx_id)));
+        db.upsert_scraped_trades(&trades)?;
+
+        if self.price_enrichment {
+            for trade in &trades {
+                let base = base_prices[(trade.issuer_id - 1) as usize];
+                let price = (base * rng.gen_range(0.8..1.25) * 100.0).round() / 100.0;
+                let shares = trade.value as f64 / price;
+                db.update_trade_prices(
+                    trade.tx_id,
+                    Some(price),
+                    Some(shares),
+                    Some(trade.value as f64),
+                    Some("fixture"),
+                )?;
+            }
+        }
+
+        if self.donations_per_politician > 0 {
+            for (p, politician_id) in politician_ids.iter().enumerate() {
+                let committee_id = format!("C{:08}", p + 1);
+                db.upsert_committee(&Committee {
+                    committee_id: committee_id.clone(),
+                    name: format!("{} for Congress", politicians[p].last_name),
+                    committee_type: Some(
+                        if politicians[p].chamber == "senate" { "S" } else { "H" }.to_string(),
+                    ),
+                    designation: Some("P".to_string()),
+                    party: None,
+                    state: Some(politicians[p].state.to_string()),
+                    cycles: vec![2024],
+                })?;
+                let contributions: Vec<Contribution> = (0..self.donations_per_politician)
+                    .map(|d| {
+                        let date = self.start_date - Duration::days(rng.gen_range(0..540));
+                        Contribution {
+                            sub_id: Some(format!("FX{}-{:05}", p + 1, d + 1)),
+                            committee: None,
+                            contributor_name: Some(format!(
+                                "{}, {}",
+                                pick(&mut rng, LAST_NAMES).to_uppercase(),
+                                pick(&mut rng, FIRST_NAMES).to_uppercase()
+                            )),
+                            contributor_state: Some(pick(&mut rng, STATES).to_string()),
+                            contributor_city: None,
+                            contributor_zip: Some(format!("{:05}", rng.gen_range(10000..99999))),
+                            contributor_employer: Some(pick(&mut rng, EMPLOYERS).to_string()),
+                            contributor_occupation: None,
+                            contribution_receipt_date: Some(date.format("%Y-%m-%d").to_string()),
+                            contribution_receipt_amount: Some(pick(&mut rng, DONATION_AMOUNTS)),
+                            memo_text: None,
+                            receipt_type: None,
+                            two_year_transaction_period: None,
+                        }
+                    })
+                    .collect();
+                db.save_sync_cursor_with_donations(
+                    politician_id,
+                    &committee_id,
+                    &contributions,
+                    Some(2024),
+                    contributions.len() as i64,
+                    "2024-01-01",
+                )?;
+                db.mark_sync_completed(politician_id, &committee_id, Some(2024))?;
+            }
+        }
+
+        Ok(FixtureDb {
+            db,
+            politician_ids,
+            issuer_ids,
+            seed: self.seed,
+        })
+    }
+}
+
+struct PoliticianSpec {
+    state: &'static str,
+    chamber: &'static str,
+    dob: String,
+    first_name: &'static str,
+    last_name: &'static str,
+    gender: &'static str,
+    party: &'static str,
+}
+
+impl PoliticianSpec {
+    fn scraped(&self) -> ScrapedPolitician {
+        ScrapedPolitician {
+            state_id: self.state.to_string(),
+            chamber: self.chamber.to_string(),
+            dob: self.dob.clone(),
+            first_name: self.first_name.to_string(),
+            gender: self.gender.to_string(),
+            last_name: self.last_name.to_string(),
+            nickname: None,
+            party: self.party.to_string(),
+        }
+    }
+}
+
+struct IssuerSpec {
+    name: String,
+    ticker: String,
+    sector: &'static str,
+}
+
+impl IssuerSpec {
+    fn scraped(&self) -> ScrapedIssuer {
+        ScrapedIssuer {
+            state_id: None,
+            c2iq: None,
+            country: Some("us".to_string()),
+            issuer_name: self.name.clone(),
+            issuer_ticker: Some(self.ticker.clone()),
+            sector: Some(self.sector.to_string()),
+        }
+    }
+}
+
+fn pick<T: Copy>(rng: &mut ChaCha8Rng, items: &[T]) -> T {
+    items[rng.gen_range(0..items.len())]
+}
+
+/// Fresh in-memory database with the schema applied.
+pub fn open_db() -> Db {
+    let db = Db::open_in_memory().expect("open in-memory db");
+    db.init().expect("init schema");
+    db
+}
+
+/// A minimal scraped trade: Jane Doe (Democrat, CA senate) buying $50,000
+/// of `TestCorp{issuer_id}` (ticker `TST`) on 2025-06-10, published
+/// 2025-06-15. Adjust fields before upserting.
+pub fn scraped_trade(tx_id: i64, politician_id: &str, issuer_id: i64) -> ScrapedTrade {
+    ScrapedTrade {
+        tx_id,
+        politician_id: politician_id.to_string(),
+        issuer_id,
+        chamber: "senate".to_string(),
+        comment: None,
+        issuer: ScrapedIssuer {
+            state_id: None,
+            c2iq: None,
+            country: None,
+            issuer_name: format!("TestCorp{}", issuer_id),
+            issuer_ticker: Some("TST".to_string()),
+            sector: None,
+        },
+        owner: "self".to_string(),
+        politician: ScrapedPolitician {
+            state_id: "CA".to_string(),
+            chamber: "senate".to_string(),
+            dob: "1970-01-01".to_string(),
+            first_name: "Jane".to_string(),
+            gender: "female".to_string(),
+            last_name: "Doe".to_string(),
+            nickname: None,
+            party: "Democrat".to_string(),
+        },
+        price: None,
+        pub_date: "2025-06-15T00:00:00Z".to_string(),
+        reporting_gap: 5,
+        tx_date: "2025-06-10".to_string(),
+        tx_type: "buy".to_string(),
+        tx_type_extended: None,
+        value: 50000,
+        filing_url: None,
+        filing_id: None,
+    }
+}
+
+/// A trades listing page as the site serves it: the RSC payload holding
+/// `(tx_id, politician_id, pub_date)` trades (otherwise as in
+/// [`scraped_trade`]) and the page metadata, wrapped in a
+/// `self.__next_f.push` script.
+pub fn trades_listing_html(
+    trades: &[(i64, &str, &str)],
+    total_pages: i64,
+    total_count: i64,
+) -> String {
+    let data: Vec<serde_json::Value> = trades
+        .iter()
+        .map(|&(tx_id, politician_id, pub_date)| {
+            serde_json::json!({
+                "_txId": tx_id,
+                "_politicianId": politician_id,
+                "_issuerId": 1,
+                "chamber": "senate",
+                "comment": null,
+                "issuer": {
+                    "_stateId": null,
+                    "c2iq": null,
+                    "country": null,
+                    "issuerName": "TestCorp1",
+                    "issuerTicker": "TST",
+                    "sector": null
+                },
+                "owner": "self",
+                "politician": {
+                    "_stateId": "CA",
+                    "chamber": "senate",
+                    "dob": "1970-01-01",
+                    "firstName": "Jane",
+                    "gender": "female",
+                    "lastName": "Doe",
+                    "nickname": null,
+                    "party": "Democrat"
+                },
+                "price": null,
+                "pubDate": format!("{}T00:00:00Z", pub_date),
+                "reportingGap": 5,
+                "txDate": pub_date,
+                "txType": "buy",
+                "txTypeExtended": null,
+                "value": 50000
+            })
+        })
+        .collect();
+    let payload = serde_json::json!({
+        "data": data,
+        "totalCount": total_count,
+        "totalPages": total_pages
+    })
+    .to_string();
+    let escaped = serde_json::to_string(&payload).expect("encode payload");
+    format!(
+        "<html><body><script>self.__next_f.push([1,{}])</script></body></html>",
+        escaped
+    )
+}
+
+/// Insert a CA Democrat senator named `{first_name} Test`.
+pub fn insert_politician(db: &Db, id: &str, first_name: &str) {
+    insert_politician_full(db, id, first_name, "Test", "Democrat", "CA", "senate");
+}
+
+/// Insert a politician row (dob 1970-01-01, gender female).
+pub fn insert_politician_full(
+    db: &Db,
+    id: &str,
+    first_name: &str,
+    last_name: &str,
+    party: &str,
+    state: &str,
+    chamber: &str,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO politicians (politician_id, state_id, party, first_name, last_name, dob, gender, chamber)
+             VALUES (?1, ?2, ?3, ?4, ?5, '1970-01-01', 'female', ?6)",
+            params![id, state, party, first_name, last_name, chamber],
+        )
+        .expect("insert test politician");
+}
+
+pub fn insert_politician_stats(
+    db: &Db,
+    id: &str,
+    trades: i64,
+    issuers: i64,
+    volume: i64,
+    last_traded: Option<&str>,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO politician_stats (politician_id, count_trades, count_issuers, volume, date_last_traded)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![id, trades, issuers, volume, last_traded],
+        )
+        .expect("insert test politician stats");
+}
+
+pub fn insert_issuer(
+    db: &Db,
+    issuer_id: i64,
+    name: &str,
+    ticker: Option<&str>,
+    sector: Option<&str>,
+    state: Option<&str>,
+    country: Option<&str>,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO issuers (issuer_id, issuer_name, issuer_ticker, sector, state_id, country)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![issuer_id, name, ticker, sector, state, country],
+        )
+        .expect("insert test issuer");
+}
+
+pub fn insert_issuer_stats(
+    db: &Db,
+    issuer_id: i64,
+    trades: i64,
+    politicians: i64,
+    volume: i64,
+    last_traded: &str,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO issuer_stats (issuer_id, count_trades, count_politicians, volume, date_last_traded)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![issuer_id, trades, politicians, volume, last_traded],
+        )
+        .expect("insert test issuer stats");
+}
+
+/// Insert an asset row unless `asset_id` already exists.
+pub fn insert_asset(db: &Db, asset_id: i64, asset_type: &str) {
+    db.conn()
+        .execute(
+            "INSERT OR IGNORE INTO assets (asset_id, asset_type) VALUES (?1, ?2)",
+            params![asset_id, asset_type],
+        )
+        .expect("insert test asset");
+}
+
+/// Insert a bare trade row on asset 1 (see [`insert_asset`]): $8,000 in the
+/// 1,001-15,000 bracket, owned by self, filed 2024-01-19 and published
+/// 2024-01-20. Unlike [`scraped_trade`], it leaves the issuer row alone.
+pub fn insert_trade(
+    db: &Db,
+    tx_id: i64,
+    politician_id: &str,
+    issuer_id: i64,
+    tx_date: &str,
+    tx_type: &str,
+    chamber: &str,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO trades (tx_id, politician_id, asset_id, issuer_id, pub_date,
+                filing_date, tx_date, tx_type, has_capital_gains, owner, chamber,
+                size_range_low, size_range_high, value, filing_id, filing_url, reporting_gap)
+             VALUES (?1, ?2, 1, ?3, '2024-01-20',
+                '2024-01-19', ?4, ?5, 0, 'self', ?6,
+                1001, 15000, 8000, 1, 'https://example.com', 5)",
+            params![tx_id, politician_id, issuer_id, tx_date, tx_type, chamber],
+        )
+        .expect("insert test trade");
+}
+
+/// Insert an issuer's performance row with the given market cap and
+/// 30/90/365-day changes; every other field is 1.0 (prices) or 0.0 (changes).
+pub fn insert_issuer_performance(
+    db: &Db,
+    issuer_id: i64,
+    mcap: i64,
+    trailing30_change: f64,
+    trailing90_change: f64,
+    trailing365_change: f64,
+) {
+    db.conn()
+        .execute(
+            "INSERT INTO issuer_performance (issuer_id, mcap, trailing1, trailing1_change, trailing7, trailing7_change, trailing30, trailing30_change, trailing90, trailing90_change, trailing365, trailing365_change, wtd, wtd_change, mtd, mtd_change, qtd, qtd_change, ytd, ytd_change)
+             VALUES (?1, ?2, 1.0, 0.0, 1.0, 0.0, 1.0, ?3, 1.0, ?4, 1.0, ?5, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0)",
+            params![issuer_id, mcap, trailing30_change, trailing90_change, trailing365_change],
+        )
+        .expect("insert test issuer performance");
+}
+
+/// A contribution from Donor (Acme, CA) received 2024-06-01. Adjust fields
+/// before saving.
+pub fn contribution(sub_id: &str, amount: f64) -> Contribution {
+    Contribution {
+        sub_id: Some(sub_id.to_string()),
+        committee: None,
+        contributor_name: Some("Donor".to_string()),
+        contributor_state: Some("CA".to_string()),
+        contributor_city: None,
+        contributor_zip: None,
+        contributor_employer: Some("Acme".to_string()),
+        contributor_occupation: None,
+        contribution_receipt_date: Some("2024-06-01".to_string()),
+        contribution_receipt_amount: Some(amount),
+        memo_text: None,
+        receipt_type: None,
+        two_year_transaction_period: None,
+    }
+}
+
+/// Row count of `table`. Panics on an unknown table.
+pub fn table_count(db: &Db, table: &str) -> i64 {
+    db.conn()
+        .query_row(&format!("SELECT COUNT(*) FROM \"{}\"", table), [], |row| {
+            row.get(0)
+        })
+        .unwrap_or_else(|e| panic!("count rows in {}: {}", table, e))
+}
+
+/// Assert `table` holds exactly `expected` rows.
+#[track_caller]
+pub fn assert_table_count(db: &Db, table: &str, expected: i64) {
+    let actual = table_count(db, table);
+    assert_eq!(
+        actual, expected,
+        "expected {} rows in {}, found {}",
+        expected, table, actual
+    );
+}
+
+/// Assert no row violates a foreign key (`PRAGMA foreign_key_check`).
+#[track_caller]
+pub fn assert_foreign_keys_valid(db: &Db) {
+    let mut stmt = db.conn().prepare("PRAGMA foreign_key_check").expect("prepare fk check");
+    let violations: Vec<String> = stmt
+        .query_map([], |row| {
+            Ok(format!(
+                "{} rowid {:?}",
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<i64>>(1)?
+            ))
+        })
+        .expect("run fk check")
+        .collect::<Result<_, _>>()
+        .expect("read fk check");
+    assert!(violations.is_empty(), "foreign key violations: {:?}", violations);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{DbTradeFilter, DonationFilter};
+
+    fn trade_fingerprint(fx: &FixtureDb) -> Vec<(i64, String, String, i64)> {
+        fx.query_trades(&DbTradeFilter::default())
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.tx_id, t.politician_id, t.tx_date, t.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_builder_is_deterministic_per_seed() {
+        let build = |seed| {
+            FixtureDb::builder()
+                .seed(seed)
+                .trades_per_politician(15)
+                .with_price_enrichment()
+                .with_donations(5)
+                .build()
+                .unwrap()
+        };
+        let a = build(7);
+        let b = build(7);
+        let c = build(8);
+        assert_eq!(trade_fingerprint(&a), trade_fingerprint(&b));
+        assert_ne!(trade_fingerprint(&a), trade_fingerprint(&c));
+
+        let donations = |fx: &FixtureDb| -> Vec<(String, f64)> {
+            fx.query_donations(&DonationFilter::default())
+                .unwrap()
+                .into_iter()
+                .map(|d| (d.sub_id, d.amount))
+                .collect()
+        };
+        assert_eq!(donations(&a), donations(&b));
+    }
+
+    #[test]
+    fn test_builder_populates_main_tables() {
+        let fx = FixtureDb::builder()
+            .politicians(4)
+            .issuers(12)
+            .trades_per_politician(5)
+            .with_price_enrichment()
+            .with_donations(3)
+            .build()
+            .unwrap();
+
+        assert_eq!(fx.politician_ids, vec!["F000001", "F000002", "F000003", "F000004"]);
+        assert_table_count(&fx, "politicians", 4);
+        assert_table_count(&fx, "trades", 20);
+        assert_table_count(&fx, "donations", 12);
+        assert_table_count(&fx, "fec_committees", 4);
+        // Issuers are written with their first trade: seed 42 spreads the 20
+        // trades over 10 of the 12.
+        assert_table_count(&fx, "issuers", 10);
+        assert_foreign_keys_valid(&fx);
+
+        let analytics = fx.query_trades_for_analytics().unwrap();
+        assert_eq!(analytics.len(), 20, "every trade is price-enriched");
+        for politician_id in &fx.politician_ids {
+            assert_eq!(fx.count_donations_for_politician(politician_id).unwrap(), 3);
+        }
+    }
+}
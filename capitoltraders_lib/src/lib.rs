@@ -7,23 +7,42 @@ pub mod analysis;
 pub mod analytics;
 pub mod anomaly;
 pub mod cache;
+pub mod calendar;
 pub mod client;
 pub mod committee;
 pub mod committee_jurisdiction;
+pub mod config;
+pub mod congress_committee;
 pub mod conflict;
+pub mod events;
 pub mod db;
 pub mod employer_mapping;
+pub mod enrich;
+pub mod exchange_suffix;
 pub mod error;
 pub mod fec_mapping;
+#[cfg(any(test, feature = "testkit"))]
+pub mod fixtures;
+pub mod observer;
 pub mod openfec;
 pub mod portfolio;
+pub mod price_fetch;
 pub mod pricing;
+pub mod ratelimit;
 pub mod scrape;
 pub mod sector_mapping;
+pub mod shutdown;
+pub mod snapshot;
+pub mod store;
+pub mod suggest;
 pub mod ticker_alias;
 pub mod tiingo;
+pub mod trading_calendar;
+pub mod universe;
 pub mod validation;
+pub mod watch;
 pub mod yahoo;
+pub mod zip_district;
 
 pub use capitoltrades_api;
 pub use capitoltrades_api::types;
@@ -35,47 +54,99 @@ pub use capitoltrades_api::{
 pub use client::CachedClient;
 pub use committee::{CommitteeClass, CommitteeError, CommitteeResolver, ResolvedCommittee};
 pub use db::{
-    AnalyticsTradeRow, ContributorAggRow, Db, DbError, DbIssuerFilter, DbIssuerRow,
+    AffinityInput, AnalyticsCache, AnalyticsSourceState, AnalyticsTradeRow, ChamberActivityRow, ContributorAggRow, CoverageRow, CoverageScope, Db, DbError, DbIssuerFilter, DbIssuerRow,
     DbPoliticianFilter, DbPoliticianRow, DbTradeFilter, DbTradeRow, DonationFilter, DonationRow,
-    DonationSummary, DonorContext, EmployerAggRow, EnrichmentDiagnostics, HHIPositionRow,
-    IssuerStatsRow, PoliticianStatsRow, PortfolioFilter, PortfolioPosition, PreMoveCandidateRow,
-    PriceEnrichmentRow, SectorTotal, StateAggRow, TradeVolumeRow,
+    DonationGeography, DonationGeographyRow, DonationSyncCandidate, ForeignKeyViolation, IntegrityIssue, IntegrityIssueKind, IntegrityReport, OrphanedRows,
+    DisclosureLateness, DonationSummary, DonorContext, DonorTradeConflictRow, EmployerAggRow, EnrichmentDiagnostics, EnrichmentEntity, FixedIncomeExposureRow, FlaggedDonation, FlaggedMember, FlaggedTrade, HHIPositionRow, HomeStateVolumeRow, ImportReport,
+    IssuerEnrichmentPriority, IssuerMergeReport, LabelCount, LabelPoliticianRow, LabelReport, LABEL_PNL_BASIS, IssuerScreen, MatchedDonation, MemberTickerActivityRow, IssuerScreenResult, OccupationAggRow, IssuerStatsRow, OnConflict, OwnerSectorVolumeRow, PoliticianSort, PoliticianStatsRow, PortfolioFilter, PortfolioPosition, PreMoveCandidateRow, PriceSource, RoundAmountSet, SellCandidateRow,
+    SnapshotInfo, SnapshotSection, StoredSnapshot, AnchorPrices, SyncPageAnchor, TradeDatePrice,
+    CoreTable, FromRow, RawDonation, RawFecMapping, RawIssuer, RawPolitician, RawPosition, RawTrade, TableRows,
+    CommitteeSectorTradeRow, PriceCoverage, PriceEnrichmentRow, RepairStats, SectorBackfill, SectorFlowFilter, SectorFlowRow, SectorTotal, StateAggRow, SyncStatus, TickerReconcileReport, TickerSource,
+    TimeBucket, TradeRevisionRow,
+    TradeVolumeRow, UpsertProgress, LOW_CONFIDENCE_EMPLOYER_MATCH, SCHEMA_VERSION, STOCK_ACT_DISCLOSURE_DAYS,
 };
 pub use employer_mapping::{
-    is_blacklisted, load_seed_data, match_employer, normalize_employer, EmployerMappingError,
-    MatchResult, MatchType, SeedMapping,
+    is_blacklisted, load_seed_data, match_employer, normalize_employer, normalize_occupation,
+    parse_employer_mappings_csv, write_employer_mappings_csv, EmployerMappingError,
+    EmployerMappingRecord, MatchResult, MatchType, SeedMapping,
+};
+pub use enrich::{
+    enrich_after_sync, enrich_issuers, enrich_politicians, enrich_trades, EnrichOptions,
+    EnrichPhase, EnrichSummary, IssuerQueue, NoProgress, ProgressSink,
 };
 pub use error::CapitolTradesError;
+pub use exchange_suffix::{ExchangeSuffixError, ExchangeSuffixes};
+pub use events::{
+    load_events, load_hearings, parse_events, parse_hearings, CommitteeHearing, Event, EventsError,
+};
 pub use fec_mapping::{FecMapping, FecMappingError, Legislator, download_legislators, match_legislators_to_politicians};
 pub use openfec::{OpenFecClient, OpenFecError};
-pub use portfolio::{calculate_positions, Lot, Position, TradeFIFO};
-pub use pricing::{estimate_shares, parse_trade_range, resolve_yahoo_ticker, ShareEstimate, TradeRange};
-pub use ticker_alias::{load_ticker_aliases, parse_ticker_aliases, TickerAlias, TickerAliasError};
+pub use portfolio::{calculate_positions, Lot, Position, TradeFIFO, DEFAULT_PORTFOLIO_ASSET_TYPES};
+pub use pricing::{
+    c2iq_yahoo_ticker, estimate_shares, exchange_route, face_value_estimate, is_fixed_income,
+    parse_c2iq, parse_trade_range, resolve_yahoo_candidates, resolve_yahoo_ticker,
+    ticker_matches, ExchangeRoute, ShareEstimate, TradeRange, ValuationMethod, FIXED_INCOME_ASSET_TYPES,
+};
+pub use ticker_alias::{
+    is_valid_ticker, load_ticker_aliases, parse_ticker_aliases, parse_ticker_aliases_csv,
+    write_ticker_aliases_csv, TickerAlias, TickerAliasError,
+};
+pub use zip_district::{GeographyBucket, ZipDistrictError, ZipDistrictMap};
+pub use calendar::{CalendarError, SessionCalendar, SessionStatus};
 pub use scrape::{
-    ScrapeClient, ScrapeError, ScrapePage, ScrapedIssuerDetail, ScrapedIssuerList,
-    ScrapedPoliticianCard, ScrapedTrade, ScrapedTradeDetail,
+    CapDetector, PaginationCap, ScrapeClient, ScrapeError, ScrapePage, ScrapedIssuerDetail,
+    ScrapedIssuerList, ScrapedPoliticianCard, ScrapedTrade, ScrapedTradeDetail, TradeFilter,
 };
 pub use sector_mapping::{
     load_sector_mappings, parse_sector_mappings, validate_sector, SectorMapping,
     SectorMappingError, GICS_SECTORS,
 };
+pub use store::TradeStore;
+pub use suggest::{did_you_mean, Suggestion};
+pub use universe::{
+    TradeUniverse, Universe, UniverseProvenance, UniverseSpec, UniverseTrade, STOCK_ASSET_TYPES,
+};
 pub use tiingo::{TiingoClient, TiingoError};
 pub use yahoo::{YahooClient, YahooError};
+pub use observer::{RequestInfo, RequestObserver, Service};
+pub use ratelimit::{Limiter, Rate};
+pub use config::{AppConfig, ConfigEntry, ConfigError, ConfigSource};
+pub use price_fetch::{
+    plan_anchors, AnchorOutcome, AnchorPass, AnchorRequest, AnchorTally, CachedFetch, CachedRange,
+    PriceAnchor, PriceCache, PriceFetch, PriceFetchChain, PriceFetchError, RangeFetch, TickerPlan,
+};
+pub use shutdown::CancellationToken;
 pub use analytics::{
-    AnalyticsTrade, ClosedTrade, TradeMetrics, PoliticianMetrics, calculate_closed_trades,
-    compute_trade_metrics, aggregate_politician_metrics, absolute_return, annualized_return,
-    holding_period_days, simple_alpha,
+    AnalyticsTrade, ClosedTrade, GainTerm, TaxRates, TradeMetrics, PoliticianMetrics, calculate_closed_trades,
+    compute_trade_metrics, aggregate_politician_metrics, apply_tax_estimate, absolute_return, annualized_return,
+    holding_period_days, simple_alpha, sort_by_lower_bound, wilson_interval, IntervalConfig,
+    apply_recess_volume_share, group_by_session, SessionGroupRow, apply_trading_style,
+    classify_trading_style, TradingStyle, TradingStyleConfig,
 };
 pub use committee_jurisdiction::{
     CommitteeJurisdiction, load_committee_jurisdictions, get_committee_sectors,
+    get_committee_sectors_as_of, load_committee_jurisdictions_with,
+    merge_jurisdiction_overrides, JurisdictionChange, JurisdictionMergeReport,
+    JurisdictionOverrideError, JurisdictionOverrides, JurisdictionSectorRow,
+    MergedJurisdictions, SectorSource,
+};
+pub use congress_committee::{
+    CongressCommitteeError, CongressCommitteeResolver, ResolvedCongressCommittee, congress_for_date,
 };
 pub use conflict::{
     CommitteeTradingScore, DonationTradeCorrelation, ConflictSummary,
-    calculate_committee_trading_score,
+    calculate_committee_trading_score, calculate_committee_trading_score_as_of,
+    calculate_committee_trading_score_by_owner,
+    hearing_proximity_analysis, HearingProximityReport, HearingProximityRow, OwnerClass,
+    OwnerCommitteeScore, OwnerVolume,
 };
 pub use anomaly::{
     PreMoveSignal, VolumeSignal, ConcentrationScore, AnomalyScore,
-    TradeWithFuturePrice, TradeVolumeRecord, PortfolioPositionForHHI,
-    detect_pre_move_trades, detect_unusual_volume, calculate_sector_concentration,
+    TradeWithFuturePrice, TradeVolumeRecord, PortfolioPositionForHHI, PoliticianVolumeSignal,
+    VolumeParams, SellTrade, SellsWithSeries, SellParams, SellSignal, SellScan, RealizedCheck,
+    AnomalyConfig, AnomalyConfigError, PreMoveConfig, VolumeConfig, ConcentrationConfig,
+    CompositeConfig, CrowdedConfig, EventsConfig,
+    detect_pre_move_trades, future_price_candidates, detect_unusual_volume, detect_unusual_volume_db, detect_unusual_volume_universe, detect_prescient_sells,
+    calculate_sector_concentration,
     calculate_composite_anomaly_score,
 };
@@ -0,0 +1,243 @@
+//! Request observation hook shared by the network clients.
+//!
+//! Every HTTP-backed client (`CachedClient`, `ScrapeClient`, `OpenFecClient`,
+//! `TiingoClient`, `YahooClient`) reports each outbound request to an optional
+//! [`RequestObserver`], so embedders can feed request counts and latencies
+//! into their own metrics system. Clients built without an observer skip the
+//! hook entirely: no clock reads, no allocation.
+//!
+//! Cache hits never reach the network and are not reported. Each retry
+//! attempt is reported as its own request. Time spent waiting on a client's
+//! [`crate::ratelimit::Limiter`] is reported separately through
+//! [`RequestObserver::on_throttle`], so it never inflates response latency.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Upstream service a request was sent to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Service {
+    /// The CapitolTrades JSON API (`CachedClient`).
+    CapitolTrades,
+    /// CapitolTrades HTML pages (`ScrapeClient`).
+    CapitolTradesScrape,
+    OpenFec,
+    Tiingo,
+    Yahoo,
+}
+
+impl Service {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Service::CapitolTrades => "capitoltrades",
+            Service::CapitolTradesScrape => "capitoltrades_scrape",
+            Service::OpenFec => "openfec",
+            Service::Tiingo => "tiingo",
+            Service::Yahoo => "yahoo",
+        }
+    }
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Identifies an outbound request by service and endpoint category.
+///
+/// `endpoint` is a fixed label such as `"trades"` or `"schedule_a"`, never a
+/// full URL, so it is safe to use as a low-cardinality metrics label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RequestInfo {
+    pub service: Service,
+    pub endpoint: &'static str,
+}
+
+/// Receives a callback around each outbound HTTP request.
+///
+/// All methods default to no-ops. Implementations are called inline on the
+/// request path and should return quickly.
+pub trait RequestObserver: Send + Sync {
+    /// Called immediately before a request is sent.
+    fn on_request(&self, _request: &RequestInfo) {}
+
+    /// Called when a request finishes. `status` is the HTTP status code, or
+    /// `None` when the request failed before a status was received (network
+    /// error, timeout) or the client does not expose one.
+    fn on_response(&self, _request: &RequestInfo, _status: Option<u16>, _elapsed: Duration) {}
+
+    /// Called once the client's rate limiter admits a request, before
+    /// [`on_request`](Self::on_request). `waited` is zero when a token was
+    /// available immediately.
+    fn on_throttle(&self, _request: &RequestInfo, _waited: Duration) {}
+}
+
+/// Optional observer held by each client.
+#[derive(Clone, Default)]
+pub(crate) struct ObserverSlot(Option<Arc<dyn RequestObserver>>);
+
+impl ObserverSlot {
+    pub(crate) fn new(observer: Arc<dyn RequestObserver>) -> Self {
+        Self(Some(observer))
+    }
+
+    /// Report time spent waiting on the rate limiter.
+    pub(crate) fn throttled(&self, service: Service, endpoint: &'static str, waited: Duration) {
+        if let Some(observer) = self.0.as_deref() {
+            observer.on_throttle(&RequestInfo { service, endpoint }, waited);
+        }
+    }
+
+    /// Report the start of a request. Returns `None` when no observer is set.
+    pub(crate) fn start(&self, service: Service, endpoint: &'static str) -> Option<RequestSpan<'_>> {
+        let observer = self.0.as_deref()?;
+        let info = RequestInfo { service, endpoint };
+        observer.on_request(&info);
+        Some(RequestSpan {
+            observer,
+            info,
+            started: Instant::now(),
+        })
+    }
+}
+
+impl fmt::Debug for ObserverSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_some() { "Some(RequestObserver)" } else { "None" })
+    }
+}
+
+/// An in-flight observed request.
+pub(crate) struct RequestSpan<'a> {
+    observer: &'a dyn RequestObserver,
+    info: RequestInfo,
+    started: Instant,
+}
+
+impl RequestSpan<'_> {
+    pub(crate) fn finish(self, status: Option<u16>) {
+        self.observer
+            .on_response(&self.info, status, self.started.elapsed());
+    }
+}
+
+/// Finish an optional span; a no-op when no observer is set.
+pub(crate) fn finish(span: Option<RequestSpan<'_>>, status: Option<u16>) {
+    if let Some(span) = span {
+        span.finish(status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use crate::openfec::types::ScheduleAQuery;
+    use crate::openfec::OpenFecClient;
+    use crate::scrape::ScrapeClient;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<(String, &'static str, Option<u16>)>>,
+    }
+
+    impl RequestObserver for Recorder {
+        fn on_request(&self, request: &RequestInfo) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(("request".into(), request.endpoint, None));
+        }
+
+        fn on_response(&self, request: &RequestInfo, status: Option<u16>, _elapsed: Duration) {
+            self.events
+                .lock()
+                .unwrap()
+                .push((request.service.to_string(), request.endpoint, status));
+        }
+    }
+
+    #[tokio::test]
+    async fn observer_sees_endpoint_category_and_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/schedules/schedule_a/"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/trades/7"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let fec = OpenFecClient::with_base_url(&server.uri(), "key".into())
+            .unwrap()
+            .with_observer(recorder.clone());
+        assert!(fec.get_schedule_a(&ScheduleAQuery::default()).await.is_err());
+
+        let scrape = ScrapeClient::with_base_url(&server.uri())
+            .unwrap()
+            .with_observer(recorder.clone());
+        assert!(scrape.trade_detail(7).await.is_err());
+
+        let events = recorder.events.lock().unwrap().clone();
+        assert_eq!(
+            events,
+            vec![
+                ("request".to_string(), "schedule_a", None),
+                ("openfec".to_string(), "schedule_a", Some(403)),
+                ("request".to_string(), "trade_detail", None),
+                ("capitoltrades_scrape".to_string(), "trade_detail", Some(404)),
+            ]
+        );
+    }
+
+    #[derive(Default)]
+    struct ThrottleRecorder {
+        waits: Mutex<Vec<(&'static str, Duration)>>,
+    }
+
+    impl RequestObserver for ThrottleRecorder {
+        fn on_throttle(&self, request: &RequestInfo, waited: Duration) {
+            self.waits.lock().unwrap().push((request.endpoint, waited));
+        }
+    }
+
+    #[tokio::test]
+    async fn observer_sees_limiter_wait() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let recorder = Arc::new(ThrottleRecorder::default());
+        let tiingo = crate::tiingo::TiingoClient::with_base_url(&server.uri(), "key".into())
+            .unwrap()
+            .with_rate(crate::ratelimit::Rate::new(1, Duration::from_millis(50)))
+            .with_observer(recorder.clone())
+            .with_cache_capacity(0);
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        assert_eq!(tiingo.get_price_on_date("AAPL", date).await.unwrap(), None);
+        assert_eq!(tiingo.get_price_on_date("AAPL", date).await.unwrap(), None);
+
+        let waits = recorder.waits.lock().unwrap().clone();
+        assert_eq!(waits.len(), 2);
+        assert_eq!(waits[0], ("daily_prices", Duration::ZERO));
+        assert!(waits[1].1 > Duration::ZERO);
+    }
+
+    #[test]
+    fn empty_slot_reports_nothing() {
+        let slot = ObserverSlot::default();
+        assert!(slot.start(Service::Yahoo, "quote_history").is_none());
+    }
+}
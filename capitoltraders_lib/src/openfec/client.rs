@@ -1,10 +1,14 @@
 //! OpenFEC API client implementation.
 
 use super::error::OpenFecError;
+use crate::config::AppConfig;
+use crate::observer::{self, ObserverSlot, RequestObserver, Service};
+use crate::ratelimit::{HostLimiter, Limiter, Rate};
 use super::types::{
     CandidateSearchQuery, CandidateSearchResponse, CommitteeResponse, ScheduleAQuery,
     ScheduleAResponse,
 };
+use std::sync::Arc;
 use std::time::Duration;
 
 use serde::de::DeserializeOwned;
@@ -12,11 +16,20 @@ use serde::de::DeserializeOwned;
 /// Request timeout for OpenFEC API calls (seconds).
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(90);
 
+/// Production API endpoint.
+pub const DEFAULT_BASE_URL: &str = "https://api.open.fec.gov/v1";
+
+/// Default pacing: 900 requests per hour, a 10% margin under the 1,000 per
+/// hour free tier, refilled evenly.
+pub const DEFAULT_RATE: Rate = Rate::new(900, Duration::from_secs(3600));
+
 /// OpenFEC API client for fetching FEC data.
 pub struct OpenFecClient {
     client: reqwest::Client,
     api_key: String,
     base_url: String,
+    observer: ObserverSlot,
+    limiter: HostLimiter,
 }
 
 impl OpenFecClient {
@@ -25,11 +38,14 @@ impl OpenFecClient {
         let client = reqwest::Client::builder()
             .timeout(REQUEST_TIMEOUT)
             .build()
-            .map_err(|e| OpenFecError::Network(e))?;
+            .map_err(OpenFecError::Network)?;
+        let base_url = DEFAULT_BASE_URL.to_string();
         Ok(Self {
             client,
             api_key,
-            base_url: "https://api.open.fec.gov/v1".to_string(),
+            limiter: HostLimiter::new(Arc::new(Limiter::new(DEFAULT_RATE)), &base_url),
+            base_url,
+            observer: ObserverSlot::default(),
         })
     }
 
@@ -38,17 +54,59 @@ impl OpenFecClient {
         let client = reqwest::Client::builder()
             .timeout(REQUEST_TIMEOUT)
             .build()
-            .map_err(|e| OpenFecError::Network(e))?;
+            .map_err(OpenFecError::Network)?;
+        let base_url = base_url.to_string();
         Ok(Self {
             client,
             api_key,
-            base_url: base_url.to_string(),
+            limiter: HostLimiter::new(Arc::new(Limiter::new(DEFAULT_RATE)), &base_url),
+            base_url,
+            observer: ObserverSlot::default(),
         })
     }
 
+    /// Build from the `[openfec]` settings of a resolved config. Returns `None`
+    /// when no API key is configured.
+    pub fn from_config(config: &AppConfig) -> Result<Option<Self>, OpenFecError> {
+        let Some(api_key) = config.openfec.api_key.clone() else {
+            return Ok(None);
+        };
+        let base_url = config.openfec.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        let client = Self::with_base_url(base_url, api_key)?;
+        Ok(Some(match config.openfec.rate {
+            Some(rate) => client.with_rate(rate.to_rate()),
+            None => client,
+        }))
+    }
+
+    /// Report every request to `observer` (see [`crate::observer`]).
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = ObserverSlot::new(observer);
+        self
+    }
+
+    /// Pace requests through a shared limiter (see [`crate::ratelimit`])
+    /// instead of the default [`DEFAULT_RATE`].
+    pub fn with_limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter.replace(limiter);
+        self
+    }
+
+    /// Pace requests at `rate` instead of [`DEFAULT_RATE`].
+    pub fn with_rate(self, rate: Rate) -> Self {
+        self.with_limiter(Arc::new(Limiter::new(rate)))
+    }
+
+    /// Requests that could be sent now without waiting on the limiter, and
+    /// the limiter's burst (`None` when unlimited), for progress displays.
+    pub fn request_budget(&self) -> (u32, Option<u32>) {
+        (self.limiter.available(), self.limiter.rate().map(|rate| rate.burst()))
+    }
+
     /// Internal helper to perform GET requests with query parameters.
     async fn get<T: DeserializeOwned>(
         &self,
+        endpoint: &'static str,
         path: &str,
         params: &[(String, String)],
     ) -> Result<T, OpenFecError> {
@@ -60,15 +118,21 @@ impl OpenFecClient {
         all_params.push(("api_key".to_string(), self.api_key.clone()));
 
         // Make request
-        let response = self
-            .client
-            .get(&url)
-            .query(&all_params)
-            .send()
-            .await?;
+        self.limiter
+            .acquire(&self.observer, Service::OpenFec, endpoint)
+            .await;
+        let span = self.observer.start(Service::OpenFec, endpoint);
+        let response = match self.client.get(&url).query(&all_params).send().await {
+            Ok(response) => response,
+            Err(err) => {
+                observer::finish(span, None);
+                return Err(err.into());
+            }
+        };
 
         // Check status code
         let status = response.status();
+        observer::finish(span, Some(status.as_u16()));
         if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
             return Err(OpenFecError::RateLimited);
         } else if status == reqwest::StatusCode::FORBIDDEN {
@@ -108,7 +172,7 @@ impl OpenFecClient {
         query: &CandidateSearchQuery,
     ) -> Result<CandidateSearchResponse, OpenFecError> {
         let params = query.to_query_pairs();
-        self.get("/candidates/search/", &params).await
+        self.get("candidates_search", "/candidates/search/", &params).await
     }
 
     /// Get committees authorized by a specific candidate.
@@ -117,7 +181,16 @@ impl OpenFecClient {
         candidate_id: &str,
     ) -> Result<CommitteeResponse, OpenFecError> {
         let path = format!("/candidate/{}/committees/", candidate_id);
-        self.get(&path, &[]).await
+        self.get("candidate_committees", &path, &[]).await
+    }
+
+    /// Get a single committee by ID. `results` holds at most one committee.
+    pub async fn get_committee(
+        &self,
+        committee_id: &str,
+    ) -> Result<CommitteeResponse, OpenFecError> {
+        let path = format!("/committee/{}/", committee_id);
+        self.get("committee", &path, &[]).await
     }
 
     /// Get Schedule A contributions with keyset pagination.
@@ -126,6 +199,6 @@ impl OpenFecClient {
         query: &ScheduleAQuery,
     ) -> Result<ScheduleAResponse, OpenFecError> {
         let params = query.to_query_pairs();
-        self.get("/schedules/schedule_a/", &params).await
+        self.get("schedule_a", "/schedules/schedule_a/", &params).await
     }
 }
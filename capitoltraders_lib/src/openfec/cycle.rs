@@ -0,0 +1,60 @@
+//! Two-year election cycle derivation.
+//!
+//! FEC cycles are named for the even year that closes them: 2023 and 2024
+//! receipts both belong to the 2024 cycle. The `two_year_transaction_period`
+//! OpenFEC reports is the filing's period, which can disagree with the
+//! receipt date for late or amended filings, and a sync scoped to one cycle
+//! would otherwise stamp every row it fetched with that cycle. The stored
+//! `election_cycle` is derived from the receipt date instead.
+
+use super::types::Contribution;
+
+/// Election cycle for a `YYYY-MM-DD` (or longer ISO) date: the receipt year
+/// rounded up to the next even year. `None` when the year does not parse.
+pub fn election_cycle_for_date(date: &str) -> Option<i32> {
+    let year: i32 = date.trim().get(..4)?.parse().ok()?;
+    (year > 0).then_some(year + year % 2)
+}
+
+impl Contribution {
+    /// Cycle derived from the receipt date, falling back to the period the
+    /// API reported when the date is missing or malformed.
+    pub fn election_cycle(&self) -> Option<i32> {
+        self.contribution_receipt_date
+            .as_deref()
+            .and_then(election_cycle_for_date)
+            .or(self.two_year_transaction_period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycle_boundary_between_even_and_odd_year() {
+        assert_eq!(election_cycle_for_date("2024-12-31"), Some(2024));
+        assert_eq!(election_cycle_for_date("2025-01-01"), Some(2026));
+        assert_eq!(election_cycle_for_date("2023-06-15T00:00:00"), Some(2024));
+        assert_eq!(election_cycle_for_date(""), None);
+        assert_eq!(election_cycle_for_date("n/a"), None);
+    }
+
+    #[test]
+    fn test_contribution_cycle_prefers_receipt_date() {
+        let contribution: Contribution = serde_json::from_value(serde_json::json!({
+            "sub_id": "1",
+            "contribution_receipt_date": "2025-01-01",
+            "two_year_transaction_period": 2024
+        }))
+        .unwrap();
+        assert_eq!(contribution.election_cycle(), Some(2026));
+
+        let undated: Contribution = serde_json::from_value(serde_json::json!({
+            "sub_id": "2",
+            "two_year_transaction_period": 2022
+        }))
+        .unwrap();
+        assert_eq!(undated.election_cycle(), Some(2022));
+    }
+}
@@ -1,10 +1,14 @@
 //! OpenFEC API client module for fetching FEC candidate, committee, and donation data.
 
 pub mod client;
+pub mod cycle;
 pub mod error;
-pub mod rate_limiter;
+pub mod receipt;
+pub mod retry;
 pub mod types;
 
 pub use client::OpenFecClient;
+pub use cycle::election_cycle_for_date;
 pub use error::OpenFecError;
-pub use rate_limiter::{RateLimiter, RequestTracker};
+pub use receipt::{classify_receipt, ReceiptClass};
+pub use retry::{with_retry, RequestTracker, TrackerSummary};
@@ -0,0 +1,202 @@
+//! Schedule A receipt classification.
+//!
+//! Schedule A mixes ordinary contributions with refunds, transfers between
+//! committees, and earmarked pass-through money. Counting all of them as
+//! donations double counts transfers and overstates refunded money, so each
+//! stored donation carries a [`ReceiptClass`] derived from the FEC
+//! transaction code (`receipt_type`) and, failing that, the filer's memo.
+
+use serde::{Deserialize, Serialize};
+
+use super::types::Contribution;
+
+/// What a Schedule A receipt represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ReceiptClass {
+    /// An ordinary contribution.
+    Individual,
+    /// Money returned to (or by) a contributor.
+    Refund,
+    /// Money moved between committees (affiliated, joint fundraising, party).
+    Transfer,
+    /// A contribution routed through a conduit such as ActBlue or WinRed.
+    Earmark,
+    /// Loans, offsets, and other non-contribution receipts.
+    Other,
+}
+
+impl ReceiptClass {
+    /// All classes, in display order.
+    pub const ALL: [ReceiptClass; 5] = [
+        Self::Individual,
+        Self::Refund,
+        Self::Transfer,
+        Self::Earmark,
+        Self::Other,
+    ];
+
+    /// Stored value in the `donations.receipt_class` column.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Individual => "individual",
+            Self::Refund => "refund",
+            Self::Transfer => "transfer",
+            Self::Earmark => "earmark",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::fmt::Display for ReceiptClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ReceiptClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|class| class.as_str() == normalized)
+            .ok_or_else(|| format!("Invalid receipt class '{}'", s))
+    }
+}
+
+/// Classify a receipt from its FEC transaction code and memo text.
+///
+/// The transaction code decides when it is known:
+/// - `20*`, `21*`, `22*`, `28*`, `17R` -> Refund
+/// - `12*`, `18*`, `30T`/`31T`/`32T` -> Transfer
+/// - `15E`, `15T`, `24T` -> Earmark
+/// - `10`, `11`, `15`, `15C`, `15I`, `15J`, `15Z`, `30`, `31`, `32` -> Individual
+/// - anything else -> Other
+///
+/// Without a code (older rows, some amended filings) the memo is searched
+/// for "REFUND", "TRANSFER", and "EARMARK"; a receipt with neither is treated
+/// as an individual contribution.
+pub fn classify_receipt(receipt_type: Option<&str>, memo: Option<&str>) -> ReceiptClass {
+    let code = receipt_type
+        .map(|t| t.trim().to_ascii_uppercase())
+        .filter(|t| !t.is_empty());
+    let memo = memo.map(str::to_ascii_uppercase).unwrap_or_default();
+
+    if let Some(code) = code {
+        let line = code.get(..2).unwrap_or(&code);
+        return match (line, code.as_str()) {
+            (_, "17R") | ("20" | "21" | "22" | "28", _) => ReceiptClass::Refund,
+            (_, "30T" | "31T" | "32T") | ("12" | "18", _) => ReceiptClass::Transfer,
+            (_, "15E" | "15T" | "24T") => ReceiptClass::Earmark,
+            (_, "10" | "11" | "15" | "15C" | "15I" | "15J" | "15Z" | "30" | "31" | "32") => {
+                // A plain contribution code whose memo says it was earmarked.
+                if memo.contains("EARMARK") {
+                    ReceiptClass::Earmark
+                } else {
+                    ReceiptClass::Individual
+                }
+            }
+            _ => ReceiptClass::Other,
+        };
+    }
+
+    if memo.contains("REFUND") {
+        ReceiptClass::Refund
+    } else if memo.contains("TRANSFER") {
+        ReceiptClass::Transfer
+    } else if memo.contains("EARMARK") {
+        ReceiptClass::Earmark
+    } else {
+        ReceiptClass::Individual
+    }
+}
+
+/// [`classify_receipt`], treating a negative amount that would otherwise
+/// count as a contribution as a refund.
+pub(crate) fn classify_receipt_with_amount(
+    receipt_type: Option<&str>,
+    memo: Option<&str>,
+    amount: Option<f64>,
+) -> ReceiptClass {
+    match classify_receipt(receipt_type, memo) {
+        ReceiptClass::Individual | ReceiptClass::Earmark if amount.is_some_and(|a| a < 0.0) => {
+            ReceiptClass::Refund
+        }
+        class => class,
+    }
+}
+
+impl Contribution {
+    /// Class stored with this record; a negative contribution is a refund.
+    pub fn receipt_class(&self) -> ReceiptClass {
+        classify_receipt_with_amount(
+            self.receipt_type.as_deref(),
+            self.memo_text.as_deref(),
+            self.contribution_receipt_amount,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_by_receipt_type() {
+        assert_eq!(classify_receipt(Some("15"), None), ReceiptClass::Individual);
+        assert_eq!(classify_receipt(Some("15J"), None), ReceiptClass::Individual);
+        assert_eq!(classify_receipt(Some("22Y"), None), ReceiptClass::Refund);
+        assert_eq!(classify_receipt(Some("17R"), None), ReceiptClass::Refund);
+        assert_eq!(classify_receipt(Some("18G"), None), ReceiptClass::Transfer);
+        assert_eq!(classify_receipt(Some("12"), None), ReceiptClass::Transfer);
+        assert_eq!(classify_receipt(Some("15E"), None), ReceiptClass::Earmark);
+        assert_eq!(classify_receipt(Some("16C"), None), ReceiptClass::Other);
+        // Codes are matched case-insensitively and trimmed.
+        assert_eq!(classify_receipt(Some(" 18k "), None), ReceiptClass::Transfer);
+    }
+
+    #[test]
+    fn test_classify_falls_back_to_memo() {
+        assert_eq!(
+            classify_receipt(None, Some("Refund of excessive contribution")),
+            ReceiptClass::Refund
+        );
+        assert_eq!(
+            classify_receipt(Some(""), Some("TRANSFER FROM JFC")),
+            ReceiptClass::Transfer
+        );
+        assert_eq!(
+            classify_receipt(Some("15"), Some("EARMARKED THROUGH ACTBLUE")),
+            ReceiptClass::Earmark
+        );
+        // The code wins over a contradicting memo.
+        assert_eq!(
+            classify_receipt(Some("18G"), Some("refund")),
+            ReceiptClass::Transfer
+        );
+        assert_eq!(classify_receipt(None, None), ReceiptClass::Individual);
+    }
+
+    #[test]
+    fn test_negative_contribution_is_refund() {
+        let contribution: Contribution = serde_json::from_value(serde_json::json!({
+            "sub_id": "1",
+            "contribution_receipt_amount": -500.0,
+            "receipt_type": "15",
+            "memo_text": null
+        }))
+        .unwrap();
+        assert_eq!(contribution.receipt_class(), ReceiptClass::Refund);
+    }
+
+    #[test]
+    fn test_receipt_class_round_trips_through_str() {
+        for class in ReceiptClass::ALL {
+            assert_eq!(class.as_str().parse::<ReceiptClass>(), Ok(class));
+        }
+        assert!("gift".parse::<ReceiptClass>().is_err());
+    }
+}
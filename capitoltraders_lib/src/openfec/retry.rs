@@ -0,0 +1,284 @@
+//! Retry logic and request accounting for OpenFEC API requests.
+//!
+//! Pacing is the client's job: every [`OpenFecClient`](super::OpenFecClient)
+//! request waits on its [`crate::ratelimit::Limiter`] (900 req/hr by default).
+//! This module only retries 429 responses with exponential backoff and counts
+//! outcomes, including the time the limiter made requests wait, which a
+//! [`RequestTracker`] receives as the client's [`RequestObserver`].
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use rand::Rng;
+use tokio::time::sleep;
+
+use super::error::OpenFecError;
+use crate::observer::{RequestInfo, RequestObserver};
+
+/// Atomic counters tracking API request outcomes.
+///
+/// Attach it to the client with
+/// [`OpenFecClient::with_observer`](super::OpenFecClient::with_observer) to
+/// also count time spent waiting on the client's rate limiter.
+#[derive(Debug, Default)]
+pub struct RequestTracker {
+    pub(crate) requests_made: AtomicU64,
+    pub(crate) requests_succeeded: AtomicU64,
+    pub(crate) requests_rate_limited: AtomicU64,
+    pub(crate) requests_failed: AtomicU64,
+    /// Cumulative backoff time in milliseconds.
+    pub(crate) total_backoff_ms: AtomicU64,
+    /// Cumulative rate-limiter wait in milliseconds.
+    pub(crate) total_throttle_ms: AtomicU64,
+}
+
+impl RequestTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_success(&self) {
+        self.requests_made.fetch_add(1, Ordering::Relaxed);
+        self.requests_succeeded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_rate_limited(&self) {
+        self.requests_made.fetch_add(1, Ordering::Relaxed);
+        self.requests_rate_limited.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_failure(&self) {
+        self.requests_made.fetch_add(1, Ordering::Relaxed);
+        self.requests_failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_backoff(&self, duration: Duration) {
+        self.total_backoff_ms
+            .fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_throttle(&self, duration: Duration) {
+        self.total_throttle_ms
+            .fetch_add(duration.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Snapshot the current counters.
+    pub fn summary(&self) -> TrackerSummary {
+        TrackerSummary {
+            requests_made: self.requests_made.load(Ordering::Relaxed),
+            requests_succeeded: self.requests_succeeded.load(Ordering::Relaxed),
+            requests_rate_limited: self.requests_rate_limited.load(Ordering::Relaxed),
+            requests_failed: self.requests_failed.load(Ordering::Relaxed),
+            total_backoff_secs: self.total_backoff_ms.load(Ordering::Relaxed) as f64 / 1000.0,
+            total_throttle_secs: self.total_throttle_ms.load(Ordering::Relaxed) as f64 / 1000.0,
+        }
+    }
+}
+
+impl RequestObserver for RequestTracker {
+    fn on_throttle(&self, _request: &RequestInfo, waited: Duration) {
+        self.record_throttle(waited);
+    }
+}
+
+/// Immutable snapshot of tracker counters for display.
+#[derive(Debug, Clone)]
+pub struct TrackerSummary {
+    pub requests_made: u64,
+    pub requests_succeeded: u64,
+    pub requests_rate_limited: u64,
+    pub requests_failed: u64,
+    pub total_backoff_secs: f64,
+    /// Time requests waited on the client's rate limiter.
+    pub total_throttle_secs: f64,
+}
+
+/// Execute an async operation with exponential backoff on 429s.
+///
+/// - Pacing is left to the client's limiter; attempts are not delayed here.
+/// - On `OpenFecError::RateLimited`: waits `base_backoff * 2^(attempt-1)` plus
+///   0-10s jitter, then retries up to `max_retries` times.
+/// - On `OpenFecError::InvalidApiKey` or other errors: returns immediately.
+/// - Records all outcomes on the tracker.
+pub async fn with_retry<F, Fut, T>(
+    tracker: &RequestTracker,
+    max_retries: u32,
+    base_backoff: Duration,
+    operation: F,
+) -> Result<T, OpenFecError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, OpenFecError>>,
+{
+    for attempt in 0..=max_retries {
+        match operation().await {
+            Ok(val) => {
+                tracker.record_success();
+                return Ok(val);
+            }
+            Err(OpenFecError::RateLimited) => {
+                tracker.record_rate_limited();
+
+                if attempt == max_retries {
+                    return Err(OpenFecError::RateLimited);
+                }
+
+                // Exponential backoff: base * 2^attempt, plus 0-10s jitter
+                let backoff_multiplier = 1u64 << attempt;
+                let backoff = base_backoff * backoff_multiplier as u32;
+                let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..10_000));
+                let total_wait = backoff + jitter;
+
+                tracker.record_backoff(total_wait);
+                sleep(total_wait).await;
+            }
+            Err(e) => {
+                tracker.record_failure();
+                return Err(e);
+            }
+        }
+    }
+
+    // Unreachable, but satisfies the compiler
+    Err(OpenFecError::RateLimited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn tracker_counters() {
+        let tracker = RequestTracker::new();
+
+        tracker.record_success();
+        tracker.record_success();
+        tracker.record_rate_limited();
+        tracker.record_failure();
+        tracker.record_backoff(Duration::from_secs(60));
+
+        let summary = tracker.summary();
+        assert_eq!(summary.requests_made, 4);
+        assert_eq!(summary.requests_succeeded, 2);
+        assert_eq!(summary.requests_rate_limited, 1);
+        assert_eq!(summary.requests_failed, 1);
+        assert!((summary.total_backoff_secs - 60.0).abs() < 0.01);
+        assert_eq!(summary.total_throttle_secs, 0.0);
+    }
+
+    #[tokio::test]
+    async fn tracker_counts_limiter_waits_as_observer() {
+        use crate::openfec::OpenFecClient;
+        use crate::ratelimit::Rate;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"results": [], "pagination": {"count": 0, "per_page": 1, "last_indexes": null}}"#,
+            ))
+            .mount(&server)
+            .await;
+        let tracker = Arc::new(RequestTracker::new());
+        let client = OpenFecClient::with_base_url(&server.uri(), "key".to_string())
+            .unwrap()
+            .with_rate(Rate::new(1, Duration::from_millis(200)))
+            .with_observer(tracker.clone());
+        let query = crate::openfec::types::ScheduleAQuery::default().with_committee_id("C1");
+
+        for _ in 0..2 {
+            with_retry(&tracker, 0, Duration::from_secs(1), || client.get_schedule_a(&query))
+                .await
+                .unwrap();
+        }
+
+        let summary = tracker.summary();
+        assert_eq!(summary.requests_succeeded, 2);
+        assert!(summary.total_throttle_secs > 0.1, "{:?}", summary);
+    }
+
+    #[tokio::test]
+    async fn with_retry_succeeds_first_attempt() {
+        let tracker = RequestTracker::new();
+        let result = with_retry(&tracker, 3, Duration::from_secs(1), || async {
+            Ok::<_, OpenFecError>(42)
+        })
+        .await;
+        assert_eq!(result.unwrap(), 42);
+
+        let summary = tracker.summary();
+        assert_eq!(summary.requests_made, 1);
+        assert_eq!(summary.requests_succeeded, 1);
+    }
+
+    #[tokio::test]
+    async fn with_retry_retries_on_rate_limit() {
+        tokio::time::pause();
+
+        let tracker = RequestTracker::new();
+        let attempt = Arc::new(AtomicU64::new(0));
+        let attempt_clone = Arc::clone(&attempt);
+
+        let result = with_retry(
+            &tracker,
+            3,
+            Duration::from_millis(100), // Short backoff for test speed
+            move || {
+                let attempt = Arc::clone(&attempt_clone);
+                async move {
+                    let n = attempt.fetch_add(1, Ordering::SeqCst);
+                    if n < 2 {
+                        Err(OpenFecError::RateLimited)
+                    } else {
+                        Ok(99)
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), 99);
+
+        let summary = tracker.summary();
+        assert_eq!(summary.requests_succeeded, 1);
+        assert_eq!(summary.requests_rate_limited, 2);
+    }
+
+    #[tokio::test]
+    async fn with_retry_propagates_non_rate_limit_errors() {
+        let tracker = RequestTracker::new();
+        let result = with_retry(&tracker, 3, Duration::from_secs(1), || async {
+            Err::<i32, _>(OpenFecError::InvalidApiKey)
+        })
+        .await;
+
+        assert!(matches!(result, Err(OpenFecError::InvalidApiKey)));
+
+        let summary = tracker.summary();
+        assert_eq!(summary.requests_made, 1);
+        assert_eq!(summary.requests_failed, 1);
+    }
+
+    #[tokio::test]
+    async fn with_retry_exhausts_retries() {
+        tokio::time::pause();
+
+        let tracker = RequestTracker::new();
+        let result = with_retry(
+            &tracker,
+            2,
+            Duration::from_millis(100),
+            || async { Err::<i32, _>(OpenFecError::RateLimited) },
+        )
+        .await;
+
+        assert!(matches!(result, Err(OpenFecError::RateLimited)));
+
+        let summary = tracker.summary();
+        // 1 initial + 2 retries = 3 rate_limited
+        assert_eq!(summary.requests_rate_limited, 3);
+    }
+}
@@ -79,10 +79,19 @@ pub struct Contribution {
     pub committee: Option<CommitteeRef>,
     pub contributor_name: Option<String>,
     pub contributor_state: Option<String>,
+    pub contributor_city: Option<String>,
+    pub contributor_zip: Option<String>,
     pub contributor_employer: Option<String>,
     pub contributor_occupation: Option<String>,
     pub contribution_receipt_date: Option<String>,
     pub contribution_receipt_amount: Option<f64>,
+    /// Filer's free-text note, e.g. "REFUND" or "EARMARKED THROUGH ACTBLUE".
+    pub memo_text: Option<String>,
+    /// FEC transaction code (`15`, `15E`, `18G`, `22Y`, ...).
+    pub receipt_type: Option<String>,
+    /// Filing period the API assigned, e.g. `2024` for 2023-2024.
+    #[serde(default)]
+    pub two_year_transaction_period: Option<i32>,
 }
 
 /// Committee reference nested in contribution records.
@@ -6,11 +6,28 @@
 
 use std::collections::{HashMap, VecDeque};
 
+/// Asset types the FIFO portfolio tracks by default. `unknown` covers trades
+/// scraped before their asset type was known, which are overwhelmingly
+/// stock. Options and fixed income are left out: neither has a share count
+/// that FIFO lot accounting can use.
+pub const DEFAULT_PORTFOLIO_ASSET_TYPES: &[&str] = &["stock", "unknown"];
+
+/// Whether `asset_types` names exactly [`DEFAULT_PORTFOLIO_ASSET_TYPES`], in
+/// any order.
+pub fn is_default_asset_types<S: AsRef<str>>(asset_types: &[S]) -> bool {
+    let mut types: Vec<&str> = asset_types.iter().map(AsRef::as_ref).collect();
+    let mut default = DEFAULT_PORTFOLIO_ASSET_TYPES.to_vec();
+    types.sort_unstable();
+    types.dedup();
+    default.sort_unstable();
+    types == default
+}
+
 /// Epsilon constant for floating-point comparisons.
-const EPSILON: f64 = 0.0001;
+pub(crate) const EPSILON: f64 = 0.0001;
 
 /// A single buy lot in a FIFO queue.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Lot {
     pub shares: f64,
     pub cost_basis: f64,
@@ -73,6 +90,15 @@ impl Position {
         Ok(())
     }
 
+    /// The open tax lots under FIFO accounting, in the order they were
+    /// bought (the order trades were processed), next to be sold first.
+    ///
+    /// A lot partly consumed by a sell reports only its remaining shares, at
+    /// its original per-share cost.
+    pub fn open_lots(&self) -> impl ExactSizeIterator<Item = &Lot> {
+        self.lots.iter()
+    }
+
     pub fn shares_held(&self) -> f64 {
         self.lots.iter().map(|lot| lot.shares).sum()
     }
@@ -203,6 +229,27 @@ mod tests {
         assert!((pos.realized_pnl - 2400.0).abs() < EPSILON);
     }
 
+    #[test]
+    fn test_open_lots_reflect_fifo_consumption() {
+        let mut pos = Position::new("P000001".to_string(), "AAPL".to_string());
+        pos.buy(10.0, 20.0, "2024-01-02".to_string());
+        pos.buy(50.0, 40.0, "2024-01-05".to_string());
+        pos.buy(30.0, 60.0, "2024-02-01".to_string());
+        pos.sell(25.0, 80.0).unwrap();
+
+        let lots: Vec<&Lot> = pos.open_lots().collect();
+        let dates: Vec<&str> = lots.iter().map(|l| l.tx_date.as_str()).collect();
+        assert_eq!(dates, vec!["2024-01-05", "2024-02-01"]);
+        // The 2024-01-02 lot went first, then 15 of the 50 @ 40.
+        assert!((lots[0].shares - 35.0).abs() < EPSILON);
+        assert!((lots[0].cost_basis - 40.0).abs() < EPSILON);
+        assert!((lots[1].shares - 30.0).abs() < EPSILON);
+        assert!((pos.realized_pnl - (10.0 * 60.0 + 15.0 * 40.0)).abs() < EPSILON);
+
+        pos.sell(65.0, 80.0).unwrap();
+        assert_eq!(pos.open_lots().len(), 0);
+    }
+
     #[test]
     fn test_sell_from_empty() {
         let mut pos = Position::new("P000001".to_string(), "AAPL".to_string());
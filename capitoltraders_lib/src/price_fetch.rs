@@ -0,0 +1,927 @@
+//! Historical price lookups across Yahoo Finance and Tiingo.
+//!
+//! [`PriceFetchChain`] asks Yahoo first and falls back to Tiingo (which keeps
+//! delisted tickers). Each lookup downloads the [`LOOKBACK_DAYS`] before the
+//! requested date as well, so a weekend or holiday resolves to the prior
+//! close, and returns every close it downloaded. [`PriceCache`] consults the
+//! database's `price_cache` ([`Db::cached_close`]) before fetching and stores
+//! those closes in it ([`Db::store_price_closes`]), so repeated runs only
+//! download dates they have not seen.
+//!
+//! `enrich-prices` needs several dates per trade (the trade date, the
+//! disclosure date and today). [`plan_anchors`] groups every trade's dates
+//! by ticker so [`PriceFetchChain::fetch_range`] can answer all of them with
+//! one download per ticker.
+//!
+//! [`Db::store_price_closes`]: crate::db::Db::store_price_closes
+//! [`Db::cached_close`]: crate::db::Db::cached_close
+
+use chrono::{Days, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::db::{AnchorPrices, Db, DbError, PriceEnrichmentRow, TradeDatePrice};
+use crate::pricing::{self, FxRates};
+use crate::tiingo::{TiingoClient, TiingoError};
+use crate::yahoo::{YahooClient, YahooError};
+
+/// Calendar days before the requested date downloaded with it.
+pub const LOOKBACK_DAYS: u64 = 7;
+
+/// Closes this many days old or newer are downloaded again even when cached,
+/// since providers still adjust recent sessions.
+pub const DEFAULT_REFRESH_DAYS: u64 = 5;
+
+/// Errors from a [`PriceFetchChain`] lookup.
+#[derive(Error, Debug)]
+pub enum PriceFetchError {
+    #[error("Invalid date: {0}")]
+    InvalidDate(NaiveDate),
+    #[error(transparent)]
+    Yahoo(#[from] YahooError),
+    #[error(transparent)]
+    Tiingo(#[from] TiingoError),
+}
+
+impl PriceFetchError {
+    /// Whether a later run may succeed: rate limits, network failures, and
+    /// a rejected Tiingo key, which is a configuration problem rather than
+    /// one with the ticker.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::InvalidDate(_) => false,
+            Self::Yahoo(e) => matches!(e, YahooError::RateLimited | YahooError::Upstream(_)),
+            Self::Tiingo(e) => matches!(
+                e,
+                TiingoError::RateLimited | TiingoError::Network(_) | TiingoError::InvalidApiKey
+            ),
+        }
+    }
+}
+
+/// Result of one [`PriceFetchChain::fetch`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PriceFetch {
+    /// Close on the requested date, or the latest one before it in the
+    /// lookback window.
+    pub price: Option<f64>,
+    /// Provider of `price` (`yahoo` or `tiingo`).
+    pub source: Option<&'static str>,
+    /// Every close downloaded from `source`, oldest first.
+    pub closes: Vec<(NaiveDate, f64)>,
+}
+
+/// A date a trade needs a price for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PriceAnchor {
+    /// `tx_date`, stored as `trade_date_price`.
+    TradeDate,
+    /// `pub_date`, stored as `pub_date_price`.
+    PubDate,
+    /// Today, stored as `current_price`.
+    Current,
+}
+
+/// The anchors one trade needs, keyed by its (resolved) ticker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnchorRequest {
+    pub ticker: String,
+    pub anchors: Vec<(PriceAnchor, NaiveDate)>,
+}
+
+impl AnchorRequest {
+    /// Date requested for `anchor`, if any.
+    pub fn date(&self, anchor: PriceAnchor) -> Option<NaiveDate> {
+        self.anchors
+            .iter()
+            .find(|(a, _)| *a == anchor)
+            .map(|&(_, date)| date)
+    }
+}
+
+/// Work for one ticker: the distinct dates its trades need.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TickerPlan {
+    pub dates: BTreeSet<NaiveDate>,
+    /// Indices into the request slice given to [`plan_anchors`].
+    pub requests: Vec<usize>,
+}
+
+/// Group `requests` by ticker, deduplicating their dates. Requests without
+/// anchors are left out.
+pub fn plan_anchors(requests: &[AnchorRequest]) -> BTreeMap<String, TickerPlan> {
+    let mut plan: BTreeMap<String, TickerPlan> = BTreeMap::new();
+    for (idx, request) in requests.iter().enumerate() {
+        if request.anchors.is_empty() {
+            continue;
+        }
+        let entry = plan.entry(request.ticker.clone()).or_default();
+        entry.dates.extend(request.anchors.iter().map(|&(_, date)| date));
+        entry.requests.push(idx);
+    }
+    plan
+}
+
+/// Result of one [`PriceFetchChain::fetch_range`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RangeFetch {
+    /// Provider of `closes` (`yahoo` or `tiingo`); `None` when neither had data.
+    pub source: Option<&'static str>,
+    /// Every close downloaded from `source`, oldest first.
+    pub closes: Vec<(NaiveDate, f64)>,
+}
+
+impl RangeFetch {
+    /// Close on `date`, or the latest one in the [`LOOKBACK_DAYS`] before it.
+    pub fn price_on(&self, date: NaiveDate) -> Option<f64> {
+        let earliest = date.checked_sub_days(Days::new(LOOKBACK_DAYS))?;
+        self.closes
+            .iter()
+            .rev()
+            .find(|(d, _)| *d <= date)
+            .filter(|(d, _)| *d >= earliest)
+            .map(|&(_, close)| close)
+    }
+
+    fn resolved(&self, dates: &BTreeSet<NaiveDate>) -> usize {
+        dates.iter().filter(|d| self.price_on(**d).is_some()).count()
+    }
+}
+
+/// Yahoo-first historical price lookups with an optional Tiingo fallback.
+#[derive(Default, Clone)]
+pub struct PriceFetchChain {
+    yahoo: Option<Arc<YahooClient>>,
+    tiingo: Option<Arc<TiingoClient>>,
+}
+
+impl PriceFetchChain {
+    /// Chain that asks `yahoo` first.
+    pub fn new(yahoo: Arc<YahooClient>) -> Self {
+        Self {
+            yahoo: Some(yahoo),
+            tiingo: None,
+        }
+    }
+
+    /// Fall back to `tiingo` when Yahoo has no data (or, without Yahoo, use
+    /// it alone).
+    pub fn with_tiingo(mut self, tiingo: Arc<TiingoClient>) -> Self {
+        self.tiingo = Some(tiingo);
+        self
+    }
+
+    /// The same chain without its Tiingo fallback, for listings on markets
+    /// Tiingo does not carry (see [`crate::pricing::ExchangeRoute`]).
+    pub fn yahoo_only(&self) -> Self {
+        Self {
+            yahoo: self.yahoo.clone(),
+            tiingo: None,
+        }
+    }
+
+    /// Close for `ticker` on `date`, falling back to the latest close in the
+    /// [`LOOKBACK_DAYS`] before it.
+    ///
+    /// Yahoo errors are returned so callers can count failures. When Yahoo
+    /// answered without data, a failing Tiingo fallback is reported as no
+    /// data rather than an error.
+    pub async fn fetch(&self, ticker: &str, date: NaiveDate) -> Result<PriceFetch, PriceFetchError> {
+        let range = self.fetch_range(ticker, &BTreeSet::from([date])).await?;
+        match range.price_on(date) {
+            Some(price) => Ok(PriceFetch {
+                price: Some(price),
+                source: range.source,
+                closes: range.closes,
+            }),
+            None => Ok(PriceFetch::default()),
+        }
+    }
+
+    /// Closes covering every date in `dates` with a single download per
+    /// provider: from [`LOOKBACK_DAYS`] before the earliest date through the
+    /// latest one.
+    ///
+    /// Tiingo is asked only when Yahoo leaves some date without a close, and
+    /// its series is used when it resolves more of them. Errors are handled
+    /// as in [`PriceFetchChain::fetch`].
+    pub async fn fetch_range(
+        &self,
+        ticker: &str,
+        dates: &BTreeSet<NaiveDate>,
+    ) -> Result<RangeFetch, PriceFetchError> {
+        let (Some(&first), Some(&last)) = (dates.first(), dates.last()) else {
+            return Ok(RangeFetch::default());
+        };
+        let start = first
+            .checked_sub_days(Days::new(LOOKBACK_DAYS))
+            .ok_or(PriceFetchError::InvalidDate(first))?;
+
+        let mut best = RangeFetch::default();
+        if let Some(ref yahoo) = self.yahoo {
+            let end = last
+                .checked_add_days(Days::new(1))
+                .ok_or(PriceFetchError::InvalidDate(last))?;
+            let closes = yahoo.daily_closes(ticker, start, end).await?;
+            if !closes.is_empty() {
+                best = RangeFetch {
+                    source: Some("yahoo"),
+                    closes: closes.to_vec(),
+                };
+            }
+            if best.resolved(dates) == dates.len() {
+                return Ok(best);
+            }
+        }
+
+        let Some(ref tiingo) = self.tiingo else {
+            return Ok(best);
+        };
+        match tiingo.daily_closes(ticker, start, last).await {
+            Ok(closes) => {
+                let fallback = RangeFetch {
+                    source: (!closes.is_empty()).then_some("tiingo"),
+                    closes,
+                };
+                if fallback.resolved(dates) > best.resolved(dates) {
+                    best = fallback;
+                }
+                Ok(best)
+            }
+            Err(_) if self.yahoo.is_some() => Ok(best),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// [`PriceFetchChain::fetch_range`] over `candidates` in order (see
+    /// [`crate::pricing::resolve_yahoo_candidates`]), stopping at the first
+    /// ticker with any close.
+    ///
+    /// Returns the index of the candidate that answered with its range. When
+    /// none has data, the index is 0 with the first candidate's result, so
+    /// its error (if any) is what callers report.
+    pub async fn fetch_range_candidates(
+        &self,
+        candidates: &[String],
+        dates: &BTreeSet<NaiveDate>,
+    ) -> (usize, Result<RangeFetch, PriceFetchError>) {
+        let mut first = None;
+        for (idx, ticker) in candidates.iter().enumerate() {
+            let result = self.fetch_range(ticker, dates).await;
+            if matches!(result, Ok(ref range) if !range.closes.is_empty()) {
+                return (idx, result);
+            }
+            first.get_or_insert(result);
+        }
+        (0, first.unwrap_or_else(|| Ok(RangeFetch::default())))
+    }
+}
+
+/// The database's `price_cache` in front of a [`PriceFetchChain`]: stored
+/// closes answer their dates, and closes downloaded for the rest are written
+/// back.
+///
+/// Lookups are split in three steps so the download can run on another
+/// task: [`PriceCache::split`], [`CachedRange::fetch`], then
+/// [`PriceCache::store`].
+#[derive(Clone, Copy)]
+pub struct PriceCache<'a> {
+    db: &'a Db,
+    fresh_from: NaiveDate,
+    enabled: bool,
+}
+
+impl<'a> PriceCache<'a> {
+    /// Cache in `db` that downloads sessions on or after `fresh_from` again
+    /// (see [`Db::cached_close`]).
+    pub fn new(db: &'a Db, fresh_from: NaiveDate) -> Self {
+        Self {
+            db,
+            fresh_from,
+            enabled: true,
+        }
+    }
+
+    /// A cache that neither answers nor stores anything.
+    pub fn disabled(db: &'a Db) -> Self {
+        Self {
+            db,
+            fresh_from: NaiveDate::MIN,
+            enabled: false,
+        }
+    }
+
+    /// Split `dates` into the closes stored for `ticker` and the dates left
+    /// to download.
+    pub fn split(&self, ticker: &str, dates: &BTreeSet<NaiveDate>) -> Result<CachedRange, DbError> {
+        let mut range = CachedRange::default();
+        for &date in dates {
+            let hit = if self.enabled {
+                self.db.cached_close(ticker, date, self.fresh_from)?
+            } else {
+                None
+            };
+            match hit {
+                Some(hit) => {
+                    range.cached.insert(date, hit);
+                }
+                None => {
+                    range.missing.insert(date);
+                }
+            }
+        }
+        Ok(range)
+    }
+
+    /// Store the closes `fetch` downloaded under `ticker`, the listing that
+    /// answered.
+    pub fn store(&self, ticker: &str, fetch: &CachedFetch) -> Result<(), DbError> {
+        if let (true, Ok(range)) = (self.enabled, &fetch.result) {
+            if let Some(source) = range.source {
+                self.db.store_price_closes(ticker, source, &range.closes)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One ticker's dates after a [`PriceCache::split`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CachedRange {
+    /// Dates answered by the cache, with the close and its source.
+    pub cached: HashMap<NaiveDate, (f64, String)>,
+    /// Dates with no usable cached close.
+    pub missing: BTreeSet<NaiveDate>,
+}
+
+impl CachedRange {
+    /// Download the missing dates through `chain`, trying `candidates` in
+    /// order as [`PriceFetchChain::fetch_range_candidates`] does. Nothing is
+    /// downloaded when every date was cached.
+    pub async fn fetch(self, chain: &PriceFetchChain, candidates: &[String]) -> CachedFetch {
+        let (candidate, result) = if self.missing.is_empty() {
+            (0, Ok(RangeFetch::default()))
+        } else {
+            chain.fetch_range_candidates(candidates, &self.missing).await
+        };
+        CachedFetch {
+            cached: self.cached,
+            result,
+            candidate,
+        }
+    }
+}
+
+/// A [`CachedRange`] with its download.
+#[derive(Debug)]
+pub struct CachedFetch {
+    /// Dates answered by the cache, with the close and its source.
+    pub cached: HashMap<NaiveDate, (f64, String)>,
+    /// Download of the missing dates; an empty range when none was missing.
+    pub result: Result<RangeFetch, PriceFetchError>,
+    /// Index of the candidate ticker that answered the download.
+    pub candidate: usize,
+}
+
+impl CachedFetch {
+    /// Close on `date` with its source, from the cache or else the download.
+    pub fn price_on(&self, date: NaiveDate) -> Option<(f64, String)> {
+        if let Some(hit) = self.cached.get(&date) {
+            return Some(hit.clone());
+        }
+        let range = self.result.as_ref().ok()?;
+        range
+            .price_on(date)
+            .map(|close| (close, range.source.unwrap_or_default().to_string()))
+    }
+}
+
+/// Running totals of an [`AnchorPass`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnchorTally {
+    /// Trades with a trade-date price and a share estimate.
+    pub enriched: usize,
+    /// Trades whose lookup failed.
+    pub failed: usize,
+    /// Trades without a trade-date price or without a share estimate.
+    pub skipped: usize,
+    /// Trade-date prices answered by Tiingo.
+    pub tiingo_resolved: usize,
+    pub pub_enriched: usize,
+    pub current_enriched: usize,
+    pub current_skipped: usize,
+    /// Listings with a price but no FX rate, stored in their own currency.
+    pub fx_unconverted: BTreeSet<String>,
+}
+
+/// What [`AnchorPass::apply`] did with one ticker's lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnchorOutcome {
+    /// At least one anchor got a price.
+    Priced,
+    /// No anchor got a price; every anchor is stored as attempted.
+    NoData,
+    /// The lookup failed for good; every anchor is stored as attempted so
+    /// the trades are not retried.
+    Failed,
+    /// The lookup failed transiently ([`PriceFetchError::is_transient`]);
+    /// the trades are left unenriched for the next run.
+    Deferred,
+}
+
+/// Writes `enrich-prices` Phase 1 results: each ticker's lookup, as it
+/// arrives, is turned into [`AnchorPrices`] for the trades that asked for it.
+pub struct AnchorPass<'a> {
+    db: &'a Db,
+    trades: &'a [PriceEnrichmentRow],
+    requests: &'a [AnchorRequest],
+    fx_rates: &'a FxRates,
+    pub tally: AnchorTally,
+}
+
+impl<'a> AnchorPass<'a> {
+    /// Pass over `trades` and their `requests` (same order, as planned by
+    /// [`plan_anchors`]), converting prices with `fx_rates`.
+    pub fn new(
+        db: &'a Db,
+        trades: &'a [PriceEnrichmentRow],
+        requests: &'a [AnchorRequest],
+        fx_rates: &'a FxRates,
+    ) -> Self {
+        Self {
+            db,
+            trades,
+            requests,
+            fx_rates,
+            tally: AnchorTally::default(),
+        }
+    }
+
+    /// Store `fetch` for the trades at `indices`. `price_ticker` is the
+    /// listing the closes belong to, whose currency is converted to USD.
+    pub fn apply(
+        &mut self,
+        indices: &[usize],
+        fetch: &CachedFetch,
+        price_ticker: &str,
+    ) -> Result<AnchorOutcome, DbError> {
+        if let Err(ref err) = fetch.result {
+            self.tally.failed += indices.len();
+            if err.is_transient() {
+                return Ok(AnchorOutcome::Deferred);
+            }
+            for &idx in indices {
+                self.db
+                    .update_trade_anchor_prices(self.trades[idx].tx_id, &attempted(&self.requests[idx]))?;
+            }
+            return Ok(AnchorOutcome::Failed);
+        }
+
+        let currency = pricing::listing_currency(price_ticker);
+        let mut any_price = false;
+        let mut fx_missing = false;
+        for &idx in indices {
+            let request = &self.requests[idx];
+            let trade = &self.trades[idx];
+            let mut prices = AnchorPrices::default();
+            // Local-currency close on `date`, converted to USD, with its source.
+            let mut usd = |date: NaiveDate| {
+                fetch.price_on(date).map(|(local, source)| {
+                    let converted = pricing::to_usd(local, currency, date, self.fx_rates);
+                    prices.fx_missing |= converted.fx_missing;
+                    (converted.amount, source)
+                })
+            };
+
+            let trade_date = request.date(PriceAnchor::TradeDate).map(&mut usd);
+            let pub_date = request.date(PriceAnchor::PubDate).map(&mut usd);
+            let current = request.date(PriceAnchor::Current).map(&mut usd);
+
+            if let Some(found) = trade_date {
+                prices.trade_date = Some(match found {
+                    Some((price, source)) => {
+                        any_price = true;
+                        if source == "tiingo" {
+                            self.tally.tiingo_resolved += 1;
+                        }
+                        // Estimate shares: try range-based first, fall back to value-based
+                        let estimate =
+                            pricing::parse_trade_range(trade.size_range_low, trade.size_range_high)
+                                .and_then(|range| pricing::estimate_shares(&range, price))
+                                .or_else(|| pricing::estimate_shares_from_value(trade.value, price));
+                        if estimate.is_some() {
+                            self.tally.enriched += 1;
+                        } else {
+                            self.tally.skipped += 1;
+                        }
+                        prices.source = (!source.is_empty()).then_some(source);
+                        TradeDatePrice {
+                            price: Some(price),
+                            estimated_shares: estimate.as_ref().map(|e| e.estimated_shares),
+                            estimated_value: estimate.as_ref().map(|e| e.estimated_value),
+                        }
+                    }
+                    None => {
+                        self.tally.skipped += 1;
+                        TradeDatePrice::default()
+                    }
+                });
+            }
+            if let Some(found) = pub_date {
+                let price = found.map(|(price, _)| price);
+                self.tally.pub_enriched += usize::from(price.is_some());
+                prices.pub_date = Some(price);
+            }
+            if let Some(found) = current {
+                // Current price is best-effort; a miss is stored as no price
+                let price = found.map(|(price, _)| price);
+                if price.is_some() {
+                    any_price = true;
+                    self.tally.current_enriched += 1;
+                } else {
+                    self.tally.current_skipped += 1;
+                }
+                prices.current = Some(price);
+            }
+            fx_missing |= prices.fx_missing;
+            self.db.update_trade_anchor_prices(trade.tx_id, &prices)?;
+        }
+
+        if fx_missing {
+            self.tally.fx_unconverted.insert(price_ticker.to_string());
+        }
+        Ok(if any_price {
+            AnchorOutcome::Priced
+        } else {
+            AnchorOutcome::NoData
+        })
+    }
+}
+
+/// Every anchor `request` asked for, marked as looked up without a price.
+fn attempted(request: &AnchorRequest) -> AnchorPrices {
+    AnchorPrices {
+        trade_date: request
+            .date(PriceAnchor::TradeDate)
+            .map(|_| TradeDatePrice::default()),
+        pub_date: request.date(PriceAnchor::PubDate).map(|_| None),
+        current: request.date(PriceAnchor::Current).map(|_| None),
+        source: None,
+        fx_missing: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Db;
+    use crate::pricing::resolve_yahoo_candidates;
+    use crate::ticker_alias::TickerAlias;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn d(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn daily(date: &str, close: f64) -> serde_json::Value {
+        serde_json::json!({
+            "adjClose": close, "adjHigh": close, "adjLow": close, "adjOpen": close,
+            "adjVolume": 1000, "close": close, "date": format!("{}T00:00:00+00:00", date),
+            "divCash": 0.0, "high": close, "low": close, "open": close,
+            "splitFactor": 1.0, "volume": 1000
+        })
+    }
+
+    /// One lookup of `dates` through the cache, as `enrich-prices` runs it.
+    async fn enrich(
+        chain: &PriceFetchChain,
+        cache: PriceCache<'_>,
+        ticker: &str,
+        dates: &BTreeSet<NaiveDate>,
+    ) -> Vec<Option<f64>> {
+        let range = cache.split(ticker, dates).unwrap();
+        let fetch = range.fetch(chain, &[ticker.to_string()]).await;
+        cache.store(ticker, &fetch).unwrap();
+        dates
+            .iter()
+            .map(|date| fetch.price_on(*date).map(|(close, _)| close))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_second_run_is_served_from_price_cache() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/AAPL/prices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                daily("2024-06-10", 190.0),
+                daily("2024-06-11", 191.0),
+                daily("2024-06-12", 192.0),
+                daily("2024-06-13", 193.0),
+                daily("2024-06-14", 194.0),
+            ])))
+            .mount(&server)
+            .await;
+
+        let db = Db::open_in_memory().unwrap();
+        db.init().unwrap();
+        let cache = PriceCache::new(&db, d("2099-01-01"));
+        // A Wednesday, the Friday after it and the Sunday after that.
+        let dates = BTreeSet::from([d("2024-06-12"), d("2024-06-14"), d("2024-06-16")]);
+
+        let tiingo = || {
+            let client = TiingoClient::with_base_url(&server.uri(), "key".to_string()).unwrap();
+            PriceFetchChain::default().with_tiingo(Arc::new(client))
+        };
+        let first = enrich(&tiingo(), cache, "AAPL", &dates).await;
+        assert_eq!(first, vec![Some(192.0), Some(194.0), Some(194.0)]);
+        let after_first = server.received_requests().await.unwrap().len();
+        assert_eq!(after_first, 1);
+
+        // A new client has an empty in-memory cache; the database answers.
+        let range = cache.split("AAPL", &dates).unwrap();
+        assert!(range.missing.is_empty());
+        assert_eq!(range.cached[&d("2024-06-16")], (194.0, "tiingo".to_string()));
+        let second = enrich(&tiingo(), cache, "AAPL", &dates).await;
+        assert_eq!(second, first);
+        assert_eq!(server.received_requests().await.unwrap().len(), after_first);
+
+        // Without the cache every date is downloaded again.
+        let disabled = PriceCache::disabled(&db);
+        assert_eq!(disabled.split("AAPL", &dates).unwrap().missing, dates);
+        let third = enrich(&tiingo(), disabled, "AAPL", &dates).await;
+        assert_eq!(third, first);
+        assert_eq!(server.received_requests().await.unwrap().len(), after_first + 1);
+    }
+
+    /// Queue `(tx_id, ticker, tx_date, pub_date)` trades and read them back
+    /// as `enrich-prices` does, each asking for its trade and disclosure
+    /// dates.
+    fn queue(db: &mut Db, trades: &[(i64, &str, &str, &str)]) -> (Vec<PriceEnrichmentRow>, Vec<AnchorRequest>) {
+        for &(tx_id, ticker, tx_date, pub_date) in trades {
+            let issuer_id = ["AAPL", "MSFT", "SAP"].iter().position(|t| *t == ticker).unwrap() as i64 + 1;
+            let mut trade = crate::fixtures::scraped_trade(tx_id, "P000001", issuer_id);
+            trade.issuer.issuer_ticker = Some(ticker.to_string());
+            trade.tx_date = tx_date.to_string();
+            trade.pub_date = format!("{}T00:00:00Z", pub_date);
+            db.upsert_scraped_trades(&[trade]).unwrap();
+        }
+        let rows = db.get_unenriched_price_trades(None).unwrap();
+        let requests = rows
+            .iter()
+            .map(|row| AnchorRequest {
+                ticker: row.issuer_ticker.clone(),
+                anchors: vec![
+                    (PriceAnchor::TradeDate, d(&row.tx_date)),
+                    (PriceAnchor::PubDate, d(&row.pub_date[..10])),
+                ],
+            })
+            .collect();
+        (rows, requests)
+    }
+
+    /// Phase 1 of `enrich-prices`: one lookup per ticker, applied as it
+    /// completes.
+    async fn run_pass(
+        chain: &PriceFetchChain,
+        db: &Db,
+        trades: &[PriceEnrichmentRow],
+        requests: &[AnchorRequest],
+    ) -> (AnchorTally, BTreeMap<String, AnchorOutcome>) {
+        let fx = FxRates::new();
+        let cache = PriceCache::disabled(db);
+        let mut pass = AnchorPass::new(db, trades, requests, &fx);
+        let mut outcomes = BTreeMap::new();
+        for (ticker, work) in plan_anchors(requests) {
+            let range = cache.split(&ticker, &work.dates).unwrap();
+            let fetch = range.fetch(chain, std::slice::from_ref(&ticker)).await;
+            outcomes.insert(ticker.clone(), pass.apply(&work.requests, &fetch, &ticker).unwrap());
+        }
+        (pass.tally, outcomes)
+    }
+
+    /// Stored trade_date_price, pub_date_price, current_price, and whether
+    /// price_enriched_at is set.
+    type StoredPrices = (Option<f64>, Option<f64>, Option<f64>, bool);
+
+    fn stored_prices(db: &Db) -> BTreeMap<i64, StoredPrices> {
+        db.iter_table::<crate::db::RawTrade>(crate::db::CoreTable::Trades)
+            .map(|row| {
+                let t = row.unwrap();
+                let enriched = t.price_enriched_at.is_some();
+                (t.tx_id, (t.trade_date_price, t.pub_date_price, t.current_price, enriched))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_one_range_request_per_ticker_serves_every_anchor() {
+        let server = MockServer::start().await;
+        for (ticker, base) in [("AAPL", 100.0), ("MSFT", 400.0)] {
+            let closes: Vec<_> = (3..=14)
+                .filter(|day| ![8, 9].contains(day))
+                .map(|day| daily(&format!("2024-06-{:02}", day), base + day as f64))
+                .collect();
+            Mock::given(method("GET"))
+                .and(path(format!("/tiingo/daily/{}/prices", ticker)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(closes))
+                .mount(&server)
+                .await;
+        }
+        let client = TiingoClient::with_base_url(&server.uri(), "key".to_string()).unwrap();
+        let chain = PriceFetchChain::default().with_tiingo(Arc::new(client));
+
+        let mut db = crate::fixtures::open_db();
+        let (trades, requests) = queue(
+            &mut db,
+            &[
+                (1, "AAPL", "2024-06-04", "2024-06-12"),
+                (2, "MSFT", "2024-06-05", "2024-06-14"),
+                // Disclosed on a Sunday: priced at Friday's close.
+                (3, "AAPL", "2024-06-06", "2024-06-16"),
+                (4, "MSFT", "2024-06-05", "2024-06-13"),
+            ],
+        );
+        let plan = plan_anchors(&requests);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan["AAPL"].requests, vec![0, 2]);
+        assert_eq!(plan["MSFT"].dates.len(), 3);
+
+        let (tally, outcomes) = run_pass(&chain, &db, &trades, &requests).await;
+        assert!(outcomes.values().all(|o| *o == AnchorOutcome::Priced));
+        assert_eq!((tally.enriched, tally.pub_enriched, tally.tiingo_resolved), (4, 4, 4));
+        let stored = stored_prices(&db);
+        assert_eq!(stored[&1], (Some(104.0), Some(112.0), None, true));
+        assert_eq!(stored[&2], (Some(405.0), Some(414.0), None, true));
+        assert_eq!(stored[&3], (Some(106.0), Some(114.0), None, true));
+        assert_eq!(stored[&4], (Some(405.0), Some(413.0), None, true));
+        assert!(db.get_unenriched_price_trades(None).unwrap().is_empty());
+
+        let requests = server.received_requests().await.unwrap();
+        let mut paths: Vec<_> = requests.iter().map(|r| r.url.path().to_string()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec!["/tiingo/daily/AAPL/prices", "/tiingo/daily/MSFT/prices"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_anchor_pass_outcomes_drive_breaker_and_requeue() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/AAPL/prices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([daily("2024-06-14", 194.0)])),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/MSFT/prices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("Error: Rate limit exceeded.")
+                    .insert_header("content-type", "text/plain"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/SAP/prices"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+            .mount(&server)
+            .await;
+        let client = TiingoClient::with_base_url(&server.uri(), "key".to_string()).unwrap();
+        let chain = PriceFetchChain::default().with_tiingo(Arc::new(client));
+
+        let mut db = crate::fixtures::open_db();
+        let (trades, mut requests) = queue(
+            &mut db,
+            &[
+                (1, "AAPL", "2024-06-14", "2024-06-14"),
+                (2, "MSFT", "2024-06-14", "2024-06-14"),
+                (3, "SAP", "2024-06-14", "2024-06-14"),
+            ],
+        );
+        // A current-price refresh: the only anchor left is today's.
+        requests[0].anchors = vec![(PriceAnchor::Current, d("2024-06-14"))];
+
+        let (tally, outcomes) = run_pass(&chain, &db, &trades, &requests).await;
+        // A current price alone counts as a success for the circuit breaker.
+        assert_eq!(outcomes["AAPL"], AnchorOutcome::Priced);
+        assert_eq!(tally.current_enriched, 1);
+        assert_eq!(tally.enriched, 0);
+        // A rate limit leaves the trade queued; a broken response does not.
+        assert_eq!(outcomes["MSFT"], AnchorOutcome::Deferred);
+        assert_eq!(outcomes["SAP"], AnchorOutcome::Failed);
+        assert_eq!(tally.failed, 2);
+
+        let stored = stored_prices(&db);
+        assert_eq!(stored[&1], (None, None, Some(194.0), true));
+        assert_eq!(stored[&2], (None, None, None, false));
+        assert_eq!(stored[&3], (None, None, None, true));
+        let queued: Vec<i64> = db
+            .get_unenriched_price_trades(None)
+            .unwrap()
+            .iter()
+            .map(|t| t.tx_id)
+            .collect();
+        assert_eq!(queued, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_suffix_found_by_trial_is_persisted() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/SAP/prices"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/SAP.DE/prices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([daily("2024-06-14", 170.0)])),
+            )
+            .mount(&server)
+            .await;
+        let tiingo = || {
+            let client = TiingoClient::with_base_url(&server.uri(), "key".to_string()).unwrap();
+            PriceFetchChain::default().with_tiingo(Arc::new(client))
+        };
+        let db = Db::open_in_memory().unwrap();
+        db.init().unwrap();
+        let suffixes = crate::exchange_suffix::ExchangeSuffixes::bundled().unwrap();
+        let dates = BTreeSet::from([d("2024-06-14")]);
+
+        // As enrich-prices does it: try the candidates, keep the one that
+        // answered as an alias for the raw ticker.
+        let aliases = std::collections::HashMap::new();
+        let candidates = resolve_yahoo_candidates("SAP:US", &aliases, Some("de"), None, &suffixes);
+        let (idx, result) = tiingo().fetch_range_candidates(&candidates, &dates).await;
+        assert_eq!(candidates[idx], "SAP.DE");
+        assert_eq!(result.unwrap().price_on(d("2024-06-14")), Some(170.0));
+        assert!(db.record_suffix_alias("SAP:US", &candidates[idx]).unwrap());
+        assert_eq!(
+            db.get_ticker_aliases().unwrap(),
+            vec![TickerAlias {
+                from: "SAP:US".to_string(),
+                to: Some("SAP.DE".to_string()),
+                source: "suffix".to_string(),
+            }]
+        );
+        let requests = server.received_requests().await.unwrap().len();
+        assert_eq!(requests, 2);
+
+        // The next run (a new client, so nothing is cached in memory) goes
+        // straight to the suffixed listing.
+        let aliases: std::collections::HashMap<_, _> = db
+            .get_ticker_aliases()
+            .unwrap()
+            .into_iter()
+            .map(|a| (a.from, a.to))
+            .collect();
+        let candidates = resolve_yahoo_candidates("SAP:US", &aliases, Some("de"), None, &suffixes);
+        assert_eq!(candidates, vec!["SAP.DE"]);
+        let (_, result) = tiingo().fetch_range_candidates(&candidates, &dates).await;
+        assert!(result.is_ok());
+        assert_eq!(server.received_requests().await.unwrap().len(), requests + 1);
+        assert_eq!(db.get_enrichment_diagnostics().unwrap().suffix_resolved_tickers, 1);
+
+        // An imported alias is never replaced by a suffix trial.
+        assert!(!db.record_suffix_alias("SAP:US", "SAP.F").unwrap());
+    }
+
+    #[test]
+    fn test_price_on_respects_lookback() {
+        let range = RangeFetch {
+            source: Some("yahoo"),
+            closes: vec![(d("2024-05-01"), 10.0), (d("2024-06-14"), 20.0)],
+        };
+        assert_eq!(range.price_on(d("2024-06-17")), Some(20.0));
+        // The May close is too old to stand in for a June date.
+        assert_eq!(range.price_on(d("2024-06-10")), None);
+        assert_eq!(range.price_on(d("2024-04-30")), None);
+    }
+
+    #[tokio::test]
+    async fn test_tiingo_only_chain_surfaces_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&server)
+            .await;
+        let client = TiingoClient::with_base_url(&server.uri(), "bad".to_string()).unwrap();
+        let chain = PriceFetchChain::default().with_tiingo(Arc::new(client));
+        assert!(matches!(
+            chain.fetch("AAPL", d("2024-06-14")).await,
+            Err(PriceFetchError::Tiingo(TiingoError::InvalidApiKey))
+        ));
+        assert_eq!(
+            PriceFetchChain::default().fetch("AAPL", d("2024-06-14")).await.unwrap(),
+            PriceFetch::default()
+        );
+    }
+}
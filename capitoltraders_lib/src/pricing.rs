@@ -3,6 +3,13 @@
 //! This module provides primitives for estimating share counts from dollar ranges
 //! and historical prices. It does NOT validate tickers or run batch processing --
 //! those concerns belong to the enrichment pipeline (Phase 4).
+//!
+//! It also converts prices quoted in an issuer's listing currency into USD
+//! (see [`to_usd`]), since Yahoo returns foreign listings in local currency.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::NaiveDate;
 
 /// A dollar range extracted from trade data.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -113,6 +120,53 @@ pub fn estimate_shares_from_value(value: i64, trade_date_price: f64) -> Option<S
     })
 }
 
+/// Asset types valued at face value rather than from an equity price.
+///
+/// Bonds and treasuries have no share price on Yahoo (a bond's ticker, when
+/// it has one, usually belongs to the issuer's stock), so dividing their
+/// dollar range by a quote produces meaningless share counts.
+pub const FIXED_INCOME_ASSET_TYPES: &[&str] =
+    &["corporate-bond", "government-bond", "municipal-security"];
+
+/// Whether trades of `asset_type` are valued at face value.
+pub fn is_fixed_income(asset_type: &str) -> bool {
+    FIXED_INCOME_ASSET_TYPES.contains(&asset_type)
+}
+
+/// How a trade's `estimated_value` was derived, stored in
+/// `trades.valuation_method`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValuationMethod {
+    /// Dollar midpoint divided by the trade-date share price.
+    EquityPrice,
+    /// Dollar midpoint carried directly, with no share estimate.
+    FaceValue,
+    /// Enrichment ran but produced no estimate.
+    None,
+}
+
+impl ValuationMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValuationMethod::EquityPrice => "equity_price",
+            ValuationMethod::FaceValue => "face_value",
+            ValuationMethod::None => "none",
+        }
+    }
+}
+
+/// Face-value estimate for a fixed-income trade: the midpoint of its dollar
+/// range, or its `value` when the range is missing.
+///
+/// Returns None when neither yields a positive amount.
+pub fn face_value_estimate(range: Option<TradeRange>, value: i64) -> Option<f64> {
+    match range {
+        Some(range) => Some(range.midpoint()),
+        None if value > 0 => Some(value as f64),
+        None => None,
+    }
+}
+
 /// Resolve a raw CapitolTrades ticker to a Yahoo Finance ticker using aliases.
 ///
 /// Checks the alias map first (both the raw ticker and its base without suffix),
@@ -142,6 +196,175 @@ pub fn resolve_yahoo_ticker(
     normalize_ticker_for_yahoo(raw)
 }
 
+/// Yahoo tickers to try, in order, for a raw CapitolTrades ticker whose
+/// issuer is listed in `country` under the c2iq code `c2iq`.
+///
+/// An alias always wins and is the only candidate, so ADRs that trade in
+/// the US under their own symbol never get suffixed. Otherwise a c2iq code
+/// on a known exchange ([`c2iq_yahoo_ticker`]) names the listing and goes
+/// first, but only when its symbol matches the raw ticker's or the raw
+/// ticker is not a US listing: a US ticker whose c2iq points at another
+/// symbol (an ADR such as `TOYOF:US` with `7203:JP`) is priced as the US
+/// listing it is. The heuristics follow: [`resolve_yahoo_ticker`]'s answer, then,
+/// when that is a bare symbol (no Bloomberg exchange suffix other than
+/// `:US`) and the country has exchange suffixes, `<symbol>.<suffix>` for
+/// each of them.
+///
+/// Returns an empty list where [`resolve_yahoo_ticker`] returns `None`.
+pub fn resolve_yahoo_candidates(
+    raw: &str,
+    aliases: &std::collections::HashMap<String, Option<String>>,
+    country: Option<&str>,
+    c2iq: Option<&str>,
+    suffixes: &crate::exchange_suffix::ExchangeSuffixes,
+) -> Vec<String> {
+    let Some(bare) = resolve_yahoo_ticker(raw, aliases) else {
+        return Vec::new();
+    };
+    let trimmed = raw.trim();
+    let aliased = aliases.contains_key(raw) || aliases.contains_key(trimmed);
+    if aliased {
+        return vec![bare];
+    }
+
+    let (symbol, exchange) = match trimmed.rsplit_once(':') {
+        Some((symbol, exchange)) => (symbol, Some(exchange.trim().to_uppercase())),
+        None => (trimmed, None),
+    };
+    let listed_abroad = exchange.as_deref().is_some_and(|s| s != "US");
+    let symbol = symbol.trim().to_uppercase();
+    let mut candidates: Vec<String> = c2iq
+        .filter(|code| {
+            listed_abroad || parse_c2iq(code).is_some_and(|(c2iq_symbol, _)| c2iq_symbol == symbol)
+        })
+        .and_then(c2iq_yahoo_ticker)
+        .into_iter()
+        .collect();
+    let mut heuristics = vec![bare.clone()];
+    if let (Some(country), false) = (country, listed_abroad || trimmed.starts_with("$$")) {
+        heuristics.extend(
+            suffixes
+                .for_country(country)
+                .iter()
+                .map(|suffix| format!("{}.{}", bare, suffix)),
+        );
+    }
+    for ticker in heuristics {
+        if !candidates.contains(&ticker) {
+            candidates.push(ticker);
+        }
+    }
+    candidates
+}
+
+/// Split an issuer's c2iq code (`AAPL:US`, `SHEL:LN`, `7203:JP`) into its
+/// symbol and Bloomberg exchange code, both upper-cased.
+///
+/// Returns `None` for anything else: no colon, an empty symbol or one with
+/// spaces, or an exchange code that is not two letters.
+pub fn parse_c2iq(code: &str) -> Option<(String, String)> {
+    let (symbol, exchange) = code.trim().rsplit_once(':')?;
+    let (symbol, exchange) = (symbol.trim(), exchange.trim());
+    if symbol.is_empty() || symbol.contains(':') || symbol.contains(char::is_whitespace) {
+        return None;
+    }
+    if exchange.len() != 2 || !exchange.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((symbol.to_uppercase(), exchange.to_uppercase()))
+}
+
+/// Where listings on one Bloomberg exchange are priced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeRoute {
+    /// Yahoo suffix (without the dot); `None` for US listings.
+    pub yahoo_suffix: Option<&'static str>,
+    /// Whether Tiingo carries the listing. Tiingo only prices US markets.
+    pub tiingo: bool,
+}
+
+/// Price routing for a Bloomberg exchange code, or `None` for codes this
+/// table does not know (callers fall back to ticker heuristics).
+pub fn exchange_route(exchange: &str) -> Option<ExchangeRoute> {
+    let suffix = match exchange.trim().to_uppercase().as_str() {
+        // US composite and its venues: NYSE, Nasdaq, NYSE American, Arca, OTC
+        "US" | "UN" | "UW" | "UQ" | "UR" | "UA" | "UP" | "UF" | "UV" | "PQ" => {
+            return Some(ExchangeRoute {
+                yahoo_suffix: None,
+                tiingo: true,
+            })
+        }
+        "LN" => "L",
+        "JP" | "JT" => "T",
+        "HK" => "HK",
+        "GR" | "GY" => "DE",
+        "GF" => "F",
+        "FP" => "PA",
+        "NA" => "AS",
+        "BB" => "BR",
+        "IM" => "MI",
+        "SM" => "MC",
+        "ID" => "IR",
+        "FH" => "HE",
+        "SW" | "SE" | "VX" => "SW",
+        "SS" => "ST",
+        "DC" => "CO",
+        "NO" => "OL",
+        "CN" | "CT" => "TO",
+        "CV" => "V",
+        "AU" | "AT" => "AX",
+        "NZ" => "NZ",
+        "CH" | "CG" => "SS",
+        "CS" => "SZ",
+        "SP" => "SI",
+        "KS" => "KS",
+        "KQ" => "KQ",
+        "TT" => "TW",
+        "IN" | "IS" => "NS",
+        "IB" => "BO",
+        "BZ" => "SA",
+        "MM" => "MX",
+        "SJ" => "JO",
+        "IT" => "TA",
+        _ => return None,
+    };
+    Some(ExchangeRoute {
+        yahoo_suffix: Some(suffix),
+        tiingo: false,
+    })
+}
+
+/// The Yahoo ticker a c2iq code names (`SHEL:LN` -> `SHEL.L`,
+/// `BRK/B:US` -> `BRK-B`), when the code parses and its exchange is in
+/// [`exchange_route`]'s table.
+pub fn c2iq_yahoo_ticker(c2iq: &str) -> Option<String> {
+    let (symbol, exchange) = parse_c2iq(c2iq)?;
+    let route = exchange_route(&exchange)?;
+    let symbol = symbol.replace(['/', '.'], "-");
+    Some(match route.yahoo_suffix {
+        Some(suffix) => format!("{}.{}", symbol, suffix),
+        None => symbol,
+    })
+}
+
+/// Whether a user-supplied ticker names a stored CapitolTrades ticker.
+///
+/// Matching is case-insensitive. A query with an exchange suffix must match
+/// exactly; a bare symbol ("NVDA") also matches the stored suffixed form
+/// ("NVDA:US").
+pub fn ticker_matches(query: &str, stored: &str) -> bool {
+    let (query, stored) = (query.trim(), stored.trim());
+    if query.is_empty() {
+        return false;
+    }
+    query.eq_ignore_ascii_case(stored)
+        || (!query.contains(':')
+            && stored
+                .split(':')
+                .next()
+                .is_some_and(|base| base.eq_ignore_ascii_case(query)))
+}
+
 /// Normalize a CapitolTrades ticker to Yahoo Finance format.
 ///
 /// CapitolTrades uses Bloomberg-style exchange suffixes (e.g., `MSFT:US`).
@@ -169,7 +392,7 @@ pub fn normalize_ticker_for_yahoo(ticker: &str) -> Option<String> {
             return None;
         }
         // Replace slash or dot with dash for share classes (BRK/B -> BRK-B, CWEN.A -> CWEN-A)
-        let base = base.replace('/', "-").replace('.', "-");
+        let base = base.replace(['/', '.'], "-");
         match suffix.trim().to_uppercase().as_str() {
             "US" => Some(base),
             "LN" => Some(format!("{}.L", base)),
@@ -183,14 +406,183 @@ pub fn normalize_ticker_for_yahoo(ticker: &str) -> Option<String> {
         }
     } else {
         // No suffix -- replace slash/dot and use as-is
-        Some(trimmed.replace('/', "-").replace('.', "-"))
+        Some(trimmed.replace(['/', '.'], "-"))
+    }
+}
+
+/// Currency all stored trade and portfolio values are expressed in.
+pub const BASE_CURRENCY: &str = "USD";
+
+/// How many days an FX rate stays usable for later dates without a quote
+/// (weekends, market holidays).
+pub const FX_MAX_STALENESS_DAYS: i64 = 7;
+
+/// Listing currency for a Yahoo ticker, inferred from its exchange suffix.
+///
+/// London listings are quoted in pence (`GBp`). Unsuffixed tickers and
+/// crypto pairs (`BTC-USD`) are treated as USD.
+pub fn listing_currency(yahoo_ticker: &str) -> &'static str {
+    let Some((_, suffix)) = yahoo_ticker.rsplit_once('.') else {
+        return BASE_CURRENCY;
+    };
+    match suffix.to_uppercase().as_str() {
+        "L" => "GBp",
+        "HK" => "HKD",
+        "ST" => "SEK",
+        "SI" => "SGD",
+        "NZ" => "NZD",
+        "SS" | "SZ" => "CNY",
+        "TO" | "V" => "CAD",
+        "AX" => "AUD",
+        "T" => "JPY",
+        "PA" | "DE" | "F" | "AS" | "BR" | "MI" | "MC" | "IR" | "HE" => "EUR",
+        "SW" => "CHF",
+        "CO" => "DKK",
+        "OL" => "NOK",
+        "KS" | "KQ" => "KRW",
+        "TW" => "TWD",
+        "NS" | "BO" => "INR",
+        "SA" => "BRL",
+        "MX" => "MXN",
+        "JO" => "ZAc",
+        "TA" => "ILA",
+        _ => BASE_CURRENCY,
+    }
+}
+
+/// Split minor-unit currency codes into their major currency and the factor
+/// that converts an amount into it (`GBp` pence -> `GBP` at 0.01).
+pub fn major_currency(currency: &str) -> (String, f64) {
+    match currency {
+        "GBp" | "GBX" => ("GBP".to_string(), 0.01),
+        "ZAc" | "ZAC" => ("ZAR".to_string(), 0.01),
+        "ILA" => ("ILS".to_string(), 0.01),
+        other => (other.to_uppercase(), 1.0),
+    }
+}
+
+/// Supplies daily exchange rates.
+pub trait FxSource {
+    /// USD value of one unit of `currency` (ISO code, e.g. `GBP`) on `date`,
+    /// or `None` when no rate is known.
+    fn usd_per_unit(&self, currency: &str, date: NaiveDate) -> Option<f64>;
+}
+
+/// In-memory table of daily FX rates, filled from a provided file or from
+/// quotes fetched ahead of a conversion run.
+#[derive(Debug, Clone, Default)]
+pub struct FxRates {
+    rates: HashMap<String, BTreeMap<NaiveDate, f64>>,
+}
+
+impl FxRates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the USD value of one unit of `currency` (a major currency code,
+    /// see [`major_currency`]) on `date`. Non-positive rates are ignored.
+    pub fn insert(&mut self, currency: &str, date: NaiveDate, usd_per_unit: f64) {
+        if usd_per_unit > 0.0 && usd_per_unit.is_finite() {
+            self.rates
+                .entry(currency.to_uppercase())
+                .or_default()
+                .insert(date, usd_per_unit);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+}
+
+impl FxSource for FxRates {
+    /// Uses the rate on `date`, or the latest earlier rate no more than
+    /// [`FX_MAX_STALENESS_DAYS`] old.
+    fn usd_per_unit(&self, currency: &str, date: NaiveDate) -> Option<f64> {
+        let (found, rate) = self
+            .rates
+            .get(&currency.to_uppercase())?
+            .range(..=date)
+            .next_back()?;
+        ((date - *found).num_days() <= FX_MAX_STALENESS_DAYS).then_some(*rate)
     }
 }
 
+/// Result of a currency conversion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Converted {
+    /// Converted amount, or the original amount when `fx_missing` is set.
+    pub amount: f64,
+    /// True when no rate was available and `amount` is still in the source
+    /// currency.
+    pub fx_missing: bool,
+}
+
+/// Convert `amount` from `from` to `to` on `date`, crossing through USD.
+///
+/// Same-currency conversions return the amount untouched. When either leg
+/// has no rate the amount comes back unconverted with `fx_missing` set.
+pub fn convert(
+    amount: f64,
+    from: &str,
+    to: &str,
+    date: NaiveDate,
+    fx: &dyn FxSource,
+) -> Converted {
+    let (from_major, from_factor) = major_currency(from);
+    let (to_major, to_factor) = major_currency(to);
+    if from_major == to_major {
+        return Converted {
+            amount: amount * from_factor / to_factor,
+            fx_missing: false,
+        };
+    }
+    let usd_rate = |currency: &str| {
+        if currency == BASE_CURRENCY {
+            Some(1.0)
+        } else {
+            fx.usd_per_unit(currency, date)
+        }
+    };
+    match (usd_rate(&from_major), usd_rate(&to_major)) {
+        (Some(from_rate), Some(to_rate)) => Converted {
+            amount: amount * from_factor * from_rate / (to_rate * to_factor),
+            fx_missing: false,
+        },
+        _ => Converted {
+            amount,
+            fx_missing: true,
+        },
+    }
+}
+
+/// Convert `amount` quoted in `currency` on `date` into USD.
+pub fn to_usd(amount: f64, currency: &str, date: NaiveDate, fx: &dyn FxSource) -> Converted {
+    convert(amount, currency, BASE_CURRENCY, date, fx)
+}
+
+/// Yahoo symbol quoting the USD value of one unit of `currency`
+/// (`GBP` -> `GBPUSD=X`).
+pub fn fx_pair_ticker(currency: &str) -> String {
+    format!("{}{}=X", major_currency(currency).0, BASE_CURRENCY)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ticker_matches_base_symbol() {
+        assert!(ticker_matches("NVDA", "NVDA:US"));
+        assert!(ticker_matches("nvda", "NVDA:US"));
+        assert!(ticker_matches("NVDA:US", "nvda:us"));
+        assert!(ticker_matches("NVDA", "NVDA"));
+        assert!(!ticker_matches("NVDA:LN", "NVDA:US"));
+        assert!(!ticker_matches("NVD", "NVDA:US"));
+        assert!(!ticker_matches("", ":US"));
+    }
+
     #[test]
     fn test_parse_range_both_bounds() {
         let range = parse_trade_range(Some(15001), Some(50000)).unwrap();
@@ -302,6 +694,22 @@ mod tests {
 
     // --- normalize_ticker_for_yahoo tests ---
 
+    #[test]
+    fn test_face_value_estimate_prefers_range_midpoint() {
+        let range = parse_trade_range(Some(15_001), Some(50_000));
+        assert_eq!(face_value_estimate(range, 32_500), Some(32_500.5));
+        assert_eq!(face_value_estimate(None, 8_000), Some(8_000.0));
+        assert_eq!(face_value_estimate(None, 0), None);
+    }
+
+    #[test]
+    fn test_is_fixed_income() {
+        assert!(is_fixed_income("government-bond"));
+        assert!(is_fixed_income("corporate-bond"));
+        assert!(!is_fixed_income("stock"));
+        assert!(!is_fixed_income("unknown"));
+    }
+
     #[test]
     fn normalize_us_ticker() {
         assert_eq!(normalize_ticker_for_yahoo("MSFT:US"), Some("MSFT".into()));
@@ -450,6 +858,109 @@ mod tests {
         assert_eq!(resolve_yahoo_ticker("FLT:US ", &aliases), Some("CPAY".to_string()));
     }
 
+    #[test]
+    fn candidates_add_country_suffixes_after_bare_symbol() {
+        let suffixes = crate::exchange_suffix::ExchangeSuffixes::bundled().unwrap();
+        let aliases = test_aliases();
+        assert_eq!(
+            resolve_yahoo_candidates("SAP:US", &aliases, Some("de"), None, &suffixes),
+            vec!["SAP", "SAP.DE", "SAP.F"]
+        );
+        assert_eq!(
+            resolve_yahoo_candidates("AAPL:US", &aliases, Some("us"), None, &suffixes),
+            vec!["AAPL"]
+        );
+        assert_eq!(
+            resolve_yahoo_candidates("AAPL:US", &aliases, None, None, &suffixes),
+            vec!["AAPL"]
+        );
+    }
+
+    #[test]
+    fn candidates_prefer_aliases_and_explicit_exchanges() {
+        let suffixes = crate::exchange_suffix::ExchangeSuffixes::bundled().unwrap();
+        let mut aliases = test_aliases();
+        aliases.insert("TOYOF:US".to_string(), Some("TM".to_string()));
+        // An ADR alias is never suffixed.
+        assert_eq!(
+            resolve_yahoo_candidates("TOYOF:US", &aliases, Some("jp"), None, &suffixes),
+            vec!["TM"]
+        );
+        // A Bloomberg exchange code already picks the listing.
+        assert_eq!(
+            resolve_yahoo_candidates("SHEL:LN", &aliases, Some("gb"), None, &suffixes),
+            vec!["SHEL.L"]
+        );
+        assert!(resolve_yahoo_candidates("ATVI:US", &aliases, Some("jp"), None, &suffixes).is_empty());
+    }
+
+    #[test]
+    fn parse_c2iq_splits_symbol_and_exchange() {
+        assert_eq!(parse_c2iq("AAPL:US"), Some(("AAPL".to_string(), "US".to_string())));
+        assert_eq!(parse_c2iq(" shel:ln "), Some(("SHEL".to_string(), "LN".to_string())));
+        assert_eq!(parse_c2iq("7203:JP"), Some(("7203".to_string(), "JP".to_string())));
+        assert_eq!(parse_c2iq("BRK/B:US"), Some(("BRK/B".to_string(), "US".to_string())));
+    }
+
+    #[test]
+    fn parse_c2iq_rejects_malformed_codes() {
+        for bad in ["", "AAPL", ":US", "AAPL:", "AAPL:USA", "AAPL:U1", "A B:US", "A:B:US", "é:US:"] {
+            assert_eq!(parse_c2iq(bad), None, "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn c2iq_routes_to_yahoo_suffix_and_tiingo_market() {
+        assert_eq!(c2iq_yahoo_ticker("AAPL:US").as_deref(), Some("AAPL"));
+        assert_eq!(c2iq_yahoo_ticker("BRK/B:UN").as_deref(), Some("BRK-B"));
+        assert_eq!(c2iq_yahoo_ticker("SHEL:LN").as_deref(), Some("SHEL.L"));
+        assert_eq!(c2iq_yahoo_ticker("7203:JP").as_deref(), Some("7203.T"));
+        assert_eq!(c2iq_yahoo_ticker("XYZ:ZZ"), None);
+        assert_eq!(c2iq_yahoo_ticker("garbage"), None);
+
+        assert!(exchange_route("US").unwrap().tiingo);
+        assert!(!exchange_route("LN").unwrap().tiingo);
+        assert_eq!(exchange_route("jp").unwrap().yahoo_suffix, Some("T"));
+    }
+
+    #[test]
+    fn candidates_try_c2iq_listing_before_heuristics() {
+        let suffixes = crate::exchange_suffix::ExchangeSuffixes::bundled().unwrap();
+        let mut aliases = test_aliases();
+        // A US ticker whose c2iq names another symbol is the US ADR, not
+        // the Tokyo listing: heuristics only.
+        assert_eq!(
+            resolve_yahoo_candidates("TOYOF:US", &aliases, Some("jp"), Some("7203:JP"), &suffixes),
+            vec!["TOYOF", "TOYOF.T"]
+        );
+        // Matching symbols: the c2iq listing goes first.
+        assert_eq!(
+            resolve_yahoo_candidates("BRK/B:US", &aliases, Some("us"), Some("BRK/B:US"), &suffixes),
+            vec!["BRK-B"]
+        );
+        // A ticker listed abroad takes its c2iq listing even under another symbol.
+        assert_eq!(
+            resolve_yahoo_candidates("RDSA:LN", &aliases, Some("gb"), Some("SHEL:LN"), &suffixes),
+            vec!["SHEL.L", "RDSA.L"]
+        );
+        // London: the same listing the heuristics find, tried once.
+        assert_eq!(
+            resolve_yahoo_candidates("SHEL:LN", &aliases, Some("gb"), Some("SHEL:LN"), &suffixes),
+            vec!["SHEL.L"]
+        );
+        // A malformed code leaves the heuristics alone.
+        assert_eq!(
+            resolve_yahoo_candidates("SAP:US", &aliases, Some("de"), Some("SAP"), &suffixes),
+            vec!["SAP", "SAP.DE", "SAP.F"]
+        );
+        // Aliases still win.
+        aliases.insert("TOYOF:US".to_string(), Some("TM".to_string()));
+        assert_eq!(
+            resolve_yahoo_candidates("TOYOF:US", &aliases, Some("jp"), Some("7203:JP"), &suffixes),
+            vec!["TM"]
+        );
+    }
+
     // --- estimate_shares_from_value tests ---
 
     #[test]
@@ -486,4 +997,69 @@ mod tests {
         assert!((result.estimated_shares - 10000.0).abs() < 0.01);
         assert!((result.estimated_value - 5000.0).abs() < 0.01);
     }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, d).unwrap()
+    }
+
+    struct NoRates;
+
+    impl FxSource for NoRates {
+        fn usd_per_unit(&self, _currency: &str, _date: NaiveDate) -> Option<f64> {
+            panic!("USD amounts must not consult the FX source");
+        }
+    }
+
+    #[test]
+    fn test_to_usd_is_noop_for_usd() {
+        let converted = to_usd(123.45, "USD", day(4), &NoRates);
+        assert_eq!(
+            converted,
+            Converted {
+                amount: 123.45,
+                fx_missing: false
+            }
+        );
+        assert_eq!(listing_currency("AAPL"), "USD");
+        assert_eq!(listing_currency("BTC-USD"), "USD");
+        assert_eq!(listing_currency("BRK-B"), "USD");
+    }
+
+    #[test]
+    fn test_to_usd_converts_minor_units_and_uses_recent_rate() {
+        let mut fx = FxRates::new();
+        fx.insert("GBP", day(1), 1.25);
+        fx.insert("HKD", day(1), 0.128);
+
+        // Saturday the 2nd falls back to Friday's rate.
+        let pence = to_usd(2000.0, listing_currency("VOD.L"), day(2), &fx);
+        assert!(!pence.fx_missing);
+        assert!((pence.amount - 25.0).abs() < 1e-9);
+
+        let hkd = to_usd(100.0, "HKD", day(1), &fx);
+        assert!((hkd.amount - 12.8).abs() < 1e-9);
+
+        // Base currency other than USD crosses through USD.
+        let gbp = convert(12.8, "USD", "GBP", day(1), &fx);
+        assert!((gbp.amount - 10.24).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_to_usd_missing_rate_leaves_amount_flagged() {
+        let mut fx = FxRates::new();
+        fx.insert("GBP", day(1), 1.25);
+
+        let stale = to_usd(500.0, "GBP", day(20), &fx);
+        assert_eq!(
+            stale,
+            Converted {
+                amount: 500.0,
+                fx_missing: true
+            }
+        );
+        let unknown = to_usd(500.0, "SEK", day(1), &fx);
+        assert!(unknown.fx_missing);
+        assert_eq!(unknown.amount, 500.0);
+        assert_eq!(fx_pair_ticker("GBp"), "GBPUSD=X");
+    }
 }
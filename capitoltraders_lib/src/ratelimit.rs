@@ -0,0 +1,344 @@
+//! Request pacing shared by the network clients.
+//!
+//! A [`Limiter`] keeps one token bucket per host. Each bucket holds up to
+//! `burst` tokens and refills evenly, one token every `period / burst`, so a
+//! [`Rate`] of 900 per hour allows 900 back-to-back requests and then one
+//! every four seconds. Buckets are tracked with the GCRA formulation (a single
+//! "theoretical arrival time" per host), which behaves like a token bucket
+//! without floating-point refill arithmetic.
+//!
+//! `ScrapeClient`, `YahooClient`, `TiingoClient` and `OpenFecClient` each
+//! take a limiter with a per-client default rate. Passing one
+//! `Arc<Limiter>` to several clients makes them draw from the same buckets,
+//! so concurrent commands hitting the same host are paced together while
+//! different hosts never wait on each other.
+//!
+//! Time comes from `tokio::time`, so tests can drive it with a paused clock.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+use crate::observer::{ObserverSlot, Service};
+
+/// Allowed request rate for one host: `burst` requests per `period`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rate {
+    burst: u32,
+    period: Duration,
+}
+
+impl Rate {
+    /// `burst` requests per `period`, refilled evenly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero or `period` is zero.
+    pub const fn new(burst: u32, period: Duration) -> Self {
+        assert!(burst > 0, "rate burst must be at least 1");
+        assert!(!period.is_zero(), "rate period must be non-zero");
+        Self { burst, period }
+    }
+
+    /// `n` requests per second, with a burst of `n`.
+    pub fn per_second(n: u32) -> Self {
+        Self::new(n, Duration::from_secs(1))
+    }
+
+    /// `n` requests per hour, with a burst of `n`.
+    pub fn per_hour(n: u32) -> Self {
+        Self::new(n, Duration::from_secs(3600))
+    }
+
+    /// Maximum number of requests admitted without waiting.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Time to refill a full bucket.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Steady-state spacing between requests once the burst is spent.
+    pub fn interval(&self) -> Duration {
+        self.period / self.burst
+    }
+
+    fn tolerance(&self) -> Duration {
+        self.interval() * (self.burst - 1)
+    }
+}
+
+/// Per-host token-bucket rate limiter.
+///
+/// Hosts without an explicit rate use the default rate; a limiter built with
+/// [`Limiter::unlimited`] admits those hosts immediately.
+#[derive(Debug, Default)]
+pub struct Limiter {
+    default_rate: Option<Rate>,
+    host_rates: HashMap<String, Rate>,
+    /// Theoretical arrival time of the next request, per host.
+    buckets: Mutex<HashMap<String, Instant>>,
+}
+
+impl Limiter {
+    /// Apply `rate` to every host.
+    pub fn new(rate: Rate) -> Self {
+        Self {
+            default_rate: Some(rate),
+            ..Self::default()
+        }
+    }
+
+    /// A limiter that never waits unless a host rate is added.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Override the rate for one host (as returned by [`host_key`]).
+    pub fn with_host_rate(mut self, host: impl Into<String>, rate: Rate) -> Self {
+        self.host_rates.insert(host.into(), rate);
+        self
+    }
+
+    /// The rate applied to `host`, or `None` when it is unlimited.
+    pub fn rate_for(&self, host: &str) -> Option<Rate> {
+        self.host_rates.get(host).copied().or(self.default_rate)
+    }
+
+    /// Wait until `host` has a token, take it, and return how long the call
+    /// waited.
+    ///
+    /// Cancel-safe: a token is only taken at the moment the call returns, so
+    /// dropping the future (a `tokio::time::timeout`, a losing
+    /// `tokio::select!` branch, an aborted task) leaves the bucket untouched.
+    pub async fn acquire(&self, host: &str) -> Duration {
+        let Some(rate) = self.rate_for(host) else {
+            return Duration::ZERO;
+        };
+        let started = Instant::now();
+        let mut waited = false;
+        loop {
+            match self.try_take(host, rate, Instant::now()) {
+                Ok(()) if !waited => return Duration::ZERO,
+                Ok(()) => return started.elapsed(),
+                Err(wait) => {
+                    waited = true;
+                    sleep(wait).await;
+                }
+            }
+        }
+    }
+
+    /// Tokens `host` could spend right now without waiting. Unlimited hosts
+    /// report `u32::MAX`.
+    pub fn available(&self, host: &str) -> u32 {
+        let Some(rate) = self.rate_for(host) else {
+            return u32::MAX;
+        };
+        let now = Instant::now();
+        let buckets = self.buckets.lock().expect("limiter lock poisoned");
+        let backlog = buckets
+            .get(host)
+            .map_or(Duration::ZERO, |&tat| tat.saturating_duration_since(now));
+        match rate.tolerance().checked_sub(backlog) {
+            Some(slack) => (slack.as_nanos() / rate.interval().as_nanos()) as u32 + 1,
+            None => 0,
+        }
+    }
+
+    /// Take a token if one is available at `now`, otherwise return how long
+    /// until the next one is.
+    fn try_take(&self, host: &str, rate: Rate, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().expect("limiter lock poisoned");
+        let tat = buckets.get(host).copied().map_or(now, |tat| tat.max(now));
+        let earliest = tat.checked_sub(rate.tolerance()).unwrap_or(now);
+        if earliest > now {
+            return Err(earliest - now);
+        }
+        buckets.insert(host.to_string(), tat + rate.interval());
+        Ok(())
+    }
+}
+
+/// Bucket key for a base URL: its host, plus the port when one is given
+/// explicitly. Unparseable URLs are used verbatim.
+pub fn host_key(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            _ => url.to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+/// A client's limiter together with the bucket it draws from.
+#[derive(Debug, Clone)]
+pub(crate) struct HostLimiter {
+    limiter: Arc<Limiter>,
+    host: String,
+}
+
+impl HostLimiter {
+    pub(crate) fn new(limiter: Arc<Limiter>, base_url: &str) -> Self {
+        Self {
+            limiter,
+            host: host_key(base_url),
+        }
+    }
+
+    /// Replace the limiter, keeping the host.
+    pub(crate) fn replace(&mut self, limiter: Arc<Limiter>) {
+        self.limiter = limiter;
+    }
+
+    /// Tokens the client's host could spend right now without waiting.
+    pub(crate) fn available(&self) -> u32 {
+        self.limiter.available(&self.host)
+    }
+
+    /// The rate applied to the client's host, or `None` when unlimited.
+    pub(crate) fn rate(&self) -> Option<Rate> {
+        self.limiter.rate_for(&self.host)
+    }
+
+    /// Wait for a token and report the wait to the client's observer.
+    pub(crate) async fn acquire(
+        &self,
+        observer: &ObserverSlot,
+        service: Service,
+        endpoint: &'static str,
+    ) {
+        let waited = self.limiter.acquire(&self.host).await;
+        observer.throttled(service, endpoint, waited);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn burst_is_immediate_then_paced_at_interval() {
+        tokio::time::pause();
+        let limiter = Limiter::new(Rate::new(3, Duration::from_secs(3)));
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert_eq!(limiter.acquire("a").await, Duration::ZERO);
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        assert_eq!(limiter.available("a"), 0);
+
+        // Three more need one refill interval each.
+        for _ in 0..3 {
+            limiter.acquire("a").await;
+        }
+        assert!(start.elapsed() >= Duration::from_secs(3));
+        assert!(start.elapsed() < Duration::from_millis(3100));
+    }
+
+    #[tokio::test]
+    async fn n_rapid_acquires_take_at_least_the_minimum_time() {
+        tokio::time::pause();
+        // Burst of 1: every acquire after the first waits a full interval.
+        let limiter = Arc::new(Limiter::new(Rate::new(1, Duration::from_millis(250))));
+
+        let start = Instant::now();
+        let mut tasks = Vec::new();
+        for _ in 0..9 {
+            let limiter = Arc::clone(&limiter);
+            tasks.push(tokio::spawn(async move { limiter.acquire("host").await }));
+        }
+        let mut waits = Vec::new();
+        for task in tasks {
+            waits.push(task.await.unwrap());
+        }
+
+        assert!(start.elapsed() >= Duration::from_secs(2));
+        assert_eq!(waits.iter().filter(|w| w.is_zero()).count(), 1);
+        assert!(waits.iter().max().unwrap() >= &Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn separate_hosts_do_not_contend() {
+        tokio::time::pause();
+        let limiter = Limiter::new(Rate::new(1, Duration::from_secs(60)));
+
+        limiter.acquire("api.open.fec.gov").await;
+        assert_eq!(limiter.available("api.open.fec.gov"), 0);
+
+        let start = Instant::now();
+        assert_eq!(limiter.acquire("api.tiingo.com").await, Duration::ZERO);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn dropped_acquire_does_not_consume_a_token() {
+        tokio::time::pause();
+        let limiter = Limiter::new(Rate::new(1, Duration::from_secs(1)));
+        limiter.acquire("h").await;
+
+        let start = Instant::now();
+        let cancelled =
+            tokio::time::timeout(Duration::from_millis(100), limiter.acquire("h")).await;
+        assert!(cancelled.is_err());
+
+        // The next token is still due one interval after the first request
+        // (tokio timers round up to the millisecond).
+        limiter.acquire("h").await;
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert!(start.elapsed() < Duration::from_millis(1010));
+    }
+
+    #[tokio::test]
+    async fn bucket_refills_while_idle() {
+        tokio::time::pause();
+        let limiter = Limiter::new(Rate::per_second(4));
+        for _ in 0..4 {
+            limiter.acquire("h").await;
+        }
+        assert_eq!(limiter.available("h"), 0);
+
+        tokio::time::advance(Duration::from_millis(500)).await;
+        assert_eq!(limiter.available("h"), 2);
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(limiter.available("h"), 4);
+    }
+
+    #[tokio::test]
+    async fn host_overrides_and_unlimited_hosts() {
+        tokio::time::pause();
+        let limiter = Limiter::unlimited().with_host_rate("slow", Rate::new(1, Duration::from_secs(5)));
+        assert_eq!(limiter.rate_for("fast"), None);
+        assert_eq!(limiter.available("fast"), u32::MAX);
+
+        let start = Instant::now();
+        for _ in 0..100 {
+            limiter.acquire("fast").await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire("slow").await;
+        let waited = limiter.acquire("slow").await;
+        assert!(waited >= Duration::from_secs(5) && waited < Duration::from_millis(5010));
+    }
+
+    #[test]
+    fn host_key_keeps_explicit_ports() {
+        assert_eq!(host_key("https://api.open.fec.gov/v1"), "api.open.fec.gov");
+        assert_eq!(host_key("http://127.0.0.1:4010"), "127.0.0.1:4010");
+        assert_eq!(host_key("not a url"), "not a url");
+    }
+
+    #[test]
+    fn rate_interval_spreads_period_over_burst() {
+        assert_eq!(Rate::per_hour(900).interval(), Duration::from_secs(4));
+        assert_eq!(Rate::per_second(1).tolerance(), Duration::ZERO);
+    }
+}
@@ -1,7 +1,11 @@
 //! HTML scraping utilities for CapitolTrades pages (no API).
 
+use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::NaiveDate;
+
 use rand::Rng;
 use regex::Regex;
 use reqwest::header::{HeaderMap, RETRY_AFTER};
@@ -11,6 +15,13 @@ use tokio::time::sleep;
 
 use capitoltrades_api::user_agent::get_user_agent;
 
+use crate::config::AppConfig;
+use crate::observer::{self, ObserverSlot, RequestObserver, Service};
+use crate::ratelimit::{HostLimiter, Limiter, Rate};
+
+/// Public CapitolTrades site.
+pub const DEFAULT_BASE_URL: &str = "https://www.capitoltrades.com";
+
 #[derive(thiserror::Error, Debug)]
 pub enum ScrapeError {
     #[error("http client error: {0}")]
@@ -40,6 +51,8 @@ pub enum ScrapeError {
 pub struct ScrapeClient {
     base_url: String,
     http: reqwest::Client,
+    observer: ObserverSlot,
+    limiter: HostLimiter,
 }
 
 pub struct ScrapePage<T> {
@@ -48,6 +61,117 @@ pub struct ScrapePage<T> {
     pub total_count: Option<i64>,
 }
 
+/// Site-side filters for the trades listing, used to split history into
+/// passes small enough to page through (see [`CapDetector`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TradeFilter {
+    /// Only this politician's trades (`politician=`).
+    pub politician_id: Option<String>,
+    /// Only trades published in this inclusive range (`pubDate=from,to`).
+    pub pub_date: Option<(NaiveDate, NaiveDate)>,
+}
+
+impl TradeFilter {
+    fn query_string(&self) -> String {
+        let mut query = String::new();
+        if let Some(ref id) = self.politician_id {
+            query.push_str(&format!("&politician={}", id));
+        }
+        if let Some((from, to)) = self.pub_date {
+            query.push_str(&format!(
+                "&pubDate={},{}",
+                from.format("%Y-%m-%d"),
+                to.format("%Y-%m-%d")
+            ));
+        }
+        query
+    }
+}
+
+/// Why a trades listing cannot be paged to its end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaginationCap {
+    /// The listing reports more trades than its pages hold.
+    Total {
+        reachable_pages: i64,
+        total_count: i64,
+    },
+    /// This page returned only trades seen on earlier pages; the site keeps
+    /// serving its last page past the cap.
+    Repeated { page: i64 },
+}
+
+impl std::fmt::Display for PaginationCap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaginationCap::Total {
+                reachable_pages,
+                total_count,
+            } => write!(
+                f,
+                "the listing reports {} trades but serves only {} pages",
+                total_count, reachable_pages
+            ),
+            PaginationCap::Repeated { page } => {
+                write!(f, "page {} repeated trades from earlier pages", page)
+            }
+        }
+    }
+}
+
+/// Watches successive trades listing pages for the site's pagination cap.
+///
+/// Two signals are checked: page metadata whose `totalPages` cannot hold
+/// `totalCount` at the first page's size, and a page made up entirely of
+/// trades already returned. The first is known up front and paging can
+/// continue to the last reachable page; the second means paging further
+/// only returns duplicates.
+#[derive(Debug, Default)]
+pub struct CapDetector {
+    page_size: Option<usize>,
+    seen: HashSet<i64>,
+    cap: Option<PaginationCap>,
+}
+
+impl CapDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `page`. Returns `false` when it only repeated earlier trades
+    /// and should not be stored or followed.
+    pub fn observe(&mut self, page: i64, resp: &ScrapePage<ScrapedTrade>) -> bool {
+        if resp.data.is_empty() {
+            return true;
+        }
+        let page_size = *self.page_size.get_or_insert(resp.data.len());
+        if self.cap.is_none() {
+            if let (Some(pages), Some(count)) = (resp.total_pages, resp.total_count) {
+                if pages.saturating_mul(page_size as i64) < count {
+                    self.cap = Some(PaginationCap::Total {
+                        reachable_pages: pages,
+                        total_count: count,
+                    });
+                }
+            }
+        }
+
+        let mut fresh = false;
+        for trade in &resp.data {
+            fresh |= self.seen.insert(trade.tx_id);
+        }
+        if !fresh {
+            self.cap = Some(PaginationCap::Repeated { page });
+        }
+        fresh
+    }
+
+    /// The cap detected so far, if any.
+    pub fn cap(&self) -> Option<&PaginationCap> {
+        self.cap.as_ref()
+    }
+}
+
 struct RetryConfig {
     max_retries: usize,
     base_delay_ms: u64,
@@ -171,6 +295,10 @@ pub struct ScrapedIssuerList {
     pub issuer_ticker: Option<String>,
     pub sector: Option<String>,
     pub stats: ScrapedIssuerStats,
+    /// Preview of the member behind the most recent trade; absent on
+    /// issuers nobody has traded and on older payloads.
+    #[serde(default)]
+    pub last_trader: Option<ScrapedLastTrader>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -188,6 +316,26 @@ pub struct ScrapedIssuerDetail {
     pub performance: Option<serde_json::Value>,
     pub sector: Option<String>,
     pub stats: ScrapedIssuerStats,
+    #[serde(default)]
+    pub last_trader: Option<ScrapedLastTrader>,
+}
+
+/// The "last traded by" politician shown next to an issuer. A display hint
+/// only: it reflects the site at scrape time, not the local trade table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrapedLastTrader {
+    #[serde(rename = "_politicianId")]
+    pub politician_id: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+impl ScrapedLastTrader {
+    /// `first last`, the same form used for politician names elsewhere.
+    pub fn name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -209,9 +357,12 @@ impl ScrapeClient {
             .timeout(Duration::from_secs(30))
             .build()
             .map_err(ScrapeError::HttpClient)?;
+        let base_url = DEFAULT_BASE_URL.to_string();
         Ok(Self {
-            base_url: "https://www.capitoltrades.com".to_string(),
+            limiter: HostLimiter::new(Arc::new(Limiter::unlimited()), &base_url),
+            base_url,
             http,
+            observer: ObserverSlot::default(),
         })
     }
 
@@ -224,20 +375,73 @@ impl ScrapeClient {
         Ok(Self {
             base_url: base_url.trim_end_matches('/').to_string(),
             http,
+            observer: ObserverSlot::default(),
+            limiter: HostLimiter::new(Arc::new(Limiter::unlimited()), base_url),
+        })
+    }
+
+    /// Build from the `[capitoltrades]` settings of a resolved config.
+    pub fn from_config(config: &AppConfig) -> Result<Self, ScrapeError> {
+        let client = match config.capitoltrades.base_url.as_deref() {
+            Some(url) => Self::with_base_url(url)?,
+            None => Self::new()?,
+        };
+        Ok(match config.capitoltrades.rate {
+            Some(rate) => client.with_rate(rate.to_rate()),
+            None => client,
         })
     }
 
+    /// Report every page fetch to `observer` (see [`crate::observer`]).
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = ObserverSlot::new(observer);
+        self
+    }
+
+    /// Pace page fetches through a shared limiter (see [`crate::ratelimit`]).
+    ///
+    /// Unpaced by default; the CLI derives a rate from `--details-delay-ms`
+    /// unless the config sets `[capitoltrades] rate`.
+    pub fn with_limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter.replace(limiter);
+        self
+    }
+
+    /// Pace page fetches at `rate`.
+    pub fn with_rate(self, rate: Rate) -> Self {
+        self.with_limiter(Arc::new(Limiter::new(rate)))
+    }
+
     pub async fn trades_page(&self, page: i64) -> Result<ScrapePage<ScrapedTrade>, ScrapeError> {
-        let url = format!("{}/trades?page={}", self.base_url, page);
-        let html = self.fetch_html(&url).await?;
-        let payload = extract_rsc_payload(&html)?;
-        let data = extract_array_with_key(&payload, "_txId")
-            .ok_or_else(|| ScrapeError::Parse("missing trades data array".into()))?;
-        let trades: Vec<ScrapedTrade> = serde_json::from_value(data)?;
+        self.trades_page_filtered(&TradeFilter::default(), page).await
+    }
 
+    /// Fetch one page of the trades listing narrowed by `filter`.
+    ///
+    /// A filter that matches nothing yields an empty page rather than a
+    /// parse error, as long as the page reports a zero `totalCount`.
+    pub async fn trades_page_filtered(
+        &self,
+        filter: &TradeFilter,
+        page: i64,
+    ) -> Result<ScrapePage<ScrapedTrade>, ScrapeError> {
+        let url = format!(
+            "{}/trades?page={}{}",
+            self.base_url,
+            page,
+            filter.query_string()
+        );
+        let html = self.fetch_html("trades", &url).await?;
+        let payload = extract_rsc_payload(&html)?;
         let total_pages = extract_number(&payload, "\"totalPages\":");
         let total_count = extract_number(&payload, "\"totalCount\":");
 
+        let trades: Vec<ScrapedTrade> = match extract_array_with_key(&payload, "_txId") {
+            Some(data) => serde_json::from_value(data)?,
+            None if total_count == Some(0) => Vec::new(),
+            None => return Err(ScrapeError::Parse("missing trades data array".into())),
+        };
+
         Ok(ScrapePage {
             data: trades,
             total_pages,
@@ -250,7 +454,7 @@ impl ScrapeClient {
         page: i64,
     ) -> Result<ScrapePage<ScrapedIssuerList>, ScrapeError> {
         let url = format!("{}/issuers?page={}", self.base_url, page);
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("issuers", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         let data = extract_array_with_key(&payload, "_issuerId")
             .ok_or_else(|| ScrapeError::Parse("missing issuers data array".into()))?;
@@ -268,7 +472,7 @@ impl ScrapeClient {
 
     pub async fn issuer_detail(&self, issuer_id: i64) -> Result<ScrapedIssuerDetail, ScrapeError> {
         let url = format!("{}/issuers/{}", self.base_url, issuer_id);
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("issuer_detail", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         let obj = extract_json_object_after(&payload, "\"issuerData\":");
         match obj {
@@ -283,7 +487,7 @@ impl ScrapeClient {
         page: i64,
     ) -> Result<ScrapePage<ScrapedPoliticianCard>, ScrapeError> {
         let url = format!("{}/politicians?page={}", self.base_url, page);
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("politicians", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         let total_count = extract_number(&payload, "\"totalCount\":");
 
@@ -318,7 +522,7 @@ impl ScrapeClient {
             "{}/politicians?committee={}&page={}",
             self.base_url, committee_code, page
         );
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("politicians_by_committee", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         let total_count = extract_number(&payload, "\"totalCount\":");
 
@@ -344,25 +548,29 @@ impl ScrapeClient {
         politician_id: &str,
     ) -> Result<Option<ScrapedPolitician>, ScrapeError> {
         let url = format!("{}/politicians/{}", self.base_url, politician_id);
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("politician_detail", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         Ok(extract_politician_detail(&payload))
     }
 
     pub async fn trade_detail(&self, trade_id: i64) -> Result<ScrapedTradeDetail, ScrapeError> {
         let url = format!("{}/trades/{}", self.base_url, trade_id);
-        let html = self.fetch_html(&url).await?;
+        let html = self.fetch_html("trade_detail", &url).await?;
         let payload = extract_rsc_payload(&html)?;
         Ok(extract_trade_detail(&payload, trade_id))
     }
 
-    async fn fetch_html(&self, url: &str) -> Result<String, ScrapeError> {
-        self.with_retry(url, || async { self.fetch_html_once(url).await })
+    async fn fetch_html(&self, endpoint: &'static str, url: &str) -> Result<String, ScrapeError> {
+        self.with_retry(url, || async { self.fetch_html_once(endpoint, url).await })
             .await
     }
 
-    async fn fetch_html_once(&self, url: &str) -> Result<String, ScrapeError> {
-        let resp = self
+    async fn fetch_html_once(&self, endpoint: &'static str, url: &str) -> Result<String, ScrapeError> {
+        self.limiter
+            .acquire(&self.observer, Service::CapitolTradesScrape, endpoint)
+            .await;
+        let span = self.observer.start(Service::CapitolTradesScrape, endpoint);
+        let sent = self
             .http
             .get(url)
             .header("accept", "text/html,application/xhtml+xml")
@@ -371,13 +579,20 @@ impl ScrapeClient {
             .header("cache-control", "no-cache")
             .header("pragma", "no-cache")
             .send()
-            .await
-            .map_err(|err| ScrapeError::Http {
-                url: url.to_string(),
-                source: err,
-            })?;
+            .await;
+        let resp = match sent {
+            Ok(resp) => resp,
+            Err(err) => {
+                observer::finish(span, None);
+                return Err(ScrapeError::Http {
+                    url: url.to_string(),
+                    source: err,
+                });
+            }
+        };
 
         let status = resp.status();
+        observer::finish(span, Some(status.as_u16()));
         let retry_after = parse_retry_after(resp.headers());
         let body = resp.text().await.map_err(|err| ScrapeError::Http {
             url: url.to_string(),
@@ -761,8 +976,9 @@ fn parse_politician_cards(payload: &str) -> Result<Vec<ScrapedPoliticianCard>, S
         .map_err(|e| ScrapeError::Parse(format!("regex compile error: {}", e)))?;
     // The live site uses singular labels ("Trade", "Issuer") when count == 1
     // and plural labels ("Trades", "Issuers") when count > 1. Accept both.
+    // Members who never traded render "-" for volume and last traded.
     let card_re = Regex::new(
-        r#"(?s)href":"/politicians/(?P<id>[A-Z]\d{6})".*?cell--name.*?children":"(?P<name>[^"]+)".*?party--(?P<party>democrat|republican|other).*?us-state-full--(?P<state>[a-z]{2}).*?cell--count-trades.*?children":"Trades?".*?children":"(?P<trades>[\d,]+)".*?cell--count-issuers.*?children":"Issuers?".*?children":"(?P<issuers>[\d,]+)".*?cell--volume.*?children":"Volume".*?children":"(?P<volume>[^"]+)".*?cell--last-traded.*?children":"Last Traded".*?children":"(?P<last>\d{4}-\d{2}-\d{2})""#,
+        r#"(?s)href":"/politicians/(?P<id>[A-Z]\d{6})".*?cell--name.*?children":"(?P<name>[^"]+)".*?party--(?P<party>democrat|republican|other).*?us-state-full--(?P<state>[a-z]{2}).*?cell--count-trades.*?children":"Trades?".*?children":"(?P<trades>[\d,]+)".*?cell--count-issuers.*?children":"Issuers?".*?children":"(?P<issuers>[\d,]+)".*?cell--volume.*?children":"Volume".*?children":"(?P<volume>[^"]+)".*?cell--last-traded.*?children":"Last Traded".*?children":"(?P<last>\d{4}-\d{2}-\d{2}|-|—)""#,
     )
     .map_err(|e| ScrapeError::Parse(format!("regex compile error: {}", e)))?;
 
@@ -789,10 +1005,17 @@ fn parse_politician_cards(payload: &str) -> Result<Vec<ScrapedPoliticianCard>, S
                 politician_id
             ))
         })?;
-        let volume = parse_compact_number(&cap["volume"]).ok_or_else(|| {
-            ScrapeError::Parse(format!("invalid volume for politician {}", politician_id))
-        })?;
-        let last_traded = Some(cap["last"].to_string());
+        let volume = match parse_compact_number(&cap["volume"]) {
+            Some(volume) => volume,
+            None if is_placeholder(&cap["volume"]) => 0,
+            None => {
+                return Err(ScrapeError::Parse(format!(
+                    "invalid volume for politician {}",
+                    politician_id
+                )))
+            }
+        };
+        let last_traded = Some(cap["last"].to_string()).filter(|last| !is_placeholder(last));
 
         cards.push(ScrapedPoliticianCard {
             politician_id,
@@ -836,9 +1059,14 @@ fn parse_int(raw: &str) -> Option<i64> {
     cleaned.parse().ok()
 }
 
+/// True for the dash the listing pages render in place of an empty value.
+fn is_placeholder(raw: &str) -> bool {
+    matches!(raw.trim(), "" | "-" | "—")
+}
+
 fn parse_compact_number(raw: &str) -> Option<i64> {
     let mut cleaned = raw.trim().replace(',', "");
-    if cleaned.is_empty() || cleaned == "-" || cleaned == "—" {
+    if is_placeholder(&cleaned) {
         return None;
     }
     if cleaned.starts_with('$') {
@@ -885,6 +1113,7 @@ fn issuer_fallback(issuer_id: i64) -> Option<ScrapedIssuerDetail> {
             volume: 0,
             date_last_traded: String::new(),
         },
+        last_trader: None,
     })
 }
 
@@ -1196,6 +1425,39 @@ mod tests {
 
     // ---- Committee-filtered politician listing fixture tests ----
 
+    // ---- Issuer list fixture tests ----
+
+    fn load_issuers_list_fixture() -> Vec<ScrapedIssuerList> {
+        let html = include_str!("../tests/fixtures/issuers_list.html");
+        let payload =
+            extract_rsc_payload(html).expect("issuers list fixture should have RSC payload");
+        let data = extract_array_with_key(&payload, "_issuerId")
+            .expect("issuers array should be extractable");
+        serde_json::from_value(data).expect("should deserialize to ScrapedIssuerList")
+    }
+
+    #[test]
+    fn test_issuers_list_last_trader_preview() {
+        let issuers = load_issuers_list_fixture();
+        assert_eq!(issuers.len(), 3);
+
+        let traded = &issuers[0];
+        assert_eq!(traded.issuer_name, "Apple Inc.");
+        let last = traded.last_trader.as_ref().expect("preview present");
+        assert_eq!(last.politician_id, "P000197");
+        assert_eq!(last.name(), "Nancy Pelosi");
+        assert_eq!(traded.stats.date_last_traded, "2025-06-10");
+    }
+
+    #[test]
+    fn test_issuers_list_without_preview() {
+        let issuers = load_issuers_list_fixture();
+        // One issuer has "lastTrader": null, another omits the key entirely.
+        assert!(issuers[1].last_trader.is_none());
+        assert!(issuers[2].last_trader.is_none());
+        assert_eq!(issuers[2].issuer_ticker, None);
+    }
+
     // ---- Issuer detail fixture tests ----
 
     fn load_issuer_perf_fixture() -> String {
@@ -1320,4 +1582,133 @@ mod tests {
         assert!(ids.contains(&"W000802"), "Sheldon Whitehouse should be in ssfi");
         assert!(ids.contains(&"C000174"), "Tom Carper should be in ssfi");
     }
+
+    #[test]
+    fn test_politicians_directory_fixture_with_inactive_member() {
+        let html = include_str!("../tests/fixtures/politicians_directory.html");
+        let payload = extract_rsc_payload(html).expect("fixture should have RSC payload");
+        assert_eq!(extract_number(&payload, "\"totalCount\":"), Some(3));
+
+        let cards = parse_politician_cards(&payload).expect("should parse directory cards");
+        let ids: Vec<&str> = cards.iter().map(|c| c.politician_id.as_str()).collect();
+        assert_eq!(ids, vec!["W000802", "C000174", "Z000001"]);
+
+        let active = &cards[0];
+        assert_eq!(active.name, "Sheldon Whitehouse");
+        assert_eq!(active.trades, 73);
+        assert_eq!(active.volume, 1_230_000);
+        assert_eq!(active.last_traded.as_deref(), Some("2024-12-19"));
+
+        // A zero-trade member must not borrow the next card's values.
+        let inactive = &cards[2];
+        assert_eq!(inactive.name, "Newly Sworn");
+        assert_eq!(inactive.party, "republican");
+        assert_eq!(inactive.state, "TX");
+        assert_eq!(inactive.trades, 0);
+        assert_eq!(inactive.issuers, 0);
+        assert_eq!(inactive.volume, 0);
+        assert_eq!(inactive.last_traded, None);
+    }
+
+    fn listing_page(html: &str) -> ScrapePage<ScrapedTrade> {
+        let payload = extract_rsc_payload(html).expect("listing payload");
+        ScrapePage {
+            data: serde_json::from_value(extract_array_with_key(&payload, "_txId").unwrap())
+                .unwrap(),
+            total_pages: extract_number(&payload, "\"totalPages\":"),
+            total_count: extract_number(&payload, "\"totalCount\":"),
+        }
+    }
+
+    #[test]
+    fn test_cap_detector_reads_page_metadata() {
+        use crate::fixtures::trades_listing_html;
+        let mut detector = CapDetector::new();
+        // Two pages of two trades cannot hold the five reported.
+        let page = listing_page(&trades_listing_html(
+            &[(5, "P000001", "2024-05-01"), (4, "P000001", "2024-04-01")],
+            2,
+            5,
+        ));
+        assert!(detector.observe(1, &page));
+        assert_eq!(
+            detector.cap(),
+            Some(&PaginationCap::Total {
+                reachable_pages: 2,
+                total_count: 5
+            })
+        );
+
+        let mut uncapped = CapDetector::new();
+        let page = listing_page(&trades_listing_html(
+            &[(5, "P000001", "2024-05-01"), (4, "P000001", "2024-04-01")],
+            3,
+            5,
+        ));
+        assert!(uncapped.observe(1, &page));
+        assert_eq!(uncapped.cap(), None);
+    }
+
+    #[test]
+    fn test_cap_detector_flags_repeated_page() {
+        use crate::fixtures::trades_listing_html;
+        let mut detector = CapDetector::new();
+        let html = trades_listing_html(
+            &[(5, "P000001", "2024-05-01"), (4, "P000001", "2024-04-01")],
+            9,
+            18,
+        );
+        assert!(detector.observe(1, &listing_page(&html)));
+        // Past the cap the site serves the same page again.
+        assert!(!detector.observe(2, &listing_page(&html)));
+        assert_eq!(detector.cap(), Some(&PaginationCap::Repeated { page: 2 }));
+    }
+
+    #[tokio::test]
+    async fn test_trades_page_filtered_query_and_empty_result() {
+        use crate::fixtures::trades_listing_html;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("politician", "P000001"))
+            .and(query_param("pubDate", "2024-01-01,2024-01-31"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(trades_listing_html(
+                &[(7, "P000001", "2024-01-15")],
+                1,
+                1,
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("politician", "P000002"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string(trades_listing_html(&[], 0, 0)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ScrapeClient::with_base_url(&server.uri()).unwrap();
+        let filter = TradeFilter {
+            politician_id: Some("P000001".to_string()),
+            pub_date: Some((
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            )),
+        };
+        let page = client.trades_page_filtered(&filter, 1).await.unwrap();
+        assert_eq!(page.data.len(), 1);
+        assert_eq!(page.data[0].tx_id, 7);
+
+        let empty = TradeFilter {
+            politician_id: Some("P000002".to_string()),
+            pub_date: None,
+        };
+        let page = client.trades_page_filtered(&empty, 1).await.unwrap();
+        assert!(page.data.is_empty());
+        assert_eq!(page.total_count, Some(0));
+    }
 }
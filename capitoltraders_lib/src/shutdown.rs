@@ -0,0 +1,69 @@
+//! Graceful shutdown for long-running commands.
+//!
+//! Sync, enrichment, donation sync and price enrichment check a
+//! [`CancellationToken`] between units of work (a page, a detail fetch, a
+//! donation page) instead of relying on the runtime being torn down. Once it
+//! is cancelled they stop starting new work, let in-flight work land, write
+//! their cursors and stats, and print the usual summary.
+//!
+//! [`ctrl_c_token`] wires the token to Ctrl-C: the first press cancels it, a
+//! second press exits immediately with [`ABORT_EXIT_CODE`].
+
+use std::time::Duration;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// Exit status after a second Ctrl-C (128 + SIGINT, as a shell reports it).
+pub const ABORT_EXIT_CODE: i32 = 130;
+
+/// Token cancelled by the first Ctrl-C. A second Ctrl-C exits the process
+/// without waiting for checkpoints. Must be called inside a Tokio runtime.
+pub fn ctrl_c_token() -> CancellationToken {
+    let token = CancellationToken::new();
+    let handle = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        eprintln!();
+        eprintln!("Interrupted: finishing the current step and saving progress (Ctrl-C again to abort)");
+        handle.cancel();
+        if tokio::signal::ctrl_c().await.is_ok() {
+            eprintln!("Aborted");
+            std::process::exit(ABORT_EXIT_CODE);
+        }
+    });
+    token
+}
+
+/// Sleep for `duration` unless `token` is cancelled first. Returns `false`
+/// when the wait was cut short, so throttled loops can stop right away.
+pub async fn pause(token: &CancellationToken, duration: Duration) -> bool {
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => true,
+        _ = token.cancelled() => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pause_returns_early_when_cancelled() {
+        let token = CancellationToken::new();
+        assert!(pause(&token, Duration::from_millis(10)).await);
+
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            canceller.cancel();
+        });
+        let started = tokio::time::Instant::now();
+        assert!(!pause(&token, Duration::from_secs(3600)).await);
+        assert!(started.elapsed() < Duration::from_secs(2));
+
+        // Already cancelled: no wait at all.
+        assert!(!pause(&token, Duration::from_secs(3600)).await);
+    }
+}
@@ -0,0 +1,817 @@
+//! Named snapshots of derived state, and what changed since one was taken.
+//!
+//! [`create_snapshot`] stores compact summaries rather than copies: the
+//! highest tx_id, a hash of each open position's share count, each
+//! politician's leaderboard rank, and the anomaly signals raised. [`compare`]
+//! recomputes the same summaries and reports new trades, changed positions,
+//! rank moves and new anomaly signals.
+//!
+//! Every section is stored with its own format number. A section missing
+//! from an older snapshot, or written in a format this build does not read,
+//! is reported as unavailable while the other sections are still compared.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use chrono::NaiveDate;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::analytics::{
+    aggregate_politician_metrics, calculate_closed_trades, compute_trade_metrics, IntervalConfig,
+};
+use crate::anomaly::{
+    calculate_composite_anomaly_score, calculate_sector_concentration, detect_pre_move_trades,
+    detect_unusual_volume_universe, future_price_candidates, AnomalyConfig, PortfolioPositionForHHI,
+    PreMoveSignal, VolumeParams,
+};
+use crate::db::{Db, DbError, DbTradeRow, SnapshotSection, StoredSnapshot};
+use crate::portfolio::{calculate_positions, DEFAULT_PORTFOLIO_ASSET_TYPES, EPSILON};
+use crate::universe::TradeUniverse;
+
+/// Section names, as stored in `snapshot_sections.section`.
+const TRADES: &str = "trades";
+const POSITIONS: &str = "positions";
+const RANKS: &str = "ranks";
+const ANOMALIES: &str = "anomalies";
+
+/// Current format of each section. Bump one when its summary changes shape;
+/// snapshots in another format are skipped for that section only.
+const TRADES_FORMAT: i64 = 1;
+const POSITIONS_FORMAT: i64 = 1;
+const RANKS_FORMAT: i64 = 1;
+const ANOMALIES_FORMAT: i64 = 1;
+
+/// Error creating or comparing a snapshot.
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    #[error(transparent)]
+    Db(#[from] DbError),
+    #[error("no snapshot named '{0}'")]
+    NotFound(String),
+    #[error("invalid snapshot name '{0}': use 1-64 letters, digits, '.', '-' or '_'")]
+    InvalidName(String),
+}
+
+/// Highest tx_id and trade count.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeSummary {
+    pub max_tx_id: Option<i64>,
+    pub trade_count: i64,
+}
+
+/// A politician's place on the return leaderboard.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankSummary {
+    /// 1 is the highest average return.
+    pub rank: usize,
+    pub avg_return: f64,
+    pub closed_trades: usize,
+}
+
+/// Anomaly signals raised at snapshot time.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct AnomalySummary {
+    /// tx_ids flagged as pre-move trades.
+    pub pre_move_tx_ids: BTreeSet<i64>,
+    /// Politicians with unusual trading volume.
+    pub unusual_volume: BTreeSet<String>,
+    /// Composite anomaly score per politician.
+    pub composites: BTreeMap<String, f64>,
+}
+
+/// Every section of a snapshot, computed from the current database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotSummary {
+    pub trades: TradeSummary,
+    /// politician_id -> ticker -> hash of the shares held.
+    pub positions: BTreeMap<String, BTreeMap<String, String>>,
+    /// politician_id -> rank.
+    pub ranks: BTreeMap<String, RankSummary>,
+    pub anomalies: AnomalySummary,
+}
+
+/// Outcome of comparing one section.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SectionDiff<T> {
+    Compared { changes: Vec<T> },
+    /// The snapshot has no usable summary for this section.
+    Unavailable { reason: String },
+}
+
+impl<T> SectionDiff<T> {
+    pub fn changes(&self) -> Option<&[T]> {
+        match self {
+            SectionDiff::Compared { changes } => Some(changes),
+            SectionDiff::Unavailable { .. } => None,
+        }
+    }
+}
+
+/// How an open position differs from the snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PositionChangeKind {
+    Opened,
+    Changed,
+    Closed,
+}
+
+impl PositionChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PositionChangeKind::Opened => "opened",
+            PositionChangeKind::Changed => "changed",
+            PositionChangeKind::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PositionChange {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub ticker: String,
+    pub change: PositionChangeKind,
+    /// Shares held now; None once closed.
+    pub shares_held: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RankChange {
+    pub politician_id: String,
+    pub politician_name: String,
+    pub old_rank: usize,
+    pub new_rank: usize,
+    /// Places gained (positive) or lost (negative).
+    pub moved: i64,
+    pub avg_return: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NewAnomaly {
+    pub politician_id: String,
+    pub politician_name: String,
+    /// `pre_move` or `unusual_volume`.
+    pub signal: String,
+    pub detail: String,
+    /// Current composite anomaly score.
+    pub composite: f64,
+}
+
+/// Everything that changed since a named snapshot.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SnapshotDiff {
+    pub snapshot: String,
+    pub created_at: String,
+    pub snapshot_schema_version: i32,
+    pub new_trades: SectionDiff<DbTradeRow>,
+    pub positions: SectionDiff<PositionChange>,
+    pub ranks: SectionDiff<RankChange>,
+    pub anomalies: SectionDiff<NewAnomaly>,
+}
+
+/// Options for [`compare`].
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Report rank moves of more than this many places.
+    pub rank_threshold: usize,
+    /// Reference date for the volume window.
+    pub today: NaiveDate,
+}
+
+/// Snapshot names are used on the command line and as keys, so keep them
+/// simple.
+pub fn validate_snapshot_name(name: &str) -> Result<&str, SnapshotError> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'));
+    if valid {
+        Ok(name)
+    } else {
+        Err(SnapshotError::InvalidName(name.to_string()))
+    }
+}
+
+/// Compute every section from the current database.
+pub fn summarize(db: &Db, today: NaiveDate) -> Result<SnapshotSummary, DbError> {
+    let (max_tx_id, trade_count) = db.trade_id_summary(None)?;
+    Ok(SnapshotSummary {
+        trades: TradeSummary {
+            max_tx_id,
+            trade_count,
+        },
+        positions: position_hashes(db)?,
+        ranks: leaderboard_ranks(db)?,
+        anomalies: current_anomalies(db, today)?.summary,
+    })
+}
+
+/// Summarize the database and store it under `name`, replacing any snapshot
+/// with that name.
+pub fn create_snapshot(
+    db: &Db,
+    name: &str,
+    today: NaiveDate,
+) -> Result<SnapshotSummary, SnapshotError> {
+    validate_snapshot_name(name)?;
+    let summary = summarize(db, today)?;
+    db.save_snapshot(
+        name,
+        &[
+            section(TRADES, TRADES_FORMAT, &summary.trades),
+            section(POSITIONS, POSITIONS_FORMAT, &summary.positions),
+            section(RANKS, RANKS_FORMAT, &summary.ranks),
+            section(ANOMALIES, ANOMALIES_FORMAT, &summary.anomalies),
+        ],
+    )?;
+    Ok(summary)
+}
+
+/// Store `trades` as the only section of the snapshot called `name`,
+/// replacing any snapshot with that name. [`compare`] reports the other
+/// sections of such a snapshot as unavailable.
+pub fn save_trade_summary(db: &Db, name: &str, trades: &TradeSummary) -> Result<(), DbError> {
+    db.save_snapshot(name, &[section(TRADES, TRADES_FORMAT, trades)])
+}
+
+/// The trades section of the snapshot called `name`; None when there is no
+/// such snapshot or its trades section is missing or in another format.
+pub fn load_trade_summary(db: &Db, name: &str) -> Result<Option<TradeSummary>, DbError> {
+    Ok(db
+        .load_snapshot(name)?
+        .and_then(|stored| read_section(&stored, TRADES, TRADES_FORMAT).ok()))
+}
+
+/// Compare the current database against the snapshot called `name`.
+pub fn compare(db: &Db, name: &str, options: &DiffOptions) -> Result<SnapshotDiff, SnapshotError> {
+    let stored = db
+        .load_snapshot(name)?
+        .ok_or_else(|| SnapshotError::NotFound(name.to_string()))?;
+    let names: HashMap<String, String> = db.trading_politicians()?.into_iter().collect();
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+    let new_trades = match read_section::<TradeSummary>(&stored, TRADES, TRADES_FORMAT) {
+        Ok(then) => SectionDiff::Compared {
+            changes: db.trades_after_tx_id(then.max_tx_id)?,
+        },
+        Err(reason) => SectionDiff::Unavailable { reason },
+    };
+
+    let positions = match read_section(&stored, POSITIONS, POSITIONS_FORMAT) {
+        Ok(then) => SectionDiff::Compared {
+            changes: position_changes(db, &then, &name_of)?,
+        },
+        Err(reason) => SectionDiff::Unavailable { reason },
+    };
+
+    let ranks = match read_section::<BTreeMap<String, RankSummary>>(&stored, RANKS, RANKS_FORMAT) {
+        Ok(then) => {
+            let now = leaderboard_ranks(db)?;
+            let mut changes: Vec<RankChange> = now
+                .iter()
+                .filter_map(|(id, current)| {
+                    let old = then.get(id)?;
+                    let moved = old.rank as i64 - current.rank as i64;
+                    (moved.unsigned_abs() as usize > options.rank_threshold).then(|| RankChange {
+                        politician_id: id.clone(),
+                        politician_name: name_of(id),
+                        old_rank: old.rank,
+                        new_rank: current.rank,
+                        moved,
+                        avg_return: current.avg_return,
+                    })
+                })
+                .collect();
+            changes.sort_by(|a, b| {
+                b.moved
+                    .abs()
+                    .cmp(&a.moved.abs())
+                    .then(a.new_rank.cmp(&b.new_rank))
+            });
+            SectionDiff::Compared { changes }
+        }
+        Err(reason) => SectionDiff::Unavailable { reason },
+    };
+
+    let anomalies = match read_section::<AnomalySummary>(&stored, ANOMALIES, ANOMALIES_FORMAT) {
+        Ok(then) => SectionDiff::Compared {
+            changes: new_anomalies(db, &then, options.today, &name_of)?,
+        },
+        Err(reason) => SectionDiff::Unavailable { reason },
+    };
+
+    Ok(SnapshotDiff {
+        snapshot: stored.name,
+        created_at: stored.created_at,
+        snapshot_schema_version: stored.schema_version,
+        new_trades,
+        positions,
+        ranks,
+        anomalies,
+    })
+}
+
+fn section<T: Serialize>(section: &str, format: i64, value: &T) -> SnapshotSection {
+    SnapshotSection {
+        section: section.to_string(),
+        format,
+        // String-keyed maps and plain structs always serialize.
+        data: serde_json::to_string(value).expect("serialize snapshot section"),
+    }
+}
+
+/// Decode one section, or explain why it cannot be compared.
+fn read_section<T: DeserializeOwned>(
+    stored: &StoredSnapshot,
+    section: &str,
+    format: i64,
+) -> Result<T, String> {
+    let Some(found) = stored.sections.iter().find(|s| s.section == section) else {
+        return Err(format!(
+            "snapshot has no {} section (taken at schema version {})",
+            section, stored.schema_version
+        ));
+    };
+    if found.format != format {
+        return Err(format!(
+            "{} section is in format {}, this version reads format {}",
+            section, found.format, format
+        ));
+    }
+    serde_json::from_str(&found.data).map_err(|e| format!("{} section is unreadable: {}", section, e))
+}
+
+/// FNV-1a, so stored hashes stay comparable across builds and platforms.
+fn stable_hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+fn shares_hash(shares: f64) -> String {
+    stable_hash(&format!("{:.4}", shares))
+}
+
+/// Open (politician_id, ticker, shares) positions rebuilt from trades. The
+/// stored `positions` table is only refreshed by `portfolio`, so it can lag
+/// behind the trades a snapshot is compared against.
+fn open_positions(db: &Db) -> Result<Vec<(String, String, f64)>, DbError> {
+    let trades = db.query_trades_for_portfolio_of(DEFAULT_PORTFOLIO_ASSET_TYPES)?;
+    Ok(calculate_positions(trades, false)
+        .into_values()
+        .map(|position| {
+            let shares = position.shares_held();
+            (position.politician_id, position.ticker, shares)
+        })
+        .filter(|(_, _, shares)| *shares > EPSILON)
+        .collect())
+}
+
+fn position_hashes(db: &Db) -> Result<BTreeMap<String, BTreeMap<String, String>>, DbError> {
+    let mut hashes: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    for (politician_id, ticker, shares) in open_positions(db)? {
+        hashes
+            .entry(politician_id)
+            .or_default()
+            .insert(ticker, shares_hash(shares));
+    }
+    Ok(hashes)
+}
+
+fn position_changes(
+    db: &Db,
+    then: &BTreeMap<String, BTreeMap<String, String>>,
+    name_of: &dyn Fn(&str) -> String,
+) -> Result<Vec<PositionChange>, DbError> {
+    let mut changes = Vec::new();
+    let mut open_now: BTreeSet<(String, String)> = BTreeSet::new();
+    for (politician_id, ticker, shares) in open_positions(db)? {
+        let previous = then
+            .get(&politician_id)
+            .and_then(|tickers| tickers.get(&ticker));
+        let change = match previous {
+            None => Some(PositionChangeKind::Opened),
+            Some(hash) if *hash != shares_hash(shares) => Some(PositionChangeKind::Changed),
+            Some(_) => None,
+        };
+        if let Some(change) = change {
+            changes.push(PositionChange {
+                politician_name: name_of(&politician_id),
+                politician_id: politician_id.clone(),
+                ticker: ticker.clone(),
+                change,
+                shares_held: Some(shares),
+            });
+        }
+        open_now.insert((politician_id, ticker));
+    }
+    for (politician_id, tickers) in then {
+        for ticker in tickers.keys() {
+            if !open_now.contains(&(politician_id.clone(), ticker.clone())) {
+                changes.push(PositionChange {
+                    politician_id: politician_id.clone(),
+                    politician_name: name_of(politician_id),
+                    ticker: ticker.clone(),
+                    change: PositionChangeKind::Closed,
+                    shares_held: None,
+                });
+            }
+        }
+    }
+    changes.sort_by(|a, b| {
+        a.politician_name
+            .cmp(&b.politician_name)
+            .then(a.ticker.cmp(&b.ticker))
+    });
+    Ok(changes)
+}
+
+/// Leaderboard by average return over all closed trades, as `analytics`
+/// ranks it by default.
+fn leaderboard_ranks(db: &Db) -> Result<BTreeMap<String, RankSummary>, DbError> {
+    let trades = TradeUniverse::priced_stocks(db).build()?.analytics_trades();
+    let trade_metrics: Vec<_> = calculate_closed_trades(trades, false)
+        .iter()
+        .map(compute_trade_metrics)
+        .collect();
+    // Ranks only need the point estimates, so skip the bootstrap.
+    let intervals = IntervalConfig {
+        bootstrap_iterations: 0,
+        ..IntervalConfig::default()
+    };
+    let mut metrics = aggregate_politician_metrics(&trade_metrics, &intervals);
+    metrics.sort_by(|a, b| {
+        b.avg_return
+            .partial_cmp(&a.avg_return)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.politician_id.cmp(&b.politician_id))
+    });
+    Ok(metrics
+        .into_iter()
+        .enumerate()
+        .map(|(idx, m)| {
+            (
+                m.politician_id,
+                RankSummary {
+                    rank: idx + 1,
+                    avg_return: m.avg_return,
+                    closed_trades: m.total_trades,
+                },
+            )
+        })
+        .collect())
+}
+
+struct CurrentAnomalies {
+    summary: AnomalySummary,
+    pre_move: Vec<PreMoveSignal>,
+    volume_ratios: HashMap<String, f64>,
+}
+
+/// Pre-move, volume and composite signals with the default thresholds.
+fn current_anomalies(db: &Db, today: NaiveDate) -> Result<CurrentAnomalies, DbError> {
+    let config = AnomalyConfig::default();
+    let universe = TradeUniverse::priced_stocks(db).build()?;
+    let candidates = future_price_candidates(&universe.trades, today);
+    let pre_move = detect_pre_move_trades(&candidates, config.pre_move.threshold_pct, None);
+    let volume =
+        detect_unusual_volume_universe(&universe.trades, &VolumeParams::from_config(today, &config.volume));
+
+    let mut by_politician: HashMap<String, Vec<PortfolioPositionForHHI>> = HashMap::new();
+    for row in db.query_portfolio_positions_for_hhi()? {
+        by_politician
+            .entry(row.politician_id)
+            .or_default()
+            .push(PortfolioPositionForHHI {
+                ticker: row.ticker,
+                gics_sector: row.gics_sector,
+                estimated_value: row.estimated_value,
+            });
+    }
+
+    let mut pre_move_counts: HashMap<&str, usize> = HashMap::new();
+    for signal in &pre_move {
+        *pre_move_counts.entry(signal.politician_id.as_str()).or_default() += 1;
+    }
+    let volume_ratios: HashMap<String, f64> = volume
+        .iter()
+        .map(|v| (v.politician_id.clone(), v.signal.volume_ratio))
+        .collect();
+
+    let politicians: BTreeSet<&str> = pre_move_counts
+        .keys()
+        .copied()
+        .chain(volume_ratios.keys().map(String::as_str))
+        .chain(by_politician.keys().map(String::as_str))
+        .collect();
+    let composites = politicians
+        .into_iter()
+        .map(|id| {
+            let hhi = by_politician
+                .get(id)
+                .map(|positions| calculate_sector_concentration(positions, &config.concentration).hhi_score)
+                .unwrap_or(0.0);
+            let score = calculate_composite_anomaly_score(
+                pre_move_counts.get(id).copied().unwrap_or(0),
+                volume_ratios.get(id).copied().unwrap_or(0.0),
+                hhi,
+                &config.composite,
+            );
+            (id.to_string(), (score.composite * 10_000.0).round() / 10_000.0)
+        })
+        .collect();
+
+    Ok(CurrentAnomalies {
+        summary: AnomalySummary {
+            pre_move_tx_ids: pre_move.iter().map(|s| s.tx_id).collect(),
+            unusual_volume: volume
+                .iter()
+                .filter(|v| v.signal.is_unusual)
+                .map(|v| v.politician_id.clone())
+                .collect(),
+            composites,
+        },
+        pre_move,
+        volume_ratios,
+    })
+}
+
+fn new_anomalies(
+    db: &Db,
+    then: &AnomalySummary,
+    today: NaiveDate,
+    name_of: &dyn Fn(&str) -> String,
+) -> Result<Vec<NewAnomaly>, DbError> {
+    let now = current_anomalies(db, today)?;
+    let composite = |id: &str| now.summary.composites.get(id).copied().unwrap_or(0.0);
+
+    let mut changes: Vec<NewAnomaly> = now
+        .pre_move
+        .iter()
+        .filter(|s| !then.pre_move_tx_ids.contains(&s.tx_id))
+        .map(|s| NewAnomaly {
+            politician_id: s.politician_id.clone(),
+            politician_name: name_of(&s.politician_id),
+            signal: "pre_move".to_string(),
+            detail: format!(
+                "{} {} on {}, {:+.1}% in 30 days",
+                s.tx_type, s.ticker, s.tx_date, s.price_change_pct
+            ),
+            composite: composite(&s.politician_id),
+        })
+        .collect();
+    changes.extend(
+        now.summary
+            .unusual_volume
+            .difference(&then.unusual_volume)
+            .map(|id| NewAnomaly {
+                politician_id: id.clone(),
+                politician_name: name_of(id),
+                signal: "unusual_volume".to_string(),
+                detail: format!(
+                    "volume ratio {:.2}",
+                    now.volume_ratios.get(id).copied().unwrap_or(0.0)
+                ),
+                composite: composite(id),
+            }),
+    );
+    changes.sort_by(|a, b| {
+        b.composite
+            .partial_cmp(&a.composite)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.politician_name.cmp(&b.politician_name))
+    });
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{open_db, scraped_trade};
+    use crate::portfolio::Position;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()
+    }
+
+    fn options(rank_threshold: usize) -> DiffOptions {
+        DiffOptions {
+            rank_threshold,
+            today: today(),
+        }
+    }
+
+    /// Insert a trade and, when `price` is given, enrich it at that price.
+    fn trade(
+        db: &mut Db,
+        tx_id: i64,
+        politician_id: &str,
+        issuer_id: i64,
+        tx: (&str, &str),
+        price: Option<f64>,
+    ) {
+        holding(db, tx_id, politician_id, issuer_id, tx, price.map(|price| (price, 100.0)));
+    }
+
+    /// Insert a trade of `shares` at `price` when given. Issuers 1-3 are
+    /// AAPL, MSFT and NVDA.
+    fn holding(
+        db: &mut Db,
+        tx_id: i64,
+        politician_id: &str,
+        issuer_id: i64,
+        tx: (&str, &str),
+        priced: Option<(f64, f64)>,
+    ) {
+        let mut t = scraped_trade(tx_id, politician_id, issuer_id);
+        t.tx_type = tx.0.to_string();
+        t.tx_date = tx.1.to_string();
+        t.issuer.issuer_ticker = Some(["AAPL", "MSFT", "NVDA"][issuer_id as usize - 1].to_string());
+        db.upsert_scraped_trades(&[t]).unwrap();
+        if let Some((price, shares)) = priced {
+            db.update_trade_prices(tx_id, Some(price), Some(shares), Some(shares * price), Some("test"))
+                .unwrap();
+        }
+    }
+
+    fn positions(entries: &[(&str, &str, f64)]) -> HashMap<(String, String), Position> {
+        entries
+            .iter()
+            .map(|(politician_id, ticker, shares)| {
+                let mut position = Position::new(politician_id.to_string(), ticker.to_string());
+                position.buy(*shares, 10.0, "2024-01-02".to_string());
+                ((politician_id.to_string(), ticker.to_string()), position)
+            })
+            .collect()
+    }
+
+    /// Three politicians with one closed round trip each, returning 10%, 5%
+    /// and 1%, plus two open positions. The stored positions table is left
+    /// empty: snapshots rebuild positions from trades.
+    fn seeded_db() -> Db {
+        let mut db = open_db();
+        for (i, (id, exit)) in [("P000001", 110.0), ("P000002", 105.0), ("P000003", 101.0)]
+            .into_iter()
+            .enumerate()
+        {
+            let base = i as i64 * 10;
+            trade(&mut db, base + 1, id, i as i64 + 1, ("buy", "2024-01-10"), Some(100.0));
+            trade(&mut db, base + 2, id, i as i64 + 1, ("sell", "2024-03-10"), Some(exit));
+        }
+        holding(&mut db, 3, "P000001", 1, ("buy", "2024-04-01"), Some((100.0, 10.0)));
+        holding(&mut db, 4, "P000002", 2, ("buy", "2024-04-01"), Some((100.0, 5.0)));
+        db
+    }
+
+    #[test]
+    fn test_snapshot_names() {
+        assert!(validate_snapshot_name("before-sync_2025.10").is_ok());
+        for bad in ["", "has space", "semi;colon", &"x".repeat(65)] {
+            assert!(matches!(
+                validate_snapshot_name(bad),
+                Err(SnapshotError::InvalidName(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_each_section() {
+        let mut db = seeded_db();
+        let summary = create_snapshot(&db, "base", today()).unwrap();
+        assert_eq!(summary.trades.max_tx_id, Some(22));
+        assert_eq!(summary.ranks["P000003"].rank, 3);
+        assert!(summary.anomalies.unusual_volume.is_empty());
+
+        let unchanged = compare(&db, "base", &options(0)).unwrap();
+        assert_eq!(unchanged.new_trades.changes().unwrap().len(), 0);
+        assert_eq!(unchanged.positions.changes().unwrap().len(), 0);
+        assert_eq!(unchanged.ranks.changes().unwrap().len(), 0);
+        assert_eq!(unchanged.anomalies.changes().unwrap().len(), 0);
+
+        // A doubled round trip lifts P000003 from third to first.
+        trade(&mut db, 23, "P000003", 3, ("buy", "2024-05-01"), Some(100.0));
+        trade(&mut db, 24, "P000003", 3, ("sell", "2024-06-01"), Some(200.0));
+        // One baseline trade, then a burst in the last 90 days, priced so
+        // they are in the universe the volume scan reads.
+        trade(&mut db, 30, "P000001", 1, ("buy", "2025-03-01"), Some(100.0));
+        for tx_id in 31..36 {
+            trade(&mut db, tx_id, "P000001", 1, ("buy", "2025-12-01"), Some(100.0));
+        }
+        holding(&mut db, 36, "P000001", 1, ("buy", "2024-07-01"), Some((100.0, 10.0)));
+        holding(&mut db, 37, "P000002", 2, ("sell", "2024-07-01"), Some((100.0, 5.0)));
+        holding(&mut db, 38, "P000003", 3, ("buy", "2024-07-01"), Some((200.0, 7.0)));
+        // A stale positions table is ignored.
+        db.replace_positions(&positions(&[("P000002", "MSFT", 5.0)])).unwrap();
+
+        let diff = compare(&db, "base", &options(1)).unwrap();
+        assert_eq!(diff.snapshot, "base");
+
+        let new_ids: Vec<i64> = diff
+            .new_trades
+            .changes()
+            .unwrap()
+            .iter()
+            .map(|t| t.tx_id)
+            .collect();
+        assert_eq!(new_ids.len(), 11);
+        assert!(new_ids.iter().all(|id| *id > 22));
+
+        let positions: Vec<(&str, &str, PositionChangeKind)> = diff
+            .positions
+            .changes()
+            .unwrap()
+            .iter()
+            .map(|p| (p.politician_id.as_str(), p.ticker.as_str(), p.change))
+            .collect();
+        assert_eq!(positions.len(), 3);
+        assert!(positions.contains(&("P000001", "AAPL", PositionChangeKind::Changed)));
+        assert!(positions.contains(&("P000002", "MSFT", PositionChangeKind::Closed)));
+        assert!(positions.contains(&("P000003", "NVDA", PositionChangeKind::Opened)));
+
+        // Only P000003 moved more than one place.
+        let ranks = diff.ranks.changes().unwrap();
+        assert_eq!(ranks.len(), 1);
+        assert_eq!(ranks[0].politician_id, "P000003");
+        assert_eq!((ranks[0].old_rank, ranks[0].new_rank, ranks[0].moved), (3, 1, 2));
+
+        // The burst is unusual volume; the doubled buy is a pre-move trade.
+        let anomalies: Vec<(&str, &str)> = diff
+            .anomalies
+            .changes()
+            .unwrap()
+            .iter()
+            .map(|a| (a.politician_id.as_str(), a.signal.as_str()))
+            .collect();
+        assert_eq!(
+            anomalies,
+            vec![("P000001", "unusual_volume"), ("P000003", "pre_move")]
+        );
+    }
+
+    #[test]
+    fn test_old_or_partial_snapshots_degrade_per_section() {
+        let mut db = seeded_db();
+        db.save_snapshot(
+            "old",
+            &[
+                section(TRADES, TRADES_FORMAT, &TradeSummary {
+                    max_tx_id: Some(12),
+                    trade_count: 4,
+                }),
+                SnapshotSection {
+                    section: RANKS.to_string(),
+                    format: RANKS_FORMAT + 1,
+                    data: "[]".to_string(),
+                },
+                SnapshotSection {
+                    section: POSITIONS.to_string(),
+                    format: POSITIONS_FORMAT,
+                    data: "not json".to_string(),
+                },
+            ],
+        )
+        .unwrap();
+        trade(&mut db, 40, "P000002", 2, ("buy", "2024-08-01"), None);
+
+        let diff = compare(&db, "old", &options(0)).unwrap();
+        assert_eq!(diff.new_trades.changes().unwrap().len(), 3);
+        assert!(diff.positions.changes().is_none());
+        assert!(diff.ranks.changes().is_none());
+        assert!(diff.anomalies.changes().is_none());
+        let SectionDiff::Unavailable { reason } = &diff.anomalies else {
+            unreachable!()
+        };
+        assert!(reason.contains("no anomalies section"), "{reason}");
+
+        assert!(matches!(
+            compare(&db, "missing", &options(0)),
+            Err(SnapshotError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_replaces_and_delete_removes() {
+        let db = seeded_db();
+        create_snapshot(&db, "base", today()).unwrap();
+        create_snapshot(&db, "base", today()).unwrap();
+        let listed = db.list_snapshots().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].sections, 4);
+        assert!(db.delete_snapshot("base").unwrap());
+        assert!(!db.delete_snapshot("base").unwrap());
+        assert!(db.load_snapshot("base").unwrap().is_none());
+    }
+}
@@ -0,0 +1,146 @@
+//! Storage backend abstraction for read queries.
+//!
+//! [`TradeStore`] captures the read side of the database: the filtered trade,
+//! politician, issuer, donation, and portfolio queries that the CLI renders.
+//! [`Db`] is the SQLite implementation; other backends (an in-memory store for
+//! tests, Postgres for larger deployments) only need to return the same row
+//! types. Writes, sync bookkeeping, and enrichment queues stay on [`Db`].
+
+use crate::db::{
+    AnalyticsTradeRow, Db, DbError, DbIssuerFilter, DbIssuerRow, DbPoliticianFilter,
+    DbPoliticianRow, DbTradeFilter, DbTradeRow, DonationFilter, DonationRow, PortfolioFilter,
+    PortfolioPosition, TradeRevisionRow,
+};
+use crate::portfolio::TradeFIFO;
+
+/// Read-only query interface over stored trades, politicians, and issuers.
+pub trait TradeStore {
+    /// Backend error type; must convert into `anyhow::Error` for CLI callers.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Trades matching `filter`, most recently published first.
+    fn query_trades(&self, filter: &DbTradeFilter) -> Result<Vec<DbTradeRow>, Self::Error>;
+
+    /// Politicians with stats and committee memberships, ordered by the
+    /// filter's sort key.
+    fn query_politicians(
+        &self,
+        filter: &DbPoliticianFilter,
+    ) -> Result<Vec<DbPoliticianRow>, Self::Error>;
+
+    /// Issuers with stats and performance data.
+    fn query_issuers(&self, filter: &DbIssuerFilter) -> Result<Vec<DbIssuerRow>, Self::Error>;
+
+    /// Individual FEC donations matching `filter`, largest first.
+    fn query_donations(&self, filter: &DonationFilter) -> Result<Vec<DonationRow>, Self::Error>;
+
+    /// Materialized portfolio positions matching `filter`.
+    fn get_portfolio(&self, filter: &PortfolioFilter)
+        -> Result<Vec<PortfolioPosition>, Self::Error>;
+
+    /// Price-enriched stock trades in chronological order, for FIFO analytics.
+    fn query_trades_for_analytics(&self) -> Result<Vec<AnalyticsTradeRow>, Self::Error>;
+
+    /// Price-enriched trades in FIFO order, for position calculation.
+    fn query_trades_for_portfolio(&self) -> Result<Vec<TradeFIFO>, Self::Error>;
+
+    /// Recorded upstream edits of one trade, oldest first.
+    fn get_trade_revisions(&self, tx_id: i64) -> Result<Vec<TradeRevisionRow>, Self::Error>;
+}
+
+impl TradeStore for Db {
+    type Error = DbError;
+
+    fn query_trades(&self, filter: &DbTradeFilter) -> Result<Vec<DbTradeRow>, DbError> {
+        Db::query_trades(self, filter)
+    }
+
+    fn query_politicians(
+        &self,
+        filter: &DbPoliticianFilter,
+    ) -> Result<Vec<DbPoliticianRow>, DbError> {
+        Db::query_politicians(self, filter)
+    }
+
+    fn query_issuers(&self, filter: &DbIssuerFilter) -> Result<Vec<DbIssuerRow>, DbError> {
+        Db::query_issuers(self, filter)
+    }
+
+    fn query_donations(&self, filter: &DonationFilter) -> Result<Vec<DonationRow>, DbError> {
+        Db::query_donations(self, filter)
+    }
+
+    fn get_portfolio(&self, filter: &PortfolioFilter) -> Result<Vec<PortfolioPosition>, DbError> {
+        Db::get_portfolio(self, filter)
+    }
+
+    fn query_trades_for_analytics(&self) -> Result<Vec<AnalyticsTradeRow>, DbError> {
+        Db::query_trades_for_analytics(self)
+    }
+
+    fn query_trades_for_portfolio(&self) -> Result<Vec<TradeFIFO>, DbError> {
+        Db::query_trades_for_portfolio(self)
+    }
+
+    fn get_trade_revisions(&self, tx_id: i64) -> Result<Vec<TradeRevisionRow>, DbError> {
+        Db::get_trade_revisions(self, tx_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::FixtureDb;
+
+    fn politician_ids<S: TradeStore>(store: &S, filter: &DbPoliticianFilter) -> Vec<String> {
+        store
+            .query_politicians(filter)
+            .expect("query through trait")
+            .into_iter()
+            .map(|row| row.politician_id)
+            .collect()
+    }
+
+    #[test]
+    fn test_db_trait_queries_match_inherent_methods() {
+        let fx = FixtureDb::builder()
+            .politicians(2)
+            .trades_per_politician(6)
+            .with_price_enrichment()
+            .with_donations(3)
+            .build()
+            .expect("build fixture");
+        let db: &Db = &fx;
+
+        let filter = DbPoliticianFilter {
+            include_inactive: true,
+            ..Default::default()
+        };
+        let inherent: Vec<String> = db
+            .query_politicians(&filter)
+            .unwrap()
+            .into_iter()
+            .map(|row| row.politician_id)
+            .collect();
+        assert_eq!(inherent.len(), 2);
+        assert_eq!(politician_ids(db, &filter), inherent);
+
+        let trade_ids = |rows: Vec<DbTradeRow>| rows.into_iter().map(|t| t.tx_id).collect::<Vec<_>>();
+        let trades = TradeStore::query_trades(db, &DbTradeFilter::default()).unwrap();
+        assert_eq!(trades.len(), 12);
+        assert_eq!(
+            trade_ids(trades),
+            trade_ids(db.query_trades(&DbTradeFilter::default()).unwrap())
+        );
+        assert_eq!(TradeStore::query_trades_for_analytics(db).unwrap().len(), 12);
+        assert_eq!(
+            TradeStore::query_donations(db, &DonationFilter::default()).unwrap().len(),
+            6
+        );
+        let tx_id = db.query_trades(&DbTradeFilter::default()).unwrap()[0].tx_id;
+        assert_eq!(
+            TradeStore::get_trade_revisions(db, tx_id).unwrap().len(),
+            db.get_trade_revisions(tx_id).unwrap().len()
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! "Did you mean" suggestions for names and tickers that matched nothing.
+//!
+//! Candidates are scored by string similarity against the query: the better
+//! of trigram overlap (Jaccard over padded character trigrams, robust to
+//! transpositions inside long names) and normalized Damerau-Levenshtein
+//! distance (which handles short tickers, where one typo wipes out most
+//! trigrams). Each candidate key is compared whole and word by word, so
+//! "Pelossi" finds "Nancy Pelosi". The database side lives in
+//! [`crate::db::Db::suggest_politicians`] and
+//! [`crate::db::Db::suggest_issuers`].
+
+use std::collections::HashSet;
+
+/// Suggestions returned by default.
+pub const DEFAULT_SUGGESTIONS: usize = 3;
+
+/// Most issuer rows scanned for one suggestion lookup.
+pub const ISSUER_SCAN_LIMIT: i64 = 20_000;
+
+/// Similarity below which a candidate is not worth suggesting.
+pub const MIN_SIMILARITY: f64 = 0.5;
+
+/// A candidate the user might have meant.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    /// What to pass back on the command line: a politician ID or a ticker.
+    pub value: String,
+    /// How to show it, e.g. "Nancy Pelosi (P000197)".
+    pub label: String,
+    /// Similarity in [0, 1].
+    pub score: f64,
+}
+
+/// Something a query can be matched against.
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub value: String,
+    pub label: String,
+    /// Strings compared with the query, e.g. a full name, or a ticker and
+    /// an issuer name.
+    pub keys: Vec<String>,
+}
+
+/// Lowercase, with everything but letters, digits and single spaces dropped.
+fn normalize(s: &str) -> String {
+    let mapped: String = s
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect();
+    mapped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Character trigrams of `s`, padded with two leading blanks and one
+/// trailing blank so word starts weigh more than word ends.
+fn trigrams(s: &str) -> HashSet<[char; 3]> {
+    let padded: Vec<char> = "  ".chars().chain(s.chars()).chain(" ".chars()).collect();
+    padded.windows(3).map(|w| [w[0], w[1], w[2]]).collect()
+}
+
+/// Similarity of two normalized strings in [0, 1].
+pub fn similarity(a: &str, b: &str) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let (ta, tb) = (trigrams(a), trigrams(b));
+    let shared = ta.intersection(&tb).count();
+    let jaccard = shared as f64 / (ta.len() + tb.len() - shared) as f64;
+    jaccard.max(strsim::normalized_damerau_levenshtein(a, b))
+}
+
+/// Whether two lengths are close enough for a typo to explain the gap.
+fn plausible_length(query: usize, key: usize) -> bool {
+    query.abs_diff(key) <= (query / 2).max(2)
+}
+
+/// Best similarity of `query` against `key` and each of its words.
+fn score_key(query: &str, key: &str) -> f64 {
+    let key = normalize(key);
+    let query_len = query.chars().count();
+    let mut best = 0.0_f64;
+    if plausible_length(query_len, key.chars().count()) {
+        best = similarity(query, &key);
+    }
+    if !query.contains(' ') {
+        for word in key.split(' ') {
+            if plausible_length(query_len, word.chars().count()) {
+                best = best.max(similarity(query, word));
+            }
+        }
+    }
+    best
+}
+
+/// The `limit` candidates most similar to `query`, best first, leaving out
+/// any below [`MIN_SIMILARITY`]. An exact match is not special-cased: the
+/// caller only asks once the query matched nothing.
+pub fn rank(
+    query: &str,
+    candidates: impl IntoIterator<Item = Candidate>,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut scored: Vec<Suggestion> = candidates
+        .into_iter()
+        .filter_map(|c| {
+            let score = c
+                .keys
+                .iter()
+                .map(|key| score_key(&query, key))
+                .fold(0.0, f64::max);
+            (score >= MIN_SIMILARITY).then_some(Suggestion {
+                value: c.value,
+                label: c.label,
+                score,
+            })
+        })
+        .collect();
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.label.cmp(&b.label)));
+    let mut seen = HashSet::new();
+    scored.retain(|s| seen.insert(s.value.clone()));
+    scored.truncate(limit);
+    scored
+}
+
+/// A "did you mean" line listing `suggestions` by label, or `None` when
+/// there are none.
+pub fn did_you_mean(suggestions: &[Suggestion]) -> Option<String> {
+    if suggestions.is_empty() {
+        return None;
+    }
+    let labels: Vec<&str> = suggestions.iter().map(|s| s.label.as_str()).collect();
+    Some(format!("Did you mean: {}?", labels.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn politicians() -> Vec<Candidate> {
+        [
+            ("P000197", "Nancy Pelosi"),
+            ("P000608", "Mike Pence"),
+            ("G000583", "Josh Gottheimer"),
+            ("C001120", "Dan Crenshaw"),
+        ]
+        .iter()
+        .map(|(id, name)| Candidate {
+            value: id.to_string(),
+            label: format!("{} ({})", name, id),
+            keys: vec![name.to_string()],
+        })
+        .collect()
+    }
+
+    fn issuers() -> Vec<Candidate> {
+        [
+            ("NVDA", "NVIDIA Corp"),
+            ("NVO", "Novo Nordisk A/S"),
+            ("AAPL", "Apple Inc"),
+            ("MSFT", "Microsoft Corp"),
+            ("XOM", "Exxon Mobil Corp"),
+        ]
+        .iter()
+        .map(|(ticker, name)| Candidate {
+            value: format!("{}:US", ticker),
+            label: format!("{}:US ({})", ticker, name),
+            keys: vec![ticker.to_string(), name.to_string()],
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_suggests_misspelled_politician() {
+        let suggestions = rank("Pelossi", politicians(), DEFAULT_SUGGESTIONS);
+        assert_eq!(suggestions[0].value, "P000197");
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(
+            did_you_mean(&suggestions).unwrap(),
+            "Did you mean: Nancy Pelosi (P000197)?"
+        );
+    }
+
+    #[test]
+    fn test_suggests_misspelled_ticker() {
+        let suggestions = rank("NVDIA", issuers(), DEFAULT_SUGGESTIONS);
+        assert_eq!(suggestions[0].value, "NVDA:US");
+        assert!(suggestions.iter().all(|s| s.value != "AAPL:US"));
+    }
+
+    #[test]
+    fn test_no_suggestions_when_nothing_is_close() {
+        assert!(rank("Zzyzx", politicians(), DEFAULT_SUGGESTIONS).is_empty());
+        assert!(rank("QQQQQQ", issuers(), DEFAULT_SUGGESTIONS).is_empty());
+        assert!(rank("", issuers(), DEFAULT_SUGGESTIONS).is_empty());
+        assert_eq!(did_you_mean(&[]), None);
+    }
+
+    #[test]
+    fn test_similarity_bounds() {
+        assert_eq!(similarity("pelosi", "pelosi"), 1.0);
+        assert_eq!(similarity("", "pelosi"), 0.0);
+        assert!(similarity("pelossi", "pelosi") > similarity("pelossi", "pence"));
+    }
+}
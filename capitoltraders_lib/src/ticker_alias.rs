@@ -6,7 +6,7 @@
 //!
 //! Follows the same compile-time `include_str!` pattern as `sector_mapping.rs`.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -17,6 +17,10 @@ pub enum TickerAliasError {
     YamlParse(#[from] serde_yml::Error),
     #[error("Duplicate 'from' ticker in alias file: {0}")]
     DuplicateFrom(String),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("line {line}: {message}")]
+    InvalidRow { line: u64, message: String },
 }
 
 /// Top-level structure for ticker alias YAML file.
@@ -30,10 +34,19 @@ pub struct TickerAliasFile {
 /// `from` is the raw CapitolTrades ticker (e.g., "ATVI:US").
 /// `to` is the Yahoo Finance equivalent, or `None` if the ticker is
 /// known to be unenrichable (delisted with no successor, money market funds, etc.).
-#[derive(Deserialize, Debug, Clone)]
+/// `source` records where the alias came from: `import` (the `aliases`
+/// command, and the default when a file has no `source` column) or `suffix`
+/// (an exchange suffix found by `enrich-prices`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct TickerAlias {
     pub from: String,
     pub to: Option<String>,
+    #[serde(default = "import_source")]
+    pub source: String,
+}
+
+fn import_source() -> String {
+    "import".to_string()
 }
 
 /// Parse ticker aliases from YAML content.
@@ -62,6 +75,66 @@ pub fn load_ticker_aliases() -> Result<HashMap<String, Option<String>>, TickerAl
     parse_ticker_aliases(yaml_content)
 }
 
+/// Whether `ticker` looks like a CapitolTrades or Yahoo symbol: 1-20
+/// characters drawn from ASCII letters, digits and `. - : ^ = /`.
+pub fn is_valid_ticker(ticker: &str) -> bool {
+    !ticker.is_empty()
+        && ticker.len() <= 20
+        && ticker
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || ".-:^=/".contains(c))
+}
+
+/// Parse ticker aliases from CSV with `from,to` columns and an optional
+/// `source` column; an empty `to` marks the ticker as known-unenrichable,
+/// like `to: ~` in the YAML file.
+///
+/// Invalid tickers and repeated `from` values are rejected with their line
+/// number.
+pub fn parse_ticker_aliases_csv<R: std::io::Read>(
+    reader: R,
+) -> Result<Vec<TickerAlias>, TickerAliasError> {
+    let mut rdr = csv::Reader::from_reader(reader);
+    let headers = rdr.headers()?.clone();
+    let mut seen = std::collections::HashSet::new();
+    let mut aliases = Vec::new();
+
+    for result in rdr.records() {
+        let row = result?;
+        let line = row.position().map_or(0, |pos| pos.line());
+        let invalid = |message: String| TickerAliasError::InvalidRow { line, message };
+        let alias: TickerAlias = row
+            .deserialize(Some(&headers))
+            .map_err(|e| invalid(e.to_string()))?;
+
+        if !is_valid_ticker(&alias.from) {
+            return Err(invalid(format!("invalid 'from' ticker '{}'", alias.from)));
+        }
+        if let Some(to) = alias.to.as_deref().filter(|to| !is_valid_ticker(to)) {
+            return Err(invalid(format!("invalid 'to' ticker '{}'", to)));
+        }
+        if !seen.insert(alias.from.clone()) {
+            return Err(invalid(format!("duplicate 'from' ticker '{}'", alias.from)));
+        }
+        aliases.push(alias);
+    }
+
+    Ok(aliases)
+}
+
+/// Write ticker aliases as CSV (the format [`parse_ticker_aliases_csv`] reads).
+pub fn write_ticker_aliases_csv<W: std::io::Write>(
+    writer: W,
+    aliases: &[TickerAlias],
+) -> Result<(), TickerAliasError> {
+    let mut wtr = csv::Writer::from_writer(writer);
+    for alias in aliases {
+        wtr.serialize(alias)?;
+    }
+    wtr.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +216,24 @@ aliases:
         assert_eq!(result.get("VMFXX:US"), Some(&None));
         assert_eq!(result.get("CDAY:US"), Some(&Some("DAY".to_string())));
     }
+
+    #[test]
+    fn test_alias_csv_empty_to_and_line_numbers() {
+        let csv = "from,to\nATVI:US,MSFT\nVMFXX:US,\n";
+        let aliases = parse_ticker_aliases_csv(csv.as_bytes()).unwrap();
+        assert_eq!(aliases[1].to, None);
+        assert_eq!(aliases[0].source, "import");
+
+        let csv = "from,to,source\nSAP:US,SAP.DE,suffix\n";
+        let aliases = parse_ticker_aliases_csv(csv.as_bytes()).unwrap();
+        assert_eq!(aliases[0].source, "suffix");
+
+        let csv = "from,to\nATVI:US,MSFT\nBAD TICKER,X\n";
+        let err = parse_ticker_aliases_csv(csv.as_bytes()).unwrap_err();
+        assert!(matches!(err, TickerAliasError::InvalidRow { line: 3, .. }), "{err}");
+
+        let csv = "from,to\nATVI:US,MSFT\nATVI:US,ACTIVISION\n";
+        let err = parse_ticker_aliases_csv(csv.as_bytes()).unwrap_err();
+        assert!(matches!(err, TickerAliasError::InvalidRow { line: 3, .. }), "{err}");
+    }
 }
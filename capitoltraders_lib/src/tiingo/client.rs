@@ -5,17 +5,37 @@
 
 use super::error::TiingoError;
 use super::types::TiingoDailyPrice;
+use crate::cache::LruCache;
+use crate::config::AppConfig;
+use crate::observer::{self, ObserverSlot, RequestObserver, Service};
+use crate::ratelimit::{HostLimiter, Limiter, Rate};
 use chrono::NaiveDate;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Request timeout for Tiingo API calls.
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Default pacing, matching the Yahoo client it backs up: bursts of 5, about
+/// 14 requests per second sustained.
+/// Production API endpoint.
+pub const DEFAULT_BASE_URL: &str = "https://api.tiingo.com";
+
+pub const DEFAULT_RATE: Rate = Rate::new(5, Duration::from_millis(350));
+
+/// Default number of (ticker, start, end) price series kept in memory.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+type SeriesKey = (String, NaiveDate, NaiveDate);
+
 /// Tiingo REST API client for end-of-day historical prices.
 pub struct TiingoClient {
     client: reqwest::Client,
     api_key: String,
     base_url: String,
+    observer: ObserverSlot,
+    limiter: HostLimiter,
+    series: LruCache<SeriesKey, Arc<Vec<TiingoDailyPrice>>>,
 }
 
 impl TiingoClient {
@@ -24,10 +44,14 @@ impl TiingoClient {
         let client = reqwest::Client::builder()
             .timeout(REQUEST_TIMEOUT)
             .build()?;
+        let base_url = DEFAULT_BASE_URL.to_string();
         Ok(Self {
             client,
             api_key,
-            base_url: "https://api.tiingo.com".to_string(),
+            limiter: HostLimiter::new(Arc::new(Limiter::new(DEFAULT_RATE)), &base_url),
+            base_url,
+            observer: ObserverSlot::default(),
+            series: LruCache::new(DEFAULT_CACHE_CAPACITY),
         })
     }
 
@@ -36,45 +60,142 @@ impl TiingoClient {
         let client = reqwest::Client::builder()
             .timeout(REQUEST_TIMEOUT)
             .build()?;
+        let base_url = base_url.to_string();
         Ok(Self {
             client,
             api_key,
-            base_url: base_url.to_string(),
+            limiter: HostLimiter::new(Arc::new(Limiter::new(DEFAULT_RATE)), &base_url),
+            base_url,
+            observer: ObserverSlot::default(),
+            series: LruCache::new(DEFAULT_CACHE_CAPACITY),
         })
     }
 
+    /// Build from the `[tiingo]` settings of a resolved config. Returns `None`
+    /// when no API key is configured.
+    pub fn from_config(config: &AppConfig) -> Result<Option<Self>, TiingoError> {
+        let Some(api_key) = config.tiingo.api_key.clone() else {
+            return Ok(None);
+        };
+        let base_url = config.tiingo.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+        let mut client = Self::with_base_url(base_url, api_key)?;
+        if let Some(rate) = config.tiingo.rate {
+            client = client.with_rate(rate.to_rate());
+        }
+        if let Some(capacity) = config.tiingo.cache_capacity {
+            client = client.with_cache_capacity(capacity);
+        }
+        Ok(Some(client))
+    }
+
+    /// Report every request to `observer` (see [`crate::observer`]).
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = ObserverSlot::new(observer);
+        self
+    }
+
+    /// Pace requests through a shared limiter (see [`crate::ratelimit`])
+    /// instead of the default [`DEFAULT_RATE`].
+    pub fn with_limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter.replace(limiter);
+        self
+    }
+
+    /// Pace requests at `rate` instead of [`DEFAULT_RATE`].
+    pub fn with_rate(self, rate: Rate) -> Self {
+        self.with_limiter(Arc::new(Limiter::new(rate)))
+    }
+
+    /// Keep up to `capacity` fetched price series in memory, keyed by
+    /// (ticker, start, end). Zero disables the cache.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.series = LruCache::new(capacity);
+        self
+    }
+
     /// Get the adjusted close price for a ticker on a specific date.
     ///
     /// Returns `Ok(Some(price))` if data is found.
     /// Returns `Ok(None)` if the ticker is unknown (404) or no data exists for that date (empty array).
     /// Returns `Err(TiingoError::RateLimited)` if Tiingo returns a rate limit response.
-    ///
-    /// Tiingo quirk: rate limits return HTTP 200 with Content-Type text/plain
-    /// instead of a proper 429 status code.
     pub async fn get_price_on_date(
         &self,
         ticker: &str,
         date: NaiveDate,
     ) -> Result<Option<f64>, TiingoError> {
-        let date_str = date.format("%Y-%m-%d").to_string();
+        let prices = self.daily_prices(ticker, date, date).await?;
+        Ok(prices.first().map(|p| p.adj_close))
+    }
+
+    /// Dated adjusted closes for `ticker` from `start` through `end`, oldest
+    /// first. Records with an unparseable date are dropped.
+    pub async fn daily_closes(
+        &self,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, TiingoError> {
+        let prices = self.daily_prices(ticker, start, end).await?;
+        Ok(prices
+            .iter()
+            .filter_map(|p| {
+                let date = NaiveDate::parse_from_str(p.date.get(..10)?, "%Y-%m-%d").ok()?;
+                Some((date, p.adj_close))
+            })
+            .collect())
+    }
+
+    /// Daily prices for `ticker` from `start` through `end`, served from the
+    /// series cache when the same range was fetched before. An unknown ticker
+    /// (404) yields an empty series; errors are never cached.
+    ///
+    /// Tiingo quirk: rate limits return HTTP 200 with Content-Type text/plain
+    /// instead of a proper 429 status code.
+    async fn daily_prices(
+        &self,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Arc<Vec<TiingoDailyPrice>>, TiingoError> {
+        let key = (ticker.to_string(), start, end);
+        if let Some(cached) = self.series.get(&key) {
+            return Ok(cached);
+        }
+
+        let start_str = start.format("%Y-%m-%d").to_string();
+        let end_str = end.format("%Y-%m-%d").to_string();
         let url = format!(
             "{}/tiingo/daily/{}/prices",
             self.base_url, ticker
         );
 
-        let response = self
+        self.limiter
+            .acquire(&self.observer, Service::Tiingo, "daily_prices")
+            .await;
+        let span = self.observer.start(Service::Tiingo, "daily_prices");
+        let response = match self
             .client
             .get(&url)
             .header("Authorization", format!("Token {}", self.api_key))
-            .query(&[("startDate", &date_str), ("endDate", &date_str)])
+            .query(&[("startDate", &start_str), ("endDate", &end_str)])
             .send()
-            .await?;
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                observer::finish(span, None);
+                return Err(err.into());
+            }
+        };
 
         let status = response.status();
+        observer::finish(span, Some(status.as_u16()));
 
         // 404 = ticker not found on Tiingo
         if status == reqwest::StatusCode::NOT_FOUND {
-            return Ok(None);
+            let empty = Arc::new(Vec::new());
+            self.series.insert(key, Arc::clone(&empty));
+            return Ok(empty);
         }
 
         // 401 = bad API key
@@ -125,7 +246,9 @@ impl TiingoClient {
         })?;
 
         // Empty array = no data for this date range
-        Ok(prices.first().map(|p| p.adj_close))
+        let prices = Arc::new(prices);
+        self.series.insert(key, Arc::clone(&prices));
+        Ok(prices)
     }
 }
 
@@ -255,6 +378,69 @@ mod tests {
         assert!(matches!(result.unwrap_err(), TiingoError::InvalidApiKey));
     }
 
+    #[tokio::test]
+    async fn repeated_range_is_served_from_cache() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/AAPL/prices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(sample_price_json())
+                    .insert_header("content-type", "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tiingo/daily/GONE/prices"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TiingoClient::with_base_url(&server.uri(), "test-key".to_string()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        for _ in 0..3 {
+            assert_eq!(client.get_price_on_date("AAPL", date).await.unwrap(), Some(142.56));
+            assert_eq!(client.get_price_on_date("GONE", date).await.unwrap(), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn cache_is_bounded_and_can_be_disabled() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(sample_price_json())
+                    .insert_header("content-type", "application/json"),
+            )
+            .mount(&server)
+            .await;
+
+        let date = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        let requests = || async { server.received_requests().await.unwrap().len() };
+
+        // One slot: alternating tickers evict each other.
+        let client = TiingoClient::with_base_url(&server.uri(), "test-key".to_string())
+            .unwrap()
+            .with_cache_capacity(1);
+        for ticker in ["AAPL", "MSFT", "AAPL"] {
+            client.get_price_on_date(ticker, date).await.unwrap();
+        }
+        assert_eq!(requests().await, 3);
+
+        // Capacity 0 fetches every time.
+        let client = TiingoClient::with_base_url(&server.uri(), "test-key".to_string())
+            .unwrap()
+            .with_cache_capacity(0);
+        client.get_price_on_date("AAPL", date).await.unwrap();
+        client.get_price_on_date("AAPL", date).await.unwrap();
+        assert_eq!(requests().await, 5);
+    }
+
     #[test]
     fn tiingo_error_display() {
         let err = TiingoError::RateLimited;
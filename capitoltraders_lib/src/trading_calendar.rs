@@ -0,0 +1,175 @@
+//! US equity market (NYSE) trading-day calendar.
+//!
+//! Holidays are computed from the exchange's rules rather than a fixed list:
+//! New Year's Day, Martin Luther King Jr. Day, Washington's Birthday, Good
+//! Friday, Memorial Day, Juneteenth (from 2022), Independence Day, Labor Day,
+//! Thanksgiving, and Christmas. Fixed-date holidays falling on a Saturday are
+//! observed the Friday before and on a Sunday the Monday after, except that
+//! New Year's Day on a Saturday is not made up (the exchange stays open on
+//! December 31). One-off closures (national days of mourning, 2012's
+//! Hurricane Sandy) are not modelled.
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+
+/// True when the exchange is open on `date`.
+pub fn is_trading_day(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !is_market_holiday(date)
+}
+
+/// Number of trading days strictly between `start` and `end`.
+///
+/// Zero when the dates are adjacent, equal, or out of order.
+pub fn trading_days_between(start: NaiveDate, end: NaiveDate) -> i64 {
+    let mut count = 0;
+    let mut day = start;
+    while let Some(next) = day.checked_add_days(Days::new(1)) {
+        if next >= end {
+            break;
+        }
+        if is_trading_day(next) {
+            count += 1;
+        }
+        day = next;
+    }
+    count
+}
+
+/// Latest trading day on or before `date`: `date` itself when the exchange
+/// is open, otherwise the session a weekend or holiday falls back to.
+pub fn last_trading_day_on_or_before(date: NaiveDate) -> NaiveDate {
+    let mut day = date;
+    while !is_trading_day(day) {
+        match day.pred_opt() {
+            Some(prev) => day = prev,
+            None => break,
+        }
+    }
+    day
+}
+
+/// True when `date` is a weekday market holiday.
+pub fn is_market_holiday(date: NaiveDate) -> bool {
+    let year = date.year();
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).map(observed);
+
+    // New Year's Day: only the Sunday -> Monday shift applies.
+    let new_year = NaiveDate::from_ymd_opt(year, 1, 1)
+        .filter(|d| d.weekday() != Weekday::Sat)
+        .map(observed);
+
+    let holidays = [
+        new_year,
+        nth_weekday(year, 1, Weekday::Mon, 3),
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        easter_sunday(year).and_then(|d| d.checked_sub_days(Days::new(2))),
+        last_weekday(year, 5, Weekday::Mon),
+        if year >= 2022 { fixed(6, 19) } else { None },
+        fixed(7, 4),
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        fixed(12, 25),
+    ];
+    holidays.contains(&Some(date))
+}
+
+/// Shift a fixed-date holiday off the weekend.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().unwrap_or(date),
+        Weekday::Sun => date.succ_opt().unwrap_or(date),
+        _ => date,
+    }
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    nth_weekday(year, month, weekday, 5).or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Western (Gregorian) Easter Sunday, by the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_last_trading_day_on_or_before() {
+        assert_eq!(last_trading_day_on_or_before(ymd(2024, 6, 14)), ymd(2024, 6, 14));
+        assert_eq!(last_trading_day_on_or_before(ymd(2024, 6, 16)), ymd(2024, 6, 14));
+        // Good Friday 2024 and the weekend after it fall back to Thursday.
+        assert_eq!(last_trading_day_on_or_before(ymd(2024, 3, 31)), ymd(2024, 3, 28));
+    }
+
+    #[test]
+    fn test_2024_holidays() {
+        let holidays = [
+            ymd(2024, 1, 1),
+            ymd(2024, 1, 15),
+            ymd(2024, 2, 19),
+            ymd(2024, 3, 29),
+            ymd(2024, 5, 27),
+            ymd(2024, 6, 19),
+            ymd(2024, 7, 4),
+            ymd(2024, 9, 2),
+            ymd(2024, 11, 28),
+            ymd(2024, 12, 25),
+        ];
+        for day in holidays {
+            assert!(is_market_holiday(day), "{} should be a holiday", day);
+            assert!(!is_trading_day(day));
+        }
+        assert!(is_trading_day(ymd(2024, 7, 5)));
+        assert!(!is_trading_day(ymd(2024, 7, 6)));
+    }
+
+    #[test]
+    fn test_observed_shifts() {
+        // Independence Day 2026 is a Saturday: observed Friday July 3.
+        assert!(is_market_holiday(ymd(2026, 7, 3)));
+        // Christmas 2022 is a Sunday: observed Monday December 26.
+        assert!(is_market_holiday(ymd(2022, 12, 26)));
+        // New Year's Day 2022 is a Saturday: no make-up day on December 31, 2021.
+        assert!(is_trading_day(ymd(2021, 12, 31)));
+        // Juneteenth only from 2022.
+        assert!(is_trading_day(ymd(2020, 6, 19)));
+    }
+
+    #[test]
+    fn test_easter() {
+        assert_eq!(easter_sunday(2024), Some(ymd(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Some(ymd(2025, 4, 20)));
+    }
+
+    #[test]
+    fn test_trading_days_between() {
+        // Friday to Monday: only the weekend in between.
+        assert_eq!(trading_days_between(ymd(2024, 6, 7), ymd(2024, 6, 10)), 0);
+        // Wed July 3 to Mon July 8, 2024: July 4 holiday, July 5 open.
+        assert_eq!(trading_days_between(ymd(2024, 7, 3), ymd(2024, 7, 8)), 1);
+        assert_eq!(trading_days_between(ymd(2024, 7, 8), ymd(2024, 7, 3)), 0);
+    }
+}
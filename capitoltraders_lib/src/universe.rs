@@ -0,0 +1,450 @@
+//! The trade universe: which trades an analysis is allowed to see.
+//!
+//! Analytics, anomaly detection and conflict scoring used to each pick their
+//! own subset of `trades` in SQL, so a rule change in one (say, dropping
+//! options) quietly made its numbers disagree with the others. A
+//! [`TradeUniverse`] names the inclusion rules once, loads the matching trades
+//! as [`UniverseTrade`]s through [`Db::query_trade_universe`], and records the
+//! rules and resulting count in a [`UniverseProvenance`] that outputs carry
+//! alongside their results.
+//!
+//! [`TradeUniverse::priced_stocks`] is the universe analytics and anomaly
+//! detection share: stock (and unclassified) trades with a trade-date price
+//! and an estimated share count.
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::analytics::AnalyticsTrade;
+use crate::calendar::SessionStatus;
+use crate::db::{AnalyticsTradeRow, Db, DbError};
+
+/// Asset types treated as stock: `unknown` covers rows scraped before the
+/// asset type was recorded.
+pub const STOCK_ASSET_TYPES: [&str; 2] = ["stock", "unknown"];
+
+/// Inclusion rules for a trade universe.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UniverseSpec {
+    /// Asset types kept; empty keeps every type.
+    pub asset_types: Vec<String>,
+    /// Only trades with a trade-date price and an estimated share count.
+    pub enriched_prices_only: bool,
+    /// Drop trades a later filing reported again (same politician, issuer,
+    /// asset type, owner, type and transaction date), keeping the latest filing.
+    pub exclude_superseded: bool,
+    /// Drop trades [`Db::disclosure_integrity`] flags as inconsistent.
+    pub exclude_quarantined: bool,
+    /// First transaction date kept (YYYY-MM-DD).
+    pub since: Option<String>,
+    /// Last transaction date kept (YYYY-MM-DD).
+    pub until: Option<String>,
+    /// Only this politician's trades.
+    pub politician_id: Option<String>,
+}
+
+impl UniverseSpec {
+    /// One human-readable line per rule in force, in a fixed order.
+    pub fn rules(&self) -> Vec<String> {
+        let mut rules = Vec::new();
+        if self.asset_types.is_empty() {
+            rules.push("all asset types".to_string());
+        } else {
+            rules.push(format!("asset types: {}", self.asset_types.join(", ")));
+        }
+        if self.enriched_prices_only {
+            rules.push("enriched prices only".to_string());
+        }
+        if self.exclude_superseded {
+            rules.push("superseded filings excluded".to_string());
+        }
+        if self.exclude_quarantined {
+            rules.push("integrity-flagged trades excluded".to_string());
+        }
+        match (&self.since, &self.until) {
+            (Some(since), Some(until)) => rules.push(format!("traded {} to {}", since, until)),
+            (Some(since), None) => rules.push(format!("traded from {}", since)),
+            (None, Some(until)) => rules.push(format!("traded through {}", until)),
+            (None, None) => {}
+        }
+        if let Some(ref politician_id) = self.politician_id {
+            rules.push(format!("politician {}", politician_id));
+        }
+        rules
+    }
+
+    /// Compact identifier of the rules, for cache keys.
+    pub fn key(&self) -> String {
+        format!(
+            "assets={};priced={};exclude_superseded={};exclude_quarantined={};since={};until={};politician={}",
+            if self.asset_types.is_empty() {
+                "all".to_string()
+            } else {
+                self.asset_types.join("+")
+            },
+            self.enriched_prices_only,
+            self.exclude_superseded,
+            self.exclude_quarantined,
+            self.since.as_deref().unwrap_or("-"),
+            self.until.as_deref().unwrap_or("-"),
+            self.politician_id.as_deref().unwrap_or("-"),
+        )
+    }
+}
+
+/// The rules a universe was built with and how many trades they admitted,
+/// attached to the outputs computed from it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UniverseProvenance {
+    pub spec: UniverseSpec,
+    /// [`UniverseSpec::rules`], spelled out.
+    pub rules: Vec<String>,
+    pub trade_count: usize,
+}
+
+impl fmt::Display for UniverseProvenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} trades ({})", self.trade_count, self.rules.join("; "))
+    }
+}
+
+/// A trade in a universe, with what analytics and anomaly detection read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniverseTrade {
+    pub tx_id: i64,
+    pub politician_id: String,
+    /// "First Last", or the politician ID when the politician row is missing.
+    pub politician_name: String,
+    pub issuer_ticker: String,
+    pub asset_type: String,
+    pub tx_type: String,
+    pub tx_date: String,
+    pub estimated_shares: Option<f64>,
+    pub trade_date_price: Option<f64>,
+    pub benchmark_price: Option<f64>,
+    pub gics_sector: Option<String>,
+    /// Trade chamber, falling back to the politician's when the trade's is blank.
+    pub chamber: String,
+}
+
+impl UniverseTrade {
+    /// The trade as an analytics row, when it has a price and share count.
+    pub fn analytics_row(&self) -> Option<AnalyticsTradeRow> {
+        Some(AnalyticsTradeRow {
+            tx_id: self.tx_id,
+            politician_id: self.politician_id.clone(),
+            issuer_ticker: self.issuer_ticker.clone(),
+            tx_type: self.tx_type.clone(),
+            tx_date: self.tx_date.clone(),
+            estimated_shares: self.estimated_shares?,
+            trade_date_price: self.trade_date_price?,
+            benchmark_price: self.benchmark_price,
+            gics_sector: self.gics_sector.clone(),
+            chamber: self.chamber.clone(),
+            session_status: SessionStatus::Unknown,
+        })
+    }
+
+    /// The trade as FIFO input, when it has a price and share count.
+    pub fn analytics_trade(&self) -> Option<AnalyticsTrade> {
+        Some(AnalyticsTrade {
+            tx_id: self.tx_id,
+            politician_id: self.politician_id.clone(),
+            ticker: self.issuer_ticker.clone(),
+            tx_type: self.tx_type.clone(),
+            tx_date: self.tx_date.clone(),
+            estimated_shares: self.estimated_shares?,
+            trade_date_price: self.trade_date_price?,
+            benchmark_price: self.benchmark_price,
+            has_sector_benchmark: self.gics_sector.is_some() && self.benchmark_price.is_some(),
+            gics_sector: self.gics_sector.clone(),
+        })
+    }
+}
+
+/// Builder for a trade universe over a database.
+pub struct TradeUniverse<'a> {
+    db: &'a Db,
+    spec: UniverseSpec,
+}
+
+impl<'a> TradeUniverse<'a> {
+    /// Every trade, no rules applied.
+    pub fn from_db(db: &'a Db) -> Self {
+        Self {
+            db,
+            spec: UniverseSpec::default(),
+        }
+    }
+
+    /// The universe analytics and anomaly detection share: priced stock trades.
+    pub fn priced_stocks(db: &'a Db) -> Self {
+        Self::from_db(db)
+            .with_asset_types(STOCK_ASSET_TYPES)
+            .enriched_prices_only()
+    }
+
+    /// Keep only these asset types.
+    pub fn with_asset_types<I, S>(mut self, asset_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.spec.asset_types = asset_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Keep only trades with a trade-date price and an estimated share count.
+    pub fn enriched_prices_only(mut self) -> Self {
+        self.spec.enriched_prices_only = true;
+        self
+    }
+
+    /// Drop trades a later filing reported again.
+    pub fn exclude_superseded(mut self) -> Self {
+        self.spec.exclude_superseded = true;
+        self
+    }
+
+    /// Drop trades with inconsistent disclosure data.
+    pub fn exclude_quarantined(mut self) -> Self {
+        self.spec.exclude_quarantined = true;
+        self
+    }
+
+    /// Keep trades transacted within `since..=until`; either end may be open.
+    pub fn date_range(mut self, since: Option<NaiveDate>, until: Option<NaiveDate>) -> Self {
+        self.spec.since = since.map(|d| d.format("%Y-%m-%d").to_string());
+        self.spec.until = until.map(|d| d.format("%Y-%m-%d").to_string());
+        self
+    }
+
+    /// Keep only `politician_id`'s trades.
+    pub fn for_politician(mut self, politician_id: impl Into<String>) -> Self {
+        self.spec.politician_id = Some(politician_id.into());
+        self
+    }
+
+    pub fn spec(&self) -> &UniverseSpec {
+        &self.spec
+    }
+
+    /// Load the trades, oldest first (tx_date, then tx_id).
+    pub fn build(self) -> Result<Universe, DbError> {
+        let trades = self.db.query_trade_universe(&self.spec)?;
+        Ok(Universe {
+            provenance: UniverseProvenance {
+                rules: self.spec.rules(),
+                trade_count: trades.len(),
+                spec: self.spec,
+            },
+            trades,
+        })
+    }
+}
+
+/// Trades admitted by a [`TradeUniverse`], with the rules that admitted them.
+#[derive(Debug, Clone)]
+pub struct Universe {
+    pub trades: Vec<UniverseTrade>,
+    pub provenance: UniverseProvenance,
+}
+
+impl Universe {
+    /// Priced trades as analytics rows, in universe order.
+    pub fn analytics_rows(&self) -> Vec<AnalyticsTradeRow> {
+        self.trades.iter().filter_map(UniverseTrade::analytics_row).collect()
+    }
+
+    /// Priced trades as FIFO input, in universe order.
+    pub fn analytics_trades(&self) -> Vec<AnalyticsTrade> {
+        self.trades.iter().filter_map(UniverseTrade::analytics_trade).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.trades.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trades.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anomaly::{detect_unusual_volume_universe, future_price_candidates, VolumeParams};
+    use crate::fixtures::{open_db, scraped_trade};
+
+    /// Insert a trade by Jane Doe in issuer 1, filed on `pub_date` under
+    /// `filing_id`, priced at 100 when `priced`.
+    fn trade(db: &mut Db, tx_id: i64, tx: (&str, &str), filing: (&str, i64), priced: bool) {
+        let mut t = scraped_trade(tx_id, "P000001", 1);
+        t.tx_type = tx.0.to_string();
+        t.tx_date = tx.1.to_string();
+        t.pub_date = format!("{}T00:00:00Z", filing.0);
+        t.filing_id = Some(filing.1);
+        db.upsert_scraped_trades(&[t]).unwrap();
+        if priced {
+            db.update_trade_prices(tx_id, Some(100.0), Some(10.0), Some(1000.0), Some("test"))
+                .unwrap();
+        }
+    }
+
+    /// Six trades, each tripping at most one rule:
+    /// 1. a stock buy a later filing (5) reports again
+    /// 2. a stock sell
+    /// 3. an option
+    /// 4. an unpriced stock buy
+    /// 5. the later filing of 1
+    /// 6. a buy filed before it was made
+    fn seeded_db() -> Db {
+        let mut db = open_db();
+        trade(&mut db, 1, ("buy", "2024-01-10"), ("2024-01-20", 100), true);
+        trade(&mut db, 2, ("sell", "2024-02-09"), ("2024-02-20", 102), true);
+        trade(&mut db, 3, ("buy", "2024-01-15"), ("2024-01-25", 103), true);
+        db.conn()
+            .execute("UPDATE assets SET asset_type = 'stock-option' WHERE asset_id = 3", [])
+            .unwrap();
+        trade(&mut db, 4, ("buy", "2024-01-12"), ("2024-01-22", 104), false);
+        trade(&mut db, 5, ("buy", "2024-01-10"), ("2024-02-01", 105), true);
+        trade(&mut db, 6, ("buy", "2024-03-10"), ("2024-03-01", 106), true);
+        db
+    }
+
+    fn tx_ids(universe: &Universe) -> Vec<i64> {
+        universe.trades.iter().map(|t| t.tx_id).collect()
+    }
+
+    #[test]
+    fn test_each_rule_drops_its_trades() {
+        let db = seeded_db();
+
+        let all = TradeUniverse::from_db(&db).build().unwrap();
+        assert_eq!(tx_ids(&all), vec![1, 5, 4, 3, 2, 6]);
+        assert_eq!(all.provenance.rules, vec!["all asset types"]);
+        assert_eq!(all.trades[0].politician_name, "Jane Doe");
+
+        let priced = TradeUniverse::priced_stocks(&db).build().unwrap();
+        assert_eq!(tx_ids(&priced), vec![1, 5, 2, 6]);
+
+        let current = TradeUniverse::priced_stocks(&db).exclude_superseded().build().unwrap();
+        assert_eq!(tx_ids(&current), vec![5, 2, 6]);
+
+        let clean = TradeUniverse::priced_stocks(&db).exclude_quarantined().build().unwrap();
+        assert_eq!(tx_ids(&clean), vec![1, 5, 2]);
+
+        let february = TradeUniverse::from_db(&db)
+            .date_range(NaiveDate::from_ymd_opt(2024, 2, 1), NaiveDate::from_ymd_opt(2024, 2, 29))
+            .build()
+            .unwrap();
+        assert_eq!(tx_ids(&february), vec![2]);
+        assert_eq!(february.provenance.rules[1], "traded 2024-02-01 to 2024-02-29");
+
+        let jane = TradeUniverse::priced_stocks(&db).for_politician("P000001").build().unwrap();
+        assert_eq!(tx_ids(&jane), vec![1, 5, 2, 6]);
+        assert_eq!(jane.provenance.rules.last().unwrap(), "politician P000001");
+        let nobody = TradeUniverse::priced_stocks(&db).for_politician("P999999").build().unwrap();
+        assert!(nobody.is_empty());
+    }
+
+    #[test]
+    fn test_quarantine_drops_what_disclosure_integrity_flags() {
+        let db = seeded_db();
+        db.conn()
+            .execute(
+                "UPDATE trades SET size_range_low = 50000, size_range_high = 1000 WHERE tx_id = 2",
+                [],
+            )
+            .unwrap();
+        let clean = tx_ids(&TradeUniverse::from_db(&db).exclude_quarantined().build().unwrap());
+        let mut dropped: Vec<i64> = tx_ids(&TradeUniverse::from_db(&db).build().unwrap())
+            .into_iter()
+            .filter(|tx_id| !clean.contains(tx_id))
+            .collect();
+        dropped.sort_unstable();
+        let mut flagged: Vec<i64> =
+            db.disclosure_integrity().unwrap().iter().map(|issue| issue.tx_id).collect();
+        flagged.dedup();
+        assert_eq!(dropped, vec![2, 6]);
+        assert_eq!(dropped, flagged);
+    }
+
+    #[test]
+    fn test_provenance_records_rules_and_count() {
+        let db = seeded_db();
+        let universe = TradeUniverse::priced_stocks(&db)
+            .exclude_superseded()
+            .exclude_quarantined()
+            .build()
+            .unwrap();
+        assert_eq!(universe.provenance.trade_count, 2);
+        assert_eq!(
+            universe.provenance.to_string(),
+            "2 trades (asset types: stock, unknown; enriched prices only; \
+             superseded filings excluded; integrity-flagged trades excluded)"
+        );
+        assert_ne!(
+            universe.provenance.spec.key(),
+            TradeUniverse::priced_stocks(&db).spec().key()
+        );
+    }
+
+    #[test]
+    fn test_analytics_and_anomalies_see_the_same_trades() {
+        let db = seeded_db();
+        let far_future = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        let specs = [
+            TradeUniverse::priced_stocks(&db),
+            TradeUniverse::priced_stocks(&db).exclude_superseded(),
+            TradeUniverse::priced_stocks(&db).exclude_quarantined(),
+            TradeUniverse::from_db(&db).enriched_prices_only(),
+        ];
+        for spec in specs {
+            let universe = spec.build().unwrap();
+            let fifo_input = universe.analytics_trades().len();
+            let pre_move_input = future_price_candidates(&universe.trades, far_future).len();
+            assert_eq!(fifo_input, universe.provenance.trade_count, "{}", universe.provenance);
+            assert_eq!(pre_move_input, universe.provenance.trade_count, "{}", universe.provenance);
+        }
+        assert_eq!(
+            db.query_trades_for_analytics().unwrap().len(),
+            TradeUniverse::priced_stocks(&db).build().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_volume_signal_follows_the_universe() {
+        let db = seeded_db();
+        let params = VolumeParams {
+            reference_date: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+            lookback_days: 60,
+            baseline_days: 365,
+            unusual_ratio: 2.0,
+        };
+        let volume = |universe: TradeUniverse| {
+            let universe = universe.build().unwrap();
+            let signals = detect_unusual_volume_universe(&universe.trades, &params);
+            assert_eq!(signals.len(), 1);
+            signals[0].signal.clone()
+        };
+
+        // Trades 2 and 6 fall in the lookback window; 1 and its later
+        // filing 5 both count toward the baseline until 1 is dropped.
+        let all = volume(TradeUniverse::priced_stocks(&db));
+        let current = volume(TradeUniverse::priced_stocks(&db).exclude_superseded());
+        assert_eq!(all.recent_trade_count, 2);
+        assert_eq!(current.recent_trade_count, 2);
+        assert!((all.historical_avg - 2.0 * 60.0 / 365.0).abs() < 1e-9);
+        assert!((current.historical_avg - 60.0 / 365.0).abs() < 1e-9);
+        assert!((current.volume_ratio - 2.0 * all.volume_ratio).abs() < 1e-9);
+
+        // Quarantine drops a lookback trade instead, so it moves the ratio too.
+        let clean = volume(TradeUniverse::priced_stocks(&db).exclude_quarantined());
+        assert_eq!(clean.recent_trade_count, 1);
+    }
+}
@@ -7,7 +7,7 @@
 use capitoltrades_api::types::{
     AssetType, Chamber, Gender, Label, MarketCap, Party, Sector, TradeSize, TxType,
 };
-use chrono::{NaiveDate, Utc};
+use chrono::{Datelike, Days, Months, NaiveDate, Utc};
 
 use crate::error::CapitolTradesError;
 
@@ -312,6 +312,23 @@ pub fn validate_tx_type(input: &str) -> Result<TxType, CapitolTradesError> {
     }
 }
 
+/// Validate a trade owner string, returning the canonical CapitolTrades value
+/// (`self`, `spouse`, `joint`, `child`, `not-disclosed`). Accepts spaces or
+/// underscores in place of hyphens and a few common aliases.
+pub fn validate_owner(input: &str) -> Result<&'static str, CapitolTradesError> {
+    match input.trim().to_lowercase().replace([' ', '_'], "-").as_str() {
+        "self" | "owner-self" | "filer" => Ok("self"),
+        "spouse" | "sp" => Ok("spouse"),
+        "joint" | "jt" => Ok("joint"),
+        "child" | "dependent" | "dc" => Ok("child"),
+        "not-disclosed" | "undisclosed" => Ok("not-disclosed"),
+        _ => Err(CapitolTradesError::InvalidInput(format!(
+            "unknown owner '{}'. Valid values: self, spouse, joint, child, not-disclosed",
+            input
+        ))),
+    }
+}
+
 /// Validate a chamber string: case-insensitive, supports shorthand h/s.
 pub fn validate_chamber(input: &str) -> Result<Chamber, CapitolTradesError> {
     match input.trim().to_lowercase().as_str() {
@@ -399,6 +416,70 @@ pub fn validate_date(input: &str) -> Result<NaiveDate, CapitolTradesError> {
     })
 }
 
+/// Parse a date that is either ISO (YYYY-MM-DD) or relative to `today`.
+///
+/// Relative forms are a positive count followed by a unit: `d` (days),
+/// `w` (weeks), `m` (calendar months), `y` (calendar years), e.g. `30d`,
+/// `6m`, `1y`. `ytd` resolves to January 1 of `today`'s year. Month and
+/// year offsets clamp to the last day of the target month, so `1m` from
+/// March 31 is February 28/29.
+///
+/// Bare numbers (`30`), missing counts (`m`), zero counts, and unknown or
+/// multi-letter units (`5min`, `2q`) are rejected rather than guessed at.
+pub fn parse_relative_date(input: &str, today: NaiveDate) -> Result<NaiveDate, CapitolTradesError> {
+    let trimmed = input.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    let invalid = |reason: &str| {
+        CapitolTradesError::InvalidInput(format!(
+            "invalid date '{}': {}. Expected YYYY-MM-DD or a relative expression \
+             like 30d, 2w, 6m, 1y, ytd",
+            trimmed, reason
+        ))
+    };
+
+    if lower.is_empty() {
+        return Err(invalid("empty value"));
+    }
+    if lower == "ytd" {
+        return NaiveDate::from_ymd_opt(today.year(), 1, 1)
+            .ok_or_else(|| invalid("year out of range"));
+    }
+    if lower.contains('-') {
+        return validate_date(trimmed);
+    }
+
+    let digits_end = lower
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(lower.len());
+    let (count, unit) = lower.split_at(digits_end);
+    if count.is_empty() {
+        return Err(invalid("missing count before the unit"));
+    }
+    if unit.is_empty() {
+        return Err(invalid("missing unit (d, w, m, or y)"));
+    }
+    let n: u32 = count.parse().map_err(|_| invalid("count is too large"))?;
+    if n == 0 {
+        return Err(invalid("count must be at least 1"));
+    }
+
+    let resolved = match unit {
+        "d" => today.checked_sub_days(Days::new(u64::from(n))),
+        "w" => today.checked_sub_days(Days::new(u64::from(n) * 7)),
+        "m" => today.checked_sub_months(Months::new(n)),
+        "y" => n
+            .checked_mul(12)
+            .and_then(|months| today.checked_sub_months(Months::new(months))),
+        _ => return Err(invalid("unknown unit (use d, w, m, or y)")),
+    };
+    resolved.ok_or_else(|| invalid("resolves outside the supported date range"))
+}
+
+/// [`parse_relative_date`] resolved against today's UTC date.
+pub fn validate_since_date(input: &str) -> Result<NaiveDate, CapitolTradesError> {
+    parse_relative_date(input, Utc::now().date_naive())
+}
+
 /// Validate relative days: must be 1..=3650 (approx 10 years).
 pub fn validate_days(days: i64) -> Result<i64, CapitolTradesError> {
     if !(1..=3650).contains(&days) {
@@ -422,6 +503,44 @@ pub fn date_to_relative_days(date: NaiveDate) -> Option<i64> {
     }
 }
 
+// -- Canonical stored forms --
+//
+// The database stores party, chamber, and gender as the lowercase serde names
+// of the vendored enums ("democrat", "senate", "female"). The functions below
+// map labels from any source onto those enums and, unlike the validators
+// above, never fail on unrecognised input.
+
+/// Map a party label from any source to its canonical [`Party`].
+///
+/// Recognises full names, "democratic"/"dem"/"d", and "rep"/"gop"/"r".
+/// Everything else -- "Independent", "I", third parties, "other", empty --
+/// maps to [`Party::Other`], stored as `"other"`.
+pub fn canonical_party(input: &str) -> Party {
+    match input.trim().to_lowercase().as_str() {
+        "democrat" | "democratic" | "democratic party" | "dem" | "d" => Party::Democrat,
+        "republican" | "republican party" | "rep" | "gop" | "r" => Party::Republican,
+        _ => Party::Other,
+    }
+}
+
+/// Map a chamber label from any source to [`Chamber`], or `None` if unrecognised.
+pub fn canonical_chamber(input: &str) -> Option<Chamber> {
+    match input.trim().to_lowercase().as_str() {
+        "house" | "house of representatives" | "h" => Some(Chamber::House),
+        "senate" | "s" => Some(Chamber::Senate),
+        _ => None,
+    }
+}
+
+/// Map a gender label from any source to [`Gender`], or `None` if unrecognised.
+pub fn canonical_gender(input: &str) -> Option<Gender> {
+    match input.trim().to_lowercase().as_str() {
+        "female" | "f" => Some(Gender::Female),
+        "male" | "m" => Some(Gender::Male),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 #[path = "validation_tests.rs"]
 mod tests;
@@ -360,6 +360,22 @@ fn tx_type_invalid() {
     assert!(validate_tx_type("transfer").is_err());
 }
 
+// -- Owner validation --
+
+#[test]
+fn owner_canonical_and_aliases() {
+    assert_eq!(validate_owner("Spouse").unwrap(), "spouse");
+    assert_eq!(validate_owner("JT").unwrap(), "joint");
+    assert_eq!(validate_owner("not disclosed").unwrap(), "not-disclosed");
+    assert_eq!(validate_owner("not_disclosed").unwrap(), "not-disclosed");
+    assert_eq!(validate_owner(" self ").unwrap(), "self");
+}
+
+#[test]
+fn owner_invalid() {
+    assert!(validate_owner("trust").is_err());
+}
+
 // -- Chamber validation --
 
 #[test]
@@ -500,6 +516,55 @@ fn date_empty() {
     assert!(validate_date("   ").is_err());
 }
 
+// -- Relative date parsing --
+
+fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn relative_date_units() {
+    let today = ymd(2025, 6, 15);
+    assert_eq!(parse_relative_date("30d", today).unwrap(), ymd(2025, 5, 16));
+    assert_eq!(parse_relative_date("2w", today).unwrap(), ymd(2025, 6, 1));
+    assert_eq!(parse_relative_date("6m", today).unwrap(), ymd(2024, 12, 15));
+    assert_eq!(parse_relative_date("1y", today).unwrap(), ymd(2024, 6, 15));
+    assert_eq!(parse_relative_date(" 1Y ", today).unwrap(), ymd(2024, 6, 15));
+}
+
+#[test]
+fn relative_date_ytd_is_jan_first() {
+    assert_eq!(parse_relative_date("ytd", ymd(2025, 6, 15)).unwrap(), ymd(2025, 1, 1));
+    assert_eq!(parse_relative_date("YTD", ymd(2024, 1, 1)).unwrap(), ymd(2024, 1, 1));
+}
+
+#[test]
+fn relative_date_month_end_clamps() {
+    assert_eq!(parse_relative_date("1m", ymd(2024, 3, 31)).unwrap(), ymd(2024, 2, 29));
+    assert_eq!(parse_relative_date("1y", ymd(2024, 2, 29)).unwrap(), ymd(2023, 2, 28));
+}
+
+#[test]
+fn relative_date_accepts_iso() {
+    assert_eq!(parse_relative_date("2024-06-01", ymd(2025, 6, 15)).unwrap(), ymd(2024, 6, 1));
+    assert!(parse_relative_date("2024-13-01", ymd(2025, 6, 15)).is_err());
+}
+
+#[test]
+fn relative_date_rejects_ambiguous() {
+    let today = ymd(2025, 6, 15);
+    for input in ["", "30", "m", "0d", "-5d", "5min", "2q", "1.5y", "d30", "99999999999d"] {
+        let err = parse_relative_date(input, today).unwrap_err();
+        assert!(
+            matches!(err, CapitolTradesError::InvalidInput(_)),
+            "expected InvalidInput for {:?}",
+            input
+        );
+    }
+    let msg = parse_relative_date("30", today).unwrap_err().to_string();
+    assert!(msg.contains("missing unit"), "{}", msg);
+}
+
 // -- Days validation --
 
 #[test]
@@ -543,3 +608,32 @@ fn relative_days_future_returns_none() {
     let tomorrow = Utc::now().date_naive() + chrono::Duration::days(1);
     assert_eq!(date_to_relative_days(tomorrow), None);
 }
+
+// -- canonical forms --
+
+#[test]
+fn canonical_party_case_and_aliases() {
+    for raw in ["Democrat", "DEMOCRAT", " democratic ", "Dem", "d"] {
+        assert_eq!(canonical_party(raw).to_string(), "democrat", "{raw}");
+    }
+    for raw in ["Republican", "GOP", "r"] {
+        assert_eq!(canonical_party(raw).to_string(), "republican", "{raw}");
+    }
+}
+
+#[test]
+fn canonical_party_unknown_is_other() {
+    for raw in ["Independent", "I", "Libertarian", "Other", ""] {
+        assert_eq!(canonical_party(raw).to_string(), "other", "{raw}");
+    }
+}
+
+#[test]
+fn canonical_chamber_and_gender() {
+    assert_eq!(canonical_chamber("Senate").unwrap().to_string(), "senate");
+    assert_eq!(canonical_chamber(" HOUSE ").unwrap().to_string(), "house");
+    assert!(canonical_chamber("joint").is_none());
+    assert_eq!(canonical_gender("F").unwrap().to_string(), "female");
+    assert_eq!(canonical_gender("Male").unwrap().to_string(), "male");
+    assert!(canonical_gender("unknown").is_none());
+}
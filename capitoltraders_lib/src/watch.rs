@@ -0,0 +1,441 @@
+//! Trade watch support: notification filters and snapshot-based delivery.
+//!
+//! The `watch` CLI command repeatedly syncs trades and calls [`deliver_new_trades`]
+//! to notify about trades that appeared since the last recorded snapshot. The
+//! snapshot is advanced only past trades that were delivered (or filtered out),
+//! so a restart or a failed notification resumes without repeating or skipping
+//! trades.
+//!
+//! The mark is the named snapshot [`WATCH_SNAPSHOT`], holding only the trades
+//! section of [`crate::snapshot`], so `diff --since-snapshot @watch` lists the
+//! trades not yet delivered.
+
+use std::future::Future;
+use std::path::Path;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::db::{Db, DbError, DbTradeRow};
+use crate::snapshot::{load_trade_summary, save_trade_summary, TradeSummary};
+use crate::validation;
+
+/// Name of the snapshot holding the delivery mark. The `@` keeps it out of
+/// the names `snapshot create` accepts, so a user snapshot never replaces it.
+pub const WATCH_SNAPSHOT: &str = "@watch";
+
+/// Errors from watch operations.
+#[derive(Error, Debug)]
+pub enum WatchError {
+    #[error("Database error: {0}")]
+    Database(#[from] DbError),
+    #[error("Failed to read filter file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid filter file: {0}")]
+    InvalidFilter(String),
+    #[error("Notification for trade {tx_id} failed: {message}")]
+    Notify { tx_id: i64, message: String },
+}
+
+/// Criteria a new trade must meet to trigger a notification.
+///
+/// Loaded from a TOML file; every field is optional and set fields are
+/// combined with AND. List fields match any of their values. String
+/// comparisons are case-insensitive.
+///
+/// ```toml
+/// min_value = 100000
+/// committees = ["ssfi"]   # Senate - Finance (code or full name)
+/// tx_types = ["buy"]
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TradeAlertFilter {
+    /// Minimum trade value in dollars (inclusive).
+    pub min_value: Option<i64>,
+    /// Maximum trade value in dollars (inclusive).
+    pub max_value: Option<i64>,
+    #[serde(default)]
+    pub tx_types: Vec<String>,
+    #[serde(default)]
+    pub parties: Vec<String>,
+    #[serde(default)]
+    pub chambers: Vec<String>,
+    /// Politician state codes (e.g. "CA").
+    #[serde(default)]
+    pub states: Vec<String>,
+    /// Committee codes or full names; matched against the politician's
+    /// committee memberships and the committees recorded on the trade.
+    #[serde(default)]
+    pub committees: Vec<String>,
+    #[serde(default)]
+    pub politician_ids: Vec<String>,
+    /// Tickers, with or without exchange suffix ("NVDA" matches "NVDA:US").
+    #[serde(default)]
+    pub tickers: Vec<String>,
+}
+
+impl TradeAlertFilter {
+    /// Parse a filter from TOML, normalizing committee names to codes.
+    pub fn from_toml_str(content: &str) -> Result<Self, WatchError> {
+        let mut filter: TradeAlertFilter =
+            toml::from_str(content).map_err(|e| WatchError::InvalidFilter(e.to_string()))?;
+
+        filter.committees = filter
+            .committees
+            .iter()
+            .map(|c| validation::validate_committee(c))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| WatchError::InvalidFilter(e.to_string()))?;
+
+        if let (Some(min), Some(max)) = (filter.min_value, filter.max_value) {
+            if min > max {
+                return Err(WatchError::InvalidFilter(format!(
+                    "min_value ({}) exceeds max_value ({})",
+                    min, max
+                )));
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Read and parse a TOML filter file.
+    pub fn from_file(path: &Path) -> Result<Self, WatchError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml_str(&content)
+    }
+
+    /// True when the filter needs politician committee memberships to evaluate.
+    pub fn uses_committees(&self) -> bool {
+        !self.committees.is_empty()
+    }
+
+    /// Check a trade against the filter.
+    ///
+    /// `member_committees` holds the politician's committee codes; it is only
+    /// consulted when the filter has committee criteria.
+    pub fn matches(&self, trade: &DbTradeRow, member_committees: &[String]) -> bool {
+        if self.min_value.is_some_and(|min| trade.value < min) {
+            return false;
+        }
+        if self.max_value.is_some_and(|max| trade.value > max) {
+            return false;
+        }
+        if !matches_any(&self.tx_types, &trade.tx_type)
+            || !matches_any(&self.parties, &trade.party)
+            || !matches_any(&self.chambers, &trade.chamber)
+            || !matches_any(&self.states, &trade.state)
+            || !matches_any(&self.politician_ids, &trade.politician_id)
+        {
+            return false;
+        }
+        if !self.tickers.is_empty() {
+            let base = trade
+                .issuer_ticker
+                .split(':')
+                .next()
+                .unwrap_or_default();
+            let hit = self.tickers.iter().any(|t| {
+                t.eq_ignore_ascii_case(&trade.issuer_ticker) || t.eq_ignore_ascii_case(base)
+            });
+            if !hit {
+                return false;
+            }
+        }
+        if !self.committees.is_empty() {
+            let hit = self.committees.iter().any(|code| {
+                member_committees
+                    .iter()
+                    .chain(trade.committees.iter())
+                    .any(|c| c.eq_ignore_ascii_case(code))
+            });
+            if !hit {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn matches_any(allowed: &[String], value: &str) -> bool {
+    allowed.is_empty() || allowed.iter().any(|a| a.eq_ignore_ascii_case(value))
+}
+
+/// Destination for new-trade notifications (command, webhook, or a test recorder).
+pub trait TradeNotifier {
+    /// Deliver one trade. An `Err` stops the batch; the trade is retried next cycle.
+    fn notify(&mut self, trade: &DbTradeRow) -> impl Future<Output = Result<(), String>>;
+}
+
+/// Outcome of one [`deliver_new_trades`] pass.
+#[derive(Debug, Clone)]
+pub struct DeliveryReport {
+    /// Trades newer than the previous snapshot.
+    pub new_trades: usize,
+    /// Trades that matched the filter and were delivered.
+    pub notified: usize,
+    /// Snapshot recorded after this pass (None if nothing new was processed).
+    pub snapshot: Option<TradeSummary>,
+}
+
+/// Record a baseline snapshot if none exists, so a first watch run does not
+/// notify about the trades already in the database.
+pub fn ensure_baseline(db: &Db) -> Result<TradeSummary, DbError> {
+    match load_trade_summary(db, WATCH_SNAPSHOT)? {
+        Some(snapshot) => Ok(snapshot),
+        None => advance_mark(db, None),
+    }
+}
+
+/// Store the mark covering trades up to `through_tx_id` (all trades for None).
+fn advance_mark(db: &Db, through_tx_id: Option<i64>) -> Result<TradeSummary, DbError> {
+    let (max_tx_id, trade_count) = db.trade_id_summary(through_tx_id)?;
+    let snapshot = TradeSummary {
+        max_tx_id,
+        trade_count,
+    };
+    save_trade_summary(db, WATCH_SNAPSHOT, &snapshot)?;
+    Ok(snapshot)
+}
+
+/// Notify about trades added since the latest snapshot, then advance it.
+///
+/// Trades are processed in tx_id order. When a notification fails, the snapshot
+/// is advanced only through the last trade before the failure and the error is
+/// returned, so the failing trade and everything after it are retried on the
+/// next call. Trades that do not match `filter` count as processed.
+pub async fn deliver_new_trades<N: TradeNotifier>(
+    db: &Db,
+    filter: &TradeAlertFilter,
+    notifier: &mut N,
+) -> Result<DeliveryReport, WatchError> {
+    let baseline = ensure_baseline(db)?;
+    let new_trades = db.trades_after_tx_id(baseline.max_tx_id)?;
+    if new_trades.is_empty() {
+        return Ok(DeliveryReport {
+            new_trades: 0,
+            notified: 0,
+            snapshot: None,
+        });
+    }
+
+    let mut notified = 0usize;
+    let mut processed_through: Option<i64> = None;
+
+    for trade in &new_trades {
+        let member_committees = if filter.uses_committees() {
+            db.get_politician_committee_names(&trade.politician_id)?
+        } else {
+            Vec::new()
+        };
+
+        if filter.matches(trade, &member_committees) {
+            if let Err(message) = notifier.notify(trade).await {
+                if let Some(through) = processed_through {
+                    advance_mark(db, Some(through))?;
+                }
+                return Err(WatchError::Notify {
+                    tx_id: trade.tx_id,
+                    message,
+                });
+            }
+            notified += 1;
+        }
+        processed_through = Some(trade.tx_id);
+    }
+
+    let snapshot = advance_mark(db, processed_through)?;
+    Ok(DeliveryReport {
+        new_trades: new_trades.len(),
+        notified,
+        snapshot: Some(snapshot),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::open_db as open_test_db;
+
+    /// Records delivered tx_ids; fails on the listed tx_ids until cleared.
+    #[derive(Default)]
+    struct RecordingNotifier {
+        delivered: Vec<i64>,
+        fail_on: Vec<i64>,
+    }
+
+    impl TradeNotifier for RecordingNotifier {
+        async fn notify(&mut self, trade: &DbTradeRow) -> Result<(), String> {
+            if self.fail_on.contains(&trade.tx_id) {
+                return Err("endpoint unavailable".to_string());
+            }
+            self.delivered.push(trade.tx_id);
+            Ok(())
+        }
+    }
+
+    /// Simulate a sync cycle by inserting trades with INSERT OR IGNORE,
+    /// so re-synced (overlapping) trades are left as-is.
+    fn sync_cycle(db: &Db, trades: &[(i64, i64)]) {
+        db.conn()
+            .execute_batch(
+                "INSERT OR IGNORE INTO assets (asset_id, asset_type) VALUES (1, 'stock');
+                 INSERT OR IGNORE INTO issuers (issuer_id, issuer_name, issuer_ticker)
+                     VALUES (1, 'NVIDIA Corp', 'NVDA:US');
+                 INSERT OR IGNORE INTO politicians (politician_id, state_id, party, first_name, last_name, dob, gender, chamber)
+                     VALUES ('P000001', 'ID', 'republican', 'Mike', 'Crapo', '1951-05-20', 'male', 'senate'),
+                            ('P000002', 'CA', 'democrat', 'Ro', 'Khanna', '1976-09-13', 'male', 'house');
+                 INSERT OR IGNORE INTO politician_committees (politician_id, committee)
+                     VALUES ('P000001', 'ssfi');",
+            )
+            .expect("seed rows");
+        for &(tx_id, value) in trades {
+            let politician_id = if tx_id % 2 == 0 { "P000002" } else { "P000001" };
+            db.conn()
+                .execute(
+                    "INSERT OR IGNORE INTO trades (tx_id, politician_id, asset_id, issuer_id, pub_date,
+                        filing_date, tx_date, tx_type, has_capital_gains, owner, chamber,
+                        value, filing_id, filing_url, reporting_gap)
+                     VALUES (?1, ?2, 1, 1, '2025-01-10', '2025-01-09', '2025-01-02', 'buy', 0,
+                        'self', 'senate', ?3, 0, '', 8)",
+                    rusqlite::params![tx_id, politician_id, value],
+                )
+                .expect("insert trade");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_sync_cycles_notify_exactly_once() {
+        let db = open_test_db();
+        ensure_baseline(&db).expect("baseline");
+        let filter = TradeAlertFilter::default();
+        let mut notifier = RecordingNotifier::default();
+
+        sync_cycle(&db, &[(1, 1_000), (2, 1_000), (3, 1_000)]);
+        let first = deliver_new_trades(&db, &filter, &mut notifier).await.expect("cycle 1");
+        assert_eq!(first.new_trades, 3);
+        assert_eq!(first.notified, 3);
+
+        // Incremental sync re-fetches the last day: trades 2-3 again, plus 4-5
+        sync_cycle(&db, &[(2, 1_000), (3, 1_000), (4, 1_000), (5, 1_000)]);
+        let second = deliver_new_trades(&db, &filter, &mut notifier).await.expect("cycle 2");
+        assert_eq!(second.new_trades, 2);
+
+        // Nothing new: no notifications, no new snapshot
+        let third = deliver_new_trades(&db, &filter, &mut notifier).await.expect("cycle 3");
+        assert_eq!(third.new_trades, 0);
+        assert!(third.snapshot.is_none());
+
+        assert_eq!(notifier.delivered, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_restart_resumes_from_stored_snapshot() {
+        let db = open_test_db();
+        ensure_baseline(&db).expect("baseline");
+        let filter = TradeAlertFilter::default();
+
+        sync_cycle(&db, &[(1, 1_000), (2, 1_000)]);
+        let mut before_restart = RecordingNotifier::default();
+        deliver_new_trades(&db, &filter, &mut before_restart).await.expect("cycle 1");
+
+        // A fresh notifier (new process) sees only trades after the stored snapshot
+        sync_cycle(&db, &[(2, 1_000), (3, 1_000)]);
+        let mut after_restart = RecordingNotifier::default();
+        deliver_new_trades(&db, &filter, &mut after_restart).await.expect("cycle 2");
+
+        assert_eq!(before_restart.delivered, vec![1, 2]);
+        assert_eq!(after_restart.delivered, vec![3]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_notification_is_retried_next_cycle() {
+        let db = open_test_db();
+        ensure_baseline(&db).expect("baseline");
+        let filter = TradeAlertFilter::default();
+        let mut notifier = RecordingNotifier {
+            fail_on: vec![2],
+            ..Default::default()
+        };
+
+        sync_cycle(&db, &[(1, 1_000), (2, 1_000), (3, 1_000)]);
+        let err = deliver_new_trades(&db, &filter, &mut notifier)
+            .await
+            .expect_err("trade 2 fails");
+        assert!(matches!(err, WatchError::Notify { tx_id: 2, .. }));
+
+        notifier.fail_on.clear();
+        deliver_new_trades(&db, &filter, &mut notifier).await.expect("retry");
+        assert_eq!(notifier.delivered, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_baseline_skips_existing_trades() {
+        let db = open_test_db();
+        sync_cycle(&db, &[(1, 1_000), (2, 1_000)]);
+        ensure_baseline(&db).expect("baseline");
+
+        sync_cycle(&db, &[(3, 1_000)]);
+        let mut notifier = RecordingNotifier::default();
+        deliver_new_trades(&db, &TradeAlertFilter::default(), &mut notifier)
+            .await
+            .expect("deliver");
+        assert_eq!(notifier.delivered, vec![3]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_value_and_committee() {
+        let db = open_test_db();
+        ensure_baseline(&db).expect("baseline");
+        let filter = TradeAlertFilter::from_toml_str(
+            "min_value = 100000\ncommittees = [\"Senate - Finance\"]",
+        )
+        .expect("parse filter");
+        assert_eq!(filter.committees, vec!["ssfi".to_string()]);
+
+        // Odd tx_ids belong to the Finance member, even ones do not
+        sync_cycle(&db, &[(1, 250_000), (2, 250_000), (3, 15_000)]);
+        let mut notifier = RecordingNotifier::default();
+        let report = deliver_new_trades(&db, &filter, &mut notifier).await.expect("deliver");
+        assert_eq!(report.new_trades, 3);
+        assert_eq!(notifier.delivered, vec![1]);
+
+        // Filtered-out trades are not reconsidered
+        let again = deliver_new_trades(&db, &filter, &mut notifier).await.expect("deliver");
+        assert_eq!(again.new_trades, 0);
+    }
+
+    #[test]
+    fn test_filter_parse_rejects_unknown_fields() {
+        assert!(TradeAlertFilter::from_toml_str("min_valu = 5").is_err());
+        assert!(TradeAlertFilter::from_toml_str("committees = [\"nope\"]").is_err());
+        assert!(TradeAlertFilter::from_toml_str("min_value = 10\nmax_value = 5").is_err());
+    }
+
+    #[test]
+    fn test_filter_ticker_matches_with_or_without_suffix() {
+        let db = open_test_db();
+        sync_cycle(&db, &[(1, 1_000)]);
+        let trade = db
+            .query_trades(&crate::db::DbTradeFilter::default())
+            .expect("query")
+            .remove(0);
+
+        let by_base = TradeAlertFilter {
+            tickers: vec!["nvda".to_string()],
+            ..Default::default()
+        };
+        let by_full = TradeAlertFilter {
+            tickers: vec!["NVDA:US".to_string()],
+            ..Default::default()
+        };
+        let other = TradeAlertFilter {
+            tickers: vec!["AAPL".to_string()],
+            ..Default::default()
+        };
+        assert!(by_base.matches(&trade, &[]));
+        assert!(by_full.matches(&trade, &[]));
+        assert!(!other.matches(&trade, &[]));
+    }
+}
@@ -6,9 +6,27 @@
 use chrono::{Datelike, NaiveDate};
 use dashmap::DashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use time::OffsetDateTime;
 
+use crate::cache::LruCache;
+use crate::config::AppConfig;
+use crate::observer::{ObserverSlot, RequestObserver, Service};
+use crate::ratelimit::{HostLimiter, Limiter, Rate};
+
+/// Default pacing for quote requests: bursts of 5, about 14 per second
+/// sustained.
+pub const DEFAULT_RATE: Rate = Rate::new(5, Duration::from_millis(350));
+
+/// Default number of (ticker, start, end) quote series kept in memory.
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Host the connector sends quote requests to; keys the rate limit bucket.
+const YAHOO_URL: &str = "https://query1.finance.yahoo.com";
+
+type SeriesKey = (String, NaiveDate, NaiveDate);
+
 /// Errors from Yahoo Finance operations.
 #[derive(Error, Debug)]
 pub enum YahooError {
@@ -56,6 +74,10 @@ fn is_rate_limit_api_error(err: &yahoo_finance_api::YahooError) -> bool {
 pub struct YahooClient {
     connector: yahoo_finance_api::YahooConnector,
     cache: Arc<DashMap<(String, NaiveDate), Option<f64>>>,
+    /// Dated adjusted closes per (ticker, start, end) range actually fetched.
+    series: LruCache<SeriesKey, Arc<Vec<(NaiveDate, f64)>>>,
+    observer: ObserverSlot,
+    limiter: HostLimiter,
 }
 
 impl YahooClient {
@@ -64,9 +86,75 @@ impl YahooClient {
         Ok(Self {
             connector: yahoo_finance_api::YahooConnector::new()?,
             cache: Arc::new(DashMap::new()),
+            series: LruCache::new(DEFAULT_CACHE_CAPACITY),
+            observer: ObserverSlot::default(),
+            limiter: HostLimiter::new(Arc::new(Limiter::new(DEFAULT_RATE)), YAHOO_URL),
         })
     }
 
+    /// Build with the `[yahoo]` rate of a resolved config.
+    pub fn from_config(config: &AppConfig) -> Result<Self, YahooError> {
+        let mut client = Self::new()?;
+        if let Some(rate) = config.yahoo.rate {
+            client = client.with_rate(rate.to_rate());
+        }
+        if let Some(capacity) = config.yahoo.cache_capacity {
+            client = client.with_cache_capacity(capacity);
+        }
+        Ok(client)
+    }
+
+    /// Report every quote request to `observer` (see [`crate::observer`]).
+    ///
+    /// The Yahoo connector does not expose HTTP status codes, so responses
+    /// report `Some(200)` on success, `Some(429)` when rate limited and
+    /// `None` for other failures.
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = ObserverSlot::new(observer);
+        self
+    }
+
+    /// Pace quote requests through a shared limiter (see [`crate::ratelimit`])
+    /// instead of the default [`DEFAULT_RATE`].
+    pub fn with_limiter(mut self, limiter: Arc<Limiter>) -> Self {
+        self.limiter.replace(limiter);
+        self
+    }
+
+    /// Pace quote requests at `rate` instead of [`DEFAULT_RATE`].
+    pub fn with_rate(self, rate: Rate) -> Self {
+        self.with_limiter(Arc::new(Limiter::new(rate)))
+    }
+
+    /// Keep up to `capacity` fetched quote series in memory, keyed by
+    /// (ticker, start, end), so overlapping lookups reuse one request. Zero
+    /// disables the series cache.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.series = LruCache::new(capacity);
+        self
+    }
+
+    async fn quote_history(
+        &self,
+        ticker: &str,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> Result<yahoo_finance_api::YResponse, yahoo_finance_api::YahooError> {
+        self.limiter
+            .acquire(&self.observer, Service::Yahoo, "quote_history")
+            .await;
+        let span = self.observer.start(Service::Yahoo, "quote_history");
+        let result = self.connector.get_quote_history(ticker, start, end).await;
+        if let Some(span) = span {
+            span.finish(match &result {
+                Ok(_) => Some(200),
+                Err(e) if is_rate_limit_api_error(e) => Some(429),
+                Err(_) => None,
+            });
+        }
+        result
+    }
+
     /// Get the number of cached entries (for testing).
     pub fn cache_len(&self) -> usize {
         self.cache.len()
@@ -89,52 +177,13 @@ impl YahooClient {
             return Ok(*cached);
         }
 
-        // Convert date to time range (midnight to midnight+1 day)
-        let start = date_to_offset_datetime(date)?;
-        let end = date_to_offset_datetime(
-            date.checked_add_days(chrono::Days::new(1))
-                .ok_or_else(|| YahooError::InvalidDate(date.to_string()))?,
-        )?;
-
-        // Fetch from Yahoo Finance
-        match self.connector.get_quote_history(ticker, start, end).await {
-            Ok(response) => {
-                // Extract quotes - this can also fail with NoQuotes/NoResult
-                match response.quotes() {
-                    Ok(quotes) => {
-                        // Take first quote's adjclose (already f64)
-                        let price = quotes.first().map(|q| q.adjclose);
-
-                        // Cache the result
-                        self.cache.insert(key, price);
-
-                        Ok(price)
-                    }
-                    Err(yahoo_finance_api::YahooError::NoQuotes)
-                    | Err(yahoo_finance_api::YahooError::NoResult) => {
-                        // No data for this date
-                        self.cache.insert(key, None);
-                        Ok(None)
-                    }
-                    Err(e) => Err(YahooError::ParseFailed(format!(
-                        "Failed to extract quotes: {}",
-                        e
-                    ))),
-                }
-            }
-            Err(ref e) if is_rate_limit_api_error(e) => {
-                // Rate limited -- do NOT cache, let caller retry later
-                Err(YahooError::RateLimited)
-            }
-            Err(yahoo_finance_api::YahooError::NoQuotes)
-            | Err(yahoo_finance_api::YahooError::NoResult)
-            | Err(yahoo_finance_api::YahooError::ApiError(_)) => {
-                // Invalid ticker or no data - cache None and return gracefully
-                self.cache.insert(key, None);
-                Ok(None)
-            }
-            Err(e) => Err(YahooError::Upstream(e)),
-        }
+        let next_day = date
+            .checked_add_days(chrono::Days::new(1))
+            .ok_or_else(|| YahooError::InvalidDate(date.to_string()))?;
+        let series = self.daily_closes(ticker, date, next_day).await?;
+        let price = series.first().map(|&(_, close)| close);
+        self.cache.insert(key, price);
+        Ok(price)
     }
 
     /// Get price on date with weekend/holiday fallback.
@@ -178,47 +227,68 @@ impl YahooClient {
             .checked_sub_days(chrono::Days::new(7))
             .ok_or_else(|| YahooError::InvalidDate(date.to_string()))?;
 
-        let start = date_to_offset_datetime(start_date)?;
-        let end = date_to_offset_datetime(
-            date.checked_add_days(chrono::Days::new(1))
-                .ok_or_else(|| YahooError::InvalidDate(date.to_string()))?,
-        )?;
-
-        match self.connector.get_quote_history(ticker, start, end).await {
-            Ok(response) => {
-                // Extract quotes - this can also fail with NoQuotes/NoResult
-                match response.quotes() {
-                    Ok(quotes) => {
-                        // Take the last (most recent) quote's adjclose (already f64)
-                        let price = quotes.last().map(|q| q.adjclose);
-
-                        // Cache the original date -> found price
-                        self.cache.insert((ticker.to_string(), date), price);
-
-                        Ok(price)
-                    }
-                    Err(yahoo_finance_api::YahooError::NoQuotes)
-                    | Err(yahoo_finance_api::YahooError::NoResult) => {
-                        // No data in this range
-                        Ok(None)
-                    }
-                    Err(e) => Err(YahooError::ParseFailed(format!(
+        let next_day = date
+            .checked_add_days(chrono::Days::new(1))
+            .ok_or_else(|| YahooError::InvalidDate(date.to_string()))?;
+        let series = self.daily_closes(ticker, start_date, next_day).await?;
+
+        // Take the last (most recent) quote and cache it for the original date
+        let price = series.last().map(|&(_, close)| close);
+        if price.is_some() {
+            self.cache.insert((ticker.to_string(), date), price);
+        }
+        Ok(price)
+    }
+
+    /// Adjusted closes for `ticker` from `start` up to (not including) `end`,
+    /// oldest first and dated by their UTC session timestamp, reusing the
+    /// series cache when the same range was fetched before.
+    ///
+    /// Invalid tickers and ranges without data yield an empty series. Rate
+    /// limits and other failures are returned uncached so callers can retry.
+    pub async fn daily_closes(
+        &self,
+        ticker: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Arc<Vec<(NaiveDate, f64)>>, YahooError> {
+        let key = (ticker.to_string(), start, end);
+        if let Some(cached) = self.series.get(&key) {
+            return Ok(cached);
+        }
+
+        let from = date_to_offset_datetime(start)?;
+        let to = date_to_offset_datetime(end)?;
+        let closes = match self.quote_history(ticker, from, to).await {
+            // Extracting quotes can also fail with NoQuotes/NoResult
+            Ok(response) => match response.quotes() {
+                Ok(quotes) => quotes
+                    .iter()
+                    .filter_map(|q| {
+                        let date = chrono::DateTime::from_timestamp(q.timestamp, 0)?.date_naive();
+                        Some((date, q.adjclose))
+                    })
+                    .collect(),
+                Err(yahoo_finance_api::YahooError::NoQuotes)
+                | Err(yahoo_finance_api::YahooError::NoResult) => Vec::new(),
+                Err(e) => {
+                    return Err(YahooError::ParseFailed(format!(
                         "Failed to extract quotes: {}",
                         e
-                    ))),
+                    )))
                 }
-            }
-            Err(ref e) if is_rate_limit_api_error(e) => {
-                Err(YahooError::RateLimited)
-            }
+            },
+            Err(ref e) if is_rate_limit_api_error(e) => return Err(YahooError::RateLimited),
+            // Invalid ticker or no data
             Err(yahoo_finance_api::YahooError::NoQuotes)
             | Err(yahoo_finance_api::YahooError::NoResult)
-            | Err(yahoo_finance_api::YahooError::ApiError(_)) => {
-                // Still no data - ticker is genuinely invalid
-                Ok(None)
-            }
-            Err(e) => Err(YahooError::Upstream(e)),
-        }
+            | Err(yahoo_finance_api::YahooError::ApiError(_)) => Vec::new(),
+            Err(e) => return Err(YahooError::Upstream(e)),
+        };
+
+        let closes = Arc::new(closes);
+        self.series.insert(key, Arc::clone(&closes));
+        Ok(closes)
     }
 
     /// Get the current price for a ticker.
@@ -321,6 +391,17 @@ mod tests {
         assert_eq!(client.cache_len(), 0, "Cache should start empty");
     }
 
+    #[test]
+    fn test_from_config_applies_cache_capacity() {
+        let mut config = AppConfig::defaults();
+        let client = YahooClient::from_config(&config).unwrap();
+        assert_eq!(client.series.capacity(), DEFAULT_CACHE_CAPACITY);
+
+        config.yahoo.cache_capacity = Some(0);
+        let client = YahooClient::from_config(&config).unwrap();
+        assert_eq!(client.series.capacity(), 0);
+    }
+
     #[tokio::test]
     async fn test_cache_deduplication() {
         let client = YahooClient::new().unwrap();
@@ -0,0 +1,356 @@
+//! ZIP-code to congressional district lookup for donation geography analysis.
+//!
+//! Mappings are plain CSV with a version header:
+//!
+//! ```text
+//! # version: 2024-census-zcta
+//! zip,state,district
+//! 94110,CA,11
+//! 995,AK,0
+//! ```
+//!
+//! `zip` is either a 5-digit ZIP or a 3-digit prefix; 5-digit rows win over
+//! prefix rows. A ZIP that straddles districts is listed once per district.
+//! District `0` means at-large and matches any district in that state.
+//!
+//! The bundled file (`seed_data/zip_districts.csv`, embedded with
+//! `include_str!` like `ticker_alias.rs`) only covers single-district states.
+//! Users can point at a fuller file, e.g. one derived from the Census
+//! ZCTA-to-congressional-district relationship file. The version label is
+//! carried into query output so results can be traced to their mapping.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Serialize;
+use thiserror::Error;
+
+/// Error types for ZIP-to-district mapping files.
+#[derive(Error, Debug)]
+pub enum ZipDistrictError {
+    #[error("Failed to read ZIP-to-district file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("ZIP-to-district file is missing a '# version: ...' line")]
+    MissingVersion,
+    #[error("Invalid ZIP-to-district row at line {line}: {message}")]
+    InvalidRow { line: usize, message: String },
+}
+
+/// One district a ZIP (or ZIP prefix) falls in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipDistrict {
+    /// Two-letter state code, uppercase.
+    pub state: String,
+    /// District number; 0 means at-large.
+    pub district: u32,
+}
+
+/// Parsed ZIP-to-district mapping.
+#[derive(Debug, Clone)]
+pub struct ZipDistrictMap {
+    version: String,
+    by_zip5: HashMap<String, Vec<ZipDistrict>>,
+    by_prefix: HashMap<String, Vec<ZipDistrict>>,
+}
+
+impl ZipDistrictMap {
+    /// Parse mapping CSV content. Blank lines and `#` comments are skipped;
+    /// a `zip,state,district` header row is optional.
+    pub fn parse(content: &str) -> Result<Self, ZipDistrictError> {
+        let mut version = None;
+        let mut by_zip5: HashMap<String, Vec<ZipDistrict>> = HashMap::new();
+        let mut by_prefix: HashMap<String, Vec<ZipDistrict>> = HashMap::new();
+
+        for (idx, raw_line) in content.lines().enumerate() {
+            let line_no = idx + 1;
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(value) = comment.trim().strip_prefix("version:") {
+                    version = Some(value.trim().to_string());
+                }
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 3 {
+                return Err(ZipDistrictError::InvalidRow {
+                    line: line_no,
+                    message: format!("expected 3 fields, found {}", fields.len()),
+                });
+            }
+            if fields[0].eq_ignore_ascii_case("zip") {
+                continue;
+            }
+
+            let zip = fields[0];
+            if !zip.chars().all(|c| c.is_ascii_digit()) || !matches!(zip.len(), 3 | 5) {
+                return Err(ZipDistrictError::InvalidRow {
+                    line: line_no,
+                    message: format!("zip '{}' must be 5 digits or a 3-digit prefix", zip),
+                });
+            }
+            let state = fields[1].to_uppercase();
+            if state.len() != 2 || !state.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ZipDistrictError::InvalidRow {
+                    line: line_no,
+                    message: format!("state '{}' must be a two-letter code", fields[1]),
+                });
+            }
+            let district = parse_district(fields[2]).ok_or_else(|| ZipDistrictError::InvalidRow {
+                line: line_no,
+                message: format!("district '{}' is not a number or 'AL'", fields[2]),
+            })?;
+
+            let target = if zip.len() == 5 {
+                &mut by_zip5
+            } else {
+                &mut by_prefix
+            };
+            let entry = ZipDistrict { state, district };
+            let districts = target.entry(zip.to_string()).or_default();
+            if !districts.contains(&entry) {
+                districts.push(entry);
+            }
+        }
+
+        Ok(Self {
+            version: version.ok_or(ZipDistrictError::MissingVersion)?,
+            by_zip5,
+            by_prefix,
+        })
+    }
+
+    /// Load a user-supplied mapping file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ZipDistrictError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::parse(&content)
+    }
+
+    /// Load the mapping embedded at compile time.
+    pub fn bundled() -> Result<Self, ZipDistrictError> {
+        Self::parse(include_str!("../../seed_data/zip_districts.csv"))
+    }
+
+    /// Version label from the file's `# version:` line.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Number of ZIP and prefix entries.
+    pub fn len(&self) -> usize {
+        self.by_zip5.len() + self.by_prefix.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Districts for a normalized 5-digit ZIP: exact rows if present,
+    /// otherwise rows for its 3-digit prefix. Empty if unmapped.
+    pub fn lookup(&self, zip5: &str) -> &[ZipDistrict] {
+        if let Some(districts) = self.by_zip5.get(zip5) {
+            return districts;
+        }
+        zip5.get(..3)
+            .and_then(|prefix| self.by_prefix.get(prefix))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Classify one donation relative to a member's state and (House) district.
+    ///
+    /// The ZIP drives classification: a missing or malformed ZIP is
+    /// [`GeographyBucket::Unknown`] even when a state is present. The
+    /// contributor state falls back to the mapping's state for the ZIP.
+    pub fn classify(
+        &self,
+        member_state: &str,
+        member_district: Option<u32>,
+        contributor_state: Option<&str>,
+        contributor_zip: Option<&str>,
+    ) -> GeographyBucket {
+        let Some(zip) = contributor_zip.and_then(normalize_zip) else {
+            return GeographyBucket::Unknown;
+        };
+        let districts = self.lookup(&zip);
+
+        let state = contributor_state
+            .map(|s| s.trim().to_uppercase())
+            .filter(|s| !s.is_empty())
+            .or_else(|| districts.first().map(|d| d.state.clone()));
+        let Some(state) = state else {
+            return GeographyBucket::Unknown;
+        };
+        if !state.eq_ignore_ascii_case(member_state) {
+            return GeographyBucket::OutOfState;
+        }
+
+        let Some(member_district) = member_district else {
+            return GeographyBucket::InState;
+        };
+        let in_member_state: Vec<&ZipDistrict> = districts
+            .iter()
+            .filter(|d| d.state.eq_ignore_ascii_case(member_state))
+            .collect();
+        if in_member_state.is_empty() {
+            GeographyBucket::InState
+        } else if in_member_state
+            .iter()
+            .any(|d| d.district == 0 || d.district == member_district)
+        {
+            GeographyBucket::InDistrict
+        } else {
+            GeographyBucket::InStateOtherDistrict
+        }
+    }
+}
+
+/// Where a donation came from relative to the recipient member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GeographyBucket {
+    /// ZIP maps to the member's House district.
+    InDistrict,
+    /// In the member's state, but the ZIP maps only to other districts.
+    InStateOtherDistrict,
+    /// In the member's state; district not determined (senator, no district
+    /// on file, or ZIP absent from the mapping).
+    InState,
+    /// Contributor state differs from the member's state.
+    OutOfState,
+    /// Missing or invalid ZIP, or no state could be determined.
+    Unknown,
+}
+
+impl GeographyBucket {
+    /// All buckets in display order.
+    pub const ALL: [GeographyBucket; 5] = [
+        GeographyBucket::InDistrict,
+        GeographyBucket::InStateOtherDistrict,
+        GeographyBucket::InState,
+        GeographyBucket::OutOfState,
+        GeographyBucket::Unknown,
+    ];
+}
+
+impl std::fmt::Display for GeographyBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            GeographyBucket::InDistrict => "in-district",
+            GeographyBucket::InStateOtherDistrict => "in-state (other district)",
+            GeographyBucket::InState => "in-state",
+            GeographyBucket::OutOfState => "out-of-state",
+            GeographyBucket::Unknown => "unknown",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Normalize a contributor ZIP (5-digit or ZIP+4, with or without a dash)
+/// to its 5-digit form. Returns `None` for anything shorter or non-numeric.
+pub fn normalize_zip(raw: &str) -> Option<String> {
+    let digits: String = raw.trim().chars().filter(|c| *c != '-').collect();
+    if !matches!(digits.len(), 5 | 9) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(digits[..5].to_string())
+}
+
+/// Parse a district label: "13", "013", or "AL"/"at-large" (returned as 0).
+pub fn parse_district(raw: &str) -> Option<u32> {
+    let trimmed = raw.trim();
+    if trimmed.eq_ignore_ascii_case("al") || trimmed.eq_ignore_ascii_case("at-large") {
+        return Some(0);
+    }
+    trimmed.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = "# version: test-1\nzip,state,district\n94110,CA,11\n94121,CA,11\n94121,CA,15\n940,CA,15\n995,AK,AL\n";
+
+    #[test]
+    fn test_parse_fixture() {
+        let map = ZipDistrictMap::parse(FIXTURE).unwrap();
+        assert_eq!(map.version(), "test-1");
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.lookup("94121").len(), 2);
+        // Exact row wins over the 940 prefix
+        assert_eq!(map.lookup("94110")[0].district, 11);
+        assert_eq!(map.lookup("94025")[0].district, 15);
+        assert_eq!(map.lookup("99501")[0].district, 0);
+        assert!(map.lookup("10001").is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            ZipDistrictMap::parse("zip,state,district\n94110,CA,11\n"),
+            Err(ZipDistrictError::MissingVersion)
+        ));
+        assert!(matches!(
+            ZipDistrictMap::parse("# version: x\n9411,CA,11\n"),
+            Err(ZipDistrictError::InvalidRow { line: 2, .. })
+        ));
+        assert!(matches!(
+            ZipDistrictMap::parse("# version: x\n94110,CA\n"),
+            Err(ZipDistrictError::InvalidRow { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_bundled_mapping_loads() {
+        let map = ZipDistrictMap::bundled().unwrap();
+        assert!(!map.version().is_empty());
+        assert_eq!(map.lookup("99501")[0].state, "AK");
+    }
+
+    #[test]
+    fn test_normalize_zip() {
+        assert_eq!(normalize_zip("94110").as_deref(), Some("94110"));
+        assert_eq!(normalize_zip("941101234").as_deref(), Some("94110"));
+        assert_eq!(normalize_zip("94110-1234").as_deref(), Some("94110"));
+        assert_eq!(normalize_zip("9411"), None);
+        assert_eq!(normalize_zip("ABCDE"), None);
+        assert_eq!(normalize_zip(""), None);
+    }
+
+    #[test]
+    fn test_classify_buckets() {
+        let map = ZipDistrictMap::parse(FIXTURE).unwrap();
+        let classify = |state: Option<&str>, zip: Option<&str>| {
+            map.classify("CA", Some(11), state, zip)
+        };
+        assert_eq!(classify(Some("CA"), Some("94110")), GeographyBucket::InDistrict);
+        // Split ZIP counts as in-district if any of its districts match
+        assert_eq!(classify(Some("CA"), Some("94121")), GeographyBucket::InDistrict);
+        assert_eq!(
+            classify(Some("CA"), Some("94025")),
+            GeographyBucket::InStateOtherDistrict
+        );
+        assert_eq!(classify(Some("CA"), Some("90210")), GeographyBucket::InState);
+        assert_eq!(classify(Some("NY"), Some("10001")), GeographyBucket::OutOfState);
+        assert_eq!(classify(Some("CA"), None), GeographyBucket::Unknown);
+        assert_eq!(classify(Some("CA"), Some("123")), GeographyBucket::Unknown);
+        // State inferred from the mapping when the contributor state is blank
+        assert_eq!(classify(None, Some("94110")), GeographyBucket::InDistrict);
+        assert_eq!(classify(None, Some("10001")), GeographyBucket::Unknown);
+
+        // Senators (no district) never land in a district bucket
+        assert_eq!(
+            map.classify("CA", None, Some("CA"), Some("94110")),
+            GeographyBucket::InState
+        );
+        // At-large rows match any district in the state
+        assert_eq!(
+            map.classify("AK", Some(1), Some("AK"), Some("99501")),
+            GeographyBucket::InDistrict
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Integration tests for CommitteeResolver with wiremock.
 
 use capitoltraders_lib::openfec::{OpenFecClient, OpenFecError};
-use capitoltraders_lib::{CommitteeClass, CommitteeResolver, Db};
+use capitoltraders_lib::ratelimit::host_key;
+use capitoltraders_lib::{CommitteeClass, CommitteeResolver, Db, Limiter, Rate};
 use serde_json::json;
 use std::sync::{Arc, Mutex};
 use wiremock::matchers::{method, path};
@@ -399,3 +400,117 @@ async fn test_resolve_api_error_propagates() {
         capitoltraders_lib::CommitteeError::OpenFec(OpenFecError::RateLimited)
     ));
 }
+
+fn committee_json(
+    committee_id: &str,
+    name: &str,
+    committee_type: &str,
+    designation: &str,
+) -> serde_json::Value {
+    json!({
+        "results": [
+            {
+                "committee_id": committee_id,
+                "name": name,
+                "committee_type": committee_type,
+                "designation": designation,
+                "party": "DEM",
+                "state": "CA",
+                "cycles": [2022, 2024]
+            }
+        ],
+        "pagination": {
+            "count": 1,
+            "page": null,
+            "pages": null,
+            "per_page": 20
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_warm_cache_fetches_each_uncached_committee_once() {
+    let db = Db::open_in_memory().expect("open db");
+    db.init().expect("init db");
+    db.conn()
+        .execute(
+            "INSERT INTO fec_committees (committee_id, name, committee_type, designation, last_synced)
+             VALUES ('C00000001', 'ALREADY STORED', 'H', 'P', datetime('now'))",
+            [],
+        )
+        .expect("insert committee");
+    let db = Arc::new(Mutex::new(db));
+
+    let mock_server = MockServer::start().await;
+    let base_url = format!("{}/v1", mock_server.uri());
+    let limiter = Arc::new(Limiter::new(Rate::per_hour(10)));
+    let client = OpenFecClient::with_base_url(&base_url, "test_api_key".to_string())
+        .expect("create client")
+        .with_limiter(Arc::clone(&limiter));
+    let resolver = CommitteeResolver::new(Arc::new(client), db.clone());
+
+    Mock::given(method("GET"))
+        .and(path("/v1/committee/C00000001/"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/committee/C00000002/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(committee_json("C00000002", "LEADERSHIP PAC", "N", "D")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/committee/C00000003/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(committee_json("C00000003", "STATE PARTY", "Y", "U")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let fetched = resolver
+        .warm_cache(&["C00000001", "C00000002", "C00000002", "C00000003"])
+        .await
+        .expect("warm_cache");
+    assert_eq!(fetched, 2);
+    // Each API call drew one token from the shared limiter; the stored
+    // committee and the duplicate did not.
+    assert_eq!(limiter.available(&host_key(&base_url)), 8);
+
+    assert_eq!(
+        resolver.cached_committee("C00000001").unwrap().classification,
+        CommitteeClass::Campaign
+    );
+    assert_eq!(
+        resolver.cached_committee("C00000002").unwrap().classification,
+        CommitteeClass::LeadershipPac
+    );
+    assert_eq!(
+        resolver.cached_committee("C00000003").unwrap().classification,
+        CommitteeClass::Party
+    );
+
+    // Fetched committees are persisted.
+    let stored: i64 = db
+        .lock()
+        .unwrap()
+        .conn()
+        .query_row("SELECT COUNT(*) FROM fec_committees", [], |row: &rusqlite::Row| {
+            row.get(0)
+        })
+        .expect("count committees");
+    assert_eq!(stored, 3);
+
+    // A second pass finds everything in memory.
+    let fetched = resolver
+        .warm_cache(&["C00000002", "C00000003"])
+        .await
+        .expect("warm_cache second pass");
+    assert_eq!(fetched, 0);
+}
@@ -55,6 +55,15 @@ fn deserialize_schedule_a_page1_fixture() {
         response.results[0].contributor_name.as_deref(),
         Some("SMITH, JOHN")
     );
+    assert_eq!(
+        response.results[0].contributor_zip.as_deref(),
+        Some("941101234")
+    );
+    assert_eq!(
+        response.results[0].contributor_city.as_deref(),
+        Some("SAN FRANCISCO")
+    );
+    assert!(response.results[1].contributor_zip.is_none());
     assert!(response.pagination.last_indexes.is_some());
     let cursor = response.pagination.last_indexes.as_ref().unwrap();
     assert_eq!(cursor.last_index, 230880619);
@@ -14,7 +14,8 @@ CREATE TABLE IF NOT EXISTS issuers (
     issuer_ticker TEXT,
     sector TEXT,
     enriched_at TEXT,
-    gics_sector TEXT
+    gics_sector TEXT,
+    exchange TEXT
 );
 
 CREATE TABLE IF NOT EXISTS politicians (
@@ -68,6 +69,9 @@ CREATE TABLE IF NOT EXISTS trades (
     estimated_value REAL,
     benchmark_price REAL,
     price_source TEXT,
+    valuation_method TEXT,
+    pub_date_price REAL,
+    fx_missing INTEGER NOT NULL DEFAULT 0,
     FOREIGN KEY (politician_id) REFERENCES politicians(politician_id) ON DELETE CASCADE,
     FOREIGN KEY (asset_id) REFERENCES assets(asset_id) ON DELETE CASCADE,
     FOREIGN KEY (issuer_id) REFERENCES issuers(issuer_id) ON DELETE CASCADE
@@ -109,6 +113,8 @@ CREATE TABLE IF NOT EXISTS issuer_stats (
     count_politicians INTEGER NOT NULL,
     volume INTEGER NOT NULL,
     date_last_traded TEXT NOT NULL,
+    last_trader_politician_id TEXT,
+    last_trader_name TEXT,
     FOREIGN KEY (issuer_id) REFERENCES issuers(issuer_id) ON DELETE CASCADE
 );
 
@@ -193,9 +199,13 @@ CREATE TABLE IF NOT EXISTS donations (
     contributor_zip TEXT,
     contribution_receipt_amount REAL,
     contribution_receipt_date TEXT,
+    -- Derived from contribution_receipt_date: the receipt year rounded up to
+    -- the next even year. two_year_transaction_period is what OpenFEC reported.
     election_cycle INTEGER,
     memo_text TEXT,
-    receipt_type TEXT
+    receipt_type TEXT,
+    receipt_class TEXT NOT NULL DEFAULT 'individual',
+    two_year_transaction_period INTEGER
 );
 
 CREATE TABLE IF NOT EXISTS donation_sync_meta (
@@ -206,6 +216,9 @@ CREATE TABLE IF NOT EXISTS donation_sync_meta (
     last_contribution_receipt_date TEXT,
     last_synced_at TEXT NOT NULL,
     total_synced INTEGER NOT NULL DEFAULT 0,
+    page_index INTEGER,
+    page_receipt_date TEXT,
+    last_sub_id TEXT,
     PRIMARY KEY (politician_id, committee_id, election_cycle)
 );
 
@@ -224,13 +237,105 @@ CREATE TABLE IF NOT EXISTS employer_lookup (
     normalized_employer TEXT NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS issuer_aliases (
+    from_issuer_id INTEGER PRIMARY KEY,
+    to_issuer_id INTEGER NOT NULL,
+    merged_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS ticker_aliases (
+    from_ticker TEXT PRIMARY KEY,
+    to_ticker TEXT,
+    source TEXT NOT NULL DEFAULT 'import'
+);
+
+CREATE TABLE IF NOT EXISTS trade_revisions (
+    revision_id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tx_id INTEGER NOT NULL,
+    observed_at TEXT NOT NULL,
+    old_value INTEGER NOT NULL,
+    new_value INTEGER NOT NULL,
+    old_tx_date TEXT NOT NULL,
+    new_tx_date TEXT NOT NULL,
+    old_tx_type TEXT NOT NULL,
+    new_tx_type TEXT NOT NULL,
+    old_size_range_low INTEGER,
+    new_size_range_low INTEGER,
+    old_size_range_high INTEGER,
+    new_size_range_high INTEGER,
+    old_price REAL,
+    new_price REAL,
+    FOREIGN KEY (tx_id) REFERENCES trades(tx_id) ON DELETE CASCADE
+);
+
 CREATE TABLE IF NOT EXISTS sector_benchmarks (
     sector TEXT PRIMARY KEY,
     etf_ticker TEXT NOT NULL,
     etf_name TEXT NOT NULL
 );
 
+-- Precomputed analytics leaderboard metrics. One parameter set at a time,
+-- rebuilt by `analytics --refresh`.
+CREATE TABLE IF NOT EXISTS politician_analytics_cache_meta (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    cache_key TEXT NOT NULL,
+    source_rows INTEGER NOT NULL,
+    source_fingerprint TEXT NOT NULL,
+    closed_trades INTEGER NOT NULL,
+    refreshed_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS politician_analytics_cache (
+    politician_id TEXT PRIMARY KEY,
+    total_trades INTEGER NOT NULL,
+    win_count INTEGER NOT NULL,
+    win_rate REAL NOT NULL,
+    avg_return REAL NOT NULL,
+    avg_alpha_spy REAL,
+    avg_alpha_sector REAL,
+    avg_holding_days INTEGER,
+    realized_short_term_gain REAL NOT NULL,
+    realized_long_term_gain REAL NOT NULL,
+    unknown_term_trades INTEGER NOT NULL,
+    return_std_error REAL,
+    return_ci_low REAL,
+    return_ci_high REAL,
+    win_rate_ci_low REAL,
+    win_rate_ci_high REAL
+);
+
+-- Daily closes downloaded by `enrich-prices`, keyed by the normalized
+-- (Yahoo-style) ticker, so later runs only request dates not stored yet.
+CREATE TABLE IF NOT EXISTS price_cache (
+    ticker TEXT NOT NULL,
+    price_date TEXT NOT NULL,
+    close REAL NOT NULL,
+    source TEXT NOT NULL,
+    fetched_at TEXT NOT NULL,
+    PRIMARY KEY (ticker, price_date)
+);
+
+-- Named summaries written by `snapshot create` and read by `diff`. Each
+-- section (trades, positions, ranks, anomalies) is a small JSON document
+-- with its own format number. `watch` keeps its delivery mark here too, as
+-- `@watch` with only a trades section.
+CREATE TABLE IF NOT EXISTS snapshots (
+    name TEXT PRIMARY KEY,
+    created_at TEXT NOT NULL,
+    schema_version INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS snapshot_sections (
+    snapshot_name TEXT NOT NULL,
+    section TEXT NOT NULL,
+    format INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (snapshot_name, section),
+    FOREIGN KEY (snapshot_name) REFERENCES snapshots(name) ON DELETE CASCADE
+);
+
 CREATE INDEX IF NOT EXISTS idx_trades_politician ON trades(politician_id);
+CREATE INDEX IF NOT EXISTS idx_trade_revisions_tx ON trade_revisions(tx_id);
 CREATE INDEX IF NOT EXISTS idx_trades_issuer ON trades(issuer_id);
 CREATE INDEX IF NOT EXISTS idx_trades_pub_date ON trades(pub_date);
 CREATE INDEX IF NOT EXISTS idx_trades_tx_date ON trades(tx_date);
@@ -241,6 +346,7 @@ CREATE INDEX IF NOT EXISTS idx_trade_labels_label ON trade_labels(label);
 CREATE INDEX IF NOT EXISTS idx_trade_committees_committee ON trade_committees(committee);
 CREATE INDEX IF NOT EXISTS idx_politician_committees_committee ON politician_committees(committee);
 CREATE INDEX IF NOT EXISTS idx_eod_prices_date ON issuer_eod_prices(price_date);
+CREATE INDEX IF NOT EXISTS idx_price_cache_fetched_at ON price_cache(fetched_at);
 CREATE INDEX IF NOT EXISTS idx_trades_enriched ON trades(enriched_at);
 CREATE INDEX IF NOT EXISTS idx_politicians_enriched ON politicians(enriched_at);
 CREATE INDEX IF NOT EXISTS idx_issuers_enriched ON issuers(enriched_at);